@@ -0,0 +1,226 @@
+use crate::{
+    middleware::{
+        circuit_breaker::circuit_breaker_middleware, jwt,
+        request_limiter::request_limiter_middleware, session::session_middleware,
+    },
+    state::AppState,
+};
+use axum::{
+    Json,
+    extract::{Extension, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+use shared::{
+    domain::{
+        requests::{
+            activity::FindRecentActivity, topup::FindAllTopupsByCardNumber,
+            transaction::FindAllTransactionCardNumber, transfer::FindTransfers,
+            withdraw::FindAllWithdrawCardNumber,
+        },
+        responses::{
+            ActivityItem, ActivityType, ApiResponsePagination, Pagination, RecentActivityResponse,
+        },
+    },
+    errors::HttpError,
+};
+use std::sync::Arc;
+use tracing::{info, warn};
+use utoipa_axum::router::OpenApiRouter;
+
+#[derive(Default)]
+struct ActivitySources {
+    sources: Vec<Vec<ActivityItem>>,
+    failed: Vec<ActivityType>,
+    total_items: i64,
+}
+
+impl ActivitySources {
+    fn collect<T>(
+        &mut self,
+        activity_type: ActivityType,
+        result: Result<ApiResponsePagination<Vec<T>>, HttpError>,
+        to_item: impl Fn(T) -> ActivityItem,
+    ) {
+        match result {
+            Ok(response) => {
+                self.total_items += response.pagination.total_items;
+                self.sources
+                    .push(response.data.into_iter().map(to_item).collect());
+            }
+            Err(e) => {
+                warn!("⚠️ Recent activity source {activity_type:?} failed: {e:?}");
+                self.failed.push(activity_type);
+            }
+        }
+    }
+}
+
+fn activity_response(
+    data: RecentActivityResponse,
+    page: i32,
+    page_size: i32,
+    total_items: i64,
+) -> ApiResponsePagination<RecentActivityResponse> {
+    let total_pages = ((total_items + page_size as i64 - 1) / page_size as i64) as i32;
+
+    let message = if data.failed_sources.is_empty() {
+        "Recent activity retrieved successfully".to_string()
+    } else {
+        "Recent activity retrieved with some sources unavailable".to_string()
+    };
+
+    ApiResponsePagination {
+        status: "success".to_string(),
+        message,
+        data,
+        pagination: Pagination {
+            page,
+            page_size,
+            total_items,
+            total_pages,
+        },
+        links: None,
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/me/activity",
+    tag = "User",
+    security(("bearer_auth" = [])),
+    params(FindRecentActivity),
+    responses(
+        (status = 200, description = "Recent topups, transfers, withdrawals and transactions, newest first", body = ApiResponsePagination<RecentActivityResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_my_activity(
+    State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
+    Query(params): Query<FindRecentActivity>,
+) -> Result<impl IntoResponse, HttpError> {
+    let clients = &app_state.di_container;
+
+    let page = params.page();
+    let page_size = params.page_size();
+    let window = params.window();
+
+    let cards = clients
+        .card_clients
+        .find_all_by_user_id(user_id)
+        .await?
+        .data;
+
+    if cards.is_empty() {
+        info!("User {user_id} has no card, returning empty activity feed");
+        return Ok((
+            StatusCode::OK,
+            Json(activity_response(
+                RecentActivityResponse::default(),
+                page,
+                page_size,
+                0,
+            )),
+        ));
+    }
+
+    let mut sources = ActivitySources::default();
+
+    for card in cards {
+        let card_number = card.card_number;
+
+        let topup_req = FindAllTopupsByCardNumber {
+            card_number: card_number.clone(),
+            page: 1,
+            page_size: window,
+            search: String::new(),
+        };
+        let transfer_req =
+            |transfer_from: Option<String>, transfer_to: Option<String>| FindTransfers {
+                page: 1,
+                page_size: window,
+                transfer_from,
+                transfer_to,
+                status: None,
+                start_date: None,
+                end_date: None,
+            };
+        let sent_req = transfer_req(Some(card_number.clone()), None);
+        let received_req = transfer_req(None, Some(card_number.clone()));
+        let withdraw_req = FindAllWithdrawCardNumber {
+            card_number: card_number.clone(),
+            search: String::new(),
+            page: 1,
+            page_size: window,
+        };
+        let transaction_req = FindAllTransactionCardNumber {
+            card_number: card_number.clone(),
+            page: 1,
+            page_size: window,
+            search: String::new(),
+        };
+
+        let (topups, sent, received, withdraws, transactions) = tokio::join!(
+            clients.topup_clients.find_all_by_card_number(&topup_req),
+            clients.transfer_clients.find_transfers(&sent_req),
+            clients.transfer_clients.find_transfers(&received_req),
+            clients
+                .withdraw_clients
+                .find_all_by_card_number(&withdraw_req),
+            clients
+                .transaction_clients
+                .find_all_by_card_number(&transaction_req),
+        );
+
+        sources.collect(ActivityType::Topup, topups, ActivityItem::from);
+        sources.collect(ActivityType::Transfer, sent, |t| {
+            ActivityItem::from_transfer(t, &card_number)
+        });
+        sources.collect(ActivityType::Transfer, received, |t| {
+            ActivityItem::from_transfer(t, &card_number)
+        });
+        sources.collect(ActivityType::Withdraw, withdraws, ActivityItem::from);
+        sources.collect(ActivityType::Transaction, transactions, ActivityItem::from);
+    }
+
+    if sources.sources.is_empty() {
+        return Err(HttpError::ServiceUnavailable(
+            "Recent activity is temporarily unavailable".to_string(),
+        ));
+    }
+
+    let data = RecentActivityResponse::merge(sources.sources, sources.failed, page, page_size);
+
+    Ok((
+        StatusCode::OK,
+        Json(activity_response(
+            data,
+            page,
+            page_size,
+            sources.total_items,
+        )),
+    ))
+}
+
+pub fn activity_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/me/activity", get(get_my_activity))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            session_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(state.clone(), jwt::auth))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            circuit_breaker_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            request_limiter_middleware,
+        ))
+        .with_state(state)
+}
@@ -11,12 +11,16 @@ use axum::{
     routing::{get, post},
 };
 use shared::{
+    config::Claims,
     domain::{
         requests::{
-            auth::{AuthRequest, RegisterRequest},
+            auth::{
+                AuthRequest, ChangePasswordRequest, RegisterRequest, ResendVerificationRequest,
+                VerifyEmailRequest,
+            },
             refresh_token::RefreshTokenRequest,
         },
-        responses::{ApiResponse, TokenResponse, UserResponse},
+        responses::{ApiResponse, TokenInfoResponse, TokenResponse, UserResponse},
     },
     errors::HttpError,
 };
@@ -58,7 +62,8 @@ pub async fn login_user_handler(
     request_body = RegisterRequest,
     responses(
         (status = 200, description = "Login successful", body = ApiResponse<UserResponse>),
-        (status = 401, description = "Unauthorized")
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Self-registration is disabled")
     ),
     tag = "Auth"
 )]
@@ -66,6 +71,12 @@ pub async fn register_user_handler(
     State(app_state): State<Arc<AppState>>,
     SimpleValidatedJson(body): SimpleValidatedJson<RegisterRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
+    if !app_state.features.self_registration {
+        return Err(HttpError::Forbidden(
+            "Self-registration is disabled".to_string(),
+        ));
+    }
+
     let auth_client = &app_state.di_container.auth_clients;
 
     let response = auth_client.register(&body).await?;
@@ -93,6 +104,37 @@ pub async fn get_me_handler(
     Ok((StatusCode::OK, Json(response)))
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/auth/token-info",
+    responses(
+        (status = 200, description = "Expiry and subject of the current token", body = ApiResponse<TokenInfoResponse>),
+        (status = 401, description = "Missing, invalid or expired token")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth",
+)]
+pub async fn token_info_handler(
+    Extension(claims): Extension<Claims>,
+) -> Result<impl IntoResponse, HttpError> {
+    let info = TokenInfoResponse::from_claims(&claims, chrono::Utc::now().timestamp());
+
+    if info.expires_in <= 0 {
+        return Err(HttpError::Unauthorized("Token expired".to_string()));
+    }
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            status: "success".to_string(),
+            message: "Token info retrieved successfully".to_string(),
+            data: info,
+        }),
+    ))
+}
+
 #[utoipa::path(
     post,
     path = "/api/auth/refresh-token",
@@ -105,7 +147,7 @@ pub async fn get_me_handler(
 )]
 pub async fn refresh_token_handler(
     State(app_state): State<Arc<AppState>>,
-    Json(req): Json<RefreshTokenRequest>,
+    SimpleValidatedJson(req): SimpleValidatedJson<RefreshTokenRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
     let auth_client = &app_state.di_container.auth_clients;
 
@@ -113,21 +155,142 @@ pub async fn refresh_token_handler(
     Ok((StatusCode::OK, Json(response)))
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/auth/verify-email/send",
+    responses(
+        (status = 200, description = "Verification email sent", body = ApiResponse<bool>),
+        (status = 400, description = "Email already verified")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth",
+)]
+pub async fn send_verification_handler(
+    State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    let auth_client = &app_state.di_container.auth_clients;
+
+    let response = auth_client.send_verification(user_id).await?;
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/verify-email/resend",
+    request_body = ResendVerificationRequest,
+    responses(
+        (status = 200, description = "Verification email sent if the address belongs to an unverified account", body = ApiResponse<bool>),
+        (status = 400, description = "Validation error"),
+        (status = 429, description = "Too many requests")
+    ),
+    tag = "Auth"
+)]
+pub async fn resend_verification_handler(
+    State(app_state): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<ResendVerificationRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    let auth_client = &app_state.di_container.auth_clients;
+
+    let response = auth_client.resend_verification(&body.email).await?;
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/verify-email",
+    request_body = VerifyEmailRequest,
+    responses(
+        (status = 200, description = "Email verified", body = ApiResponse<bool>),
+        (status = 400, description = "Invalid or already used token"),
+        (status = 401, description = "Token expired")
+    ),
+    tag = "Auth"
+)]
+pub async fn verify_email_handler(
+    State(app_state): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<VerifyEmailRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    let auth_client = &app_state.di_container.auth_clients;
+
+    let response = auth_client.verify_email(&body.token).await?;
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/auth/change-password",
+    request_body = ChangePasswordRequest,
+    responses(
+        (status = 200, description = "Password changed", body = ApiResponse<TokenResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Invalid current password")
+    ),
+    security(
+        ("bearer_auth" = [])
+    ),
+    tag = "Auth",
+)]
+pub async fn change_password_handler(
+    State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
+    SimpleValidatedJson(body): SimpleValidatedJson<ChangePasswordRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    let auth_client = &app_state.di_container.auth_clients;
+
+    let response = auth_client.change_password(user_id, &body).await?;
+    Ok((StatusCode::OK, Json(response)))
+}
+
 pub fn auth_routes(state: Arc<AppState>) -> OpenApiRouter {
     let public_routes = OpenApiRouter::new()
         .route("/api/auth/register", post(register_user_handler))
         .route("/api/auth/login", post(login_user_handler))
+        .route("/api/auth/verify-email", post(verify_email_handler))
         .route("/api/healthchecker", get(health_checker_handler))
         .with_state(state.clone());
 
+    let resend_routes = OpenApiRouter::new()
+        .route(
+            "/api/auth/verify-email/resend",
+            post(resend_verification_handler),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit_middleware,
+        ))
+        .with_state(state.clone());
+
     let private_routes = OpenApiRouter::new()
         .route("/api/auth/me", get(get_me_handler))
+        .route("/api/auth/token-info", get(token_info_handler))
         .route("/api/auth/refresh-token", post(refresh_token_handler))
+        .route(
+            "/api/auth/verify-email/send",
+            post(send_verification_handler),
+        )
         .route_layer(middleware::from_fn_with_state(state.clone(), jwt::auth))
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             rate_limit_middleware,
         ))
+        .with_state(state.clone());
+    let password_change_routes = OpenApiRouter::new()
+        .route("/api/auth/change-password", post(change_password_handler))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            jwt::password_change_auth,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit_middleware,
+        ))
         .with_state(state);
-    public_routes.merge(private_routes)
+
+    public_routes
+        .merge(resend_routes)
+        .merge(private_routes)
+        .merge(password_change_routes)
 }
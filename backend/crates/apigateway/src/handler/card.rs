@@ -1,15 +1,15 @@
 use crate::{
     middleware::{
-        circuit_breaker::circuit_breaker_middleware, jwt,
-        request_limiter::request_limiter_middleware, session::session_middleware,
-        validate::SimpleValidatedJson,
+        circuit_breaker::circuit_breaker_middleware, etag::etag_response, jwt,
+        rate_limit::rate_limit_middleware, request_limiter::request_limiter_middleware,
+        session::session_middleware, validate::SimpleValidatedJson,
     },
     state::AppState,
 };
 use axum::{
     Json,
     extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post},
@@ -18,19 +18,22 @@ use serde_json::json;
 use shared::{
     domain::{
         requests::{
-            card::{CreateCardRequest, FindAllCards, MonthYearCardNumberCard, UpdateCardRequest},
+            card::{
+                CreateCardRequest, FindAllCards, FindCardDashboards, MonthYearCardNumberCard,
+                RestoreCardsByFilter, UpdateCardRequest,
+            },
             withdraw::YearQuery,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, CardResponse, CardResponseDeleteAt,
-            CardResponseMonthAmount, CardResponseMonthBalance, CardResponseYearAmount,
-            CardResponseYearlyBalance, DashboardCard, DashboardCardCardNumber,
+            ApiResponse, ApiResponsePagination, BulkResult, CardFreezeResponse,
+            CardReissueResponse, CardResponse, CardResponseDeleteAt, CardResponseMonthAmount,
+            CardResponseMonthBalance, CardResponseYearAmount, CardResponseYearlyBalance,
+            CardStatusResponse, DashboardCard, DashboardCardCardNumber, DashboardCardNumbers,
         },
     },
     errors::HttpError,
 };
 use std::sync::Arc;
-use tracing::info;
 use utoipa_axum::router::OpenApiRouter;
 
 #[utoipa::path(
@@ -113,6 +116,7 @@ pub async fn get_trashed_cards(
     params(("id" = i32, Path, description = "Card ID")),
     responses(
         (status = 200, description = "Card details", body = ApiResponse<CardResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Card not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -120,11 +124,12 @@ pub async fn get_trashed_cards(
 pub async fn get_card(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
     match card_client.find_by_id(id).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Ok(response) => Ok(etag_response(&headers, response)),
         Err(err) => Err(err),
     }
 }
@@ -137,6 +142,7 @@ pub async fn get_card(
     params(("user_id" = i32, Path, description = "User ID")),
     responses(
         (status = 200, description = "Card by user", body = ApiResponse<CardResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Card not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -144,11 +150,12 @@ pub async fn get_card(
 pub async fn get_card_by_user(
     State(app_state): State<Arc<AppState>>,
     Path(user_id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
     match card_client.find_by_user_id(user_id).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Ok(response) => Ok(etag_response(&headers, response)),
         Err(err) => Err(err),
     }
 }
@@ -161,6 +168,7 @@ pub async fn get_card_by_user(
     params(("card_number" = String, Path, description = "Card Number")),
     responses(
         (status = 200, description = "Card by number", body = ApiResponse<CardResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Card not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -168,10 +176,35 @@ pub async fn get_card_by_user(
 pub async fn get_card_by_number(
     State(app_state): State<Arc<AppState>>,
     Path(card_number): Path<String>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
     match card_client.find_by_card_number(card_number).await {
+        Ok(response) => Ok(etag_response(&headers, response)),
+        Err(err) => Err(err),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/cards/check/{card_number}",
+    tag = "Card",
+    security(("bearer_auth" = [])),
+    params(("card_number" = String, Path, description = "Card Number")),
+    responses(
+        (status = 200, description = "Whether the card exists and is active", body = ApiResponse<CardStatusResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 429, description = "Too many requests")
+    )
+)]
+pub async fn check_card(
+    State(app_state): State<Arc<AppState>>,
+    Path(card_number): Path<String>,
+) -> Result<impl IntoResponse, HttpError> {
+    let card_client = &app_state.di_container.card_clients;
+
+    match card_client.check_card(card_number).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
     }
@@ -245,72 +278,147 @@ pub async fn update_card(
 pub async fn trash_card_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
+    match card_client.trash(id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/cards/reissue/{id}",
+    tag = "Card",
+    security(("bearer_auth" = [])),
+    params(("id" = i32, Path, description = "Card ID")),
+    responses(
+        (status = 200, description = "Card reissued with a new number; saldo moved to the new card", body = ApiResponse<CardReissueResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Card not found or already inactive"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn reissue_card_handler(
+    State(app_state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    let card_client = &app_state.di_container.card_clients;
+
+    match card_client.reissue(id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
 
+async fn ensure_card_holder_or_admin(
+    app_state: &AppState,
+    user_id: i32,
+    card_id: i32,
+) -> Result<(), HttpError> {
     let key = format!("session:{user_id}");
 
-    let current_session = session
+    let current_session = app_state
+        .session
         .get_session(&key)
         .await
         .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
 
-    if !current_session
+    if current_session
         .roles
         .iter()
         .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
     {
+        return Ok(());
+    }
+
+    let card = app_state
+        .di_container
+        .card_clients
+        .find_by_id(card_id)
+        .await?;
+
+    if card.data.user_id != user_id {
         return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
+            "Access denied. Only the cardholder, ADMIN or MODERATOR can change this card"
+                .to_string(),
         ));
     }
 
-    match card_client.trash(id).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
-        Err(err) => Err(err),
-    }
+    Ok(())
 }
 
 #[utoipa::path(
     post,
-    path = "/api/cards/restore/{id}",
+    path = "/api/cards/freeze/{id}",
     tag = "Card",
     security(("bearer_auth" = [])),
     params(("id" = i32, Path, description = "Card ID")),
     responses(
-        (status = 200, description = "Card restored", body = ApiResponse<CardResponse>),
+        (status = 200, description = "Card frozen; money movements on it are rejected", body = ApiResponse<CardFreezeResponse>),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Card not found or already inactive"),
         (status = 500, description = "Internal server error")
     )
 )]
-pub async fn restore_card_handler(
+pub async fn freeze_card_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
     Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
+    ensure_card_holder_or_admin(&app_state, user_id, id).await?;
 
-    let card_client = &app_state.di_container.card_clients;
+    let response = app_state.di_container.card_clients.freeze(id).await?;
 
-    let session = &app_state.session;
+    Ok((StatusCode::OK, Json(response)))
+}
 
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
+#[utoipa::path(
+    post,
+    path = "/api/cards/unfreeze/{id}",
+    tag = "Card",
+    security(("bearer_auth" = [])),
+    params(("id" = i32, Path, description = "Card ID")),
+    responses(
+        (status = 200, description = "Card unfrozen", body = ApiResponse<CardFreezeResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Card not found or already inactive"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn unfreeze_card_handler(
+    State(app_state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    ensure_card_holder_or_admin(&app_state, user_id, id).await?;
+
+    let response = app_state.di_container.card_clients.unfreeze(id).await?;
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/cards/restore/{id}",
+    tag = "Card",
+    security(("bearer_auth" = [])),
+    params(("id" = i32, Path, description = "Card ID")),
+    responses(
+        (status = 200, description = "Card restored", body = ApiResponse<CardResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn restore_card_handler(
+    State(app_state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    let card_client = &app_state.di_container.card_clients;
 
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
     match card_client.restore(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -332,29 +440,9 @@ pub async fn restore_card_handler(
 pub async fn delete_card(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
-
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.delete(id).await {
         Ok(_) => Ok((
             StatusCode::OK,
@@ -373,41 +461,22 @@ pub async fn delete_card(
     tag = "Card",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed cards restored", body = serde_json::Value),
+        (status = 200, description = "All trashed cards restored", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn restore_all_card_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
-
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.restore_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                 "status": "success",
-                "message": "All cards restored successfully"
+                "message": "All cards restored successfully",
+                "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -416,45 +485,50 @@ pub async fn restore_all_card_handler(
 
 #[utoipa::path(
     post,
-    path = "/api/cards/delete-all",
+    path = "/api/cards/restore-by-filter",
     tag = "Card",
     security(("bearer_auth" = [])),
+    request_body = RestoreCardsByFilter,
     responses(
-        (status = 200, description = "All trashed cards permanently deleted", body = serde_json::Value),
+        (status = 200, description = "Trashed cards matching the filter restored", body = ApiResponse<Vec<i32>>),
+        (status = 400, description = "Invalid or empty filter"),
         (status = 500, description = "Internal server error")
     )
 )]
-pub async fn delete_all_card_handler(
+pub async fn restore_cards_by_filter_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
+    SimpleValidatedJson(body): SimpleValidatedJson<RestoreCardsByFilter>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
-
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
+    match card_client.restore_by_filter(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
     }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/cards/delete-all",
+    tag = "Card",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "All trashed cards permanently deleted", body = ApiResponse<BulkResult>),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn delete_all_card_handler(
+    State(app_state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let card_client = &app_state.di_container.card_clients;
 
     match card_client.delete_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                 "status": "success",
-                "message": "All trashed cards deleted permanently"
+                "message": "All trashed cards deleted permanently",
+                "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -478,29 +552,9 @@ pub async fn delete_all_card_handler(
 pub async fn get_monthly_balance(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_monthly_balance(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -522,29 +576,9 @@ pub async fn get_monthly_balance(
 pub async fn get_yearly_balance(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_balance(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -568,29 +602,9 @@ pub async fn get_yearly_balance(
 pub async fn get_monthly_topup_amount(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
-
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_monthly_topup_amount(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -612,28 +626,9 @@ pub async fn get_monthly_topup_amount(
 pub async fn get_yearly_topup_amount(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_topup_amount(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -655,29 +650,9 @@ pub async fn get_yearly_topup_amount(
 pub async fn get_monthly_transaction_amount(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
-
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_monthly_transaction_amount(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -699,29 +674,9 @@ pub async fn get_monthly_transaction_amount(
 pub async fn get_yearly_transaction_amount(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
-
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_transaction_amount(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -745,29 +700,9 @@ pub async fn get_yearly_transaction_amount(
 pub async fn get_monthly_transfer_amount_sender(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
-
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_monthly_amount_sender(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -789,29 +724,9 @@ pub async fn get_monthly_transfer_amount_sender(
 pub async fn get_monthly_transfer_amount_receiver(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
-
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_monthly_amount_receiver(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -833,29 +748,9 @@ pub async fn get_monthly_transfer_amount_receiver(
 pub async fn get_yearly_transfer_amount_sender(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
-    let key = format!("session:{user_id}");
-
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_amount_sender(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -877,29 +772,9 @@ pub async fn get_yearly_transfer_amount_sender(
 pub async fn get_yearly_transfer_amount_receiver(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_amount_receiver(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -923,29 +798,9 @@ pub async fn get_yearly_transfer_amount_receiver(
 pub async fn get_monthly_withdraw_amount(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_monthly_withdraw_amount(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -967,29 +822,9 @@ pub async fn get_monthly_withdraw_amount(
 pub async fn get_yearly_withdraw_amount(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_withdraw_amount(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1011,31 +846,11 @@ pub async fn get_yearly_withdraw_amount(
 
 // Stats by card
 
-pub async fn get_monthly_balance_by_card(
-    State(app_state): State<Arc<AppState>>,
-    Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
-) -> Result<impl IntoResponse, HttpError> {
-    let card_client = &app_state.di_container.card_clients;
-
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
+pub async fn get_monthly_balance_by_card(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<MonthYearCardNumberCard>,
+) -> Result<impl IntoResponse, HttpError> {
+    let card_client = &app_state.di_container.card_clients;
 
     match card_client.get_monthly_balance_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
@@ -1058,29 +873,9 @@ pub async fn get_monthly_balance_by_card(
 pub async fn get_yearly_balance_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_balance_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1102,29 +897,9 @@ pub async fn get_yearly_balance_by_card(
 pub async fn get_monthly_topup_amount_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_monthly_topup_amount_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1146,29 +921,9 @@ pub async fn get_monthly_topup_amount_by_card(
 pub async fn get_yearly_topup_amount_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_topup_amount_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1190,29 +945,9 @@ pub async fn get_yearly_topup_amount_by_card(
 pub async fn get_monthly_transaction_amount_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client
         .get_monthly_transaction_amount_bycard(&params)
         .await
@@ -1237,29 +972,9 @@ pub async fn get_monthly_transaction_amount_by_card(
 pub async fn get_yearly_transaction_amount_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client
         .get_yearly_transaction_amount_bycard(&params)
         .await
@@ -1284,29 +999,9 @@ pub async fn get_yearly_transaction_amount_by_card(
 pub async fn get_monthly_transfer_amount_by_card_sender(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_monthly_amount_sender_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1328,29 +1023,9 @@ pub async fn get_monthly_transfer_amount_by_card_sender(
 pub async fn get_monthly_transfer_amount_by_card_receiver(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client
         .get_monthly_amount_receiver_bycard(&params)
         .await
@@ -1375,29 +1050,9 @@ pub async fn get_monthly_transfer_amount_by_card_receiver(
 pub async fn get_yearly_transfer_amount_by_card_sender(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_amount_sender_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1419,29 +1074,9 @@ pub async fn get_yearly_transfer_amount_by_card_sender(
 pub async fn get_yearly_transfer_amount_by_card_receiver(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_amount_receiver_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1463,29 +1098,9 @@ pub async fn get_yearly_transfer_amount_by_card_receiver(
 pub async fn get_monthly_withdraw_amount_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client
         .get_monthly_withdraw_amount_bycard(&params)
         .await
@@ -1510,29 +1125,9 @@ pub async fn get_monthly_withdraw_amount_by_card(
 pub async fn get_yearly_withdraw_amount_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumberCard>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_yearly_withdraw_amount_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1552,35 +1147,9 @@ pub async fn get_yearly_withdraw_amount_by_card(
 )]
 pub async fn get_card_dashboard(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    info!(
-        user_id = user_id,
-        roles = ?current_session.roles,
-        "Authenticated user roles"
-    );
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match card_client.get_dashboard().await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1602,35 +1171,64 @@ pub async fn get_card_dashboard(
 pub async fn get_card_dashboard_by_card_number(
     State(app_state): State<Arc<AppState>>,
     Path(card_number): Path<String>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let card_client = &app_state.di_container.card_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
+    match card_client.get_dashboard_bycard(card_number).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
     }
+}
 
-    match card_client.get_dashboard_bycard(card_number).await {
+#[utoipa::path(
+    post,
+    path = "/api/cards/dashboard/batch",
+    tag = "Card Dashboard",
+    security(("bearer_auth" = [])),
+    request_body = FindCardDashboards,
+    responses(
+        (status = 200, description = "Card dashboards keyed by card number", body = ApiResponse<DashboardCardNumbers>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_card_dashboards_by_card_numbers(
+    State(app_state): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<FindCardDashboards>,
+) -> Result<impl IntoResponse, HttpError> {
+    let card_client = &app_state.di_container.card_clients;
+
+    match card_client.get_dashboard_bycards(&body).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
     }
 }
 
+fn card_check_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/cards/check/{card_number}", get(check_card))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            session_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(state.clone(), jwt::auth))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            circuit_breaker_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            request_limiter_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            rate_limit_middleware,
+        ))
+        .with_state(state)
+}
+
 pub fn card_routes(state: Arc<AppState>) -> OpenApiRouter {
     OpenApiRouter::new()
         .route("/api/cards", get(get_cards))
@@ -1645,9 +1243,16 @@ pub fn card_routes(state: Arc<AppState>) -> OpenApiRouter {
             get(get_card_by_number),
         )
         .route("/api/cards/trash/{id}", post(trash_card_handler))
+        .route("/api/cards/reissue/{id}", post(reissue_card_handler))
+        .route("/api/cards/freeze/{id}", post(freeze_card_handler))
+        .route("/api/cards/unfreeze/{id}", post(unfreeze_card_handler))
         .route("/api/cards/restore/{id}", post(restore_card_handler))
         .route("/api/cards/delete/{id}", delete(delete_card))
         .route("/api/cards/restore-all", post(restore_all_card_handler))
+        .route(
+            "/api/cards/restore-by-filter",
+            post(restore_cards_by_filter_handler),
+        )
         .route("/api/cards/delete-all", post(delete_all_card_handler))
         .route("/api/cards/stats/balance/monthly", get(get_monthly_balance))
         .route("/api/cards/stats/balance/yearly", get(get_yearly_balance))
@@ -1741,6 +1346,10 @@ pub fn card_routes(state: Arc<AppState>) -> OpenApiRouter {
             get(get_yearly_withdraw_amount_by_card),
         )
         .route("/api/cards/dashboard", get(get_card_dashboard))
+        .route(
+            "/api/cards/dashboard/batch",
+            post(get_card_dashboards_by_card_numbers),
+        )
         .route(
             "/api/cards/dashboard/{card_number}",
             get(get_card_dashboard_by_card_number),
@@ -1758,5 +1367,6 @@ pub fn card_routes(state: Arc<AppState>) -> OpenApiRouter {
             state.clone(),
             request_limiter_middleware,
         ))
-        .with_state(state)
+        .with_state(state.clone())
+        .merge(card_check_routes(state))
 }
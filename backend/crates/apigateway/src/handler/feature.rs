@@ -0,0 +1,56 @@
+use crate::{
+    middleware::{
+        circuit_breaker::circuit_breaker_middleware, request_limiter::request_limiter_middleware,
+    },
+    state::AppState,
+};
+use axum::{
+    Json, extract::State, http::StatusCode, middleware, response::IntoResponse, routing::get,
+};
+use shared::{
+    domain::responses::{ApiResponse, FeatureFlagsResponse},
+    errors::HttpError,
+};
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/config/features",
+    tag = "Config",
+    responses(
+        (status = 200, description = "Features enabled in this deployment", body = ApiResponse<FeatureFlagsResponse>)
+    )
+)]
+pub async fn get_features(
+    State(app_state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let data = FeatureFlagsResponse::from_config(
+        &app_state.features,
+        &app_state.payment_methods,
+        &app_state.locale,
+    );
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            status: "success".to_string(),
+            message: "Features retrieved successfully".to_string(),
+            data,
+        }),
+    ))
+}
+
+pub fn feature_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/config/features", get(get_features))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            circuit_breaker_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            request_limiter_middleware,
+        ))
+        .with_state(state)
+}
@@ -0,0 +1,118 @@
+use crate::state::AppState;
+use axum::{Json, extract::State, http::StatusCode, response::IntoResponse, routing::get};
+use serde_json::{Value, json};
+use shared::utils::check_grpc_health;
+use std::{collections::HashMap, sync::Arc};
+use tokio::task::JoinSet;
+use tracing::warn;
+use utoipa_axum::router::OpenApiRouter;
+
+fn circuit_state(open: bool, rejecting: bool) -> &'static str {
+    match (open, rejecting) {
+        (false, _) => "closed",
+        (true, true) => "open",
+        (true, false) => "half_open",
+    }
+}
+
+pub async fn readiness_handler(State(app_state): State<Arc<AppState>>) -> impl IntoResponse {
+    let readiness = &app_state.readiness;
+    let mut dependencies: Vec<Value> = Vec::new();
+    let mut ready = true;
+
+    let mut report = |name: &str, available: bool, detail: Value| {
+        let critical = readiness.is_critical(name);
+        if critical && !available {
+            ready = false;
+        }
+
+        let mut entry = json!({
+            "name": name,
+            "status": if available { "up" } else { "down" },
+            "critical": critical,
+        });
+        if let (Some(entry), Value::Object(detail)) = (entry.as_object_mut(), detail) {
+            entry.extend(detail);
+        }
+        dependencies.push(entry);
+    };
+
+    let redis = tokio::time::timeout(readiness.check_timeout(), app_state.redis.check()).await;
+    match redis {
+        Ok(Ok(())) => report("redis", true, json!({})),
+        Ok(Err(e)) => report("redis", false, json!({ "error": e.to_string() })),
+        Err(_) => report("redis", false, json!({ "error": "timed out" })),
+    }
+
+    let gateway = &app_state.circuit_breaker;
+    let gateway_rejecting = gateway.is_rejecting().await;
+    report(
+        "gateway",
+        !gateway_rejecting,
+        json!({
+            "circuit": circuit_state(gateway.is_open(), gateway_rejecting),
+            "failures": gateway.get_failure_count(),
+        }),
+    );
+
+    let mut checks = JoinSet::new();
+    for (service, channel) in app_state.grpc_channels.iter() {
+        let service = *service;
+        let channel = channel.clone();
+        let timeout = readiness.check_timeout();
+        checks.spawn(async move {
+            let health = match tokio::time::timeout(timeout, check_grpc_health(channel)).await {
+                Ok(result) => result,
+                Err(_) => Err("timed out".to_string()),
+            };
+            (service, health)
+        });
+    }
+
+    let mut health: HashMap<&str, Result<(), String>> = HashMap::new();
+    while let Some(joined) = checks.join_next().await {
+        if let Ok((service, result)) = joined {
+            health.insert(service, result);
+        }
+    }
+
+    for (service, breaker) in app_state.service_breakers.iter() {
+        let rejecting = breaker.is_rejecting().await;
+        let mut detail = json!({
+            "circuit": circuit_state(breaker.is_open(), rejecting),
+            "failures": breaker.get_failure_count(),
+        });
+
+        let available = match health.get(service) {
+            Some(Ok(())) => true,
+            Some(Err(reason)) => {
+                detail["error"] = json!(reason);
+                false
+            }
+            None => !rejecting,
+        };
+
+        report(service, available, detail);
+    }
+
+    let status = if ready {
+        StatusCode::OK
+    } else {
+        warn!("⚠️  Readiness check failed: a critical dependency is unavailable");
+        StatusCode::SERVICE_UNAVAILABLE
+    };
+
+    (
+        status,
+        Json(json!({
+            "status": if ready { "ready" } else { "not_ready" },
+            "dependencies": dependencies,
+        })),
+    )
+}
+
+pub fn health_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/readyz", get(readiness_handler))
+        .with_state(state)
+}
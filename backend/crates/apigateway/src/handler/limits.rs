@@ -0,0 +1,111 @@
+use crate::{
+    middleware::{
+        circuit_breaker::circuit_breaker_middleware,
+        jwt,
+        rate_limit::{RATE_LIMIT_MAX_REQUESTS, RATE_LIMIT_WINDOW_SECS, RATE_LIMITED_ROUTES},
+        request_limiter::request_limiter_middleware,
+        session::session_middleware,
+    },
+    state::AppState,
+};
+use axum::{
+    Json,
+    extract::{Extension, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+use shared::{
+    domain::responses::{ApiResponse, EffectiveLimitsResponse, RateLimitInfoResponse},
+    errors::HttpError,
+};
+use std::sync::Arc;
+use tracing::info;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/me/limits",
+    tag = "User",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Effective rate limit and amount caps for the caller", body = ApiResponse<EffectiveLimitsResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_my_limits(
+    State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    let card_numbers: Vec<String> = app_state
+        .di_container
+        .card_clients
+        .find_all_by_user_id(user_id)
+        .await?
+        .data
+        .into_iter()
+        .map(|card| card.card_number)
+        .collect();
+
+    if card_numbers.is_empty() {
+        info!("User {user_id} has no card, returning default limits");
+    }
+
+    let merchant_id = app_state
+        .di_container
+        .merchant_clients
+        .find_merchant_user_id(user_id)
+        .await?
+        .data
+        .into_iter()
+        .map(|merchant| merchant.id)
+        .min();
+
+    let rate_limit = RateLimitInfoResponse {
+        scope: "ip".to_string(),
+        max_requests: RATE_LIMIT_MAX_REQUESTS,
+        window_secs: RATE_LIMIT_WINDOW_SECS,
+        routes: RATE_LIMITED_ROUTES
+            .iter()
+            .map(|route| route.to_string())
+            .collect(),
+    };
+
+    let data = EffectiveLimitsResponse::resolve(
+        &card_numbers,
+        rate_limit,
+        &app_state.payment_methods,
+        &app_state.transfer_limits,
+        merchant_id,
+    );
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            status: "success".to_string(),
+            message: "Limits retrieved successfully".to_string(),
+            data,
+        }),
+    ))
+}
+
+pub fn limits_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/me/limits", get(get_my_limits))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            session_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(state.clone(), jwt::auth))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            circuit_breaker_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            request_limiter_middleware,
+        ))
+        .with_state(state)
+}
@@ -1,15 +1,15 @@
 use crate::{
     middleware::{
-        circuit_breaker::circuit_breaker_middleware, jwt, rate_limit::rate_limit_middleware,
-        request_limiter::request_limiter_middleware, session::session_middleware,
-        validate::SimpleValidatedJson,
+        circuit_breaker::circuit_breaker_middleware, etag::etag_response, jwt,
+        rate_limit::rate_limit_middleware, request_limiter::request_limiter_middleware,
+        session::session_middleware, validate::SimpleValidatedJson,
     },
     state::AppState,
 };
 use axum::{
     Json,
     extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post},
@@ -21,18 +21,21 @@ use shared::{
             merchant::{
                 CreateMerchantRequest, FindAllMerchantTransactions,
                 FindAllMerchantTransactionsByApiKey, FindAllMerchantTransactionsById,
-                FindAllMerchants, MonthYearAmountApiKey, MonthYearAmountMerchant,
-                MonthYearPaymentMethodApiKey, MonthYearPaymentMethodMerchant,
-                MonthYearTotalAmountApiKey, MonthYearTotalAmountMerchant, UpdateMerchantRequest,
+                FindAllMerchants, FindMerchantTransactionsDaily, MerchantUserRequest,
+                MonthYearAmountApiKey, MonthYearAmountMerchant, MonthYearPaymentMethodApiKey,
+                MonthYearPaymentMethodMerchant, MonthYearTotalAmountApiKey,
+                MonthYearTotalAmountMerchant, SetMerchantWebhookRequest, UpdateMerchantRequest,
             },
             withdraw::YearQuery,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, MerchantResponse, MerchantResponseDeleteAt,
-            MerchantResponseMonthlyAmount, MerchantResponseMonthlyPaymentMethod,
-            MerchantResponseMonthlyTotalAmount, MerchantResponseYearlyAmount,
-            MerchantResponseYearlyPaymentMethod, MerchantResponseYearlyTotalAmount,
-            MerchantTransactionResponse,
+            ApiResponse, ApiResponsePagination, BulkResult, MerchantApiKeyUsageResponse,
+            MerchantResponse, MerchantResponseDeleteAt, MerchantResponseMonthlyAmount,
+            MerchantResponseMonthlyPaymentMethod, MerchantResponseMonthlyTotalAmount,
+            MerchantResponseYearlyAmount, MerchantResponseYearlyPaymentMethod,
+            MerchantResponseYearlyTotalAmount, MerchantSettlementResponse,
+            MerchantTransactionDailyResponse, MerchantTransactionResponse, MerchantWebhookResponse,
+            Pagination,
         },
     },
     errors::HttpError,
@@ -40,6 +43,87 @@ use shared::{
 use std::sync::Arc;
 use utoipa_axum::router::OpenApiRouter;
 
+async fn has_full_merchant_access(app_state: &AppState, user_id: i32) -> Result<bool, HttpError> {
+    let key = format!("session:{user_id}");
+
+    let current_session = app_state
+        .session
+        .get_session(&key)
+        .await
+        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
+
+    Ok(current_session
+        .roles
+        .iter()
+        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR"))
+}
+
+async fn ensure_merchant_access(
+    app_state: &AppState,
+    user_id: i32,
+    merchant_id: i32,
+) -> Result<(), HttpError> {
+    if has_full_merchant_access(app_state, user_id).await? {
+        return Ok(());
+    }
+
+    let accessible = app_state
+        .di_container
+        .merchant_clients
+        .list_accessible_merchants(user_id)
+        .await?;
+
+    if accessible.data.iter().any(|m| m.id == merchant_id) {
+        Ok(())
+    } else {
+        Err(HttpError::NotFound("Merchant not found".to_string()))
+    }
+}
+
+async fn find_accessible_page(
+    app_state: &AppState,
+    user_id: i32,
+    params: &FindAllMerchants,
+) -> Result<(Vec<MerchantResponse>, Pagination), HttpError> {
+    let accessible = app_state
+        .di_container
+        .merchant_clients
+        .list_accessible_merchants(user_id)
+        .await?;
+
+    let page = if params.page > 0 { params.page } else { 1 };
+    let page_size = params.page_size.clamp(1, 100);
+    let search = params.search.trim().to_lowercase();
+
+    let matching: Vec<MerchantResponse> = accessible
+        .data
+        .into_iter()
+        .filter(|m| {
+            search.is_empty()
+                || m.name.to_lowercase().contains(&search)
+                || m.api_key.to_lowercase().contains(&search)
+                || m.status.to_lowercase().contains(&search)
+        })
+        .collect();
+
+    let total_items = matching.len() as i64;
+    let data = matching
+        .into_iter()
+        .skip(((page - 1) * page_size) as usize)
+        .take(page_size as usize)
+        .collect();
+
+    Ok((
+        data,
+        Pagination {
+            page,
+            page_size,
+            total_items,
+            total_pages: ((total_items + page_size as i64 - 1) / page_size as i64) as i32,
+        },
+    ))
+}
+
 #[utoipa::path(
     get,
     path = "/api/merchants",
@@ -54,10 +138,26 @@ use utoipa_axum::router::OpenApiRouter;
 )]
 pub async fn get_merchants(
     State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
     Query(params): Query<FindAllMerchants>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
+    if !has_full_merchant_access(&app_state, user_id).await? {
+        let (data, pagination) = find_accessible_page(&app_state, user_id, &params).await?;
+
+        return Ok((
+            StatusCode::OK,
+            Json(ApiResponsePagination {
+                status: "success".to_string(),
+                message: "Merchants retrieved successfully".to_string(),
+                data,
+                pagination,
+                links: None,
+            }),
+        ));
+    }
+
     match merchant_client.find_all(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -78,10 +178,38 @@ pub async fn get_merchants(
 )]
 pub async fn get_active_merchants(
     State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
     Query(params): Query<FindAllMerchants>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
+    if !has_full_merchant_access(&app_state, user_id).await? {
+        let (data, pagination) = find_accessible_page(&app_state, user_id, &params).await?;
+
+        return Ok((
+            StatusCode::OK,
+            Json(ApiResponsePagination {
+                status: "success".to_string(),
+                message: "Active merchants retrieved successfully".to_string(),
+                data: data
+                    .into_iter()
+                    .map(|m| MerchantResponseDeleteAt {
+                        id: m.id,
+                        name: m.name,
+                        user_id: m.user_id,
+                        api_key: m.api_key,
+                        status: m.status,
+                        created_at: m.created_at,
+                        updated_at: m.updated_at,
+                        deleted_at: None,
+                    })
+                    .collect(),
+                pagination,
+                links: None,
+            }),
+        ));
+    }
+
     match merchant_client.find_active(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -97,15 +225,23 @@ pub async fn get_active_merchants(
     responses(
         (status = 200, description = "List of soft-deleted merchants", body = ApiResponsePagination<Vec<MerchantResponse>>),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn get_trashed_merchants(
     State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
     Query(params): Query<FindAllMerchants>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
+    if !has_full_merchant_access(&app_state, user_id).await? {
+        return Err(HttpError::Forbidden(
+            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
+        ));
+    }
+
     match merchant_client.find_trashed(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -120,18 +256,23 @@ pub async fn get_trashed_merchants(
     params(("id" = i32, Path, description = "Merchant ID")),
     responses(
         (status = 200, description = "Merchant details", body = ApiResponse<MerchantResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Merchant not found"),
         (status = 401, description = "Unauthorized")
     )
 )]
 pub async fn get_merchant(
     State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
     Path(id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
+    ensure_merchant_access(&app_state, user_id, id).await?;
+
     match merchant_client.find_by_id(id).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Ok(response) => Ok(etag_response(&headers, response)),
         Err(err) => Err(err),
     }
 }
@@ -144,20 +285,24 @@ pub async fn get_merchant(
     params(("api_key" = String, Path, description = "API Key")),
     responses(
         (status = 200, description = "Merchant details", body = ApiResponse<MerchantResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Merchant not found"),
         (status = 401, description = "Unauthorized")
     )
 )]
 pub async fn get_merchant_by_apikey(
     State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
     Path(api_key): Path<String>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    match merchant_client.find_by_apikey(&api_key).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
-        Err(err) => Err(err),
-    }
+    let response = merchant_client.find_by_apikey(&api_key).await?;
+
+    ensure_merchant_access(&app_state, user_id, response.data.id).await?;
+
+    Ok(etag_response(&headers, response))
 }
 
 #[utoipa::path(
@@ -169,21 +314,52 @@ pub async fn get_merchant_by_apikey(
     responses(
         (status = 200, description = "Merchant list for this user", body = ApiResponse<Vec<MerchantResponse>>),
         (status = 404, description = "No merchant found for this user"),
-        (status = 401, description = "Unauthorized")
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden")
     )
 )]
 pub async fn get_merchants_by_user_id(
     State(app_state): State<Arc<AppState>>,
+    Extension(current_user_id): Extension<i32>,
     Path(user_id): Path<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
+    if user_id != current_user_id && !has_full_merchant_access(&app_state, current_user_id).await? {
+        return Err(HttpError::Forbidden(
+            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
+        ));
+    }
+
     match merchant_client.find_merchant_user_id(user_id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/merchants/accessible",
+    tag = "Merchant",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "Merchants the current user owns or is linked to", body = ApiResponse<Vec<MerchantResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_accessible_merchants(
+    State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    let merchant_client = &app_state.di_container.merchant_clients;
+
+    match merchant_client.list_accessible_merchants(user_id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/api/merchants/create",
@@ -191,9 +367,10 @@ pub async fn get_merchants_by_user_id(
     security(("bearer_auth" = [])),
     request_body = CreateMerchantRequest,
     responses(
-        (status = 201, description = "Merchant created", body = ApiResponse<MerchantResponse>),
+        (status = 201, description = "Merchant created, or the existing merchant for the same business_id", body = ApiResponse<MerchantResponse>),
         (status = 400, description = "Validation error"),
         (status = 401, description = "Unauthorized"),
+        (status = 409, description = "business_id belongs to another user's merchant"),
         (status = 500, description = "Internal server error")
     )
 )]
@@ -225,11 +402,14 @@ pub async fn create_merchant(
 )]
 pub async fn update_merchant(
     State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
     Path(id): Path<i32>,
     SimpleValidatedJson(mut body): SimpleValidatedJson<UpdateMerchantRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
+    ensure_merchant_access(&app_state, user_id, id).await?;
+
     body.merchant_id = Some(id);
     match merchant_client.update(&body).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
@@ -239,41 +419,53 @@ pub async fn update_merchant(
 
 #[utoipa::path(
     post,
-    path = "/api/merchants/trash/{id}",
+    path = "/api/merchants/webhook/{id}",
     tag = "Merchant",
     security(("bearer_auth" = [])),
     params(("id" = i32, Path, description = "Merchant ID")),
+    request_body = SetMerchantWebhookRequest,
     responses(
-        (status = 200, description = "Merchant soft-deleted", body = ApiResponse<MerchantResponseDeleteAt>),
+        (status = 200, description = "Merchant webhook URL created or replaced", body = ApiResponse<MerchantWebhookResponse>),
+        (status = 400, description = "Invalid URL, or a target the webhook policy does not allow"),
         (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Merchant not found"),
         (status = 500, description = "Internal server error")
     )
 )]
-pub async fn trash_merchant_handler(
+pub async fn set_merchant_webhook(
     State(app_state): State<Arc<AppState>>,
     Extension(user_id): Extension<i32>,
     Path(id): Path<i32>,
+    SimpleValidatedJson(mut body): SimpleValidatedJson<SetMerchantWebhookRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
+    ensure_merchant_access(&app_state, user_id, id).await?;
 
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
+    body.merchant_id = Some(id);
+    match merchant_client.set_webhook(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
     }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/merchants/trash/{id}",
+    tag = "Merchant",
+    security(("bearer_auth" = [])),
+    params(("id" = i32, Path, description = "Merchant ID")),
+    responses(
+        (status = 200, description = "Merchant soft-deleted", body = ApiResponse<MerchantResponseDeleteAt>),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn trash_merchant_handler(
+    State(app_state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    let merchant_client = &app_state.di_container.merchant_clients;
 
     match merchant_client.trash(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
@@ -296,29 +488,9 @@ pub async fn trash_merchant_handler(
 pub async fn restore_merchant_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.restore(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -340,29 +512,9 @@ pub async fn restore_merchant_handler(
 pub async fn delete_merchant(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.delete(id).await {
         Ok(_) => Ok((
             StatusCode::OK,
@@ -381,41 +533,22 @@ pub async fn delete_merchant(
     tag = "Merchant",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed merchants restored", body = serde_json::Value),
+        (status = 200, description = "All trashed merchants restored", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn restore_all_merchant_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.restore_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All merchants restored successfully"
+               "message": "All merchants restored successfully",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -428,47 +561,79 @@ pub async fn restore_all_merchant_handler(
     tag = "Merchant",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed merchants permanently deleted", body = serde_json::Value),
+        (status = 200, description = "All trashed merchants permanently deleted", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn delete_all_merchant_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.delete_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All trashed merchants deleted permanently"
+               "message": "All trashed merchants deleted permanently",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/merchants/users/grant",
+    tag = "Merchant",
+    security(("bearer_auth" = [])),
+    request_body = MerchantUserRequest,
+    responses(
+        (status = 200, description = "User granted access to the merchant", body = ApiResponse<bool>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Merchant or user not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn grant_merchant_user_handler(
+    State(app_state): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<MerchantUserRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    let merchant_client = &app_state.di_container.merchant_clients;
+
+    match merchant_client.grant_user(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/merchants/users/revoke",
+    tag = "Merchant",
+    security(("bearer_auth" = [])),
+    request_body = MerchantUserRequest,
+    responses(
+        (status = 200, description = "User access to the merchant revoked; data is false if no grant existed", body = ApiResponse<bool>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn revoke_merchant_user_handler(
+    State(app_state): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<MerchantUserRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    let merchant_client = &app_state.di_container.merchant_clients;
+
+    match merchant_client.revoke_user(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/merchants/stats/amount/monthly",
@@ -484,29 +649,9 @@ pub async fn delete_all_merchant_handler(
 pub async fn get_monthly_amount(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_monthly_amount(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -528,29 +673,9 @@ pub async fn get_monthly_amount(
 pub async fn get_yearly_amount(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_yearly_amount(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -572,29 +697,9 @@ pub async fn get_yearly_amount(
 pub async fn get_monthly_method(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_monthly_method(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -616,29 +721,9 @@ pub async fn get_monthly_method(
 pub async fn get_yearly_method(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_yearly_method(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -660,29 +745,9 @@ pub async fn get_yearly_method(
 pub async fn get_monthly_total_amount(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_monthly_total_amount(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -704,29 +769,9 @@ pub async fn get_monthly_total_amount(
 pub async fn get_yearly_total_amount(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_yearly_total_amount(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -748,29 +793,9 @@ pub async fn get_yearly_total_amount(
 pub async fn get_monthly_amount_by_merchant(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearAmountMerchant>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_monthly_amount_bymerchant(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -792,29 +817,9 @@ pub async fn get_monthly_amount_by_merchant(
 pub async fn get_yearly_amount_by_merchant(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearAmountMerchant>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_yearly_amount_bymerchant(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -833,31 +838,11 @@ pub async fn get_yearly_amount_by_merchant(
         (status = 500, description = "Internal server error")
     )
 )]
-pub async fn get_monthly_method_by_merchant(
-    State(app_state): State<Arc<AppState>>,
-    Query(params): Query<MonthYearPaymentMethodMerchant>,
-    Extension(user_id): Extension<i32>,
-) -> Result<impl IntoResponse, HttpError> {
-    let merchant_client = &app_state.di_container.merchant_clients;
-
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
+pub async fn get_monthly_method_by_merchant(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<MonthYearPaymentMethodMerchant>,
+) -> Result<impl IntoResponse, HttpError> {
+    let merchant_client = &app_state.di_container.merchant_clients;
 
     match merchant_client.get_monthly_method_bymerchant(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
@@ -880,29 +865,9 @@ pub async fn get_monthly_method_by_merchant(
 pub async fn get_yearly_method_by_merchant(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearPaymentMethodMerchant>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_yearly_method_bymerchant(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -924,29 +889,9 @@ pub async fn get_yearly_method_by_merchant(
 pub async fn get_monthly_total_amount_by_merchant(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearTotalAmountMerchant>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client
         .get_monthly_total_amount_bymerchant(&params)
         .await
@@ -971,29 +916,9 @@ pub async fn get_monthly_total_amount_by_merchant(
 pub async fn get_yearly_total_amount_by_merchant(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearTotalAmountMerchant>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client
         .get_yearly_total_amount_bymerchant(&params)
         .await
@@ -1018,29 +943,9 @@ pub async fn get_yearly_total_amount_by_merchant(
 pub async fn get_monthly_amount_by_apikey(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearAmountApiKey>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_monthly_amount_byapikey(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1062,29 +967,9 @@ pub async fn get_monthly_amount_by_apikey(
 pub async fn get_yearly_amount_by_apikey(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearAmountApiKey>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_yearly_amount_byapikey(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1106,29 +991,9 @@ pub async fn get_yearly_amount_by_apikey(
 pub async fn get_monthly_method_by_apikey(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearPaymentMethodApiKey>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_monthly_method_byapikey(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1150,29 +1015,9 @@ pub async fn get_monthly_method_by_apikey(
 pub async fn get_yearly_method_by_apikey(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearPaymentMethodApiKey>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.get_yearly_method_byapikey(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1194,29 +1039,9 @@ pub async fn get_yearly_method_by_apikey(
 pub async fn get_monthly_total_amount_by_apikey(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearTotalAmountApiKey>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client
         .get_monthly_total_amount_byapikey(&params)
         .await
@@ -1241,29 +1066,9 @@ pub async fn get_monthly_total_amount_by_apikey(
 pub async fn get_yearly_total_amount_by_apikey(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearTotalAmountApiKey>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client
         .get_yearly_total_amount_byapikey(&params)
         .await
@@ -1288,29 +1093,9 @@ pub async fn get_yearly_total_amount_by_apikey(
 pub async fn get_merchant_transactions(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<FindAllMerchantTransactions>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client.find_all_transactiions(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1332,30 +1117,89 @@ pub async fn get_merchant_transactions(
 pub async fn get_merchant_transactions_by_id(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<FindAllMerchantTransactionsById>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
+    match merchant_client.find_all_transactiions_by_id(&params).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
 
-    let key = format!("session:{user_id}");
+#[utoipa::path(
+    get,
+    path = "/api/merchants/transactions/daily",
+    tag = "Merchant Transactions",
+    security(("bearer_auth" = [])),
+    params(FindMerchantTransactionsDaily),
+    responses(
+        (status = 200, description = "Daily transaction buckets for a merchant", body = ApiResponse<Vec<MerchantTransactionDailyResponse>>),
+        (status = 400, description = "Invalid date range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_merchant_transactions_daily(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<FindMerchantTransactionsDaily>,
+) -> Result<impl IntoResponse, HttpError> {
+    let merchant_client = &app_state.di_container.merchant_clients;
 
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
+    match merchant_client.find_daily_transactions_by_id(&params).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
 
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
+#[utoipa::path(
+    get,
+    path = "/api/merchants/transactions/api-key-usage",
+    tag = "Merchant Transactions",
+    security(("bearer_auth" = [])),
+    params(FindMerchantTransactionsDaily),
+    responses(
+        (status = 200, description = "Per API key usage of a merchant bucketed by day", body = ApiResponse<Vec<MerchantApiKeyUsageResponse>>),
+        (status = 400, description = "Invalid date range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_merchant_api_key_usage(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<FindMerchantTransactionsDaily>,
+) -> Result<impl IntoResponse, HttpError> {
+    let merchant_client = &app_state.di_container.merchant_clients;
+
+    match merchant_client.find_api_key_usage_by_id(&params).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
     }
+}
 
-    match merchant_client.find_all_transactiions_by_id(&params).await {
+#[utoipa::path(
+    get,
+    path = "/api/merchants/transactions/settlement",
+    tag = "Merchant Transactions",
+    security(("bearer_auth" = [])),
+    params(FindMerchantTransactionsDaily),
+    responses(
+        (status = 200, description = "Settlement amount of a merchant for a period. `refunds` is a placeholder and always 0 until refunds are recorded. Ranges that end today or later are computed on every request instead of cached.", body = ApiResponse<MerchantSettlementResponse>),
+        (status = 400, description = "Invalid date range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Merchant not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_merchant_settlement(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<FindMerchantTransactionsDaily>,
+    Extension(user_id): Extension<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    let merchant_client = &app_state.di_container.merchant_clients;
+
+    ensure_merchant_access(&app_state, user_id, params.merchant_id).await?;
+
+    match merchant_client.settlement(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
     }
@@ -1376,29 +1220,9 @@ pub async fn get_merchant_transactions_by_id(
 pub async fn get_merchant_transactions_by_apikey(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<FindAllMerchantTransactionsByApiKey>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let merchant_client = &app_state.di_container.merchant_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match merchant_client
         .find_all_transactiions_by_api_key(&params)
         .await
@@ -1424,6 +1248,8 @@ pub fn merchant_routes(state: Arc<AppState>) -> OpenApiRouter {
             "/api/merchants/by-user/{user_id}",
             get(get_merchants_by_user_id),
         )
+        .route("/api/merchants/accessible", get(get_accessible_merchants))
+        .route("/api/merchants/webhook/{id}", post(set_merchant_webhook))
         .route("/api/merchants/trash/{id}", post(trash_merchant_handler))
         .route(
             "/api/merchants/restore/{id}",
@@ -1438,6 +1264,14 @@ pub fn merchant_routes(state: Arc<AppState>) -> OpenApiRouter {
             "/api/merchants/delete-all",
             post(delete_all_merchant_handler),
         )
+        .route(
+            "/api/merchants/users/grant",
+            post(grant_merchant_user_handler),
+        )
+        .route(
+            "/api/merchants/users/revoke",
+            post(revoke_merchant_user_handler),
+        )
         .route(
             "/api/merchants/stats/amount/monthly",
             get(get_monthly_amount),
@@ -1516,6 +1350,18 @@ pub fn merchant_routes(state: Arc<AppState>) -> OpenApiRouter {
             "/api/merchants/transactions/by-apikey",
             get(get_merchant_transactions_by_apikey),
         )
+        .route(
+            "/api/merchants/transactions/daily",
+            get(get_merchant_transactions_daily),
+        )
+        .route(
+            "/api/merchants/transactions/api-key-usage",
+            get(get_merchant_api_key_usage),
+        )
+        .route(
+            "/api/merchants/transactions/settlement",
+            get(get_merchant_settlement),
+        )
         .route_layer(middleware::from_fn_with_state(
             state.clone(),
             session_middleware,
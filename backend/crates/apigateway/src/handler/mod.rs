@@ -1,22 +1,39 @@
+mod activity;
 mod auth;
 mod card;
+mod feature;
+mod health;
+mod limits;
 mod merchant;
+mod payment_method;
+mod rate_limit;
 mod role;
 mod saldo;
+mod stats;
 mod topup;
 mod transaction;
 mod transfer;
 mod user;
 mod withdraw;
 
-use crate::state::AppState;
+use crate::{
+    middleware::{
+        locale::locale_middleware, pagination::pagination_links_middleware,
+        request_deadline::request_deadline_middleware, request_id::request_id_middleware,
+        route_auth::route_auth_middleware, security_headers::security_headers_middleware,
+        service_account::reject_service_account_middleware,
+        service_limiter::service_limiter_middleware,
+    },
+    state::AppState,
+};
 use anyhow::{Context, Result};
 use axum::{
     extract::DefaultBodyLimit,
     http::{HeaderName, Method, header},
+    middleware,
 };
 use shared::utils::shutdown_signal;
-use std::{sync::Arc, time::Duration};
+use std::sync::Arc;
 use tokio::net::TcpListener;
 use tower::{ServiceBuilder, limit::ConcurrencyLimitLayer};
 use tower_http::{
@@ -25,15 +42,25 @@ use tower_http::{
     timeout::TimeoutLayer,
 };
 use tracing::info;
-use utoipa::{Modify, OpenApi, openapi::security::SecurityScheme};
+use utoipa::{
+    Modify, OpenApi,
+    openapi::{security::SecurityScheme, server::Server},
+};
 use utoipa_axum::router::OpenApiRouter;
 use utoipa_swagger_ui::SwaggerUi;
 
+pub use self::activity::activity_routes;
 pub use self::auth::auth_routes;
 pub use self::card::card_routes;
+pub use self::feature::feature_routes;
+pub use self::health::health_routes;
+pub use self::limits::limits_routes;
 pub use self::merchant::merchant_routes;
+pub use self::payment_method::payment_method_routes;
+pub use self::rate_limit::rate_limit_routes;
 pub use self::role::role_routes;
 pub use self::saldo::saldo_routes;
+pub use self::stats::stats_routes;
 pub use self::topup::topup_routes;
 pub use self::transaction::transaction_routes;
 pub use self::transfer::transfer_routes;
@@ -46,7 +73,12 @@ pub use self::withdraw::withdraw_routes;
         auth::register_user_handler,
         auth::login_user_handler,
         auth::get_me_handler,
+        auth::token_info_handler,
         auth::refresh_token_handler,
+        auth::send_verification_handler,
+        auth::resend_verification_handler,
+        auth::verify_email_handler,
+        auth::change_password_handler,
 
         card::get_cards,
         card::create_card,
@@ -55,11 +87,16 @@ pub use self::withdraw::withdraw_routes;
         card::get_card,
         card::get_card_by_user,
         card::get_card_by_number,
+        card::check_card,
         card::update_card,
         card::trash_card_handler,
+        card::reissue_card_handler,
+        card::freeze_card_handler,
+        card::unfreeze_card_handler,
         card::restore_card_handler,
         card::delete_card,
         card::restore_all_card_handler,
+        card::restore_cards_by_filter_handler,
         card::delete_all_card_handler,
         card::get_monthly_balance,
         card::get_yearly_balance,
@@ -87,6 +124,7 @@ pub use self::withdraw::withdraw_routes;
         card::get_yearly_withdraw_amount_by_card,
         card::get_card_dashboard,
         card::get_card_dashboard_by_card_number,
+        card::get_card_dashboards_by_card_numbers,
 
         merchant::get_merchants,
         merchant::create_merchant,
@@ -94,13 +132,17 @@ pub use self::withdraw::withdraw_routes;
         merchant::get_trashed_merchants,
         merchant::get_merchant,
         merchant::update_merchant,
+        merchant::set_merchant_webhook,
         merchant::get_merchant_by_apikey,
         merchant::get_merchants_by_user_id,
+        merchant::get_accessible_merchants,
         merchant::trash_merchant_handler,
         merchant::restore_merchant_handler,
         merchant::delete_merchant,
         merchant::restore_all_merchant_handler,
         merchant::delete_all_merchant_handler,
+        merchant::grant_merchant_user_handler,
+        merchant::revoke_merchant_user_handler,
         merchant::get_monthly_amount,
         merchant::get_yearly_amount,
         merchant::get_monthly_method,
@@ -122,6 +164,9 @@ pub use self::withdraw::withdraw_routes;
         merchant::get_merchant_transactions,
         merchant::get_merchant_transactions_by_id,
         merchant::get_merchant_transactions_by_apikey,
+        merchant::get_merchant_transactions_daily,
+        merchant::get_merchant_api_key_usage,
+        merchant::get_merchant_settlement,
 
         role::get_roles,
         role::get_active_roles,
@@ -140,6 +185,8 @@ pub use self::withdraw::withdraw_routes;
         saldo::get_active_saldos,
         saldo::get_trashed_saldos,
         saldo::get_saldo,
+        saldo::get_saldo_balances,
+        saldo::get_card_statement,
         saldo::create_saldo,
         saldo::update_saldo,
         saldo::trash_saldo_handler,
@@ -184,6 +231,7 @@ pub use self::withdraw::withdraw_routes;
 
         transaction::get_transactions,
         transaction::get_transactions_by_card_number,
+        transaction::search_transactions,
         transaction::get_active_transactions,
         transaction::get_trashed_transactions,
         transaction::get_transaction,
@@ -217,9 +265,12 @@ pub use self::withdraw::withdraw_routes;
         transfer::get_transfer,
         transfer::get_active_transfers,
         transfer::get_trashed_transfers,
+        transfer::find_transfers,
+        transfer::get_transfer_net_position,
         transfer::get_transfers_by_transfer_from,
         transfer::get_transfers_by_transfer_to,
         transfer::create_transfer,
+        transfer::preview_transfer,
         transfer::update_transfer,
         transfer::trash_transfer_handler,
         transfer::restore_transfer_handler,
@@ -243,12 +294,15 @@ pub use self::withdraw::withdraw_routes;
 
         user::get_users,
         user::get_user,
+        user::export_user_data,
         user::get_active_users,
         user::get_trashed_users,
         user::create_user,
         user::update_user,
         user::trash_user_handler,
         user::restore_user_handler,
+        user::anonymize_user_handler,
+        user::merge_users_handler,
         user::delete_user,
         user::restore_all_user_handler,
         user::delete_all_user_handler,
@@ -256,11 +310,14 @@ pub use self::withdraw::withdraw_routes;
 
         withdraw::get_withdraws,
         withdraw::get_withdraws_by_card_number,
+        withdraw::find_withdraws,
         withdraw::get_withdraw,
         withdraw::get_active_withdraws,
         withdraw::get_trashed_withdraws,
         withdraw::create_withdraw,
+        withdraw::preview_withdraw,
         withdraw::update_withdraw,
+        withdraw::cancel_withdraw_handler,
         withdraw::trash_withdraw_handler,
         withdraw::restore_withdraw_handler,
         withdraw::delete_withdraw,
@@ -278,6 +335,17 @@ pub use self::withdraw::withdraw_routes;
         withdraw::get_yearly_status_success_by_card,
         withdraw::get_month_status_failed_by_card,
         withdraw::get_yearly_status_failed_by_card,
+        payment_method::get_payment_methods,
+        feature::get_features,
+        rate_limit::list_rate_limits,
+        rate_limit::clear_rate_limit_key,
+        rate_limit::clear_rate_limit_namespace,
+        stats::get_system_stats,
+        stats::get_top_cards,
+        stats::recompute_stats,
+
+        activity::get_my_activity,
+        limits::get_my_limits,
     ),
     modifiers(&SecurityAddon),
     tags(
@@ -291,6 +359,9 @@ pub use self::withdraw::withdraw_routes;
         (name = "Transaction", description = "Transaction processing and history endpoints"),
         (name = "Transfer", description = "Money transfer between accounts or cards"),
         (name = "Withdraw", description = "Withdraw operations and endpoints"),
+        (name = "Payment Method", description = "Supported payment methods and their limits"),
+        (name = "Config", description = "Deployment features for client apps"),
+        (name = "Admin", description = "Operational endpoints for administrators"),
     )
 )]
 struct ApiDoc;
@@ -316,7 +387,31 @@ impl AppRouter {
     pub async fn serve(port: u16, app_state: AppState) -> Result<()> {
         let shared_state = Arc::new(app_state);
 
+        let request_id_headers: Vec<HeaderName> = shared_state
+            .request_id
+            .headers
+            .iter()
+            .filter_map(|name| HeaderName::from_bytes(name.as_bytes()).ok())
+            .collect();
+        let request_id_header =
+            HeaderName::from_bytes(shared_state.request_id.primary_header().as_bytes())
+                .unwrap_or_else(|_| HeaderName::from_static("x-request-id"));
+        let request_id_layer =
+            middleware::from_fn_with_state(shared_state.clone(), request_id_middleware);
+        let service_limiter_layer =
+            middleware::from_fn_with_state(shared_state.clone(), service_limiter_middleware);
+        let locale_layer = middleware::from_fn_with_state(shared_state.clone(), locale_middleware);
+        let security_headers_layer =
+            middleware::from_fn_with_state(shared_state.clone(), security_headers_middleware);
+        let request_deadline_layer =
+            middleware::from_fn_with_state(shared_state.clone(), request_deadline_middleware);
+        let route_auth_layer =
+            middleware::from_fn_with_state(shared_state.clone(), route_auth_middleware);
+        let request_timeout = shared_state.request_timeout.timeout();
+        let openapi_server_url = shared_state.openapi.server_url.clone();
+
         let api_router = OpenApiRouter::with_openapi(ApiDoc::openapi())
+            .merge(health_routes(shared_state.clone()))
             .merge(auth_routes(shared_state.clone()))
             .merge(user_routes(shared_state.clone()))
             .merge(role_routes(shared_state.clone()))
@@ -326,12 +421,26 @@ impl AppRouter {
             .merge(topup_routes(shared_state.clone()))
             .merge(transaction_routes(shared_state.clone()))
             .merge(transfer_routes(shared_state.clone()))
-            .merge(withdraw_routes(shared_state));
+            .merge(withdraw_routes(shared_state.clone()))
+            .merge(payment_method_routes(shared_state.clone()))
+            .merge(feature_routes(shared_state.clone()))
+            .merge(rate_limit_routes(shared_state.clone()))
+            .merge(stats_routes(shared_state.clone()))
+            .merge(limits_routes(shared_state.clone()))
+            .merge(activity_routes(shared_state));
 
         let allowed_origin = "http://localhost:1420"
             .parse()
             .expect("invalid CORS origin");
 
+        let mut allowed_headers = vec![
+            header::CONTENT_TYPE,
+            header::ACCEPT,
+            header::AUTHORIZATION,
+            HeaderName::from_static("x-api-key"),
+        ];
+        allowed_headers.extend(request_id_headers);
+
         let cors = CorsLayer::new()
             .allow_origin(AllowOrigin::exact(allowed_origin))
             .allow_methods([
@@ -341,28 +450,42 @@ impl AppRouter {
                 Method::DELETE,
                 Method::OPTIONS,
             ])
-            .allow_headers([
-                header::CONTENT_TYPE,
-                header::ACCEPT,
-                header::AUTHORIZATION,
-                HeaderName::from_static("x-api-key"),
+            .allow_headers(allowed_headers)
+            .expose_headers([
+                request_id_header,
+                HeaderName::from_static("x-ratelimit-limit"),
+                HeaderName::from_static("x-ratelimit-remaining"),
+                HeaderName::from_static("x-ratelimit-reset"),
             ])
             .allow_credentials(true);
 
         let router_with_layers = api_router
+            .layer(route_auth_layer)
             .layer(cors)
             .layer(DefaultBodyLimit::disable())
             .layer(
                 ServiceBuilder::new()
                     .layer(RequestBodyLimitLayer::new(10 * 1024 * 1024))
-                    .layer(TimeoutLayer::new(Duration::from_secs(45)))
+                    .layer(TimeoutLayer::new(request_timeout))
                     .layer(ConcurrencyLimitLayer::new(12000)),
-            );
+            )
+            .layer(request_deadline_layer)
+            .layer(service_limiter_layer)
+            .layer(middleware::from_fn(reject_service_account_middleware))
+            .layer(middleware::from_fn(pagination_links_middleware))
+            .layer(locale_layer)
+            .layer(request_id_layer);
+
+        let (app_router, mut api) = router_with_layers.split_for_parts();
 
-        let (app_router, api) = router_with_layers.split_for_parts();
+        if let Some(url) = openapi_server_url {
+            info!("📚 OpenAPI server URL overridden to {url}");
+            api.servers = Some(vec![Server::new(url)]);
+        }
 
         let app = app_router
-            .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", api.clone()));
+            .merge(SwaggerUi::new("/swagger-ui").url("/api-docs/openapi.json", api.clone()))
+            .layer(security_headers_layer);
 
         let addr = format!("0.0.0.0:{port}");
         let listener = TcpListener::bind(&addr).await?;
@@ -0,0 +1,71 @@
+use crate::{
+    middleware::{
+        circuit_breaker::circuit_breaker_middleware, jwt,
+        request_limiter::request_limiter_middleware,
+    },
+    state::AppState,
+};
+use axum::{
+    Json,
+    extract::{Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::get,
+};
+use shared::{
+    domain::{
+        requests::payment_method::FindPaymentMethods,
+        responses::{ApiResponse, PaymentMethodResponse},
+    },
+    errors::HttpError,
+};
+use std::sync::Arc;
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/payment-methods",
+    tag = "Payment Method",
+    security(("bearer_auth" = [])),
+    params(FindPaymentMethods),
+    responses(
+        (status = 200, description = "Supported payment methods with their limits", body = ApiResponse<Vec<PaymentMethodResponse>>),
+        (status = 401, description = "Unauthorized")
+    )
+)]
+pub async fn get_payment_methods(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<FindPaymentMethods>,
+) -> Result<impl IntoResponse, HttpError> {
+    let data: Vec<PaymentMethodResponse> = app_state
+        .payment_methods
+        .available(params.include_disabled)
+        .into_iter()
+        .map(PaymentMethodResponse::from)
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            status: "success".to_string(),
+            message: "Payment methods retrieved successfully".to_string(),
+            data,
+        }),
+    ))
+}
+
+pub fn payment_method_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/payment-methods", get(get_payment_methods))
+        .route_layer(middleware::from_fn_with_state(state.clone(), jwt::auth))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            circuit_breaker_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            request_limiter_middleware,
+        ))
+        .with_state(state)
+}
@@ -0,0 +1,181 @@
+use crate::{
+    middleware::{
+        circuit_breaker::circuit_breaker_middleware, jwt,
+        request_limiter::request_limiter_middleware, session::session_middleware,
+    },
+    state::AppState,
+};
+use axum::{
+    Json,
+    extract::{Extension, Path, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{delete, get},
+};
+use shared::{
+    domain::{
+        requests::rate_limit::RateLimitNamespace,
+        responses::{ApiResponse, RateLimitClearResponse, RateLimitStateResponse},
+    },
+    errors::HttpError,
+};
+use std::sync::Arc;
+use tracing::{error, info};
+use utoipa_axum::router::OpenApiRouter;
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/rate-limits/{namespace}",
+    tag = "Admin",
+    security(("bearer_auth" = [])),
+    params(("namespace" = RateLimitNamespace, Path, description = "Rate-limit namespace")),
+    responses(
+        (status = 200, description = "Identifiers currently tracked in the namespace", body = ApiResponse<RateLimitStateResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn list_rate_limits(
+    State(app_state): State<Arc<AppState>>,
+    Path(namespace): Path<RateLimitNamespace>,
+) -> Result<impl IntoResponse, HttpError> {
+    let prefix = namespace.prefix();
+    let keys = app_state.cache_store.scan(prefix).await.map_err(|e| {
+        error!("❌ Failed to list {namespace} keys: {e:?}");
+        HttpError::Internal(format!("Failed to list {namespace} keys"))
+    })?;
+
+    let identifiers = keys
+        .into_iter()
+        .filter_map(|key| key.strip_prefix(prefix).map(str::to_string))
+        .collect();
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            status: "success".to_string(),
+            message: format!("Listed {namespace} state"),
+            data: RateLimitStateResponse {
+                namespace,
+                identifiers,
+            },
+        }),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/rate-limits/{namespace}/{identifier}",
+    tag = "Admin",
+    security(("bearer_auth" = [])),
+    params(
+        ("namespace" = RateLimitNamespace, Path, description = "Rate-limit namespace"),
+        ("identifier" = String, Path, description = "Tracked identifier, e.g. client IP or email")
+    ),
+    responses(
+        (status = 200, description = "Identifier cleared", body = ApiResponse<RateLimitClearResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden")
+    )
+)]
+pub async fn clear_rate_limit_key(
+    State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
+    Path((namespace, identifier)): Path<(RateLimitNamespace, String)>,
+) -> Result<impl IntoResponse, HttpError> {
+    app_state
+        .cache_store
+        .delete_from_cache(&namespace.key_for(&identifier))
+        .await;
+
+    info!("🧹 User {user_id} cleared {namespace} state for '{identifier}'");
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            status: "success".to_string(),
+            message: format!("Cleared {namespace} state for '{identifier}'"),
+            data: RateLimitClearResponse {
+                namespace,
+                cleared: 1,
+            },
+        }),
+    ))
+}
+
+#[utoipa::path(
+    delete,
+    path = "/api/admin/rate-limits/{namespace}",
+    tag = "Admin",
+    security(("bearer_auth" = [])),
+    params(("namespace" = RateLimitNamespace, Path, description = "Rate-limit namespace")),
+    responses(
+        (status = 200, description = "All identifiers in the namespace cleared", body = ApiResponse<RateLimitClearResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn clear_rate_limit_namespace(
+    State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
+    Path(namespace): Path<RateLimitNamespace>,
+) -> Result<impl IntoResponse, HttpError> {
+    let keys = app_state
+        .cache_store
+        .scan(namespace.prefix())
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to list {namespace} keys: {e:?}");
+            HttpError::Internal(format!("Failed to clear {namespace} state"))
+        })?;
+
+    for key in &keys {
+        app_state.cache_store.delete_from_cache(key).await;
+    }
+
+    info!(
+        "🧹 User {user_id} cleared {} {namespace} entries",
+        keys.len()
+    );
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            status: "success".to_string(),
+            message: format!("Cleared all {namespace} state"),
+            data: RateLimitClearResponse {
+                namespace,
+                cleared: keys.len(),
+            },
+        }),
+    ))
+}
+
+pub fn rate_limit_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route(
+            "/api/admin/rate-limits/{namespace}",
+            get(list_rate_limits).delete(clear_rate_limit_namespace),
+        )
+        .route(
+            "/api/admin/rate-limits/{namespace}/{identifier}",
+            delete(clear_rate_limit_key),
+        )
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            session_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(state.clone(), jwt::auth))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            circuit_breaker_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            request_limiter_middleware,
+        ))
+        .with_state(state)
+}
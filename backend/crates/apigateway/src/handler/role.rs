@@ -1,15 +1,15 @@
 use crate::middleware::session::session_middleware;
 use crate::{
     middleware::{
-        circuit_breaker::circuit_breaker_middleware, jwt,
+        circuit_breaker::circuit_breaker_middleware, etag::etag_response, jwt,
         request_limiter::request_limiter_middleware, validate::SimpleValidatedJson,
     },
     state::AppState,
 };
 use axum::{
     Json,
-    extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post},
@@ -18,7 +18,9 @@ use serde_json::json;
 use shared::{
     domain::{
         requests::role::{CreateRoleRequest, FindAllRoles, UpdateRoleRequest},
-        responses::{ApiResponse, ApiResponsePagination, RoleResponse, RoleResponseDeleteAt},
+        responses::{
+            ApiResponse, ApiResponsePagination, BulkResult, RoleResponse, RoleResponseDeleteAt,
+        },
     },
     errors::HttpError,
 };
@@ -105,6 +107,7 @@ pub async fn get_trashed_roles(
     params(("id" = i32, Path, description = "Role ID")),
     responses(
         (status = 200, description = "Role details", body = ApiResponse<RoleResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Role not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -112,11 +115,12 @@ pub async fn get_trashed_roles(
 pub async fn get_role(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let role_client = &app_state.di_container.role_clients;
 
     match role_client.find_by_id(id).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Ok(response) => Ok(etag_response(&headers, response)),
         Err(err) => Err(err),
     }
 }
@@ -160,30 +164,10 @@ pub async fn get_roles_by_user_id(
 )]
 pub async fn create_role(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
     SimpleValidatedJson(body): SimpleValidatedJson<CreateRoleRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
     let role_client = &app_state.di_container.role_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match role_client.create(&body).await {
         Ok(response) => Ok((StatusCode::CREATED, Json(response))),
         Err(err) => Err(err),
@@ -207,31 +191,11 @@ pub async fn create_role(
 pub async fn update_role(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 
     SimpleValidatedJson(mut body): SimpleValidatedJson<UpdateRoleRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
     let role_client = &app_state.di_container.role_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     body.id = Some(id);
     match role_client.update(&body).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
@@ -254,29 +218,9 @@ pub async fn update_role(
 pub async fn trash_role_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let role_client = &app_state.di_container.role_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match role_client.trash(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -298,29 +242,9 @@ pub async fn trash_role_handler(
 pub async fn restore_role_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let role_client = &app_state.di_container.role_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match role_client.restore(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -342,29 +266,9 @@ pub async fn restore_role_handler(
 pub async fn delete_role(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let role_client = &app_state.di_container.role_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match role_client.delete(id).await {
         Ok(_) => Ok((
             StatusCode::OK,
@@ -383,41 +287,22 @@ pub async fn delete_role(
     tag = "Role",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed roles restored", body = serde_json::Value),
+        (status = 200, description = "All trashed roles restored", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn restore_all_role_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let role_client = &app_state.di_container.role_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match role_client.restore_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All roles restored successfully"
+               "message": "All roles restored successfully",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -430,41 +315,22 @@ pub async fn restore_all_role_handler(
     tag = "Role",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed roles permanently deleted", body = serde_json::Value),
+        (status = 200, description = "All trashed roles permanently deleted", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn delete_all_role_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let role_client = &app_state.di_container.role_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match role_client.delete_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All trashed roles deleted permanently"
+               "message": "All trashed roles deleted permanently",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -1,15 +1,15 @@
 use crate::{
     middleware::{
-        circuit_breaker::circuit_breaker_middleware, jwt, rate_limit::rate_limit_middleware,
-        request_limiter::request_limiter_middleware, session::session_middleware,
-        validate::SimpleValidatedJson,
+        circuit_breaker::circuit_breaker_middleware, etag::etag_response, jwt,
+        rate_limit::rate_limit_middleware, request_limiter::request_limiter_middleware,
+        session::session_middleware, validate::SimpleValidatedJson,
     },
     state::AppState,
 };
 use axum::{
     Json,
     extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post},
@@ -19,14 +19,16 @@ use shared::{
     domain::{
         requests::{
             saldo::{
-                CreateSaldoRequest, FindAllSaldos, MonthTotalSaldoBalance, UpdateSaldoRequest,
+                CreateSaldoRequest, FindAllSaldos, FindCardStatement, FindSaldoBalances,
+                MonthTotalSaldoBalance, UpdateSaldoRequest,
             },
             withdraw::YearQuery,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, SaldoMonthBalanceResponse,
-            SaldoMonthTotalBalanceResponse, SaldoResponse, SaldoResponseDeleteAt,
-            SaldoYearBalanceResponse, SaldoYearTotalBalanceResponse,
+            ApiResponse, ApiResponsePagination, BulkResult, CardStatementResponse,
+            SaldoBalancesResponse, SaldoMonthBalanceResponse, SaldoMonthTotalBalanceResponse,
+            SaldoResponse, SaldoResponseDeleteAt, SaldoYearBalanceResponse,
+            SaldoYearTotalBalanceResponse,
         },
     },
     errors::HttpError,
@@ -114,6 +116,7 @@ pub async fn get_trashed_saldos(
     params(("id" = i32, Path, description = "Saldo ID")),
     responses(
         (status = 200, description = "Saldo details", body = ApiResponse<SaldoResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Saldo not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -121,11 +124,12 @@ pub async fn get_trashed_saldos(
 pub async fn get_saldo(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
     match saldo_client.find_by_id(id).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Ok(response) => Ok(etag_response(&headers, response)),
         Err(err) => Err(err),
     }
 }
@@ -138,6 +142,7 @@ pub async fn get_saldo(
     params(("card_number" = String, Path, description = "Card Number")),
     responses(
         (status = 200, description = "Saldo details", body = ApiResponse<SaldoResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Saldo not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -145,15 +150,102 @@ pub async fn get_saldo(
 pub async fn get_saldo_by_card(
     State(app_state): State<Arc<AppState>>,
     Path(card_number): Path<String>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
     match saldo_client.find_by_card(&card_number).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Ok(response) => Ok(etag_response(&headers, response)),
         Err(err) => Err(err),
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/saldos/balances",
+    tag = "Saldo",
+    security(("bearer_auth" = [])),
+    request_body = FindSaldoBalances,
+    responses(
+        (status = 200, description = "Balances keyed by card number", body = ApiResponse<SaldoBalancesResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized")
+    )
+)]
+pub async fn get_saldo_balances(
+    State(app_state): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<FindSaldoBalances>,
+) -> Result<impl IntoResponse, HttpError> {
+    let saldo_client = &app_state.di_container.saldo_clients;
+
+    let response = saldo_client.get_balances(&body).await?;
+    Ok((StatusCode::OK, Json(response)))
+}
+
+async fn ensure_card_holder_or_staff(
+    app_state: &AppState,
+    user_id: i32,
+    card_number: &str,
+) -> Result<(), HttpError> {
+    let key = format!("session:{user_id}");
+
+    let current_session = app_state
+        .session
+        .get_session(&key)
+        .await
+        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
+
+    if current_session
+        .roles
+        .iter()
+        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
+    {
+        return Ok(());
+    }
+
+    let card = app_state
+        .di_container
+        .card_clients
+        .find_by_card_number(card_number.to_string())
+        .await?;
+
+    if card.data.user_id != user_id {
+        return Err(HttpError::Forbidden(
+            "Access denied. Only the cardholder, ADMIN or MODERATOR can view this statement"
+                .to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/saldos/statement",
+    tag = "Saldo",
+    security(("bearer_auth" = [])),
+    params(FindCardStatement),
+    responses(
+        (status = 200, description = "Monthly statement for the card, built from the saldo ledger", body = ApiResponse<CardStatementResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "Saldo not found")
+    )
+)]
+pub async fn get_card_statement(
+    State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
+    Query(params): Query<FindCardStatement>,
+) -> Result<impl IntoResponse, HttpError> {
+    ensure_card_holder_or_staff(&app_state, user_id, &params.card_number).await?;
+
+    let saldo_client = &app_state.di_container.saldo_clients;
+
+    let response = saldo_client.get_statement(&params).await?;
+    Ok((StatusCode::OK, Json(response)))
+}
+
 #[utoipa::path(
     post,
     path = "/api/saldos/create",
@@ -222,29 +314,9 @@ pub async fn update_saldo(
 pub async fn trash_saldo_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match saldo_client.trash(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -266,29 +338,9 @@ pub async fn trash_saldo_handler(
 pub async fn restore_saldo_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match saldo_client.restore(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -310,29 +362,9 @@ pub async fn restore_saldo_handler(
 pub async fn delete_saldo(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match saldo_client.delete_permanent(id).await {
         Ok(_) => Ok((
             StatusCode::OK,
@@ -351,41 +383,22 @@ pub async fn delete_saldo(
     tag = "Saldo",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed saldos restored", body = serde_json::Value),
+        (status = 200, description = "All trashed saldos restored", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn restore_all_saldo_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match saldo_client.restore_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All saldos restored successfully"
+               "message": "All saldos restored successfully",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -398,41 +411,22 @@ pub async fn restore_all_saldo_handler(
     tag = "Saldo",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed saldos permanently deleted", body = serde_json::Value),
+        (status = 200, description = "All trashed saldos permanently deleted", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn delete_all_saldo_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match saldo_client.delete_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All trashed saldos deleted permanently"
+               "message": "All trashed saldos deleted permanently",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -454,29 +448,9 @@ pub async fn delete_all_saldo_handler(
 pub async fn get_monthly_balance(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match saldo_client.get_month_balance(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -498,29 +472,9 @@ pub async fn get_monthly_balance(
 pub async fn get_yearly_balance(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match saldo_client.get_year_balance(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -542,29 +496,9 @@ pub async fn get_yearly_balance(
 pub async fn get_monthly_total_balance(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthTotalSaldoBalance>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match saldo_client.get_month_total_balance(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -586,29 +520,9 @@ pub async fn get_monthly_total_balance(
 pub async fn get_yearly_total_balance(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let saldo_client = &app_state.di_container.saldo_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match saldo_client.get_year_total_balance(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -624,6 +538,8 @@ pub fn saldo_routes(state: Arc<AppState>) -> OpenApiRouter {
         .route("/api/saldos/trashed", get(get_trashed_saldos))
         .route("/api/saldos/{id}", get(get_saldo))
         .route("/api/saldos/by-card/{card_number}", get(get_saldo_by_card))
+        .route("/api/saldos/balances", post(get_saldo_balances))
+        .route("/api/saldos/statement", get(get_card_statement))
         .route("/api/saldos/trash/{id}", post(trash_saldo_handler))
         .route("/api/saldos/restore/{id}", post(restore_saldo_handler))
         .route("/api/saldos/delete/{id}", delete(delete_saldo))
@@ -0,0 +1,301 @@
+use crate::{
+    di::DependenciesInject,
+    middleware::{
+        circuit_breaker::circuit_breaker_middleware, jwt,
+        request_limiter::request_limiter_middleware, session::session_middleware,
+        validate::SimpleValidatedJson,
+    },
+    state::AppState,
+};
+use axum::{
+    Json,
+    extract::{Extension, Query, State},
+    http::StatusCode,
+    middleware,
+    response::IntoResponse,
+    routing::{get, post},
+};
+use shared::{
+    domain::{
+        requests::{
+            card::FindTopCards,
+            stats::{RecomputeStatsRequest, StatsScope},
+        },
+        responses::{ApiResponse, StatsRecomputeResponse, SystemStatsResponse, TopCardResponse},
+    },
+    errors::HttpError,
+};
+use std::{sync::Arc, time::Duration};
+use tracing::{error, info, warn};
+use utoipa_axum::router::OpenApiRouter;
+
+const RECOMPUTE_LOCK_TTL: Duration = Duration::from_secs(300);
+
+#[derive(Default)]
+struct RecomputeTally {
+    recomputed: usize,
+    failed: Vec<String>,
+}
+
+impl RecomputeTally {
+    fn record<T>(&mut self, label: &str, year: i32, result: Result<T, HttpError>) {
+        match result {
+            Ok(_) => self.recomputed += 1,
+            Err(e) => {
+                warn!("⚠️ Failed to recompute {label} for {year}: {e:?}");
+                self.failed.push(format!("{label}:{year}"));
+            }
+        }
+    }
+}
+
+async fn recompute_year(
+    clients: &DependenciesInject,
+    scope: StatsScope,
+    year: i32,
+    tally: &mut RecomputeTally,
+) {
+    match scope {
+        StatsScope::Transaction => {
+            let c = &clients.transaction_clients;
+            tally.record("monthly_amounts", year, c.get_monthly_amounts(year).await);
+            tally.record("yearly_amounts", year, c.get_yearly_amounts(year).await);
+            tally.record("monthly_method", year, c.get_monthly_method(year).await);
+            tally.record("yearly_method", year, c.get_yearly_method(year).await);
+            tally.record(
+                "yearly_status_success",
+                year,
+                c.get_yearly_status_success(year).await,
+            );
+            tally.record(
+                "yearly_status_failed",
+                year,
+                c.get_yearly_status_failed(year).await,
+            );
+        }
+        StatsScope::Topup => {
+            let c = &clients.topup_clients;
+            tally.record("monthly_amounts", year, c.get_monthly_amounts(year).await);
+            tally.record("yearly_amounts", year, c.get_yearly_amounts(year).await);
+            tally.record("monthly_methods", year, c.get_monthly_methods(year).await);
+            tally.record("yearly_methods", year, c.get_yearly_methods(year).await);
+            tally.record(
+                "yearly_status_success",
+                year,
+                c.get_yearly_status_success(year).await,
+            );
+            tally.record(
+                "yearly_status_failed",
+                year,
+                c.get_yearly_status_failed(year).await,
+            );
+        }
+        StatsScope::Transfer => {
+            let c = &clients.transfer_clients;
+            tally.record("monthly_amounts", year, c.get_monthly_amounts(year).await);
+            tally.record("yearly_amounts", year, c.get_yearly_amounts(year).await);
+            tally.record(
+                "yearly_status_success",
+                year,
+                c.get_yearly_status_success(year).await,
+            );
+            tally.record(
+                "yearly_status_failed",
+                year,
+                c.get_yearly_status_failed(year).await,
+            );
+        }
+        StatsScope::Withdraw => {
+            let c = &clients.withdraw_clients;
+            tally.record("monthly_amounts", year, c.get_monthly_withdraws(year).await);
+            tally.record("yearly_amounts", year, c.get_yearly_withdraws(year).await);
+            tally.record(
+                "yearly_status_success",
+                year,
+                c.get_yearly_status_success(year).await,
+            );
+            tally.record(
+                "yearly_status_failed",
+                year,
+                c.get_yearly_status_failed(year).await,
+            );
+        }
+        StatsScope::Saldo => {
+            let c = &clients.saldo_clients;
+            tally.record("monthly_balance", year, c.get_month_balance(year).await);
+            tally.record("yearly_balance", year, c.get_year_balance(year).await);
+            tally.record(
+                "yearly_total_balance",
+                year,
+                c.get_year_total_balance(year).await,
+            );
+        }
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/stats",
+    tag = "Admin",
+    security(("bearer_auth" = [])),
+    responses(
+        (status = 200, description = "System-wide totals and today's transactions", body = ApiResponse<SystemStatsResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_system_stats(
+    State(app_state): State<Arc<AppState>>,
+) -> Result<impl IntoResponse, HttpError> {
+    let response = app_state
+        .di_container
+        .card_clients
+        .get_system_stats()
+        .await?;
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/admin/stats/top-cards",
+    tag = "Admin",
+    security(("bearer_auth" = [])),
+    params(FindTopCards),
+    responses(
+        (status = 200, description = "Top cards by balance or transaction activity, with masked card numbers", body = ApiResponse<Vec<TopCardResponse>>),
+        (status = 400, description = "Invalid metric, limit or date range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_top_cards(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<FindTopCards>,
+) -> Result<impl IntoResponse, HttpError> {
+    params.date_range().map_err(HttpError::BadRequest)?;
+
+    let response = app_state
+        .di_container
+        .card_clients
+        .get_top_cards(&params)
+        .await?;
+
+    Ok((StatusCode::OK, Json(response)))
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/admin/stats/recompute",
+    tag = "Admin",
+    security(("bearer_auth" = [])),
+    request_body = RecomputeStatsRequest,
+    responses(
+        (status = 200, description = "Aggregates recomputed from source rows", body = ApiResponse<StatsRecomputeResponse>),
+        (status = 400, description = "Invalid scope or year range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 409, description = "A recompute for this scope is already running"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn recompute_stats(
+    State(app_state): State<Arc<AppState>>,
+    Extension(user_id): Extension<i32>,
+    SimpleValidatedJson(body): SimpleValidatedJson<RecomputeStatsRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    body.check_year_range().map_err(HttpError::BadRequest)?;
+
+    let scope = body.scope;
+    let lock_key = scope.lock_key();
+
+    let lock_token = app_state
+        .cache_store
+        .try_lock(&lock_key, RECOMPUTE_LOCK_TTL)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to acquire {scope} recompute lock: {e:?}");
+            HttpError::Internal(format!("Failed to start {scope} stats recompute"))
+        })?;
+
+    let Some(lock_token) = lock_token else {
+        return Err(HttpError::Conflict(format!(
+            "A {scope} stats recompute is already running"
+        )));
+    };
+
+    info!(
+        "🔄 User {user_id} started {scope} stats recompute for {}..={}",
+        body.from_year, body.to_year
+    );
+
+    let mut invalidated_keys = 0;
+    let mut tally = RecomputeTally::default();
+
+    for year in body.years() {
+        for prefix in scope.cache_prefixes().iter().copied().chain(["dashboard"]) {
+            let pattern = format!("{prefix}:*year:{year}*");
+            match app_state.cache_store.invalidate_pattern(&pattern).await {
+                Ok(deleted) => invalidated_keys += deleted,
+                Err(e) => error!("❌ Failed to invalidate '{pattern}': {e:?}"),
+            }
+        }
+
+        recompute_year(&app_state.di_container, scope, year, &mut tally).await;
+    }
+
+    match app_state
+        .cache_store
+        .release_lock(&lock_key, &lock_token)
+        .await
+    {
+        Ok(true) => {}
+        Ok(false) => warn!("⚠️ {scope} recompute lock expired before the run finished"),
+        Err(e) => error!("❌ Failed to release {scope} recompute lock: {e:?}"),
+    }
+
+    info!(
+        "✅ {scope} stats recompute finished: {invalidated_keys} keys invalidated, {} aggregates recomputed, {} failed",
+        tally.recomputed,
+        tally.failed.len()
+    );
+
+    Ok((
+        StatusCode::OK,
+        Json(ApiResponse {
+            status: "success".to_string(),
+            message: format!("Recomputed {scope} stats"),
+            data: StatsRecomputeResponse {
+                scope,
+                from_year: body.from_year,
+                to_year: body.to_year,
+                invalidated_keys,
+                recomputed: tally.recomputed,
+                failed: tally.failed,
+            },
+        }),
+    ))
+}
+
+pub fn stats_routes(state: Arc<AppState>) -> OpenApiRouter {
+    OpenApiRouter::new()
+        .route("/api/admin/stats", get(get_system_stats))
+        .route("/api/admin/stats/top-cards", get(get_top_cards))
+        .route("/api/admin/stats/recompute", post(recompute_stats))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            session_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(state.clone(), jwt::auth))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            circuit_breaker_middleware,
+        ))
+        .route_layer(middleware::from_fn_with_state(
+            state.clone(),
+            request_limiter_middleware,
+        ))
+        .with_state(state)
+}
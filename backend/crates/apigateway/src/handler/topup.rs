@@ -1,15 +1,15 @@
 use crate::{
     middleware::{
-        circuit_breaker::circuit_breaker_middleware, jwt, rate_limit::rate_limit_middleware,
-        request_limiter::request_limiter_middleware, session::session_middleware,
-        validate::SimpleValidatedJson,
+        circuit_breaker::circuit_breaker_middleware, etag::etag_response, jwt,
+        rate_limit::rate_limit_middleware, request_limiter::request_limiter_middleware,
+        session::session_middleware, validate::SimpleValidatedJson,
     },
     state::AppState,
 };
 use axum::{
     Json,
-    extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post},
@@ -26,10 +26,11 @@ use shared::{
             withdraw::YearQuery,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, TopupMonthAmountResponse, TopupMonthMethodResponse,
-            TopupResponse, TopupResponseDeleteAt, TopupResponseMonthStatusFailed,
-            TopupResponseMonthStatusSuccess, TopupResponseYearStatusFailed,
-            TopupResponseYearStatusSuccess, TopupYearlyAmountResponse, TopupYearlyMethodResponse,
+            ApiResponse, ApiResponsePagination, BulkResult, TopupMonthAmountResponse,
+            TopupMonthMethodResponse, TopupResponse, TopupResponseDeleteAt,
+            TopupResponseMonthStatusFailed, TopupResponseMonthStatusSuccess,
+            TopupResponseYearStatusFailed, TopupResponseYearStatusSuccess,
+            TopupYearlyAmountResponse, TopupYearlyMethodResponse,
         },
     },
     errors::HttpError,
@@ -141,6 +142,7 @@ pub async fn get_trashed_topups(
     params(("id" = i32, Path, description = "Topup ID")),
     responses(
         (status = 200, description = "Topup details", body = ApiResponse<TopupResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Topup not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -148,11 +150,12 @@ pub async fn get_trashed_topups(
 pub async fn get_topup(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
     match topup_client.find_by_id(id).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Ok(response) => Ok(etag_response(&headers, response)),
         Err(err) => Err(err),
     }
 }
@@ -225,29 +228,9 @@ pub async fn update_topup(
 pub async fn trash_topup_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.trashed(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -269,29 +252,9 @@ pub async fn trash_topup_handler(
 pub async fn restore_topup_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.restore(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -313,29 +276,9 @@ pub async fn restore_topup_handler(
 pub async fn delete_topup(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.delete_permanent(id).await {
         Ok(_) => Ok((
             StatusCode::OK,
@@ -354,41 +297,22 @@ pub async fn delete_topup(
     tag = "Topup",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed topups restored", body = serde_json::Value),
+        (status = 200, description = "All trashed topups restored", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn restore_all_topup_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.restore_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All topups restored successfully"
+               "message": "All topups restored successfully",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -401,41 +325,22 @@ pub async fn restore_all_topup_handler(
     tag = "Topup",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed topups permanently deleted", body = serde_json::Value),
+        (status = 200, description = "All trashed topups permanently deleted", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn delete_all_topup_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.delete_all_permanent().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All trashed topups deleted permanently"
+               "message": "All trashed topups deleted permanently",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -457,29 +362,9 @@ pub async fn delete_all_topup_handler(
 pub async fn get_monthly_topup_amounts(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_monthly_amounts(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -501,29 +386,9 @@ pub async fn get_monthly_topup_amounts(
 pub async fn get_yearly_topup_amounts(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_yearly_amounts(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -545,29 +410,9 @@ pub async fn get_yearly_topup_amounts(
 pub async fn get_monthly_topup_methods(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_monthly_methods(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -589,29 +434,9 @@ pub async fn get_monthly_topup_methods(
 pub async fn get_yearly_topup_methods(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_yearly_methods(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -633,29 +458,9 @@ pub async fn get_yearly_topup_methods(
 pub async fn get_month_topup_status_success(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthTopupStatus>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_month_status_success(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -677,29 +482,9 @@ pub async fn get_month_topup_status_success(
 pub async fn get_yearly_topup_status_success(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_yearly_status_success(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -721,29 +506,9 @@ pub async fn get_yearly_topup_status_success(
 pub async fn get_month_topup_status_failed(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthTopupStatus>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_month_status_failed(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -765,29 +530,9 @@ pub async fn get_month_topup_status_failed(
 pub async fn get_yearly_topup_status_failed(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_yearly_status_failed(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -809,29 +554,9 @@ pub async fn get_yearly_topup_status_failed(
 pub async fn get_monthly_topup_amounts_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearMonthMethod>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_monthly_amounts_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -853,29 +578,9 @@ pub async fn get_monthly_topup_amounts_by_card(
 pub async fn get_yearly_topup_amounts_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearMonthMethod>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_yearly_amounts_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -897,29 +602,9 @@ pub async fn get_yearly_topup_amounts_by_card(
 pub async fn get_monthly_topup_methods_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearMonthMethod>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_monthly_methods_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -941,29 +626,9 @@ pub async fn get_monthly_topup_methods_by_card(
 pub async fn get_yearly_topup_methods_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearMonthMethod>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_yearly_methods_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -985,29 +650,9 @@ pub async fn get_yearly_topup_methods_by_card(
 pub async fn get_month_topup_status_success_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthTopupStatusCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_month_status_success_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1029,29 +674,9 @@ pub async fn get_month_topup_status_success_by_card(
 pub async fn get_yearly_topup_status_success_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearTopupStatusCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_yearly_status_success_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1073,29 +698,9 @@ pub async fn get_yearly_topup_status_success_by_card(
 pub async fn get_month_topup_status_failed_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthTopupStatusCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_month_status_failed_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1117,29 +722,9 @@ pub async fn get_month_topup_status_failed_by_card(
 pub async fn get_yearly_topup_status_failed_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearTopupStatusCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let topup_client = &app_state.di_container.topup_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match topup_client.get_yearly_status_failed_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
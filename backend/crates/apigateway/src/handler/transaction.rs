@@ -1,6 +1,6 @@
 use crate::{
     middleware::{
-        api_key::ApiKey, circuit_breaker::circuit_breaker_middleware, jwt,
+        api_key::ApiKey, circuit_breaker::circuit_breaker_middleware, etag::etag_response, jwt,
         rate_limit::rate_limit_middleware, request_limiter::request_limiter_middleware,
         session::session_middleware, validate::SimpleValidatedJson,
     },
@@ -8,8 +8,8 @@ use crate::{
 };
 use axum::{
     Json,
-    extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    extract::{Path, Query, State},
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post},
@@ -21,12 +21,12 @@ use shared::{
             transaction::{
                 CreateTransactionRequest, FindAllTransactionCardNumber, FindAllTransactions,
                 MonthStatusTransaction, MonthStatusTransactionCardNumber, MonthYearPaymentMethod,
-                UpdateTransactionRequest, YearStatusTransactionCardNumber,
+                SearchTransactions, UpdateTransactionRequest, YearStatusTransactionCardNumber,
             },
             withdraw::YearQuery,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, TransactionMonthAmountResponse,
+            ApiResponse, ApiResponsePagination, BulkResult, TransactionMonthAmountResponse,
             TransactionMonthMethodResponse, TransactionResponse, TransactionResponseDeleteAt,
             TransactionResponseMonthStatusFailed, TransactionResponseMonthStatusSuccess,
             TransactionResponseYearStatusFailed, TransactionResponseYearStatusSuccess,
@@ -86,6 +86,31 @@ pub async fn get_transactions_by_card_number(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/transactions/search",
+    tag = "Transaction",
+    security(("bearer_auth" = [])),
+    params(SearchTransactions),
+    responses(
+        (status = 200, description = "List of transactions matching status, payment method, amount range, merchant, card and date range", body = ApiResponsePagination<Vec<TransactionResponse>>),
+        (status = 400, description = "Invalid filter or sort parameters"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn search_transactions(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<SearchTransactions>,
+) -> Result<impl IntoResponse, HttpError> {
+    let transaction_client = &app_state.di_container.transaction_clients;
+
+    match transaction_client.search(&params).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/transactions/active",
@@ -142,6 +167,7 @@ pub async fn get_trashed_transactions(
     params(("id" = i32, Path, description = "Transaction ID")),
     responses(
         (status = 200, description = "Transaction details", body = ApiResponse<TransactionResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Transaction not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -149,11 +175,12 @@ pub async fn get_trashed_transactions(
 pub async fn get_transaction(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
     match transaction_client.find_by_id(id).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Ok(response) => Ok(etag_response(&headers, response)),
         Err(err) => Err(err),
     }
 }
@@ -252,29 +279,9 @@ pub async fn update_transaction(
 pub async fn trash_transaction_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.trashed(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -296,29 +303,9 @@ pub async fn trash_transaction_handler(
 pub async fn restore_transaction_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.restore(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -340,29 +327,9 @@ pub async fn restore_transaction_handler(
 pub async fn delete_transaction(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.delete_permanent(id).await {
         Ok(_) => Ok((
             StatusCode::OK,
@@ -381,41 +348,22 @@ pub async fn delete_transaction(
     tag = "Transaction",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed transactions restored", body = serde_json::Value),
+        (status = 200, description = "All trashed transactions restored", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn restore_all_transaction_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.restore_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All transactions restored successfully"
+               "message": "All transactions restored successfully",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -428,41 +376,22 @@ pub async fn restore_all_transaction_handler(
     tag = "Transaction",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed transactions permanently deleted", body = serde_json::Value),
+        (status = 200, description = "All trashed transactions permanently deleted", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn delete_all_transaction_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.delete_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All trashed transactions deleted permanently"
+               "message": "All trashed transactions deleted permanently",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -484,29 +413,9 @@ pub async fn delete_all_transaction_handler(
 pub async fn get_monthly_amounts(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_monthly_amounts(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -528,29 +437,9 @@ pub async fn get_monthly_amounts(
 pub async fn get_yearly_amounts(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_yearly_amounts(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -572,29 +461,9 @@ pub async fn get_yearly_amounts(
 pub async fn get_monthly_method(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_monthly_method(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -616,29 +485,9 @@ pub async fn get_monthly_method(
 pub async fn get_yearly_method(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_yearly_method(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -660,29 +509,9 @@ pub async fn get_yearly_method(
 pub async fn get_month_status_success(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusTransaction>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_month_status_success(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -704,29 +533,9 @@ pub async fn get_month_status_success(
 pub async fn get_yearly_status_success(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_yearly_status_success(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -748,29 +557,9 @@ pub async fn get_yearly_status_success(
 pub async fn get_month_status_failed(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusTransaction>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_month_status_failed(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -792,29 +581,9 @@ pub async fn get_month_status_failed(
 pub async fn get_yearly_status_failed(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_yearly_status_failed(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -836,29 +605,9 @@ pub async fn get_yearly_status_failed(
 pub async fn get_monthly_amounts_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearPaymentMethod>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_monthly_amounts_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -880,29 +629,9 @@ pub async fn get_monthly_amounts_by_card(
 pub async fn get_yearly_amounts_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearPaymentMethod>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_yearly_amounts_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -924,29 +653,9 @@ pub async fn get_yearly_amounts_by_card(
 pub async fn get_monthly_method_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearPaymentMethod>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_monthly_method_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -968,29 +677,9 @@ pub async fn get_monthly_method_by_card(
 pub async fn get_yearly_method_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearPaymentMethod>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client.get_yearly_method_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -1012,29 +701,9 @@ pub async fn get_yearly_method_by_card(
 pub async fn get_month_status_success_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusTransactionCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client
         .get_month_status_success_bycard(&params)
         .await
@@ -1059,29 +728,9 @@ pub async fn get_month_status_success_by_card(
 pub async fn get_yearly_status_success_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearStatusTransactionCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client
         .get_yearly_status_success_bycard(&params)
         .await
@@ -1106,29 +755,9 @@ pub async fn get_yearly_status_success_by_card(
 pub async fn get_month_status_failed_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusTransactionCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client
         .get_month_status_failed_bycard(&params)
         .await
@@ -1153,29 +782,9 @@ pub async fn get_month_status_failed_by_card(
 pub async fn get_yearly_status_failed_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearStatusTransactionCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transaction_client = &app_state.di_container.transaction_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transaction_client
         .get_yearly_status_failed_bycard(&params)
         .await
@@ -1192,6 +801,7 @@ pub fn transaction_routes(state: Arc<AppState>) -> OpenApiRouter {
             "/api/transactions/by-card",
             get(get_transactions_by_card_number),
         )
+        .route("/api/transactions/search", get(search_transactions))
         .route("/api/transactions/active", get(get_active_transactions))
         .route("/api/transactions/trashed", get(get_trashed_transactions))
         .route("/api/transactions/{id}", get(get_transaction))
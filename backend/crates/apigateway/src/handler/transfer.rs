@@ -1,32 +1,34 @@
 use crate::{
     middleware::{
-        circuit_breaker::circuit_breaker_middleware, jwt, rate_limit::rate_limit_middleware,
-        request_limiter::request_limiter_middleware, session::session_middleware,
-        validate::SimpleValidatedJson,
+        circuit_breaker::circuit_breaker_middleware, etag::etag_response, jwt,
+        rate_limit::rate_limit_middleware, request_limiter::request_limiter_middleware,
+        session::session_middleware, validate::SimpleValidatedJson,
     },
     state::AppState,
 };
 use axum::{
     Json,
     extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post, put},
 };
 use serde_json::json;
 use shared::{
+    config::Claims,
     domain::{
         requests::{
             transfer::{
-                CreateTransferRequest, FindAllTransfers, MonthStatusTransfer,
-                MonthStatusTransferCardNumber, MonthYearCardNumber, UpdateTransferRequest,
-                YearStatusTransferCardNumber,
+                CreateTransferRequest, FindAllTransfers, FindTransferNetPosition, FindTransfers,
+                MonthStatusTransfer, MonthStatusTransferCardNumber, MonthYearCardNumber,
+                UpdateTransferRequest, YearStatusTransferCardNumber,
             },
             withdraw::YearQuery,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, TransferMonthAmountResponse, TransferResponse,
+            ApiResponse, ApiResponsePagination, BalanceImpactResponse, BulkResult,
+            TransferMonthAmountResponse, TransferNetPositionResponse, TransferResponse,
             TransferResponseDeleteAt, TransferResponseMonthStatusFailed,
             TransferResponseMonthStatusSuccess, TransferResponseYearStatusFailed,
             TransferResponseYearStatusSuccess, TransferYearAmountResponse,
@@ -69,6 +71,7 @@ pub async fn get_transfers(
     params(("id" = i32, Path, description = "Transfer ID")),
     responses(
         (status = 200, description = "Transfer details", body = ApiResponse<TransferResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Transfer not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -76,11 +79,12 @@ pub async fn get_transfers(
 pub async fn get_transfer(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
     match transfer_client.find_by_id(id).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Ok(response) => Ok(etag_response(&headers, response)),
         Err(err) => Err(err),
     }
 }
@@ -133,6 +137,56 @@ pub async fn get_trashed_transfers(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/transfers/search",
+    tag = "Transfer",
+    security(("bearer_auth" = [])),
+    params(FindTransfers),
+    responses(
+        (status = 200, description = "List of transfers matching sender, receiver, status and date range", body = ApiResponsePagination<Vec<TransferResponse>>),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn find_transfers(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<FindTransfers>,
+) -> Result<impl IntoResponse, HttpError> {
+    let transfer_client = &app_state.di_container.transfer_clients;
+
+    match transfer_client.find_transfers(&params).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/transfers/net-position",
+    tag = "Transfer",
+    security(("bearer_auth" = [])),
+    params(FindTransferNetPosition),
+    responses(
+        (status = 200, description = "Net transfer flow between two cards", body = ApiResponse<TransferNetPositionResponse>),
+        (status = 400, description = "Invalid cards or date range"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn get_transfer_net_position(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<FindTransferNetPosition>,
+) -> Result<impl IntoResponse, HttpError> {
+    let transfer_client = &app_state.di_container.transfer_clients;
+
+    match transfer_client.net_position(&params).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/transfers/from/{transfer_from}",
@@ -181,6 +235,32 @@ pub async fn get_transfers_by_transfer_to(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/transfers/preview",
+    tag = "Transfer",
+    security(("bearer_auth" = [])),
+    request_body = CreateTransferRequest,
+    responses(
+        (status = 200, description = "Projected balance impact of the transfer", body = ApiResponse<BalanceImpactResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Card not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn preview_transfer(
+    State(app_state): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<CreateTransferRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    let transfer_client = &app_state.di_container.transfer_clients;
+
+    match transfer_client.preview(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/api/transfers/create",
@@ -190,14 +270,25 @@ pub async fn get_transfers_by_transfer_to(
     responses(
         (status = 201, description = "Transfer created", body = ApiResponse<TransferResponse>),
         (status = 400, description = "Validation error"),
-        (status = 401, description = "Unauthorized"),
+        (status = 401, description = "Unauthorized or re-authentication required"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn create_transfer(
     State(app_state): State<Arc<AppState>>,
+    Extension(claims): Extension<Claims>,
     SimpleValidatedJson(body): SimpleValidatedJson<CreateTransferRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
+    if app_state
+        .reauth
+        .requires_reauth_for_transfer(body.transfer_amount)
+        && !app_state.reauth.is_recent(&claims)
+    {
+        return Err(HttpError::Unauthorized(
+            "Re-authentication required".to_string(),
+        ));
+    }
+
     let transfer_client = &app_state.di_container.transfer_clients;
 
     match transfer_client.create(&body).await {
@@ -216,15 +307,26 @@ pub async fn create_transfer(
     responses(
         (status = 200, description = "Transfer updated", body = ApiResponse<TransferResponse>),
         (status = 404, description = "Transfer not found"),
-        (status = 401, description = "Unauthorized"),
+        (status = 401, description = "Unauthorized or re-authentication required"),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn update_transfer(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
+    Extension(claims): Extension<Claims>,
     SimpleValidatedJson(mut body): SimpleValidatedJson<UpdateTransferRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
+    if app_state
+        .reauth
+        .requires_reauth_for_transfer(body.transfer_amount)
+        && !app_state.reauth.is_recent(&claims)
+    {
+        return Err(HttpError::Unauthorized(
+            "Re-authentication required".to_string(),
+        ));
+    }
+
     let transfer_client = &app_state.di_container.transfer_clients;
 
     body.transfer_id = Some(id);
@@ -249,29 +351,9 @@ pub async fn update_transfer(
 pub async fn trash_transfer_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.trashed(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -293,29 +375,9 @@ pub async fn trash_transfer_handler(
 pub async fn restore_transfer_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.restore(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -337,29 +399,9 @@ pub async fn restore_transfer_handler(
 pub async fn delete_transfer(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.delete_permanent(id).await {
         Ok(_) => Ok((
             StatusCode::OK,
@@ -378,41 +420,22 @@ pub async fn delete_transfer(
     tag = "Transfer",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed transfers restored", body = serde_json::Value),
+        (status = 200, description = "All trashed transfers restored", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn restore_all_transfer_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.restore_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All transfers restored successfully"
+               "message": "All transfers restored successfully",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -425,41 +448,22 @@ pub async fn restore_all_transfer_handler(
     tag = "Transfer",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed transfers permanently deleted", body = serde_json::Value),
+        (status = 200, description = "All trashed transfers permanently deleted", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn delete_all_transfer_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.delete_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All trashed transfers deleted permanently"
+               "message": "All trashed transfers deleted permanently",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -481,29 +485,9 @@ pub async fn delete_all_transfer_handler(
 pub async fn get_monthly_amounts(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.get_monthly_amounts(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -525,29 +509,9 @@ pub async fn get_monthly_amounts(
 pub async fn get_yearly_amounts(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.get_yearly_amounts(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -569,29 +533,9 @@ pub async fn get_yearly_amounts(
 pub async fn get_month_status_success(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusTransfer>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.get_month_status_success(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -613,29 +557,9 @@ pub async fn get_month_status_success(
 pub async fn get_yearly_status_success(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.get_yearly_status_success(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -657,29 +581,9 @@ pub async fn get_yearly_status_success(
 pub async fn get_month_status_failed(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusTransfer>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.get_month_status_failed(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -701,29 +605,9 @@ pub async fn get_month_status_failed(
 pub async fn get_yearly_status_failed(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client.get_yearly_status_failed(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -745,29 +629,9 @@ pub async fn get_yearly_status_failed(
 pub async fn get_monthly_amounts_by_sender(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client
         .get_monthly_amounts_sender_bycard(&params)
         .await
@@ -792,29 +656,9 @@ pub async fn get_monthly_amounts_by_sender(
 pub async fn get_monthly_amounts_by_receiver(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client
         .get_monthly_amounts_receiver_bycard(&params)
         .await
@@ -839,29 +683,9 @@ pub async fn get_monthly_amounts_by_receiver(
 pub async fn get_yearly_amounts_by_sender(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client
         .get_yearly_amounts_sender_bycard(&params)
         .await
@@ -886,29 +710,9 @@ pub async fn get_yearly_amounts_by_sender(
 pub async fn get_yearly_amounts_by_receiver(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthYearCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client
         .get_yearly_amounts_receiver_bycard(&params)
         .await
@@ -933,29 +737,9 @@ pub async fn get_yearly_amounts_by_receiver(
 pub async fn get_month_status_success_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusTransferCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client
         .get_month_status_success_by_card(&params)
         .await
@@ -980,29 +764,9 @@ pub async fn get_month_status_success_by_card(
 pub async fn get_yearly_status_success_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearStatusTransferCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client
         .get_yearly_status_success_by_card(&params)
         .await
@@ -1027,29 +791,9 @@ pub async fn get_yearly_status_success_by_card(
 pub async fn get_month_status_failed_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusTransferCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client
         .get_month_status_failed_by_card(&params)
         .await
@@ -1074,29 +818,9 @@ pub async fn get_month_status_failed_by_card(
 pub async fn get_yearly_status_failed_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearStatusTransferCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let transfer_client = &app_state.di_container.transfer_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match transfer_client
         .get_yearly_status_failed_by_card(&params)
         .await
@@ -1111,6 +835,11 @@ pub fn transfer_routes(state: Arc<AppState>) -> OpenApiRouter {
         .route("/api/transfers/{id}", get(get_transfer))
         .route("/api/transfers/active", get(get_active_transfers))
         .route("/api/transfers/trashed", get(get_trashed_transfers))
+        .route("/api/transfers/search", get(find_transfers))
+        .route(
+            "/api/transfers/net-position",
+            get(get_transfer_net_position),
+        )
         .route(
             "/api/transfers/from/{transfer_from}",
             get(get_transfers_by_transfer_from),
@@ -1120,6 +849,7 @@ pub fn transfer_routes(state: Arc<AppState>) -> OpenApiRouter {
             get(get_transfers_by_transfer_to),
         )
         .route("/api/transfers/create", post(create_transfer))
+        .route("/api/transfers/preview", post(preview_transfer))
         .route("/api/transfers/update/{id}", post(update_transfer))
         .route("/api/transfers/trash/{id}", delete(trash_transfer_handler))
         .route(
@@ -1,7 +1,7 @@
 use crate::middleware::session::session_middleware;
 use crate::{
     middleware::{
-        circuit_breaker::circuit_breaker_middleware, jwt,
+        circuit_breaker::circuit_breaker_middleware, etag::etag_response, jwt,
         request_limiter::request_limiter_middleware, validate::SimpleValidatedJson,
     },
     state::AppState,
@@ -9,7 +9,7 @@ use crate::{
 use axum::{
     Json,
     extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post},
@@ -17,8 +17,13 @@ use axum::{
 use serde_json::json;
 use shared::{
     domain::{
-        requests::user::{CreateUserRequest, FindAllUserRequest, UpdateUserRequest},
-        responses::{ApiResponse, ApiResponsePagination, UserResponse, UserResponseDeleteAt},
+        requests::user::{
+            CreateUserRequest, FindAllUserRequest, MergeUsersRequest, UpdateUserRequest,
+        },
+        responses::{
+            ApiResponse, ApiResponsePagination, BulkResult, UserDataExportResponse,
+            UserMergeResponse, UserResponse, UserResponseDeleteAt,
+        },
     },
     errors::HttpError,
 };
@@ -57,6 +62,7 @@ pub async fn get_users(
     params(("id" = i32, Path, description = "User ID")),
     responses(
         (status = 200, description = "User details", body = ApiResponse<UserResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "User not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -64,10 +70,66 @@ pub async fn get_users(
 pub async fn get_user(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let user_client = &app_state.di_container.user_clients;
 
     match user_client.find_by_id(id).await {
+        Ok(response) => Ok(etag_response(&headers, response)),
+        Err(err) => Err(err),
+    }
+}
+
+async fn ensure_self_or_admin(
+    app_state: &AppState,
+    user_id: i32,
+    target_id: i32,
+) -> Result<(), HttpError> {
+    if user_id == target_id {
+        return Ok(());
+    }
+
+    let key = format!("session:{user_id}");
+
+    let current_session = app_state
+        .session
+        .get_session(&key)
+        .await
+        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
+
+    if !current_session.roles.iter().any(|r| r == "ROLE_ADMIN") {
+        return Err(HttpError::Forbidden(
+            "Access denied. Only the account owner or an ADMIN can access this resource"
+                .to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+#[utoipa::path(
+    get,
+    path = "/api/users/{id}/export",
+    tag = "User",
+    security(("bearer_auth" = [])),
+    params(("id" = i32, Path, description = "User ID")),
+    responses(
+        (status = 200, description = "Personal data export for the user", body = ApiResponse<UserDataExportResponse>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn export_user_data(
+    State(app_state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    let user_client = &app_state.di_container.user_clients;
+
+    ensure_self_or_admin(&app_state, user_id, id).await?;
+
+    match user_client.export_user_data(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
     }
@@ -136,31 +198,11 @@ pub async fn get_trashed_users(
 )]
 pub async fn create_user(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 
     SimpleValidatedJson(body): SimpleValidatedJson<CreateUserRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
     let user_client = &app_state.di_container.user_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match user_client.create(&body).await {
         Ok(response) => Ok((StatusCode::CREATED, Json(response))),
         Err(err) => Err(err),
@@ -184,30 +226,10 @@ pub async fn create_user(
 pub async fn update_user(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
     SimpleValidatedJson(mut body): SimpleValidatedJson<UpdateUserRequest>,
 ) -> Result<impl IntoResponse, HttpError> {
     let user_client = &app_state.di_container.user_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     body.id = Some(id);
     match user_client.update(&body).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
@@ -230,30 +252,38 @@ pub async fn update_user(
 pub async fn trash_user_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let user_client = &app_state.di_container.user_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
+    match user_client.trashed(id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
 
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
+#[utoipa::path(
+    post,
+    path = "/api/users/anonymize/{id}",
+    tag = "User",
+    security(("bearer_auth" = [])),
+    params(("id" = i32, Path, description = "User ID")),
+    responses(
+        (status = 200, description = "User anonymized", body = ApiResponse<UserResponseDeleteAt>),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn anonymize_user_handler(
+    State(app_state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+    Extension(user_id): Extension<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    let user_client = &app_state.di_container.user_clients;
 
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
+    ensure_self_or_admin(&app_state, user_id, id).await?;
 
-    match user_client.trashed(id).await {
+    match user_client.anonymize(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
     }
@@ -274,30 +304,42 @@ pub async fn trash_user_handler(
 pub async fn restore_user_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let user_client = &app_state.di_container.user_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
+    match user_client.restore(id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
 
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
+#[utoipa::path(
+    post,
+    path = "/api/users/merge",
+    tag = "User",
+    security(("bearer_auth" = [])),
+    request_body = MergeUsersRequest,
+    responses(
+        (status = 200, description = "Duplicate user merged into the primary user", body = ApiResponse<UserMergeResponse>),
+        (status = 400, description = "Invalid merge request"),
+        (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Forbidden"),
+        (status = 404, description = "User not found")
+    )
+)]
+pub async fn merge_users_handler(
+    State(app_state): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<MergeUsersRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    let user_client = &app_state.di_container.user_clients;
 
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
+    if body.primary_id == body.duplicate_id {
+        return Err(HttpError::BadRequest(
+            "primary_id and duplicate_id must be different users".to_string(),
         ));
     }
 
-    match user_client.restore(id).await {
+    match user_client.merge(&body).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
     }
@@ -318,29 +360,9 @@ pub async fn restore_user_handler(
 pub async fn delete_user(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let user_client = &app_state.di_container.user_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match user_client.delete_permanent(id).await {
         Ok(_) => Ok((
             StatusCode::OK,
@@ -359,41 +381,22 @@ pub async fn delete_user(
     tag = "User",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed users restored", body = serde_json::Value),
+        (status = 200, description = "All trashed users restored", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn restore_all_user_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let user_client = &app_state.di_container.user_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match user_client.restore_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All users restored successfully"
+               "message": "All users restored successfully",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -406,41 +409,22 @@ pub async fn restore_all_user_handler(
     tag = "User",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed users permanently deleted", body = serde_json::Value),
+        (status = 200, description = "All trashed users permanently deleted", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn delete_all_user_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let user_client = &app_state.di_container.user_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match user_client.delete_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All trashed users deleted permanently"
+               "message": "All trashed users deleted permanently",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -451,12 +435,15 @@ pub fn user_routes(state: Arc<AppState>) -> OpenApiRouter {
     OpenApiRouter::new()
         .route("/api/users", get(get_users))
         .route("/api/users/{id}", get(get_user))
+        .route("/api/users/{id}/export", get(export_user_data))
         .route("/api/users/active", get(get_active_users))
         .route("/api/users/trashed", get(get_trashed_users))
         .route("/api/users/create", post(create_user))
         .route("/api/users/update/{id}", post(update_user))
         .route("/api/users/trash/{id}", post(trash_user_handler))
         .route("/api/users/restore/{id}", post(restore_user_handler))
+        .route("/api/users/anonymize/{id}", post(anonymize_user_handler))
+        .route("/api/users/merge", post(merge_users_handler))
         .route("/api/users/delete/{id}", delete(delete_user))
         .route("/api/users/restore-all", post(restore_all_user_handler))
         .route("/api/users/delete-all", post(delete_all_user_handler))
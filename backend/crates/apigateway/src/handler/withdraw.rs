@@ -1,15 +1,15 @@
 use crate::{
     middleware::{
-        circuit_breaker::circuit_breaker_middleware, jwt, rate_limit::rate_limit_middleware,
-        request_limiter::request_limiter_middleware, session::session_middleware,
-        validate::SimpleValidatedJson,
+        circuit_breaker::circuit_breaker_middleware, etag::etag_response, jwt,
+        rate_limit::rate_limit_middleware, request_limiter::request_limiter_middleware,
+        session::session_middleware, validate::SimpleValidatedJson,
     },
     state::AppState,
 };
 use axum::{
     Json,
     extract::{Extension, Path, Query, State},
-    http::StatusCode,
+    http::{HeaderMap, StatusCode},
     middleware,
     response::IntoResponse,
     routing::{delete, get, post},
@@ -18,15 +18,16 @@ use serde_json::json;
 use shared::{
     domain::{
         requests::withdraw::{
-            CreateWithdrawRequest, FindAllWithdrawCardNumber, FindAllWithdraws,
+            CreateWithdrawRequest, FindAllWithdrawCardNumber, FindAllWithdraws, FindWithdraws,
             MonthStatusWithdraw, MonthStatusWithdrawCardNumber, UpdateWithdrawRequest,
             YearMonthCardNumber, YearQuery, YearStatusWithdrawCardNumber,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, WithdrawMonthlyAmountResponse, WithdrawResponse,
-            WithdrawResponseDeleteAt, WithdrawResponseMonthStatusFailed,
-            WithdrawResponseMonthStatusSuccess, WithdrawResponseYearStatusFailed,
-            WithdrawResponseYearStatusSuccess, WithdrawYearlyAmountResponse,
+            ApiResponse, ApiResponsePagination, BalanceImpactResponse, BulkResult,
+            WithdrawMonthlyAmountResponse, WithdrawResponse, WithdrawResponseDeleteAt,
+            WithdrawResponseMonthStatusFailed, WithdrawResponseMonthStatusSuccess,
+            WithdrawResponseYearStatusFailed, WithdrawResponseYearStatusSuccess,
+            WithdrawYearlyAmountResponse,
         },
     },
     errors::HttpError,
@@ -82,6 +83,31 @@ pub async fn get_withdraws_by_card_number(
     }
 }
 
+#[utoipa::path(
+    get,
+    path = "/api/withdraws/filter",
+    tag = "Withdraw",
+    security(("bearer_auth" = [])),
+    params(FindWithdraws),
+    responses(
+        (status = 200, description = "List of withdraws matching status, card number and date range", body = ApiResponsePagination<Vec<WithdrawResponse>>),
+        (status = 400, description = "Invalid date range or sort parameters"),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn find_withdraws(
+    State(app_state): State<Arc<AppState>>,
+    Query(params): Query<FindWithdraws>,
+) -> Result<impl IntoResponse, HttpError> {
+    let withdraw_client = &app_state.di_container.withdraw_clients;
+
+    match withdraw_client.find_withdraws(&params).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
 #[utoipa::path(
     get,
     path = "/api/withdraws/{id}",
@@ -90,6 +116,7 @@ pub async fn get_withdraws_by_card_number(
     params(("id" = i32, Path, description = "Withdraw ID")),
     responses(
         (status = 200, description = "Withdraw details", body = ApiResponse<WithdrawResponse>),
+        (status = 304, description = "Not modified"),
         (status = 404, description = "Withdraw not found"),
         (status = 401, description = "Unauthorized")
     )
@@ -97,11 +124,12 @@ pub async fn get_withdraws_by_card_number(
 pub async fn get_withdraw(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
+    headers: HeaderMap,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
     match withdraw_client.find_by_id(id).await {
-        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Ok(response) => Ok(etag_response(&headers, response)),
         Err(err) => Err(err),
     }
 }
@@ -154,6 +182,32 @@ pub async fn get_trashed_withdraws(
     }
 }
 
+#[utoipa::path(
+    post,
+    path = "/api/withdraws/preview",
+    tag = "Withdraw",
+    security(("bearer_auth" = [])),
+    request_body = CreateWithdrawRequest,
+    responses(
+        (status = 200, description = "Projected balance impact of the withdraw", body = ApiResponse<BalanceImpactResponse>),
+        (status = 400, description = "Validation error"),
+        (status = 401, description = "Unauthorized"),
+        (status = 404, description = "Card not found"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn preview_withdraw(
+    State(app_state): State<Arc<AppState>>,
+    SimpleValidatedJson(body): SimpleValidatedJson<CreateWithdrawRequest>,
+) -> Result<impl IntoResponse, HttpError> {
+    let withdraw_client = &app_state.di_container.withdraw_clients;
+
+    match withdraw_client.preview(&body).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
 #[utoipa::path(
     post,
     path = "/api/withdraws/create",
@@ -209,42 +263,70 @@ pub async fn update_withdraw(
 
 #[utoipa::path(
     post,
-    path = "/api/withdraws/trash/{id}",
+    path = "/api/withdraws/cancel/{id}",
     tag = "Withdraw",
     security(("bearer_auth" = [])),
     params(("id" = i32, Path, description = "Withdraw ID")),
     responses(
-        (status = 200, description = "Withdraw soft-deleted", body = ApiResponse<WithdrawResponseDeleteAt>),
+        (status = 200, description = "Withdraw cancelled and balance credited back", body = ApiResponse<WithdrawResponse>),
         (status = 401, description = "Unauthorized"),
+        (status = 403, description = "Cancellation window has passed or withdraw cannot be cancelled"),
+        (status = 404, description = "Withdraw not found"),
         (status = 500, description = "Internal server error")
     )
 )]
-pub async fn trash_withdraw_handler(
+pub async fn cancel_withdraw_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
     Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
     let key = format!("session:{user_id}");
 
-    let current_session = session
+    let current_session = app_state
+        .session
         .get_session(&key)
         .await
         .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
 
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
+    if !current_session.roles.iter().any(|r| r == "ROLE_ADMIN") {
+        let withdraw = withdraw_client.find_by_id(id).await?;
+        let card = app_state
+            .di_container
+            .card_clients
+            .find_by_card_number(withdraw.data.card_number)
+            .await?;
+
+        if card.data.user_id != user_id {
+            return Err(HttpError::NotFound(format!("Withdraw {id} not found")));
+        }
     }
 
+    match withdraw_client.cancel_withdraw(id).await {
+        Ok(response) => Ok((StatusCode::OK, Json(response))),
+        Err(err) => Err(err),
+    }
+}
+
+#[utoipa::path(
+    post,
+    path = "/api/withdraws/trash/{id}",
+    tag = "Withdraw",
+    security(("bearer_auth" = [])),
+    params(("id" = i32, Path, description = "Withdraw ID")),
+    responses(
+        (status = 200, description = "Withdraw soft-deleted", body = ApiResponse<WithdrawResponseDeleteAt>),
+        (status = 401, description = "Unauthorized"),
+        (status = 500, description = "Internal server error")
+    )
+)]
+pub async fn trash_withdraw_handler(
+    State(app_state): State<Arc<AppState>>,
+    Path(id): Path<i32>,
+) -> Result<impl IntoResponse, HttpError> {
+    let withdraw_client = &app_state.di_container.withdraw_clients;
+
     match withdraw_client.trashed_withdraw(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -266,29 +348,9 @@ pub async fn trash_withdraw_handler(
 pub async fn restore_withdraw_handler(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.restore(id).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -310,29 +372,9 @@ pub async fn restore_withdraw_handler(
 pub async fn delete_withdraw(
     State(app_state): State<Arc<AppState>>,
     Path(id): Path<i32>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.delete_permanent(id).await {
         Ok(_) => Ok((
             StatusCode::OK,
@@ -351,41 +393,22 @@ pub async fn delete_withdraw(
     tag = "Withdraw",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed withdraws restored", body = serde_json::Value),
+        (status = 200, description = "All trashed withdraws restored", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn restore_all_withdraw_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.restore_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All withdraws restored successfully"
+               "message": "All withdraws restored successfully",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -398,41 +421,22 @@ pub async fn restore_all_withdraw_handler(
     tag = "Withdraw",
     security(("bearer_auth" = [])),
     responses(
-        (status = 200, description = "All trashed withdraws permanently deleted", body = serde_json::Value),
+        (status = 200, description = "All trashed withdraws permanently deleted", body = ApiResponse<BulkResult>),
         (status = 500, description = "Internal server error")
     )
 )]
 pub async fn delete_all_withdraw_handler(
     State(app_state): State<Arc<AppState>>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.delete_all().await {
-        Ok(_) => Ok((
+        Ok(response) => Ok((
             StatusCode::OK,
             Json(json!({
                "status": "success",
-               "message": "All trashed withdraws deleted permanently"
+               "message": "All trashed withdraws deleted permanently",
+               "data": response.data
             })),
         )),
         Err(err) => Err(err),
@@ -454,29 +458,9 @@ pub async fn delete_all_withdraw_handler(
 pub async fn get_monthly_withdraws(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.get_monthly_withdraws(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -498,29 +482,9 @@ pub async fn get_monthly_withdraws(
 pub async fn get_yearly_withdraws(
     State(app_state): State<Arc<AppState>>,
     Query(query): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.get_yearly_withdraws(query.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -542,29 +506,9 @@ pub async fn get_yearly_withdraws(
 pub async fn get_month_status_success(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusWithdraw>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.get_month_status_success(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -586,29 +530,9 @@ pub async fn get_month_status_success(
 pub async fn get_yearly_status_success(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.get_yearly_status_success(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -630,29 +554,9 @@ pub async fn get_yearly_status_success(
 pub async fn get_month_status_failed(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusWithdraw>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.get_month_status_failed(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -674,29 +578,9 @@ pub async fn get_month_status_failed(
 pub async fn get_yearly_status_failed(
     State(app_state): State<Arc<AppState>>,
     Query(req): Query<YearQuery>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.get_yearly_status_failed(req.year).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -718,29 +602,9 @@ pub async fn get_yearly_status_failed(
 pub async fn get_monthly_by_card_number(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearMonthCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.get_monthly_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -762,29 +626,9 @@ pub async fn get_monthly_by_card_number(
 pub async fn get_yearly_by_card_number(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearMonthCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client.get_yearly_bycard(&params).await {
         Ok(response) => Ok((StatusCode::OK, Json(response))),
         Err(err) => Err(err),
@@ -806,29 +650,9 @@ pub async fn get_yearly_by_card_number(
 pub async fn get_month_status_success_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusWithdrawCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client
         .get_month_status_success_bycard(&params)
         .await
@@ -853,29 +677,9 @@ pub async fn get_month_status_success_by_card(
 pub async fn get_yearly_status_success_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearStatusWithdrawCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client
         .get_yearly_status_success_bycard(&params)
         .await
@@ -900,29 +704,9 @@ pub async fn get_yearly_status_success_by_card(
 pub async fn get_month_status_failed_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<MonthStatusWithdrawCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client
         .get_month_status_failed_bycard(&params)
         .await
@@ -947,29 +731,9 @@ pub async fn get_month_status_failed_by_card(
 pub async fn get_yearly_status_failed_by_card(
     State(app_state): State<Arc<AppState>>,
     Query(params): Query<YearStatusWithdrawCardNumber>,
-    Extension(user_id): Extension<i32>,
 ) -> Result<impl IntoResponse, HttpError> {
     let withdraw_client = &app_state.di_container.withdraw_clients;
 
-    let session = &app_state.session;
-
-    let key = format!("session:{user_id}");
-
-    let current_session = session
-        .get_session(&key)
-        .await
-        .ok_or_else(|| HttpError::Unauthorized("Session expired or not found".to_string()))?;
-
-    if !current_session
-        .roles
-        .iter()
-        .any(|r| r == "ROLE_ADMIN" || r == "ROLE_MODERATOR")
-    {
-        return Err(HttpError::Forbidden(
-            "Access denied. Required role: ADMIN or MODERATOR".to_string(),
-        ));
-    }
-
     match withdraw_client
         .get_yearly_status_failed_bycard(&params)
         .await
@@ -983,11 +747,14 @@ pub fn withdraw_routes(state: Arc<AppState>) -> OpenApiRouter {
     OpenApiRouter::new()
         .route("/api/withdraws", get(get_withdraws))
         .route("/api/withdraws/by-card", get(get_withdraws_by_card_number))
+        .route("/api/withdraws/filter", get(find_withdraws))
         .route("/api/withdraws/{id}", get(get_withdraw))
         .route("/api/withdraws/active", get(get_active_withdraws))
         .route("/api/withdraws/trashed", get(get_trashed_withdraws))
         .route("/api/withdraws/create", post(create_withdraw))
+        .route("/api/withdraws/preview", post(preview_withdraw))
         .route("/api/withdraws/update/{id}", post(update_withdraw))
+        .route("/api/withdraws/cancel/{id}", post(cancel_withdraw_handler))
         .route("/api/withdraws/trash/{id}", post(trash_withdraw_handler))
         .route(
             "/api/withdraws/restore/{id}",
@@ -24,7 +24,9 @@ async fn main() -> Result<()> {
         .map(|v| v == "true")
         .unwrap_or(false);
 
-    let telemetry = Telemetry::new("apigateway", "http://otel-collector:4317".to_string());
+    let config = Config::init().context("Failed to load configuration")?;
+
+    let telemetry = Telemetry::new("apigateway", config.telemetry.clone());
 
     let logger_provider = telemetry.init_logger();
 
@@ -38,11 +40,9 @@ async fn main() -> Result<()> {
         is_enable_file,
     );
 
-    let config = Config::init().context("Failed to load configuration")?;
-
     let port = config.port;
 
-    let state = AppState::new(&config.jwt_secret)
+    let state = AppState::new(&config)
         .await
         .context("Failed to create AppState")?;
 
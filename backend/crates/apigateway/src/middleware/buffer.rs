@@ -0,0 +1,44 @@
+use axum::{
+    body::{Body, Bytes, HttpBody, to_bytes},
+    http::{header, response::Parts},
+    response::Response,
+};
+use tracing::warn;
+
+/// Largest response body the rewriting middlewares will buffer.
+pub const MAX_BUFFERED_BODY_BYTES: usize = 2 * 1024 * 1024;
+
+/// Buffers a JSON response so a middleware can rewrite it.
+///
+/// Responses that are not JSON, or whose size is unknown or above
+/// [`MAX_BUFFERED_BODY_BYTES`], come back untouched in `Err` without their
+/// body being read.
+pub async fn buffer_json(response: Response, purpose: &str) -> Result<(Parts, Bytes), Response> {
+    let is_json = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.starts_with("application/json"));
+
+    let fits = response
+        .body()
+        .size_hint()
+        .upper()
+        .is_some_and(|size| size <= MAX_BUFFERED_BODY_BYTES as u64);
+
+    if !is_json || !fits {
+        return Err(response);
+    }
+
+    let (parts, body) = response.into_parts();
+
+    match to_bytes(body, MAX_BUFFERED_BODY_BYTES).await {
+        Ok(bytes) => Ok((parts, bytes)),
+        Err(e) => {
+            // The size was checked up front, so this is the body stream itself
+            // failing; nothing of it is left to pass on.
+            warn!("Failed to buffer response for {purpose}: {e:?}");
+            Err(Response::from_parts(parts, Body::empty()))
+        }
+    }
+}
@@ -3,11 +3,15 @@ use axum::{
     Json,
     body::Body,
     extract::State,
-    http::{Request, StatusCode},
+    http::{HeaderValue, Request, StatusCode, header},
     middleware::Next,
     response::Response,
 };
-use shared::{errors::ErrorResponse, utils::get_trace_id};
+use shared::{
+    errors::{ErrorResponse, LoadShed},
+    resilience::GatewayServiceLimiter,
+    utils::get_trace_id,
+};
 use std::sync::Arc;
 use tracing::warn;
 
@@ -15,14 +19,26 @@ pub async fn circuit_breaker_middleware(
     State(app_state): State<Arc<AppState>>,
     req: Request<Body>,
     next: Next,
-) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<
+    Response,
+    (
+        StatusCode,
+        [(header::HeaderName, String); 1],
+        Json<ErrorResponse>,
+    ),
+> {
     let circuit_breaker = &app_state.circuit_breaker;
     let trace_id = get_trace_id();
 
     if !circuit_breaker.should_allow_request().await {
         warn!("🔴 Request rejected by circuit breaker");
+        let retry_after = app_state
+            .retry_after
+            .header_value(circuit_breaker.reset_timeout().as_secs());
+
         return Err((
             StatusCode::SERVICE_UNAVAILABLE,
+            [(header::RETRY_AFTER, retry_after)],
             Json(ErrorResponse {
                 status: "error".to_string(),
                 message: "Service temporarily unavailable due to high error rate. Please try again later.".to_string(),
@@ -31,15 +47,55 @@ pub async fn circuit_breaker_middleware(
         ));
     }
 
-    let response = next.run(req).await;
+    let service_breaker = GatewayServiceLimiter::service_for_path(req.uri().path())
+        .and_then(|service| Some((service, app_state.service_breakers.get(service)?)));
+
+    if let Some((service, breaker)) = service_breaker
+        && !breaker.should_allow_request().await
+    {
+        warn!("🔴 Request rejected by {service} circuit breaker");
+        let retry_after = app_state
+            .retry_after
+            .header_value(breaker.reset_timeout().as_secs());
+
+        return Err((
+            StatusCode::SERVICE_UNAVAILABLE,
+            [(header::RETRY_AFTER, retry_after)],
+            Json(ErrorResponse {
+                status: "error".to_string(),
+                message: format!(
+                    "The {service} service is temporarily unavailable due to high error rate. Please try again later."
+                ),
+                trace_id,
+            }),
+        ));
+    }
+
+    let mut response = next.run(req).await;
 
     let status = response.status();
 
-    if status.is_server_error() {
+    if response.extensions().get::<LoadShed>().is_some() {
+        warn!("🚦 Request shed by a downstream write limiter");
+        if !response.headers().contains_key(header::RETRY_AFTER) {
+            let retry_after = app_state
+                .retry_after
+                .header_value(app_state.retry_after.load_shed_secs);
+            if let Ok(value) = HeaderValue::from_str(&retry_after) {
+                response.headers_mut().insert(header::RETRY_AFTER, value);
+            }
+        }
+    } else if status.is_server_error() {
         circuit_breaker.record_failure();
+        if let Some((_, breaker)) = service_breaker {
+            breaker.record_failure();
+        }
         warn!("❌ Request failed with status: {}", status);
     } else if status.is_success() {
         circuit_breaker.record_success();
+        if let Some((_, breaker)) = service_breaker {
+            breaker.record_success();
+        }
     }
 
     Ok(response)
@@ -0,0 +1,111 @@
+use axum::{
+    Json,
+    http::{
+        HeaderMap, HeaderValue, StatusCode,
+        header::{ETAG, IF_NONE_MATCH},
+    },
+    response::{IntoResponse, Response},
+};
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+
+pub fn compute_etag<T: Serialize>(body: &T) -> Option<String> {
+    let bytes = serde_json::to_vec(body).ok()?;
+    let digest = Sha256::digest(&bytes);
+
+    Some(format!("\"{:x}\"", digest))
+}
+
+pub fn if_none_match(headers: &HeaderMap, etag: &str) -> bool {
+    let Some(value) = headers.get(IF_NONE_MATCH).and_then(|v| v.to_str().ok()) else {
+        return false;
+    };
+
+    value.split(',').map(str::trim).any(|candidate| {
+        candidate == "*" || candidate.strip_prefix("W/").unwrap_or(candidate) == etag
+    })
+}
+
+pub fn etag_response<T: Serialize>(headers: &HeaderMap, body: T) -> Response {
+    let Some(etag) = compute_etag(&body) else {
+        return (StatusCode::OK, Json(body)).into_response();
+    };
+
+    let Ok(header_value) = HeaderValue::from_str(&etag) else {
+        return (StatusCode::OK, Json(body)).into_response();
+    };
+
+    if if_none_match(headers, &etag) {
+        return (StatusCode::NOT_MODIFIED, [(ETAG, header_value)]).into_response();
+    }
+
+    (StatusCode::OK, [(ETAG, header_value)], Json(body)).into_response()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn headers_with(if_none_match: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert(IF_NONE_MATCH, HeaderValue::from_str(if_none_match).unwrap());
+        headers
+    }
+
+    #[test]
+    fn etag_changes_with_the_body() {
+        let etag = compute_etag(&json!({ "id": 1, "name": "a" })).unwrap();
+
+        assert!(etag.starts_with('"') && etag.ends_with('"'));
+        assert_eq!(
+            compute_etag(&json!({ "id": 1, "name": "a" })).unwrap(),
+            etag
+        );
+        assert_ne!(
+            compute_etag(&json!({ "id": 1, "name": "b" })).unwrap(),
+            etag
+        );
+    }
+
+    #[test]
+    fn matching_etag_returns_not_modified() {
+        let body = json!({ "id": 1 });
+        let etag = compute_etag(&body).unwrap();
+
+        let response = etag_response(&headers_with(&etag), body);
+
+        assert_eq!(response.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(response.headers()[ETAG], etag.as_str());
+    }
+
+    #[test]
+    fn stale_etag_returns_the_body() {
+        let old = compute_etag(&json!({ "id": 1, "version": 1 })).unwrap();
+        let body = json!({ "id": 1, "version": 2 });
+        let etag = compute_etag(&body).unwrap();
+
+        let response = etag_response(&headers_with(&old), body);
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert_eq!(response.headers()[ETAG], etag.as_str());
+    }
+
+    #[test]
+    fn missing_header_returns_the_body() {
+        let response = etag_response(&HeaderMap::new(), json!({ "id": 1 }));
+
+        assert_eq!(response.status(), StatusCode::OK);
+        assert!(response.headers().contains_key(ETAG));
+    }
+
+    #[test]
+    fn if_none_match_accepts_lists_weak_tags_and_wildcards() {
+        let etag = "\"abc\"";
+
+        assert!(if_none_match(&headers_with("\"zzz\", \"abc\""), etag));
+        assert!(if_none_match(&headers_with("W/\"abc\""), etag));
+        assert!(if_none_match(&headers_with("*"), etag));
+        assert!(!if_none_match(&headers_with("\"zzz\""), etag));
+    }
+}
@@ -5,21 +5,68 @@ use axum::{
     extract::State,
     http::{Request, StatusCode, header},
     middleware::Next,
-    response::IntoResponse,
+    response::{IntoResponse, Response},
 };
 use axum_extra::extract::cookie::CookieJar;
-use shared::{errors::ErrorResponse, utils::get_trace_id};
+use shared::{
+    abstract_trait::jwt::DynJwtService, config::Claims, errors::ErrorResponse, utils::get_trace_id,
+};
 use std::sync::Arc;
 
 pub async fn auth(
     cookie_jar: CookieJar,
     State(app_state): State<Arc<AppState>>,
-    mut req: Request<Body>,
+    req: Request<Body>,
     next: Next,
 ) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
-    let jwt = &app_state.jwt_config;
-    let trace_id = get_trace_id();
+    authenticate(cookie_jar, &app_state, req, next, &["access"]).await
+}
+
+pub async fn password_change_auth(
+    cookie_jar: CookieJar,
+    State(app_state): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+    authenticate(
+        cookie_jar,
+        &app_state,
+        req,
+        next,
+        &["access", "password_change"],
+    )
+    .await
+}
+
+async fn authenticate(
+    cookie_jar: CookieJar,
+    app_state: &AppState,
+    mut req: Request<Body>,
+    next: Next,
+    accepted_types: &[&str],
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    if let Some(claims) = req.extensions().get::<Claims>() {
+        if accepted_types.contains(&claims.token_type.as_str()) {
+            return Ok(next.run(req).await);
+        }
+
+        return Err(unauthorized("Invalid token"));
+    }
+
+    let claims = verify_request(&cookie_jar, &app_state.jwt_config, &req, accepted_types)?;
+
+    req.extensions_mut().insert(claims.user_id as i32);
+    req.extensions_mut().insert(claims);
+
+    Ok(next.run(req).await)
+}
 
+pub fn verify_request(
+    cookie_jar: &CookieJar,
+    jwt: &DynJwtService,
+    req: &Request<Body>,
+    accepted_types: &[&str],
+) -> Result<Claims, (StatusCode, Json<ErrorResponse>)> {
     let token = cookie_jar
         .get("token")
         .map(|cookie| cookie.value().to_string())
@@ -28,37 +75,22 @@ pub async fn auth(
                 .get(header::AUTHORIZATION)
                 .and_then(|auth_header| auth_header.to_str().ok())
                 .and_then(|auth_value| auth_value.strip_prefix("Bearer ").map(str::to_owned))
-        });
+        })
+        .ok_or_else(|| unauthorized("You are not logged in, please provide token"))?;
 
-    let token = match token {
-        Some(token) => token,
-        None => {
-            return Err((
-                StatusCode::UNAUTHORIZED,
-                Json(ErrorResponse {
-                    trace_id: trace_id.clone(),
-                    status: "fail".to_string(),
-                    message: "You are not logged in, please provide token".to_string(),
-                }),
-            ));
-        }
-    };
-
-    let user_id = match jwt.verify_token(&token, "access") {
-        Ok(id) => id as i32,
-        Err(_) => {
-            return Err((
-                StatusCode::UNAUTHORIZED,
-                Json(ErrorResponse {
-                    trace_id: trace_id.clone(),
-                    status: "fail".to_string(),
-                    message: "Invalid token".to_string(),
-                }),
-            ));
-        }
-    };
-
-    req.extensions_mut().insert(user_id);
+    accepted_types
+        .iter()
+        .find_map(|token_type| jwt.verify_claims(&token, token_type).ok())
+        .ok_or_else(|| unauthorized("Invalid token"))
+}
 
-    Ok(next.run(req).await)
+fn unauthorized(message: &str) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        StatusCode::UNAUTHORIZED,
+        Json(ErrorResponse {
+            trace_id: get_trace_id(),
+            status: "fail".to_string(),
+            message: message.to_string(),
+        }),
+    )
 }
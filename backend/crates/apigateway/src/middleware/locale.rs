@@ -0,0 +1,75 @@
+use super::buffer::buffer_json;
+use crate::state::AppState;
+use axum::{
+    body::{Body, Bytes},
+    extract::State,
+    http::{HeaderValue, Request, header, response::Parts},
+    middleware::Next,
+    response::Response,
+};
+use serde_json::Value;
+use shared::utils::translate_message;
+use std::sync::Arc;
+
+/// Response bodies are written in English; `Content-Language` only names
+/// another locale once the message has actually been translated into it.
+const DEFAULT_LANGUAGE: &str = "en";
+
+pub async fn locale_middleware(
+    State(app_state): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let accept_language = req
+        .headers()
+        .get(header::ACCEPT_LANGUAGE)
+        .and_then(|v| v.to_str().ok());
+    let locale = app_state.locale.negotiate(accept_language);
+
+    let response = next.run(req).await;
+
+    if locale == DEFAULT_LANGUAGE {
+        return with_language(response, DEFAULT_LANGUAGE);
+    }
+
+    let (mut parts, bytes) = match buffer_json(response, "localization").await {
+        Ok(buffered) => buffered,
+        Err(response) => return with_language(response, DEFAULT_LANGUAGE),
+    };
+
+    let Ok(mut json) = serde_json::from_slice::<Value>(&bytes) else {
+        return untranslated(parts, bytes);
+    };
+
+    let Some(translated) = json
+        .get("message")
+        .and_then(Value::as_str)
+        .and_then(|message| translate_message(locale, message))
+    else {
+        return untranslated(parts, bytes);
+    };
+
+    json["message"] = Value::String(translated.to_string());
+
+    match serde_json::to_vec(&json) {
+        Ok(body) => {
+            parts.headers.remove(header::CONTENT_LENGTH);
+            with_language(Response::from_parts(parts, Body::from(body)), locale)
+        }
+        Err(_) => untranslated(parts, bytes),
+    }
+}
+
+fn untranslated(parts: Parts, bytes: Bytes) -> Response {
+    with_language(
+        Response::from_parts(parts, Body::from(bytes)),
+        DEFAULT_LANGUAGE,
+    )
+}
+
+fn with_language(mut response: Response, language: &'static str) -> Response {
+    response
+        .headers_mut()
+        .insert(header::CONTENT_LANGUAGE, HeaderValue::from_static(language));
+    response
+}
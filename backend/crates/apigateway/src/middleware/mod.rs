@@ -1,7 +1,17 @@
 pub mod api_key;
+pub mod buffer;
 pub mod circuit_breaker;
+pub mod etag;
 pub mod jwt;
+pub mod locale;
+pub mod pagination;
 pub mod rate_limit;
+pub mod request_deadline;
+pub mod request_id;
 pub mod request_limiter;
+pub mod route_auth;
+pub mod security_headers;
+pub mod service_account;
+pub mod service_limiter;
 pub mod session;
 pub mod validate;
@@ -0,0 +1,55 @@
+use super::buffer::buffer_json;
+use axum::{
+    body::Body,
+    http::{Method, Request, header},
+    middleware::Next,
+    response::Response,
+};
+use serde_json::Value;
+use shared::domain::responses::{Pagination, PaginationLinks};
+
+pub async fn pagination_links_middleware(req: Request<Body>, next: Next) -> Response {
+    if req.method() != Method::GET {
+        return next.run(req).await;
+    }
+
+    let path = req.uri().path().to_string();
+    let query = req.uri().query().map(str::to_string);
+
+    let response = next.run(req).await;
+
+    if !response.status().is_success() {
+        return response;
+    }
+
+    let (mut parts, bytes) = match buffer_json(response, "pagination links").await {
+        Ok(buffered) => buffered,
+        Err(response) => return response,
+    };
+
+    let Ok(mut json) = serde_json::from_slice::<Value>(&bytes) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    let Some(pagination) = json
+        .get("pagination")
+        .and_then(|p| serde_json::from_value::<Pagination>(p.clone()).ok())
+    else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+
+    let links = PaginationLinks::build(&path, query.as_deref(), &pagination);
+
+    let (Some(object), Ok(links)) = (json.as_object_mut(), serde_json::to_value(links)) else {
+        return Response::from_parts(parts, Body::from(bytes));
+    };
+    object.insert("links".to_string(), links);
+
+    match serde_json::to_vec(&json) {
+        Ok(body) => {
+            parts.headers.remove(header::CONTENT_LENGTH);
+            Response::from_parts(parts, Body::from(body))
+        }
+        Err(_) => Response::from_parts(parts, Body::from(bytes)),
+    }
+}
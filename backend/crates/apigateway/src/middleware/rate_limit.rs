@@ -3,19 +3,46 @@ use axum::{
     Json,
     body::Body,
     extract::State,
-    http::{Request, StatusCode},
+    http::{HeaderName, HeaderValue, Request, StatusCode, header},
     middleware::Next,
     response::IntoResponse,
 };
-use shared::{errors::ErrorResponse, utils::get_trace_id};
+use shared::{
+    abstract_trait::rate_limit::RateLimitStatus, errors::ErrorResponse, utils::get_trace_id,
+};
 use std::sync::Arc;
 use tracing::warn;
 
+const X_RATELIMIT_LIMIT: HeaderName = HeaderName::from_static("x-ratelimit-limit");
+const X_RATELIMIT_REMAINING: HeaderName = HeaderName::from_static("x-ratelimit-remaining");
+const X_RATELIMIT_RESET: HeaderName = HeaderName::from_static("x-ratelimit-reset");
+
+pub const RATE_LIMIT_MAX_REQUESTS: u32 = 100;
+pub const RATE_LIMIT_WINDOW_SECS: u32 = 60;
+
+/// Routes that carry `rate_limit_middleware`, as reported by `/api/me/limits`.
+/// Keep in step with the route builders that add the layer.
+pub const RATE_LIMITED_ROUTES: &[&str] = &[
+    "/api/auth/me",
+    "/api/auth/token-info",
+    "/api/auth/refresh-token",
+    "/api/auth/verify-email/send",
+    "/api/auth/verify-email/resend",
+    "/api/auth/change-password",
+    "/api/cards/check/*",
+    "/api/merchants/*",
+    "/api/saldos/*",
+    "/api/topups/*",
+    "/api/transactions/*",
+    "/api/transfers/*",
+    "/api/withdraws/*",
+];
+
 pub async fn rate_limit_middleware(
     State(app_state): State<Arc<AppState>>,
     req: Request<Body>,
     next: Next,
-) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<impl IntoResponse, (StatusCode, [(HeaderName, String); 4], Json<ErrorResponse>)> {
     let rate_limiter = &app_state.rate_limit;
     let trace_id = get_trace_id();
 
@@ -27,20 +54,24 @@ pub async fn rate_limit_middleware(
         .unwrap_or_else(|| "unknown".to_string());
 
     let key = format!("rate_limit:{client_ip}");
-    let max_requests = 100;
-    let window_seconds = 60;
+    let max_requests = RATE_LIMIT_MAX_REQUESTS;
+    let window_seconds = RATE_LIMIT_WINDOW_SECS;
 
-    let (allowed, current) = rate_limiter
+    let status = rate_limiter
         .check_rate_limit(&key, max_requests, window_seconds)
         .await;
 
-    if !allowed {
+    if !status.allowed {
         warn!(
             "Rate limit exceeded for IP: {} (requests: {})",
-            client_ip, current
+            client_ip, status.current
         );
+        let retry_after = app_state.retry_after.header_value(status.reset_after_secs);
+        let [limit, remaining, reset] = rate_limit_headers(&status);
+
         return Err((
             StatusCode::TOO_MANY_REQUESTS,
+            [(header::RETRY_AFTER, retry_after), limit, remaining, reset],
             Json(ErrorResponse {
                 trace_id,
                 status: "fail".to_string(),
@@ -49,5 +80,21 @@ pub async fn rate_limit_middleware(
         ));
     }
 
-    Ok(next.run(req).await)
+    let mut response = next.run(req).await;
+
+    for (name, value) in rate_limit_headers(&status) {
+        if let Ok(value) = HeaderValue::from_str(&value) {
+            response.headers_mut().insert(name, value);
+        }
+    }
+
+    Ok(response)
+}
+
+fn rate_limit_headers(status: &RateLimitStatus) -> [(HeaderName, String); 3] {
+    [
+        (X_RATELIMIT_LIMIT, status.limit.to_string()),
+        (X_RATELIMIT_REMAINING, status.remaining().to_string()),
+        (X_RATELIMIT_RESET, status.reset_after_secs.to_string()),
+    ]
 }
@@ -0,0 +1,20 @@
+use crate::state::AppState;
+use axum::{body::Body, extract::State, http::Request, middleware::Next, response::Response};
+use shared::observability::with_request_deadline;
+use std::sync::Arc;
+use tokio::time::Instant;
+
+pub async fn request_deadline_middleware(
+    State(app_state): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let config = app_state.request_timeout;
+
+    if !config.propagate_deadline {
+        return next.run(req).await;
+    }
+
+    let deadline = Instant::now() + config.timeout();
+    with_request_deadline(deadline, next.run(req)).await
+}
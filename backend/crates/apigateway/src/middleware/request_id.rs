@@ -0,0 +1,76 @@
+use crate::state::AppState;
+use axum::{
+    body::Body,
+    extract::State,
+    http::{HeaderMap, HeaderName, HeaderValue, Request},
+    middleware::Next,
+    response::Response,
+};
+use shared::observability::{RequestId, with_request_id};
+use std::sync::Arc;
+use tracing::{Instrument, info_span};
+use uuid::Uuid;
+
+const MAX_REQUEST_ID_LEN: usize = 128;
+
+pub async fn request_id_middleware(
+    State(app_state): State<Arc<AppState>>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Response {
+    let config = &app_state.request_id;
+
+    let request_id = config
+        .headers
+        .iter()
+        .find_map(|name| extract_request_id(req.headers(), name))
+        .unwrap_or_else(|| Uuid::new_v4().to_string());
+
+    let header_name = HeaderName::from_bytes(config.primary_header().as_bytes())
+        .unwrap_or_else(|_| HeaderName::from_static("x-request-id"));
+    let header_value = HeaderValue::from_str(&request_id).ok();
+
+    if let Some(value) = &header_value {
+        req.headers_mut().insert(header_name.clone(), value.clone());
+    }
+
+    let context = RequestId {
+        header: header_name.as_str().to_string(),
+        value: request_id.clone(),
+    };
+    req.extensions_mut().insert(context.clone());
+
+    let span = info_span!("http_request", request_id = %request_id);
+    let mut response = with_request_id(context, next.run(req))
+        .instrument(span)
+        .await;
+
+    if let Some(value) = header_value {
+        response.headers_mut().insert(header_name, value);
+    }
+
+    response
+}
+
+fn extract_request_id(headers: &HeaderMap, name: &str) -> Option<String> {
+    let raw = headers.get(name)?.to_str().ok()?.trim();
+
+    let candidate = if name == "traceparent" {
+        let mut parts = raw.split('-');
+        let (_version, trace_id) = (parts.next()?, parts.next()?);
+        if trace_id.len() != 32 || trace_id.bytes().all(|b| b == b'0') {
+            return None;
+        }
+        trace_id
+    } else {
+        raw
+    };
+
+    let valid = !candidate.is_empty()
+        && candidate.len() <= MAX_REQUEST_ID_LEN
+        && candidate
+            .bytes()
+            .all(|b| b.is_ascii_alphanumeric() || matches!(b, b'-' | b'_' | b'.' | b':'));
+
+    valid.then(|| candidate.to_string())
+}
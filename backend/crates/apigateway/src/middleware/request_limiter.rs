@@ -3,11 +3,11 @@ use axum::{
     Json,
     body::Body,
     extract::State,
-    http::{Request, StatusCode},
+    http::{Request, StatusCode, header},
     middleware::Next,
     response::Response,
 };
-use shared::{errors::ErrorResponse, utils::get_trace_id};
+use shared::{errors::ErrorResponse, resilience::RequestAdmission, utils::get_trace_id};
 use std::sync::Arc;
 use tracing::warn;
 
@@ -15,33 +15,62 @@ pub async fn request_limiter_middleware(
     State(app_state): State<Arc<AppState>>,
     req: Request<Body>,
     next: Next,
-) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+) -> Result<
+    Response,
+    (
+        StatusCode,
+        [(header::HeaderName, String); 1],
+        Json<ErrorResponse>,
+    ),
+> {
     let limiter = &app_state.request_limiter;
     let trace_id = get_trace_id();
 
-    match limiter.semaphore.try_acquire() {
-        Ok(_permit) => {
+    let (status, message) = match limiter.admit().await {
+        RequestAdmission::Admitted(_permit) => {
             let response = next.run(req).await;
-            Ok(response)
+            return Ok(response);
         }
-        Err(_) => {
-            let available = limiter.available_permits();
+        RequestAdmission::Rejected => {
             warn!(
                 "⚠️  Request limiter: Too many concurrent requests (limit: {}, available: {})",
-                limiter.max_concurrent, available
+                limiter.max_concurrent,
+                limiter.available_permits()
             );
 
-            Err((
+            (
                 StatusCode::TOO_MANY_REQUESTS,
-                Json(ErrorResponse {
-                    trace_id,
-                    status: "error".to_string(),
-                    message: format!(
-                        "Too many concurrent requests. Server is handling {} requests. Please try again later.",
-                        limiter.max_concurrent
-                    ),
-                }),
-            ))
+                format!(
+                    "Too many concurrent requests. Server is handling {} requests. Please try again later.",
+                    limiter.max_concurrent
+                ),
+            )
         }
-    }
+        RequestAdmission::Shed(waited) => {
+            warn!(
+                "⚠️  Request limiter: Shed request after queueing {:?} (max queue time: {:?}, limit: {})",
+                waited, limiter.max_queue_time, limiter.max_concurrent
+            );
+
+            (
+                StatusCode::SERVICE_UNAVAILABLE,
+                "Server is overloaded and the request waited too long in the queue. Please try again later."
+                    .to_string(),
+            )
+        }
+    };
+
+    let retry_after = app_state
+        .retry_after
+        .header_value(app_state.retry_after.load_shed_secs);
+
+    Err((
+        status,
+        [(header::RETRY_AFTER, retry_after)],
+        Json(ErrorResponse {
+            trace_id,
+            status: "error".to_string(),
+            message,
+        }),
+    ))
 }
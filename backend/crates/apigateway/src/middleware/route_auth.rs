@@ -0,0 +1,418 @@
+use crate::{
+    middleware::{jwt::verify_request, session::load_session},
+    state::AppState,
+};
+use axum::{
+    Json,
+    body::Body,
+    extract::{MatchedPath, State},
+    http::{Request, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use axum_extra::extract::cookie::CookieJar;
+use shared::{
+    abstract_trait::jwt::DynJwtService,
+    config::Claims,
+    errors::{ErrorResponse, HttpError},
+    utils::get_trace_id,
+};
+use std::sync::Arc;
+use tracing::warn;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuthRequirement {
+    None,
+    Jwt,
+    PasswordChange,
+    ApiKey,
+    Admin,
+    Staff,
+}
+
+pub const ROUTE_AUTH: &[(&str, AuthRequirement)] = &[
+    ("/api/*", AuthRequirement::Jwt),
+    ("/readyz", AuthRequirement::None),
+    ("/api/healthchecker", AuthRequirement::None),
+    ("/api/auth/register", AuthRequirement::None),
+    ("/api/auth/login", AuthRequirement::None),
+    ("/api/auth/verify-email", AuthRequirement::None),
+    ("/api/auth/verify-email/resend", AuthRequirement::None),
+    ("/api/config/features", AuthRequirement::None),
+    ("/api/auth/change-password", AuthRequirement::PasswordChange),
+    ("/api/transactions/create", AuthRequirement::ApiKey),
+    ("/api/transactions/update/{id}", AuthRequirement::ApiKey),
+    ("/api/admin/*", AuthRequirement::Admin),
+    ("/api/users/merge", AuthRequirement::Admin),
+    ("/api/cards/trash/{id}", AuthRequirement::Staff),
+    ("/api/cards/reissue/{id}", AuthRequirement::Staff),
+    ("/api/cards/restore/{id}", AuthRequirement::Staff),
+    ("/api/cards/delete/{id}", AuthRequirement::Staff),
+    ("/api/cards/restore-all", AuthRequirement::Staff),
+    ("/api/cards/restore-by-filter", AuthRequirement::Staff),
+    ("/api/cards/delete-all", AuthRequirement::Staff),
+    ("/api/cards/stats/*", AuthRequirement::Staff),
+    ("/api/cards/dashboard", AuthRequirement::Staff),
+    ("/api/cards/dashboard/*", AuthRequirement::Staff),
+    ("/api/merchants/trash/{id}", AuthRequirement::Staff),
+    ("/api/merchants/restore/{id}", AuthRequirement::Staff),
+    ("/api/merchants/delete/{id}", AuthRequirement::Staff),
+    ("/api/merchants/restore-all", AuthRequirement::Staff),
+    ("/api/merchants/delete-all", AuthRequirement::Staff),
+    ("/api/merchants/users/grant", AuthRequirement::Staff),
+    ("/api/merchants/users/revoke", AuthRequirement::Staff),
+    ("/api/merchants/stats/*", AuthRequirement::Staff),
+    ("/api/merchants/transactions", AuthRequirement::Staff),
+    ("/api/merchants/transactions/*", AuthRequirement::Staff),
+    ("/api/roles/create", AuthRequirement::Staff),
+    ("/api/roles/update/{id}", AuthRequirement::Staff),
+    ("/api/roles/trashed/{id}", AuthRequirement::Staff),
+    ("/api/roles/restore/{id}", AuthRequirement::Staff),
+    ("/api/roles/delete/{id}", AuthRequirement::Staff),
+    ("/api/roles/restore-all", AuthRequirement::Staff),
+    ("/api/roles/delete-all", AuthRequirement::Staff),
+    ("/api/saldos/trash/{id}", AuthRequirement::Staff),
+    ("/api/saldos/restore/{id}", AuthRequirement::Staff),
+    ("/api/saldos/delete/{id}", AuthRequirement::Staff),
+    ("/api/saldos/restore-all", AuthRequirement::Staff),
+    ("/api/saldos/delete-all", AuthRequirement::Staff),
+    ("/api/saldos/stats/*", AuthRequirement::Staff),
+    ("/api/topups/trash/{id}", AuthRequirement::Staff),
+    ("/api/topups/restore/{id}", AuthRequirement::Staff),
+    ("/api/topups/delete/{id}", AuthRequirement::Staff),
+    ("/api/topups/restore-all", AuthRequirement::Staff),
+    ("/api/topups/delete-all", AuthRequirement::Staff),
+    ("/api/topups/stats/*", AuthRequirement::Staff),
+    ("/api/transactions/trash/{id}", AuthRequirement::Staff),
+    ("/api/transactions/restore/{id}", AuthRequirement::Staff),
+    ("/api/transactions/delete/{id}", AuthRequirement::Staff),
+    ("/api/transactions/restore-all", AuthRequirement::Staff),
+    ("/api/transactions/delete-all", AuthRequirement::Staff),
+    ("/api/transactions/stats/*", AuthRequirement::Staff),
+    ("/api/transfers/net-position", AuthRequirement::Staff),
+    ("/api/transfers/trash/{id}", AuthRequirement::Staff),
+    ("/api/transfers/restore/{id}", AuthRequirement::Staff),
+    ("/api/transfers/delete/{id}", AuthRequirement::Staff),
+    ("/api/transfers/restore-all", AuthRequirement::Staff),
+    ("/api/transfers/delete-all", AuthRequirement::Staff),
+    ("/api/transfers/stats/*", AuthRequirement::Staff),
+    ("/api/users/create", AuthRequirement::Staff),
+    ("/api/users/update/{id}", AuthRequirement::Staff),
+    ("/api/users/trash/{id}", AuthRequirement::Staff),
+    ("/api/users/restore/{id}", AuthRequirement::Staff),
+    ("/api/users/delete/{id}", AuthRequirement::Staff),
+    ("/api/users/restore-all", AuthRequirement::Staff),
+    ("/api/users/delete-all", AuthRequirement::Staff),
+    ("/api/withdraws/trash/{id}", AuthRequirement::Staff),
+    ("/api/withdraws/restore/{id}", AuthRequirement::Staff),
+    ("/api/withdraws/delete/{id}", AuthRequirement::Staff),
+    ("/api/withdraws/restore-all", AuthRequirement::Staff),
+    ("/api/withdraws/delete-all", AuthRequirement::Staff),
+    ("/api/withdraws/stats/*", AuthRequirement::Staff),
+    (
+        "/api/merchants/transactions/settlement",
+        AuthRequirement::Jwt,
+    ),
+];
+
+pub fn requirement_for(path: &str) -> AuthRequirement {
+    ROUTE_AUTH
+        .iter()
+        .filter(|(pattern, _)| match pattern.strip_suffix('*') {
+            Some(prefix) => path.starts_with(prefix),
+            None => path == *pattern,
+        })
+        .max_by_key(|(pattern, _)| (!pattern.ends_with('*'), pattern.len()))
+        .map(|(_, requirement)| *requirement)
+        .unwrap_or(AuthRequirement::None)
+}
+
+/// Verifies the token a requirement asks for. Anonymous routes yield no claims.
+pub fn authenticate(
+    requirement: AuthRequirement,
+    cookie_jar: &CookieJar,
+    jwt: &DynJwtService,
+    req: &Request<Body>,
+) -> Result<Option<Claims>, (StatusCode, Json<ErrorResponse>)> {
+    let accepted_types: &[&str] = match requirement {
+        AuthRequirement::None => return Ok(None),
+        AuthRequirement::PasswordChange => &["access", "password_change"],
+        AuthRequirement::Jwt
+        | AuthRequirement::ApiKey
+        | AuthRequirement::Admin
+        | AuthRequirement::Staff => &["access"],
+    };
+
+    verify_request(cookie_jar, jwt, req, accepted_types).map(Some)
+}
+
+/// Checks the caller's session roles against an `Admin` or `Staff` requirement.
+pub fn authorize(
+    requirement: AuthRequirement,
+    roles: &[String],
+) -> Result<(), (StatusCode, Json<ErrorResponse>)> {
+    let (allowed, label) = match requirement {
+        AuthRequirement::Admin => (&["ROLE_ADMIN"][..], "ADMIN"),
+        AuthRequirement::Staff => (&["ROLE_ADMIN", "ROLE_MODERATOR"][..], "ADMIN or MODERATOR"),
+        _ => return Ok(()),
+    };
+
+    if roles.iter().any(|r| allowed.contains(&r.as_str())) {
+        return Ok(());
+    }
+
+    Err(reject(
+        StatusCode::FORBIDDEN,
+        &format!("Access denied. Required role: {label}"),
+    ))
+}
+
+pub async fn route_auth_middleware(
+    cookie_jar: CookieJar,
+    State(app_state): State<Arc<AppState>>,
+    mut req: Request<Body>,
+    next: Next,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let matched_path = req
+        .extensions()
+        .get::<MatchedPath>()
+        .map(|path| path.as_str().to_string());
+    let requirement = requirement_for(matched_path.as_deref().unwrap_or(req.uri().path()));
+
+    let Some(claims) = authenticate(requirement, &cookie_jar, &app_state.jwt_config, &req)? else {
+        return Ok(next.run(req).await);
+    };
+    let user_id = claims.user_id as i32;
+
+    if requirement == AuthRequirement::ApiKey {
+        let api_key = req
+            .headers()
+            .get("x-api-key")
+            .and_then(|v| v.to_str().ok())
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .ok_or_else(|| {
+                reject(
+                    StatusCode::UNAUTHORIZED,
+                    "Missing API key. Provide via 'x-api-key'",
+                )
+            })?;
+
+        match app_state
+            .di_container
+            .merchant_clients
+            .find_by_apikey(api_key)
+            .await
+        {
+            Ok(_) => {}
+            Err(HttpError::NotFound(_)) => {
+                warn!(user_id, path = %req.uri().path(), "route rejected for unknown API key");
+                return Err(reject(StatusCode::UNAUTHORIZED, "Invalid API key"));
+            }
+            Err(e) => {
+                warn!(user_id, error = ?e, "failed to validate API key");
+                return Err(reject(
+                    StatusCode::SERVICE_UNAVAILABLE,
+                    "Unable to validate API key",
+                ));
+            }
+        }
+    }
+
+    if matches!(requirement, AuthRequirement::Admin | AuthRequirement::Staff) {
+        let session = load_session(&app_state, user_id).await?;
+
+        if let Err(rejection) = authorize(requirement, &session.roles) {
+            warn!(user_id, path = %req.uri().path(), "{requirement:?} route rejected for user without role");
+            return Err(rejection);
+        }
+
+        req.extensions_mut().insert(session);
+    }
+
+    if let Some(path) = matched_path.as_deref()
+        && app_state.reauth.requires_reauth(path)
+        && !app_state.reauth.is_recent(&claims)
+    {
+        warn!(
+            user_id,
+            path, "sensitive route rejected for stale authentication"
+        );
+        return Err(reject(
+            StatusCode::UNAUTHORIZED,
+            "Re-authentication required",
+        ));
+    }
+
+    req.extensions_mut().insert(user_id);
+    req.extensions_mut().insert(claims);
+
+    Ok(next.run(req).await)
+}
+
+fn reject(status: StatusCode, message: &str) -> (StatusCode, Json<ErrorResponse>) {
+    (
+        status,
+        Json(ErrorResponse {
+            trace_id: get_trace_id(),
+            status: "fail".to_string(),
+            message: message.to_string(),
+        }),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::http::header;
+    use shared::config::JwtConfig;
+
+    fn jwt() -> DynJwtService {
+        Arc::new(JwtConfig::new(
+            "test-secret",
+            "gateway",
+            "payment-api",
+            false,
+        ))
+    }
+
+    fn request(token: Option<&str>) -> Request<Body> {
+        let builder = Request::builder().uri("/api/users/1");
+        let builder = match token {
+            Some(token) => builder.header(header::AUTHORIZATION, format!("Bearer {token}")),
+            None => builder,
+        };
+
+        builder.body(Body::empty()).unwrap()
+    }
+
+    fn roles(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn exact_routes_use_their_own_requirement() {
+        assert_eq!(requirement_for("/api/auth/login"), AuthRequirement::None);
+        assert_eq!(requirement_for("/readyz"), AuthRequirement::None);
+        assert_eq!(
+            requirement_for("/api/transactions/create"),
+            AuthRequirement::ApiKey
+        );
+        assert_eq!(requirement_for("/api/users/merge"), AuthRequirement::Admin);
+        assert_eq!(
+            requirement_for("/api/cards/dashboard"),
+            AuthRequirement::Staff
+        );
+        assert_eq!(
+            requirement_for("/api/merchants/users/grant"),
+            AuthRequirement::Staff
+        );
+    }
+
+    #[test]
+    fn wildcards_cover_sub_paths() {
+        assert_eq!(
+            requirement_for("/api/admin/rate-limits/{namespace}"),
+            AuthRequirement::Admin
+        );
+        assert_eq!(
+            requirement_for("/api/cards/dashboard/{card_number}"),
+            AuthRequirement::Staff
+        );
+    }
+
+    #[test]
+    fn exact_entries_win_over_wildcards() {
+        assert_eq!(
+            requirement_for("/api/merchants/transactions/settlement"),
+            AuthRequirement::Jwt
+        );
+        assert_eq!(
+            requirement_for("/api/merchants/transactions/{merchant_id}"),
+            AuthRequirement::Staff
+        );
+    }
+
+    #[test]
+    fn api_routes_require_a_jwt_through_the_wildcard() {
+        assert_eq!(requirement_for("/api/users/{id}"), AuthRequirement::Jwt);
+        assert_eq!(requirement_for("/api/not-listed"), AuthRequirement::Jwt);
+    }
+
+    #[test]
+    fn unmarked_routes_are_anonymous() {
+        assert_eq!(requirement_for("/metrics"), AuthRequirement::None);
+        assert_eq!(requirement_for("/not-listed"), AuthRequirement::None);
+    }
+
+    #[test]
+    fn anonymous_callers_get_401_on_jwt_routes() {
+        let (status, _) = authenticate(
+            AuthRequirement::Jwt,
+            &CookieJar::new(),
+            &jwt(),
+            &request(None),
+        )
+        .unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+
+        let (status, _) = authenticate(
+            AuthRequirement::Jwt,
+            &CookieJar::new(),
+            &jwt(),
+            &request(Some("not-a-token")),
+        )
+        .unwrap_err();
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+    }
+
+    #[test]
+    fn anonymous_callers_pass_unmarked_routes() {
+        let claims = authenticate(
+            requirement_for("/not-listed"),
+            &CookieJar::new(),
+            &jwt(),
+            &request(None),
+        )
+        .unwrap();
+
+        assert!(claims.is_none());
+    }
+
+    #[test]
+    fn valid_tokens_pass_jwt_routes() {
+        let jwt = jwt();
+        let token = jwt.generate_token(42, "access").unwrap();
+
+        let claims = authenticate(
+            AuthRequirement::Jwt,
+            &CookieJar::new(),
+            &jwt,
+            &request(Some(&token)),
+        )
+        .unwrap()
+        .unwrap();
+
+        assert_eq!(claims.user_id, 42);
+    }
+
+    #[test]
+    fn non_admins_get_403_on_admin_routes() {
+        let (status, _) =
+            authorize(AuthRequirement::Admin, &roles(&["ROLE_MODERATOR"])).unwrap_err();
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        let (status, _) = authorize(AuthRequirement::Admin, &[]).unwrap_err();
+        assert_eq!(status, StatusCode::FORBIDDEN);
+
+        assert!(authorize(AuthRequirement::Admin, &roles(&["ROLE_ADMIN"])).is_ok());
+    }
+
+    #[test]
+    fn staff_routes_accept_admins_and_moderators() {
+        assert!(authorize(AuthRequirement::Staff, &roles(&["ROLE_MODERATOR"])).is_ok());
+        assert!(authorize(AuthRequirement::Staff, &roles(&["ROLE_ADMIN"])).is_ok());
+        assert!(authorize(AuthRequirement::Staff, &roles(&["ROLE_USER"])).is_err());
+        assert!(authorize(AuthRequirement::Jwt, &[]).is_ok());
+    }
+}
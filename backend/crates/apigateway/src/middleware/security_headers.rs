@@ -0,0 +1,49 @@
+use crate::state::AppState;
+use axum::{
+    body::Body,
+    extract::State,
+    http::{HeaderValue, Request, header},
+    middleware::Next,
+    response::Response,
+};
+use std::sync::Arc;
+
+pub async fn security_headers_middleware(
+    State(app_state): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Response {
+    let config = &app_state.security_headers;
+    if !config.enabled {
+        return next.run(req).await;
+    }
+
+    let path = req.uri().path().to_string();
+    let mut response = next.run(req).await;
+    let headers = response.headers_mut();
+
+    headers.insert(
+        header::X_CONTENT_TYPE_OPTIONS,
+        HeaderValue::from_static("nosniff"),
+    );
+
+    if let Ok(value) = HeaderValue::from_str(&config.frame_options) {
+        headers.insert(header::X_FRAME_OPTIONS, value);
+    }
+
+    if let Some(value) = config
+        .hsts_value()
+        .and_then(|v| HeaderValue::from_str(&v).ok())
+    {
+        headers.insert(header::STRICT_TRANSPORT_SECURITY, value);
+    }
+
+    if let Some(value) = config
+        .content_security_policy_for(&path)
+        .and_then(|v| HeaderValue::from_str(v).ok())
+    {
+        headers.insert(header::CONTENT_SECURITY_POLICY, value);
+    }
+
+    response
+}
@@ -0,0 +1,40 @@
+use axum::{
+    Json,
+    body::Body,
+    http::{Request, StatusCode},
+    middleware::Next,
+    response::Response,
+};
+use shared::{
+    config::{SERVICE_ACCOUNT_HEADER, SERVICE_ACCOUNT_TOKEN_HEADER},
+    errors::ErrorResponse,
+    utils::get_trace_id,
+};
+use tracing::warn;
+
+pub async fn reject_service_account_middleware(
+    req: Request<Body>,
+    next: Next,
+) -> Result<Response, (StatusCode, Json<ErrorResponse>)> {
+    let headers = req.headers();
+    if headers.contains_key(SERVICE_ACCOUNT_HEADER)
+        || headers.contains_key(SERVICE_ACCOUNT_TOKEN_HEADER)
+    {
+        warn!(
+            "🚫 Rejected public request to {} carrying service account credentials",
+            req.uri().path()
+        );
+
+        return Err((
+            StatusCode::FORBIDDEN,
+            Json(ErrorResponse {
+                trace_id: get_trace_id(),
+                status: "error".to_string(),
+                message: "Service account credentials are not accepted by the public gateway"
+                    .to_string(),
+            }),
+        ));
+    }
+
+    Ok(next.run(req).await)
+}
@@ -0,0 +1,58 @@
+use crate::state::AppState;
+use axum::{
+    Json,
+    body::Body,
+    extract::State,
+    http::{Request, StatusCode, header},
+    middleware::Next,
+    response::Response,
+};
+use shared::{errors::ErrorResponse, resilience::GatewayServiceLimiter, utils::get_trace_id};
+use std::sync::Arc;
+use tracing::warn;
+
+pub async fn service_limiter_middleware(
+    State(app_state): State<Arc<AppState>>,
+    req: Request<Body>,
+    next: Next,
+) -> Result<
+    Response,
+    (
+        StatusCode,
+        [(header::HeaderName, String); 1],
+        Json<ErrorResponse>,
+    ),
+> {
+    let Some(service) = GatewayServiceLimiter::service_for_path(req.uri().path()) else {
+        return Ok(next.run(req).await);
+    };
+
+    let limiter = &app_state.service_limiter;
+
+    match limiter.acquire(service).await {
+        Some(_permit) => Ok(next.run(req).await),
+        None => {
+            warn!(
+                "⚠️  Service limiter: {service} service saturated (limit: {}, available: {})",
+                limiter.limit(service),
+                limiter.available_permits(service)
+            );
+
+            let retry_after = app_state
+                .retry_after
+                .header_value(app_state.retry_after.load_shed_secs);
+
+            Err((
+                StatusCode::SERVICE_UNAVAILABLE,
+                [(header::RETRY_AFTER, retry_after)],
+                Json(ErrorResponse {
+                    trace_id: get_trace_id(),
+                    status: "error".to_string(),
+                    message: format!(
+                        "The {service} service is handling too many requests. Please try again later."
+                    ),
+                }),
+            ))
+        }
+    }
+}
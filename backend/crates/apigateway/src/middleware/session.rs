@@ -16,9 +16,10 @@ pub async fn session_middleware(
     mut req: Request<Body>,
     next: Next,
 ) -> Result<impl IntoResponse, (StatusCode, Json<ErrorResponse>)> {
-    let role_client = &app_state.di_container.role_clients;
-    let session_service = &app_state.session;
-    let trace_id = get_trace_id();
+    // The route table middleware already loaded the session for role checks.
+    if req.extensions().get::<Session>().is_some() {
+        return Ok(next.run(req).await);
+    }
 
     let user_id = match req.extensions().get::<i32>() {
         Some(id) => *id,
@@ -26,7 +27,7 @@ pub async fn session_middleware(
             return Err((
                 StatusCode::UNAUTHORIZED,
                 Json(ErrorResponse {
-                    trace_id: trace_id.clone(),
+                    trace_id: get_trace_id(),
                     status: "fail".to_string(),
                     message: "Missing user_id in request context".to_string(),
                 }),
@@ -34,13 +35,27 @@ pub async fn session_middleware(
         }
     };
 
+    let session = load_session(&app_state, user_id).await?;
+
+    req.extensions_mut().insert(session);
+
+    Ok(next.run(req).await)
+}
+
+pub async fn load_session(
+    app_state: &AppState,
+    user_id: i32,
+) -> Result<Session, (StatusCode, Json<ErrorResponse>)> {
+    let role_client = &app_state.di_container.role_clients;
+    let session_service = &app_state.session;
+
     let roles = match role_client.find_by_user_id(user_id).await {
         Ok(resp) => resp.data.into_iter().map(|r| r.name).collect(),
         Err(_) => {
             return Err((
                 StatusCode::INTERNAL_SERVER_ERROR,
                 Json(ErrorResponse {
-                    trace_id: trace_id.clone(),
+                    trace_id: get_trace_id(),
                     status: "fail".to_string(),
                     message: "Failed to fetch roles".to_string(),
                 }),
@@ -59,7 +74,5 @@ pub async fn session_middleware(
         .create_session(&key, &session, Duration::minutes(30))
         .await;
 
-    req.extensions_mut().insert(session.clone());
-
-    Ok(next.run(req).await)
+    Ok(session)
 }
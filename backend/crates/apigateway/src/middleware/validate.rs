@@ -1,9 +1,10 @@
 use axum::{
-    extract::{FromRequest, Request},
+    extract::{FromRequest, Request, rejection::JsonRejection},
     http::StatusCode,
 };
 use serde::de::DeserializeOwned;
 use serde_json::{Value, json};
+use shared::config::JsonBodyConfig;
 use validator::{Validate, ValidationErrors};
 
 pub struct SimpleValidatedJson<T>(pub T);
@@ -16,22 +17,25 @@ where
     type Rejection = (StatusCode, axum::Json<Value>);
 
     async fn from_request(req: Request, state: &S) -> Result<Self, Self::Rejection> {
-        let axum::Json(json_value) =
-            axum::Json::<T>::from_request(req, state)
+        let json_value = if JsonBodyConfig::global().reject_unknown_fields {
+            strict_from_request::<S, T>(req, state).await?
+        } else {
+            let axum::Json(json_value) = axum::Json::<T>::from_request(req, state)
                 .await
-                .map_err(|rejection| {
-                    let payload = json!({
-                        "error": "Invalid JSON",
-                        "message": rejection.body_text(),
-                    });
-                    (rejection.status(), axum::Json(payload))
-                })?;
+                .map_err(invalid_json)?;
+            json_value
+        };
 
         json_value.validate().map_err(|validation_errors| {
+            let (errors, truncated) = collect_validation_errors(
+                &validation_errors,
+                JsonBodyConfig::global().max_validation_errors,
+            );
             let payload = json!({
                 "error": "Validation failed",
-                "message": format_validation_errors(&validation_errors),
-                "details": format_validation_errors_detailed(&validation_errors)
+                "message": format_validation_errors(&errors),
+                "details": format_validation_errors_detailed(&errors),
+                "truncated": truncated,
             });
             (StatusCode::BAD_REQUEST, axum::Json(payload))
         })?;
@@ -40,11 +44,73 @@ where
     }
 }
 
-fn format_validation_errors(errors: &ValidationErrors) -> String {
-    let mut error_messages = Vec::new();
+fn invalid_json(rejection: JsonRejection) -> (StatusCode, axum::Json<Value>) {
+    let payload = json!({
+        "error": "Invalid JSON",
+        "message": rejection.body_text(),
+    });
+    (rejection.status(), axum::Json(payload))
+}
+
+async fn strict_from_request<S, T>(
+    req: Request,
+    state: &S,
+) -> Result<T, (StatusCode, axum::Json<Value>)>
+where
+    T: DeserializeOwned,
+    S: Send + Sync,
+{
+    let axum::Json(raw) = axum::Json::<Value>::from_request(req, state)
+        .await
+        .map_err(invalid_json)?;
+
+    let max_errors = JsonBodyConfig::global().max_validation_errors;
+    let mut unknown_fields = Vec::new();
+    let mut truncated = false;
+    let parsed: T = serde_ignored::deserialize(raw, |path| {
+        if unknown_fields.len() < max_errors {
+            unknown_fields.push(path.to_string());
+        } else {
+            truncated = true;
+        }
+    })
+    .map_err(|e| {
+        let payload = json!({
+            "error": "Invalid JSON",
+            "message": format!("Failed to deserialize the JSON body into the target type: {e}"),
+        });
+        (StatusCode::UNPROCESSABLE_ENTITY, axum::Json(payload))
+    })?;
+
+    if !unknown_fields.is_empty() {
+        let payload = json!({
+            "error": "Unknown fields",
+            "message": format!("Unknown fields in request body: {}", unknown_fields.join(", ")),
+            "fields": unknown_fields,
+            "truncated": truncated,
+        });
+        return Err((StatusCode::UNPROCESSABLE_ENTITY, axum::Json(payload)));
+    }
+
+    Ok(parsed)
+}
+
+fn collect_validation_errors(
+    errors: &ValidationErrors,
+    max_errors: usize,
+) -> (Vec<(String, String)>, bool) {
+    let field_errors = errors.field_errors();
+    let mut fields: Vec<_> = field_errors.iter().collect();
+    fields.sort_by(|a, b| a.0.cmp(b.0));
+
+    let mut collected = Vec::new();
+
+    for (field, field_errors) in fields {
+        for error in field_errors.iter() {
+            if collected.len() == max_errors {
+                return (collected, true);
+            }
 
-    for (field, field_errors) in errors.field_errors() {
-        for error in field_errors {
             let message = error
                 .message
                 .as_ref()
@@ -57,39 +123,82 @@ fn format_validation_errors(errors: &ValidationErrors) -> String {
                     "custom" => "Custom validation failed".to_string(),
                     _ => format!("Invalid {field}"),
                 });
-            error_messages.push(format!("{field}: {message}"));
+            collected.push((field.to_string(), message));
         }
     }
 
-    if error_messages.is_empty() {
-        "Validation failed".to_string()
-    } else {
-        error_messages.join("; ")
+    (collected, false)
+}
+
+fn format_validation_errors(errors: &[(String, String)]) -> String {
+    if errors.is_empty() {
+        return "Validation failed".to_string();
     }
+
+    errors
+        .iter()
+        .map(|(field, message)| format!("{field}: {message}"))
+        .collect::<Vec<_>>()
+        .join("; ")
 }
 
-fn format_validation_errors_detailed(errors: &ValidationErrors) -> Value {
+fn format_validation_errors_detailed(errors: &[(String, String)]) -> Value {
     let mut error_map = serde_json::Map::new();
 
-    for (field, field_errors) in errors.field_errors() {
-        let messages: Vec<String> = field_errors
-            .iter()
-            .map(|e| {
-                e.message
-                    .as_ref()
-                    .map(|m| m.to_string())
-                    .unwrap_or_else(|| match e.code.as_ref() {
-                        "email" => "Invalid email format".to_string(),
-                        "url" => "Invalid URL format".to_string(),
-                        "length" => "Invalid length".to_string(),
-                        "range" => "Value out of range".to_string(),
-                        "custom" => "Custom validation failed".to_string(),
-                        _ => format!("Invalid {field}"),
-                    })
-            })
-            .collect();
-        error_map.insert(field.to_string(), json!(messages));
+    for (field, message) in errors {
+        let entry = error_map
+            .entry(field.clone())
+            .or_insert_with(|| Value::Array(Vec::new()));
+        if let Value::Array(messages) = entry {
+            messages.push(json!(message));
+        }
     }
 
     json!(error_map)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use validator::ValidationError;
+
+    fn errors() -> ValidationErrors {
+        let mut errors = ValidationErrors::new();
+        errors.add("email", ValidationError::new("email"));
+        errors.add(
+            "amount",
+            ValidationError::new("range").with_message("Amount too small".into()),
+        );
+        errors.add("card_number", ValidationError::new("length"));
+        errors.add("card_number", ValidationError::new("unknown"));
+        errors
+    }
+
+    #[test]
+    fn collects_errors_sorted_by_field_with_fallback_messages() {
+        let (collected, truncated) = collect_validation_errors(&errors(), 10);
+
+        assert!(!truncated);
+        assert_eq!(
+            collected,
+            vec![
+                ("amount".to_string(), "Amount too small".to_string()),
+                ("card_number".to_string(), "Invalid length".to_string()),
+                ("card_number".to_string(), "Invalid card_number".to_string()),
+                ("email".to_string(), "Invalid email format".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn stops_at_the_error_cap() {
+        let (collected, truncated) = collect_validation_errors(&errors(), 2);
+
+        assert!(truncated);
+        assert_eq!(collected.len(), 2);
+
+        let (collected, truncated) = collect_validation_errors(&errors(), 4);
+        assert!(!truncated);
+        assert_eq!(collected.len(), 4);
+    }
+}
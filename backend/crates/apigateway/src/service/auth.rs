@@ -4,17 +4,19 @@ use anyhow::Result;
 use async_trait::async_trait;
 use chrono::Duration;
 use genproto::auth::{
-    ApiResponseGetMe, ApiResponseLogin, ApiResponseRefreshToken, ApiResponseRegister, GetMeRequest,
-    LoginRequest, RefreshTokenRequest, RegisterRequest as ProtoRegisterRequest,
-    auth_service_client::AuthServiceClient,
+    ApiResponseGetMe, ApiResponseLogin, ApiResponseRefreshToken, ApiResponseRegister,
+    ChangePasswordRequest as ProtoChangePasswordRequest, GetMeRequest, LoginRequest,
+    RefreshTokenRequest, RegisterRequest as ProtoRegisterRequest, ResendVerificationRequest,
+    SendVerificationRequest, VerifyEmailRequest, auth_service_client::AuthServiceClient,
 };
 use opentelemetry::KeyValue;
 use shared::{
     abstract_trait::auth::http::AuthGrpcClientTrait,
     cache::CacheStore,
+    config::LogPrivacyConfig,
     context::shared_resources::SharedResources,
     domain::{
-        requests::auth::{AuthRequest, RegisterRequest},
+        requests::auth::{AuthRequest, ChangePasswordRequest, RegisterRequest},
         responses::{ApiResponse, TokenResponse, UserResponse},
     },
     errors::{AppErrorGrpc, HttpError},
@@ -27,6 +29,7 @@ pub struct AuthGrpcClientService {
     client: AuthServiceClient<Channel>,
     tracing_metrics_core: TracingMetrics,
     cache_store: Arc<CacheStore>,
+    log_privacy: LogPrivacyConfig,
 }
 
 impl AuthGrpcClientService {
@@ -35,6 +38,7 @@ impl AuthGrpcClientService {
             client,
             tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
             cache_store: Arc::clone(&shared.cache_store),
+            log_privacy: LogPrivacyConfig::from_env(),
         })
     }
 }
@@ -42,7 +46,10 @@ impl AuthGrpcClientService {
 #[async_trait]
 impl AuthGrpcClientTrait for AuthGrpcClientService {
     async fn login(&self, req: &AuthRequest) -> Result<ApiResponse<TokenResponse>, HttpError> {
-        info!("Attempting login for email={}", req.email);
+        info!(
+            "Attempting login for email={}",
+            self.log_privacy.email(&req.email)
+        );
 
         let method = Method::Post;
 
@@ -54,7 +61,7 @@ impl AuthGrpcClientTrait for AuthGrpcClientService {
             vec![
                 KeyValue::new("component", "auth"),
                 KeyValue::new("operation", "login"),
-                KeyValue::new("email", email.clone()),
+                KeyValue::new("email", self.log_privacy.email(&email)),
             ],
         );
 
@@ -234,7 +241,7 @@ impl AuthGrpcClientTrait for AuthGrpcClientService {
             vec![
                 KeyValue::new("component", "auth"),
                 KeyValue::new("operation", "register"),
-                KeyValue::new("email", email.clone()),
+                KeyValue::new("email", self.log_privacy.email(&email)),
             ],
         );
 
@@ -280,4 +287,203 @@ impl AuthGrpcClientTrait for AuthGrpcClientService {
             data: domain_user,
         })
     }
+
+    async fn send_verification(&self, user_id: i32) -> Result<ApiResponse<bool>, HttpError> {
+        info!("Requesting verification email for user_id={user_id}");
+
+        let method = Method::Post;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "SendVerification",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "send_verification"),
+                KeyValue::new("user_id", user_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(SendVerificationRequest { user_id });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let response = match self.client.clone().send_verification(request).await {
+            Ok(resp) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Verification email sent")
+                    .await;
+                info!("✅ gRPC send_verification succeeded for user_id={user_id}");
+                resp
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to send verification")
+                    .await;
+                error!("❌ gRPC send_verification failed: {}", status);
+                return Err(AppErrorGrpc::from(status).into());
+            }
+        };
+
+        let inner = response.into_inner();
+
+        Ok(ApiResponse {
+            status: inner.status,
+            message: inner.message,
+            data: true,
+        })
+    }
+
+    async fn resend_verification(&self, email: &str) -> Result<ApiResponse<bool>, HttpError> {
+        info!("Requesting a new verification email");
+
+        let method = Method::Post;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "ResendVerification",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "resend_verification"),
+            ],
+        );
+
+        let mut request = Request::new(ResendVerificationRequest {
+            email: email.to_string(),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let response = match self.client.clone().resend_verification(request).await {
+            Ok(resp) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Verification email resent")
+                    .await;
+                info!("✅ gRPC resend_verification succeeded");
+                resp
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to resend verification")
+                    .await;
+                error!("❌ gRPC resend_verification failed: {}", status);
+                return Err(AppErrorGrpc::from(status).into());
+            }
+        };
+
+        let inner = response.into_inner();
+
+        Ok(ApiResponse {
+            status: inner.status,
+            message: inner.message,
+            data: true,
+        })
+    }
+
+    async fn verify_email(&self, token: &str) -> Result<ApiResponse<bool>, HttpError> {
+        info!("Verifying email token");
+
+        let method = Method::Post;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "VerifyEmail",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "verify_email"),
+            ],
+        );
+
+        let mut request = Request::new(VerifyEmailRequest {
+            token: token.to_string(),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let response = match self.client.clone().verify_email(request).await {
+            Ok(resp) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Email verified")
+                    .await;
+                info!("✅ gRPC verify_email succeeded");
+                resp
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to verify email")
+                    .await;
+                error!("❌ gRPC verify_email failed: {}", status);
+                return Err(AppErrorGrpc::from(status).into());
+            }
+        };
+
+        let inner = response.into_inner();
+
+        Ok(ApiResponse {
+            status: inner.status,
+            message: inner.message,
+            data: true,
+        })
+    }
+
+    async fn change_password(
+        &self,
+        user_id: i32,
+        req: &ChangePasswordRequest,
+    ) -> Result<ApiResponse<TokenResponse>, HttpError> {
+        info!("Changing password for user_id={user_id}");
+
+        let method = Method::Post;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "ChangePassword",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("operation", "change_password"),
+                KeyValue::new("user_id", user_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(ProtoChangePasswordRequest {
+            user_id,
+            current_password: req.current_password.clone(),
+            new_password: req.new_password.clone(),
+            confirm_password: req.confirm_password.clone(),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let response = match self.client.clone().change_password(request).await {
+            Ok(resp) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Password changed")
+                    .await;
+                info!("✅ gRPC change_password succeeded for user_id={user_id}");
+                resp
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to change password")
+                    .await;
+                error!("❌ gRPC change_password failed: {}", status);
+                return Err(AppErrorGrpc::from(status).into());
+            }
+        };
+
+        self.cache_store
+            .delete_from_cache(&format!("auth:get_me:{user_id}"))
+            .await;
+
+        let inner = response.into_inner();
+        let proto_token = inner.data.ok_or_else(|| {
+            error!("❌ gRPC change_password returned empty token response");
+            AppErrorGrpc::Unhandled("Missing token".into())
+        })?;
+
+        Ok(ApiResponse {
+            status: inner.status,
+            message: inner.message,
+            data: proto_token.into(),
+        })
+    }
 }
@@ -3,8 +3,9 @@ use async_trait::async_trait;
 use chrono::Duration;
 use genproto::card::{
     CreateCardRequest, FindAllCardRequest, FindByCardNumberRequest, FindByIdCardRequest,
-    FindByUserIdCardRequest, FindYearAmount, FindYearAmountCardNumber, FindYearBalance,
-    FindYearBalanceCardNumber, UpdateCardRequest, card_service_client::CardServiceClient,
+    FindByUserIdCardRequest, FindDashboardCardNumbersRequest, FindTopCardsRequest, FindYearAmount,
+    FindYearAmountCardNumber, FindYearBalance, FindYearBalanceCardNumber,
+    RestoreCardsByFilterRequest, UpdateCardRequest, card_service_client::CardServiceClient,
 };
 use opentelemetry::KeyValue;
 use shared::{
@@ -22,13 +23,17 @@ use shared::{
     domain::{
         requests::card::{
             CreateCardRequest as DomainCreateCardRequest, FindAllCards as DomainFindAllCardRequest,
+            FindCardDashboards as DomainFindCardDashboards, FindTopCards as DomainFindTopCards,
             MonthYearCardNumberCard as DomainMonthYearCardNumberCard,
+            RestoreCardsByFilter as DomainRestoreCardsByFilter,
             UpdateCardRequest as DomainUpdateCardRequest,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, CardResponse, CardResponseDeleteAt,
-            CardResponseMonthAmount, CardResponseMonthBalance, CardResponseYearAmount,
-            CardResponseYearlyBalance, DashboardCard, DashboardCardCardNumber,
+            ApiResponse, ApiResponsePagination, BulkResult, CardFreezeResponse,
+            CardReissueResponse, CardResponse, CardResponseDeleteAt, CardResponseMonthAmount,
+            CardResponseMonthBalance, CardResponseYearAmount, CardResponseYearlyBalance,
+            CardStatusResponse, DashboardCard, DashboardCardCardNumber, DashboardCardNumbers,
+            SystemStatsResponse, TopCardResponse,
         },
     },
     errors::{AppErrorGrpc, HttpError},
@@ -224,6 +229,171 @@ impl CardDashboardGrpcClientTrait for CardGrpcClientService {
             }
         }
     }
+
+    #[instrument(skip(self, request), level = "info")]
+    async fn get_dashboard_bycards(
+        &self,
+        request: &DomainFindCardDashboards,
+    ) -> Result<ApiResponse<DashboardCardNumbers>, HttpError> {
+        let method = Method::Get;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "GetDashboardByCardNumbers",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "get_dashboard_by_cards"),
+                KeyValue::new("card_count", request.card_numbers.len() as i64),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindDashboardCardNumbersRequest {
+            card_numbers: request.card_numbers.clone(),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().dashboard_card_numbers(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched dashboard data by card numbers",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+
+                Ok(ApiResponse {
+                    data: DashboardCardNumbers {
+                        dashboards: inner
+                            .dashboards
+                            .into_iter()
+                            .map(|(card_number, dashboard)| (card_number, dashboard.into()))
+                            .collect(),
+                    },
+                    message: inner.message,
+                    status: inner.status,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to fetch dashboard data by card numbers",
+                    )
+                    .await;
+                error!("card dashboards - gRPC failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
+    #[instrument(skip_all)]
+    async fn get_system_stats(&self) -> Result<ApiResponse<SystemStatsResponse>, HttpError> {
+        let method = Method::Get;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "GetSystemStats",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "get_system_stats"),
+            ],
+        );
+
+        let mut request = Request::new(());
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.clone().system_stats(request).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched system stats",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+
+                let data = inner.data.ok_or_else(|| {
+                    error!("System stats data is missing in gRPC response");
+                    HttpError::Internal("System stats data is missing in gRPC response".into())
+                })?;
+
+                Ok(ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: data.into(),
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to fetch system stats")
+                    .await;
+                error!("system stats - gRPC failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, req), fields(metric = %req.metric))]
+    async fn get_top_cards(
+        &self,
+        req: &DomainFindTopCards,
+    ) -> Result<ApiResponse<Vec<TopCardResponse>>, HttpError> {
+        let method = Method::Get;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "GetTopCards",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "get_top_cards"),
+                KeyValue::new("metric", req.metric.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(FindTopCardsRequest {
+            metric: req.metric.to_string(),
+            limit: req.limit,
+            start_date: req.start_date.map(|d| d.to_string()).unwrap_or_default(),
+            end_date: req.end_date.map(|d| d.to_string()).unwrap_or_default(),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.client.clone().top_cards(request).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched top cards",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+
+                Ok(ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: inner.data.into_iter().map(Into::into).collect(),
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to fetch top cards")
+                    .await;
+                error!("top cards - gRPC failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -258,6 +428,7 @@ impl CardQueryGrpcClientTrait for CardGrpcClientService {
             page,
             page_size,
             search: search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -296,6 +467,7 @@ impl CardQueryGrpcClientTrait for CardGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 info!("fetched {} cards", api_response.data.len());
@@ -346,6 +518,7 @@ impl CardQueryGrpcClientTrait for CardGrpcClientService {
             page,
             page_size,
             search: search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -389,6 +562,7 @@ impl CardQueryGrpcClientTrait for CardGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 info!("fetched {} active cards", api_response.data.len());
@@ -439,14 +613,16 @@ impl CardQueryGrpcClientTrait for CardGrpcClientService {
             page,
             page_size,
             search: search.clone(),
+            sort_by: req.sort_by.clone().unwrap_or_default(),
         });
 
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
 
         let cache_key = format!(
-            "user:find_by_trashed:page:{page}:size:{page_size}:search:{}",
-            search
+            "card:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            search,
+            req.sort_by.as_deref().unwrap_or_default()
         );
 
         if let Some(cached_result) = self
@@ -486,6 +662,7 @@ impl CardQueryGrpcClientTrait for CardGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 info!("fetched {} trashed cards", api_response.data.len());
@@ -658,6 +835,63 @@ impl CardQueryGrpcClientTrait for CardGrpcClientService {
         }
     }
 
+    #[instrument(skip(self), level = "info")]
+    async fn find_all_by_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<CardResponse>>, HttpError> {
+        info!("fetching all cards by user_id: {user_id}");
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "FindAllCardsByUserId",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "find_all_by_user_id"),
+                KeyValue::new("user_id", user_id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindByUserIdCardRequest { user_id });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().find_all_by_user_id_card(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched cards by user id",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                let data: Vec<CardResponse> = inner.data.into_iter().map(Into::into).collect();
+
+                info!("found {} cards for user {user_id}", data.len());
+
+                Ok(ApiResponse {
+                    data,
+                    message: inner.message,
+                    status: inner.status,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to fetch cards by user id",
+                    )
+                    .await;
+                error!("user {user_id} - gRPC failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "info")]
     async fn find_by_card_number(
         &self,
@@ -740,6 +974,57 @@ impl CardQueryGrpcClientTrait for CardGrpcClientService {
             }
         }
     }
+
+    #[instrument(skip(self, card_number), level = "info")]
+    async fn check_card(
+        &self,
+        card_number: String,
+    ) -> Result<ApiResponse<CardStatusResponse>, HttpError> {
+        let masked = mask_card_number(&card_number);
+        info!("checking card status: {masked}");
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "CheckCard",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "check_card"),
+                KeyValue::new("card_number", masked.clone()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindByCardNumberRequest { card_number });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().check_card(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Card status checked")
+                    .await;
+
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    error!("card {masked} - missing status in gRPC response");
+                    HttpError::Internal("Card status is missing in gRPC response".into())
+                })?;
+
+                Ok(ApiResponse {
+                    data: data.into(),
+                    message: inner.message,
+                    status: inner.status,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to check card status")
+                    .await;
+                error!("card {masked} - check gRPC failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -964,6 +1249,169 @@ impl CardCommandGrpcClientTrait for CardGrpcClientService {
         }
     }
 
+    #[instrument(skip(self), level = "info")]
+    async fn reissue(&self, id: i32) -> Result<ApiResponse<CardReissueResponse>, HttpError> {
+        info!("reissuing card id: {id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "ReissueCard",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "reissue"),
+                KeyValue::new("card_id", id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindByIdCardRequest { card_id: id });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().reissue_card(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Successfully reissued card")
+                    .await;
+
+                let inner = response.into_inner();
+                let (previous_card, card) = inner
+                    .data
+                    .and_then(|data| data.previous_card.zip(data.card))
+                    .ok_or_else(|| {
+                        error!("card {id} - reissue data missing in gRPC response");
+                        HttpError::Internal("Card data is missing in gRPC response".into())
+                    })?;
+
+                let api_response = ApiResponse {
+                    data: CardReissueResponse {
+                        previous_card: previous_card.into(),
+                        card: card.into(),
+                    },
+                    status: inner.status,
+                    message: inner.message,
+                };
+
+                let cache_key_delete = vec![
+                    format!("card:find_by_id:{id}"),
+                    "card:find_all:*".to_string(),
+                    "card:find_by_active:*".to_string(),
+                    "card:find_by_trashed:*".to_string(),
+                ];
+
+                for key_delete in cache_key_delete {
+                    self.cache_store.delete_from_cache(&key_delete).await;
+                }
+
+                info!("card {id} reissued successfully");
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to reissue card")
+                    .await;
+                error!("reissue card {id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
+    #[instrument(skip(self), level = "info")]
+    async fn freeze(&self, id: i32) -> Result<ApiResponse<CardFreezeResponse>, HttpError> {
+        info!("freezing card id: {id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "FreezeCard",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "freeze"),
+                KeyValue::new("card_id", id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindByIdCardRequest { card_id: id });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().freeze_card(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Successfully froze card")
+                    .await;
+
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    error!("card {id} - freeze data missing in gRPC response");
+                    HttpError::Internal("Card data is missing in gRPC response".into())
+                })?;
+
+                info!("card {id} frozen successfully");
+                Ok(ApiResponse {
+                    data: data.into(),
+                    status: inner.status,
+                    message: inner.message,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to freeze card")
+                    .await;
+                error!("freeze card {id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
+    #[instrument(skip(self), level = "info")]
+    async fn unfreeze(&self, id: i32) -> Result<ApiResponse<CardFreezeResponse>, HttpError> {
+        info!("unfreezing card id: {id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "UnfreezeCard",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "unfreeze"),
+                KeyValue::new("card_id", id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindByIdCardRequest { card_id: id });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().unfreeze_card(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Successfully unfroze card")
+                    .await;
+
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    error!("card {id} - unfreeze data missing in gRPC response");
+                    HttpError::Internal("Card data is missing in gRPC response".into())
+                })?;
+
+                info!("card {id} unfrozen successfully");
+                Ok(ApiResponse {
+                    data: data.into(),
+                    status: inner.status,
+                    message: inner.message,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to unfreeze card")
+                    .await;
+                error!("unfreeze card {id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "info")]
     async fn restore(&self, id: i32) -> Result<ApiResponse<CardResponseDeleteAt>, HttpError> {
         info!("restoring card id: {id}");
@@ -1001,7 +1449,7 @@ impl CardCommandGrpcClientTrait for CardGrpcClientService {
                     format!("card:find_by_card:number:{}", data.card_number),
                     "card:find_all:*".to_string(),
                     "user:find_by_active:*".to_string(),
-                    "user:find_by_trashed:*".to_string(),
+                    "card:find_by_trashed:*".to_string(),
                 ];
 
                 for key in cache_keys {
@@ -1066,7 +1514,7 @@ impl CardCommandGrpcClientTrait for CardGrpcClientService {
                     "card:find_by_card_number:number:*".to_string(),
                     "card:find_all:*".to_string(),
                     "user:find_by_active:*".to_string(),
-                    "user:find_by_trashed:*".to_string(),
+                    "card:find_by_trashed:*".to_string(),
                 ];
 
                 for key in cache_keys {
@@ -1098,7 +1546,7 @@ impl CardCommandGrpcClientTrait for CardGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("restoring all trashed cards");
 
         let method = Method::Post;
@@ -1129,7 +1577,7 @@ impl CardCommandGrpcClientTrait for CardGrpcClientService {
                 info!("all trashed cards restored successfully");
 
                 let cache_keys = vec![
-                    "user:find_by_trashed:*",
+                    "card:find_by_trashed:*",
                     "user:find_by_active:*",
                     "card:find_all:*",
                 ];
@@ -1139,7 +1587,7 @@ impl CardCommandGrpcClientTrait for CardGrpcClientService {
                 }
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
@@ -1160,8 +1608,83 @@ impl CardCommandGrpcClientTrait for CardGrpcClientService {
         }
     }
 
+    #[instrument(skip(self, filter), level = "info")]
+    async fn restore_by_filter(
+        &self,
+        filter: &DomainRestoreCardsByFilter,
+    ) -> Result<ApiResponse<Vec<i32>>, HttpError> {
+        info!(
+            "restoring trashed cards by filter - user_id: {:?}, deleted_from: {:?}, deleted_to: {:?}",
+            filter.user_id, filter.deleted_from, filter.deleted_to
+        );
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "RestoreCardsByFilter",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "restore_by_filter"),
+            ],
+        );
+
+        let mut grpc_req = Request::new(RestoreCardsByFilterRequest {
+            search: filter.search.clone(),
+            user_id: filter.user_id,
+            deleted_from: filter.deleted_from.map(naive_date_to_timestamp),
+            deleted_to: filter.deleted_to.map(naive_date_to_timestamp),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().restore_cards_by_filter(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully restored trashed cards by filter",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                info!(
+                    "restored {} trashed cards by filter",
+                    inner.restored_ids.len()
+                );
+
+                let cache_keys = vec![
+                    "card:find_trashed:*",
+                    "card:find_active:*",
+                    "card:find_all:*",
+                ];
+
+                for key in cache_keys {
+                    self.cache_store.delete_from_cache(key).await;
+                }
+
+                Ok(ApiResponse {
+                    data: inner.restored_ids,
+                    status: inner.status,
+                    message: inner.message,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to restore trashed cards by filter",
+                    )
+                    .await;
+                error!("restore cards by filter failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "info")]
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("permanently deleting all cards");
 
         let method = Method::Post;
@@ -1196,7 +1719,7 @@ impl CardCommandGrpcClientTrait for CardGrpcClientService {
                 let inner = response.into_inner();
 
                 let cache_keys = vec![
-                    "user:find_by_trashed:*",
+                    "card:find_by_trashed:*",
                     "user:find_by_active:*",
                     "card:find_all:*",
                 ];
@@ -1206,7 +1729,7 @@ impl CardCommandGrpcClientTrait for CardGrpcClientService {
                 }
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
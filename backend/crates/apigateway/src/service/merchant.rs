@@ -4,11 +4,12 @@ use chrono::Duration;
 use genproto::merchant::{
     CreateMerchantRequest, FindAllMerchantApikey, FindAllMerchantRequest,
     FindAllMerchantTransaction, FindByApiKeyRequest, FindByIdMerchantRequest,
-    FindByMerchantUserIdRequest, FindYearMerchant, FindYearMerchantByApikey, FindYearMerchantById,
+    FindByMerchantUserIdRequest, FindMerchantTransactionDaily, FindYearMerchant,
+    FindYearMerchantByApikey, FindYearMerchantById, MerchantUserRequest, SetMerchantWebhookRequest,
     UpdateMerchantRequest, merchant_service_client::MerchantServiceClient,
 };
 use opentelemetry::KeyValue;
-use shared::utils::mask_api_key;
+use shared::utils::{mask_api_key, naive_date_to_timestamp};
 use shared::{
     abstract_trait::merchant::http::{
         MerchantCommandGrpcClientTrait, MerchantGrpcClientServiceTrait,
@@ -28,20 +29,24 @@ use shared::{
             FindAllMerchantTransactionsByApiKey as DomainFindAllMerchantTransactionsByApiKey,
             FindAllMerchantTransactionsById as DomainFindAllMerchantTransactionsById,
             FindAllMerchants as DomainFindAllMerchants,
+            FindMerchantTransactionsDaily as DomainFindMerchantTransactionsDaily,
+            MerchantUserRequest as DomainMerchantUserRequest,
             MonthYearAmountApiKey as DomainMonthYearAmountApiKey,
             MonthYearAmountMerchant as DomainMonthYearAmountMerchant,
             MonthYearPaymentMethodApiKey as DomainMonthYearPaymentMethodApiKey,
             MonthYearPaymentMethodMerchant as DomainMonthYearPaymentMethodMerchant,
             MonthYearTotalAmountApiKey as DomainMonthYearTotalAmountApiKey,
             MonthYearTotalAmountMerchant as DomainMonthYearTotalAmountMerchant,
+            SetMerchantWebhookRequest as DomainSetMerchantWebhookRequest,
             UpdateMerchantRequest as DomainUpdateMerchantRequest,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, MerchantResponse, MerchantResponseDeleteAt,
-            MerchantResponseMonthlyAmount, MerchantResponseMonthlyPaymentMethod,
-            MerchantResponseMonthlyTotalAmount, MerchantResponseYearlyAmount,
-            MerchantResponseYearlyPaymentMethod, MerchantResponseYearlyTotalAmount,
-            MerchantTransactionResponse,
+            ApiResponse, ApiResponsePagination, BulkResult, MerchantApiKeyUsageResponse,
+            MerchantResponse, MerchantResponseDeleteAt, MerchantResponseMonthlyAmount,
+            MerchantResponseMonthlyPaymentMethod, MerchantResponseMonthlyTotalAmount,
+            MerchantResponseYearlyAmount, MerchantResponseYearlyPaymentMethod,
+            MerchantResponseYearlyTotalAmount, MerchantSettlementResponse,
+            MerchantTransactionDailyResponse, MerchantTransactionResponse, MerchantWebhookResponse,
         },
     },
     errors::{AppErrorGrpc, HttpError},
@@ -101,6 +106,7 @@ impl MerchantQueryGrpcClientTrait for MerchantGrpcClientService {
             page,
             page_size,
             search: request.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -143,6 +149,7 @@ impl MerchantQueryGrpcClientTrait for MerchantGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -193,6 +200,7 @@ impl MerchantQueryGrpcClientTrait for MerchantGrpcClientService {
             page,
             page_size,
             search: request.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -240,6 +248,7 @@ impl MerchantQueryGrpcClientTrait for MerchantGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -293,14 +302,16 @@ impl MerchantQueryGrpcClientTrait for MerchantGrpcClientService {
             page,
             page_size,
             search: request.search.clone(),
+            sort_by: request.sort_by.clone().unwrap_or_default(),
         });
 
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
 
         let cache_key = format!(
-            "merchant:find_by_trashed:page:{page}:size:{page_size}:search:{}",
-            request.search.clone()
+            "merchant:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            request.search.clone(),
+            request.sort_by.as_deref().unwrap_or_default()
         );
 
         if let Some(cache) = self
@@ -340,6 +351,7 @@ impl MerchantQueryGrpcClientTrait for MerchantGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -531,6 +543,65 @@ impl MerchantQueryGrpcClientTrait for MerchantGrpcClientService {
         }
     }
 
+    #[instrument(skip(self), level = "info")]
+    async fn list_accessible_merchants(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<MerchantResponse>>, HttpError> {
+        info!("listing merchants accessible to user_id: {user_id}");
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "ListAccessibleMerchants",
+            vec![
+                KeyValue::new("component", "merchant"),
+                KeyValue::new("operation", "list_accessible"),
+                KeyValue::new("user_id", user_id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindByMerchantUserIdRequest { user_id });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self
+            .client
+            .clone()
+            .list_accessible_merchants(grpc_req)
+            .await
+        {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully listed accessible merchants",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+
+                Ok(ApiResponse {
+                    data: inner.data.into_iter().map(Into::into).collect(),
+                    message: inner.message,
+                    status: inner.status,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to list accessible merchants",
+                    )
+                    .await;
+                error!("list accessible merchants for user_id {user_id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "info")]
     async fn find_by_id(&self, id: i32) -> Result<ApiResponse<MerchantResponse>, HttpError> {
         info!("fetching merchant by id: {id}");
@@ -637,6 +708,7 @@ impl MerchantTransactionGrpcClientTrait for MerchantGrpcClientService {
             page,
             page_size,
             search: search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -689,6 +761,7 @@ impl MerchantTransactionGrpcClientTrait for MerchantGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
                 self.cache_store
                     .set_to_cache(&cache_key, &api_response, Duration::minutes(10))
@@ -795,6 +868,7 @@ impl MerchantTransactionGrpcClientTrait for MerchantGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -906,6 +980,7 @@ impl MerchantTransactionGrpcClientTrait for MerchantGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -936,6 +1011,322 @@ impl MerchantTransactionGrpcClientTrait for MerchantGrpcClientService {
             }
         }
     }
+
+    #[instrument(skip(self, request), level = "info")]
+    async fn find_daily_transactions_by_id(
+        &self,
+        request: &DomainFindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<Vec<MerchantTransactionDailyResponse>>, HttpError> {
+        let merchant_id = request.merchant_id;
+
+        info!(
+            "fetching daily merchant transactions for merchant_id: {merchant_id} - range: {} to {}",
+            request.start_date, request.end_date
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "FindDailyTransactionByMerchant",
+            vec![
+                KeyValue::new("component", "merchant_transaction"),
+                KeyValue::new("operation", "find_daily_transactions_by_id"),
+                KeyValue::new("merchant_id", merchant_id.to_string()),
+                KeyValue::new("start_date", request.start_date.to_string()),
+                KeyValue::new("end_date", request.end_date.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindMerchantTransactionDaily {
+            merchant_id,
+            start_date: Some(naive_date_to_timestamp(request.start_date)),
+            end_date: Some(naive_date_to_timestamp(request.end_date)),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        let cache_key = format!(
+            "merchant_transaction:find_daily_by_id:merchant_id:{merchant_id}:start:{}:end:{}",
+            request.start_date, request.end_date
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponse<Vec<MerchantTransactionDailyResponse>>>(&cache_key)
+            .await
+        {
+            info!("✅ Found daily merchant transactions in cache");
+            self.tracing_metrics_core
+                .complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Daily merchant transactions retrieved from cache",
+                )
+                .await;
+            return Ok(cache);
+        }
+
+        match self
+            .client
+            .clone()
+            .find_daily_transaction_by_merchant(grpc_req)
+            .await
+        {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched daily merchant transactions",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                let data: Vec<MerchantTransactionDailyResponse> =
+                    inner.data.into_iter().map(Into::into).collect();
+
+                let api_response = ApiResponse {
+                    data,
+                    message: inner.message,
+                    status: inner.status,
+                };
+
+                self.cache_store
+                    .set_to_cache(&cache_key, &api_response, Duration::minutes(10))
+                    .await;
+
+                info!(
+                    "fetched {} daily buckets for merchant {merchant_id}",
+                    api_response.data.len()
+                );
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to fetch daily merchant transactions",
+                    )
+                    .await;
+                error!("fetch daily transactions for merchant {merchant_id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+    #[instrument(skip(self, request), level = "info")]
+    async fn find_api_key_usage_by_id(
+        &self,
+        request: &DomainFindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<Vec<MerchantApiKeyUsageResponse>>, HttpError> {
+        let merchant_id = request.merchant_id;
+
+        info!(
+            "fetching API key usage for merchant_id: {merchant_id} - range: {} to {}",
+            request.start_date, request.end_date
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "FindApiKeyUsageByMerchant",
+            vec![
+                KeyValue::new("component", "merchant_transaction"),
+                KeyValue::new("operation", "find_api_key_usage_by_id"),
+                KeyValue::new("merchant_id", merchant_id.to_string()),
+                KeyValue::new("start_date", request.start_date.to_string()),
+                KeyValue::new("end_date", request.end_date.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindMerchantTransactionDaily {
+            merchant_id,
+            start_date: Some(naive_date_to_timestamp(request.start_date)),
+            end_date: Some(naive_date_to_timestamp(request.end_date)),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        let cache_key = format!(
+            "merchant_transaction:find_api_key_usage_by_id:merchant_id:{merchant_id}:start:{}:end:{}",
+            request.start_date, request.end_date
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponse<Vec<MerchantApiKeyUsageResponse>>>(&cache_key)
+            .await
+        {
+            info!("✅ Found merchant API key usage in cache");
+            self.tracing_metrics_core
+                .complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Merchant API key usage retrieved from cache",
+                )
+                .await;
+            return Ok(cache);
+        }
+
+        match self
+            .client
+            .clone()
+            .find_api_key_usage_by_merchant(grpc_req)
+            .await
+        {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched merchant API key usage",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                let data: Vec<MerchantApiKeyUsageResponse> =
+                    inner.data.into_iter().map(Into::into).collect();
+
+                let api_response = ApiResponse {
+                    data,
+                    message: inner.message,
+                    status: inner.status,
+                };
+
+                self.cache_store
+                    .set_to_cache(&cache_key, &api_response, Duration::minutes(10))
+                    .await;
+
+                info!(
+                    "fetched usage for {} API keys of merchant {merchant_id}",
+                    api_response.data.len()
+                );
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to fetch merchant API key usage",
+                    )
+                    .await;
+                error!("fetch API key usage for merchant {merchant_id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), level = "info")]
+    async fn settlement(
+        &self,
+        request: &DomainFindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<MerchantSettlementResponse>, HttpError> {
+        let merchant_id = request.merchant_id;
+
+        info!(
+            "fetching settlement for merchant_id: {merchant_id} - range: {} to {}",
+            request.start_date, request.end_date
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "FindSettlementByMerchant",
+            vec![
+                KeyValue::new("component", "merchant_transaction"),
+                KeyValue::new("operation", "settlement"),
+                KeyValue::new("merchant_id", merchant_id.to_string()),
+                KeyValue::new("start_date", request.start_date.to_string()),
+                KeyValue::new("end_date", request.end_date.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindMerchantTransactionDaily {
+            merchant_id,
+            start_date: Some(naive_date_to_timestamp(request.start_date)),
+            end_date: Some(naive_date_to_timestamp(request.end_date)),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        let cache_key = format!(
+            "merchant_transaction:settlement:merchant_id:{merchant_id}:start:{}:end:{}",
+            request.start_date, request.end_date
+        );
+
+        if request.is_closed()
+            && let Some(cache) = self
+                .cache_store
+                .get_from_cache::<ApiResponse<MerchantSettlementResponse>>(&cache_key)
+                .await
+        {
+            info!("✅ Found merchant settlement in cache");
+            self.tracing_metrics_core
+                .complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Merchant settlement retrieved from cache",
+                )
+                .await;
+            return Ok(cache);
+        }
+
+        match self
+            .client
+            .clone()
+            .find_settlement_by_merchant(grpc_req)
+            .await
+        {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched merchant settlement",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    error!("settlement for merchant {merchant_id} - data missing in gRPC response");
+                    HttpError::Internal("Settlement data is missing in gRPC response".into())
+                })?;
+
+                let api_response: ApiResponse<MerchantSettlementResponse> = ApiResponse {
+                    data: data.into(),
+                    message: inner.message,
+                    status: inner.status,
+                };
+
+                if request.is_closed() {
+                    self.cache_store
+                        .set_to_cache(&cache_key, &api_response, Duration::minutes(10))
+                        .await;
+                }
+
+                info!(
+                    "fetched settlement for merchant {merchant_id}: net={}",
+                    api_response.data.net
+                );
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to fetch merchant settlement",
+                    )
+                    .await;
+                error!("fetch settlement for merchant {merchant_id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -960,6 +1351,7 @@ impl MerchantCommandGrpcClientTrait for MerchantGrpcClientService {
         let mut grpc_req = Request::new(CreateMerchantRequest {
             name: request.name.clone(),
             user_id: request.user_id,
+            business_id: request.business_id.clone(),
         });
 
         self.tracing_metrics_core
@@ -1008,9 +1400,17 @@ impl MerchantCommandGrpcClientTrait for MerchantGrpcClientService {
                     ),
                 ];
 
+                let cached_response = ApiResponse {
+                    data: MerchantResponse {
+                        api_key: masked_key,
+                        ..merchant_response
+                    },
+                    ..api_response.clone()
+                };
+
                 for key in cache_key {
                     self.cache_store
-                        .set_to_cache(&key, &api_response, Duration::minutes(10))
+                        .set_to_cache(&key, &cached_response, Duration::minutes(10))
                         .await;
                 }
 
@@ -1347,7 +1747,7 @@ impl MerchantCommandGrpcClientTrait for MerchantGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("restoring all trashed merchants");
 
         let method = Method::Post;
@@ -1377,7 +1777,7 @@ impl MerchantCommandGrpcClientTrait for MerchantGrpcClientService {
                 let inner = response.into_inner();
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
@@ -1414,7 +1814,7 @@ impl MerchantCommandGrpcClientTrait for MerchantGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("permanently deleting all merchants");
 
         let method = Method::Post;
@@ -1449,7 +1849,7 @@ impl MerchantCommandGrpcClientTrait for MerchantGrpcClientService {
                 let inner = response.into_inner();
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
@@ -1484,6 +1884,223 @@ impl MerchantCommandGrpcClientTrait for MerchantGrpcClientService {
             }
         }
     }
+
+    #[instrument(skip(self, request), level = "info")]
+    async fn set_webhook(
+        &self,
+        request: &DomainSetMerchantWebhookRequest,
+    ) -> Result<ApiResponse<MerchantWebhookResponse>, HttpError> {
+        let merchant_id = request
+            .merchant_id
+            .ok_or_else(|| HttpError::Internal("merchant_id is required".to_string()))?;
+
+        info!("setting webhook for merchant id: {merchant_id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "SetMerchantWebhook",
+            vec![
+                KeyValue::new("component", "merchant"),
+                KeyValue::new("operation", "set_webhook"),
+                KeyValue::new("merchant_id", merchant_id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(SetMerchantWebhookRequest {
+            merchant_id,
+            url: request.url.clone(),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().set_merchant_webhook(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully set merchant webhook",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    error!(
+                        "set webhook for merchant {merchant_id} - data missing in gRPC response"
+                    );
+                    HttpError::Internal("Merchant webhook data is missing in gRPC response".into())
+                })?;
+
+                info!("webhook set for merchant {merchant_id}");
+
+                Ok(ApiResponse {
+                    data: data.into(),
+                    status: inner.status,
+                    message: inner.message,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to set merchant webhook")
+                    .await;
+                error!("set webhook for merchant {merchant_id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), level = "info")]
+    async fn grant_user(
+        &self,
+        request: &DomainMerchantUserRequest,
+    ) -> Result<ApiResponse<bool>, HttpError> {
+        info!(
+            "granting user {} access to merchant {}",
+            request.user_id, request.merchant_id
+        );
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "GrantMerchantUser",
+            vec![
+                KeyValue::new("component", "merchant"),
+                KeyValue::new("operation", "grant_user"),
+                KeyValue::new("merchant_id", request.merchant_id.to_string()),
+                KeyValue::new("user_id", request.user_id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(MerchantUserRequest {
+            merchant_id: request.merchant_id,
+            user_id: request.user_id,
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().grant_merchant_user(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully granted merchant access",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+
+                let api_response = ApiResponse {
+                    data: true,
+                    status: inner.status,
+                    message: inner.message,
+                };
+
+                self.cache_store
+                    .delete_from_cache(&format!(
+                        "merchant:find_by_user_id:user_id:{}",
+                        request.user_id
+                    ))
+                    .await;
+
+                info!(
+                    "user {} granted access to merchant {}",
+                    request.user_id, request.merchant_id
+                );
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to grant merchant access")
+                    .await;
+                error!(
+                    "grant merchant {} access for user {} failed: {status:?}",
+                    request.merchant_id, request.user_id
+                );
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), level = "info")]
+    async fn revoke_user(
+        &self,
+        request: &DomainMerchantUserRequest,
+    ) -> Result<ApiResponse<bool>, HttpError> {
+        info!(
+            "revoking user {} access to merchant {}",
+            request.user_id, request.merchant_id
+        );
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "RevokeMerchantUser",
+            vec![
+                KeyValue::new("component", "merchant"),
+                KeyValue::new("operation", "revoke_user"),
+                KeyValue::new("merchant_id", request.merchant_id.to_string()),
+                KeyValue::new("user_id", request.user_id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(MerchantUserRequest {
+            merchant_id: request.merchant_id,
+            user_id: request.user_id,
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().revoke_merchant_user(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully revoked merchant access",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+
+                let api_response = ApiResponse {
+                    data: true,
+                    status: inner.status,
+                    message: inner.message,
+                };
+
+                self.cache_store
+                    .delete_from_cache(&format!(
+                        "merchant:find_by_user_id:user_id:{}",
+                        request.user_id
+                    ))
+                    .await;
+
+                info!(
+                    "user {} lost access to merchant {}",
+                    request.user_id, request.merchant_id
+                );
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to revoke merchant access",
+                    )
+                    .await;
+                error!(
+                    "revoke merchant {} access for user {} failed: {status:?}",
+                    request.merchant_id, request.user_id
+                );
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -20,7 +20,7 @@ pub use self::transfer::TransferGrpcClientService;
 pub use self::user::UserGrpcClientService;
 pub use self::withdraw::WithdrawGrpcClientService;
 
-use anyhow::{Context, Result};
+use anyhow::{Context, Result, bail};
 use genproto::{
     auth::auth_service_client::AuthServiceClient, card::card_service_client::CardServiceClient,
     merchant::merchant_service_client::MerchantServiceClient,
@@ -31,8 +31,11 @@ use genproto::{
     user::user_service_client::UserServiceClient,
     withdraw::withdraw_service_client::WithdrawServiceClient,
 };
-use shared::config::{GrpcClientConfig, GrpcServiceEndpoints};
-use tonic::transport::{Channel, Endpoint};
+use shared::{
+    config::{DependencyWaitConfig, GrpcClientConfig, GrpcServiceEndpoints, WarmupConfig},
+    utils::{wait_for_dependencies, warm_up_channel},
+};
+use tonic::transport::{Channel, Endpoint, Uri};
 use tracing::info;
 
 #[derive(Clone)]
@@ -47,6 +50,7 @@ pub struct GrpcClients {
     pub transfer: TransferServiceClient<Channel>,
     pub user: UserServiceClient<Channel>,
     pub withdraw: WithdrawServiceClient<Channel>,
+    pub channels: Vec<(&'static str, Channel)>,
 }
 
 impl GrpcClients {
@@ -62,7 +66,35 @@ impl GrpcClients {
         let user_channel = Self::connect(&config.user, "user-service").await?;
         let withdraw_channel = Self::connect(&config.withdraw, "withdraw-service").await?;
 
+        let channels = [
+            (&auth_channel, "auth-service"),
+            (&card_channel, "card-service"),
+            (&merchant_channel, "merchant-service"),
+            (&role_channel, "role-service"),
+            (&saldo_channel, "saldo-service"),
+            (&topup_channel, "topup-service"),
+            (&transaction_channel, "transaction-service"),
+            (&transfer_channel, "transfer-service"),
+            (&user_channel, "user-service"),
+            (&withdraw_channel, "withdraw-service"),
+        ];
+
+        let warmup = WarmupConfig::from_env();
+        for (channel, service) in channels {
+            warm_up_channel(channel, service, &warmup).await;
+        }
+
+        wait_for_dependencies(&channels, &DependencyWaitConfig::from_env())
+            .await
+            .context("failed waiting for downstream services")?;
+
+        let named_channels = channels
+            .iter()
+            .map(|(channel, service)| (service.trim_end_matches("-service"), (*channel).clone()))
+            .collect();
+
         Ok(Self {
+            channels: named_channels,
             auth: AuthServiceClient::new(auth_channel),
             card: CardServiceClient::new(card_channel),
             merchant: MerchantServiceClient::new(merchant_channel),
@@ -81,11 +113,22 @@ impl GrpcClients {
 
         let config_grpc = GrpcClientConfig::from_env()?;
 
+        if config_grpc.pool_size == 0 {
+            bail!("GRPC_CLIENT_POOL_SIZE must be at least 1, cannot build a channel for {service}");
+        }
+
+        let uri: Uri = addr
+            .trim()
+            .parse()
+            .with_context(|| format!("Invalid gRPC address for {service}: {addr:?}"))?;
+        if uri.scheme().is_none() || uri.host().is_none_or(str::is_empty) {
+            bail!("Invalid gRPC address for {service}: {addr:?}, expected http(s)://host:port");
+        }
+
         let mut endpoints = Vec::with_capacity(config_grpc.pool_size);
 
         for _ in 0..config_grpc.pool_size {
-            let ep = Endpoint::from_shared(addr.to_string())
-                .with_context(|| format!("Invalid gRPC address for {service}: {addr}"))?
+            let ep = Endpoint::from(uri.clone())
                 .connect_timeout(config_grpc.connect_timeout())
                 .timeout(config_grpc.request_timeout())
                 .tcp_keepalive(config_grpc.tcp_keepalive())
@@ -17,7 +17,9 @@ use shared::{
             CreateRoleRequest as DomainCreateRoleRequest, FindAllRoles as DomainFindAllRoles,
             UpdateRoleRequest as DomainUpdateRoleRequest,
         },
-        responses::{ApiResponse, ApiResponsePagination, RoleResponse, RoleResponseDeleteAt},
+        responses::{
+            ApiResponse, ApiResponsePagination, BulkResult, RoleResponse, RoleResponseDeleteAt,
+        },
     },
     errors::{AppErrorGrpc, HttpError},
     observability::{Method, TracingMetrics},
@@ -76,6 +78,7 @@ impl RoleQueryGrpcClientTrait for RoleGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -126,6 +129,7 @@ impl RoleQueryGrpcClientTrait for RoleGrpcClientService {
             pagination,
             message: inner.message,
             status: inner.status,
+            links: None,
         };
 
         let role_len = api_response.data.len();
@@ -167,6 +171,7 @@ impl RoleQueryGrpcClientTrait for RoleGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -216,6 +221,7 @@ impl RoleQueryGrpcClientTrait for RoleGrpcClientService {
             pagination,
             message: inner.message,
             status: inner.status,
+            links: None,
         };
 
         let roles_len = api_response.data.len();
@@ -256,14 +262,16 @@ impl RoleQueryGrpcClientTrait for RoleGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: req.sort_by.clone().unwrap_or_default(),
         });
 
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         let cache_key = format!(
-            "role:find_trashed:page:{page}:size:{page_size}:search:{:?}",
-            req.search.clone()
+            "role:find_trashed:page:{page}:size:{page_size}:search:{:?}:sort:{}",
+            req.search.clone(),
+            req.sort_by.as_deref().unwrap_or_default()
         );
 
         if let Some(cache) = self
@@ -305,6 +313,7 @@ impl RoleQueryGrpcClientTrait for RoleGrpcClientService {
             pagination,
             message: inner.message,
             status: inner.status,
+            links: None,
         };
 
         let roles_len = api_response.data.len();
@@ -800,7 +809,7 @@ impl RoleCommandGrpcClientTrait for RoleGrpcClientService {
         Ok(api_response)
     }
     #[instrument(skip(self), level = "info")]
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("Restoring all trashed Roles");
 
         let method = Method::Post;
@@ -837,7 +846,7 @@ impl RoleCommandGrpcClientTrait for RoleGrpcClientService {
         let api_response = ApiResponse {
             status: inner.status,
             message: inner.message,
-            data: true,
+            data: inner.data.map(Into::into).unwrap_or_default(),
         };
 
         let cache_keys = vec![
@@ -855,7 +864,7 @@ impl RoleCommandGrpcClientTrait for RoleGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("Permanently deleting all trashed Roles");
 
         let method = Method::Post;
@@ -896,7 +905,7 @@ impl RoleCommandGrpcClientTrait for RoleGrpcClientService {
         let api_response = ApiResponse {
             status: inner.status,
             message: inner.message,
-            data: true,
+            data: inner.data.map(Into::into).unwrap_or_default(),
         };
 
         let cache_keys = vec![
@@ -4,9 +4,9 @@ use chrono::Duration;
 use genproto::{
     card::FindByCardNumberRequest,
     saldo::{
-        CreateSaldoRequest, FindAllSaldoRequest, FindByIdSaldoRequest,
-        FindMonthlySaldoTotalBalance, FindYearlySaldo, UpdateSaldoRequest,
-        saldo_service_client::SaldoServiceClient,
+        CreateSaldoRequest, FindAllSaldoRequest, FindByIdSaldoRequest, FindCardStatementRequest,
+        FindMonthlySaldoTotalBalance, FindSaldoBalancesRequest, FindYearlySaldo,
+        UpdateSaldoRequest, saldo_service_client::SaldoServiceClient,
     },
 };
 use opentelemetry::KeyValue;
@@ -20,13 +20,16 @@ use shared::{
     domain::{
         requests::saldo::{
             CreateSaldoRequest as DomainCreateSaldoRequest, FindAllSaldos as DomainFindAllSaldos,
+            FindCardStatement as DomainFindCardStatement,
+            FindSaldoBalances as DomainFindSaldoBalances,
             MonthTotalSaldoBalance as DomainMonthTotalSaldoBalance,
             UpdateSaldoRequest as DomainUpdateSaldoRequest,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, SaldoMonthBalanceResponse,
-            SaldoMonthTotalBalanceResponse, SaldoResponse, SaldoResponseDeleteAt,
-            SaldoYearBalanceResponse, SaldoYearTotalBalanceResponse,
+            ApiResponse, ApiResponsePagination, BulkResult, CardStatementResponse,
+            SaldoBalancesResponse, SaldoMonthBalanceResponse, SaldoMonthTotalBalanceResponse,
+            SaldoResponse, SaldoResponseDeleteAt, SaldoYearBalanceResponse,
+            SaldoYearTotalBalanceResponse,
         },
     },
     errors::{AppErrorGrpc, HttpError},
@@ -87,6 +90,7 @@ impl SaldoQueryGrpcClientTrait for SaldoGrpcClientService {
             page,
             page_size,
             search: request.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -126,6 +130,7 @@ impl SaldoQueryGrpcClientTrait for SaldoGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -176,6 +181,7 @@ impl SaldoQueryGrpcClientTrait for SaldoGrpcClientService {
             page,
             page_size,
             search: request.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -220,6 +226,7 @@ impl SaldoQueryGrpcClientTrait for SaldoGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -269,14 +276,16 @@ impl SaldoQueryGrpcClientTrait for SaldoGrpcClientService {
             page,
             page_size,
             search: request.search.clone(),
+            sort_by: request.sort_by.clone().unwrap_or_default(),
         });
 
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
 
         let cache_key = format!(
-            "saldo:find_by_trashed:page:{page}:size:{page_size}:search:{}",
+            "saldo:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
             request.search,
+            request.sort_by.as_deref().unwrap_or_default()
         );
 
         if let Some(cache) = self
@@ -313,6 +322,7 @@ impl SaldoQueryGrpcClientTrait for SaldoGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -492,6 +502,132 @@ impl SaldoQueryGrpcClientTrait for SaldoGrpcClientService {
             }
         }
     }
+
+    #[instrument(skip(self, request), level = "info")]
+    async fn get_balances(
+        &self,
+        request: &DomainFindSaldoBalances,
+    ) -> Result<ApiResponse<SaldoBalancesResponse>, HttpError> {
+        info!(
+            "fetching saldo balances for {} cards",
+            request.card_numbers.len()
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "FindSaldoBalances",
+            vec![
+                KeyValue::new("component", "saldo"),
+                KeyValue::new("operation", "get_balances"),
+                KeyValue::new("card_count", request.card_numbers.len() as i64),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindSaldoBalancesRequest {
+            card_numbers: request.card_numbers.clone(),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self
+            .client
+            .clone()
+            .find_balances_by_card_numbers(grpc_req)
+            .await
+        {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched saldo balances",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+
+                Ok(ApiResponse {
+                    data: SaldoBalancesResponse {
+                        balances: inner.balances.into_iter().collect(),
+                        missing_card_numbers: inner.missing_card_numbers,
+                    },
+                    message: inner.message,
+                    status: inner.status,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to fetch saldo balances")
+                    .await;
+                error!("find saldo balances failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), level = "info")]
+    async fn get_statement(
+        &self,
+        request: &DomainFindCardStatement,
+    ) -> Result<ApiResponse<CardStatementResponse>, HttpError> {
+        let masked_card = mask_card_number(&request.card_number);
+        info!(
+            "fetching statement for card {masked_card}, {}-{:02}",
+            request.year, request.month
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "FindCardStatement",
+            vec![
+                KeyValue::new("component", "saldo"),
+                KeyValue::new("operation", "get_statement"),
+                KeyValue::new("card_number", masked_card.clone()),
+                KeyValue::new("year", request.year as i64),
+                KeyValue::new("month", request.month as i64),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindCardStatementRequest {
+            card_number: request.card_number.clone(),
+            year: request.year,
+            month: request.month,
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().find_card_statement(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched card statement",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    HttpError::Internal("Card statement data is missing in gRPC response".into())
+                })?;
+
+                Ok(ApiResponse {
+                    data: data.into(),
+                    message: inner.message,
+                    status: inner.status,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to fetch card statement")
+                    .await;
+                error!("find statement for card {masked_card} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
 }
 
 #[async_trait]
@@ -504,7 +640,7 @@ impl SaldoCommandGrpcClientTrait for SaldoGrpcClientService {
         let masked_card = mask_card_number(&request.card_number);
 
         info!(
-            "creating saldo for card: {masked_card} with balance: {}",
+            "creating saldo for card: {masked_card} with balance: {:?}",
             request.total_balance
         );
 
@@ -520,7 +656,7 @@ impl SaldoCommandGrpcClientTrait for SaldoGrpcClientService {
 
         let mut grpc_req = Request::new(CreateSaldoRequest {
             card_number: request.card_number.clone(),
-            total_balance: request.total_balance as i32,
+            total_balance: request.total_balance.map(|balance| balance as i32),
         });
 
         self.tracing_metrics_core
@@ -869,7 +1005,7 @@ impl SaldoCommandGrpcClientTrait for SaldoGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("restoring all trashed saldos");
 
         let method = Method::Post;
@@ -900,7 +1036,7 @@ impl SaldoCommandGrpcClientTrait for SaldoGrpcClientService {
                 info!("all trashed saldos restored successfully");
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
@@ -934,7 +1070,7 @@ impl SaldoCommandGrpcClientTrait for SaldoGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("permanently deleting all saldos");
 
         let method = Method::Post;
@@ -969,7 +1105,7 @@ impl SaldoCommandGrpcClientTrait for SaldoGrpcClientService {
                 let inner = response.into_inner();
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
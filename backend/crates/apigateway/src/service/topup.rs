@@ -28,10 +28,11 @@ use shared::{
             YearTopupStatusCardNumber as DomainYearTopupStatusCardNumber,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, TopupMonthAmountResponse, TopupMonthMethodResponse,
-            TopupResponse, TopupResponseDeleteAt, TopupResponseMonthStatusFailed,
-            TopupResponseMonthStatusSuccess, TopupResponseYearStatusFailed,
-            TopupResponseYearStatusSuccess, TopupYearlyAmountResponse, TopupYearlyMethodResponse,
+            ApiResponse, ApiResponsePagination, BulkResult, TopupMonthAmountResponse,
+            TopupMonthMethodResponse, TopupResponse, TopupResponseDeleteAt,
+            TopupResponseMonthStatusFailed, TopupResponseMonthStatusSuccess,
+            TopupResponseYearStatusFailed, TopupResponseYearStatusSuccess,
+            TopupYearlyAmountResponse, TopupYearlyMethodResponse,
         },
     },
     errors::{AppErrorGrpc, HttpError},
@@ -92,6 +93,7 @@ impl TopupQueryGrpcClientTrait for TopupGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -131,6 +133,7 @@ impl TopupQueryGrpcClientTrait for TopupGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -237,6 +240,7 @@ impl TopupQueryGrpcClientTrait for TopupGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -294,6 +298,7 @@ impl TopupQueryGrpcClientTrait for TopupGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -338,6 +343,7 @@ impl TopupQueryGrpcClientTrait for TopupGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -386,14 +392,16 @@ impl TopupQueryGrpcClientTrait for TopupGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: req.sort_by.clone().unwrap_or_default(),
         });
 
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
 
         let cache_key = format!(
-            "topup:find_by_trashed:page:{page}:size:{page_size}:search:{}",
-            req.search
+            "topup:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            req.search,
+            req.sort_by.as_deref().unwrap_or_default()
         );
 
         if let Some(cache) = self
@@ -430,6 +438,7 @@ impl TopupQueryGrpcClientTrait for TopupGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -987,7 +996,7 @@ impl TopupCommandGrpcClientTrait for TopupGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("restoring all trashed topups");
 
         let method = Method::Post;
@@ -1017,7 +1026,7 @@ impl TopupCommandGrpcClientTrait for TopupGrpcClientService {
                 let inner = response.into_inner();
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
@@ -1054,7 +1063,7 @@ impl TopupCommandGrpcClientTrait for TopupGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn delete_all_permanent(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn delete_all_permanent(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("permanently deleting all topups");
 
         let method = Method::Post;
@@ -1089,7 +1098,7 @@ impl TopupCommandGrpcClientTrait for TopupGrpcClientService {
                 let inner = response.into_inner();
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
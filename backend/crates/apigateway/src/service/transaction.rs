@@ -6,7 +6,7 @@ use genproto::transaction::{
     FindByIdTransactionRequest, FindByYearCardNumberTransactionRequest,
     FindMonthlyTransactionStatus, FindMonthlyTransactionStatusCardNumber,
     FindTransactionByMerchantIdRequest, FindYearTransactionStatus,
-    FindYearTransactionStatusCardNumber, UpdateTransactionRequest,
+    FindYearTransactionStatusCardNumber, SearchTransactionsRequest, UpdateTransactionRequest,
     transaction_service_client::TransactionServiceClient,
 };
 use opentelemetry::KeyValue;
@@ -27,11 +27,12 @@ use shared::{
             MonthStatusTransaction as DomainMonthStatusTransaction,
             MonthStatusTransactionCardNumber as DomainMonthStatusTransactionCardNumber,
             MonthYearPaymentMethod as DomainMonthYearPaymentMethod,
+            SearchTransactions as DomainSearchTransactions,
             UpdateTransactionRequest as DomainUpdateTransactionRequest,
             YearStatusTransactionCardNumber as DomainYearStatusTransactionCardNumber,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, TransactionMonthAmountResponse,
+            ApiResponse, ApiResponsePagination, BulkResult, TransactionMonthAmountResponse,
             TransactionMonthMethodResponse, TransactionResponse, TransactionResponseDeleteAt,
             TransactionResponseMonthStatusFailed, TransactionResponseMonthStatusSuccess,
             TransactionResponseYearStatusFailed, TransactionResponseYearStatusSuccess,
@@ -40,7 +41,10 @@ use shared::{
     },
     errors::{AppErrorGrpc, HttpError},
     observability::{Method, TracingMetrics},
-    utils::{mask_api_key, mask_card_number, month_name, naive_datetime_to_timestamp},
+    utils::{
+        mask_api_key, mask_card_number, month_name, naive_date_to_timestamp,
+        naive_datetime_to_timestamp,
+    },
 };
 use std::sync::Arc;
 use tonic::{Request, transport::Channel};
@@ -99,6 +103,7 @@ impl TransactionQueryGrpcClientTrait for TransactionGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -143,6 +148,7 @@ impl TransactionQueryGrpcClientTrait for TransactionGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -250,6 +256,7 @@ impl TransactionQueryGrpcClientTrait for TransactionGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -310,6 +317,7 @@ impl TransactionQueryGrpcClientTrait for TransactionGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -359,6 +367,7 @@ impl TransactionQueryGrpcClientTrait for TransactionGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -412,14 +421,16 @@ impl TransactionQueryGrpcClientTrait for TransactionGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: req.sort_by.clone().unwrap_or_default(),
         });
 
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
 
         let cache_key = format!(
-            "transaction:find_by_trashed:page:{page}:size:{page_size}:search:{}",
-            req.search
+            "transaction:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            req.search,
+            req.sort_by.as_deref().unwrap_or_default()
         );
 
         if let Some(cache) = self
@@ -464,6 +475,7 @@ impl TransactionQueryGrpcClientTrait for TransactionGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -488,6 +500,130 @@ impl TransactionQueryGrpcClientTrait for TransactionGrpcClientService {
         }
     }
 
+    #[instrument(skip(self, req), level = "info")]
+    async fn search(
+        &self,
+        req: &DomainSearchTransactions,
+    ) -> Result<ApiResponsePagination<Vec<TransactionResponse>>, HttpError> {
+        let page = req.page;
+        let page_size = req.page_size;
+
+        let status = req.status.clone().unwrap_or_default();
+        let payment_method = req.payment_method.clone().unwrap_or_default();
+        let card_number = req.card_number.clone().unwrap_or_default();
+        let sort_by = req.sort_by.clone().unwrap_or_default();
+        let sort_dir = req.sort_dir.clone().unwrap_or_default();
+        let metadata_key = req.metadata_key.clone().unwrap_or_default();
+        let metadata_value = req.metadata_value.clone().unwrap_or_default();
+        let merchant_id = req.merchant_id.map(|v| v.to_string()).unwrap_or_default();
+        let min_amount = req.min_amount.map(|v| v.to_string()).unwrap_or_default();
+        let max_amount = req.max_amount.map(|v| v.to_string()).unwrap_or_default();
+        let start_date = req.start_date.map(|d| d.to_string()).unwrap_or_default();
+        let end_date = req.end_date.map(|d| d.to_string()).unwrap_or_default();
+
+        let masked_card = mask_card_number(&card_number);
+
+        info!(
+            "searching transactions - page: {page}, page_size: {page_size}, status: {status:?}, method: {payment_method:?}, card: {masked_card}, merchant: {merchant_id:?}"
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "SearchTransactions",
+            vec![
+                KeyValue::new("component", "transaction"),
+                KeyValue::new("operation", "search"),
+                KeyValue::new("page", page.to_string()),
+                KeyValue::new("page_size", page_size.to_string()),
+                KeyValue::new("status", status.clone()),
+                KeyValue::new("payment_method", payment_method.clone()),
+                KeyValue::new("card_number", masked_card.clone()),
+                KeyValue::new("merchant_id", merchant_id.clone()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(SearchTransactionsRequest {
+            page,
+            page_size,
+            status: status.clone(),
+            payment_method: payment_method.clone(),
+            min_amount: req.min_amount,
+            max_amount: req.max_amount,
+            merchant_id: req.merchant_id,
+            card_number: card_number.clone(),
+            start_date: req.start_date.map(naive_date_to_timestamp),
+            end_date: req.end_date.map(naive_date_to_timestamp),
+            sort_by: sort_by.clone(),
+            sort_dir: sort_dir.clone(),
+            metadata_key: metadata_key.clone(),
+            metadata_value: metadata_value.clone(),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        let cache_key = format!(
+            "transaction:search:page:{page}:size:{page_size}:status:{status}:method:{payment_method}:card:{card_number}:merchant:{merchant_id}:min:{min_amount}:max:{max_amount}:start:{start_date}:end:{end_date}:sort:{sort_by}:{sort_dir}:meta:{metadata_key}={metadata_value}"
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponsePagination<Vec<TransactionResponse>>>(&cache_key)
+            .await
+        {
+            let log_msg = format!(
+                "✅ Found {} searched transactions in cache",
+                cache.data.len()
+            );
+            info!("{log_msg}");
+            self.tracing_metrics_core
+                .complete_tracing_success(&tracing_ctx, method, &log_msg)
+                .await;
+            return Ok(cache);
+        }
+
+        match self.client.clone().search_transactions(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully searched transactions",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                let data: Vec<TransactionResponse> =
+                    inner.data.into_iter().map(Into::into).collect();
+
+                let pagination = inner.pagination.map(Into::into).unwrap_or_default();
+
+                let api_response = ApiResponsePagination {
+                    data,
+                    pagination,
+                    message: inner.message,
+                    status: inner.status,
+                    links: None,
+                };
+
+                self.cache_store
+                    .set_to_cache(&cache_key, &api_response, Duration::minutes(10))
+                    .await;
+
+                info!("fetched {} searched transactions", api_response.data.len());
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to search transactions")
+                    .await;
+                error!("search transactions failed: {status:?}");
+                return Err(AppErrorGrpc::from(status).into());
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "info")]
     async fn find_by_id(
         &self,
@@ -690,6 +826,12 @@ impl TransactionCommandGrpcClientTrait for TransactionGrpcClientService {
             payment_method: req.payment_method.clone(),
             merchant_id: req.merchant_id.unwrap_or(0),
             transaction_time: Some(date),
+            metadata: req
+                .metadata
+                .clone()
+                .unwrap_or_default()
+                .into_iter()
+                .collect(),
         });
 
         self.tracing_metrics_core
@@ -1084,7 +1226,7 @@ impl TransactionCommandGrpcClientTrait for TransactionGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("restoring all trashed transactions");
 
         let method = Method::Post;
@@ -1114,7 +1256,7 @@ impl TransactionCommandGrpcClientTrait for TransactionGrpcClientService {
                 let inner = response.into_inner();
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
@@ -1151,7 +1293,7 @@ impl TransactionCommandGrpcClientTrait for TransactionGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("permanently deleting all transactions");
 
         let method = Method::Post;
@@ -1186,7 +1328,7 @@ impl TransactionCommandGrpcClientTrait for TransactionGrpcClientService {
                 let inner = response.into_inner();
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
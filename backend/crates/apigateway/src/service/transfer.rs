@@ -4,7 +4,8 @@ use chrono::Duration;
 use genproto::transfer::{
     CreateTransferRequest, FindAllTransferRequest, FindByCardNumberTransferRequest,
     FindByIdTransferRequest, FindMonthlyTransferStatus, FindMonthlyTransferStatusCardNumber,
-    FindTransferByTransferFromRequest, FindTransferByTransferToRequest, FindYearTransferStatus,
+    FindTransferByTransferFromRequest, FindTransferByTransferToRequest,
+    FindTransferNetPositionRequest, FindTransfersRequest, FindYearTransferStatus,
     FindYearTransferStatusCardNumber, UpdateTransferRequest,
     transfer_service_client::TransferServiceClient,
 };
@@ -22,14 +23,16 @@ use shared::{
         requests::transfer::{
             CreateTransferRequest as DomainCreateTransferRequest,
             FindAllTransfers as DomainFindAllTransfers,
-            MonthStatusTransfer as DomainMonthStatusTransfer,
+            FindTransferNetPosition as DomainFindTransferNetPosition,
+            FindTransfers as DomainFindTransfers, MonthStatusTransfer as DomainMonthStatusTransfer,
             MonthStatusTransferCardNumber as DomainMonthStatusTransferCardNumber,
             MonthYearCardNumber as DomainMonthYearCardNumber,
             UpdateTransferRequest as DomainUpdateTransferRequest,
             YearStatusTransferCardNumber as DomainYearStatusTransferCardNumber,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, TransferMonthAmountResponse, TransferResponse,
+            ApiResponse, ApiResponsePagination, BalanceImpactResponse, BulkResult,
+            TransferMonthAmountResponse, TransferNetPositionResponse, TransferResponse,
             TransferResponseDeleteAt, TransferResponseMonthStatusFailed,
             TransferResponseMonthStatusSuccess, TransferResponseYearStatusFailed,
             TransferResponseYearStatusSuccess, TransferYearAmountResponse,
@@ -37,7 +40,7 @@ use shared::{
     },
     errors::{AppErrorGrpc, HttpError},
     observability::{Method, TracingMetrics},
-    utils::{mask_card_number, month_name},
+    utils::{mask_card_number, month_name, naive_date_to_timestamp},
 };
 use std::sync::Arc;
 use tonic::{Request, transport::Channel};
@@ -93,6 +96,7 @@ impl TransferQueryGrpcClientTrait for TransferGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -136,6 +140,7 @@ impl TransferQueryGrpcClientTrait for TransferGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -263,6 +268,7 @@ impl TransferQueryGrpcClientTrait for TransferGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -307,6 +313,7 @@ impl TransferQueryGrpcClientTrait for TransferGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -360,14 +367,16 @@ impl TransferQueryGrpcClientTrait for TransferGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: req.sort_by.clone().unwrap_or_default(),
         });
 
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
 
         let cache_key = format!(
-            "transfer:find_by_trashed:page:{page}:size:{page_size}:search:{}",
-            req.search
+            "transfer:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            req.search,
+            req.sort_by.as_deref().unwrap_or_default()
         );
 
         if let Some(cache) = self
@@ -404,6 +413,7 @@ impl TransferQueryGrpcClientTrait for TransferGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
                 self.cache_store
                     .set_to_cache(&cache_key, &api_response, Duration::minutes(10))
@@ -427,6 +437,185 @@ impl TransferQueryGrpcClientTrait for TransferGrpcClientService {
         }
     }
 
+    #[instrument(skip(self, req), level = "info")]
+    async fn find_transfers(
+        &self,
+        req: &DomainFindTransfers,
+    ) -> Result<ApiResponsePagination<Vec<TransferResponse>>, HttpError> {
+        let page = req.page;
+        let page_size = req.page_size;
+
+        let transfer_from = req.transfer_from.clone().unwrap_or_default();
+        let transfer_to = req.transfer_to.clone().unwrap_or_default();
+        let status = req.status.clone().unwrap_or_default();
+        let start_date = req.start_date.map(|d| d.to_string()).unwrap_or_default();
+        let end_date = req.end_date.map(|d| d.to_string()).unwrap_or_default();
+
+        let masked_from = mask_card_number(&transfer_from);
+        let masked_to = mask_card_number(&transfer_to);
+
+        info!(
+            "filtering transfers - page: {page}, page_size: {page_size}, from: {masked_from}, to: {masked_to}, status: {status:?}"
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "FindTransfers",
+            vec![
+                KeyValue::new("component", "transfer"),
+                KeyValue::new("operation", "find_transfers"),
+                KeyValue::new("page", page.to_string()),
+                KeyValue::new("page_size", page_size.to_string()),
+                KeyValue::new("transfer_from", masked_from.clone()),
+                KeyValue::new("transfer_to", masked_to.clone()),
+                KeyValue::new("status", status.clone()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindTransfersRequest {
+            page,
+            page_size,
+            transfer_from: transfer_from.clone(),
+            transfer_to: transfer_to.clone(),
+            status: status.clone(),
+            start_date: req.start_date.map(naive_date_to_timestamp),
+            end_date: req.end_date.map(naive_date_to_timestamp),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        let cache_key = format!(
+            "transfer:find_transfers:page:{page}:size:{page_size}:from:{transfer_from}:to:{transfer_to}:status:{status}:start:{start_date}:end:{end_date}"
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponsePagination<Vec<TransferResponse>>>(&cache_key)
+            .await
+        {
+            let log_msg = format!("✅ Found {} filtered transfers in cache", cache.data.len());
+            info!("{log_msg}");
+            self.tracing_metrics_core
+                .complete_tracing_success(&tracing_ctx, method, &log_msg)
+                .await;
+            return Ok(cache);
+        }
+
+        match self.client.clone().find_transfers(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched filtered transfers",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                let data: Vec<TransferResponse> = inner.data.into_iter().map(Into::into).collect();
+
+                let pagination = inner.pagination.map(Into::into).unwrap_or_default();
+
+                let api_response = ApiResponsePagination {
+                    data,
+                    pagination,
+                    message: inner.message,
+                    status: inner.status,
+                    links: None,
+                };
+
+                self.cache_store
+                    .set_to_cache(&cache_key, &api_response, Duration::minutes(10))
+                    .await;
+
+                info!("fetched {} filtered transfers", api_response.data.len());
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to fetch filtered transfers",
+                    )
+                    .await;
+                error!("fetch filtered transfers failed: {status:?}");
+
+                return Err(AppErrorGrpc::from(status).into());
+            }
+        }
+    }
+
+    #[instrument(skip(self, req), level = "info")]
+    async fn net_position(
+        &self,
+        req: &DomainFindTransferNetPosition,
+    ) -> Result<ApiResponse<TransferNetPositionResponse>, HttpError> {
+        let masked_a = mask_card_number(&req.card_a);
+        let masked_b = mask_card_number(&req.card_b);
+
+        info!("computing net position between {masked_a} and {masked_b}");
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "FindTransferNetPosition",
+            vec![
+                KeyValue::new("component", "transfer"),
+                KeyValue::new("operation", "net_position"),
+                KeyValue::new("card_a", masked_a.clone()),
+                KeyValue::new("card_b", masked_b.clone()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindTransferNetPositionRequest {
+            card_a: req.card_a.clone(),
+            card_b: req.card_b.clone(),
+            start_date: req.start_date.map(naive_date_to_timestamp),
+            end_date: req.end_date.map(naive_date_to_timestamp),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self
+            .client
+            .clone()
+            .find_transfer_net_position(grpc_req)
+            .await
+        {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully computed net position",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    error!("net position data is missing in gRPC response");
+                    HttpError::Internal("Net position data is missing in gRPC response".into())
+                })?;
+
+                Ok(ApiResponse {
+                    status: inner.status,
+                    message: inner.message,
+                    data: data.into(),
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to compute net position")
+                    .await;
+                error!("compute net position between {masked_a} and {masked_b} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
     #[instrument(skip(self, transfer_from), level = "info")]
     async fn find_by_transfer_from(
         &self,
@@ -612,6 +801,72 @@ impl TransferQueryGrpcClientTrait for TransferGrpcClientService {
 
 #[async_trait]
 impl TransferCommandGrpcClientTrait for TransferGrpcClientService {
+    #[instrument(skip(self, req), level = "info")]
+    async fn preview(
+        &self,
+        req: &DomainCreateTransferRequest,
+    ) -> Result<ApiResponse<BalanceImpactResponse>, HttpError> {
+        let masked_from = mask_card_number(&req.transfer_from);
+        let masked_to = mask_card_number(&req.transfer_to);
+        info!(
+            "previewing transfer FROM {masked_from} TO {masked_to}, amount: {}",
+            req.transfer_amount
+        );
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "PreviewTransfer",
+            vec![
+                KeyValue::new("component", "transfer"),
+                KeyValue::new("operation", "preview"),
+                KeyValue::new("transfer.from", masked_from.clone()),
+                KeyValue::new("transfer.to", masked_to.clone()),
+                KeyValue::new("transfer.amount", req.transfer_amount.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(CreateTransferRequest {
+            transfer_from: req.transfer_from.clone(),
+            transfer_to: req.transfer_to.clone(),
+            transfer_amount: req.transfer_amount as i32,
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().preview_transfer(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully previewed Transfer",
+                    )
+                    .await;
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    error!(
+                        "transfer preview failed - data missing in gRPC response FROM {masked_from}"
+                    );
+                    HttpError::Internal("Transfer preview data is missing in gRPC response".into())
+                })?;
+
+                Ok(ApiResponse {
+                    data: data.into(),
+                    status: inner.status,
+                    message: inner.message,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to preview Transfer")
+                    .await;
+                error!("preview transfer FROM {masked_from} TO {masked_to} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
     #[instrument(skip(self, req), level = "info")]
     async fn create(
         &self,
@@ -1038,7 +1293,7 @@ impl TransferCommandGrpcClientTrait for TransferGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("restoring all trashed transfers");
 
         let method = Method::Post;
@@ -1067,7 +1322,7 @@ impl TransferCommandGrpcClientTrait for TransferGrpcClientService {
                 let inner = response.into_inner();
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
@@ -1101,7 +1356,7 @@ impl TransferCommandGrpcClientTrait for TransferGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("permanently deleting all transfers");
 
         let method = Method::Post;
@@ -1150,7 +1405,7 @@ impl TransferCommandGrpcClientTrait for TransferGrpcClientService {
                 }
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
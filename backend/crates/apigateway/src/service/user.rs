@@ -2,8 +2,8 @@ use anyhow::Result;
 use async_trait::async_trait;
 use chrono::Duration;
 use genproto::user::{
-    CreateUserRequest, FindAllUserRequest, FindByIdUserRequest, UpdateUserRequest,
-    user_service_client::UserServiceClient,
+    CreateUserRequest, FindAllUserRequest, FindByIdUserRequest, MergeUsersRequest,
+    UpdateUserRequest, user_service_client::UserServiceClient,
 };
 use opentelemetry::KeyValue;
 use shared::{
@@ -16,9 +16,13 @@ use shared::{
         requests::user::{
             CreateUserRequest as DomainCreateUserRequest,
             FindAllUserRequest as DomainFindAllUserRequest,
+            MergeUsersRequest as DomainMergeUsersRequest,
             UpdateUserRequest as DomainUpdateUserRequest,
         },
-        responses::{ApiResponse, ApiResponsePagination, UserResponse, UserResponseDeleteAt},
+        responses::{
+            ApiResponse, ApiResponsePagination, BulkResult, UserDataExportResponse,
+            UserMergeResponse, UserResponse, UserResponseDeleteAt,
+        },
     },
     errors::{AppErrorGrpc, HttpError},
     observability::{Method, TracingMetrics},
@@ -77,6 +81,7 @@ impl UserQueryGrpcClientTrait for UserGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -116,6 +121,7 @@ impl UserQueryGrpcClientTrait for UserGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -211,6 +217,69 @@ impl UserQueryGrpcClientTrait for UserGrpcClientService {
         }
     }
 
+    #[instrument(skip(self), level = "info")]
+    async fn export_user_data(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<UserDataExportResponse>, HttpError> {
+        info!("exporting data for user id: {user_id}");
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "ExportUserData",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("operation", "export_user_data"),
+                KeyValue::new("user_id", user_id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindByIdUserRequest { id: user_id });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().export_user_data(grpc_req).await {
+            Ok(response) => {
+                let inner = response.into_inner();
+                let data = inner
+                    .data
+                    .ok_or_else(|| {
+                        error!("user {user_id} - export data missing in gRPC response");
+                        HttpError::Internal("User export is missing in gRPC response".into())
+                    })?
+                    .try_into()
+                    .map_err(|e: String| {
+                        error!("user {user_id} - invalid export payload: {e}");
+                        HttpError::Internal(e)
+                    })?;
+
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully exported user data",
+                    )
+                    .await;
+
+                info!("exported data for user {user_id}");
+
+                Ok(ApiResponse {
+                    data,
+                    message: inner.message,
+                    status: inner.status,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to export user data")
+                    .await;
+                error!("export user {user_id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
     #[instrument(skip(self, req), level = "info")]
     async fn find_by_active(
         &self,
@@ -240,6 +309,7 @@ impl UserQueryGrpcClientTrait for UserGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -284,6 +354,7 @@ impl UserQueryGrpcClientTrait for UserGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -332,14 +403,16 @@ impl UserQueryGrpcClientTrait for UserGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: req.sort_by.clone().unwrap_or_default(),
         });
 
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
 
         let cache_key = format!(
-            "user:find_by_trashed:page:{page}:size:{page_size}:search:{}",
-            req.search
+            "user:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            req.search,
+            req.sort_by.as_deref().unwrap_or_default()
         );
 
         if let Some(cache) = self
@@ -376,6 +449,7 @@ impl UserQueryGrpcClientTrait for UserGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -424,6 +498,7 @@ impl UserCommandGrpcClientTrait for UserGrpcClientService {
             email: req.email.clone(),
             password: req.password.clone(),
             confirm_password: req.confirm_password.clone(),
+            must_change_password: req.must_change_password,
         });
 
         self.tracing_metrics_core
@@ -554,10 +629,11 @@ impl UserCommandGrpcClientTrait for UserGrpcClientService {
                     self.cache_store.delete_from_cache(key).await;
                 }
 
-                let cache_key = format!("user:find_by_id:{}", api_response.data.id);
-
                 self.cache_store
-                    .set_to_cache(&cache_key, &api_response, Duration::minutes(10))
+                    .invalidate_after_write(&[
+                        format!("user:find_by_id:{}", api_response.data.id),
+                        format!("auth:get_me:{}", api_response.data.id),
+                    ])
                     .await;
 
                 info!("user {user_id} updated successfully");
@@ -701,6 +777,156 @@ impl UserCommandGrpcClientTrait for UserGrpcClientService {
         }
     }
 
+    #[instrument(skip(self), level = "info")]
+    async fn anonymize(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<UserResponseDeleteAt>, HttpError> {
+        info!("anonymizing user id: {user_id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "AnonymizeUser",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("operation", "anonymize"),
+                KeyValue::new("user_id", user_id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindByIdUserRequest { id: user_id });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().anonymize_user(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Successfully anonymized user")
+                    .await;
+
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    error!("anonymize user {user_id} - data missing in gRPC response");
+                    HttpError::Internal("User data is missing in gRPC response".into())
+                })?;
+
+                let data: UserResponseDeleteAt = data.into();
+
+                let api_response = ApiResponse {
+                    data,
+                    status: inner.status,
+                    message: inner.message,
+                };
+
+                let cache_keys = vec![
+                    format!("user:find_by_id:{}", user_id),
+                    format!("user:find_by_id:id:{}", user_id),
+                    "user:find_all:*".to_string(),
+                    "user:find_by_active:*".to_string(),
+                    "user:find_by_trashed:*".to_string(),
+                ];
+
+                for key in cache_keys {
+                    self.cache_store.delete_from_cache(&key).await;
+                }
+
+                info!("user {user_id} anonymized successfully");
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to anonymize user")
+                    .await;
+                error!("anonymize user {user_id} failed: {status:?}");
+                return Err(AppErrorGrpc::from(status).into());
+            }
+        }
+    }
+
+    #[instrument(skip(self, req), level = "info")]
+    async fn merge(
+        &self,
+        req: &DomainMergeUsersRequest,
+    ) -> Result<ApiResponse<UserMergeResponse>, HttpError> {
+        let primary_id = req.primary_id;
+        let duplicate_id = req.duplicate_id;
+
+        info!("merging user id: {duplicate_id} into user id: {primary_id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "MergeUsers",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("operation", "merge"),
+                KeyValue::new("primary_id", primary_id.to_string()),
+                KeyValue::new("duplicate_id", duplicate_id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(MergeUsersRequest {
+            primary_id,
+            duplicate_id,
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().merge_users(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Successfully merged users")
+                    .await;
+
+                let inner = response.into_inner();
+                let data: UserMergeResponse = inner
+                    .data
+                    .ok_or_else(|| {
+                        error!("merge users {duplicate_id} -> {primary_id} - data missing in gRPC response");
+                        HttpError::Internal("User merge is missing in gRPC response".into())
+                    })?
+                    .try_into()
+                    .map_err(|e: String| {
+                        error!("merge users {duplicate_id} -> {primary_id} - invalid payload: {e}");
+                        HttpError::Internal(e)
+                    })?;
+
+                let api_response = ApiResponse {
+                    data,
+                    status: inner.status,
+                    message: inner.message,
+                };
+
+                let cache_keys = vec![
+                    format!("user:find_by_id:{}", primary_id),
+                    format!("user:find_by_id:id:{}", primary_id),
+                    format!("user:find_by_id:{}", duplicate_id),
+                    format!("user:find_by_id:id:{}", duplicate_id),
+                    "user:find_all:*".to_string(),
+                    "user:find_by_active:*".to_string(),
+                    "user:find_by_trashed:*".to_string(),
+                ];
+
+                for key in cache_keys {
+                    self.cache_store.delete_from_cache(&key).await;
+                }
+
+                info!("user {duplicate_id} merged into {primary_id} successfully");
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to merge users")
+                    .await;
+                error!("merge users {duplicate_id} -> {primary_id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "info")]
     async fn delete_permanent(&self, user_id: i32) -> Result<ApiResponse<bool>, HttpError> {
         info!("permanently deleting user id: {user_id}");
@@ -768,7 +994,7 @@ impl UserCommandGrpcClientTrait for UserGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("restoring all trashed users");
 
         let method = Method::Post;
@@ -798,7 +1024,7 @@ impl UserCommandGrpcClientTrait for UserGrpcClientService {
                 let inner = response.into_inner();
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
@@ -833,7 +1059,7 @@ impl UserCommandGrpcClientTrait for UserGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("permanently deleting all users");
 
         let method = Method::Post;
@@ -868,7 +1094,7 @@ impl UserCommandGrpcClientTrait for UserGrpcClientService {
                 let inner = response.into_inner();
 
                 let api_response = ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 };
@@ -4,8 +4,9 @@ use chrono::Duration;
 use genproto::withdraw::{
     CreateWithdrawRequest, FindAllWithdrawByCardNumberRequest, FindAllWithdrawRequest,
     FindByIdWithdrawRequest, FindMonthlyWithdrawStatus, FindMonthlyWithdrawStatusCardNumber,
-    FindYearWithdrawCardNumber, FindYearWithdrawStatus, FindYearWithdrawStatusCardNumber,
-    UpdateWithdrawRequest, withdraw_service_client::WithdrawServiceClient,
+    FindWithdrawsRequest, FindYearWithdrawCardNumber, FindYearWithdrawStatus,
+    FindYearWithdrawStatusCardNumber, UpdateWithdrawRequest,
+    withdraw_service_client::WithdrawServiceClient,
 };
 use opentelemetry::KeyValue;
 use shared::{
@@ -21,7 +22,7 @@ use shared::{
         requests::withdraw::{
             CreateWithdrawRequest as DomainCreateWithdrawRequest,
             FindAllWithdrawCardNumber as DomainFindAllWithdrawCardNumber,
-            FindAllWithdraws as DomainFindAllWithdraws,
+            FindAllWithdraws as DomainFindAllWithdraws, FindWithdraws as DomainFindWithdraws,
             MonthStatusWithdraw as DomainMonthStatusWithdraw,
             MonthStatusWithdrawCardNumber as DomainMonthStatusWithdrawCardNumber,
             UpdateWithdrawRequest as DomainUpdateWithdrawRequest,
@@ -29,15 +30,19 @@ use shared::{
             YearStatusWithdrawCardNumber as DomainYearStatusWithdrawCardNumber,
         },
         responses::{
-            ApiResponse, ApiResponsePagination, WithdrawMonthlyAmountResponse, WithdrawResponse,
-            WithdrawResponseDeleteAt, WithdrawResponseMonthStatusFailed,
-            WithdrawResponseMonthStatusSuccess, WithdrawResponseYearStatusFailed,
-            WithdrawResponseYearStatusSuccess, WithdrawYearlyAmountResponse,
+            ApiResponse, ApiResponsePagination, BalanceImpactResponse, BulkResult,
+            WithdrawMonthlyAmountResponse, WithdrawResponse, WithdrawResponseDeleteAt,
+            WithdrawResponseMonthStatusFailed, WithdrawResponseMonthStatusSuccess,
+            WithdrawResponseYearStatusFailed, WithdrawResponseYearStatusSuccess,
+            WithdrawYearlyAmountResponse,
         },
     },
     errors::{AppErrorGrpc, HttpError},
     observability::{Method, TracingMetrics},
-    utils::{mask_card_number, month_name, naive_datetime_to_timestamp},
+    utils::{
+        card_cache_key, mask_card_number, month_name, naive_date_to_timestamp,
+        naive_datetime_to_timestamp,
+    },
 };
 use std::sync::Arc;
 use tonic::{Request, transport::Channel};
@@ -92,6 +97,7 @@ impl WithdrawQueryGrpcClientTrait for WithdrawGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -134,6 +140,7 @@ impl WithdrawQueryGrpcClientTrait for WithdrawGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 info!("fetched {} withdraws", api_response.data.len());
@@ -233,6 +240,7 @@ impl WithdrawQueryGrpcClientTrait for WithdrawGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 info!(
@@ -260,6 +268,120 @@ impl WithdrawQueryGrpcClientTrait for WithdrawGrpcClientService {
         }
     }
 
+    #[instrument(skip(self, req), level = "info")]
+    async fn find_withdraws(
+        &self,
+        req: &DomainFindWithdraws,
+    ) -> Result<ApiResponsePagination<Vec<WithdrawResponse>>, HttpError> {
+        let page = req.page;
+        let page_size = req.page_size;
+
+        let status = req.status.clone().unwrap_or_default();
+        let card_number = req.card_number.clone().unwrap_or_default();
+        let sort_by = req.sort_by.clone().unwrap_or_default();
+        let sort_dir = req.sort_dir.clone().unwrap_or_default();
+        let from = req.from.map(|d| d.to_string()).unwrap_or_default();
+        let to = req.to.map(|d| d.to_string()).unwrap_or_default();
+
+        let masked_card = mask_card_number(&card_number);
+
+        info!(
+            "filtering withdraws - page: {page}, page_size: {page_size}, status: {status:?}, card: {masked_card}, range: {from:?}..{to:?}"
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "FindWithdraws",
+            vec![
+                KeyValue::new("component", "withdraw"),
+                KeyValue::new("operation", "find_withdraws"),
+                KeyValue::new("withdraw.page", page.to_string()),
+                KeyValue::new("withdraw.page_size", page_size.to_string()),
+                KeyValue::new("withdraw.status", status.clone()),
+                KeyValue::new("withdraw.card_number", masked_card.clone()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindWithdrawsRequest {
+            page,
+            page_size,
+            status: status.clone(),
+            card_number: card_number.clone(),
+            from: req.from.map(naive_date_to_timestamp),
+            to: req.to.map(naive_date_to_timestamp),
+            sort_by: sort_by.clone(),
+            sort_dir: sort_dir.clone(),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        let card_key = card_cache_key(&card_number);
+        let cache_key = format!(
+            "withdrawal:find_withdraws:page:{page}:size:{page_size}:status:{status}:card:{card_key}:from:{from}:to:{to}:sort:{sort_by}:{sort_dir}"
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponsePagination<Vec<WithdrawResponse>>>(&cache_key)
+            .await
+        {
+            let log_msg = format!(
+                "✅ Found {} filtered withdrawals in cache",
+                cache.data.len()
+            );
+            info!("{log_msg}");
+            self.tracing_metrics_core
+                .complete_tracing_success(&tracing_ctx, method, &log_msg)
+                .await;
+            return Ok(cache);
+        }
+
+        match self.client.clone().find_withdraws(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully fetched filtered withdraws",
+                    )
+                    .await;
+
+                let inner = response.into_inner();
+                let data: Vec<WithdrawResponse> = inner.data.into_iter().map(Into::into).collect();
+
+                let pagination = inner.pagination.map(Into::into).unwrap_or_default();
+
+                let api_response = ApiResponsePagination {
+                    data,
+                    pagination,
+                    message: inner.message,
+                    status: inner.status,
+                    links: None,
+                };
+
+                self.cache_store
+                    .set_to_cache(&cache_key, &api_response, Duration::minutes(10))
+                    .await;
+
+                info!("fetched {} filtered withdraws", api_response.data.len());
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to fetch filtered withdraws",
+                    )
+                    .await;
+                error!("fetch filtered withdraws failed: {status:?}");
+                return Err(AppErrorGrpc::from(status).into());
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "info")]
     async fn find_by_id(
         &self,
@@ -364,6 +486,7 @@ impl WithdrawQueryGrpcClientTrait for WithdrawGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: String::new(),
         });
 
         self.tracing_metrics_core
@@ -407,6 +530,7 @@ impl WithdrawQueryGrpcClientTrait for WithdrawGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -459,14 +583,16 @@ impl WithdrawQueryGrpcClientTrait for WithdrawGrpcClientService {
             page,
             page_size,
             search: req.search.clone(),
+            sort_by: req.sort_by.clone().unwrap_or_default(),
         });
 
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
 
         let cache_key = format!(
-            "withdrawal:find_by_trashed:page:{page}:size:{page_size}:search:{}",
-            req.search.clone()
+            "withdrawal:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            req.search.clone(),
+            req.sort_by.as_deref().unwrap_or_default()
         );
 
         if let Some(cache) = self
@@ -502,6 +628,7 @@ impl WithdrawQueryGrpcClientTrait for WithdrawGrpcClientService {
                     pagination,
                     message: inner.message,
                     status: inner.status,
+                    links: None,
                 };
 
                 self.cache_store
@@ -529,6 +656,70 @@ impl WithdrawQueryGrpcClientTrait for WithdrawGrpcClientService {
 
 #[async_trait]
 impl WithdrawCommandGrpcClientTrait for WithdrawGrpcClientService {
+    #[instrument(skip(self, req), level = "info")]
+    async fn preview(
+        &self,
+        req: &DomainCreateWithdrawRequest,
+    ) -> Result<ApiResponse<BalanceImpactResponse>, HttpError> {
+        let masked_card = mask_card_number(&req.card_number);
+        info!(
+            "previewing withdraw for card: {masked_card}, amount: {}",
+            req.withdraw_amount
+        );
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "PreviewWithdraw",
+            vec![
+                KeyValue::new("component", "withdraw"),
+                KeyValue::new("operation", "preview"),
+                KeyValue::new("withdraw.card_number", masked_card.clone()),
+                KeyValue::new("withdraw.amount", req.withdraw_amount.to_string()),
+            ],
+        );
+
+        let date = naive_datetime_to_timestamp(req.withdraw_time);
+
+        let mut grpc_req = Request::new(CreateWithdrawRequest {
+            card_number: req.card_number.clone(),
+            withdraw_amount: req.withdraw_amount,
+            withdraw_time: Some(date),
+        });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().preview_withdraw(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully previewed Withdraw",
+                    )
+                    .await;
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    error!("withdraw preview failed - data missing in gRPC response for card: {masked_card}");
+                    HttpError::Internal("Withdraw preview data is missing in gRPC response".into())
+                })?;
+
+                Ok(ApiResponse {
+                    data: data.into(),
+                    status: inner.status,
+                    message: inner.message,
+                })
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to preview Withdraw")
+                    .await;
+                error!("preview withdraw for card {masked_card} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
     #[instrument(skip(self, req), level = "info")]
     async fn create(
         &self,
@@ -706,6 +897,77 @@ impl WithdrawCommandGrpcClientTrait for WithdrawGrpcClientService {
         }
     }
 
+    #[instrument(skip(self), level = "info")]
+    async fn cancel_withdraw(
+        &self,
+        withdraw_id: i32,
+    ) -> Result<ApiResponse<WithdrawResponse>, HttpError> {
+        info!("cancelling withdraw id: {withdraw_id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "CancelWithdraw",
+            vec![
+                KeyValue::new("component", "withdraw"),
+                KeyValue::new("operation", "cancel"),
+                KeyValue::new("withdraw.id", withdraw_id.to_string()),
+            ],
+        );
+
+        let mut grpc_req = Request::new(FindByIdWithdrawRequest { withdraw_id });
+
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_req);
+
+        match self.client.clone().cancel_withdraw(grpc_req).await {
+            Ok(response) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Successfully cancelled Withdraw",
+                    )
+                    .await;
+                let inner = response.into_inner();
+                let data = inner.data.ok_or_else(|| {
+                    error!("cancel withdraw {withdraw_id} - data missing in gRPC response");
+                    HttpError::Internal("Withdraw data is missing in gRPC response".into())
+                })?;
+
+                let withdraw_response: WithdrawResponse = data.into();
+
+                let api_response = ApiResponse {
+                    data: withdraw_response,
+                    status: inner.status,
+                    message: inner.message,
+                };
+
+                let cache_keys = vec![
+                    format!("withdraw:find_by_id:{}", api_response.data.clone().id),
+                    "withdraw:find_all:*".to_string(),
+                    "withdraw:find_by_active:*".to_string(),
+                    "withdraw:find_by_trashed:*".to_string(),
+                ];
+
+                for key in cache_keys {
+                    self.cache_store.delete_from_cache(&key).await;
+                    info!("Invalidated cache key: {}", key);
+                }
+
+                info!("withdraw {withdraw_id} cancelled successfully");
+
+                Ok(api_response)
+            }
+            Err(status) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to cancel Withdraw")
+                    .await;
+                error!("cancel withdraw {withdraw_id} failed: {status:?}");
+                Err(AppErrorGrpc::from(status).into())
+            }
+        }
+    }
+
     #[instrument(skip(self), level = "info")]
     async fn trashed_withdraw(
         &self,
@@ -912,7 +1174,7 @@ impl WithdrawCommandGrpcClientTrait for WithdrawGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("restoring all trashed withdraws");
 
         let method = Method::Post;
@@ -955,7 +1217,7 @@ impl WithdrawCommandGrpcClientTrait for WithdrawGrpcClientService {
 
                 info!("all trashed withdraws restored successfully");
                 Ok(ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 })
@@ -971,7 +1233,7 @@ impl WithdrawCommandGrpcClientTrait for WithdrawGrpcClientService {
     }
 
     #[instrument(skip(self), level = "info")]
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError> {
         info!("permanently deleting all withdraws");
 
         let method = Method::Post;
@@ -1019,7 +1281,7 @@ impl WithdrawCommandGrpcClientTrait for WithdrawGrpcClientService {
                 info!("all withdraws permanently deleted");
 
                 Ok(ApiResponse {
-                    data: true,
+                    data: inner.data.map(Into::into).unwrap_or_default(),
                     status: inner.status,
                     message: inner.message,
                 })
@@ -3,11 +3,17 @@ use anyhow::{Context, Result};
 use shared::abstract_trait::rate_limit::DynRateLimitMiddleware;
 use shared::abstract_trait::session::DynSessionMiddleware;
 use shared::cache::session::SessionStore;
-use shared::config::GatewayLimiterConfig;
-use shared::resilience::{GatewayCircuitBreaker, GatewayRequestLimiter};
+use shared::config::{
+    FeaturesConfig, GatewayLimiterConfig, LocaleConfig, OpenApiConfig, PaymentMethodsConfig,
+    ReadinessConfig, ReauthConfig, RequestIdConfig, RequestTimeoutConfig, RetryAfterConfig,
+    SecurityHeadersConfig, TransferLimitsConfig,
+};
+use shared::resilience::{
+    GatewayCircuitBreaker, GatewayRequestLimiter, GatewayServiceBreakers, GatewayServiceLimiter,
+};
 use shared::{
     abstract_trait::jwt::DynJwtService,
-    config::{GrpcServiceEndpoints, JwtConfig},
+    config::{Config, GrpcServiceEndpoints, JwtConfig},
     observability::run_metrics_collector,
 };
 use shared::{
@@ -17,6 +23,7 @@ use shared::{
 };
 use std::sync::Arc;
 use std::time::Duration;
+use tonic::transport::Channel;
 use tracing::info;
 
 pub struct AppState {
@@ -25,13 +32,33 @@ pub struct AppState {
     pub session: DynSessionMiddleware,
     pub di_container: Arc<DependenciesInject>,
     pub circuit_breaker: Arc<GatewayCircuitBreaker>,
+    pub service_breakers: Arc<GatewayServiceBreakers>,
     pub request_limiter: Arc<GatewayRequestLimiter>,
+    pub service_limiter: Arc<GatewayServiceLimiter>,
     pub cache_store: Arc<CacheStore>,
+    pub redis: RedisPool,
+    pub request_id: RequestIdConfig,
+    pub request_timeout: RequestTimeoutConfig,
+    pub retry_after: RetryAfterConfig,
+    pub payment_methods: PaymentMethodsConfig,
+    pub transfer_limits: TransferLimitsConfig,
+    pub features: FeaturesConfig,
+    pub locale: LocaleConfig,
+    pub readiness: ReadinessConfig,
+    pub reauth: ReauthConfig,
+    pub security_headers: SecurityHeadersConfig,
+    pub openapi: OpenApiConfig,
+    pub grpc_channels: Vec<(&'static str, Channel)>,
 }
 
 impl AppState {
-    pub async fn new(jwt_secret: &str) -> Result<Self> {
-        let jwt_config = Arc::new(JwtConfig::new(jwt_secret)) as DynJwtService;
+    pub async fn new(config: &Config) -> Result<Self> {
+        let jwt_config = Arc::new(JwtConfig::new(
+            &config.jwt_secret,
+            &config.jwt_issuer,
+            &config.jwt_audience,
+            config.jwt_accept_legacy_tokens,
+        )) as DynJwtService;
 
         let grpc_config = GrpcServiceEndpoints::init().context("failed config grpc")?;
 
@@ -52,6 +79,8 @@ impl AppState {
             .await
             .context("failed grpc client")?;
 
+        let grpc_channels = clients.channels.clone();
+
         let di_container = Arc::new(
             DependenciesInject::new(clients, cache_store.clone())
                 .context("Failed to initialized depencency injection container")?,
@@ -64,7 +93,16 @@ impl AppState {
             cfg.cb_reset_timeout_sec,
         ));
 
-        let request_limiter = Arc::new(GatewayRequestLimiter::new(cfg.rate_limit));
+        let service_breakers = Arc::new(GatewayServiceBreakers::new(
+            cfg.cb_max_failures,
+            cfg.cb_reset_timeout_sec,
+        ));
+
+        let request_limiter = Arc::new(GatewayRequestLimiter::new(
+            cfg.rate_limit,
+            Duration::from_millis(cfg.max_queue_time_ms),
+        ));
+        let service_limiter = Arc::new(GatewayServiceLimiter::from_config(&cfg));
 
         Self::spawn_monitoring_task(
             Arc::clone(&circuit_breaker),
@@ -72,7 +110,7 @@ impl AppState {
             Arc::clone(&di_container),
         );
 
-        tokio::spawn(run_metrics_collector());
+        tokio::spawn(run_metrics_collector(None));
 
         Ok(Self {
             jwt_config,
@@ -80,8 +118,25 @@ impl AppState {
             session: session_middleware,
             rate_limit: rate_limiter_middleware,
             circuit_breaker,
+            service_breakers,
             request_limiter,
+            service_limiter,
             cache_store,
+            redis,
+            request_id: RequestIdConfig::from_env(),
+            request_timeout: RequestTimeoutConfig::from_env(),
+            retry_after: RetryAfterConfig::from_env(),
+            payment_methods: PaymentMethodsConfig::from_env()
+                .context("Failed to load payment methods config")?,
+            transfer_limits: TransferLimitsConfig::from_env()
+                .context("Failed to load transfer limits config")?,
+            features: FeaturesConfig::from_env(),
+            locale: LocaleConfig::from_env(),
+            readiness: ReadinessConfig::from_env(),
+            reauth: ReauthConfig::from_env(),
+            security_headers: SecurityHeadersConfig::from_env(),
+            openapi: OpenApiConfig::from_env(),
+            grpc_channels,
         })
     }
 
@@ -2,25 +2,37 @@ use anyhow::{Context, Result};
 use shared::{
     abstract_trait::{
         auth::service::DynAuthService,
+        email_verification::{DynEmailVerificationRepository, DynVerificationMailer},
         hashing::DynHashing,
         jwt::DynJwtService,
         refresh_token::command::DynRefreshTokenCommandRepository,
         role::repository::query::DynRoleQueryRepository,
         token::DynTokenService,
-        user::repository::{command::DynUserCommandRepository, query::DynUserQueryRepository},
+        user::repository::{
+            command::DynUserCommandRepository, credential::DynUserCredentialRepository,
+            query::DynUserQueryRepository,
+        },
         user_roles::DynUserRoleCommandRepository,
     },
     cache::CacheStore,
-    config::{ConnectionPool, RedisPool, ServiceLimiterConfig},
+    config::{
+        ConnectionPool, EmailDomainDenylist, EmailNormalizationConfig, EmailVerificationConfig,
+        EventsConfig, LogPrivacyConfig, RedisPool, ServiceLimiterConfig,
+    },
+    events::EventPublisher,
     observability::{CacheMetricsCore, TracingMetricsCore},
     repository::{
+        email_verification::EmailVerificationRepository,
         refresh_token::RefreshTokenCommandRepository,
         role::query::RoleQueryRepository,
-        user::{command::UserCommandRepository, query::UserQueryRepository},
+        user::{
+            command::UserCommandRepository, credential::UserCredentialRepository,
+            query::UserQueryRepository,
+        },
         user_role::UserRoleRepository,
     },
     service::{
-        auth::{AuthService, AuthServiceDeps},
+        auth::{AuthService, AuthServiceDeps, LoggingVerificationMailer},
         token::TokenService,
     },
 };
@@ -37,7 +49,9 @@ pub struct DependencyMetrics {
 #[derive(Clone)]
 pub struct DependenciesInject {
     pub auth_service: DynAuthService,
+    pub role_query: DynRoleQueryRepository,
     pub cache_store: Arc<CacheStore>,
+    pub log_privacy: LogPrivacyConfig,
     pub request_limiter: Arc<Semaphore>,
 }
 
@@ -74,11 +88,26 @@ impl DependenciesInject {
         let user_command =
             Arc::new(UserCommandRepository::new(pool.clone())) as DynUserCommandRepository;
 
+        let credential =
+            Arc::new(UserCredentialRepository::new(pool.clone())) as DynUserCredentialRepository;
+
         let role = Arc::new(RoleQueryRepository::new(pool.clone())) as DynRoleQueryRepository;
 
         let refresh_command = Arc::new(RefreshTokenCommandRepository::new(pool.clone()))
             as DynRefreshTokenCommandRepository;
 
+        let verification = Arc::new(EmailVerificationRepository::new(pool.clone()))
+            as DynEmailVerificationRepository;
+        let log_privacy = LogPrivacyConfig::from_env();
+        let mailer = Arc::new(LoggingVerificationMailer::new(log_privacy)) as DynVerificationMailer;
+        let verification_config = EmailVerificationConfig::from_env()
+            .context("failed to load email verification config")?;
+        let email_denylist = Arc::new(EmailDomainDenylist::from_env());
+        email_denylist.spawn_reload();
+        let events = EventPublisher::from_config(
+            &EventsConfig::from_env().context("failed to load events config")?,
+        );
+
         let cache_store = Arc::new(CacheStore::new(redis.pool.clone(), cache_metrics));
 
         let tracing_metrics =
@@ -95,10 +124,18 @@ impl DependenciesInject {
             command: user_command,
             jwt_config: jwt_config.clone(),
             hashing: hash,
-            role,
+            role: role.clone(),
             user_role,
             token: token_service.clone(),
             refresh_command,
+            credential,
+            verification,
+            mailer,
+            verification_config,
+            email_denylist,
+            email_normalization: EmailNormalizationConfig::from_env(),
+            log_privacy,
+            events,
             cache_store: cache_store.clone(),
         };
 
@@ -113,8 +150,10 @@ impl DependenciesInject {
 
         Ok(Self {
             auth_service,
+            role_query: role,
             request_limiter: Arc::new(Semaphore::new(cfg_limiter.max_concurrent)),
             cache_store,
+            log_privacy,
         })
     }
 
@@ -1,12 +1,18 @@
 use std::sync::Arc;
 
 use genproto::auth::{
-    ApiResponseGetMe, ApiResponseLogin, ApiResponseRefreshToken, ApiResponseRegister, GetMeRequest,
-    LoginRequest, RefreshTokenRequest, RegisterRequest, auth_service_server::AuthService,
+    ApiResponseBatchIntrospect, ApiResponseEmailVerification, ApiResponseGetMe, ApiResponseLogin,
+    ApiResponseRefreshToken, ApiResponseRegister, BatchIntrospectRequest, ChangePasswordRequest,
+    GetMeRequest, LoginRequest, RefreshTokenRequest, RegisterRequest, ResendVerificationRequest,
+    SendVerificationRequest, VerifyEmailRequest, auth_service_server::AuthService,
 };
 use shared::{
-    domain::requests::auth::{AuthRequest, RegisterRequest as RegisterDomainRequest},
+    domain::requests::auth::{
+        AuthRequest, ChangePasswordRequest as ChangePasswordDomainRequest,
+        RegisterRequest as RegisterDomainRequest,
+    },
     errors::{AppErrorGrpc, CircuitBreakerError},
+    utils::peer_addr,
 };
 use tonic::{Request, Response, Status};
 use tracing::{error, info, instrument, warn};
@@ -47,7 +53,7 @@ impl AuthServiceImpl {
 
 #[tonic::async_trait]
 impl AuthService for AuthServiceImpl {
-    #[instrument(skip(self, request), fields(method = "register_user"))]
+    #[instrument(skip(self, request), fields(method = "register_user", peer = %peer_addr(&request)))]
     async fn register_user(
         &self,
         request: Request<RegisterRequest>,
@@ -94,13 +100,13 @@ impl AuthService for AuthServiceImpl {
                 match &e {
                     CircuitBreakerError::Open => {
                         warn!(
-                            email = domain_req.email,
+                            email = %self.state.di_container.log_privacy.email(&domain_req.email),
                             "register_user rejected: circuit breaker open"
                         );
                     }
                     CircuitBreakerError::Inner(inner) => {
                         error!(
-                            email = domain_req.email,
+                            email = %self.state.di_container.log_privacy.email(&domain_req.email),
                             error = %inner,
                             "register_user failed"
                         );
@@ -111,7 +117,7 @@ impl AuthService for AuthServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "login_user"))]
+    #[instrument(skip(self, request), fields(method = "login_user", peer = %peer_addr(&request)))]
     async fn login_user(
         &self,
         request: Request<LoginRequest>,
@@ -155,13 +161,13 @@ impl AuthService for AuthServiceImpl {
                 match &e {
                     CircuitBreakerError::Open => {
                         warn!(
-                            email = domain_req.email,
+                            email = %self.state.di_container.log_privacy.email(&domain_req.email),
                             "login_user rejected: circuit breaker open"
                         );
                     }
                     CircuitBreakerError::Inner(inner) => {
                         error!(
-                            email = domain_req.email,
+                            email = %self.state.di_container.log_privacy.email(&domain_req.email),
                             error = %inner,
                             "login_user failed"
                         );
@@ -172,7 +178,7 @@ impl AuthService for AuthServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "refresh_token"))]
+    #[instrument(skip(self, request), fields(method = "refresh_token", peer = %peer_addr(&request)))]
     async fn refresh_token(
         &self,
         request: Request<RefreshTokenRequest>,
@@ -226,7 +232,7 @@ impl AuthService for AuthServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "get_me"))]
+    #[instrument(skip(self, request), fields(method = "get_me", peer = %peer_addr(&request)))]
     async fn get_me(
         &self,
         request: Request<GetMeRequest>,
@@ -280,4 +286,284 @@ impl AuthService for AuthServiceImpl {
             }
         }
     }
+
+    #[instrument(skip(self, request), fields(method = "send_verification", peer = %peer_addr(&request)))]
+    async fn send_verification(
+        &self,
+        request: Request<SendVerificationRequest>,
+    ) -> Result<Response<ApiResponseEmailVerification>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let user_id = req.user_id;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .auth_service
+                    .send_verification(user_id)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                let reply = ApiResponseEmailVerification {
+                    status: api_response.status,
+                    message: api_response.message,
+                };
+
+                Ok(Response::new(reply))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!("SendVerification success: {}", resp.get_ref().message);
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            user_id = user_id,
+                            "send_verification rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(
+                            user_id = user_id,
+                            error = %inner,
+                            "send_verification failed"
+                        );
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "resend_verification", peer = %peer_addr(&request)))]
+    async fn resend_verification(
+        &self,
+        request: Request<ResendVerificationRequest>,
+    ) -> Result<Response<ApiResponseEmailVerification>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .auth_service
+                    .resend_verification(&req.email)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                let reply = ApiResponseEmailVerification {
+                    status: api_response.status,
+                    message: api_response.message,
+                };
+
+                Ok(Response::new(reply))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!("ResendVerification success: {}", resp.get_ref().message);
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!("resend_verification rejected: circuit breaker open");
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(error = %inner, "resend_verification failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "verify_email", peer = %peer_addr(&request)))]
+    async fn verify_email(
+        &self,
+        request: Request<VerifyEmailRequest>,
+    ) -> Result<Response<ApiResponseEmailVerification>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let token = req.token;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .auth_service
+                    .verify_email(&token)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                let reply = ApiResponseEmailVerification {
+                    status: api_response.status,
+                    message: api_response.message,
+                };
+
+                Ok(Response::new(reply))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!("VerifyEmail success: {}", resp.get_ref().message);
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!("verify_email rejected: circuit breaker open");
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(
+                            error = %inner,
+                            "verify_email failed"
+                        );
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "change_password", peer = %peer_addr(&request), user_id = request.get_ref().user_id))]
+    async fn change_password(
+        &self,
+        request: Request<ChangePasswordRequest>,
+    ) -> Result<Response<ApiResponseLogin>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let user_id = req.user_id;
+        let domain_req = ChangePasswordDomainRequest {
+            current_password: req.current_password,
+            new_password: req.new_password,
+            confirm_password: req.confirm_password,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .auth_service
+                    .change_password(user_id, &domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                let reply = ApiResponseLogin {
+                    status: api_response.status,
+                    message: api_response.message.clone(),
+                    data: Some(api_response.data.into()),
+                };
+
+                Ok(Response::new(reply))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!("ChangePassword success: {}", resp.get_ref().message);
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            user_id = user_id,
+                            "change_password rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(
+                            user_id = user_id,
+                            error = %inner,
+                            "change_password failed"
+                        );
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "batch_introspect", peer = %peer_addr(&request)))]
+    async fn batch_introspect(
+        &self,
+        request: Request<BatchIntrospectRequest>,
+    ) -> Result<Response<ApiResponseBatchIntrospect>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let batch_size = req.tokens.len();
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .auth_service
+                    .batch_introspect(req.tokens.clone())
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                let reply = ApiResponseBatchIntrospect {
+                    status: api_response.status,
+                    message: api_response.message.clone(),
+                    data: api_response.data.into_iter().map(Into::into).collect(),
+                };
+
+                Ok(Response::new(reply))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!("BatchIntrospect success: {}", resp.get_ref().message);
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            batch_size,
+                            "batch_introspect rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(
+                            batch_size,
+                            error = %inner,
+                            "batch_introspect failed"
+                        );
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
 }
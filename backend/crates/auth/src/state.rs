@@ -5,6 +5,8 @@ use shared::{
     config::{Config, ConnectionPool, Hashing, JwtConfig, RedisConfig, RedisPool},
     observability::run_metrics_collector,
     resilience::{CircuitBreaker, LoadMonitor},
+    service::auth::DEFAULT_ROLE_NAME,
+    utils::{SelfCheck, check_database, check_tables},
 };
 use std::{fmt, sync::Arc, time::Duration};
 use tracing::{error, info, warn};
@@ -26,7 +28,12 @@ impl fmt::Debug for AppState {
 
 impl AppState {
     pub async fn new(pool: ConnectionPool, config: Config) -> Result<Self> {
-        let jwt_config = Arc::new(JwtConfig::new(&config.jwt_secret)) as DynJwtService;
+        let jwt_config = Arc::new(JwtConfig::new(
+            &config.jwt_secret,
+            &config.jwt_issuer,
+            &config.jwt_audience,
+            config.jwt_accept_legacy_tokens,
+        )) as DynJwtService;
         let hashing = Arc::new(Hashing::new()) as DynHashing;
 
         let redis_config = RedisConfig::new();
@@ -56,7 +63,7 @@ impl AppState {
             circuit_breaker.clone(),
         );
 
-        tokio::spawn(run_metrics_collector());
+        tokio::spawn(run_metrics_collector(Some(pool.clone())));
 
         Ok(Self {
             di_container,
@@ -64,6 +71,43 @@ impl AppState {
             load_monitor,
         })
     }
+
+    pub async fn self_check(&self, db: &ConnectionPool) -> Result<()> {
+        let mut check = SelfCheck::new("auth-service");
+
+        check.record("database", check_database(db).await);
+        check.record(
+            "schema",
+            check_tables(
+                db,
+                &[
+                    "users",
+                    "roles",
+                    "user_roles",
+                    "refresh_tokens",
+                    "email_verification_tokens",
+                ],
+            )
+            .await,
+        );
+
+        let default_role = match self
+            .di_container
+            .role_query
+            .find_by_name(DEFAULT_ROLE_NAME)
+            .await
+        {
+            Ok(Some(_)) => Ok(()),
+            Ok(None) => Err(format!(
+                "default role {DEFAULT_ROLE_NAME} is missing; registration cannot assign it"
+            )),
+            Err(e) => Err(format!("failed to look up {DEFAULT_ROLE_NAME} ({e})")),
+        };
+        check.record("default_role", default_role);
+
+        check.finish()
+    }
+
     fn spawn_load_monitoring(
         monitor: Arc<LoadMonitor>,
         di: Arc<DependenciesInject>,
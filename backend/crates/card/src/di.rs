@@ -6,6 +6,7 @@ use shared::{
                 command::DynCardCommandRepository,
                 dashboard::{
                     balance::DynCardDashboardBalanceRepository,
+                    system::DynCardDashboardSystemRepository,
                     topup::DynCardDashboardTopupRepository,
                     transaction::DynCardDashboardTransactionRepository,
                     transfer::DynCardDashboardTransferRepository,
@@ -47,14 +48,15 @@ use shared::{
         user::repository::query::DynUserQueryRepository,
     },
     cache::CacheStore,
-    config::{ConnectionPool, RedisPool},
+    config::{CardExpiryConfig, ConnectionPool, DashboardConfig, RedisPool},
     context::shared_resources::SharedResources,
     observability::{CacheMetricsCore, TracingMetricsCore},
     repository::{
         card::{
             command::CardCommandRepository,
             dashboard::{
-                balance::CardDashboardBalanceRepository, topup::CardDashboardTopupRepository,
+                balance::CardDashboardBalanceRepository, system::CardDashboardSystemRepository,
+                topup::CardDashboardTopupRepository,
                 transaction::CardDashboardTransactionRepository,
                 transfer::CardDashboardTransferRepository,
                 withdraw::CardDashboardWithdrawRepository,
@@ -90,6 +92,7 @@ use shared::{
         },
     },
 };
+use shared::{config::SoftDeleteRetentionConfig, utils::spawn_soft_delete_purge};
 use std::{fmt, sync::Arc, time::Duration};
 use tokio::sync::Semaphore;
 use tracing::{error, info, warn};
@@ -183,6 +186,7 @@ impl DependenciesInject {
             user_query: user_query_repo.clone(),
             query: card_query_repo.clone(),
             command: card_command_repo.clone(),
+            expiry: CardExpiryConfig::from_env().context("invalid card expiry config")?,
         };
         let card_command = Arc::new(
             CardCommandService::new(card_command_deps, &shared)
@@ -200,6 +204,11 @@ impl DependenciesInject {
                 as DynCardDashboardTransferRepository,
             withdraw: Arc::new(CardDashboardWithdrawRepository::new(db.clone()))
                 as DynCardDashboardWithdrawRepository,
+            system: Arc::new(CardDashboardSystemRepository::new(db.clone()))
+                as DynCardDashboardSystemRepository,
+            stats_concurrency: DashboardConfig::from_env()
+                .context("failed to load dashboard config")?
+                .stats_concurrency,
         };
         let card_dashboard = Arc::new(
             CardDashboardService::new(card_dashboard_deps, &shared)
@@ -300,6 +309,7 @@ impl DependenciesInject {
 
         Self::spawn_monitoring_task(Arc::clone(&shared.cache_store));
         Self::spawn_cleanup_task(Arc::clone(&shared.cache_store));
+        spawn_soft_delete_purge(db.clone(), &SoftDeleteRetentionConfig::from_env(), "cards");
 
         Ok(Self {
             card_query,
@@ -7,8 +7,12 @@ use anyhow::{Context, Result};
 use card::{config::ServerConfig, service::CardServiceImpl, state::AppState};
 use genproto::card::card_service_server::CardServiceServer;
 use shared::{
-    config::{Config, ConnectionManager, GrpcServerConfig},
-    utils::{Telemetry, init_logger},
+    config::{
+        Config, ConnectionManager, GrpcHealthConfig, GrpcServerConfig, ServiceAccountConfig,
+        WarmupConfig,
+    },
+    resilience::{GrpcHandlerTimeoutLayer, RestartBackoff, ServiceAccountLayer},
+    utils::{GrpcHealthGate, Telemetry, init_logger, warm_up_db_pool},
 };
 use std::sync::Arc;
 use tokio::sync::broadcast;
@@ -17,11 +21,14 @@ use tracing::{error, info, warn};
 
 #[tokio::main]
 async fn main() -> Result<()> {
-    let (server_config, state, telemetry) = setup().await.context("Failed to setup application")?;
+    let (server_config, state, telemetry, health) =
+        setup().await.context("Failed to setup application")?;
+
+    let shutdown_timeout = server_config.shutdown_timeout;
 
     let (shutdown_tx, mut shutdown_rx) = broadcast::channel::<()>(1);
 
-    let server_handle = run_servers(server_config, state, shutdown_tx.clone())
+    let server_handle = run_servers(server_config, state, health, shutdown_tx.clone())
         .await
         .context("Failed to start servers")?;
 
@@ -34,12 +41,12 @@ async fn main() -> Result<()> {
         }
     }
 
-    shutdown(telemetry, server_handle).await;
+    shutdown(shutdown_timeout, telemetry, server_handle).await;
 
     Ok(())
 }
 
-async fn setup() -> Result<(ServerConfig, Arc<AppState>, Telemetry)> {
+async fn setup() -> Result<(ServerConfig, Arc<AppState>, Telemetry, GrpcHealthGate)> {
     dotenv::dotenv().ok();
 
     let is_dev = std::env::var("DEV_MODE")
@@ -52,7 +59,7 @@ async fn setup() -> Result<(ServerConfig, Arc<AppState>, Telemetry)> {
     let config = Config::init().context("Failed to load configuration")?;
     let server_config = ServerConfig::from_config(&config)?;
 
-    let telemetry = Telemetry::new("card-service", "http://otel-collector:4317".to_string());
+    let telemetry = Telemetry::new("card-service", config.telemetry.clone());
     let logger_provider = telemetry.init_logger();
     let _meter_provider = telemetry.init_meter();
     let _tracer_provider = telemetry.init_tracer();
@@ -73,19 +80,32 @@ async fn setup() -> Result<(ServerConfig, Arc<AppState>, Telemetry)> {
     .await
     .context("Failed to initialize database pool")?;
 
+    warm_up_db_pool(&db_pool, &WarmupConfig::from_env()).await;
+
     let state = Arc::new(
-        AppState::new(db_pool)
+        AppState::new(db_pool.clone())
             .await
             .context("Failed to create AppState")?,
     );
 
+    state
+        .self_check(&db_pool)
+        .await
+        .context("Startup self-check failed")?;
+
+    let health =
+        GrpcHealthGate::new::<CardServiceServer<CardServiceImpl>>(GrpcHealthConfig::from_env())
+            .await;
+    health.spawn_monitor(db_pool.clone());
+
     info!("✅ Application setup completed successfully.");
-    Ok((server_config, state, telemetry))
+    Ok((server_config, state, telemetry, health))
 }
 
 async fn run_servers(
     server_config: ServerConfig,
     state: Arc<AppState>,
+    health: GrpcHealthGate,
     shutdown_tx: broadcast::Sender<()>,
 ) -> Result<tokio::task::JoinHandle<()>> {
     let grpc_addr = server_config.grpc_addr;
@@ -94,22 +114,29 @@ async fn run_servers(
     let shutdown_tx_for_ctrlc = shutdown_tx.clone();
 
     let server_handle = tokio::spawn(async move {
+        let mut backoff = RestartBackoff::from_env();
+
         loop {
             info!("Attempting to start gRPC server on {grpc_addr}");
+            let started_at = tokio::time::Instant::now();
 
             let state = Arc::clone(&state);
             let service = CardServiceImpl::new(state);
 
             let shutdown_rx = shutdown_tx_for_server.subscribe();
 
-            match start_grpc_server(service, grpc_addr, shutdown_rx).await {
+            match start_grpc_server(service, grpc_addr, health.clone(), shutdown_rx).await {
                 Ok(()) => {
                     info!("gRPC server stopped gracefully.");
                     break;
                 }
                 Err(e) => {
-                    error!("gRPC server failed: {e}. Restarting in 5s...");
-                    tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
+                    let delay = backoff.next_delay(started_at.elapsed());
+                    error!(
+                        "gRPC server failed: {e}. Restarting in {delay:?} (attempt {})...",
+                        backoff.attempt()
+                    );
+                    tokio::time::sleep(delay).await;
                 }
             }
         }
@@ -129,10 +156,16 @@ async fn run_servers(
     Ok(server_handle)
 }
 
-async fn shutdown(telemetry: Telemetry, server_handle: tokio::task::JoinHandle<()>) {
-    info!("🛑 Shutting down all servers...");
+async fn shutdown(
+    shutdown_timeout: std::time::Duration,
+    telemetry: Telemetry,
+    server_handle: tokio::task::JoinHandle<()>,
+) {
+    info!(
+        "🛑 Shutting down all servers (timeout: {}s)...",
+        shutdown_timeout.as_secs()
+    );
 
-    let shutdown_timeout = tokio::time::Duration::from_secs(30);
     let shutdown_result = tokio::time::timeout(shutdown_timeout, server_handle).await;
 
     match shutdown_result {
@@ -157,19 +190,26 @@ async fn shutdown(telemetry: Telemetry, server_handle: tokio::task::JoinHandle<(
 async fn start_grpc_server(
     service: CardServiceImpl,
     addr: std::net::SocketAddr,
+    health: GrpcHealthGate,
     mut shutdown_rx: broadcast::Receiver<()>,
 ) -> Result<()> {
     let config = GrpcServerConfig::from_env()?;
+    let service_accounts = ServiceAccountConfig::from_env()?;
 
     info!("Starting gRPC server on {addr} with config: {:?}", config);
 
+    let health_service = health.service();
+
     let shutdown_future = async move {
         let _ = shutdown_rx.recv().await;
         info!("gRPC server received shutdown signal");
+        health.drain().await;
     };
 
     tonic::transport::Server::builder()
         .layer(ConcurrencyLimitLayer::new(config.concurrency_limit))
+        .layer(ServiceAccountLayer::new(service_accounts))
+        .layer(GrpcHandlerTimeoutLayer::new(&config))
         .tcp_keepalive(config.tcp_keepalive())
         .tcp_nodelay(config.tcp_nodelay)
         .timeout(config.timeout())
@@ -178,6 +218,7 @@ async fn start_grpc_server(
         .initial_connection_window_size(config.initial_connection_window_size())
         .initial_stream_window_size(config.initial_stream_window_size())
         .max_concurrent_streams(Some(config.max_concurrent_streams))
+        .add_service(health_service)
         .add_service(CardServiceServer::new(service))
         .serve_with_shutdown(addr, shutdown_future)
         .await
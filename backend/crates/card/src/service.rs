@@ -1,20 +1,24 @@
 use crate::state::AppState;
 use genproto::card::{
     ApiResponseCard, ApiResponseCardAll, ApiResponseCardDelete, ApiResponseCardDeleteAt,
-    ApiResponseDashboardCard, ApiResponseDashboardCardNumber, ApiResponseMonthlyAmount,
+    ApiResponseCardFreeze, ApiResponseCardReissue, ApiResponseCardRestoredIds,
+    ApiResponseCardStatus, ApiResponseCards, ApiResponseDashboardCard,
+    ApiResponseDashboardCardNumber, ApiResponseDashboardCardNumbers, ApiResponseMonthlyAmount,
     ApiResponseMonthlyBalance, ApiResponsePaginationCard, ApiResponsePaginationCardDeleteAt,
-    ApiResponseYearlyAmount, ApiResponseYearlyBalance, CreateCardRequest, FindAllCardRequest,
-    FindByCardNumberRequest, FindByIdCardRequest, FindByUserIdCardRequest, FindYearAmount,
-    FindYearAmountCardNumber, FindYearBalance, FindYearBalanceCardNumber, UpdateCardRequest,
-    card_service_server::CardService,
+    ApiResponseSystemStats, ApiResponseTopCards, ApiResponseYearlyAmount, ApiResponseYearlyBalance,
+    CreateCardRequest, FindAllCardRequest, FindByCardNumberRequest, FindByIdCardRequest,
+    FindByUserIdCardRequest, FindDashboardCardNumbersRequest, FindTopCardsRequest, FindYearAmount,
+    FindYearAmountCardNumber, FindYearBalance, FindYearBalanceCardNumber,
+    RestoreCardsByFilterRequest, UpdateCardRequest, card_service_server::CardService,
 };
 use shared::{
     domain::requests::card::{
-        CreateCardRequest as DomainCreateCardRequest, FindAllCards, MonthYearCardNumberCard,
+        CreateCardRequest as DomainCreateCardRequest, FindAllCards, FindCardDashboards,
+        FindTopCards, MonthYearCardNumberCard, RestoreCardsByFilter,
         UpdateCardRequest as DomainUpdateCardRequest,
     },
     errors::{AppErrorGrpc, CircuitBreakerError},
-    utils::timestamp_to_naive_date,
+    utils::{mask_card_number, peer_addr, timestamp_to_naive_date},
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
@@ -55,6 +59,7 @@ impl CardServiceImpl {
 impl CardService for CardServiceImpl {
     #[instrument(skip(self, request), fields(
         method = "find_all_card",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -70,6 +75,7 @@ impl CardService for CardServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -128,7 +134,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_id_card", card_id = request.get_ref().card_id))]
+    #[instrument(skip(self, request), fields(method = "find_by_id_card", peer = %peer_addr(&request), card_id = request.get_ref().card_id))]
     async fn find_by_id_card(
         &self,
         request: Request<FindByIdCardRequest>,
@@ -180,7 +186,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_user_id_card", user_id = request.get_ref().user_id))]
+    #[instrument(skip(self, request), fields(method = "find_by_user_id_card", peer = %peer_addr(&request), user_id = request.get_ref().user_id))]
     async fn find_by_user_id_card(
         &self,
         request: Request<FindByUserIdCardRequest>,
@@ -232,8 +238,61 @@ impl CardService for CardServiceImpl {
         }
     }
 
+    #[instrument(skip(self, request), fields(method = "find_all_by_user_id_card", peer = %peer_addr(&request), user_id = request.get_ref().user_id))]
+    async fn find_all_by_user_id_card(
+        &self,
+        request: Request<FindByUserIdCardRequest>,
+    ) -> Result<Response<ApiResponseCards>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let user_id = req.user_id;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .card_query
+                    .find_all_by_user_id(user_id)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseCards {
+                    data: api_response.data.into_iter().map(Into::into).collect(),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(user_id = user_id, "find_all_by_user_id_card success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            user_id = user_id,
+                            "find_all_by_user_id_card rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(user_id = user_id, error = %inner, "find_all_by_user_id_card failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
     #[instrument(skip(self, request), fields(
         method = "find_by_active_card",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -249,6 +308,7 @@ impl CardService for CardServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -309,6 +369,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_trashed_card",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -324,6 +385,7 @@ impl CardService for CardServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: (!req.sort_by.is_empty()).then(|| req.sort_by.clone()),
         };
 
         let result = self
@@ -382,7 +444,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_card_number", card_number = request.get_ref().card_number))]
+    #[instrument(skip(self, request), fields(method = "find_by_card_number", peer = %peer_addr(&request), card_number = request.get_ref().card_number))]
     async fn find_by_card_number(
         &self,
         request: Request<FindByCardNumberRequest>,
@@ -433,7 +495,55 @@ impl CardService for CardServiceImpl {
             }
         }
     }
-    #[instrument(skip(self, _request), fields(method = "dashboard_card"))]
+    #[instrument(skip(self, request), fields(method = "check_card", peer = %peer_addr(&request), card_number = %mask_card_number(&request.get_ref().card_number)))]
+    async fn check_card(
+        &self,
+        request: Request<FindByCardNumberRequest>,
+    ) -> Result<Response<ApiResponseCardStatus>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let card_number = req.card_number.clone();
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .card_query
+                    .check_card(&card_number)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseCardStatus {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!("check_card success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!("check_card rejected: circuit breaker open");
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(error = %inner, "check_card failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+    #[instrument(skip(self, _request), fields(method = "dashboard_card", peer = %peer_addr(&_request)))]
     async fn dashboard_card(
         &self,
         _request: Request<()>,
@@ -479,7 +589,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "dashboard_card_number", card_number = request.get_ref().card_number))]
+    #[instrument(skip(self, request), fields(method = "dashboard_card_number", peer = %peer_addr(&request), card_number = request.get_ref().card_number))]
     async fn dashboard_card_number(
         &self,
         request: Request<FindByCardNumberRequest>,
@@ -535,7 +645,178 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_monthly_balance", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "dashboard_card_numbers", peer = %peer_addr(&request), card_count = request.get_ref().card_numbers.len()))]
+    async fn dashboard_card_numbers(
+        &self,
+        request: Request<FindDashboardCardNumbersRequest>,
+    ) -> Result<Response<ApiResponseDashboardCardNumbers>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let domain_req = FindCardDashboards {
+            card_numbers: req.card_numbers,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .card_dashboard
+                    .get_dashboard_bycards(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseDashboardCardNumbers {
+                    status: api_response.status,
+                    message: api_response.message,
+                    dashboards: api_response
+                        .data
+                        .dashboards
+                        .into_iter()
+                        .map(|(card_number, dashboard)| (card_number, dashboard.into()))
+                        .collect(),
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    found = resp.get_ref().dashboards.len(),
+                    "dashboard_card_numbers success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!("dashboard_card_numbers rejected: circuit breaker open");
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(error = %inner, "dashboard_card_numbers failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, _request), fields(method = "system_stats", peer = %peer_addr(&_request)))]
+    async fn system_stats(
+        &self,
+        _request: Request<()>,
+    ) -> Result<Response<ApiResponseSystemStats>, Status> {
+        self.check_rate_limit().await?;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .card_dashboard
+                    .get_system_stats()
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseSystemStats {
+                    status: api_response.status,
+                    message: api_response.message,
+                    data: Some(api_response.data.into()),
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!("system_stats success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!("system_stats rejected: circuit breaker open");
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(error = %inner, "system_stats failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "top_cards", peer = %peer_addr(&request)))]
+    async fn top_cards(
+        &self,
+        request: Request<FindTopCardsRequest>,
+    ) -> Result<Response<ApiResponseTopCards>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+
+        let parse_date = |value: &str, field: &str| {
+            if value.is_empty() {
+                return Ok(None);
+            }
+            value
+                .parse()
+                .map(Some)
+                .map_err(|_| Status::invalid_argument(format!("{field} invalid")))
+        };
+
+        let domain_req = FindTopCards {
+            metric: req.metric.parse().map_err(Status::invalid_argument)?,
+            limit: if req.limit > 0 { req.limit } else { 10 },
+            start_date: parse_date(&req.start_date, "start_date")?,
+            end_date: parse_date(&req.end_date, "end_date")?,
+        };
+        let metric = domain_req.metric;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .card_dashboard
+                    .get_top_cards(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseTopCards {
+                    status: api_response.status,
+                    message: api_response.message,
+                    data: api_response.data.into_iter().map(Into::into).collect(),
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(%metric, "top_cards success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(%metric, "top_cards rejected: circuit breaker open");
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(%metric, error = %inner, "top_cards failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "find_monthly_balance", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_monthly_balance(
         &self,
         request: Request<FindYearBalance>,
@@ -591,7 +872,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_yearly_balance", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_yearly_balance", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_yearly_balance(
         &self,
         request: Request<FindYearBalance>,
@@ -646,7 +927,7 @@ impl CardService for CardServiceImpl {
             }
         }
     }
-    #[instrument(skip(self, request), fields(method = "find_monthly_topup_amount", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_monthly_topup_amount", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_monthly_topup_amount(
         &self,
         request: Request<FindYearAmount>,
@@ -702,7 +983,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_yearly_topup_amount", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_yearly_topup_amount", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_yearly_topup_amount(
         &self,
         request: Request<FindYearAmount>,
@@ -758,7 +1039,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_monthly_withdraw_amount", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_monthly_withdraw_amount", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_monthly_withdraw_amount(
         &self,
         request: Request<FindYearAmount>,
@@ -814,7 +1095,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_yearly_withdraw_amount", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_yearly_withdraw_amount", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_yearly_withdraw_amount(
         &self,
         request: Request<FindYearAmount>,
@@ -869,7 +1150,7 @@ impl CardService for CardServiceImpl {
             }
         }
     }
-    #[instrument(skip(self, request), fields(method = "find_monthly_transaction_amount", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_monthly_transaction_amount", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_monthly_transaction_amount(
         &self,
         request: Request<FindYearAmount>,
@@ -925,7 +1206,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_yearly_transaction_amount", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_yearly_transaction_amount", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_yearly_transaction_amount(
         &self,
         request: Request<FindYearAmount>,
@@ -981,7 +1262,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_monthly_transfer_sender_amount", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_monthly_transfer_sender_amount", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_monthly_transfer_sender_amount(
         &self,
         request: Request<FindYearAmount>,
@@ -1037,7 +1318,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_yearly_transfer_sender_amount", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_yearly_transfer_sender_amount", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_yearly_transfer_sender_amount(
         &self,
         request: Request<FindYearAmount>,
@@ -1093,7 +1374,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_monthly_transfer_receiver_amount", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_monthly_transfer_receiver_amount", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_monthly_transfer_receiver_amount(
         &self,
         request: Request<FindYearAmount>,
@@ -1149,7 +1430,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_yearly_transfer_receiver_amount", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_yearly_transfer_receiver_amount", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_yearly_transfer_receiver_amount(
         &self,
         request: Request<FindYearAmount>,
@@ -1206,6 +1487,7 @@ impl CardService for CardServiceImpl {
     }
     #[instrument(skip(self, request), fields(
         method = "find_monthly_balance_by_card_number",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         card_number = %request.get_ref().card_number
     ))]
@@ -1275,6 +1557,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_balance_by_card_number",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         card_number = %request.get_ref().card_number
     ))]
@@ -1344,6 +1627,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_topup_amount_by_card_number",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         card_number = %request.get_ref().card_number
     ))]
@@ -1413,6 +1697,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_topup_amount_by_card_number",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         card_number = %request.get_ref().card_number
     ))]
@@ -1482,6 +1767,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_withdraw_amount_by_card_number",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         card_number = %request.get_ref().card_number
     ))]
@@ -1551,6 +1837,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_withdraw_amount_by_card_number",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         card_number = %request.get_ref().card_number
     ))]
@@ -1619,6 +1906,7 @@ impl CardService for CardServiceImpl {
     }
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transaction_amount_by_card_number",
+        peer = %peer_addr(&request),
         card_number = %request.get_ref().card_number,
         year = request.get_ref().year
     ))]
@@ -1688,6 +1976,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transaction_amount_by_card_number",
+        peer = %peer_addr(&request),
         card_number = %request.get_ref().card_number,
         year = request.get_ref().year
     ))]
@@ -1757,6 +2046,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transfer_sender_amount_by_card_number",
+        peer = %peer_addr(&request),
         card_number = %request.get_ref().card_number,
         year = request.get_ref().year
     ))]
@@ -1826,6 +2116,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transfer_sender_amount_by_card_number",
+        peer = %peer_addr(&request),
         card_number = %request.get_ref().card_number,
         year = request.get_ref().year
     ))]
@@ -1895,6 +2186,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transfer_receiver_amount_by_card_number",
+        peer = %peer_addr(&request),
         card_number = %request.get_ref().card_number,
         year = request.get_ref().year
     ))]
@@ -1964,6 +2256,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transfer_receiver_amount_by_card_number",
+        peer = %peer_addr(&request),
         card_number = %request.get_ref().card_number,
         year = request.get_ref().year
     ))]
@@ -2033,6 +2326,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "create_card",
+        peer = %peer_addr(&request),
         user_id = request.get_ref().user_id,
         card_type = ?request.get_ref().card_type
     ))]
@@ -2100,6 +2394,7 @@ impl CardService for CardServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "update_card",
+        peer = %peer_addr(&request),
         card_id = request.get_ref().card_id,
         user_id = request.get_ref().user_id
     ))]
@@ -2166,7 +2461,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "trashed_card", card_id = request.get_ref().card_id))]
+    #[instrument(skip(self, request), fields(method = "trashed_card", peer = %peer_addr(&request), card_id = request.get_ref().card_id))]
     async fn trashed_card(
         &self,
         request: Request<FindByIdCardRequest>,
@@ -2218,7 +2513,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "restore_card", card_id = request.get_ref().card_id))]
+    #[instrument(skip(self, request), fields(method = "restore_card", peer = %peer_addr(&request), card_id = request.get_ref().card_id))]
     async fn restore_card(
         &self,
         request: Request<FindByIdCardRequest>,
@@ -2270,7 +2565,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "delete_card_permanent", card_id = request.get_ref().card_id))]
+    #[instrument(skip(self, request), fields(method = "delete_card_permanent", peer = %peer_addr(&request), card_id = request.get_ref().card_id))]
     async fn delete_card_permanent(
         &self,
         request: Request<FindByIdCardRequest>,
@@ -2321,7 +2616,7 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, _request), fields(method = "restore_all_card"))]
+    #[instrument(skip(self, _request), fields(method = "restore_all_card", peer = %peer_addr(&_request)))]
     async fn restore_all_card(
         &self,
         _request: Request<()>,
@@ -2343,6 +2638,7 @@ impl CardService for CardServiceImpl {
                 Ok(Response::new(ApiResponseCardAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
@@ -2366,7 +2662,221 @@ impl CardService for CardServiceImpl {
         }
     }
 
-    #[instrument(skip(self, _request), fields(method = "delete_all_card_permanent"))]
+    #[instrument(skip(self, request), fields(method = "reissue_card", peer = %peer_addr(&request), card_id = request.get_ref().card_id))]
+    async fn reissue_card(
+        &self,
+        request: Request<FindByIdCardRequest>,
+    ) -> Result<Response<ApiResponseCardReissue>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let card_id = req.card_id;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .card_command
+                    .reissue(card_id)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseCardReissue {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(card_id = card_id, "reissue_card success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            card_id = card_id,
+                            "reissue_card rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(card_id = card_id, error = %inner, "reissue_card failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "freeze_card", peer = %peer_addr(&request), card_id = request.get_ref().card_id))]
+    async fn freeze_card(
+        &self,
+        request: Request<FindByIdCardRequest>,
+    ) -> Result<Response<ApiResponseCardFreeze>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let card_id = req.card_id;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .card_command
+                    .freeze(card_id)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseCardFreeze {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(card_id = card_id, "freeze_card success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            card_id = card_id,
+                            "freeze_card rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(card_id = card_id, error = %inner, "freeze_card failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "unfreeze_card", peer = %peer_addr(&request), card_id = request.get_ref().card_id))]
+    async fn unfreeze_card(
+        &self,
+        request: Request<FindByIdCardRequest>,
+    ) -> Result<Response<ApiResponseCardFreeze>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let card_id = req.card_id;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .card_command
+                    .unfreeze(card_id)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseCardFreeze {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(card_id = card_id, "unfreeze_card success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            card_id = card_id,
+                            "unfreeze_card rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(card_id = card_id, error = %inner, "unfreeze_card failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "restore_cards_by_filter", peer = %peer_addr(&request)))]
+    async fn restore_cards_by_filter(
+        &self,
+        request: Request<RestoreCardsByFilterRequest>,
+    ) -> Result<Response<ApiResponseCardRestoredIds>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+
+        let domain_req = RestoreCardsByFilter {
+            search: req.search,
+            user_id: req.user_id,
+            deleted_from: timestamp_to_naive_date(req.deleted_from),
+            deleted_to: timestamp_to_naive_date(req.deleted_to),
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .card_command
+                    .restore_by_filter(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseCardRestoredIds {
+                    message: api_response.message,
+                    status: api_response.status,
+                    restored_ids: api_response.data,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    restored = resp.get_ref().restored_ids.len(),
+                    "restore_cards_by_filter success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!("restore_cards_by_filter rejected: circuit breaker open");
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(error = %inner, "restore_cards_by_filter failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, _request), fields(method = "delete_all_card_permanent", peer = %peer_addr(&_request)))]
     async fn delete_all_card_permanent(
         &self,
         _request: Request<()>,
@@ -2388,6 +2898,7 @@ impl CardService for CardServiceImpl {
                 Ok(Response::new(ApiResponseCardAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
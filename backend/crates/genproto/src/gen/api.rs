@@ -19,3 +19,47 @@ pub struct ErrorResponse {
     #[prost(int32, tag = "3")]
     pub code: i32,
 }
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BalanceImpact {
+    #[prost(string, tag = "1")]
+    pub card_number: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub current_balance: i64,
+    #[prost(int64, tag = "3")]
+    pub amount: i64,
+    #[prost(int64, tag = "4")]
+    pub fee: i64,
+    #[prost(int64, tag = "5")]
+    pub resulting_balance: i64,
+    #[prost(bool, tag = "6")]
+    pub allowed: bool,
+    #[prost(string, tag = "7")]
+    pub reason: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseBalanceImpact {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<BalanceImpact>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct BulkResultError {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(string, tag = "2")]
+    pub reason: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct BulkResult {
+    #[prost(int64, tag = "1")]
+    pub total: i64,
+    #[prost(int64, tag = "2")]
+    pub affected: i64,
+    #[prost(int64, tag = "3")]
+    pub skipped: i64,
+    #[prost(message, repeated, tag = "4")]
+    pub errors: ::prost::alloc::vec::Vec<BulkResultError>,
+}
@@ -7,6 +7,8 @@ pub struct FindAllCardRequest {
     pub page_size: i32,
     #[prost(string, tag = "3")]
     pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub sort_by: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindByIdCardRequest {
@@ -167,11 +169,89 @@ pub struct ApiResponseCardDelete {
     pub message: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RestoreCardsByFilterRequest {
+    #[prost(string, tag = "1")]
+    pub search: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub user_id: ::core::option::Option<i32>,
+    #[prost(message, optional, tag = "3")]
+    pub deleted_from: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "4")]
+    pub deleted_to: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseCardRestoredIds {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(int32, repeated, tag = "3")]
+    pub restored_ids: ::prost::alloc::vec::Vec<i32>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CardReissueResponse {
+    #[prost(message, optional, tag = "1")]
+    pub previous_card: ::core::option::Option<CardResponseDeleteAt>,
+    #[prost(message, optional, tag = "2")]
+    pub card: ::core::option::Option<CardResponse>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseCardReissue {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<CardReissueResponse>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CardFreezeResponse {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(int32, tag = "2")]
+    pub user_id: i32,
+    #[prost(string, tag = "3")]
+    pub card_number: ::prost::alloc::string::String,
+    #[prost(bool, tag = "4")]
+    pub frozen: bool,
+    #[prost(string, tag = "5")]
+    pub frozen_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseCardFreeze {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<CardFreezeResponse>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CardStatusResponse {
+    #[prost(bool, tag = "1")]
+    pub exists: bool,
+    #[prost(bool, tag = "2")]
+    pub active: bool,
+    #[prost(string, tag = "3")]
+    pub scheme: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseCardStatus {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<CardStatusResponse>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseCardAll {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<super::api::BulkResult>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponsePaginationCard {
@@ -249,6 +329,78 @@ pub struct ApiResponseDashboardCardNumber {
     #[prost(message, optional, tag = "3")]
     pub data: ::core::option::Option<CardResponseDashboardCardNumber>,
 }
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindDashboardCardNumbersRequest {
+    #[prost(string, repeated, tag = "1")]
+    pub card_numbers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApiResponseDashboardCardNumbers {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(map = "string, message", tag = "3")]
+    pub dashboards: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        CardResponseDashboardCardNumber,
+    >,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CardResponseSystemStats {
+    #[prost(int64, tag = "1")]
+    pub total_users: i64,
+    #[prost(int64, tag = "2")]
+    pub total_cards: i64,
+    #[prost(int64, tag = "3")]
+    pub total_balance: i64,
+    #[prost(int64, tag = "4")]
+    pub today_transaction_count: i64,
+    #[prost(int64, tag = "5")]
+    pub today_transaction_volume: i64,
+    #[prost(string, tag = "6")]
+    pub date: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseSystemStats {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<CardResponseSystemStats>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindTopCardsRequest {
+    #[prost(string, tag = "1")]
+    pub metric: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub limit: i32,
+    #[prost(string, tag = "3")]
+    pub start_date: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub end_date: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CardResponseTopCard {
+    #[prost(int32, tag = "1")]
+    pub rank: i32,
+    #[prost(string, tag = "2")]
+    pub card_number: ::prost::alloc::string::String,
+    #[prost(int32, tag = "3")]
+    pub user_id: i32,
+    #[prost(int64, tag = "4")]
+    pub value: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApiResponseTopCards {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub data: ::prost::alloc::vec::Vec<CardResponseTopCard>,
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindByUserIdCardRequest {
     #[prost(int32, tag = "1")]
@@ -462,6 +614,30 @@ pub mod card_service_client {
                 .insert(GrpcMethod::new("card.CardService", "FindByUserIdCard"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn find_all_by_user_id_card(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindByUserIdCardRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCards>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/card.CardService/FindAllByUserIdCard",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("card.CardService", "FindAllByUserIdCard"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn find_by_active_card(
             &mut self,
             request: impl tonic::IntoRequest<super::FindAllCardRequest>,
@@ -534,6 +710,30 @@ pub mod card_service_client {
                 .insert(GrpcMethod::new("card.CardService", "FindByCardNumber"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn check_card(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindByCardNumberRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardStatus>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/card.CardService/CheckCard",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("card.CardService", "CheckCard"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn dashboard_card(
             &mut self,
             request: impl tonic::IntoRequest<()>,
@@ -582,6 +782,77 @@ pub mod card_service_client {
                 .insert(GrpcMethod::new("card.CardService", "DashboardCardNumber"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn dashboard_card_numbers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindDashboardCardNumbersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseDashboardCardNumbers>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/card.CardService/DashboardCardNumbers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("card.CardService", "DashboardCardNumbers"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn system_stats(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseSystemStats>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/card.CardService/SystemStats",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("card.CardService", "SystemStats"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn top_cards(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindTopCardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseTopCards>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/card.CardService/TopCards",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("card.CardService", "TopCards"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn find_monthly_balance(
             &mut self,
             request: impl tonic::IntoRequest<super::FindYearBalance>,
@@ -1355,6 +1626,78 @@ pub mod card_service_client {
                 .insert(GrpcMethod::new("card.CardService", "DeleteCardPermanent"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn reissue_card(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindByIdCardRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardReissue>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/card.CardService/ReissueCard",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("card.CardService", "ReissueCard"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn freeze_card(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindByIdCardRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardFreeze>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/card.CardService/FreezeCard",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("card.CardService", "FreezeCard"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn unfreeze_card(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindByIdCardRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardFreeze>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/card.CardService/UnfreezeCard",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("card.CardService", "UnfreezeCard"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn restore_all_card(
             &mut self,
             request: impl tonic::IntoRequest<()>,
@@ -1379,11 +1722,11 @@ pub mod card_service_client {
                 .insert(GrpcMethod::new("card.CardService", "RestoreAllCard"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn delete_all_card_permanent(
+        pub async fn restore_cards_by_filter(
             &mut self,
-            request: impl tonic::IntoRequest<()>,
+            request: impl tonic::IntoRequest<super::RestoreCardsByFilterRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ApiResponseCardAll>,
+            tonic::Response<super::ApiResponseCardRestoredIds>,
             tonic::Status,
         > {
             self.inner
@@ -1396,14 +1739,38 @@ pub mod card_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/card.CardService/DeleteAllCardPermanent",
+                "/card.CardService/RestoreCardsByFilter",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("card.CardService", "DeleteAllCardPermanent"));
+                .insert(GrpcMethod::new("card.CardService", "RestoreCardsByFilter"));
             self.inner.unary(req, path, codec).await
         }
-    }
+        pub async fn delete_all_card_permanent(
+            &mut self,
+            request: impl tonic::IntoRequest<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardAll>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/card.CardService/DeleteAllCardPermanent",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("card.CardService", "DeleteAllCardPermanent"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
 }
 /// Generated server implementations.
 pub mod card_service_server {
@@ -1433,6 +1800,13 @@ pub mod card_service_server {
             &self,
             request: tonic::Request<super::FindByUserIdCardRequest>,
         ) -> std::result::Result<tonic::Response<super::ApiResponseCard>, tonic::Status>;
+        async fn find_all_by_user_id_card(
+            &self,
+            request: tonic::Request<super::FindByUserIdCardRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCards>,
+            tonic::Status,
+        >;
         async fn find_by_active_card(
             &self,
             request: tonic::Request<super::FindAllCardRequest>,
@@ -1451,6 +1825,13 @@ pub mod card_service_server {
             &self,
             request: tonic::Request<super::FindByCardNumberRequest>,
         ) -> std::result::Result<tonic::Response<super::ApiResponseCard>, tonic::Status>;
+        async fn check_card(
+            &self,
+            request: tonic::Request<super::FindByCardNumberRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardStatus>,
+            tonic::Status,
+        >;
         async fn dashboard_card(
             &self,
             request: tonic::Request<()>,
@@ -1465,6 +1846,27 @@ pub mod card_service_server {
             tonic::Response<super::ApiResponseDashboardCardNumber>,
             tonic::Status,
         >;
+        async fn dashboard_card_numbers(
+            &self,
+            request: tonic::Request<super::FindDashboardCardNumbersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseDashboardCardNumbers>,
+            tonic::Status,
+        >;
+        async fn system_stats(
+            &self,
+            request: tonic::Request<()>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseSystemStats>,
+            tonic::Status,
+        >;
+        async fn top_cards(
+            &self,
+            request: tonic::Request<super::FindTopCardsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseTopCards>,
+            tonic::Status,
+        >;
         async fn find_monthly_balance(
             &self,
             request: tonic::Request<super::FindYearBalance>,
@@ -1662,6 +2064,27 @@ pub mod card_service_server {
             tonic::Response<super::ApiResponseCardDelete>,
             tonic::Status,
         >;
+        async fn reissue_card(
+            &self,
+            request: tonic::Request<super::FindByIdCardRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardReissue>,
+            tonic::Status,
+        >;
+        async fn freeze_card(
+            &self,
+            request: tonic::Request<super::FindByIdCardRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardFreeze>,
+            tonic::Status,
+        >;
+        async fn unfreeze_card(
+            &self,
+            request: tonic::Request<super::FindByIdCardRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardFreeze>,
+            tonic::Status,
+        >;
         async fn restore_all_card(
             &self,
             request: tonic::Request<()>,
@@ -1669,6 +2092,13 @@ pub mod card_service_server {
             tonic::Response<super::ApiResponseCardAll>,
             tonic::Status,
         >;
+        async fn restore_cards_by_filter(
+            &self,
+            request: tonic::Request<super::RestoreCardsByFilterRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardRestoredIds>,
+            tonic::Status,
+        >;
         async fn delete_all_card_permanent(
             &self,
             request: tonic::Request<()>,
@@ -1755,12 +2185,243 @@ pub mod card_service_server {
             match req.uri().path() {
                 "/card.CardService/FindAllCard" => {
                     #[allow(non_camel_case_types)]
-                    struct FindAllCardSvc<T: CardService>(pub Arc<T>);
+                    struct FindAllCardSvc<T: CardService>(pub Arc<T>);
+                    impl<
+                        T: CardService,
+                    > tonic::server::UnaryService<super::FindAllCardRequest>
+                    for FindAllCardSvc<T> {
+                        type Response = super::ApiResponsePaginationCard;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindAllCardRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CardService>::find_all_card(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindAllCardSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/card.CardService/FindByIdCard" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindByIdCardSvc<T: CardService>(pub Arc<T>);
+                    impl<
+                        T: CardService,
+                    > tonic::server::UnaryService<super::FindByIdCardRequest>
+                    for FindByIdCardSvc<T> {
+                        type Response = super::ApiResponseCard;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindByIdCardRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CardService>::find_by_id_card(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindByIdCardSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/card.CardService/FindByUserIdCard" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindByUserIdCardSvc<T: CardService>(pub Arc<T>);
+                    impl<
+                        T: CardService,
+                    > tonic::server::UnaryService<super::FindByUserIdCardRequest>
+                    for FindByUserIdCardSvc<T> {
+                        type Response = super::ApiResponseCard;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindByUserIdCardRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CardService>::find_by_user_id_card(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindByUserIdCardSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/card.CardService/FindAllByUserIdCard" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindAllByUserIdCardSvc<T: CardService>(pub Arc<T>);
+                    impl<
+                        T: CardService,
+                    > tonic::server::UnaryService<super::FindByUserIdCardRequest>
+                    for FindAllByUserIdCardSvc<T> {
+                        type Response = super::ApiResponseCards;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindByUserIdCardRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CardService>::find_all_by_user_id_card(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindAllByUserIdCardSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/card.CardService/FindByActiveCard" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindByActiveCardSvc<T: CardService>(pub Arc<T>);
+                    impl<
+                        T: CardService,
+                    > tonic::server::UnaryService<super::FindAllCardRequest>
+                    for FindByActiveCardSvc<T> {
+                        type Response = super::ApiResponsePaginationCardDeleteAt;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindAllCardRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CardService>::find_by_active_card(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindByActiveCardSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/card.CardService/FindByTrashedCard" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindByTrashedCardSvc<T: CardService>(pub Arc<T>);
                     impl<
                         T: CardService,
                     > tonic::server::UnaryService<super::FindAllCardRequest>
-                    for FindAllCardSvc<T> {
-                        type Response = super::ApiResponsePaginationCard;
+                    for FindByTrashedCardSvc<T> {
+                        type Response = super::ApiResponsePaginationCardDeleteAt;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -1771,7 +2432,8 @@ pub mod card_service_server {
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CardService>::find_all_card(&inner, request).await
+                                <T as CardService>::find_by_trashed_card(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -1782,7 +2444,7 @@ pub mod card_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = FindAllCardSvc(inner);
+                        let method = FindByTrashedCardSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1798,13 +2460,13 @@ pub mod card_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/card.CardService/FindByIdCard" => {
+                "/card.CardService/FindByCardNumber" => {
                     #[allow(non_camel_case_types)]
-                    struct FindByIdCardSvc<T: CardService>(pub Arc<T>);
+                    struct FindByCardNumberSvc<T: CardService>(pub Arc<T>);
                     impl<
                         T: CardService,
-                    > tonic::server::UnaryService<super::FindByIdCardRequest>
-                    for FindByIdCardSvc<T> {
+                    > tonic::server::UnaryService<super::FindByCardNumberRequest>
+                    for FindByCardNumberSvc<T> {
                         type Response = super::ApiResponseCard;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
@@ -1812,11 +2474,12 @@ pub mod card_service_server {
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::FindByIdCardRequest>,
+                            request: tonic::Request<super::FindByCardNumberRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CardService>::find_by_id_card(&inner, request).await
+                                <T as CardService>::find_by_card_number(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -1827,7 +2490,7 @@ pub mod card_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = FindByIdCardSvc(inner);
+                        let method = FindByCardNumberSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1843,26 +2506,25 @@ pub mod card_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/card.CardService/FindByUserIdCard" => {
+                "/card.CardService/CheckCard" => {
                     #[allow(non_camel_case_types)]
-                    struct FindByUserIdCardSvc<T: CardService>(pub Arc<T>);
+                    struct CheckCardSvc<T: CardService>(pub Arc<T>);
                     impl<
                         T: CardService,
-                    > tonic::server::UnaryService<super::FindByUserIdCardRequest>
-                    for FindByUserIdCardSvc<T> {
-                        type Response = super::ApiResponseCard;
+                    > tonic::server::UnaryService<super::FindByCardNumberRequest>
+                    for CheckCardSvc<T> {
+                        type Response = super::ApiResponseCardStatus;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::FindByUserIdCardRequest>,
+                            request: tonic::Request<super::FindByCardNumberRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CardService>::find_by_user_id_card(&inner, request)
-                                    .await
+                                <T as CardService>::check_card(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1873,7 +2535,7 @@ pub mod card_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = FindByUserIdCardSvc(inner);
+                        let method = CheckCardSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1889,26 +2551,20 @@ pub mod card_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/card.CardService/FindByActiveCard" => {
+                "/card.CardService/DashboardCard" => {
                     #[allow(non_camel_case_types)]
-                    struct FindByActiveCardSvc<T: CardService>(pub Arc<T>);
-                    impl<
-                        T: CardService,
-                    > tonic::server::UnaryService<super::FindAllCardRequest>
-                    for FindByActiveCardSvc<T> {
-                        type Response = super::ApiResponsePaginationCardDeleteAt;
+                    struct DashboardCardSvc<T: CardService>(pub Arc<T>);
+                    impl<T: CardService> tonic::server::UnaryService<()>
+                    for DashboardCardSvc<T> {
+                        type Response = super::ApiResponseDashboardCard;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
-                        fn call(
-                            &mut self,
-                            request: tonic::Request<super::FindAllCardRequest>,
-                        ) -> Self::Future {
+                        fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CardService>::find_by_active_card(&inner, request)
-                                    .await
+                                <T as CardService>::dashboard_card(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1919,7 +2575,7 @@ pub mod card_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = FindByActiveCardSvc(inner);
+                        let method = DashboardCardSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1935,25 +2591,25 @@ pub mod card_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/card.CardService/FindByTrashedCard" => {
+                "/card.CardService/DashboardCardNumber" => {
                     #[allow(non_camel_case_types)]
-                    struct FindByTrashedCardSvc<T: CardService>(pub Arc<T>);
+                    struct DashboardCardNumberSvc<T: CardService>(pub Arc<T>);
                     impl<
                         T: CardService,
-                    > tonic::server::UnaryService<super::FindAllCardRequest>
-                    for FindByTrashedCardSvc<T> {
-                        type Response = super::ApiResponsePaginationCardDeleteAt;
+                    > tonic::server::UnaryService<super::FindByCardNumberRequest>
+                    for DashboardCardNumberSvc<T> {
+                        type Response = super::ApiResponseDashboardCardNumber;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::FindAllCardRequest>,
+                            request: tonic::Request<super::FindByCardNumberRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CardService>::find_by_trashed_card(&inner, request)
+                                <T as CardService>::dashboard_card_number(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -1965,7 +2621,7 @@ pub mod card_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = FindByTrashedCardSvc(inner);
+                        let method = DashboardCardNumberSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1981,25 +2637,27 @@ pub mod card_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/card.CardService/FindByCardNumber" => {
+                "/card.CardService/DashboardCardNumbers" => {
                     #[allow(non_camel_case_types)]
-                    struct FindByCardNumberSvc<T: CardService>(pub Arc<T>);
+                    struct DashboardCardNumbersSvc<T: CardService>(pub Arc<T>);
                     impl<
                         T: CardService,
-                    > tonic::server::UnaryService<super::FindByCardNumberRequest>
-                    for FindByCardNumberSvc<T> {
-                        type Response = super::ApiResponseCard;
+                    > tonic::server::UnaryService<super::FindDashboardCardNumbersRequest>
+                    for DashboardCardNumbersSvc<T> {
+                        type Response = super::ApiResponseDashboardCardNumbers;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::FindByCardNumberRequest>,
+                            request: tonic::Request<
+                                super::FindDashboardCardNumbersRequest,
+                            >,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CardService>::find_by_card_number(&inner, request)
+                                <T as CardService>::dashboard_card_numbers(&inner, request)
                                     .await
                             };
                             Box::pin(fut)
@@ -2011,7 +2669,7 @@ pub mod card_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = FindByCardNumberSvc(inner);
+                        let method = DashboardCardNumbersSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2027,12 +2685,12 @@ pub mod card_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/card.CardService/DashboardCard" => {
+                "/card.CardService/SystemStats" => {
                     #[allow(non_camel_case_types)]
-                    struct DashboardCardSvc<T: CardService>(pub Arc<T>);
+                    struct SystemStatsSvc<T: CardService>(pub Arc<T>);
                     impl<T: CardService> tonic::server::UnaryService<()>
-                    for DashboardCardSvc<T> {
-                        type Response = super::ApiResponseDashboardCard;
+                    for SystemStatsSvc<T> {
+                        type Response = super::ApiResponseSystemStats;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
@@ -2040,7 +2698,7 @@ pub mod card_service_server {
                         fn call(&mut self, request: tonic::Request<()>) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CardService>::dashboard_card(&inner, request).await
+                                <T as CardService>::system_stats(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2051,7 +2709,7 @@ pub mod card_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DashboardCardSvc(inner);
+                        let method = SystemStatsSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -2067,26 +2725,25 @@ pub mod card_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/card.CardService/DashboardCardNumber" => {
+                "/card.CardService/TopCards" => {
                     #[allow(non_camel_case_types)]
-                    struct DashboardCardNumberSvc<T: CardService>(pub Arc<T>);
+                    struct TopCardsSvc<T: CardService>(pub Arc<T>);
                     impl<
                         T: CardService,
-                    > tonic::server::UnaryService<super::FindByCardNumberRequest>
-                    for DashboardCardNumberSvc<T> {
-                        type Response = super::ApiResponseDashboardCardNumber;
+                    > tonic::server::UnaryService<super::FindTopCardsRequest>
+                    for TopCardsSvc<T> {
+                        type Response = super::ApiResponseTopCards;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::FindByCardNumberRequest>,
+                            request: tonic::Request<super::FindTopCardsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as CardService>::dashboard_card_number(&inner, request)
-                                    .await
+                                <T as CardService>::top_cards(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -2097,7 +2754,7 @@ pub mod card_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DashboardCardNumberSvc(inner);
+                        let method = TopCardsSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -3549,6 +4206,141 @@ pub mod card_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/card.CardService/ReissueCard" => {
+                    #[allow(non_camel_case_types)]
+                    struct ReissueCardSvc<T: CardService>(pub Arc<T>);
+                    impl<
+                        T: CardService,
+                    > tonic::server::UnaryService<super::FindByIdCardRequest>
+                    for ReissueCardSvc<T> {
+                        type Response = super::ApiResponseCardReissue;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindByIdCardRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CardService>::reissue_card(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ReissueCardSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/card.CardService/FreezeCard" => {
+                    #[allow(non_camel_case_types)]
+                    struct FreezeCardSvc<T: CardService>(pub Arc<T>);
+                    impl<
+                        T: CardService,
+                    > tonic::server::UnaryService<super::FindByIdCardRequest>
+                    for FreezeCardSvc<T> {
+                        type Response = super::ApiResponseCardFreeze;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindByIdCardRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CardService>::freeze_card(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FreezeCardSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/card.CardService/UnfreezeCard" => {
+                    #[allow(non_camel_case_types)]
+                    struct UnfreezeCardSvc<T: CardService>(pub Arc<T>);
+                    impl<
+                        T: CardService,
+                    > tonic::server::UnaryService<super::FindByIdCardRequest>
+                    for UnfreezeCardSvc<T> {
+                        type Response = super::ApiResponseCardFreeze;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindByIdCardRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CardService>::unfreeze_card(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = UnfreezeCardSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/card.CardService/RestoreAllCard" => {
                     #[allow(non_camel_case_types)]
                     struct RestoreAllCardSvc<T: CardService>(pub Arc<T>);
@@ -3589,6 +4381,52 @@ pub mod card_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/card.CardService/RestoreCardsByFilter" => {
+                    #[allow(non_camel_case_types)]
+                    struct RestoreCardsByFilterSvc<T: CardService>(pub Arc<T>);
+                    impl<
+                        T: CardService,
+                    > tonic::server::UnaryService<super::RestoreCardsByFilterRequest>
+                    for RestoreCardsByFilterSvc<T> {
+                        type Response = super::ApiResponseCardRestoredIds;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RestoreCardsByFilterRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as CardService>::restore_cards_by_filter(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RestoreCardsByFilterSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/card.CardService/DeleteAllCardPermanent" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteAllCardPermanentSvc<T: CardService>(pub Arc<T>);
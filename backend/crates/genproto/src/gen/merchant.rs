@@ -5,6 +5,8 @@ pub struct CreateMerchantRequest {
     pub name: ::prost::alloc::string::String,
     #[prost(int32, tag = "2")]
     pub user_id: i32,
+    #[prost(string, optional, tag = "3")]
+    pub business_id: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct UpdateMerchantRequest {
@@ -77,6 +79,43 @@ pub struct MerchantTransactionResponse {
     pub deleted_at: ::core::option::Option<::prost::alloc::string::String>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MerchantTransactionDailyResponse {
+    #[prost(string, tag = "1")]
+    pub day: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub total_transactions: i32,
+    #[prost(int64, tag = "3")]
+    pub total_amount: i64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct MerchantApiKeyUsageResponse {
+    #[prost(string, tag = "1")]
+    pub api_key: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub total_transactions: i32,
+    #[prost(int64, tag = "3")]
+    pub total_amount: i64,
+    #[prost(message, repeated, tag = "4")]
+    pub daily: ::prost::alloc::vec::Vec<MerchantTransactionDailyResponse>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MerchantSettlementResponse {
+    #[prost(int32, tag = "1")]
+    pub merchant_id: i32,
+    #[prost(string, tag = "2")]
+    pub start_date: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub end_date: ::prost::alloc::string::String,
+    #[prost(int64, tag = "4")]
+    pub gross: i64,
+    #[prost(int64, tag = "5")]
+    pub fees: i64,
+    #[prost(int64, tag = "6")]
+    pub refunds: i64,
+    #[prost(int64, tag = "7")]
+    pub net: i64,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct MerchantResponseMonthlyPaymentMethod {
     #[prost(string, tag = "1")]
     pub month: ::prost::alloc::string::String,
@@ -212,12 +251,14 @@ pub struct ApiResponseMerchantDelete {
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseMerchantAll {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<super::api::BulkResult>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponsePaginationMerchant {
@@ -252,6 +293,33 @@ pub struct ApiResponsePaginationMerchantTransaction {
     #[prost(message, optional, tag = "4")]
     pub pagination: ::core::option::Option<super::api::PaginationMeta>,
 }
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApiResponseMerchantTransactionDaily {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub data: ::prost::alloc::vec::Vec<MerchantTransactionDailyResponse>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApiResponseMerchantApiKeyUsage {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub data: ::prost::alloc::vec::Vec<MerchantApiKeyUsageResponse>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseMerchantSettlement {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<MerchantSettlementResponse>,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindAllMerchantRequest {
     #[prost(int32, tag = "1")]
@@ -260,6 +328,8 @@ pub struct FindAllMerchantRequest {
     pub page_size: i32,
     #[prost(string, tag = "3")]
     pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub sort_by: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindAllMerchantTransaction {
@@ -272,6 +342,15 @@ pub struct FindAllMerchantTransaction {
     #[prost(int32, tag = "4")]
     pub merchant_id: i32,
 }
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindMerchantTransactionDaily {
+    #[prost(int32, tag = "1")]
+    pub merchant_id: i32,
+    #[prost(message, optional, tag = "2")]
+    pub start_date: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "3")]
+    pub end_date: ::core::option::Option<::prost_types::Timestamp>,
+}
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindAllMerchantApikey {
     #[prost(int32, tag = "1")]
@@ -299,6 +378,40 @@ pub struct FindByMerchantUserIdRequest {
     pub user_id: i32,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MerchantUserRequest {
+    #[prost(int32, tag = "1")]
+    pub merchant_id: i32,
+    #[prost(int32, tag = "2")]
+    pub user_id: i32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetMerchantWebhookRequest {
+    #[prost(int32, tag = "1")]
+    pub merchant_id: i32,
+    #[prost(string, tag = "2")]
+    pub url: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MerchantWebhookResponse {
+    #[prost(int32, tag = "1")]
+    pub merchant_id: i32,
+    #[prost(string, tag = "2")]
+    pub url: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub created_at: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub updated_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseMerchantWebhook {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<MerchantWebhookResponse>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct TrashedMerchantRequest {
     #[prost(int32, tag = "1")]
     pub merchant_id: i32,
@@ -722,6 +835,93 @@ pub mod merchant_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn find_daily_transaction_by_merchant(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindMerchantTransactionDaily>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantTransactionDaily>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/merchant.MerchantService/FindDailyTransactionByMerchant",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "merchant.MerchantService",
+                        "FindDailyTransactionByMerchant",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn find_api_key_usage_by_merchant(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindMerchantTransactionDaily>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantApiKeyUsage>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/merchant.MerchantService/FindApiKeyUsageByMerchant",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "merchant.MerchantService",
+                        "FindApiKeyUsageByMerchant",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn find_settlement_by_merchant(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindMerchantTransactionDaily>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantSettlement>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/merchant.MerchantService/FindSettlementByMerchant",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "merchant.MerchantService",
+                        "FindSettlementByMerchant",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn find_monthly_payment_method_by_merchants(
             &mut self,
             request: impl tonic::IntoRequest<super::FindYearMerchantById>,
@@ -1125,6 +1325,113 @@ pub mod merchant_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn list_accessible_merchants(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindByMerchantUserIdRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsesMerchant>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/merchant.MerchantService/ListAccessibleMerchants",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "merchant.MerchantService",
+                        "ListAccessibleMerchants",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn grant_merchant_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MerchantUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantDelete>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/merchant.MerchantService/GrantMerchantUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("merchant.MerchantService", "GrantMerchantUser"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn revoke_merchant_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MerchantUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantDelete>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/merchant.MerchantService/RevokeMerchantUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("merchant.MerchantService", "RevokeMerchantUser"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_merchant_webhook(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetMerchantWebhookRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantWebhook>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/merchant.MerchantService/SetMerchantWebhook",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("merchant.MerchantService", "SetMerchantWebhook"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn find_by_active(
             &mut self,
             request: impl tonic::IntoRequest<super::FindAllMerchantRequest>,
@@ -1445,6 +1752,27 @@ pub mod merchant_service_server {
             tonic::Response<super::ApiResponsePaginationMerchantTransaction>,
             tonic::Status,
         >;
+        async fn find_daily_transaction_by_merchant(
+            &self,
+            request: tonic::Request<super::FindMerchantTransactionDaily>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantTransactionDaily>,
+            tonic::Status,
+        >;
+        async fn find_api_key_usage_by_merchant(
+            &self,
+            request: tonic::Request<super::FindMerchantTransactionDaily>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantApiKeyUsage>,
+            tonic::Status,
+        >;
+        async fn find_settlement_by_merchant(
+            &self,
+            request: tonic::Request<super::FindMerchantTransactionDaily>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantSettlement>,
+            tonic::Status,
+        >;
         async fn find_monthly_payment_method_by_merchants(
             &self,
             request: tonic::Request<super::FindYearMerchantById>,
@@ -1543,11 +1871,39 @@ pub mod merchant_service_server {
             tonic::Response<super::ApiResponsesMerchant>,
             tonic::Status,
         >;
-        async fn find_by_active(
+        async fn list_accessible_merchants(
             &self,
-            request: tonic::Request<super::FindAllMerchantRequest>,
+            request: tonic::Request<super::FindByMerchantUserIdRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ApiResponsePaginationMerchantDeleteAt>,
+            tonic::Response<super::ApiResponsesMerchant>,
+            tonic::Status,
+        >;
+        async fn grant_merchant_user(
+            &self,
+            request: tonic::Request<super::MerchantUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantDelete>,
+            tonic::Status,
+        >;
+        async fn revoke_merchant_user(
+            &self,
+            request: tonic::Request<super::MerchantUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantDelete>,
+            tonic::Status,
+        >;
+        async fn set_merchant_webhook(
+            &self,
+            request: tonic::Request<super::SetMerchantWebhookRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseMerchantWebhook>,
+            tonic::Status,
+        >;
+        async fn find_by_active(
+            &self,
+            request: tonic::Request<super::FindAllMerchantRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePaginationMerchantDeleteAt>,
             tonic::Status,
         >;
         async fn find_by_trashed(
@@ -2223,6 +2579,155 @@ pub mod merchant_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/merchant.MerchantService/FindDailyTransactionByMerchant" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindDailyTransactionByMerchantSvc<T: MerchantService>(
+                        pub Arc<T>,
+                    );
+                    impl<
+                        T: MerchantService,
+                    > tonic::server::UnaryService<super::FindMerchantTransactionDaily>
+                    for FindDailyTransactionByMerchantSvc<T> {
+                        type Response = super::ApiResponseMerchantTransactionDaily;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindMerchantTransactionDaily>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MerchantService>::find_daily_transaction_by_merchant(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindDailyTransactionByMerchantSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/merchant.MerchantService/FindApiKeyUsageByMerchant" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindApiKeyUsageByMerchantSvc<T: MerchantService>(pub Arc<T>);
+                    impl<
+                        T: MerchantService,
+                    > tonic::server::UnaryService<super::FindMerchantTransactionDaily>
+                    for FindApiKeyUsageByMerchantSvc<T> {
+                        type Response = super::ApiResponseMerchantApiKeyUsage;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindMerchantTransactionDaily>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MerchantService>::find_api_key_usage_by_merchant(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindApiKeyUsageByMerchantSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/merchant.MerchantService/FindSettlementByMerchant" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindSettlementByMerchantSvc<T: MerchantService>(pub Arc<T>);
+                    impl<
+                        T: MerchantService,
+                    > tonic::server::UnaryService<super::FindMerchantTransactionDaily>
+                    for FindSettlementByMerchantSvc<T> {
+                        type Response = super::ApiResponseMerchantSettlement;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindMerchantTransactionDaily>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MerchantService>::find_settlement_by_merchant(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindSettlementByMerchantSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/merchant.MerchantService/FindMonthlyPaymentMethodByMerchants" => {
                     #[allow(non_camel_case_types)]
                     struct FindMonthlyPaymentMethodByMerchantsSvc<T: MerchantService>(
@@ -2929,6 +3434,199 @@ pub mod merchant_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/merchant.MerchantService/ListAccessibleMerchants" => {
+                    #[allow(non_camel_case_types)]
+                    struct ListAccessibleMerchantsSvc<T: MerchantService>(pub Arc<T>);
+                    impl<
+                        T: MerchantService,
+                    > tonic::server::UnaryService<super::FindByMerchantUserIdRequest>
+                    for ListAccessibleMerchantsSvc<T> {
+                        type Response = super::ApiResponsesMerchant;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindByMerchantUserIdRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MerchantService>::list_accessible_merchants(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ListAccessibleMerchantsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/merchant.MerchantService/GrantMerchantUser" => {
+                    #[allow(non_camel_case_types)]
+                    struct GrantMerchantUserSvc<T: MerchantService>(pub Arc<T>);
+                    impl<
+                        T: MerchantService,
+                    > tonic::server::UnaryService<super::MerchantUserRequest>
+                    for GrantMerchantUserSvc<T> {
+                        type Response = super::ApiResponseMerchantDelete;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MerchantUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MerchantService>::grant_merchant_user(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GrantMerchantUserSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/merchant.MerchantService/RevokeMerchantUser" => {
+                    #[allow(non_camel_case_types)]
+                    struct RevokeMerchantUserSvc<T: MerchantService>(pub Arc<T>);
+                    impl<
+                        T: MerchantService,
+                    > tonic::server::UnaryService<super::MerchantUserRequest>
+                    for RevokeMerchantUserSvc<T> {
+                        type Response = super::ApiResponseMerchantDelete;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MerchantUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MerchantService>::revoke_merchant_user(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RevokeMerchantUserSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/merchant.MerchantService/SetMerchantWebhook" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetMerchantWebhookSvc<T: MerchantService>(pub Arc<T>);
+                    impl<
+                        T: MerchantService,
+                    > tonic::server::UnaryService<super::SetMerchantWebhookRequest>
+                    for SetMerchantWebhookSvc<T> {
+                        type Response = super::ApiResponseMerchantWebhook;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetMerchantWebhookRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as MerchantService>::set_merchant_webhook(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetMerchantWebhookSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/merchant.MerchantService/FindByActive" => {
                     #[allow(non_camel_case_types)]
                     struct FindByActiveSvc<T: MerchantService>(pub Arc<T>);
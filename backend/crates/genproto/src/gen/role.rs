@@ -7,6 +7,8 @@ pub struct FindAllRoleRequest {
     pub page_size: i32,
     #[prost(string, tag = "3")]
     pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub sort_by: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindByIdRoleRequest {
@@ -54,12 +56,14 @@ pub struct RoleResponseDeleteAt {
     #[prost(message, optional, tag = "5")]
     pub deleted_at: ::core::option::Option<::prost::alloc::string::String>,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseRoleAll {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<super::api::BulkResult>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ApiResponseRoleDelete {
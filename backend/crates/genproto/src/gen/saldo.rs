@@ -7,6 +7,8 @@ pub struct FindAllSaldoRequest {
     pub page_size: i32,
     #[prost(string, tag = "3")]
     pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub sort_by: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindMonthlySaldoTotalBalance {
@@ -29,8 +31,8 @@ pub struct FindByIdSaldoRequest {
 pub struct CreateSaldoRequest {
     #[prost(string, tag = "1")]
     pub card_number: ::prost::alloc::string::String,
-    #[prost(int32, tag = "2")]
-    pub total_balance: i32,
+    #[prost(int32, optional, tag = "2")]
+    pub total_balance: ::core::option::Option<i32>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct UpdateSaldoRequest {
@@ -141,12 +143,14 @@ pub struct ApiResponseSaldoDelete {
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseSaldoAll {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<super::api::BulkResult>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseMonthTotalSaldo {
@@ -206,6 +210,85 @@ pub struct ApiResponsePaginationSaldoDeleteAt {
     #[prost(message, optional, tag = "4")]
     pub pagination: ::core::option::Option<super::api::PaginationMeta>,
 }
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindSaldoBalancesRequest {
+    #[prost(string, repeated, tag = "1")]
+    pub card_numbers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApiResponseSaldoBalances {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(map = "string, int64", tag = "3")]
+    pub balances: ::std::collections::HashMap<::prost::alloc::string::String, i64>,
+    #[prost(string, repeated, tag = "4")]
+    pub missing_card_numbers: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindCardStatementRequest {
+    #[prost(string, tag = "1")]
+    pub card_number: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub year: i32,
+    #[prost(int32, tag = "3")]
+    pub month: i32,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CardStatementTotals {
+    #[prost(int64, tag = "1")]
+    pub topup: i64,
+    #[prost(int64, tag = "2")]
+    pub transfer: i64,
+    #[prost(int64, tag = "3")]
+    pub withdraw: i64,
+    #[prost(int64, tag = "4")]
+    pub transaction: i64,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CardStatementEntry {
+    #[prost(int32, tag = "1")]
+    pub id: i32,
+    #[prost(string, tag = "2")]
+    pub category: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub reference_type: ::prost::alloc::string::String,
+    #[prost(int32, tag = "4")]
+    pub reference_id: i32,
+    #[prost(int64, tag = "5")]
+    pub amount: i64,
+    #[prost(int64, tag = "6")]
+    pub balance_after: i64,
+    #[prost(string, tag = "7")]
+    pub created_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct CardStatementResponse {
+    #[prost(string, tag = "1")]
+    pub card_number: ::prost::alloc::string::String,
+    #[prost(int32, tag = "2")]
+    pub year: i32,
+    #[prost(int32, tag = "3")]
+    pub month: i32,
+    #[prost(int64, tag = "4")]
+    pub opening_balance: i64,
+    #[prost(int64, tag = "5")]
+    pub closing_balance: i64,
+    #[prost(message, optional, tag = "6")]
+    pub totals: ::core::option::Option<CardStatementTotals>,
+    #[prost(message, repeated, tag = "7")]
+    pub entries: ::prost::alloc::vec::Vec<CardStatementEntry>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApiResponseCardStatement {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<CardStatementResponse>,
+}
 /// Generated client implementations.
 pub mod saldo_service_client {
     #![allow(
@@ -473,6 +556,56 @@ pub mod saldo_service_client {
                 .insert(GrpcMethod::new("saldo.SaldoService", "FindByCardNumber"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn find_balances_by_card_numbers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindSaldoBalancesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseSaldoBalances>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/saldo.SaldoService/FindBalancesByCardNumbers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("saldo.SaldoService", "FindBalancesByCardNumbers"),
+                );
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn find_card_statement(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindCardStatementRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardStatement>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/saldo.SaldoService/FindCardStatement",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("saldo.SaldoService", "FindCardStatement"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn find_by_active(
             &mut self,
             request: impl tonic::IntoRequest<super::FindAllSaldoRequest>,
@@ -755,6 +888,20 @@ pub mod saldo_service_server {
             tonic::Response<super::ApiResponseSaldo>,
             tonic::Status,
         >;
+        async fn find_balances_by_card_numbers(
+            &self,
+            request: tonic::Request<super::FindSaldoBalancesRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseSaldoBalances>,
+            tonic::Status,
+        >;
+        async fn find_card_statement(
+            &self,
+            request: tonic::Request<super::FindCardStatementRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseCardStatement>,
+            tonic::Status,
+        >;
         async fn find_by_active(
             &self,
             request: tonic::Request<super::FindAllSaldoRequest>,
@@ -1230,6 +1377,101 @@ pub mod saldo_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/saldo.SaldoService/FindBalancesByCardNumbers" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindBalancesByCardNumbersSvc<T: SaldoService>(pub Arc<T>);
+                    impl<
+                        T: SaldoService,
+                    > tonic::server::UnaryService<super::FindSaldoBalancesRequest>
+                    for FindBalancesByCardNumbersSvc<T> {
+                        type Response = super::ApiResponseSaldoBalances;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindSaldoBalancesRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SaldoService>::find_balances_by_card_numbers(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindBalancesByCardNumbersSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/saldo.SaldoService/FindCardStatement" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindCardStatementSvc<T: SaldoService>(pub Arc<T>);
+                    impl<
+                        T: SaldoService,
+                    > tonic::server::UnaryService<super::FindCardStatementRequest>
+                    for FindCardStatementSvc<T> {
+                        type Response = super::ApiResponseCardStatement;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindCardStatementRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as SaldoService>::find_card_statement(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindCardStatementSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/saldo.SaldoService/FindByActive" => {
                     #[allow(non_camel_case_types)]
                     struct FindByActiveSvc<T: SaldoService>(pub Arc<T>);
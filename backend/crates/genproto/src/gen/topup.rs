@@ -7,6 +7,8 @@ pub struct FindAllTopupRequest {
     pub page_size: i32,
     #[prost(string, tag = "3")]
     pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub sort_by: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindAllTopupByCardNumberRequest {
@@ -328,12 +330,14 @@ pub struct ApiResponseTopupDelete {
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseTopupAll {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<super::api::BulkResult>,
 }
 /// Generated client implementations.
 pub mod topup_service_client {
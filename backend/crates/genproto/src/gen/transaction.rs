@@ -42,6 +42,39 @@ pub struct FindAllTransactionRequest {
     pub page_size: i32,
     #[prost(string, tag = "3")]
     pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub sort_by: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SearchTransactionsRequest {
+    #[prost(int32, tag = "1")]
+    pub page: i32,
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    #[prost(string, tag = "3")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub payment_method: ::prost::alloc::string::String,
+    #[prost(int32, optional, tag = "5")]
+    pub min_amount: ::core::option::Option<i32>,
+    #[prost(int32, optional, tag = "6")]
+    pub max_amount: ::core::option::Option<i32>,
+    #[prost(int32, optional, tag = "7")]
+    pub merchant_id: ::core::option::Option<i32>,
+    #[prost(string, tag = "8")]
+    pub card_number: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "9")]
+    pub start_date: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "10")]
+    pub end_date: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "11")]
+    pub sort_by: ::prost::alloc::string::String,
+    #[prost(string, tag = "12")]
+    pub sort_dir: ::prost::alloc::string::String,
+    #[prost(string, tag = "13")]
+    pub metadata_key: ::prost::alloc::string::String,
+    #[prost(string, tag = "14")]
+    pub metadata_value: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindAllTransactionCardNumberRequest {
@@ -69,7 +102,7 @@ pub struct FindTransactionByMerchantIdRequest {
     #[prost(int32, tag = "1")]
     pub merchant_id: i32,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct CreateTransactionRequest {
     #[prost(string, tag = "1")]
     pub api_key: ::prost::alloc::string::String,
@@ -83,6 +116,11 @@ pub struct CreateTransactionRequest {
     pub merchant_id: i32,
     #[prost(message, optional, tag = "6")]
     pub transaction_time: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(map = "string, string", tag = "7")]
+    pub metadata: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct UpdateTransactionRequest {
@@ -101,7 +139,7 @@ pub struct UpdateTransactionRequest {
     #[prost(message, optional, tag = "7")]
     pub transaction_time: ::core::option::Option<::prost_types::Timestamp>,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct TransactionResponse {
     #[prost(int32, tag = "1")]
     pub id: i32,
@@ -121,6 +159,17 @@ pub struct TransactionResponse {
     pub created_at: ::prost::alloc::string::String,
     #[prost(string, tag = "9")]
     pub updated_at: ::prost::alloc::string::String,
+    #[prost(int64, optional, tag = "10")]
+    pub gross_amount: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "11")]
+    pub fee_amount: ::core::option::Option<i64>,
+    #[prost(int64, optional, tag = "12")]
+    pub net_amount: ::core::option::Option<i64>,
+    #[prost(map = "string, string", tag = "13")]
+    pub metadata: ::std::collections::HashMap<
+        ::prost::alloc::string::String,
+        ::prost::alloc::string::String,
+    >,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct TransactionResponseDeleteAt {
@@ -293,7 +342,7 @@ pub struct ApiResponseTransactionYearAmount {
     #[prost(message, repeated, tag = "3")]
     pub data: ::prost::alloc::vec::Vec<TransactionYearlyAmountResponse>,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseTransaction {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
@@ -327,12 +376,14 @@ pub struct ApiResponseTransactionDelete {
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseTransactionAll {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<super::api::BulkResult>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponsePaginationTransaction {
@@ -505,6 +556,35 @@ pub mod transaction_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn search_transactions(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePaginationTransaction>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/transaction.TransactionService/SearchTransactions",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "transaction.TransactionService",
+                        "SearchTransactions",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn find_by_id_transaction(
             &mut self,
             request: impl tonic::IntoRequest<super::FindByIdTransactionRequest>,
@@ -1329,6 +1409,13 @@ pub mod transaction_service_server {
             tonic::Response<super::ApiResponsePaginationTransaction>,
             tonic::Status,
         >;
+        async fn search_transactions(
+            &self,
+            request: tonic::Request<super::SearchTransactionsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePaginationTransaction>,
+            tonic::Status,
+        >;
         async fn find_by_id_transaction(
             &self,
             request: tonic::Request<super::FindByIdTransactionRequest>,
@@ -1698,6 +1785,55 @@ pub mod transaction_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/transaction.TransactionService/SearchTransactions" => {
+                    #[allow(non_camel_case_types)]
+                    struct SearchTransactionsSvc<T: TransactionService>(pub Arc<T>);
+                    impl<
+                        T: TransactionService,
+                    > tonic::server::UnaryService<super::SearchTransactionsRequest>
+                    for SearchTransactionsSvc<T> {
+                        type Response = super::ApiResponsePaginationTransaction;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SearchTransactionsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransactionService>::search_transactions(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SearchTransactionsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/transaction.TransactionService/FindByIdTransaction" => {
                     #[allow(non_camel_case_types)]
                     struct FindByIdTransactionSvc<T: TransactionService>(pub Arc<T>);
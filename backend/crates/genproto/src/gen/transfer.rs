@@ -42,6 +42,8 @@ pub struct FindAllTransferRequest {
     pub page_size: i32,
     #[prost(string, tag = "3")]
     pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub sort_by: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindByIdTransferRequest {
@@ -59,6 +61,34 @@ pub struct FindTransferByTransferToRequest {
     pub transfer_to: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindTransfersRequest {
+    #[prost(int32, tag = "1")]
+    pub page: i32,
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    #[prost(string, tag = "3")]
+    pub transfer_from: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub transfer_to: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "6")]
+    pub start_date: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "7")]
+    pub end_date: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindTransferNetPositionRequest {
+    #[prost(string, tag = "1")]
+    pub card_a: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub card_b: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub start_date: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "4")]
+    pub end_date: ::core::option::Option<::prost_types::Timestamp>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct CreateTransferRequest {
     #[prost(string, tag = "1")]
     pub transfer_from: ::prost::alloc::string::String,
@@ -254,18 +284,48 @@ pub struct ApiResponseTransfers {
     pub data: ::prost::alloc::vec::Vec<TransferResponse>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
-pub struct ApiResponseTransferDelete {
+pub struct TransferNetPositionResponse {
+    #[prost(string, tag = "1")]
+    pub card_a: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub card_b: ::prost::alloc::string::String,
+    #[prost(int64, tag = "3")]
+    pub a_to_b_amount: i64,
+    #[prost(int64, tag = "4")]
+    pub a_to_b_count: i64,
+    #[prost(int64, tag = "5")]
+    pub b_to_a_amount: i64,
+    #[prost(int64, tag = "6")]
+    pub b_to_a_count: i64,
+    #[prost(int64, tag = "7")]
+    pub net_amount: i64,
+    #[prost(string, tag = "8")]
+    pub direction: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseTransferNetPosition {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<TransferNetPositionResponse>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseTransferDelete {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseTransferAll {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<super::api::BulkResult>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponsePaginationTransfer {
@@ -892,6 +952,59 @@ pub mod transfer_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn find_transfers(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindTransfersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePaginationTransfer>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/transfer.TransferService/FindTransfers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("transfer.TransferService", "FindTransfers"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn find_transfer_net_position(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindTransferNetPositionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseTransferNetPosition>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/transfer.TransferService/FindTransferNetPosition",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new(
+                        "transfer.TransferService",
+                        "FindTransferNetPosition",
+                    ),
+                );
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn find_by_active_transfer(
             &mut self,
             request: impl tonic::IntoRequest<super::FindAllTransferRequest>,
@@ -968,6 +1081,30 @@ pub mod transfer_service_client {
                 .insert(GrpcMethod::new("transfer.TransferService", "CreateTransfer"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn preview_transfer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateTransferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::api::ApiResponseBalanceImpact>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/transfer.TransferService/PreviewTransfer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("transfer.TransferService", "PreviewTransfer"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn update_transfer(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateTransferRequest>,
@@ -1265,6 +1402,20 @@ pub mod transfer_service_server {
             tonic::Response<super::ApiResponseTransfers>,
             tonic::Status,
         >;
+        async fn find_transfers(
+            &self,
+            request: tonic::Request<super::FindTransfersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePaginationTransfer>,
+            tonic::Status,
+        >;
+        async fn find_transfer_net_position(
+            &self,
+            request: tonic::Request<super::FindTransferNetPositionRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseTransferNetPosition>,
+            tonic::Status,
+        >;
         async fn find_by_active_transfer(
             &self,
             request: tonic::Request<super::FindAllTransferRequest>,
@@ -1286,6 +1437,13 @@ pub mod transfer_service_server {
             tonic::Response<super::ApiResponseTransfer>,
             tonic::Status,
         >;
+        async fn preview_transfer(
+            &self,
+            request: tonic::Request<super::CreateTransferRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::api::ApiResponseBalanceImpact>,
+            tonic::Status,
+        >;
         async fn update_transfer(
             &self,
             request: tonic::Request<super::UpdateTransferRequest>,
@@ -2362,6 +2520,103 @@ pub mod transfer_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/transfer.TransferService/FindTransfers" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindTransfersSvc<T: TransferService>(pub Arc<T>);
+                    impl<
+                        T: TransferService,
+                    > tonic::server::UnaryService<super::FindTransfersRequest>
+                    for FindTransfersSvc<T> {
+                        type Response = super::ApiResponsePaginationTransfer;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindTransfersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransferService>::find_transfers(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindTransfersSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/transfer.TransferService/FindTransferNetPosition" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindTransferNetPositionSvc<T: TransferService>(pub Arc<T>);
+                    impl<
+                        T: TransferService,
+                    > tonic::server::UnaryService<super::FindTransferNetPositionRequest>
+                    for FindTransferNetPositionSvc<T> {
+                        type Response = super::ApiResponseTransferNetPosition;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<
+                                super::FindTransferNetPositionRequest,
+                            >,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransferService>::find_transfer_net_position(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindTransferNetPositionSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/transfer.TransferService/FindByActiveTransfer" => {
                     #[allow(non_camel_case_types)]
                     struct FindByActiveTransferSvc<T: TransferService>(pub Arc<T>);
@@ -2506,6 +2761,52 @@ pub mod transfer_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/transfer.TransferService/PreviewTransfer" => {
+                    #[allow(non_camel_case_types)]
+                    struct PreviewTransferSvc<T: TransferService>(pub Arc<T>);
+                    impl<
+                        T: TransferService,
+                    > tonic::server::UnaryService<super::CreateTransferRequest>
+                    for PreviewTransferSvc<T> {
+                        type Response = super::super::api::ApiResponseBalanceImpact;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateTransferRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as TransferService>::preview_transfer(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PreviewTransferSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/transfer.TransferService/UpdateTransfer" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateTransferSvc<T: TransferService>(pub Arc<T>);
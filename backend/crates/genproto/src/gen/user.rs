@@ -7,6 +7,8 @@ pub struct FindAllUserRequest {
     pub page_size: i32,
     #[prost(string, tag = "3")]
     pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub sort_by: ::prost::alloc::string::String,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindByIdUserRequest {
@@ -25,6 +27,8 @@ pub struct CreateUserRequest {
     pub password: ::prost::alloc::string::String,
     #[prost(string, tag = "5")]
     pub confirm_password: ::prost::alloc::string::String,
+    #[prost(bool, tag = "6")]
+    pub must_change_password: bool,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct UpdateUserRequest {
@@ -107,12 +111,14 @@ pub struct ApiResponseUserDelete {
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseUserAll {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<super::api::BulkResult>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponsePaginationUserDeleteAt {
@@ -136,6 +142,85 @@ pub struct ApiResponsePaginationUser {
     #[prost(message, optional, tag = "4")]
     pub pagination: ::core::option::Option<super::api::PaginationMeta>,
 }
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UserExportCard {
+    #[prost(string, tag = "1")]
+    pub card_number: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub card_type: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub card_provider: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub expire_date: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub created_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UserExportTransactionSummary {
+    #[prost(string, tag = "1")]
+    pub card_number: ::prost::alloc::string::String,
+    #[prost(int64, tag = "2")]
+    pub total_transactions: i64,
+    #[prost(int64, tag = "3")]
+    pub total_amount: i64,
+    #[prost(string, tag = "4")]
+    pub first_transaction_at: ::prost::alloc::string::String,
+    #[prost(string, tag = "5")]
+    pub last_transaction_at: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct UserDataExport {
+    #[prost(string, tag = "1")]
+    pub exported_at: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "2")]
+    pub profile: ::core::option::Option<UserResponse>,
+    #[prost(string, repeated, tag = "3")]
+    pub roles: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "4")]
+    pub cards: ::prost::alloc::vec::Vec<UserExportCard>,
+    #[prost(message, repeated, tag = "5")]
+    pub transaction_summaries: ::prost::alloc::vec::Vec<UserExportTransactionSummary>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MergeUsersRequest {
+    #[prost(int32, tag = "1")]
+    pub primary_id: i32,
+    #[prost(int32, tag = "2")]
+    pub duplicate_id: i32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct UserMergeResult {
+    #[prost(message, optional, tag = "1")]
+    pub primary: ::core::option::Option<UserResponse>,
+    #[prost(message, optional, tag = "2")]
+    pub duplicate: ::core::option::Option<UserResponseDeleteAt>,
+    #[prost(int64, tag = "3")]
+    pub cards_moved: i64,
+    #[prost(int64, tag = "4")]
+    pub merchants_moved: i64,
+    #[prost(int64, tag = "5")]
+    pub roles_moved: i64,
+    #[prost(int64, tag = "6")]
+    pub merchant_links_moved: i64,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApiResponseUserMerge {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<UserMergeResult>,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ApiResponseUserDataExport {
+    #[prost(string, tag = "1")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<UserDataExport>,
+}
 /// Generated client implementations.
 pub mod user_service_client {
     #![allow(
@@ -319,6 +404,30 @@ pub mod user_service_client {
                 .insert(GrpcMethod::new("user.UserService", "FindByTrashed"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn export_user_data(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindByIdUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseUserDataExport>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/user.UserService/ExportUserData",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("user.UserService", "ExportUserData"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn create(
             &mut self,
             request: impl tonic::IntoRequest<super::CreateUserRequest>,
@@ -409,6 +518,54 @@ pub mod user_service_client {
                 .insert(GrpcMethod::new("user.UserService", "RestoreUser"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn anonymize_user(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindByIdUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseUserDeleteAt>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/user.UserService/AnonymizeUser",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("user.UserService", "AnonymizeUser"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn merge_users(
+            &mut self,
+            request: impl tonic::IntoRequest<super::MergeUsersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseUserMerge>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/user.UserService/MergeUsers",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("user.UserService", "MergeUsers"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn delete_user_permanent(
             &mut self,
             request: impl tonic::IntoRequest<super::FindByIdUserRequest>,
@@ -521,6 +678,13 @@ pub mod user_service_server {
             tonic::Response<super::ApiResponsePaginationUserDeleteAt>,
             tonic::Status,
         >;
+        async fn export_user_data(
+            &self,
+            request: tonic::Request<super::FindByIdUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseUserDataExport>,
+            tonic::Status,
+        >;
         async fn create(
             &self,
             request: tonic::Request<super::CreateUserRequest>,
@@ -543,6 +707,20 @@ pub mod user_service_server {
             tonic::Response<super::ApiResponseUserDeleteAt>,
             tonic::Status,
         >;
+        async fn anonymize_user(
+            &self,
+            request: tonic::Request<super::FindByIdUserRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseUserDeleteAt>,
+            tonic::Status,
+        >;
+        async fn merge_users(
+            &self,
+            request: tonic::Request<super::MergeUsersRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseUserMerge>,
+            tonic::Status,
+        >;
         async fn delete_user_permanent(
             &self,
             request: tonic::Request<super::FindByIdUserRequest>,
@@ -821,6 +999,51 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/user.UserService/ExportUserData" => {
+                    #[allow(non_camel_case_types)]
+                    struct ExportUserDataSvc<T: UserService>(pub Arc<T>);
+                    impl<
+                        T: UserService,
+                    > tonic::server::UnaryService<super::FindByIdUserRequest>
+                    for ExportUserDataSvc<T> {
+                        type Response = super::ApiResponseUserDataExport;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindByIdUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserService>::export_user_data(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ExportUserDataSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/user.UserService/Create" => {
                     #[allow(non_camel_case_types)]
                     struct CreateSvc<T: UserService>(pub Arc<T>);
@@ -1001,6 +1224,96 @@ pub mod user_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/user.UserService/AnonymizeUser" => {
+                    #[allow(non_camel_case_types)]
+                    struct AnonymizeUserSvc<T: UserService>(pub Arc<T>);
+                    impl<
+                        T: UserService,
+                    > tonic::server::UnaryService<super::FindByIdUserRequest>
+                    for AnonymizeUserSvc<T> {
+                        type Response = super::ApiResponseUserDeleteAt;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindByIdUserRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserService>::anonymize_user(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AnonymizeUserSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/user.UserService/MergeUsers" => {
+                    #[allow(non_camel_case_types)]
+                    struct MergeUsersSvc<T: UserService>(pub Arc<T>);
+                    impl<
+                        T: UserService,
+                    > tonic::server::UnaryService<super::MergeUsersRequest>
+                    for MergeUsersSvc<T> {
+                        type Response = super::ApiResponseUserMerge;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::MergeUsersRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as UserService>::merge_users(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = MergeUsersSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/user.UserService/DeleteUserPermanent" => {
                     #[allow(non_camel_case_types)]
                     struct DeleteUserPermanentSvc<T: UserService>(pub Arc<T>);
@@ -42,6 +42,8 @@ pub struct FindAllWithdrawRequest {
     pub page_size: i32,
     #[prost(string, tag = "3")]
     pub search: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub sort_by: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindAllWithdrawByCardNumberRequest {
@@ -54,6 +56,25 @@ pub struct FindAllWithdrawByCardNumberRequest {
     #[prost(string, tag = "4")]
     pub search: ::prost::alloc::string::String,
 }
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindWithdrawsRequest {
+    #[prost(int32, tag = "1")]
+    pub page: i32,
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
+    #[prost(string, tag = "3")]
+    pub status: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub card_number: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "5")]
+    pub from: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(message, optional, tag = "6")]
+    pub to: ::core::option::Option<::prost_types::Timestamp>,
+    #[prost(string, tag = "7")]
+    pub sort_by: ::prost::alloc::string::String,
+    #[prost(string, tag = "8")]
+    pub sort_dir: ::prost::alloc::string::String,
+}
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct FindByIdWithdrawRequest {
     #[prost(int32, tag = "1")]
@@ -257,12 +278,14 @@ pub struct ApiResponseWithdrawDelete {
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponseWithdrawAll {
     #[prost(string, tag = "1")]
     pub status: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub data: ::core::option::Option<super::api::BulkResult>,
 }
 #[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ApiResponsePaginationWithdraw {
@@ -430,6 +453,30 @@ pub mod withdraw_service_client {
                 );
             self.inner.unary(req, path, codec).await
         }
+        pub async fn find_withdraws(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindWithdrawsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePaginationWithdraw>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/withdraw.WithdrawService/FindWithdraws",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("withdraw.WithdrawService", "FindWithdraws"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn find_by_id_withdraw(
             &mut self,
             request: impl tonic::IntoRequest<super::FindByIdWithdrawRequest>,
@@ -892,6 +939,30 @@ pub mod withdraw_service_client {
                 .insert(GrpcMethod::new("withdraw.WithdrawService", "CreateWithdraw"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn preview_withdraw(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreateWithdrawRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::api::ApiResponseBalanceImpact>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/withdraw.WithdrawService/PreviewWithdraw",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("withdraw.WithdrawService", "PreviewWithdraw"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn update_withdraw(
             &mut self,
             request: impl tonic::IntoRequest<super::UpdateWithdrawRequest>,
@@ -916,6 +987,30 @@ pub mod withdraw_service_client {
                 .insert(GrpcMethod::new("withdraw.WithdrawService", "UpdateWithdraw"));
             self.inner.unary(req, path, codec).await
         }
+        pub async fn cancel_withdraw(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindByIdWithdrawRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseWithdraw>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/withdraw.WithdrawService/CancelWithdraw",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("withdraw.WithdrawService", "CancelWithdraw"));
+            self.inner.unary(req, path, codec).await
+        }
         pub async fn trashed_withdraw(
             &mut self,
             request: impl tonic::IntoRequest<super::FindByIdWithdrawRequest>,
@@ -1077,6 +1172,13 @@ pub mod withdraw_service_server {
             tonic::Response<super::ApiResponsePaginationWithdraw>,
             tonic::Status,
         >;
+        async fn find_withdraws(
+            &self,
+            request: tonic::Request<super::FindWithdrawsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponsePaginationWithdraw>,
+            tonic::Status,
+        >;
         async fn find_by_id_withdraw(
             &self,
             request: tonic::Request<super::FindByIdWithdrawRequest>,
@@ -1196,6 +1298,13 @@ pub mod withdraw_service_server {
             tonic::Response<super::ApiResponseWithdraw>,
             tonic::Status,
         >;
+        async fn preview_withdraw(
+            &self,
+            request: tonic::Request<super::CreateWithdrawRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::super::api::ApiResponseBalanceImpact>,
+            tonic::Status,
+        >;
         async fn update_withdraw(
             &self,
             request: tonic::Request<super::UpdateWithdrawRequest>,
@@ -1203,6 +1312,13 @@ pub mod withdraw_service_server {
             tonic::Response<super::ApiResponseWithdraw>,
             tonic::Status,
         >;
+        async fn cancel_withdraw(
+            &self,
+            request: tonic::Request<super::FindByIdWithdrawRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ApiResponseWithdraw>,
+            tonic::Status,
+        >;
         async fn trashed_withdraw(
             &self,
             request: tonic::Request<super::FindByIdWithdrawRequest>,
@@ -1415,6 +1531,52 @@ pub mod withdraw_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/withdraw.WithdrawService/FindWithdraws" => {
+                    #[allow(non_camel_case_types)]
+                    struct FindWithdrawsSvc<T: WithdrawService>(pub Arc<T>);
+                    impl<
+                        T: WithdrawService,
+                    > tonic::server::UnaryService<super::FindWithdrawsRequest>
+                    for FindWithdrawsSvc<T> {
+                        type Response = super::ApiResponsePaginationWithdraw;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindWithdrawsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WithdrawService>::find_withdraws(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = FindWithdrawsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/withdraw.WithdrawService/FindByIdWithdraw" => {
                     #[allow(non_camel_case_types)]
                     struct FindByIdWithdrawSvc<T: WithdrawService>(pub Arc<T>);
@@ -2278,6 +2440,52 @@ pub mod withdraw_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/withdraw.WithdrawService/PreviewWithdraw" => {
+                    #[allow(non_camel_case_types)]
+                    struct PreviewWithdrawSvc<T: WithdrawService>(pub Arc<T>);
+                    impl<
+                        T: WithdrawService,
+                    > tonic::server::UnaryService<super::CreateWithdrawRequest>
+                    for PreviewWithdrawSvc<T> {
+                        type Response = super::super::api::ApiResponseBalanceImpact;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreateWithdrawRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WithdrawService>::preview_withdraw(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PreviewWithdrawSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/withdraw.WithdrawService/UpdateWithdraw" => {
                     #[allow(non_camel_case_types)]
                     struct UpdateWithdrawSvc<T: WithdrawService>(pub Arc<T>);
@@ -2324,6 +2532,52 @@ pub mod withdraw_service_server {
                     };
                     Box::pin(fut)
                 }
+                "/withdraw.WithdrawService/CancelWithdraw" => {
+                    #[allow(non_camel_case_types)]
+                    struct CancelWithdrawSvc<T: WithdrawService>(pub Arc<T>);
+                    impl<
+                        T: WithdrawService,
+                    > tonic::server::UnaryService<super::FindByIdWithdrawRequest>
+                    for CancelWithdrawSvc<T> {
+                        type Response = super::ApiResponseWithdraw;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::FindByIdWithdrawRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as WithdrawService>::cancel_withdraw(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CancelWithdrawSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
                 "/withdraw.WithdrawService/TrashedWithdraw" => {
                     #[allow(non_camel_case_types)]
                     struct TrashedWithdrawSvc<T: WithdrawService>(pub Arc<T>);
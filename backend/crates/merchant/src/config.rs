@@ -8,6 +8,7 @@ pub struct ServerConfig {
     pub database_url: String,
     pub jwt_secret: String,
     pub run_migrations: bool,
+    pub shutdown_timeout: std::time::Duration,
 }
 
 impl ServerConfig {
@@ -22,6 +23,7 @@ impl ServerConfig {
             database_url: config.database_url.clone(),
             jwt_secret: config.jwt_secret.clone(),
             run_migrations: config.run_migrations,
+            shutdown_timeout: std::time::Duration::from_secs(config.merchant.shutdown_timeout_secs),
         })
     }
 }
@@ -45,7 +45,7 @@ use shared::{
         user::repository::query::DynUserQueryRepository,
     },
     cache::CacheStore,
-    config::{ConnectionPool, RedisPool},
+    config::{ConnectionPool, RedisPool, WebhookPolicyConfig},
     context::shared_resources::SharedResources,
     observability::{CacheMetricsCore, TracingMetricsCore},
     repository::{
@@ -88,7 +88,9 @@ use shared::{
         },
         transactions::MerchantTransactionService,
     },
+    webhook::WebhookPolicy,
 };
+use shared::{config::SoftDeleteRetentionConfig, utils::spawn_soft_delete_purge};
 use std::{fmt, sync::Arc, time::Duration};
 use tokio::sync::Semaphore;
 use tracing::{error, info, warn};
@@ -223,6 +225,10 @@ impl DependenciesInject {
             MerchantCommandService::new(
                 merchant_command_repo.clone(),
                 user_query_repo.clone(),
+                WebhookPolicy::new(
+                    WebhookPolicyConfig::from_env()
+                        .context("failed to load webhook policy config")?,
+                ),
                 &shared,
             )
             .context("failed to initialize merchant command service")?,
@@ -326,6 +332,11 @@ impl DependenciesInject {
 
         Self::spawn_monitoring_task(Arc::clone(&shared.cache_store));
         Self::spawn_cleanup_task(Arc::clone(&shared.cache_store));
+        spawn_soft_delete_purge(
+            db.clone(),
+            &SoftDeleteRetentionConfig::from_env(),
+            "merchants",
+        );
 
         Ok(Self {
             merchant_query,
@@ -1,25 +1,30 @@
 use crate::state::AppState;
 use genproto::merchant::{
-    ApiResponseMerchant, ApiResponseMerchantAll, ApiResponseMerchantDelete,
-    ApiResponseMerchantDeleteAt, ApiResponseMerchantMonthlyAmount,
+    ApiResponseMerchant, ApiResponseMerchantAll, ApiResponseMerchantApiKeyUsage,
+    ApiResponseMerchantDelete, ApiResponseMerchantDeleteAt, ApiResponseMerchantMonthlyAmount,
     ApiResponseMerchantMonthlyPaymentMethod, ApiResponseMerchantMonthlyTotalAmount,
+    ApiResponseMerchantSettlement, ApiResponseMerchantTransactionDaily, ApiResponseMerchantWebhook,
     ApiResponseMerchantYearlyAmount, ApiResponseMerchantYearlyPaymentMethod,
     ApiResponseMerchantYearlyTotalAmount, ApiResponsePaginationMerchant,
     ApiResponsePaginationMerchantDeleteAt, ApiResponsePaginationMerchantTransaction,
     ApiResponsesMerchant, CreateMerchantRequest, FindAllMerchantApikey, FindAllMerchantRequest,
     FindAllMerchantTransaction, FindByApiKeyRequest, FindByIdMerchantRequest,
-    FindByMerchantUserIdRequest, FindYearMerchant, FindYearMerchantByApikey, FindYearMerchantById,
+    FindByMerchantUserIdRequest, FindMerchantTransactionDaily, FindYearMerchant,
+    FindYearMerchantByApikey, FindYearMerchantById, MerchantUserRequest, SetMerchantWebhookRequest,
     UpdateMerchantRequest, merchant_service_server::MerchantService,
 };
 use shared::{
     domain::requests::merchant::{
         CreateMerchantRequest as DomainCreateMerchantRequest, FindAllMerchantTransactions,
         FindAllMerchantTransactionsByApiKey, FindAllMerchantTransactionsById, FindAllMerchants,
+        FindMerchantTransactionsDaily, MerchantUserRequest as DomainMerchantUserRequest,
         MonthYearAmountApiKey, MonthYearAmountMerchant, MonthYearPaymentMethodApiKey,
         MonthYearPaymentMethodMerchant, MonthYearTotalAmountApiKey, MonthYearTotalAmountMerchant,
+        SetMerchantWebhookRequest as DomainSetMerchantWebhookRequest,
         UpdateMerchantRequest as DomainUpdateMerchantRequest,
     },
     errors::{AppErrorGrpc, CircuitBreakerError},
+    utils::{mask_api_key, peer_addr, timestamp_to_naive_date},
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
@@ -61,6 +66,7 @@ impl MerchantServiceImpl {
 impl MerchantService for MerchantServiceImpl {
     #[instrument(skip(self, request), fields(
         method = "find_all_merchant",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -76,6 +82,7 @@ impl MerchantService for MerchantServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -131,7 +138,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_id_merchant", merchant_id = request.get_ref().merchant_id))]
+    #[instrument(skip(self, request), fields(method = "find_by_id_merchant", peer = %peer_addr(&request), merchant_id = request.get_ref().merchant_id))]
     async fn find_by_id_merchant(
         &self,
         request: Request<FindByIdMerchantRequest>,
@@ -183,7 +190,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_api_key", api_key = %request.get_ref().api_key))]
+    #[instrument(skip(self, request), fields(method = "find_by_api_key", peer = %peer_addr(&request), api_key = %mask_api_key(&request.get_ref().api_key)))]
     async fn find_by_api_key(
         &self,
         request: Request<FindByApiKeyRequest>,
@@ -215,19 +222,19 @@ impl MerchantService for MerchantServiceImpl {
 
         match result {
             Ok(resp) => {
-                info!(api_key = api_key, "find_by_api_key success");
+                info!(api_key = %mask_api_key(&api_key), "find_by_api_key success");
                 Ok(resp)
             }
             Err(e) => {
                 match &e {
                     CircuitBreakerError::Open => {
                         warn!(
-                            api_key = api_key,
+                            api_key = %mask_api_key(&api_key),
                             "find_by_api_key rejected: circuit breaker open"
                         );
                     }
                     CircuitBreakerError::Inner(inner) => {
-                        error!(api_key = api_key, error = %inner, "find_by_api_key failed");
+                        error!(api_key = %mask_api_key(&api_key), error = %inner, "find_by_api_key failed");
                     }
                 }
                 Err(e.into())
@@ -237,6 +244,7 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_all_transaction_merchant",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -307,7 +315,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_monthly_payment_methods_merchant", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_monthly_payment_methods_merchant", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_monthly_payment_methods_merchant(
         &self,
         request: Request<FindYearMerchant>,
@@ -359,7 +367,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_yearly_payment_method_merchant", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_yearly_payment_method_merchant", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_yearly_payment_method_merchant(
         &self,
         request: Request<FindYearMerchant>,
@@ -411,7 +419,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_monthly_amount_merchant", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_monthly_amount_merchant", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_monthly_amount_merchant(
         &self,
         request: Request<FindYearMerchant>,
@@ -463,7 +471,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_yearly_amount_merchant", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_yearly_amount_merchant", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_yearly_amount_merchant(
         &self,
         request: Request<FindYearMerchant>,
@@ -515,7 +523,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_monthly_total_amount_merchant", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_monthly_total_amount_merchant", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_monthly_total_amount_merchant(
         &self,
         request: Request<FindYearMerchant>,
@@ -567,7 +575,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_yearly_total_amount_merchant", year = request.get_ref().year))]
+    #[instrument(skip(self, request), fields(method = "find_yearly_total_amount_merchant", peer = %peer_addr(&request), year = request.get_ref().year))]
     async fn find_yearly_total_amount_merchant(
         &self,
         request: Request<FindYearMerchant>,
@@ -621,6 +629,7 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_all_transaction_by_merchant",
+        peer = %peer_addr(&request),
         merchant_id = request.get_ref().merchant_id,
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
@@ -687,8 +696,219 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
+    #[instrument(skip(self, request), fields(
+        method = "find_daily_transaction_by_merchant",
+        peer = %peer_addr(&request),
+        merchant_id = request.get_ref().merchant_id
+    ))]
+    async fn find_daily_transaction_by_merchant(
+        &self,
+        request: Request<FindMerchantTransactionDaily>,
+    ) -> Result<Response<ApiResponseMerchantTransactionDaily>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let merchant_id = req.merchant_id;
+
+        let start_date = timestamp_to_naive_date(req.start_date)
+            .ok_or_else(|| Status::invalid_argument("start_date invalid"))?;
+        let end_date = timestamp_to_naive_date(req.end_date)
+            .ok_or_else(|| Status::invalid_argument("end_date invalid"))?;
+
+        let domain_req = FindMerchantTransactionsDaily {
+            merchant_id,
+            start_date,
+            end_date,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .merchant_transaction
+                    .find_daily_by_id(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseMerchantTransactionDaily {
+                    data: api_response.data.into_iter().map(Into::into).collect(),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    merchant_id = merchant_id,
+                    "find_daily_transaction_by_merchant success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            merchant_id = merchant_id,
+                            "find_daily_transaction_by_merchant rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(merchant_id = merchant_id, error = %inner, "find_daily_transaction_by_merchant failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(
+        method = "find_api_key_usage_by_merchant",
+        peer = %peer_addr(&request),
+        merchant_id = request.get_ref().merchant_id
+    ))]
+    async fn find_api_key_usage_by_merchant(
+        &self,
+        request: Request<FindMerchantTransactionDaily>,
+    ) -> Result<Response<ApiResponseMerchantApiKeyUsage>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let merchant_id = req.merchant_id;
+
+        let start_date = timestamp_to_naive_date(req.start_date)
+            .ok_or_else(|| Status::invalid_argument("start_date invalid"))?;
+        let end_date = timestamp_to_naive_date(req.end_date)
+            .ok_or_else(|| Status::invalid_argument("end_date invalid"))?;
+
+        let domain_req = FindMerchantTransactionsDaily {
+            merchant_id,
+            start_date,
+            end_date,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .merchant_transaction
+                    .find_api_key_usage_by_id(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseMerchantApiKeyUsage {
+                    data: api_response.data.into_iter().map(Into::into).collect(),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    merchant_id = merchant_id,
+                    "find_api_key_usage_by_merchant success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            merchant_id = merchant_id,
+                            "find_api_key_usage_by_merchant rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(merchant_id = merchant_id, error = %inner, "find_api_key_usage_by_merchant failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(
+        method = "find_settlement_by_merchant",
+        peer = %peer_addr(&request),
+        merchant_id = request.get_ref().merchant_id
+    ))]
+    async fn find_settlement_by_merchant(
+        &self,
+        request: Request<FindMerchantTransactionDaily>,
+    ) -> Result<Response<ApiResponseMerchantSettlement>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let merchant_id = req.merchant_id;
+
+        let start_date = timestamp_to_naive_date(req.start_date)
+            .ok_or_else(|| Status::invalid_argument("start_date invalid"))?;
+        let end_date = timestamp_to_naive_date(req.end_date)
+            .ok_or_else(|| Status::invalid_argument("end_date invalid"))?;
+
+        let domain_req = FindMerchantTransactionsDaily {
+            merchant_id,
+            start_date,
+            end_date,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .merchant_transaction
+                    .settlement(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseMerchantSettlement {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    merchant_id = merchant_id,
+                    "find_settlement_by_merchant success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            merchant_id = merchant_id,
+                            "find_settlement_by_merchant rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(merchant_id = merchant_id, error = %inner, "find_settlement_by_merchant failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
     #[instrument(skip(self, request), fields(
         method = "find_monthly_payment_method_by_merchants",
+        peer = %peer_addr(&request),
         merchant_id = request.get_ref().merchant_id,
         year = request.get_ref().year
     ))]
@@ -753,6 +973,7 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_payment_method_by_merchants",
+        peer = %peer_addr(&request),
         merchant_id = request.get_ref().merchant_id,
         year = request.get_ref().year
     ))]
@@ -817,6 +1038,7 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_amount_by_merchants",
+        peer = %peer_addr(&request),
         merchant_id = request.get_ref().merchant_id,
         year = request.get_ref().year
     ))]
@@ -881,6 +1103,7 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_amount_by_merchants",
+        peer = %peer_addr(&request),
         merchant_id = request.get_ref().merchant_id,
         year = request.get_ref().year
     ))]
@@ -945,6 +1168,7 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_total_amount_by_merchants",
+        peer = %peer_addr(&request),
         merchant_id = request.get_ref().merchant_id,
         year = request.get_ref().year
     ))]
@@ -1009,6 +1233,7 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_total_amount_by_merchants",
+        peer = %peer_addr(&request),
         merchant_id = request.get_ref().merchant_id,
         year = request.get_ref().year
     ))]
@@ -1073,7 +1298,8 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_all_transaction_by_apikey",
-        api_key = %request.get_ref().api_key,
+        peer = %peer_addr(&request),
+        api_key = %mask_api_key(&request.get_ref().api_key),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1116,19 +1342,19 @@ impl MerchantService for MerchantServiceImpl {
 
         match result {
             Ok(resp) => {
-                info!(api_key = api_key, "find_all_transaction_by_apikey success");
+                info!(api_key = %mask_api_key(&api_key), "find_all_transaction_by_apikey success");
                 Ok(resp)
             }
             Err(e) => {
                 match &e {
                     CircuitBreakerError::Open => {
                         warn!(
-                            api_key = api_key,
+                            api_key = %mask_api_key(&api_key),
                             "find_all_transaction_by_apikey rejected: circuit breaker open"
                         );
                     }
                     CircuitBreakerError::Inner(inner) => {
-                        error!(api_key = api_key, error = %inner, "find_all_transaction_by_apikey failed");
+                        error!(api_key = %mask_api_key(&api_key), error = %inner, "find_all_transaction_by_apikey failed");
                     }
                 }
                 Err(e.into())
@@ -1138,7 +1364,8 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_payment_method_by_apikey",
-        api_key = %request.get_ref().api_key,
+        peer = %peer_addr(&request),
+        api_key = %mask_api_key(&request.get_ref().api_key),
         year = request.get_ref().year
     ))]
     async fn find_monthly_payment_method_by_apikey(
@@ -1177,7 +1404,7 @@ impl MerchantService for MerchantServiceImpl {
         match result {
             Ok(resp) => {
                 info!(
-                    api_key = api_key,
+                    api_key = %mask_api_key(&api_key),
                     "find_monthly_payment_method_by_apikey success"
                 );
                 Ok(resp)
@@ -1186,12 +1413,12 @@ impl MerchantService for MerchantServiceImpl {
                 match &e {
                     CircuitBreakerError::Open => {
                         warn!(
-                            api_key = api_key,
+                            api_key = %mask_api_key(&api_key),
                             "find_monthly_payment_method_by_apikey rejected: circuit breaker open"
                         );
                     }
                     CircuitBreakerError::Inner(inner) => {
-                        error!(api_key = api_key, error = %inner, "find_monthly_payment_method_by_apikey failed");
+                        error!(api_key = %mask_api_key(&api_key), error = %inner, "find_monthly_payment_method_by_apikey failed");
                     }
                 }
                 Err(e.into())
@@ -1201,7 +1428,8 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_payment_method_by_apikey",
-        api_key = %request.get_ref().api_key,
+        peer = %peer_addr(&request),
+        api_key = %mask_api_key(&request.get_ref().api_key),
         year = request.get_ref().year
     ))]
     async fn find_yearly_payment_method_by_apikey(
@@ -1240,7 +1468,7 @@ impl MerchantService for MerchantServiceImpl {
         match result {
             Ok(resp) => {
                 info!(
-                    api_key = api_key,
+                    api_key = %mask_api_key(&api_key),
                     "find_yearly_payment_method_by_apikey success"
                 );
                 Ok(resp)
@@ -1249,12 +1477,12 @@ impl MerchantService for MerchantServiceImpl {
                 match &e {
                     CircuitBreakerError::Open => {
                         warn!(
-                            api_key = api_key,
+                            api_key = %mask_api_key(&api_key),
                             "find_yearly_payment_method_by_apikey rejected: circuit breaker open"
                         );
                     }
                     CircuitBreakerError::Inner(inner) => {
-                        error!(api_key = api_key, error = %inner, "find_yearly_payment_method_by_apikey failed");
+                        error!(api_key = %mask_api_key(&api_key), error = %inner, "find_yearly_payment_method_by_apikey failed");
                     }
                 }
                 Err(e.into())
@@ -1264,7 +1492,8 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_amount_by_apikey",
-        api_key = %request.get_ref().api_key,
+        peer = %peer_addr(&request),
+        api_key = %mask_api_key(&request.get_ref().api_key),
         year = request.get_ref().year
     ))]
     async fn find_monthly_amount_by_apikey(
@@ -1302,19 +1531,19 @@ impl MerchantService for MerchantServiceImpl {
 
         match result {
             Ok(resp) => {
-                info!(api_key = api_key, "find_monthly_amount_by_apikey success");
+                info!(api_key = %mask_api_key(&api_key), "find_monthly_amount_by_apikey success");
                 Ok(resp)
             }
             Err(e) => {
                 match &e {
                     CircuitBreakerError::Open => {
                         warn!(
-                            api_key = api_key,
+                            api_key = %mask_api_key(&api_key),
                             "find_monthly_amount_by_apikey rejected: circuit breaker open"
                         );
                     }
                     CircuitBreakerError::Inner(inner) => {
-                        error!(api_key = api_key, error = %inner, "find_monthly_amount_by_apikey failed");
+                        error!(api_key = %mask_api_key(&api_key), error = %inner, "find_monthly_amount_by_apikey failed");
                     }
                 }
                 Err(e.into())
@@ -1324,7 +1553,8 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_amount_by_apikey",
-        api_key = %request.get_ref().api_key,
+        peer = %peer_addr(&request),
+        api_key = %mask_api_key(&request.get_ref().api_key),
         year = request.get_ref().year
     ))]
     async fn find_yearly_amount_by_apikey(
@@ -1362,19 +1592,19 @@ impl MerchantService for MerchantServiceImpl {
 
         match result {
             Ok(resp) => {
-                info!(api_key = api_key, "find_yearly_amount_by_apikey success");
+                info!(api_key = %mask_api_key(&api_key), "find_yearly_amount_by_apikey success");
                 Ok(resp)
             }
             Err(e) => {
                 match &e {
                     CircuitBreakerError::Open => {
                         warn!(
-                            api_key = api_key,
+                            api_key = %mask_api_key(&api_key),
                             "find_yearly_amount_by_apikey rejected: circuit breaker open"
                         );
                     }
                     CircuitBreakerError::Inner(inner) => {
-                        error!(api_key = api_key, error = %inner, "find_yearly_amount_by_apikey failed");
+                        error!(api_key = %mask_api_key(&api_key), error = %inner, "find_yearly_amount_by_apikey failed");
                     }
                 }
                 Err(e.into())
@@ -1384,7 +1614,8 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_total_amount_by_apikey",
-        api_key = %request.get_ref().api_key,
+        peer = %peer_addr(&request),
+        api_key = %mask_api_key(&request.get_ref().api_key),
         year = request.get_ref().year
     ))]
     async fn find_monthly_total_amount_by_apikey(
@@ -1423,7 +1654,7 @@ impl MerchantService for MerchantServiceImpl {
         match result {
             Ok(resp) => {
                 info!(
-                    api_key = api_key,
+                    api_key = %mask_api_key(&api_key),
                     "find_monthly_total_amount_by_apikey success"
                 );
                 Ok(resp)
@@ -1432,12 +1663,12 @@ impl MerchantService for MerchantServiceImpl {
                 match &e {
                     CircuitBreakerError::Open => {
                         warn!(
-                            api_key = api_key,
+                            api_key = %mask_api_key(&api_key),
                             "find_monthly_total_amount_by_apikey rejected: circuit breaker open"
                         );
                     }
                     CircuitBreakerError::Inner(inner) => {
-                        error!(api_key = api_key, error = %inner, "find_monthly_total_amount_by_apikey failed");
+                        error!(api_key = %mask_api_key(&api_key), error = %inner, "find_monthly_total_amount_by_apikey failed");
                     }
                 }
                 Err(e.into())
@@ -1447,7 +1678,8 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_total_amount_by_apikey",
-        api_key = %request.get_ref().api_key,
+        peer = %peer_addr(&request),
+        api_key = %mask_api_key(&request.get_ref().api_key),
         year = request.get_ref().year
     ))]
     async fn find_yearly_total_amount_by_apikey(
@@ -1486,7 +1718,7 @@ impl MerchantService for MerchantServiceImpl {
         match result {
             Ok(resp) => {
                 info!(
-                    api_key = api_key,
+                    api_key = %mask_api_key(&api_key),
                     "find_yearly_total_amount_by_apikey success"
                 );
                 Ok(resp)
@@ -1495,12 +1727,12 @@ impl MerchantService for MerchantServiceImpl {
                 match &e {
                     CircuitBreakerError::Open => {
                         warn!(
-                            api_key = api_key,
+                            api_key = %mask_api_key(&api_key),
                             "find_yearly_total_amount_by_apikey rejected: circuit breaker open"
                         );
                     }
                     CircuitBreakerError::Inner(inner) => {
-                        error!(api_key = api_key, error = %inner, "find_yearly_total_amount_by_apikey failed");
+                        error!(api_key = %mask_api_key(&api_key), error = %inner, "find_yearly_total_amount_by_apikey failed");
                     }
                 }
                 Err(e.into())
@@ -1508,7 +1740,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_merchant_user_id", user_id = request.get_ref().user_id))]
+    #[instrument(skip(self, request), fields(method = "find_by_merchant_user_id", peer = %peer_addr(&request), user_id = request.get_ref().user_id))]
     async fn find_by_merchant_user_id(
         &self,
         request: Request<FindByMerchantUserIdRequest>,
@@ -1560,8 +1792,61 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
+    #[instrument(skip(self, request), fields(method = "list_accessible_merchants", peer = %peer_addr(&request), user_id = request.get_ref().user_id))]
+    async fn list_accessible_merchants(
+        &self,
+        request: Request<FindByMerchantUserIdRequest>,
+    ) -> Result<Response<ApiResponsesMerchant>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let user_id = req.user_id;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .merchant_query
+                    .list_accessible_merchants(user_id)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponsesMerchant {
+                    data: api_response.data.into_iter().map(Into::into).collect(),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(user_id = user_id, "list_accessible_merchants success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            user_id = user_id,
+                            "list_accessible_merchants rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(user_id = user_id, error = %inner, "list_accessible_merchants failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
     #[instrument(skip(self, request), fields(
         method = "find_by_active",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1577,6 +1862,7 @@ impl MerchantService for MerchantServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -1634,6 +1920,7 @@ impl MerchantService for MerchantServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_trashed",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1649,6 +1936,7 @@ impl MerchantService for MerchantServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: (!req.sort_by.is_empty()).then(|| req.sort_by.clone()),
         };
 
         let result = self
@@ -1704,7 +1992,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "create_merchant", user_id = request.get_ref().user_id))]
+    #[instrument(skip(self, request), fields(method = "create_merchant", peer = %peer_addr(&request), user_id = request.get_ref().user_id))]
     async fn create_merchant(
         &self,
         request: Request<CreateMerchantRequest>,
@@ -1716,6 +2004,10 @@ impl MerchantService for MerchantServiceImpl {
         let domain_req = DomainCreateMerchantRequest {
             user_id: req.user_id,
             name: req.name,
+            business_id: req
+                .business_id
+                .map(|id| id.trim().to_string())
+                .filter(|id| !id.is_empty()),
         };
 
         let result = self
@@ -1760,7 +2052,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "update_merchant", merchant_id = request.get_ref().merchant_id))]
+    #[instrument(skip(self, request), fields(method = "update_merchant", peer = %peer_addr(&request), merchant_id = request.get_ref().merchant_id))]
     async fn update_merchant(
         &self,
         request: Request<UpdateMerchantRequest>,
@@ -1818,7 +2110,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "trashed_merchant", merchant_id = request.get_ref().merchant_id))]
+    #[instrument(skip(self, request), fields(method = "trashed_merchant", peer = %peer_addr(&request), merchant_id = request.get_ref().merchant_id))]
     async fn trashed_merchant(
         &self,
         request: Request<FindByIdMerchantRequest>,
@@ -1870,7 +2162,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "restore_merchant", merchant_id = request.get_ref().merchant_id))]
+    #[instrument(skip(self, request), fields(method = "restore_merchant", peer = %peer_addr(&request), merchant_id = request.get_ref().merchant_id))]
     async fn restore_merchant(
         &self,
         request: Request<FindByIdMerchantRequest>,
@@ -1922,7 +2214,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "delete_merchant_permanent", merchant_id = request.get_ref().merchant_id))]
+    #[instrument(skip(self, request), fields(method = "delete_merchant_permanent", peer = %peer_addr(&request), merchant_id = request.get_ref().merchant_id))]
     async fn delete_merchant_permanent(
         &self,
         request: Request<FindByIdMerchantRequest>,
@@ -1976,7 +2268,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, _request), fields(method = "restore_all_merchant"))]
+    #[instrument(skip(self, _request), fields(method = "restore_all_merchant", peer = %peer_addr(&_request)))]
     async fn restore_all_merchant(
         &self,
         _request: Request<()>,
@@ -1998,6 +2290,7 @@ impl MerchantService for MerchantServiceImpl {
                 Ok(Response::new(ApiResponseMerchantAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
@@ -2021,7 +2314,7 @@ impl MerchantService for MerchantServiceImpl {
         }
     }
 
-    #[instrument(skip(self, _request), fields(method = "delete_all_merchant_permanent"))]
+    #[instrument(skip(self, _request), fields(method = "delete_all_merchant_permanent", peer = %peer_addr(&_request)))]
     async fn delete_all_merchant_permanent(
         &self,
         _request: Request<()>,
@@ -2043,6 +2336,7 @@ impl MerchantService for MerchantServiceImpl {
                 Ok(Response::new(ApiResponseMerchantAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
@@ -2065,4 +2359,184 @@ impl MerchantService for MerchantServiceImpl {
             }
         }
     }
+
+    #[instrument(skip(self, request), fields(method = "grant_merchant_user", peer = %peer_addr(&request), merchant_id = request.get_ref().merchant_id, user_id = request.get_ref().user_id))]
+    async fn grant_merchant_user(
+        &self,
+        request: Request<MerchantUserRequest>,
+    ) -> Result<Response<ApiResponseMerchantDelete>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let merchant_id = req.merchant_id;
+        let user_id = req.user_id;
+
+        let domain_req = DomainMerchantUserRequest {
+            merchant_id,
+            user_id,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .merchant_command
+                    .grant_user(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseMerchantDelete {
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    merchant_id = merchant_id,
+                    user_id = user_id,
+                    "grant_merchant_user success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            merchant_id = merchant_id,
+                            user_id = user_id,
+                            "grant_merchant_user rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(merchant_id = merchant_id, user_id = user_id, error = %inner, "grant_merchant_user failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "revoke_merchant_user", peer = %peer_addr(&request), merchant_id = request.get_ref().merchant_id, user_id = request.get_ref().user_id))]
+    async fn revoke_merchant_user(
+        &self,
+        request: Request<MerchantUserRequest>,
+    ) -> Result<Response<ApiResponseMerchantDelete>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let merchant_id = req.merchant_id;
+        let user_id = req.user_id;
+
+        let domain_req = DomainMerchantUserRequest {
+            merchant_id,
+            user_id,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .merchant_command
+                    .revoke_user(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseMerchantDelete {
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    merchant_id = merchant_id,
+                    user_id = user_id,
+                    "revoke_merchant_user success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            merchant_id = merchant_id,
+                            user_id = user_id,
+                            "revoke_merchant_user rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(merchant_id = merchant_id, user_id = user_id, error = %inner, "revoke_merchant_user failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "set_merchant_webhook", peer = %peer_addr(&request), merchant_id = request.get_ref().merchant_id))]
+    async fn set_merchant_webhook(
+        &self,
+        request: Request<SetMerchantWebhookRequest>,
+    ) -> Result<Response<ApiResponseMerchantWebhook>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let merchant_id = req.merchant_id;
+        let domain_req = DomainSetMerchantWebhookRequest {
+            merchant_id: Some(req.merchant_id),
+            url: req.url,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .merchant_command
+                    .set_webhook(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseMerchantWebhook {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(merchant_id = merchant_id, "set_merchant_webhook success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            merchant_id = merchant_id,
+                            "set_merchant_webhook rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(merchant_id = merchant_id, error = %inner, "set_merchant_webhook failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
 }
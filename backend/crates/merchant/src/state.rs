@@ -4,6 +4,7 @@ use shared::{
     config::{ConnectionPool, RedisConfig, RedisPool},
     observability::run_metrics_collector,
     resilience::{CircuitBreaker, LoadMonitor},
+    utils::{SelfCheck, check_database, check_tables},
 };
 use std::{fmt, sync::Arc, time::Duration};
 use tracing::{error, info, warn};
@@ -45,7 +46,7 @@ impl AppState {
             circuit_breaker.clone(),
         );
 
-        tokio::spawn(run_metrics_collector());
+        tokio::spawn(run_metrics_collector(Some(pool.clone())));
 
         Ok(Self {
             di_container,
@@ -54,6 +55,15 @@ impl AppState {
         })
     }
 
+    pub async fn self_check(&self, db: &ConnectionPool) -> Result<()> {
+        let mut check = SelfCheck::new("merchant-service");
+
+        check.record("database", check_database(db).await);
+        check.record("schema", check_tables(db, &["merchants"]).await);
+
+        check.finish()
+    }
+
     fn spawn_load_monitoring(
         monitor: Arc<LoadMonitor>,
         di: Arc<DependenciesInject>,
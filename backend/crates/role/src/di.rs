@@ -11,6 +11,7 @@ use shared::{
     repository::role::{command::RoleCommandRepository, query::RoleQueryRepository},
     service::role::{command::RoleCommandService, query::RoleQueryService},
 };
+use shared::{config::SoftDeleteRetentionConfig, utils::spawn_soft_delete_purge};
 use std::{fmt, sync::Arc, time::Duration};
 use tokio::sync::Semaphore;
 use tracing::{error, info, warn};
@@ -68,6 +69,7 @@ impl DependenciesInject {
 
         Self::spawn_monitoring_task(Arc::clone(&shared.cache_store));
         Self::spawn_cleanup_task(Arc::clone(&shared.cache_store));
+        spawn_soft_delete_purge(db.clone(), &SoftDeleteRetentionConfig::from_env(), "roles");
 
         Ok(Self {
             role_query,
@@ -11,6 +11,7 @@ use shared::{
         UpdateRoleRequest as DomainUpdateRoleRequest,
     },
     errors::{AppErrorGrpc, CircuitBreakerError},
+    utils::peer_addr,
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
@@ -50,7 +51,7 @@ impl RoleServiceImpl {
 
 #[tonic::async_trait]
 impl RoleService for RoleServiceImpl {
-    #[instrument(skip(self, request), fields(method = "find_all_role"))]
+    #[instrument(skip(self, request), fields(method = "find_all_role", peer = %peer_addr(&request)))]
     async fn find_all_role(
         &self,
         request: Request<FindAllRoleRequest>,
@@ -62,6 +63,7 @@ impl RoleService for RoleServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search,
+            sort_by: None,
         };
 
         let result = self
@@ -118,7 +120,7 @@ impl RoleService for RoleServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_id_role"))]
+    #[instrument(skip(self, request), fields(method = "find_by_id_role", peer = %peer_addr(&request)))]
     async fn find_by_id_role(
         &self,
         request: Request<FindByIdRoleRequest>,
@@ -175,7 +177,7 @@ impl RoleService for RoleServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_active"))]
+    #[instrument(skip(self, request), fields(method = "find_by_active", peer = %peer_addr(&request)))]
     async fn find_by_active(
         &self,
         request: Request<FindAllRoleRequest>,
@@ -187,6 +189,7 @@ impl RoleService for RoleServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search,
+            sort_by: None,
         };
         info!(
             "Received find_by_active request: page={}, page_size={}, search={:?}",
@@ -245,7 +248,7 @@ impl RoleService for RoleServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_trashed"))]
+    #[instrument(skip(self, request), fields(method = "find_by_trashed", peer = %peer_addr(&request)))]
     async fn find_by_trashed(
         &self,
         request: Request<FindAllRoleRequest>,
@@ -257,6 +260,7 @@ impl RoleService for RoleServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search,
+            sort_by: (!req.sort_by.is_empty()).then(|| req.sort_by.clone()),
         };
         info!(
             "Received find_by_trashed request: page={}, page_size={}, search={:?}",
@@ -315,7 +319,7 @@ impl RoleService for RoleServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_user_id"))]
+    #[instrument(skip(self, request), fields(method = "find_by_user_id", peer = %peer_addr(&request)))]
     async fn find_by_user_id(
         &self,
         request: Request<FindByIdUserRoleRequest>,
@@ -371,7 +375,7 @@ impl RoleService for RoleServiceImpl {
             }
         }
     }
-    #[instrument(skip(self, request), fields(method = "create_role"))]
+    #[instrument(skip(self, request), fields(method = "create_role", peer = %peer_addr(&request)))]
     async fn create_role(
         &self,
         request: Request<CreateRoleRequest>,
@@ -424,7 +428,7 @@ impl RoleService for RoleServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "update_role"))]
+    #[instrument(skip(self, request), fields(method = "update_role", peer = %peer_addr(&request)))]
     async fn update_role(
         &self,
         request: Request<UpdateRoleRequest>,
@@ -478,7 +482,7 @@ impl RoleService for RoleServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "trashed_role"))]
+    #[instrument(skip(self, request), fields(method = "trashed_role", peer = %peer_addr(&request)))]
     async fn trashed_role(
         &self,
         request: Request<FindByIdRoleRequest>,
@@ -527,7 +531,7 @@ impl RoleService for RoleServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "restore_role"))]
+    #[instrument(skip(self, request), fields(method = "restore_role", peer = %peer_addr(&request)))]
     async fn restore_role(
         &self,
         request: Request<FindByIdRoleRequest>,
@@ -576,7 +580,7 @@ impl RoleService for RoleServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "delete_role_permanent"))]
+    #[instrument(skip(self, request), fields(method = "delete_role_permanent", peer = %peer_addr(&request)))]
     async fn delete_role_permanent(
         &self,
         request: Request<FindByIdRoleRequest>,
@@ -624,7 +628,7 @@ impl RoleService for RoleServiceImpl {
         }
     }
 
-    #[instrument(skip(self, _request), fields(method = "restore_all_role"))]
+    #[instrument(skip(self, _request), fields(method = "restore_all_role", peer = %peer_addr(&_request)))]
     async fn restore_all_role(
         &self,
         _request: Request<()>,
@@ -648,6 +652,7 @@ impl RoleService for RoleServiceImpl {
                 Ok(Response::new(ApiResponseRoleAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
@@ -671,7 +676,7 @@ impl RoleService for RoleServiceImpl {
         }
     }
 
-    #[instrument(skip(self, _request), fields(method = "delete_all_role_permanent"))]
+    #[instrument(skip(self, _request), fields(method = "delete_all_role_permanent", peer = %peer_addr(&_request)))]
     async fn delete_all_role_permanent(
         &self,
         _request: Request<()>,
@@ -695,6 +700,7 @@ impl RoleService for RoleServiceImpl {
                 Ok(Response::new(ApiResponseRoleAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
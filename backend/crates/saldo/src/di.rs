@@ -18,7 +18,7 @@ use shared::{
         },
     },
     cache::CacheStore,
-    config::{ConnectionPool, RedisPool},
+    config::{ConnectionPool, RedisPool, SaldoConfig},
     context::shared_resources::SharedResources,
     observability::{CacheMetricsCore, TracingMetricsCore},
     repository::{
@@ -92,9 +92,12 @@ impl DependenciesInject {
         let card_query_repo =
             Arc::new(CardQueryRepository::new(db.clone())) as DynCardQueryRepository;
 
+        let saldo_config = SaldoConfig::from_env().context("failed to load saldo config")?;
+
         let command_service_deps = SaldoCommandServiceDeps {
             card_query: card_query_repo,
             command: saldo_command_repo,
+            default_initial_balance: saldo_config.default_initial_balance,
         };
         let saldo_command = Arc::new(
             SaldoCommandService::new(command_service_deps, &shared)
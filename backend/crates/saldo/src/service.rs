@@ -2,21 +2,22 @@ use crate::state::AppState;
 use genproto::{
     card::FindByCardNumberRequest,
     saldo::{
-        ApiResponseMonthSaldoBalances, ApiResponseMonthTotalSaldo, ApiResponsePaginationSaldo,
-        ApiResponsePaginationSaldoDeleteAt, ApiResponseSaldo, ApiResponseSaldoAll,
-        ApiResponseSaldoDelete, ApiResponseSaldoDeleteAt, ApiResponseYearSaldoBalances,
-        ApiResponseYearTotalSaldo, CreateSaldoRequest, FindAllSaldoRequest, FindByIdSaldoRequest,
-        FindMonthlySaldoTotalBalance, FindYearlySaldo, UpdateSaldoRequest,
-        saldo_service_server::SaldoService,
+        ApiResponseCardStatement, ApiResponseMonthSaldoBalances, ApiResponseMonthTotalSaldo,
+        ApiResponsePaginationSaldo, ApiResponsePaginationSaldoDeleteAt, ApiResponseSaldo,
+        ApiResponseSaldoAll, ApiResponseSaldoBalances, ApiResponseSaldoDelete,
+        ApiResponseSaldoDeleteAt, ApiResponseYearSaldoBalances, ApiResponseYearTotalSaldo,
+        CreateSaldoRequest, FindAllSaldoRequest, FindByIdSaldoRequest, FindCardStatementRequest,
+        FindMonthlySaldoTotalBalance, FindSaldoBalancesRequest, FindYearlySaldo,
+        UpdateSaldoRequest, saldo_service_server::SaldoService,
     },
 };
 use shared::{
     domain::requests::saldo::{
-        CreateSaldoRequest as DomainCreateSaldoRequest, FindAllSaldos, MonthTotalSaldoBalance,
-        UpdateSaldoRequest as DomainUpdateSaldoRequest,
+        CreateSaldoRequest as DomainCreateSaldoRequest, FindAllSaldos, FindCardStatement,
+        FindSaldoBalances, MonthTotalSaldoBalance, UpdateSaldoRequest as DomainUpdateSaldoRequest,
     },
     errors::{AppErrorGrpc, CircuitBreakerError},
-    utils::mask_card_number,
+    utils::{mask_card_number, peer_addr},
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
@@ -57,6 +58,7 @@ impl SaldoServiceImpl {
 impl SaldoService for SaldoServiceImpl {
     #[instrument(skip(self, request), fields(
         method = "find_all_saldo",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -72,6 +74,7 @@ impl SaldoService for SaldoServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -127,7 +130,7 @@ impl SaldoService for SaldoServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_id_saldo", saldo_id = request.get_ref().saldo_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "find_by_id_saldo", peer = %peer_addr(&request), saldo_id = request.get_ref().saldo_id), level = "info")]
     async fn find_by_id_saldo(
         &self,
         request: Request<FindByIdSaldoRequest>,
@@ -181,6 +184,7 @@ impl SaldoService for SaldoServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_total_saldo_balance",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         month = request.get_ref().month
     ), level = "info")]
@@ -248,7 +252,7 @@ impl SaldoService for SaldoServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_year_total_saldo_balance", year = request.get_ref().year), level = "info")]
+    #[instrument(skip(self, request), fields(method = "find_year_total_saldo_balance", peer = %peer_addr(&request), year = request.get_ref().year), level = "info")]
     async fn find_year_total_saldo_balance(
         &self,
         request: Request<FindYearlySaldo>,
@@ -300,7 +304,7 @@ impl SaldoService for SaldoServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_monthly_saldo_balances", year = request.get_ref().year), level = "info")]
+    #[instrument(skip(self, request), fields(method = "find_monthly_saldo_balances", peer = %peer_addr(&request), year = request.get_ref().year), level = "info")]
     async fn find_monthly_saldo_balances(
         &self,
         request: Request<FindYearlySaldo>,
@@ -352,7 +356,7 @@ impl SaldoService for SaldoServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_yearly_saldo_balances", year = request.get_ref().year), level = "info")]
+    #[instrument(skip(self, request), fields(method = "find_yearly_saldo_balances", peer = %peer_addr(&request), year = request.get_ref().year), level = "info")]
     async fn find_yearly_saldo_balances(
         &self,
         request: Request<FindYearlySaldo>,
@@ -404,7 +408,7 @@ impl SaldoService for SaldoServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_card_number", card_number = tracing::field::Empty), level = "info")]
+    #[instrument(skip(self, request), fields(method = "find_by_card_number", peer = %peer_addr(&request), card_number = tracing::field::Empty), level = "info")]
     async fn find_by_card_number(
         &self,
         request: Request<FindByCardNumberRequest>,
@@ -458,8 +462,122 @@ impl SaldoService for SaldoServiceImpl {
         }
     }
 
+    #[instrument(skip(self, request), fields(method = "find_balances_by_card_numbers", peer = %peer_addr(&request), card_count = request.get_ref().card_numbers.len()), level = "info")]
+    async fn find_balances_by_card_numbers(
+        &self,
+        request: Request<FindSaldoBalancesRequest>,
+    ) -> Result<Response<ApiResponseSaldoBalances>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let domain_req = FindSaldoBalances {
+            card_numbers: req.card_numbers,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .saldo_query
+                    .get_balances(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseSaldoBalances {
+                    status: api_response.status,
+                    message: api_response.message,
+                    balances: api_response.data.balances.into_iter().collect(),
+                    missing_card_numbers: api_response.data.missing_card_numbers,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    found = resp.get_ref().balances.len(),
+                    missing = resp.get_ref().missing_card_numbers.len(),
+                    "find_balances_by_card_numbers success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!("find_balances_by_card_numbers rejected: circuit breaker open");
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(error = %inner, "find_balances_by_card_numbers failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "find_card_statement", peer = %peer_addr(&request), year = request.get_ref().year, month = request.get_ref().month), level = "info")]
+    async fn find_card_statement(
+        &self,
+        request: Request<FindCardStatementRequest>,
+    ) -> Result<Response<ApiResponseCardStatement>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let masked_card = mask_card_number(&req.card_number);
+        let domain_req = FindCardStatement {
+            card_number: req.card_number,
+            year: req.year,
+            month: req.month,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .saldo_query
+                    .get_statement(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseCardStatement {
+                    status: api_response.status,
+                    message: api_response.message,
+                    data: Some(api_response.data.into()),
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(card_number = masked_card, "find_card_statement success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            card_number = masked_card,
+                            "find_card_statement rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(card_number = masked_card, error = %inner, "find_card_statement failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
     #[instrument(skip(self, request), fields(
         method = "find_by_active",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -475,6 +593,7 @@ impl SaldoService for SaldoServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -532,6 +651,7 @@ impl SaldoService for SaldoServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_trashed",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -547,6 +667,7 @@ impl SaldoService for SaldoServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: (!req.sort_by.is_empty()).then(|| req.sort_by.clone()),
         };
 
         let result = self
@@ -604,6 +725,7 @@ impl SaldoService for SaldoServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "create_saldo",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty
     ), level = "info")]
     async fn create_saldo(
@@ -619,7 +741,7 @@ impl SaldoService for SaldoServiceImpl {
 
         let domain_req = DomainCreateSaldoRequest {
             card_number,
-            total_balance: req.total_balance as i64,
+            total_balance: req.total_balance.map(i64::from),
         };
 
         let result = self
@@ -666,6 +788,7 @@ impl SaldoService for SaldoServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "update_saldo",
+        peer = %peer_addr(&request),
         saldo_id = request.get_ref().saldo_id,
         card_number = tracing::field::Empty
     ), level = "info")]
@@ -734,7 +857,7 @@ impl SaldoService for SaldoServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "trashed_saldo", saldo_id = request.get_ref().saldo_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "trashed_saldo", peer = %peer_addr(&request), saldo_id = request.get_ref().saldo_id), level = "info")]
     async fn trashed_saldo(
         &self,
         request: Request<FindByIdSaldoRequest>,
@@ -786,7 +909,7 @@ impl SaldoService for SaldoServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "restore_saldo", saldo_id = request.get_ref().saldo_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "restore_saldo", peer = %peer_addr(&request), saldo_id = request.get_ref().saldo_id), level = "info")]
     async fn restore_saldo(
         &self,
         request: Request<FindByIdSaldoRequest>,
@@ -838,7 +961,7 @@ impl SaldoService for SaldoServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "delete_saldo_permanent", saldo_id = request.get_ref().saldo_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "delete_saldo_permanent", peer = %peer_addr(&request), saldo_id = request.get_ref().saldo_id), level = "info")]
     async fn delete_saldo_permanent(
         &self,
         request: Request<FindByIdSaldoRequest>,
@@ -891,7 +1014,7 @@ impl SaldoService for SaldoServiceImpl {
 
     #[instrument(
         skip(self, _request),
-        fields(method = "restore_all_saldo"),
+        fields(method = "restore_all_saldo", peer = %peer_addr(&_request)),
         level = "info"
     )]
     async fn restore_all_saldo(
@@ -915,6 +1038,7 @@ impl SaldoService for SaldoServiceImpl {
                 Ok(Response::new(ApiResponseSaldoAll {
                     status: api_response.status,
                     message: api_response.message,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
@@ -940,7 +1064,7 @@ impl SaldoService for SaldoServiceImpl {
 
     #[instrument(
         skip(self, _request),
-        fields(method = "delete_all_saldo_permanent"),
+        fields(method = "delete_all_saldo_permanent", peer = %peer_addr(&_request)),
         level = "info"
     )]
     async fn delete_all_saldo_permanent(
@@ -964,6 +1088,7 @@ impl SaldoService for SaldoServiceImpl {
                 Ok(Response::new(ApiResponseSaldoAll {
                     status: api_response.status,
                     message: api_response.message,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
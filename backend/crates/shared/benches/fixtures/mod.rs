@@ -0,0 +1,95 @@
+use chrono::NaiveDate;
+use shared::{
+    config::{FeeRule, JwtConfig, TransactionFeeConfig},
+    domain::{
+        requests::transaction::CreateTransactionRequest,
+        responses::{DashboardCard, TransactionResponse},
+    },
+};
+use std::collections::{BTreeMap, HashMap};
+
+pub const PASSWORD: &str = "benchmark-password";
+pub const CARD_NUMBER: &str = "4111111111111111";
+pub const USER_ID: i64 = 42;
+
+pub fn jwt_config() -> JwtConfig {
+    JwtConfig::new(
+        "benchmark-secret-benchmark-secret",
+        "payment-gateway",
+        "payment-gateway-clients",
+        false,
+    )
+}
+
+pub fn fee_config() -> TransactionFeeConfig {
+    TransactionFeeConfig {
+        default_rule: FeeRule {
+            flat: 1000,
+            percent_bps: 150,
+        },
+        payment_methods: HashMap::from([(
+            "gopay".to_string(),
+            FeeRule {
+                flat: 500,
+                percent_bps: 200,
+            },
+        )]),
+        merchants: HashMap::from([(
+            7,
+            FeeRule {
+                flat: 0,
+                percent_bps: 100,
+            },
+        )]),
+    }
+}
+
+pub fn create_transaction_request() -> CreateTransactionRequest {
+    CreateTransactionRequest {
+        card_number: CARD_NUMBER.to_string(),
+        amount: 250_000,
+        payment_method: "gopay".to_string(),
+        merchant_id: Some(7),
+        transaction_time: NaiveDate::from_ymd_opt(2025, 8, 26)
+            .and_then(|d| d.and_hms_opt(8, 19, 0))
+            .expect("valid fixture timestamp"),
+        metadata: Some(BTreeMap::from([
+            ("order_id".to_string(), "ORD-2025-0001".to_string()),
+            ("channel".to_string(), "mobile".to_string()),
+        ])),
+    }
+}
+
+pub fn transaction_response(req: &CreateTransactionRequest) -> TransactionResponse {
+    let fee = fee_config().compute(
+        req.merchant_id.unwrap_or_default(),
+        &req.payment_method,
+        req.amount,
+    );
+
+    TransactionResponse {
+        id: 1,
+        transaction_no: "6f1c7a52-3f4e-4d0e-9b8a-1d2c3e4f5a6b".to_string(),
+        card_number: req.card_number.clone(),
+        amount: fee.net,
+        payment_method: req.payment_method.clone(),
+        merchant_id: req.merchant_id.unwrap_or_default(),
+        transaction_time: req.transaction_time.to_string(),
+        created_at: Some(req.transaction_time.to_string()),
+        updated_at: Some(req.transaction_time.to_string()),
+        gross_amount: Some(fee.gross),
+        fee_amount: Some(fee.fee),
+        net_amount: Some(fee.net),
+        metadata: req.metadata.clone(),
+    }
+}
+
+pub fn dashboard_card() -> DashboardCard {
+    DashboardCard {
+        total_balance: Some(12_500_000),
+        total_topup: Some(40_000_000),
+        total_withdraw: Some(9_000_000),
+        total_transaction: Some(15_250_000),
+        total_transfer: Some(3_250_000),
+    }
+}
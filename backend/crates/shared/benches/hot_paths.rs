@@ -0,0 +1,116 @@
+//! CPU-only steps taken from the login, transaction creation and card
+//! dashboard paths. These are not end-to-end benchmarks: gRPC, database and
+//! Redis round-trips are left out, so the totals of a real request are not
+//! measured here.
+
+mod fixtures;
+
+use criterion::{BatchSize, Criterion, black_box, criterion_group, criterion_main};
+use genproto::{
+    card::CardResponseDashboard as CardResponseDashboardProto,
+    transaction::TransactionResponse as TransactionResponseProto,
+};
+use shared::{
+    abstract_trait::{hashing::HashingTrait, jwt::JwtServiceTrait},
+    config::Hashing,
+    domain::responses::ApiResponse,
+};
+use validator::Validate;
+
+fn login(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().expect("tokio runtime");
+    let hashing = Hashing::new();
+    let hashed = runtime
+        .block_on(hashing.hash_password(fixtures::PASSWORD))
+        .expect("hash fixture password");
+    let jwt = fixtures::jwt_config();
+    let access = jwt
+        .generate_token(fixtures::USER_ID, "access")
+        .expect("generate access token");
+
+    let mut group = c.benchmark_group("login_steps");
+
+    group.bench_function("compare_password", |b| {
+        b.to_async(&runtime).iter(|| async {
+            hashing
+                .compare_password(black_box(&hashed), black_box(fixtures::PASSWORD))
+                .await
+                .expect("password matches")
+        })
+    });
+
+    group.bench_function("issue_token_pair", |b| {
+        b.iter(|| {
+            let access = jwt.generate_token(black_box(fixtures::USER_ID), "access");
+            let refresh = jwt.generate_token(black_box(fixtures::USER_ID), "refresh");
+            (access, refresh)
+        })
+    });
+
+    group.bench_function("verify_access_token", |b| {
+        b.iter(|| jwt.verify_token(black_box(&access), "access"))
+    });
+
+    group.finish();
+}
+
+fn create_transaction(c: &mut Criterion) {
+    let fees = fixtures::fee_config();
+    let req = fixtures::create_transaction_request();
+    let response = fixtures::transaction_response(&req);
+
+    let mut group = c.benchmark_group("create_transaction_steps");
+
+    group.bench_function("validate_request", |b| {
+        b.iter(|| black_box(&req).validate())
+    });
+
+    group.bench_function("compute_fee", |b| {
+        b.iter(|| {
+            fees.compute(
+                black_box(7),
+                black_box(&req.payment_method),
+                black_box(req.amount),
+            )
+        })
+    });
+
+    group.bench_function("response_to_proto", |b| {
+        b.iter_batched(
+            || response.clone(),
+            TransactionResponseProto::from,
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.finish();
+}
+
+fn card_dashboard(c: &mut Criterion) {
+    let dashboard = fixtures::dashboard_card();
+
+    let mut group = c.benchmark_group("card_dashboard_steps");
+
+    group.bench_function("dashboard_to_proto", |b| {
+        b.iter_batched(
+            || dashboard.clone(),
+            CardResponseDashboardProto::from,
+            BatchSize::SmallInput,
+        )
+    });
+
+    group.bench_function("serialize_response", |b| {
+        b.iter(|| {
+            serde_json::to_vec(&ApiResponse {
+                status: "success".to_string(),
+                message: "Dashboard card retrieved successfully".to_string(),
+                data: black_box(&dashboard),
+            })
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, login, create_transaction, card_dashboard);
+criterion_main!(benches);
@@ -0,0 +1,22 @@
+use crate::{
+    domain::requests::anomaly::{AnomalyMetric, AnomalyScope},
+    errors::RepositoryError,
+    model::anomaly::DailyMetricModel,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+pub type DynAnomalyRepository = Arc<dyn AnomalyRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait AnomalyRepositoryTrait {
+    async fn get_daily_series(
+        &self,
+        scope: AnomalyScope,
+        metric: AnomalyMetric,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<DailyMetricModel>, RepositoryError>;
+}
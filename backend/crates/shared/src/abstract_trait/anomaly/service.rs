@@ -0,0 +1,21 @@
+use crate::{
+    domain::{
+        requests::anomaly::{AnomalyMetric, AnomalyScope},
+        responses::AnomalyReport,
+    },
+    errors::ServiceError,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub type DynAnomalyDetectionService = Arc<dyn AnomalyDetectionServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait AnomalyDetectionServiceTrait {
+    async fn detect_anomaly(
+        &self,
+        scope: AnomalyScope,
+        metric: AnomalyMetric,
+    ) -> Result<AnomalyReport, ServiceError>;
+}
@@ -4,7 +4,7 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
-        requests::auth::{AuthRequest, RegisterRequest},
+        requests::auth::{AuthRequest, ChangePasswordRequest, RegisterRequest},
         responses::{ApiResponse, TokenResponse, UserResponse},
     },
     errors::HttpError,
@@ -21,4 +21,12 @@ pub trait AuthGrpcClientTrait {
         &self,
         request: &RegisterRequest,
     ) -> Result<ApiResponse<UserResponse>, HttpError>;
+    async fn send_verification(&self, user_id: i32) -> Result<ApiResponse<bool>, HttpError>;
+    async fn resend_verification(&self, email: &str) -> Result<ApiResponse<bool>, HttpError>;
+    async fn verify_email(&self, token: &str) -> Result<ApiResponse<bool>, HttpError>;
+    async fn change_password(
+        &self,
+        user_id: i32,
+        req: &ChangePasswordRequest,
+    ) -> Result<ApiResponse<TokenResponse>, HttpError>;
 }
@@ -4,8 +4,8 @@ use std::sync::Arc;
 
 use crate::{
     domain::{
-        requests::auth::{AuthRequest, RegisterRequest},
-        responses::{ApiResponse, TokenResponse, UserResponse},
+        requests::auth::{AuthRequest, ChangePasswordRequest, RegisterRequest},
+        responses::{ApiResponse, TokenIntrospectionResponse, TokenResponse, UserResponse},
     },
     errors::ServiceError,
 };
@@ -24,4 +24,16 @@ pub trait AuthServiceTrait {
     ) -> Result<ApiResponse<TokenResponse>, ServiceError>;
     async fn get_me(&self, id: i32) -> Result<ApiResponse<UserResponse>, ServiceError>;
     async fn refresh_token(&self, token: &str) -> Result<ApiResponse<TokenResponse>, ServiceError>;
+    async fn send_verification(&self, user_id: i32) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn resend_verification(&self, email: &str) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn verify_email(&self, token: &str) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn change_password(
+        &self,
+        user_id: i32,
+        req: &ChangePasswordRequest,
+    ) -> Result<ApiResponse<TokenResponse>, ServiceError>;
+    async fn batch_introspect(
+        &self,
+        tokens: Vec<String>,
+    ) -> Result<ApiResponse<Vec<TokenIntrospectionResponse>>, ServiceError>;
 }
@@ -1,7 +1,10 @@
 use crate::{
     domain::{
-        requests::card::{CreateCardRequest, UpdateCardRequest},
-        responses::{ApiResponse, CardResponse, CardResponseDeleteAt},
+        requests::card::{CreateCardRequest, RestoreCardsByFilter, UpdateCardRequest},
+        responses::{
+            ApiResponse, BulkResult, CardFreezeResponse, CardReissueResponse, CardResponse,
+            CardResponseDeleteAt,
+        },
     },
     errors::HttpError,
 };
@@ -17,6 +20,13 @@ pub trait CardCommandGrpcClientTrait {
     async fn trash(&self, id: i32) -> Result<ApiResponse<CardResponseDeleteAt>, HttpError>;
     async fn restore(&self, id: i32) -> Result<ApiResponse<CardResponseDeleteAt>, HttpError>;
     async fn delete(&self, id: i32) -> Result<ApiResponse<bool>, HttpError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn reissue(&self, id: i32) -> Result<ApiResponse<CardReissueResponse>, HttpError>;
+    async fn freeze(&self, id: i32) -> Result<ApiResponse<CardFreezeResponse>, HttpError>;
+    async fn unfreeze(&self, id: i32) -> Result<ApiResponse<CardFreezeResponse>, HttpError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
+    async fn restore_by_filter(
+        &self,
+        filter: &RestoreCardsByFilter,
+    ) -> Result<ApiResponse<Vec<i32>>, HttpError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
 }
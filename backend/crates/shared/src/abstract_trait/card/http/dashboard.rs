@@ -1,5 +1,11 @@
 use crate::{
-    domain::responses::{ApiResponse, DashboardCard, DashboardCardCardNumber},
+    domain::{
+        requests::card::{FindCardDashboards, FindTopCards},
+        responses::{
+            ApiResponse, DashboardCard, DashboardCardCardNumber, DashboardCardNumbers,
+            SystemStatsResponse, TopCardResponse,
+        },
+    },
     errors::HttpError,
 };
 use anyhow::Result;
@@ -12,4 +18,13 @@ pub trait CardDashboardGrpcClientTrait {
         &self,
         card_number: String,
     ) -> Result<ApiResponse<DashboardCardCardNumber>, HttpError>;
+    async fn get_dashboard_bycards(
+        &self,
+        request: &FindCardDashboards,
+    ) -> Result<ApiResponse<DashboardCardNumbers>, HttpError>;
+    async fn get_system_stats(&self) -> Result<ApiResponse<SystemStatsResponse>, HttpError>;
+    async fn get_top_cards(
+        &self,
+        request: &FindTopCards,
+    ) -> Result<ApiResponse<Vec<TopCardResponse>>, HttpError>;
 }
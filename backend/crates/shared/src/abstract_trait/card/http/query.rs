@@ -1,7 +1,10 @@
 use crate::{
     domain::{
         requests::card::FindAllCards,
-        responses::{ApiResponse, ApiResponsePagination, CardResponse, CardResponseDeleteAt},
+        responses::{
+            ApiResponse, ApiResponsePagination, CardResponse, CardResponseDeleteAt,
+            CardStatusResponse,
+        },
     },
     errors::HttpError,
 };
@@ -24,8 +27,16 @@ pub trait CardQueryGrpcClientTrait {
     ) -> Result<ApiResponsePagination<Vec<CardResponseDeleteAt>>, HttpError>;
     async fn find_by_id(&self, id: i32) -> Result<ApiResponse<CardResponse>, HttpError>;
     async fn find_by_user_id(&self, user_id: i32) -> Result<ApiResponse<CardResponse>, HttpError>;
+    async fn find_all_by_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<CardResponse>>, HttpError>;
     async fn find_by_card_number(
         &self,
         card_number: String,
     ) -> Result<ApiResponse<CardResponse>, HttpError>;
+    async fn check_card(
+        &self,
+        card_number: String,
+    ) -> Result<ApiResponse<CardStatusResponse>, HttpError>;
 }
@@ -1,10 +1,12 @@
 use crate::{
-    domain::requests::card::{CreateCardRequest, UpdateCardRequest},
+    domain::requests::card::{CreateCardRequest, RestoreCardsByFilter, UpdateCardRequest},
     errors::RepositoryError,
-    model::card::CardModel,
+    model::bulk::BulkResultModel,
+    model::card::{CardFreezeModel, CardModel, CardReissueModel},
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDate;
 use std::sync::Arc;
 
 pub type DynCardCommandRepository = Arc<dyn CardCommandRepositoryTrait + Send + Sync>;
@@ -16,6 +18,16 @@ pub trait CardCommandRepositoryTrait {
     async fn trash(&self, id: i32) -> Result<CardModel, RepositoryError>;
     async fn restore(&self, id: i32) -> Result<CardModel, RepositoryError>;
     async fn delete_permanent(&self, id: i32) -> Result<bool, RepositoryError>;
-    async fn restore_all(&self) -> Result<bool, RepositoryError>;
-    async fn delete_all(&self) -> Result<bool, RepositoryError>;
+    async fn reissue(
+        &self,
+        id: i32,
+        expire_date: NaiveDate,
+    ) -> Result<CardReissueModel, RepositoryError>;
+    async fn set_frozen(&self, id: i32, frozen: bool) -> Result<CardFreezeModel, RepositoryError>;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError>;
+    async fn restore_by_filter(
+        &self,
+        filter: &RestoreCardsByFilter,
+    ) -> Result<Vec<i32>, RepositoryError>;
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError>;
 }
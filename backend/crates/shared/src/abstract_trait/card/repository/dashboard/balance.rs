@@ -10,4 +10,8 @@ pub type DynCardDashboardBalanceRepository =
 pub trait CardDashboardBalanceRepositoryTrait {
     async fn get_total_balance(&self) -> Result<i64, RepositoryError>;
     async fn get_total_balance_by_card(&self, card_number: String) -> Result<i64, RepositoryError>;
+    async fn get_total_balance_by_cards(
+        &self,
+        card_numbers: &[String],
+    ) -> Result<Vec<(String, i64)>, RepositoryError>;
 }
@@ -0,0 +1,24 @@
+use crate::{
+    domain::requests::card::TopCardMetric,
+    errors::RepositoryError,
+    model::card::{SystemStatsModel, TopCardModel},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use std::sync::Arc;
+
+pub type DynCardDashboardSystemRepository =
+    Arc<dyn CardDashboardSystemRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait CardDashboardSystemRepositoryTrait {
+    async fn get_system_stats(&self, day: NaiveDate) -> Result<SystemStatsModel, RepositoryError>;
+    async fn get_top_cards(
+        &self,
+        metric: TopCardMetric,
+        limit: i32,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<TopCardModel>, RepositoryError>;
+}
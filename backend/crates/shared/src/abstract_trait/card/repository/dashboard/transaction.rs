@@ -10,4 +10,8 @@ pub type DynCardDashboardTransactionRepository =
 pub trait CardDashboardTransactionRepositoryTrait {
     async fn get_total_amount(&self) -> Result<i64, RepositoryError>;
     async fn get_total_amount_by_card(&self, card_number: String) -> Result<i64, RepositoryError>;
+    async fn get_total_amount_by_cards(
+        &self,
+        card_numbers: &[String],
+    ) -> Result<Vec<(String, i64)>, RepositoryError>;
 }
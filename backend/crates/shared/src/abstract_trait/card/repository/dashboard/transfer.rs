@@ -15,4 +15,12 @@ pub trait CardDashboardTransferRepositoryTrait {
         &self,
         card_number: String,
     ) -> Result<i64, RepositoryError>;
+    async fn get_total_amount_by_senders(
+        &self,
+        card_numbers: &[String],
+    ) -> Result<Vec<(String, i64)>, RepositoryError>;
+    async fn get_total_amount_by_receivers(
+        &self,
+        card_numbers: &[String],
+    ) -> Result<Vec<(String, i64)>, RepositoryError>;
 }
@@ -1,5 +1,7 @@
 use crate::{
-    domain::requests::card::FindAllCards, errors::RepositoryError, model::card::CardModel,
+    domain::requests::card::FindAllCards,
+    errors::RepositoryError,
+    model::card::{CardModel, CardStatusModel},
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -24,4 +26,13 @@ pub trait CardQueryRepositoryTrait {
     async fn find_by_id(&self, id: i32) -> Result<CardModel, RepositoryError>;
     async fn find_by_card(&self, card_number: &str) -> Result<CardModel, RepositoryError>;
     async fn find_by_user_id(&self, user_id: i32) -> Result<CardModel, RepositoryError>;
+    async fn find_all_by_user_id(&self, user_id: i32) -> Result<Vec<CardModel>, RepositoryError>;
+    async fn find_status_by_card(
+        &self,
+        card_number: &str,
+    ) -> Result<Option<CardStatusModel>, RepositoryError>;
+    async fn find_merchant_id_by_card(
+        &self,
+        card_number: &str,
+    ) -> Result<Option<i32>, RepositoryError>;
 }
@@ -1,7 +1,10 @@
 use crate::{
     domain::{
-        requests::card::{CreateCardRequest, UpdateCardRequest},
-        responses::{ApiResponse, CardResponse, CardResponseDeleteAt},
+        requests::card::{CreateCardRequest, RestoreCardsByFilter, UpdateCardRequest},
+        responses::{
+            ApiResponse, BulkResult, CardFreezeResponse, CardReissueResponse, CardResponse,
+            CardResponseDeleteAt,
+        },
     },
     errors::ServiceError,
 };
@@ -24,6 +27,13 @@ pub trait CardCommandServiceTrait {
     async fn trash(&self, id: i32) -> Result<ApiResponse<CardResponseDeleteAt>, ServiceError>;
     async fn restore(&self, id: i32) -> Result<ApiResponse<CardResponseDeleteAt>, ServiceError>;
     async fn delete(&self, id: i32) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn reissue(&self, id: i32) -> Result<ApiResponse<CardReissueResponse>, ServiceError>;
+    async fn freeze(&self, id: i32) -> Result<ApiResponse<CardFreezeResponse>, ServiceError>;
+    async fn unfreeze(&self, id: i32) -> Result<ApiResponse<CardFreezeResponse>, ServiceError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
+    async fn restore_by_filter(
+        &self,
+        filter: &RestoreCardsByFilter,
+    ) -> Result<ApiResponse<Vec<i32>>, ServiceError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
 }
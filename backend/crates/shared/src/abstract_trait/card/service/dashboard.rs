@@ -1,5 +1,11 @@
 use crate::{
-    domain::responses::{ApiResponse, DashboardCard, DashboardCardCardNumber},
+    domain::{
+        requests::card::{FindCardDashboards, FindTopCards},
+        responses::{
+            ApiResponse, DashboardCard, DashboardCardCardNumber, DashboardCardNumbers,
+            SystemStatsResponse, TopCardResponse,
+        },
+    },
     errors::ServiceError,
 };
 use anyhow::Result;
@@ -15,4 +21,13 @@ pub trait CardDashboardServiceTrait {
         &self,
         card_number: String,
     ) -> Result<ApiResponse<DashboardCardCardNumber>, ServiceError>;
+    async fn get_dashboard_bycards(
+        &self,
+        request: &FindCardDashboards,
+    ) -> Result<ApiResponse<DashboardCardNumbers>, ServiceError>;
+    async fn get_system_stats(&self) -> Result<ApiResponse<SystemStatsResponse>, ServiceError>;
+    async fn get_top_cards(
+        &self,
+        request: &FindTopCards,
+    ) -> Result<ApiResponse<Vec<TopCardResponse>>, ServiceError>;
 }
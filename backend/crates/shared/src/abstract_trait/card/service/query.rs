@@ -1,7 +1,10 @@
 use crate::{
     domain::{
         requests::card::FindAllCards,
-        responses::{ApiResponse, ApiResponsePagination, CardResponse, CardResponseDeleteAt},
+        responses::{
+            ApiResponse, ApiResponsePagination, CardResponse, CardResponseDeleteAt,
+            CardStatusResponse,
+        },
     },
     errors::ServiceError,
 };
@@ -30,8 +33,16 @@ pub trait CardQueryServiceTrait {
         &self,
         user_id: i32,
     ) -> Result<ApiResponse<CardResponse>, ServiceError>;
+    async fn find_all_by_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<CardResponse>>, ServiceError>;
     async fn find_by_card(
         &self,
         card_number: &str,
     ) -> Result<ApiResponse<CardResponse>, ServiceError>;
+    async fn check_card(
+        &self,
+        card_number: &str,
+    ) -> Result<ApiResponse<CardStatusResponse>, ServiceError>;
 }
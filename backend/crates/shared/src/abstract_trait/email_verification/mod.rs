@@ -0,0 +1,36 @@
+use crate::{
+    errors::{RepositoryError, ServiceError},
+    model::email_verification::EmailVerificationTokenModel,
+};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use std::sync::Arc;
+
+pub type DynEmailVerificationRepository = Arc<dyn EmailVerificationRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait EmailVerificationRepositoryTrait {
+    async fn create_token(
+        &self,
+        user_id: i32,
+        token_hash: &str,
+        expires_at: NaiveDateTime,
+    ) -> Result<(), RepositoryError>;
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<EmailVerificationTokenModel>, RepositoryError>;
+    async fn consume_token(&self, token_hash: &str) -> Result<Option<i32>, RepositoryError>;
+    async fn is_verified(&self, user_id: i32) -> Result<bool, RepositoryError>;
+    async fn latest_token_created_at(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<NaiveDateTime>, RepositoryError>;
+}
+
+pub type DynVerificationMailer = Arc<dyn VerificationMailerTrait + Send + Sync>;
+
+#[async_trait]
+pub trait VerificationMailerTrait {
+    async fn send_verification(&self, email: &str, token: &str) -> Result<(), ServiceError>;
+}
@@ -0,0 +1,16 @@
+use crate::{errors::ServiceError, events::DomainEvent};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub type DynEventSink = Arc<dyn EventSinkTrait + Send + Sync>;
+pub type DynEventPublisher = Arc<dyn EventPublisherTrait + Send + Sync>;
+
+#[async_trait]
+pub trait EventSinkTrait {
+    fn name(&self) -> &'static str;
+    async fn publish(&self, event: &DomainEvent) -> Result<(), ServiceError>;
+}
+
+pub trait EventPublisherTrait {
+    fn emit(&self, event: DomainEvent);
+}
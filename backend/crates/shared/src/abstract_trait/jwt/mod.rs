@@ -1,4 +1,4 @@
-use crate::errors::ServiceError;
+use crate::{config::Claims, errors::ServiceError};
 use anyhow::Result;
 use async_trait::async_trait;
 use std::sync::Arc;
@@ -8,5 +8,12 @@ pub type DynJwtService = Arc<dyn JwtServiceTrait + Send + Sync>;
 #[async_trait]
 pub trait JwtServiceTrait: Send + Sync + std::fmt::Debug {
     fn generate_token(&self, user_id: i64, token_type: &str) -> Result<String, ServiceError>;
+    fn generate_token_with_auth_time(
+        &self,
+        user_id: i64,
+        token_type: &str,
+        auth_time: Option<usize>,
+    ) -> Result<String, ServiceError>;
     fn verify_token(&self, token: &str, expected_type: &str) -> Result<i64, ServiceError>;
+    fn verify_claims(&self, token: &str, expected_type: &str) -> Result<Claims, ServiceError>;
 }
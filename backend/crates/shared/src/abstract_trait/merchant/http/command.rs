@@ -1,7 +1,13 @@
 use crate::{
     domain::{
-        requests::merchant::{CreateMerchantRequest, UpdateMerchantRequest},
-        responses::{ApiResponse, MerchantResponse, MerchantResponseDeleteAt},
+        requests::merchant::{
+            CreateMerchantRequest, MerchantUserRequest, SetMerchantWebhookRequest,
+            UpdateMerchantRequest,
+        },
+        responses::{
+            ApiResponse, BulkResult, MerchantResponse, MerchantResponseDeleteAt,
+            MerchantWebhookResponse,
+        },
     },
     errors::HttpError,
 };
@@ -21,6 +27,18 @@ pub trait MerchantCommandGrpcClientTrait {
     async fn trash(&self, id: i32) -> Result<ApiResponse<MerchantResponseDeleteAt>, HttpError>;
     async fn restore(&self, id: i32) -> Result<ApiResponse<MerchantResponseDeleteAt>, HttpError>;
     async fn delete(&self, id: i32) -> Result<ApiResponse<bool>, HttpError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
+    async fn grant_user(
+        &self,
+        request: &MerchantUserRequest,
+    ) -> Result<ApiResponse<bool>, HttpError>;
+    async fn revoke_user(
+        &self,
+        request: &MerchantUserRequest,
+    ) -> Result<ApiResponse<bool>, HttpError>;
+    async fn set_webhook(
+        &self,
+        request: &SetMerchantWebhookRequest,
+    ) -> Result<ApiResponse<MerchantWebhookResponse>, HttpError>;
 }
@@ -33,4 +33,8 @@ pub trait MerchantQueryGrpcClientTrait {
         &self,
         user_id: i32,
     ) -> Result<ApiResponse<Vec<MerchantResponse>>, HttpError>;
+    async fn list_accessible_merchants(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<MerchantResponse>>, HttpError>;
 }
@@ -2,9 +2,13 @@ use crate::{
     domain::{
         requests::merchant::{
             FindAllMerchantTransactions, FindAllMerchantTransactionsByApiKey,
-            FindAllMerchantTransactionsById,
+            FindAllMerchantTransactionsById, FindMerchantTransactionsDaily,
+        },
+        responses::{
+            ApiResponse, ApiResponsePagination, MerchantApiKeyUsageResponse,
+            MerchantSettlementResponse, MerchantTransactionDailyResponse,
+            MerchantTransactionResponse,
         },
-        responses::{ApiResponsePagination, MerchantTransactionResponse},
     },
     errors::HttpError,
 };
@@ -26,4 +30,16 @@ pub trait MerchantTransactionGrpcClientTrait {
         &self,
         request: &FindAllMerchantTransactionsById,
     ) -> Result<ApiResponsePagination<Vec<MerchantTransactionResponse>>, HttpError>;
+    async fn find_daily_transactions_by_id(
+        &self,
+        request: &FindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<Vec<MerchantTransactionDailyResponse>>, HttpError>;
+    async fn find_api_key_usage_by_id(
+        &self,
+        request: &FindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<Vec<MerchantApiKeyUsageResponse>>, HttpError>;
+    async fn settlement(
+        &self,
+        request: &FindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<MerchantSettlementResponse>, HttpError>;
 }
@@ -1,9 +1,10 @@
 use crate::{
     domain::requests::merchant::{
-        CreateMerchantRequest, UpdateMerchantRequest, UpdateMerchantStatus,
+        CreateMerchantRequest, MerchantUserRequest, UpdateMerchantRequest, UpdateMerchantStatus,
     },
     errors::RepositoryError,
-    model::merchant::MerchantModel,
+    model::bulk::BulkResultModel,
+    model::merchant::{MerchantModel, MerchantWebhookModel},
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -17,7 +18,7 @@ pub trait MerchantCommandRepositoryTrait {
         &self,
         api_key: String,
         request: &CreateMerchantRequest,
-    ) -> Result<MerchantModel, RepositoryError>;
+    ) -> Result<(MerchantModel, bool), RepositoryError>;
     async fn update(
         &self,
         request: &UpdateMerchantRequest,
@@ -29,6 +30,13 @@ pub trait MerchantCommandRepositoryTrait {
     async fn trash(&self, id: i32) -> Result<MerchantModel, RepositoryError>;
     async fn restore(&self, id: i32) -> Result<MerchantModel, RepositoryError>;
     async fn delete_permanent(&self, id: i32) -> Result<bool, RepositoryError>;
-    async fn restore_all(&self) -> Result<bool, RepositoryError>;
-    async fn delete_all(&self) -> Result<bool, RepositoryError>;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError>;
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError>;
+    async fn grant_user(&self, request: &MerchantUserRequest) -> Result<(), RepositoryError>;
+    async fn revoke_user(&self, request: &MerchantUserRequest) -> Result<bool, RepositoryError>;
+    async fn set_webhook(
+        &self,
+        merchant_id: i32,
+        url: &str,
+    ) -> Result<MerchantWebhookModel, RepositoryError>;
 }
@@ -29,4 +29,8 @@ pub trait MerchantQueryRepositoryTrait {
         &self,
         user_id: i32,
     ) -> Result<Vec<MerchantModel>, RepositoryError>;
+    async fn list_accessible_merchants(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<MerchantModel>, RepositoryError>;
 }
@@ -1,10 +1,13 @@
 use crate::{
     domain::requests::merchant::{
         FindAllMerchantTransactions, FindAllMerchantTransactionsByApiKey,
-        FindAllMerchantTransactionsById,
+        FindAllMerchantTransactionsById, FindMerchantTransactionsDaily,
     },
     errors::RepositoryError,
-    model::merchant::MerchantTransactionsModel,
+    model::merchant::{
+        MerchantApiKeyUsageDailyModel, MerchantSettlementModel, MerchantTransactionDailyModel,
+        MerchantTransactionsModel,
+    },
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -28,4 +31,16 @@ pub trait MerchantTransactionRepositoryTrait {
         &self,
         req: &FindAllMerchantTransactionsById,
     ) -> Result<(Vec<MerchantTransactionsModel>, i64), RepositoryError>;
+    async fn find_daily_transactions_by_id(
+        &self,
+        req: &FindMerchantTransactionsDaily,
+    ) -> Result<Vec<MerchantTransactionDailyModel>, RepositoryError>;
+    async fn find_api_key_usage_by_id(
+        &self,
+        req: &FindMerchantTransactionsDaily,
+    ) -> Result<Vec<MerchantApiKeyUsageDailyModel>, RepositoryError>;
+    async fn settlement(
+        &self,
+        req: &FindMerchantTransactionsDaily,
+    ) -> Result<MerchantSettlementModel, RepositoryError>;
 }
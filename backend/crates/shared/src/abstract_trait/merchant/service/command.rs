@@ -1,7 +1,13 @@
 use crate::{
     domain::{
-        requests::merchant::{CreateMerchantRequest, UpdateMerchantRequest, UpdateMerchantStatus},
-        responses::{ApiResponse, MerchantResponse, MerchantResponseDeleteAt},
+        requests::merchant::{
+            CreateMerchantRequest, MerchantUserRequest, SetMerchantWebhookRequest,
+            UpdateMerchantRequest, UpdateMerchantStatus,
+        },
+        responses::{
+            ApiResponse, BulkResult, MerchantResponse, MerchantResponseDeleteAt,
+            MerchantWebhookResponse,
+        },
     },
     errors::ServiceError,
 };
@@ -29,6 +35,18 @@ pub trait MerchantCommandServiceTrait {
     async fn restore(&self, id: i32)
     -> Result<ApiResponse<MerchantResponseDeleteAt>, ServiceError>;
     async fn delete(&self, id: i32) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
+    async fn grant_user(
+        &self,
+        request: &MerchantUserRequest,
+    ) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn revoke_user(
+        &self,
+        request: &MerchantUserRequest,
+    ) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn set_webhook(
+        &self,
+        request: &SetMerchantWebhookRequest,
+    ) -> Result<ApiResponse<MerchantWebhookResponse>, ServiceError>;
 }
@@ -36,4 +36,8 @@ pub trait MerchantQueryServiceTrait {
         &self,
         user_id: i32,
     ) -> Result<ApiResponse<Vec<MerchantResponse>>, ServiceError>;
+    async fn list_accessible_merchants(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<MerchantResponse>>, ServiceError>;
 }
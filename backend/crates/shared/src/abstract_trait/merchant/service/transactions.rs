@@ -2,9 +2,13 @@ use crate::{
     domain::{
         requests::merchant::{
             FindAllMerchantTransactions, FindAllMerchantTransactionsByApiKey,
-            FindAllMerchantTransactionsById,
+            FindAllMerchantTransactionsById, FindMerchantTransactionsDaily,
+        },
+        responses::{
+            ApiResponse, ApiResponsePagination, MerchantApiKeyUsageResponse,
+            MerchantSettlementResponse, MerchantTransactionDailyResponse,
+            MerchantTransactionResponse,
         },
-        responses::{ApiResponsePagination, MerchantTransactionResponse},
     },
     errors::ServiceError,
 };
@@ -29,4 +33,16 @@ pub trait MerchantTransactionServiceTrait {
         &self,
         request: &FindAllMerchantTransactionsById,
     ) -> Result<ApiResponsePagination<Vec<MerchantTransactionResponse>>, ServiceError>;
+    async fn find_daily_by_id(
+        &self,
+        request: &FindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<Vec<MerchantTransactionDailyResponse>>, ServiceError>;
+    async fn find_api_key_usage_by_id(
+        &self,
+        request: &FindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<Vec<MerchantApiKeyUsageResponse>>, ServiceError>;
+    async fn settlement(
+        &self,
+        request: &FindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<MerchantSettlementResponse>, ServiceError>;
 }
@@ -1,5 +1,8 @@
+pub mod anomaly;
 pub mod auth;
 pub mod card;
+pub mod email_verification;
+pub mod events;
 pub mod hashing;
 pub mod jwt;
 pub mod merchant;
@@ -14,4 +17,5 @@ pub mod transaction;
 pub mod transfer;
 pub mod user;
 pub mod user_roles;
+pub mod webhook;
 pub mod withdraw;
@@ -3,6 +3,20 @@ use std::sync::Arc;
 
 pub type DynRateLimitMiddleware = Arc<dyn RateLimitMiddlewareTrait + Send + Sync>;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimitStatus {
+    pub allowed: bool,
+    pub limit: u32,
+    pub current: u32,
+    pub reset_after_secs: u64,
+}
+
+impl RateLimitStatus {
+    pub fn remaining(&self) -> u32 {
+        self.limit.saturating_sub(self.current)
+    }
+}
+
 #[async_trait]
 pub trait RateLimitMiddlewareTrait {
     async fn check_rate_limit(
@@ -10,7 +24,7 @@ pub trait RateLimitMiddlewareTrait {
         key: &str,
         max_requests: u32,
         window_seconds: u32,
-    ) -> (bool, u32);
+    ) -> RateLimitStatus;
     async fn get_remaining(&self, key: &str, max_requests: u32) -> u32;
     async fn reset_limit(&self, key: &str) -> bool;
 }
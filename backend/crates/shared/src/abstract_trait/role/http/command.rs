@@ -1,7 +1,7 @@
 use crate::{
     domain::{
         requests::role::{CreateRoleRequest, UpdateRoleRequest},
-        responses::{ApiResponse, RoleResponse, RoleResponseDeleteAt},
+        responses::{ApiResponse, BulkResult, RoleResponse, RoleResponseDeleteAt},
     },
     errors::HttpError,
 };
@@ -21,6 +21,6 @@ pub trait RoleCommandGrpcClientTrait {
     async fn trash(&self, id: i32) -> Result<ApiResponse<RoleResponseDeleteAt>, HttpError>;
     async fn restore(&self, id: i32) -> Result<ApiResponse<RoleResponseDeleteAt>, HttpError>;
     async fn delete(&self, id: i32) -> Result<ApiResponse<bool>, HttpError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
 }
@@ -1,6 +1,7 @@
 use crate::{
     domain::requests::role::{CreateRoleRequest, UpdateRoleRequest},
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::role::RoleModel,
 };
 use anyhow::Result;
@@ -16,6 +17,6 @@ pub trait RoleCommandRepositoryTrait {
     async fn trash(&self, id: i32) -> Result<RoleModel, RepositoryError>;
     async fn restore(&self, id: i32) -> Result<RoleModel, RepositoryError>;
     async fn delete_permanent(&self, id: i32) -> Result<(), RepositoryError>;
-    async fn restore_all(&self) -> Result<(), RepositoryError>;
-    async fn delete_all(&self) -> Result<(), RepositoryError>;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError>;
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError>;
 }
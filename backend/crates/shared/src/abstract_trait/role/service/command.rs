@@ -1,7 +1,7 @@
 use crate::{
     domain::{
         requests::role::{CreateRoleRequest, UpdateRoleRequest},
-        responses::{ApiResponse, RoleResponse, RoleResponseDeleteAt},
+        responses::{ApiResponse, BulkResult, RoleResponse, RoleResponseDeleteAt},
     },
     errors::ServiceError,
 };
@@ -24,6 +24,6 @@ pub trait RoleCommandServiceTrait {
     async fn trash(&self, id: i32) -> Result<ApiResponse<RoleResponseDeleteAt>, ServiceError>;
     async fn restore(&self, id: i32) -> Result<ApiResponse<RoleResponseDeleteAt>, ServiceError>;
     async fn delete(&self, id: i32) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
 }
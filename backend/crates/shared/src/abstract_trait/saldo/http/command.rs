@@ -1,7 +1,7 @@
 use crate::{
     domain::{
         requests::saldo::{CreateSaldoRequest, UpdateSaldoRequest},
-        responses::{ApiResponse, SaldoResponse, SaldoResponseDeleteAt},
+        responses::{ApiResponse, BulkResult, SaldoResponse, SaldoResponseDeleteAt},
     },
     errors::HttpError,
 };
@@ -21,6 +21,6 @@ pub trait SaldoCommandGrpcClientTrait {
     async fn trash(&self, id: i32) -> Result<ApiResponse<SaldoResponseDeleteAt>, HttpError>;
     async fn restore(&self, id: i32) -> Result<ApiResponse<SaldoResponseDeleteAt>, HttpError>;
     async fn delete_permanent(&self, id: i32) -> Result<ApiResponse<bool>, HttpError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
 }
@@ -1,7 +1,10 @@
 use crate::{
     domain::{
-        requests::saldo::FindAllSaldos,
-        responses::{ApiResponse, ApiResponsePagination, SaldoResponse, SaldoResponseDeleteAt},
+        requests::saldo::{FindAllSaldos, FindCardStatement, FindSaldoBalances},
+        responses::{
+            ApiResponse, ApiResponsePagination, CardStatementResponse, SaldoBalancesResponse,
+            SaldoResponse, SaldoResponseDeleteAt,
+        },
     },
     errors::HttpError,
 };
@@ -27,4 +30,12 @@ pub trait SaldoQueryGrpcClientTrait {
         &self,
         card_number: &str,
     ) -> Result<ApiResponse<SaldoResponse>, HttpError>;
+    async fn get_balances(
+        &self,
+        request: &FindSaldoBalances,
+    ) -> Result<ApiResponse<SaldoBalancesResponse>, HttpError>;
+    async fn get_statement(
+        &self,
+        request: &FindCardStatement,
+    ) -> Result<ApiResponse<CardStatementResponse>, HttpError>;
 }
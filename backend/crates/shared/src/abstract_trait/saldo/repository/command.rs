@@ -1,8 +1,10 @@
 use crate::{
     domain::requests::saldo::{
-        CreateSaldoRequest, UpdateSaldoBalance, UpdateSaldoRequest, UpdateSaldoWithdraw,
+        ApplySaldoLedgerEntry, ApplySaldoTransfer, CreateSaldoRequest, UpdateSaldoBalance,
+        UpdateSaldoRequest, UpdateSaldoWithdraw,
     },
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::saldo::SaldoModel,
 };
 use anyhow::Result;
@@ -21,9 +23,22 @@ pub trait SaldoCommandRepositoryTrait {
         &self,
         req: &UpdateSaldoWithdraw,
     ) -> Result<SaldoModel, RepositoryError>;
+    async fn withdraw_with_ledger(
+        &self,
+        req: &UpdateSaldoWithdraw,
+        withdraw_id: i32,
+    ) -> Result<SaldoModel, RepositoryError>;
+    async fn apply_ledger_entry(
+        &self,
+        entry: &ApplySaldoLedgerEntry,
+    ) -> Result<SaldoModel, RepositoryError>;
     async fn trash(&self, id: i32) -> Result<SaldoModel, RepositoryError>;
     async fn restore(&self, id: i32) -> Result<SaldoModel, RepositoryError>;
     async fn delete_permanent(&self, id: i32) -> Result<(), RepositoryError>;
-    async fn restore_all(&self) -> Result<(), RepositoryError>;
-    async fn delete_all(&self) -> Result<(), RepositoryError>;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError>;
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError>;
+    async fn transfer_with_ledger(
+        &self,
+        req: &ApplySaldoTransfer,
+    ) -> Result<(SaldoModel, SaldoModel), RepositoryError>;
 }
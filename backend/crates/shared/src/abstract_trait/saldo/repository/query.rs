@@ -1,8 +1,11 @@
 use crate::{
-    domain::requests::saldo::FindAllSaldos, errors::RepositoryError, model::saldo::SaldoModel,
+    domain::requests::saldo::FindAllSaldos,
+    errors::RepositoryError,
+    model::saldo::{SaldoModel, SaldoStatementModel},
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use std::sync::Arc;
 
 pub type DynSaldoQueryRepository = Arc<dyn SaldoQueryRepositoryTrait + Send + Sync>;
@@ -23,4 +26,14 @@ pub trait SaldoQueryRepositoryTrait {
     ) -> Result<(Vec<SaldoModel>, i64), RepositoryError>;
     async fn find_by_card(&self, card_number: &str) -> Result<SaldoModel, RepositoryError>;
     async fn find_by_id(&self, id: i32) -> Result<SaldoModel, RepositoryError>;
+    async fn find_balances_by_cards(
+        &self,
+        card_numbers: &[String],
+    ) -> Result<Vec<(String, i64)>, RepositoryError>;
+    async fn find_statement(
+        &self,
+        card_number: &str,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    ) -> Result<SaldoStatementModel, RepositoryError>;
 }
@@ -1,7 +1,7 @@
 use crate::{
     domain::{
         requests::saldo::{CreateSaldoRequest, UpdateSaldoRequest},
-        responses::{ApiResponse, SaldoResponse, SaldoResponseDeleteAt},
+        responses::{ApiResponse, BulkResult, SaldoResponse, SaldoResponseDeleteAt},
     },
     errors::ServiceError,
 };
@@ -24,6 +24,6 @@ pub trait SaldoCommandServiceTrait {
     async fn trash(&self, id: i32) -> Result<ApiResponse<SaldoResponseDeleteAt>, ServiceError>;
     async fn restore(&self, id: i32) -> Result<ApiResponse<SaldoResponseDeleteAt>, ServiceError>;
     async fn delete(&self, id: i32) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
 }
@@ -1,7 +1,10 @@
 use crate::{
     domain::{
-        requests::saldo::FindAllSaldos,
-        responses::{ApiResponse, ApiResponsePagination, SaldoResponse, SaldoResponseDeleteAt},
+        requests::saldo::{FindAllSaldos, FindCardStatement, FindSaldoBalances},
+        responses::{
+            ApiResponse, ApiResponsePagination, CardStatementResponse, SaldoBalancesResponse,
+            SaldoResponse, SaldoResponseDeleteAt,
+        },
     },
     errors::ServiceError,
 };
@@ -30,4 +33,12 @@ pub trait SaldoQueryServiceTrait {
         card_number: &str,
     ) -> Result<ApiResponse<SaldoResponse>, ServiceError>;
     async fn find_by_id(&self, id: i32) -> Result<ApiResponse<SaldoResponse>, ServiceError>;
+    async fn get_balances(
+        &self,
+        request: &FindSaldoBalances,
+    ) -> Result<ApiResponse<SaldoBalancesResponse>, ServiceError>;
+    async fn get_statement(
+        &self,
+        request: &FindCardStatement,
+    ) -> Result<ApiResponse<CardStatementResponse>, ServiceError>;
 }
@@ -11,4 +11,15 @@ pub type DynTokenService = Arc<dyn TokenServiceTrait + Send + Sync>;
 pub trait TokenServiceTrait {
     async fn create_access_token(&self, id: i32) -> Result<String, ServiceError>;
     async fn create_refresh_token(&self, id: i32) -> Result<String, ServiceError>;
+    async fn renew_access_token(
+        &self,
+        id: i32,
+        auth_time: Option<usize>,
+    ) -> Result<String, ServiceError>;
+    async fn renew_refresh_token(
+        &self,
+        id: i32,
+        auth_time: Option<usize>,
+    ) -> Result<String, ServiceError>;
+    async fn create_password_change_token(&self, id: i32) -> Result<String, ServiceError>;
 }
@@ -1,6 +1,6 @@
 use crate::{
     domain::requests::topup::{CreateTopupRequest, UpdateTopupRequest},
-    domain::responses::{ApiResponse, TopupResponse, TopupResponseDeleteAt},
+    domain::responses::{ApiResponse, BulkResult, TopupResponse, TopupResponseDeleteAt},
     errors::HttpError,
 };
 use anyhow::Result;
@@ -21,6 +21,6 @@ pub trait TopupCommandGrpcClientTrait {
     async fn restore(&self, topup_id: i32)
     -> Result<ApiResponse<TopupResponseDeleteAt>, HttpError>;
     async fn delete_permanent(&self, topup_id: i32) -> Result<ApiResponse<bool>, HttpError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError>;
-    async fn delete_all_permanent(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
+    async fn delete_all_permanent(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
 }
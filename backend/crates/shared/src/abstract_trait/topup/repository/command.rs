@@ -3,6 +3,7 @@ use crate::{
         CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest, UpdateTopupStatus,
     },
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::topup::TopupModel,
 };
 use anyhow::Result;
@@ -20,6 +21,6 @@ pub trait TopupCommandRepositoryTrait {
     async fn trashed(&self, topup_id: i32) -> Result<TopupModel, RepositoryError>;
     async fn restore(&self, topup_id: i32) -> Result<TopupModel, RepositoryError>;
     async fn delete_permanent(&self, topup_id: i32) -> Result<bool, RepositoryError>;
-    async fn restore_all(&self) -> Result<bool, RepositoryError>;
-    async fn delete_all(&self) -> Result<bool, RepositoryError>;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError>;
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError>;
 }
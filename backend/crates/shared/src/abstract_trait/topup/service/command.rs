@@ -1,6 +1,6 @@
 use crate::{
     domain::requests::topup::{CreateTopupRequest, UpdateTopupRequest},
-    domain::responses::{ApiResponse, TopupResponse, TopupResponseDeleteAt},
+    domain::responses::{ApiResponse, BulkResult, TopupResponse, TopupResponseDeleteAt},
     errors::ServiceError,
 };
 use anyhow::Result;
@@ -28,6 +28,6 @@ pub trait TopupCommandServiceTrait {
         topup_id: i32,
     ) -> Result<ApiResponse<TopupResponseDeleteAt>, ServiceError>;
     async fn delete_permanent(&self, topup_id: i32) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
 }
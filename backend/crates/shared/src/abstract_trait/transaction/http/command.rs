@@ -1,6 +1,8 @@
 use crate::{
     domain::requests::transaction::{CreateTransactionRequest, UpdateTransactionRequest},
-    domain::responses::{ApiResponse, TransactionResponse, TransactionResponseDeleteAt},
+    domain::responses::{
+        ApiResponse, BulkResult, TransactionResponse, TransactionResponseDeleteAt,
+    },
     errors::HttpError,
 };
 use anyhow::Result;
@@ -27,6 +29,6 @@ pub trait TransactionCommandGrpcClientTrait {
         transaction_id: i32,
     ) -> Result<ApiResponse<TransactionResponseDeleteAt>, HttpError>;
     async fn delete_permanent(&self, transaction_id: i32) -> Result<ApiResponse<bool>, HttpError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
 }
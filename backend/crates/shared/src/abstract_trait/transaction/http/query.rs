@@ -1,6 +1,8 @@
 use crate::{
     domain::{
-        requests::transaction::{FindAllTransactionCardNumber, FindAllTransactions},
+        requests::transaction::{
+            FindAllTransactionCardNumber, FindAllTransactions, SearchTransactions,
+        },
         responses::{
             ApiResponse, ApiResponsePagination, TransactionResponse, TransactionResponseDeleteAt,
         },
@@ -28,6 +30,10 @@ pub trait TransactionQueryGrpcClientTrait {
         &self,
         req: &FindAllTransactions,
     ) -> Result<ApiResponsePagination<Vec<TransactionResponseDeleteAt>>, HttpError>;
+    async fn search(
+        &self,
+        req: &SearchTransactions,
+    ) -> Result<ApiResponsePagination<Vec<TransactionResponse>>, HttpError>;
     async fn find_by_id(
         &self,
         transaction_id: i32,
@@ -3,10 +3,12 @@ use crate::{
         CreateTransactionRequest, UpdateTransactionRequest, UpdateTransactionStatus,
     },
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::transaction::TransactionModel,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use std::sync::Arc;
 
 pub type DynTransactionCommandRepository = Arc<dyn TransactionCommandRepositoryTrait + Send + Sync>;
@@ -34,7 +36,13 @@ pub trait TransactionCommandRepositoryTrait {
 
     async fn delete_permanent(&self, transaction_id: i32) -> Result<bool, RepositoryError>;
 
-    async fn restore_all(&self) -> Result<bool, RepositoryError>;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError>;
 
-    async fn delete_all(&self) -> Result<bool, RepositoryError>;
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError>;
+
+    async fn expire_pending_before(
+        &self,
+        cutoff: NaiveDateTime,
+        limit: i64,
+    ) -> Result<Vec<TransactionModel>, RepositoryError>;
 }
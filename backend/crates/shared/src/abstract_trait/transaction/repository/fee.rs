@@ -0,0 +1,15 @@
+use crate::{errors::RepositoryError, model::transaction::TransactionFeeModel};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub type DynTransactionFeeRepository = Arc<dyn TransactionFeeRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait TransactionFeeRepositoryTrait {
+    async fn complete_with_fee(&self, fee: &TransactionFeeModel) -> Result<(), RepositoryError>;
+    async fn find_by_transaction_id(
+        &self,
+        transaction_id: i32,
+    ) -> Result<Option<TransactionFeeModel>, RepositoryError>;
+}
@@ -0,0 +1,18 @@
+use crate::errors::RepositoryError;
+use anyhow::Result;
+use async_trait::async_trait;
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+pub type DynTransactionMetadataRepository =
+    Arc<dyn TransactionMetadataRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait TransactionMetadataRepositoryTrait {
+    async fn find_by_transaction_ids(
+        &self,
+        transaction_ids: &[i32],
+    ) -> Result<HashMap<i32, BTreeMap<String, String>>, RepositoryError>;
+}
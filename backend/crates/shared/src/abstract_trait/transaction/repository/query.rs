@@ -1,5 +1,7 @@
 use crate::{
-    domain::requests::transaction::{FindAllTransactionCardNumber, FindAllTransactions},
+    domain::requests::transaction::{
+        FindAllTransactionCardNumber, FindAllTransactions, SearchTransactions,
+    },
     errors::RepositoryError,
     model::transaction::TransactionModel,
 };
@@ -31,6 +33,11 @@ pub trait TransactionQueryRepositoryTrait {
         req: &FindAllTransactionCardNumber,
     ) -> Result<(Vec<TransactionModel>, i64), RepositoryError>;
 
+    async fn search(
+        &self,
+        req: &SearchTransactions,
+    ) -> Result<(Vec<TransactionModel>, i64), RepositoryError>;
+
     async fn find_by_id(&self, transaction_id: i32) -> Result<TransactionModel, RepositoryError>;
 
     async fn find_by_merchant_id(
@@ -1,7 +1,7 @@
 use crate::{
     domain::{
         requests::transaction::{CreateTransactionRequest, UpdateTransactionRequest},
-        responses::{ApiResponse, TransactionResponse, TransactionResponseDeleteAt},
+        responses::{ApiResponse, BulkResult, TransactionResponse, TransactionResponseDeleteAt},
     },
     errors::ServiceError,
 };
@@ -35,6 +35,6 @@ pub trait TransactionCommandServiceTrait {
         &self,
         transaction_id: i32,
     ) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
 }
@@ -1,6 +1,8 @@
 use crate::{
     domain::{
-        requests::transaction::{FindAllTransactionCardNumber, FindAllTransactions},
+        requests::transaction::{
+            FindAllTransactionCardNumber, FindAllTransactions, SearchTransactions,
+        },
         responses::{
             ApiResponse, ApiResponsePagination, TransactionResponse, TransactionResponseDeleteAt,
         },
@@ -31,6 +33,10 @@ pub trait TransactionQueryServiceTrait {
         &self,
         req: &FindAllTransactions,
     ) -> Result<ApiResponsePagination<Vec<TransactionResponseDeleteAt>>, ServiceError>;
+    async fn search(
+        &self,
+        req: &SearchTransactions,
+    ) -> Result<ApiResponsePagination<Vec<TransactionResponse>>, ServiceError>;
     async fn find_by_id(
         &self,
         transaction_id: i32,
@@ -1,6 +1,8 @@
 use crate::{
     domain::requests::transfer::{CreateTransferRequest, UpdateTransferRequest},
-    domain::responses::{ApiResponse, TransferResponse, TransferResponseDeleteAt},
+    domain::responses::{
+        ApiResponse, BalanceImpactResponse, BulkResult, TransferResponse, TransferResponseDeleteAt,
+    },
     errors::HttpError,
 };
 use anyhow::Result;
@@ -8,6 +10,10 @@ use async_trait::async_trait;
 
 #[async_trait]
 pub trait TransferCommandGrpcClientTrait {
+    async fn preview(
+        &self,
+        req: &CreateTransferRequest,
+    ) -> Result<ApiResponse<BalanceImpactResponse>, HttpError>;
     async fn create(
         &self,
         req: &CreateTransferRequest,
@@ -28,7 +34,7 @@ pub trait TransferCommandGrpcClientTrait {
         transfer_id: i32,
     ) -> Result<ApiResponse<TransferResponseDeleteAt>, HttpError>;
     async fn delete_permanent(&self, transfer_id: i32) -> Result<ApiResponse<bool>, HttpError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
 }
@@ -1,8 +1,9 @@
 use crate::{
     domain::{
-        requests::transfer::FindAllTransfers,
+        requests::transfer::{FindAllTransfers, FindTransferNetPosition, FindTransfers},
         responses::{
-            ApiResponse, ApiResponsePagination, TransferResponse, TransferResponseDeleteAt,
+            ApiResponse, ApiResponsePagination, TransferNetPositionResponse, TransferResponse,
+            TransferResponseDeleteAt,
         },
     },
     errors::HttpError,
@@ -32,6 +33,11 @@ pub trait TransferQueryGrpcClientTrait {
         req: &FindAllTransfers,
     ) -> Result<ApiResponsePagination<Vec<TransferResponseDeleteAt>>, HttpError>;
 
+    async fn find_transfers(
+        &self,
+        req: &FindTransfers,
+    ) -> Result<ApiResponsePagination<Vec<TransferResponse>>, HttpError>;
+
     async fn find_by_transfer_from(
         &self,
         transfer_from: &str,
@@ -41,4 +47,9 @@ pub trait TransferQueryGrpcClientTrait {
         &self,
         transfer_to: &str,
     ) -> Result<ApiResponse<Vec<TransferResponse>>, HttpError>;
+
+    async fn net_position(
+        &self,
+        req: &FindTransferNetPosition,
+    ) -> Result<ApiResponse<TransferNetPositionResponse>, HttpError>;
 }
@@ -4,6 +4,7 @@ use crate::{
         UpdateTransferStatus,
     },
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::transfer::TransferModel,
 };
 use anyhow::Result;
@@ -27,6 +28,6 @@ pub trait TransferCommandRepositoryTrait {
     async fn trashed(&self, transfer_id: i32) -> Result<TransferModel, RepositoryError>;
     async fn restore(&self, transfer_id: i32) -> Result<TransferModel, RepositoryError>;
     async fn delete_permanent(&self, transfer_id: i32) -> Result<bool, RepositoryError>;
-    async fn restore_all(&self) -> Result<bool, RepositoryError>;
-    async fn delete_all(&self) -> Result<bool, RepositoryError>;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError>;
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError>;
 }
@@ -1,6 +1,7 @@
 use crate::{
-    domain::requests::transfer::FindAllTransfers, errors::RepositoryError,
-    model::transfer::TransferModel,
+    domain::requests::transfer::{FindAllTransfers, FindTransferNetPosition, FindTransfers},
+    errors::RepositoryError,
+    model::transfer::{TransferModel, TransferNetPositionModel},
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -23,6 +24,10 @@ pub trait TransferQueryRepositoryTrait {
         &self,
         req: &FindAllTransfers,
     ) -> Result<(Vec<TransferModel>, i64), RepositoryError>;
+    async fn find_transfers(
+        &self,
+        req: &FindTransfers,
+    ) -> Result<(Vec<TransferModel>, i64), RepositoryError>;
     async fn find_by_id(&self, id: i32) -> Result<TransferModel, RepositoryError>;
     async fn find_by_transfer_from(
         &self,
@@ -32,4 +37,8 @@ pub trait TransferQueryRepositoryTrait {
         &self,
         transfer_to: &str,
     ) -> Result<Vec<TransferModel>, RepositoryError>;
+    async fn get_net_position(
+        &self,
+        req: &FindTransferNetPosition,
+    ) -> Result<TransferNetPositionModel, RepositoryError>;
 }
@@ -1,6 +1,8 @@
 use crate::{
     domain::requests::transfer::{CreateTransferRequest, UpdateTransferRequest},
-    domain::responses::{ApiResponse, TransferResponse, TransferResponseDeleteAt},
+    domain::responses::{
+        ApiResponse, BalanceImpactResponse, BulkResult, TransferResponse, TransferResponseDeleteAt,
+    },
     errors::ServiceError,
 };
 use anyhow::Result;
@@ -11,6 +13,11 @@ pub type DynTransferCommandService = Arc<dyn TransferCommandServiceTrait + Send
 
 #[async_trait]
 pub trait TransferCommandServiceTrait {
+    async fn preview(
+        &self,
+        req: &CreateTransferRequest,
+    ) -> Result<ApiResponse<BalanceImpactResponse>, ServiceError>;
+
     async fn create(
         &self,
         req: &CreateTransferRequest,
@@ -31,7 +38,7 @@ pub trait TransferCommandServiceTrait {
         transfer_id: i32,
     ) -> Result<ApiResponse<TransferResponseDeleteAt>, ServiceError>;
     async fn delete_permanent(&self, transfer_id: i32) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
 }
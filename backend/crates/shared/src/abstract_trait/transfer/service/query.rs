@@ -1,8 +1,9 @@
 use crate::{
     domain::{
-        requests::transfer::FindAllTransfers,
+        requests::transfer::{FindAllTransfers, FindTransferNetPosition, FindTransfers},
         responses::{
-            ApiResponse, ApiResponsePagination, TransferResponse, TransferResponseDeleteAt,
+            ApiResponse, ApiResponsePagination, TransferNetPositionResponse, TransferResponse,
+            TransferResponseDeleteAt,
         },
     },
     errors::ServiceError,
@@ -35,6 +36,11 @@ pub trait TransferQueryServiceTrait {
         req: &FindAllTransfers,
     ) -> Result<ApiResponsePagination<Vec<TransferResponseDeleteAt>>, ServiceError>;
 
+    async fn find_transfers(
+        &self,
+        req: &FindTransfers,
+    ) -> Result<ApiResponsePagination<Vec<TransferResponse>>, ServiceError>;
+
     async fn find_by_transfer_from(
         &self,
         transfer_from: &str,
@@ -44,4 +50,9 @@ pub trait TransferQueryServiceTrait {
         &self,
         transfer_to: &str,
     ) -> Result<ApiResponse<Vec<TransferResponse>>, ServiceError>;
+
+    async fn net_position(
+        &self,
+        req: &FindTransferNetPosition,
+    ) -> Result<ApiResponse<TransferNetPositionResponse>, ServiceError>;
 }
@@ -1,6 +1,8 @@
 use crate::{
-    domain::requests::user::{CreateUserRequest, UpdateUserRequest},
-    domain::responses::{ApiResponse, UserResponse, UserResponseDeleteAt},
+    domain::requests::user::{CreateUserRequest, MergeUsersRequest, UpdateUserRequest},
+    domain::responses::{
+        ApiResponse, BulkResult, UserMergeResponse, UserResponse, UserResponseDeleteAt,
+    },
     errors::HttpError,
 };
 use anyhow::Result;
@@ -18,9 +20,17 @@ pub trait UserCommandGrpcClientTrait {
 
     async fn restore(&self, user_id: i32) -> Result<ApiResponse<UserResponseDeleteAt>, HttpError>;
 
+    async fn anonymize(&self, user_id: i32)
+    -> Result<ApiResponse<UserResponseDeleteAt>, HttpError>;
+
+    async fn merge(
+        &self,
+        req: &MergeUsersRequest,
+    ) -> Result<ApiResponse<UserMergeResponse>, HttpError>;
+
     async fn delete_permanent(&self, user_id: i32) -> Result<ApiResponse<bool>, HttpError>;
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
 }
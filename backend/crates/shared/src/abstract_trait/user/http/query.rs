@@ -1,7 +1,10 @@
 use crate::{
     domain::{
         requests::user::FindAllUserRequest,
-        responses::{ApiResponse, ApiResponsePagination, UserResponse, UserResponseDeleteAt},
+        responses::{
+            ApiResponse, ApiResponsePagination, UserDataExportResponse, UserResponse,
+            UserResponseDeleteAt,
+        },
     },
     errors::HttpError,
 };
@@ -17,6 +20,11 @@ pub trait UserQueryGrpcClientTrait {
 
     async fn find_by_id(&self, user_id: i32) -> Result<ApiResponse<UserResponse>, HttpError>;
 
+    async fn export_user_data(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<UserDataExportResponse>, HttpError>;
+
     async fn find_by_active(
         &self,
         req: &FindAllUserRequest,
@@ -0,0 +1,34 @@
+use crate::{errors::RepositoryError, model::user::UserModel};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub type DynUserAnonymizationRepository = Arc<dyn UserAnonymizationRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserAnonymizationRepositoryTrait {
+    async fn anonymize(
+        &self,
+        user_id: i32,
+        placeholder: &AnonymizedIdentity,
+    ) -> Result<UserModel, RepositoryError>;
+}
+
+#[derive(Debug, Clone)]
+pub struct AnonymizedIdentity {
+    pub firstname: String,
+    pub lastname: String,
+    pub email: String,
+    pub password: String,
+}
+
+impl AnonymizedIdentity {
+    pub fn for_user(user_id: i32) -> Self {
+        Self {
+            firstname: "Deleted".to_string(),
+            lastname: "User".to_string(),
+            email: format!("deleted-user-{user_id}@anonymized.invalid"),
+            password: "!anonymized".to_string(),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     domain::requests::user::{CreateUserRequest, UpdateUserRequest},
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::user::UserModel,
 };
 use anyhow::Result;
@@ -16,6 +17,6 @@ pub trait UserCommandRepositoryTrait {
     async fn trashed(&self, user_id: i32) -> Result<UserModel, RepositoryError>;
     async fn restore(&self, user_id: i32) -> Result<UserModel, RepositoryError>;
     async fn delete_permanent(&self, user_id: i32) -> Result<bool, RepositoryError>;
-    async fn restore_all(&self) -> Result<bool, RepositoryError>;
-    async fn delete_all(&self) -> Result<bool, RepositoryError>;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError>;
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError>;
 }
@@ -0,0 +1,16 @@
+use crate::errors::RepositoryError;
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub type DynUserCredentialRepository = Arc<dyn UserCredentialRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserCredentialRepositoryTrait {
+    async fn must_change_password(&self, user_id: i32) -> Result<bool, RepositoryError>;
+    async fn update_password(
+        &self,
+        user_id: i32,
+        password_hash: &str,
+    ) -> Result<(), RepositoryError>;
+}
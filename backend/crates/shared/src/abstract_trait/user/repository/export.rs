@@ -0,0 +1,18 @@
+use crate::{
+    errors::RepositoryError,
+    model::user::{UserExportCardModel, UserTransactionSummaryModel},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub type DynUserExportRepository = Arc<dyn UserExportRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserExportRepositoryTrait {
+    async fn find_cards(&self, user_id: i32) -> Result<Vec<UserExportCardModel>, RepositoryError>;
+    async fn transaction_summaries(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<UserTransactionSummaryModel>, RepositoryError>;
+}
@@ -0,0 +1,15 @@
+use crate::{errors::RepositoryError, model::user::UserMergeModel};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub type DynUserMergeRepository = Arc<dyn UserMergeRepositoryTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserMergeRepositoryTrait {
+    async fn merge(
+        &self,
+        primary_id: i32,
+        duplicate_id: i32,
+    ) -> Result<UserMergeModel, RepositoryError>;
+}
@@ -1,6 +1,8 @@
 use crate::{
-    domain::requests::user::{CreateUserRequest, UpdateUserRequest},
-    domain::responses::{ApiResponse, UserResponse, UserResponseDeleteAt},
+    domain::requests::user::{CreateUserRequest, MergeUsersRequest, UpdateUserRequest},
+    domain::responses::{
+        ApiResponse, BulkResult, UserMergeResponse, UserResponse, UserResponseDeleteAt,
+    },
     errors::ServiceError,
 };
 use anyhow::Result;
@@ -31,9 +33,19 @@ pub trait UserCommandServiceTrait {
         user_id: i32,
     ) -> Result<ApiResponse<UserResponseDeleteAt>, ServiceError>;
 
+    async fn anonymize(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<UserResponseDeleteAt>, ServiceError>;
+
+    async fn merge(
+        &self,
+        req: &MergeUsersRequest,
+    ) -> Result<ApiResponse<UserMergeResponse>, ServiceError>;
+
     async fn delete_permanent(&self, user_id: i32) -> Result<ApiResponse<bool>, ServiceError>;
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
 }
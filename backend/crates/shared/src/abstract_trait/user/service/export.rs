@@ -0,0 +1,17 @@
+use crate::{
+    domain::responses::{ApiResponse, UserDataExportResponse},
+    errors::ServiceError,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub type DynUserExportService = Arc<dyn UserExportServiceTrait + Send + Sync>;
+
+#[async_trait]
+pub trait UserExportServiceTrait {
+    async fn export_user_data(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<UserDataExportResponse>, ServiceError>;
+}
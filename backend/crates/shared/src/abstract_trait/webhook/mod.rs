@@ -0,0 +1,11 @@
+use crate::{errors::ServiceError, events::DomainEvent};
+use async_trait::async_trait;
+use std::sync::Arc;
+
+pub type DynWebhookDispatcher = Arc<dyn WebhookDispatcherTrait + Send + Sync>;
+
+#[async_trait]
+pub trait WebhookDispatcherTrait {
+    async fn dispatch(&self, url: &str, event: &DomainEvent) -> Result<(), ServiceError>;
+    async fn shutdown(&self) {}
+}
@@ -1,6 +1,8 @@
 use crate::{
     domain::requests::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
-    domain::responses::{ApiResponse, WithdrawResponse, WithdrawResponseDeleteAt},
+    domain::responses::{
+        ApiResponse, BalanceImpactResponse, BulkResult, WithdrawResponse, WithdrawResponseDeleteAt,
+    },
     errors::HttpError,
 };
 use anyhow::Result;
@@ -8,6 +10,10 @@ use async_trait::async_trait;
 
 #[async_trait]
 pub trait WithdrawCommandGrpcClientTrait {
+    async fn preview(
+        &self,
+        req: &CreateWithdrawRequest,
+    ) -> Result<ApiResponse<BalanceImpactResponse>, HttpError>;
     async fn create(
         &self,
         req: &CreateWithdrawRequest,
@@ -16,6 +22,10 @@ pub trait WithdrawCommandGrpcClientTrait {
         &self,
         req: &UpdateWithdrawRequest,
     ) -> Result<ApiResponse<WithdrawResponse>, HttpError>;
+    async fn cancel_withdraw(
+        &self,
+        withdraw_id: i32,
+    ) -> Result<ApiResponse<WithdrawResponse>, HttpError>;
     async fn trashed_withdraw(
         &self,
         withdraw_id: i32,
@@ -25,6 +35,6 @@ pub trait WithdrawCommandGrpcClientTrait {
         withdraw_id: i32,
     ) -> Result<ApiResponse<WithdrawResponseDeleteAt>, HttpError>;
     async fn delete_permanent(&self, withdraw_id: i32) -> Result<ApiResponse<bool>, HttpError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, HttpError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, HttpError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, HttpError>;
 }
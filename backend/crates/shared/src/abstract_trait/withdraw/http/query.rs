@@ -1,6 +1,6 @@
 use crate::{
     domain::{
-        requests::withdraw::{FindAllWithdrawCardNumber, FindAllWithdraws},
+        requests::withdraw::{FindAllWithdrawCardNumber, FindAllWithdraws, FindWithdraws},
         responses::{
             ApiResponse, ApiResponsePagination, WithdrawResponse, WithdrawResponseDeleteAt,
         },
@@ -20,6 +20,10 @@ pub trait WithdrawQueryGrpcClientTrait {
         &self,
         req: &FindAllWithdrawCardNumber,
     ) -> Result<ApiResponsePagination<Vec<WithdrawResponse>>, HttpError>;
+    async fn find_withdraws(
+        &self,
+        req: &FindWithdraws,
+    ) -> Result<ApiResponsePagination<Vec<WithdrawResponse>>, HttpError>;
     async fn find_by_id(
         &self,
         withdraw_id: i32,
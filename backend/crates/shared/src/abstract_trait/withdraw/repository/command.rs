@@ -3,10 +3,12 @@ use crate::{
         CreateWithdrawRequest, UpdateWithdrawRequest, UpdateWithdrawStatus,
     },
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::withdraw::WithdrawModel,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use std::sync::Arc;
 
 pub type DynWithdrawCommandRepository = Arc<dyn WithdrawCommandRepositoryTrait + Send + Sync>;
@@ -25,6 +27,11 @@ pub trait WithdrawCommandRepositoryTrait {
     async fn trashed(&self, withdraw_id: i32) -> Result<WithdrawModel, RepositoryError>;
     async fn restore(&self, withdraw_id: i32) -> Result<WithdrawModel, RepositoryError>;
     async fn delete_permanent(&self, withdraw_id: i32) -> Result<bool, RepositoryError>;
-    async fn restore_all(&self) -> Result<bool, RepositoryError>;
-    async fn delete_all(&self) -> Result<bool, RepositoryError>;
+    async fn cancel(
+        &self,
+        withdraw_id: i32,
+        created_after: NaiveDateTime,
+    ) -> Result<Option<WithdrawModel>, RepositoryError>;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError>;
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError>;
 }
@@ -1,5 +1,5 @@
 use crate::{
-    domain::requests::withdraw::{FindAllWithdrawCardNumber, FindAllWithdraws},
+    domain::requests::withdraw::{FindAllWithdrawCardNumber, FindAllWithdraws, FindWithdraws},
     errors::RepositoryError,
     model::withdraw::WithdrawModel,
 };
@@ -31,6 +31,11 @@ pub trait WithdrawQueryRepositoryTrait {
         req: &FindAllWithdrawCardNumber,
     ) -> Result<(Vec<WithdrawModel>, i64), RepositoryError>;
 
+    async fn find_withdraws(
+        &self,
+        req: &FindWithdraws,
+    ) -> Result<(Vec<WithdrawModel>, i64), RepositoryError>;
+
     async fn find_by_id(&self, id: i32) -> Result<WithdrawModel, RepositoryError>;
 
     async fn find_by_card(&self, card_number: &str) -> Result<Vec<WithdrawModel>, RepositoryError>;
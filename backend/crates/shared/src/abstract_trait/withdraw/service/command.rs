@@ -1,6 +1,8 @@
 use crate::{
     domain::requests::withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest},
-    domain::responses::{ApiResponse, WithdrawResponse, WithdrawResponseDeleteAt},
+    domain::responses::{
+        ApiResponse, BalanceImpactResponse, BulkResult, WithdrawResponse, WithdrawResponseDeleteAt,
+    },
     errors::ServiceError,
 };
 use anyhow::Result;
@@ -11,6 +13,10 @@ pub type DynWithdrawCommandService = Arc<dyn WithdrawCommandServiceTrait + Send
 
 #[async_trait]
 pub trait WithdrawCommandServiceTrait {
+    async fn preview(
+        &self,
+        req: &CreateWithdrawRequest,
+    ) -> Result<ApiResponse<BalanceImpactResponse>, ServiceError>;
     async fn create(
         &self,
         req: &CreateWithdrawRequest,
@@ -19,6 +25,10 @@ pub trait WithdrawCommandServiceTrait {
         &self,
         req: &UpdateWithdrawRequest,
     ) -> Result<ApiResponse<WithdrawResponse>, ServiceError>;
+    async fn cancel_withdraw(
+        &self,
+        withdraw_id: i32,
+    ) -> Result<ApiResponse<WithdrawResponse>, ServiceError>;
     async fn trashed_withdraw(
         &self,
         withdraw_id: i32,
@@ -28,6 +38,6 @@ pub trait WithdrawCommandServiceTrait {
         withdraw_id: i32,
     ) -> Result<ApiResponse<WithdrawResponseDeleteAt>, ServiceError>;
     async fn delete_permanent(&self, withdraw_id: i32) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError>;
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError>;
 }
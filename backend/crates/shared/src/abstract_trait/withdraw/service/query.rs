@@ -1,6 +1,6 @@
 use crate::{
     domain::{
-        requests::withdraw::{FindAllWithdrawCardNumber, FindAllWithdraws},
+        requests::withdraw::{FindAllWithdrawCardNumber, FindAllWithdraws, FindWithdraws},
         responses::{
             ApiResponse, ApiResponsePagination, WithdrawResponse, WithdrawResponseDeleteAt,
         },
@@ -23,6 +23,10 @@ pub trait WithdrawQueryServiceTrait {
         &self,
         req: &FindAllWithdrawCardNumber,
     ) -> Result<ApiResponsePagination<Vec<WithdrawResponse>>, ServiceError>;
+    async fn find_withdraws(
+        &self,
+        req: &FindWithdraws,
+    ) -> Result<ApiResponsePagination<Vec<WithdrawResponse>>, ServiceError>;
     async fn find_by_id(
         &self,
         withdraw_id: i32,
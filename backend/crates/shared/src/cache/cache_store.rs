@@ -1,18 +1,31 @@
 use anyhow::{Context, Result};
 use chrono::Duration;
-use deadpool_redis::{Connection, Pool};
+use deadpool_redis::{Connection, Pool, redis::Script};
 use redis::AsyncCommands;
 use serde::{Serialize, de::DeserializeOwned};
 use std::sync::Arc;
 use tokio::time::Instant;
 use tracing::{debug, error, info, warn};
 
-use crate::observability::{CacheMetrics, CacheOperation};
+use crate::{
+    config::CacheConsistencyConfig,
+    observability::{CacheMetrics, CacheOperation},
+};
+
+const WRITE_HOLD_PREFIX: &str = "cache:write_hold:";
+
+const RELEASE_LOCK_SCRIPT: &str = r#"
+if redis.call('GET', KEYS[1]) == ARGV[1] then
+    return redis.call('DEL', KEYS[1])
+end
+return 0
+"#;
 
 #[derive(Clone)]
 pub struct CacheStore {
     redis_pool: Arc<Pool>,
     metrics: CacheMetrics,
+    consistency: CacheConsistencyConfig,
 }
 
 impl CacheStore {
@@ -20,6 +33,7 @@ impl CacheStore {
         Self {
             redis_pool: Arc::new(redis_pool),
             metrics,
+            consistency: CacheConsistencyConfig::from_env(),
         }
     }
 
@@ -82,6 +96,44 @@ impl CacheStore {
         }
     }
 
+    pub async fn try_get_from_cache<T>(&self, key: &str) -> Result<Option<T>>
+    where
+        T: DeserializeOwned,
+    {
+        let start = Instant::now();
+
+        let mut conn = match self.get_conn().await {
+            Some(c) => c,
+            None => {
+                self.metrics
+                    .record_error(CacheOperation::Get, start.elapsed().as_secs_f64());
+                anyhow::bail!("Redis connection unavailable");
+            }
+        };
+
+        let result: redis::RedisResult<Option<String>> =
+            redis::cmd("GET").arg(key).query_async(&mut conn).await;
+
+        let duration = start.elapsed().as_secs_f64();
+
+        match result {
+            Ok(Some(data)) => {
+                self.metrics.record_hit(CacheOperation::Get, duration);
+                serde_json::from_str::<T>(&data)
+                    .map(Some)
+                    .with_context(|| format!("failed to deserialize cached value for key '{key}'"))
+            }
+            Ok(None) => {
+                self.metrics.record_miss(CacheOperation::Get, duration);
+                Ok(None)
+            }
+            Err(e) => {
+                self.metrics.record_error(CacheOperation::Get, duration);
+                Err(e).with_context(|| format!("Redis get failed for key '{key}'"))
+            }
+        }
+    }
+
     pub async fn set_to_cache<T>(&self, key: &str, data: &T, expiration: Duration)
     where
         T: Serialize,
@@ -110,6 +162,15 @@ impl CacheStore {
         }
 
         if let Some(mut conn) = self.get_conn().await {
+            if self.consistency.hold().is_some() {
+                let held: redis::RedisResult<bool> =
+                    conn.exists(format!("{WRITE_HOLD_PREFIX}{key}")).await;
+                if let Ok(true) = held {
+                    debug!("Skipping cache set for key '{}' inside write hold", key);
+                    return;
+                }
+            }
+
             let result: redis::RedisResult<()> = conn.set_ex(key, json_data, ttl as u64).await;
             let duration = start.elapsed().as_secs_f64();
 
@@ -157,6 +218,79 @@ impl CacheStore {
         }
     }
 
+    pub async fn invalidate_after_write<K: AsRef<str>>(&self, keys: &[K]) {
+        let hold = self.consistency.hold();
+
+        for key in keys.iter().map(AsRef::as_ref) {
+            if key.contains('*') {
+                if let Err(e) = self.invalidate_pattern(key).await {
+                    error!("Failed to invalidate pattern '{}': {:?}", key, e);
+                }
+                continue;
+            }
+
+            self.delete_from_cache(key).await;
+
+            let Some(hold) = hold else {
+                continue;
+            };
+
+            if let Some(mut conn) = self.get_conn().await {
+                let result = redis::cmd("SET")
+                    .arg(format!("{WRITE_HOLD_PREFIX}{key}"))
+                    .arg(1)
+                    .arg("PX")
+                    .arg(hold.as_millis() as u64)
+                    .query_async::<()>(&mut conn)
+                    .await;
+
+                if let Err(e) = result {
+                    warn!("Failed to set write hold for key '{}': {:?}", key, e);
+                }
+            }
+        }
+    }
+
+    /// Returns the owner token on success; pass it to `release_lock`.
+    pub async fn try_lock(&self, key: &str, ttl: std::time::Duration) -> Result<Option<String>> {
+        let mut conn = self
+            .get_conn()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Failed to get Redis connection"))?;
+
+        let token = uuid::Uuid::new_v4().to_string();
+
+        let acquired: Option<String> = redis::cmd("SET")
+            .arg(key)
+            .arg(&token)
+            .arg("NX")
+            .arg("PX")
+            .arg(ttl.as_millis() as u64)
+            .query_async(&mut conn)
+            .await
+            .context("Failed to execute SET NX command")?;
+
+        Ok(acquired.map(|_| token))
+    }
+
+    /// Deletes the lock only if it is still held by `token`, so a holder
+    /// whose TTL expired cannot remove a lock taken over by someone else.
+    pub async fn release_lock(&self, key: &str, token: &str) -> Result<bool> {
+        let mut conn = self
+            .get_conn()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Failed to get Redis connection"))?;
+
+        let deleted: i64 = Script::new(RELEASE_LOCK_SCRIPT)
+            .key(key)
+            .arg(token)
+            .invoke_async(&mut conn)
+            .await
+            .context("Failed to release lock")?;
+
+        Ok(deleted == 1)
+    }
+
     pub async fn clear_expired(&self) -> Result<usize, String> {
         let start = Instant::now();
         info!("🧹 Clearing expired cache entries...");
@@ -283,6 +417,56 @@ impl CacheStore {
         Ok(deleted)
     }
 
+    pub async fn scan(&self, prefix: &str) -> Result<Vec<String>> {
+        let start = Instant::now();
+        let pattern = format!("{}*", escape_glob(prefix));
+
+        let mut conn = self.get_conn().await.ok_or_else(|| {
+            self.metrics
+                .record_error(CacheOperation::Scan, start.elapsed().as_secs_f64());
+            anyhow::anyhow!("Failed to get Redis connection")
+        })?;
+
+        let mut cursor: u64 = 0;
+        let mut found = Vec::new();
+
+        loop {
+            let (new_cursor, keys): (u64, Vec<String>) = match redis::cmd("SCAN")
+                .arg(cursor)
+                .arg("MATCH")
+                .arg(&pattern)
+                .arg("COUNT")
+                .arg(100)
+                .query_async(&mut conn)
+                .await
+            {
+                Ok(page) => page,
+                Err(e) => {
+                    error!("Failed to scan keys with prefix '{}': {:?}", prefix, e);
+                    self.metrics
+                        .record_error(CacheOperation::Scan, start.elapsed().as_secs_f64());
+                    return Err(anyhow::anyhow!("SCAN error: {e}"));
+                }
+            };
+
+            found.extend(keys);
+
+            cursor = new_cursor;
+            if cursor == 0 {
+                break;
+            }
+        }
+
+        found.sort();
+        found.dedup();
+
+        self.metrics
+            .record_success(CacheOperation::Scan, start.elapsed().as_secs_f64());
+        debug!("Scanned {} keys with prefix '{}'", found.len(), prefix);
+
+        Ok(found)
+    }
+
     pub async fn get_stats(&self) -> Result<CacheStats, String> {
         let start = Instant::now();
         debug!("Fetching cache stats from Redis...");
@@ -370,6 +554,17 @@ impl CacheStore {
     }
 }
 
+fn escape_glob(raw: &str) -> String {
+    let mut escaped = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        if matches!(c, '*' | '?' | '[' | ']' | '\\') {
+            escaped.push('\\');
+        }
+        escaped.push(c);
+    }
+    escaped
+}
+
 #[derive(Debug, Default)]
 pub struct CacheStats {
     pub memory_used: u64,
@@ -0,0 +1,134 @@
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use tracing::warn;
+
+pub struct LocalLoginLimiter {
+    window: Duration,
+    max_keys: usize,
+    attempts: Mutex<HashMap<String, VecDeque<Instant>>>,
+}
+
+impl LocalLoginLimiter {
+    pub fn new(window: Duration, max_keys: usize) -> Self {
+        Self {
+            window,
+            max_keys,
+            attempts: Mutex::new(HashMap::new()),
+        }
+    }
+
+    pub fn attempts(&self, key: &str) -> usize {
+        let now = Instant::now();
+        let mut attempts = self.attempts.lock().unwrap_or_else(|e| e.into_inner());
+
+        let Some(entries) = attempts.get_mut(key) else {
+            return 0;
+        };
+
+        Self::prune(entries, now, self.window);
+
+        if entries.is_empty() {
+            attempts.remove(key);
+            return 0;
+        }
+
+        entries.len()
+    }
+
+    pub fn record_failure(&self, key: &str, max_attempts: usize) {
+        let now = Instant::now();
+        let mut attempts = self.attempts.lock().unwrap_or_else(|e| e.into_inner());
+
+        if !attempts.contains_key(key) && attempts.len() >= self.max_keys {
+            attempts.retain(|_, entries| {
+                Self::prune(entries, now, self.window);
+                !entries.is_empty()
+            });
+
+            if attempts.len() >= self.max_keys
+                && let Some(oldest) = attempts
+                    .iter()
+                    .min_by_key(|(_, entries)| entries.back().copied())
+                    .map(|(k, _)| k.clone())
+            {
+                warn!("🚫 Local login limiter full, evicting oldest entry");
+                attempts.remove(&oldest);
+            }
+        }
+
+        let entries = attempts.entry(key.to_string()).or_default();
+        Self::prune(entries, now, self.window);
+        entries.push_back(now);
+
+        while entries.len() > max_attempts {
+            entries.pop_front();
+        }
+    }
+
+    pub fn reset(&self, key: &str) {
+        let mut attempts = self.attempts.lock().unwrap_or_else(|e| e.into_inner());
+        attempts.remove(key);
+    }
+
+    fn prune(entries: &mut VecDeque<Instant>, now: Instant, window: Duration) {
+        while let Some(first) = entries.front() {
+            if now.duration_since(*first) >= window {
+                entries.pop_front();
+            } else {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn counts_failures_up_to_the_cap() {
+        let limiter = LocalLoginLimiter::new(Duration::from_secs(60), 10);
+
+        for _ in 0..5 {
+            limiter.record_failure("alice", 3);
+        }
+
+        assert_eq!(limiter.attempts("alice"), 3);
+        assert_eq!(limiter.attempts("bob"), 0);
+    }
+
+    #[test]
+    fn reset_clears_a_key() {
+        let limiter = LocalLoginLimiter::new(Duration::from_secs(60), 10);
+
+        limiter.record_failure("alice", 5);
+        limiter.reset("alice");
+
+        assert_eq!(limiter.attempts("alice"), 0);
+    }
+
+    #[test]
+    fn failures_expire_after_the_window() {
+        let limiter = LocalLoginLimiter::new(Duration::from_millis(20), 10);
+
+        limiter.record_failure("alice", 5);
+        std::thread::sleep(Duration::from_millis(30));
+
+        assert_eq!(limiter.attempts("alice"), 0);
+    }
+
+    #[test]
+    fn evicts_the_oldest_key_when_full() {
+        let limiter = LocalLoginLimiter::new(Duration::from_secs(60), 2);
+
+        limiter.record_failure("alice", 5);
+        std::thread::sleep(Duration::from_millis(2));
+        limiter.record_failure("bob", 5);
+        limiter.record_failure("carol", 5);
+
+        assert_eq!(limiter.attempts("alice"), 0);
+        assert_eq!(limiter.attempts("bob"), 1);
+        assert_eq!(limiter.attempts("carol"), 1);
+    }
+}
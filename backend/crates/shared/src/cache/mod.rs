@@ -1,5 +1,7 @@
 mod cache_store;
+mod login_limiter;
 pub mod rate_limit;
 pub mod session;
 
 pub use self::cache_store::CacheStore;
+pub use self::login_limiter::LocalLoginLimiter;
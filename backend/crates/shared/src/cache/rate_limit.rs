@@ -1,9 +1,19 @@
-use crate::abstract_trait::rate_limit::RateLimitMiddlewareTrait;
+use crate::abstract_trait::rate_limit::{RateLimitMiddlewareTrait, RateLimitStatus};
 use async_trait::async_trait;
-use deadpool_redis::{Connection, Pool};
+use deadpool_redis::{Connection, Pool, redis::Script};
 use std::sync::Arc;
 use tracing::{debug, error};
 
+const INCR_WINDOW_SCRIPT: &str = r#"
+local current = redis.call('INCR', KEYS[1])
+local ttl = redis.call('TTL', KEYS[1])
+if ttl < 0 then
+    redis.call('EXPIRE', KEYS[1], ARGV[1])
+    ttl = tonumber(ARGV[1])
+end
+return {current, ttl}
+"#;
+
 #[derive(Clone)]
 pub struct RateLimiter {
     redis_pool: Arc<Pool>,
@@ -34,40 +44,43 @@ impl RateLimitMiddlewareTrait for RateLimiter {
         key: &str,
         max_requests: u32,
         window_seconds: u32,
-    ) -> (bool, u32) {
+    ) -> RateLimitStatus {
+        let rejected = RateLimitStatus {
+            allowed: false,
+            limit: max_requests,
+            current: max_requests,
+            reset_after_secs: window_seconds as u64,
+        };
+
         let mut conn = match self.get_conn().await {
             Some(conn) => conn,
-            None => return (false, 0),
+            None => return rejected,
         };
 
-        let current: u32 = match deadpool_redis::redis::cmd("GET")
-            .arg(key)
-            .query_async(&mut conn)
+        let (current, ttl): (u32, i64) = match Script::new(INCR_WINDOW_SCRIPT)
+            .key(key)
+            .arg(window_seconds)
+            .invoke_async(&mut conn)
             .await
         {
-            Ok(Some(val)) => val,
-            _ => 0u32,
+            Ok(result) => result,
+            Err(e) => {
+                error!("Failed to update rate limit counter for key {key}: {e:?}");
+                return rejected;
+            }
         };
 
-        if current >= max_requests {
+        let allowed = current <= max_requests;
+        if !allowed {
             debug!("Rate limit exceeded for key: {key}");
-            return (false, current);
         }
 
-        let _: () = deadpool_redis::redis::pipe()
-            .atomic()
-            .cmd("INCR")
-            .arg(key)
-            .ignore()
-            .cmd("EXPIRE")
-            .arg(key)
-            .arg(window_seconds)
-            .ignore()
-            .query_async(&mut conn)
-            .await
-            .unwrap_or(());
-
-        (true, current + 1)
+        RateLimitStatus {
+            allowed,
+            limit: max_requests,
+            current: current.min(max_requests),
+            reset_after_secs: ttl.max(0) as u64,
+        }
     }
 
     async fn get_remaining(&self, key: &str, max_requests: u32) -> u32 {
@@ -0,0 +1,46 @@
+use anyhow::Result;
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct AnomalyConfig {
+    pub enabled: bool,
+    pub window_days: u32,
+    pub z_threshold: f64,
+    pub check_interval_secs: u64,
+    pub webhook_url: Option<String>,
+}
+
+impl AnomalyConfig {
+    pub fn from_env() -> Result<Self> {
+        let config = Self {
+            enabled: Self::get_env("ANOMALY_DETECTION_ENABLED").unwrap_or(false),
+
+            window_days: Self::get_env::<u32>("ANOMALY_WINDOW_DAYS")
+                .unwrap_or(7)
+                .max(2),
+
+            z_threshold: Self::get_env::<f64>("ANOMALY_Z_THRESHOLD")
+                .filter(|v| *v > 0.0)
+                .unwrap_or(3.0),
+
+            check_interval_secs: Self::get_env::<u64>("ANOMALY_CHECK_INTERVAL_SECS")
+                .unwrap_or(3600)
+                .max(1),
+
+            webhook_url: std::env::var("ANOMALY_WEBHOOK_URL")
+                .ok()
+                .filter(|v| !v.trim().is_empty()),
+        };
+
+        tracing::info!("Anomaly detection config loaded: {:?}", config);
+        Ok(config)
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.parse().ok())
+    }
+
+    pub fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.check_interval_secs)
+    }
+}
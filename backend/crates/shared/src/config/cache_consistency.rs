@@ -0,0 +1,25 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+pub struct CacheConsistencyConfig {
+    pub read_after_write_hold_ms: u64,
+}
+
+impl CacheConsistencyConfig {
+    pub fn from_env() -> Self {
+        let config = Self {
+            read_after_write_hold_ms: std::env::var("CACHE_READ_AFTER_WRITE_HOLD_MS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(2000),
+        };
+
+        tracing::info!("Cache consistency config loaded: {:?}", config);
+        config
+    }
+
+    pub fn hold(&self) -> Option<Duration> {
+        (self.read_after_write_hold_ms > 0)
+            .then(|| Duration::from_millis(self.read_after_write_hold_ms))
+    }
+}
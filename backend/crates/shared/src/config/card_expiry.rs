@@ -0,0 +1,149 @@
+use anyhow::{Result, bail};
+use chrono::{Days, Months, NaiveDate};
+
+#[derive(Debug, Clone, Copy)]
+pub struct CardExpiryConfig {
+    pub min_days_ahead: u64,
+    pub max_validity_months: u32,
+    pub reissue_validity_months: u32,
+}
+
+impl Default for CardExpiryConfig {
+    fn default() -> Self {
+        Self {
+            min_days_ahead: 0,
+            max_validity_months: 60,
+            reissue_validity_months: 36,
+        }
+    }
+}
+
+impl CardExpiryConfig {
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+
+        let config = Self {
+            min_days_ahead: Self::get_env("CARD_EXPIRY_MIN_DAYS_AHEAD")
+                .unwrap_or(defaults.min_days_ahead),
+            max_validity_months: Self::get_env("CARD_EXPIRY_MAX_VALIDITY_MONTHS")
+                .unwrap_or(defaults.max_validity_months),
+            reissue_validity_months: Self::get_env("CARD_REISSUE_VALIDITY_MONTHS")
+                .unwrap_or(defaults.reissue_validity_months),
+        };
+
+        config.validate()?;
+
+        tracing::info!("Card expiry config loaded: {:?}", config);
+        Ok(config)
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    pub fn validate(&self) -> Result<()> {
+        let probe = NaiveDate::from_ymd_opt(2000, 1, 1).expect("valid date");
+        let (earliest, latest) = self.window(probe);
+
+        if earliest > latest {
+            bail!(
+                "CARD_EXPIRY_MIN_DAYS_AHEAD ({}) is beyond CARD_EXPIRY_MAX_VALIDITY_MONTHS ({})",
+                self.min_days_ahead,
+                self.max_validity_months
+            );
+        }
+
+        let reissued = self.reissue_expire_date(probe);
+        if reissued < earliest || reissued > latest {
+            bail!(
+                "CARD_REISSUE_VALIDITY_MONTHS ({}) must fall inside the card expiry window",
+                self.reissue_validity_months
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Earliest and latest `expire_date` accepted for a card written on `today`.
+    pub fn window(&self, today: NaiveDate) -> (NaiveDate, NaiveDate) {
+        (
+            today + Days::new(self.min_days_ahead),
+            today + Months::new(self.max_validity_months),
+        )
+    }
+
+    pub fn check(&self, expire_date: NaiveDate, today: NaiveDate) -> Result<(), String> {
+        let (earliest, latest) = self.window(today);
+
+        if expire_date < earliest {
+            return Err(format!("expire_date must be on or after {earliest}"));
+        }
+        if expire_date > latest {
+            return Err(format!("expire_date must be on or before {latest}"));
+        }
+
+        Ok(())
+    }
+
+    pub fn reissue_expire_date(&self, today: NaiveDate) -> NaiveDate {
+        today + Months::new(self.reissue_validity_months)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(year: i32, month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn accepts_dates_inside_the_window() {
+        let config = CardExpiryConfig::default();
+        let today = day(2025, 8, 26);
+
+        assert!(config.check(today, today).is_ok());
+        assert!(config.check(day(2027, 1, 31), today).is_ok());
+        assert!(config.check(day(2030, 8, 26), today).is_ok());
+    }
+
+    #[test]
+    fn rejects_past_and_too_distant_dates() {
+        let config = CardExpiryConfig::default();
+        let today = day(2025, 8, 26);
+
+        assert!(config.check(day(2025, 8, 25), today).is_err());
+        assert!(config.check(day(2030, 8, 27), today).is_err());
+    }
+
+    #[test]
+    fn honours_the_minimum_lead_time() {
+        let config = CardExpiryConfig {
+            min_days_ahead: 30,
+            ..CardExpiryConfig::default()
+        };
+        let today = day(2025, 8, 26);
+
+        assert!(config.check(day(2025, 9, 24), today).is_err());
+        assert!(config.check(day(2025, 9, 25), today).is_ok());
+    }
+
+    #[test]
+    fn reissue_date_must_fit_the_window() {
+        assert!(CardExpiryConfig::default().validate().is_ok());
+
+        let too_long = CardExpiryConfig {
+            reissue_validity_months: 72,
+            ..CardExpiryConfig::default()
+        };
+        assert!(too_long.validate().is_err());
+
+        let empty_window = CardExpiryConfig {
+            min_days_ahead: 400,
+            max_validity_months: 12,
+            reissue_validity_months: 12,
+        };
+        assert!(empty_window.validate().is_err());
+    }
+}
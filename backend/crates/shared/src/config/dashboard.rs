@@ -0,0 +1,21 @@
+use anyhow::Result;
+
+#[derive(Debug, Clone)]
+pub struct DashboardConfig {
+    pub stats_concurrency: usize,
+}
+
+impl DashboardConfig {
+    pub fn from_env() -> Result<Self> {
+        let stats_concurrency = std::env::var("DASHBOARD_STATS_CONCURRENCY")
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .unwrap_or(5)
+            .max(1);
+
+        let config = Self { stats_concurrency };
+
+        tracing::info!("Dashboard config loaded: {:?}", config);
+        Ok(config)
+    }
+}
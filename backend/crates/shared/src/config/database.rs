@@ -1,6 +1,8 @@
 use std::time::Duration;
 
+use crate::config::LogPrivacyConfig;
 use sqlx::{Pool, Postgres, postgres::PgPoolOptions};
+use tracing::info;
 
 pub type ConnectionPool = Pool<Postgres>;
 
@@ -13,6 +15,12 @@ impl ConnectionManager {
         min_conn: u32,
         max_conn: u32,
     ) -> anyhow::Result<ConnectionPool> {
+        let privacy = LogPrivacyConfig::from_env();
+        info!(
+            "Connecting to database {}",
+            privacy.connection_string(connection_string)
+        );
+
         let pool = PgPoolOptions::new()
             .min_connections(min_conn)
             .max_connections(max_conn)
@@ -22,13 +30,23 @@ impl ConnectionManager {
             .test_before_acquire(false)
             .connect(connection_string)
             .await
-            .map_err(|err| anyhow::anyhow!("Failed to create database connection pool: {}", err))?;
+            .map_err(|err| {
+                anyhow::anyhow!(
+                    "Failed to create database connection pool: {}",
+                    privacy.connection_string(&err.to_string())
+                )
+            })?;
 
         if run_migrations {
             sqlx::migrate!("../../migrations")
                 .run(&pool)
                 .await
-                .map_err(|err| anyhow::anyhow!("Failed to run database migrations: {}", err))?;
+                .map_err(|err| {
+                    anyhow::anyhow!(
+                        "Failed to run database migrations: {}",
+                        privacy.connection_string(&err.to_string())
+                    )
+                })?;
         }
 
         Ok(pool)
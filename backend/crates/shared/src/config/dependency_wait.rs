@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct DependencyWaitConfig {
+    pub enabled: bool,
+    pub required: Vec<String>,
+    pub timeout_secs: u64,
+    pub poll_interval_ms: u64,
+    pub strict: bool,
+}
+
+impl DependencyWaitConfig {
+    pub fn from_env() -> Self {
+        let required = std::env::var("GATEWAY_REQUIRED_DEPENDENCIES")
+            .unwrap_or_default()
+            .split(',')
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        let config = Self {
+            enabled: Self::get_env("GATEWAY_WAIT_FOR_DEPENDENCIES").unwrap_or(false),
+            required,
+            timeout_secs: Self::get_env("GATEWAY_DEPENDENCY_WAIT_TIMEOUT_SECS")
+                .unwrap_or(60)
+                .max(1),
+            poll_interval_ms: Self::get_env("GATEWAY_DEPENDENCY_POLL_INTERVAL_MS")
+                .unwrap_or(1000)
+                .max(100),
+            strict: Self::get_env("GATEWAY_DEPENDENCY_WAIT_STRICT").unwrap_or(false),
+        };
+
+        tracing::info!("Dependency wait config loaded: {:?}", config);
+        config
+    }
+
+    pub fn is_required(&self, dependency: &str) -> bool {
+        self.required.is_empty() || self.required.iter().any(|name| name == dependency)
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_millis(self.poll_interval_ms)
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+}
@@ -0,0 +1,178 @@
+use std::{
+    collections::HashSet,
+    path::PathBuf,
+    sync::{Arc, RwLock},
+    time::Duration,
+};
+use tracing::{info, warn};
+
+#[derive(Debug)]
+pub struct EmailDomainDenylist {
+    inline: HashSet<String>,
+    file: Option<PathBuf>,
+    reload_secs: u64,
+    domains: RwLock<HashSet<String>>,
+}
+
+impl EmailDomainDenylist {
+    pub fn from_env() -> Self {
+        let inline = Self::parse(
+            &std::env::var("REGISTRATION_DENIED_EMAIL_DOMAINS").unwrap_or_default(),
+            ',',
+        );
+        let file = std::env::var("REGISTRATION_DENIED_EMAIL_DOMAINS_FILE")
+            .ok()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+            .map(PathBuf::from);
+        let reload_secs = std::env::var("REGISTRATION_DENIED_EMAIL_DOMAINS_RELOAD_SECS")
+            .ok()
+            .and_then(|v| v.trim().parse().ok())
+            .unwrap_or(60);
+
+        let denylist = Self {
+            inline,
+            file,
+            reload_secs,
+            domains: RwLock::new(HashSet::new()),
+        };
+        denylist.reload();
+
+        info!(
+            "Email domain denylist loaded: {} domains, file: {:?}, reload every {}s",
+            denylist.len(),
+            denylist.file,
+            denylist.reload_secs
+        );
+        denylist
+    }
+
+    fn parse(raw: &str, separator: char) -> HashSet<String> {
+        raw.split(separator)
+            .map(|line| line.split('#').next().unwrap_or_default())
+            .map(|domain| domain.trim().trim_start_matches('@').to_lowercase())
+            .filter(|domain| !domain.is_empty())
+            .collect()
+    }
+
+    pub fn reload(&self) -> usize {
+        let mut domains = self.inline.clone();
+
+        if let Some(path) = &self.file {
+            match std::fs::read_to_string(path) {
+                Ok(contents) => domains.extend(Self::parse(&contents, '\n')),
+                Err(e) => {
+                    warn!("⚠️  Failed to read email domain denylist {path:?}: {e}");
+                    return self.len();
+                }
+            }
+        }
+
+        let count = domains.len();
+        let mut current = self.domains.write().unwrap_or_else(|e| e.into_inner());
+        if *current != domains {
+            *current = domains;
+            info!("🔄 Email domain denylist reloaded: {count} domains");
+        }
+        count
+    }
+
+    pub fn spawn_reload(self: &Arc<Self>) {
+        if self.file.is_none() || self.reload_secs == 0 {
+            return;
+        }
+
+        let denylist = Arc::clone(self);
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(Duration::from_secs(denylist.reload_secs));
+            interval.tick().await;
+            loop {
+                interval.tick().await;
+                denylist.reload();
+            }
+        });
+    }
+
+    pub fn len(&self) -> usize {
+        self.domains.read().map(|d| d.len()).unwrap_or(0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn is_denied(&self, email: &str) -> bool {
+        let Some((_, domain)) = email.trim().rsplit_once('@') else {
+            return false;
+        };
+        let domain = domain.to_lowercase();
+
+        let Ok(domains) = self.domains.read() else {
+            return false;
+        };
+
+        let mut candidate = domain.as_str();
+        loop {
+            if domains.contains(candidate) {
+                return true;
+            }
+            match candidate.split_once('.') {
+                Some((_, parent)) if parent.contains('.') => candidate = parent,
+                _ => return false,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn denylist(inline: &str, file: Option<PathBuf>) -> EmailDomainDenylist {
+        let denylist = EmailDomainDenylist {
+            inline: EmailDomainDenylist::parse(inline, ','),
+            file,
+            reload_secs: 0,
+            domains: RwLock::new(HashSet::new()),
+        };
+        denylist.reload();
+        denylist
+    }
+
+    #[test]
+    fn matches_domains_case_insensitively() {
+        let denylist = denylist("Mailinator.com, @tempmail.io", None);
+
+        assert!(denylist.is_denied("someone@mailinator.com"));
+        assert!(denylist.is_denied(" Someone@TEMPMAIL.IO "));
+        assert!(!denylist.is_denied("someone@example.com"));
+        assert!(!denylist.is_denied("not-an-email"));
+    }
+
+    #[test]
+    fn matches_subdomains_but_not_bare_suffixes() {
+        let denylist = denylist("mailinator.com,com", None);
+
+        assert!(denylist.is_denied("a@eu.mx.mailinator.com"));
+        assert!(!denylist.is_denied("a@notmailinator.com"));
+        assert!(!denylist.is_denied("a@example.com"));
+    }
+
+    #[test]
+    fn reads_domains_and_comments_from_the_file() {
+        let path =
+            std::env::temp_dir().join(format!("email-denylist-test-{}.txt", std::process::id()));
+        std::fs::write(
+            &path,
+            "# throwaway providers\nyopmail.com\n\nguerrillamail.com # old\n",
+        )
+        .unwrap();
+
+        let denylist = denylist("mailinator.com", Some(path.clone()));
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(denylist.len(), 3);
+        assert!(denylist.is_denied("a@yopmail.com"));
+        assert!(denylist.is_denied("a@guerrillamail.com"));
+    }
+}
@@ -0,0 +1,36 @@
+/// Controls whether emails are trimmed and lowercased before use.
+///
+/// Uniqueness is enforced by the `LOWER(TRIM(email))` index regardless of
+/// `enabled`, so disabling this only changes the stored spelling.
+#[derive(Debug, Clone, Copy)]
+pub struct EmailNormalizationConfig {
+    pub enabled: bool,
+}
+
+impl Default for EmailNormalizationConfig {
+    fn default() -> Self {
+        Self { enabled: true }
+    }
+}
+
+impl EmailNormalizationConfig {
+    pub fn from_env() -> Self {
+        let enabled = std::env::var("EMAIL_NORMALIZATION_ENABLED")
+            .ok()
+            .and_then(|v| v.trim().parse::<bool>().ok())
+            .unwrap_or(true);
+
+        let config = Self { enabled };
+
+        tracing::info!("Email normalization config loaded: {:?}", config);
+        config
+    }
+
+    pub fn normalize(&self, email: &str) -> String {
+        if self.enabled {
+            email.trim().to_lowercase()
+        } else {
+            email.to_string()
+        }
+    }
+}
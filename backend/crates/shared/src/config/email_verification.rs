@@ -0,0 +1,31 @@
+use anyhow::Result;
+
+#[derive(Debug, Clone)]
+pub struct EmailVerificationConfig {
+    pub require_verified_login: bool,
+    pub token_ttl_minutes: i64,
+    pub resend_cooldown_secs: i64,
+}
+
+impl EmailVerificationConfig {
+    pub fn from_env() -> Result<Self> {
+        let config = Self {
+            require_verified_login: Self::get_env("REQUIRE_EMAIL_VERIFICATION").unwrap_or(false),
+
+            token_ttl_minutes: Self::get_env::<i64>("EMAIL_VERIFICATION_TTL_MINUTES")
+                .filter(|v| *v > 0)
+                .unwrap_or(60),
+
+            resend_cooldown_secs: Self::get_env::<i64>("EMAIL_VERIFICATION_RESEND_COOLDOWN_SECS")
+                .filter(|v| *v >= 0)
+                .unwrap_or(60),
+        };
+
+        tracing::info!("Email verification config loaded: {:?}", config);
+        Ok(config)
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.parse().ok())
+    }
+}
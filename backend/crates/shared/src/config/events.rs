@@ -0,0 +1,58 @@
+use anyhow::{Result, bail};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EventSinkKind {
+    Log,
+}
+
+impl EventSinkKind {
+    fn parse(raw: &str) -> Result<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "log" => Ok(Self::Log),
+            other => bail!("EVENTS_SINK must be 'log', got '{other}'"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct EventsConfig {
+    pub enabled: bool,
+    pub buffer_size: usize,
+    pub sink: EventSinkKind,
+}
+
+impl Default for EventsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            buffer_size: 1024,
+            sink: EventSinkKind::Log,
+        }
+    }
+}
+
+impl EventsConfig {
+    pub fn from_env() -> Result<Self> {
+        let defaults = Self::default();
+
+        let sink = match std::env::var("EVENTS_SINK") {
+            Ok(v) if !v.trim().is_empty() => EventSinkKind::parse(&v)?,
+            _ => defaults.sink,
+        };
+
+        let config = Self {
+            enabled: Self::get_env("EVENTS_ENABLED").unwrap_or(defaults.enabled),
+            buffer_size: Self::get_env("EVENTS_BUFFER_SIZE")
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.buffer_size),
+            sink,
+        };
+
+        tracing::info!("Events config loaded: {:?}", config);
+        Ok(config)
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+}
@@ -0,0 +1,34 @@
+#[derive(Debug, Clone, Copy)]
+pub struct FeaturesConfig {
+    pub self_registration: bool,
+    pub email_verification_required: bool,
+}
+
+impl Default for FeaturesConfig {
+    fn default() -> Self {
+        Self {
+            self_registration: true,
+            email_verification_required: false,
+        }
+    }
+}
+
+impl FeaturesConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let config = Self {
+            self_registration: Self::get_env("SELF_REGISTRATION_ENABLED")
+                .unwrap_or(defaults.self_registration),
+            email_verification_required: Self::get_env("REQUIRE_EMAIL_VERIFICATION")
+                .unwrap_or(defaults.email_verification_required),
+        };
+
+        tracing::info!("Features config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env(key: &str) -> Option<bool> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+}
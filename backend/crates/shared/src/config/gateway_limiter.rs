@@ -1,10 +1,14 @@
-use std::env;
+use std::{collections::HashMap, env};
 
 #[derive(Debug, Clone)]
 pub struct GatewayLimiterConfig {
     pub cb_max_failures: u64,
     pub cb_reset_timeout_sec: u64,
     pub rate_limit: usize,
+    pub max_queue_time_ms: u64,
+    pub service_limit_default: usize,
+    pub service_limits: HashMap<String, usize>,
+    pub service_queue_timeout_ms: u64,
 }
 
 impl GatewayLimiterConfig {
@@ -24,6 +28,42 @@ impl GatewayLimiterConfig {
                 .unwrap_or_else(|_| "3000".into())
                 .parse()
                 .expect("invalid GATEWAY_RATE_LIMIT"),
+
+            max_queue_time_ms: env::var("GATEWAY_MAX_QUEUE_TIME_MS")
+                .unwrap_or_else(|_| "0".into())
+                .parse()
+                .expect("invalid GATEWAY_MAX_QUEUE_TIME_MS"),
+
+            service_limit_default: env::var("GATEWAY_SERVICE_LIMIT_DEFAULT")
+                .unwrap_or_else(|_| "500".into())
+                .parse()
+                .expect("invalid GATEWAY_SERVICE_LIMIT_DEFAULT"),
+
+            service_limits: Self::parse_service_limits(
+                &env::var("GATEWAY_SERVICE_LIMITS").unwrap_or_default(),
+            ),
+
+            service_queue_timeout_ms: env::var("GATEWAY_SERVICE_QUEUE_TIMEOUT_MS")
+                .unwrap_or_else(|_| "0".into())
+                .parse()
+                .expect("invalid GATEWAY_SERVICE_QUEUE_TIMEOUT_MS"),
         }
     }
+
+    fn parse_service_limits(raw: &str) -> HashMap<String, usize> {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (service, limit) = entry
+                    .split_once('=')
+                    .expect("GATEWAY_SERVICE_LIMITS entries must be <service>=<limit>");
+                let limit = limit
+                    .trim()
+                    .parse()
+                    .expect("invalid limit in GATEWAY_SERVICE_LIMITS");
+                (service.trim().to_lowercase(), limit)
+            })
+            .collect()
+    }
 }
@@ -0,0 +1,33 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct GrpcHealthConfig {
+    pub check_interval_secs: u64,
+    pub drain_secs: u64,
+}
+
+impl GrpcHealthConfig {
+    pub fn from_env() -> Self {
+        let config = Self {
+            check_interval_secs: Self::get_env("GRPC_HEALTH_CHECK_INTERVAL_SECS")
+                .unwrap_or(5)
+                .max(1),
+            drain_secs: Self::get_env("GRPC_HEALTH_DRAIN_SECS").unwrap_or(5),
+        };
+
+        tracing::info!("gRPC health config loaded: {:?}", config);
+        config
+    }
+
+    pub fn check_interval(&self) -> Duration {
+        Duration::from_secs(self.check_interval_secs)
+    }
+
+    pub fn drain(&self) -> Duration {
+        Duration::from_secs(self.drain_secs)
+    }
+
+    fn get_env(key: &str) -> Option<u64> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+}
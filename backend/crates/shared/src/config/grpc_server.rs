@@ -1,5 +1,5 @@
-use anyhow::Result;
-use std::time::Duration;
+use anyhow::{Result, anyhow};
+use std::{collections::HashMap, time::Duration};
 
 #[derive(Debug, Clone)]
 pub struct GrpcServerConfig {
@@ -12,6 +12,8 @@ pub struct GrpcServerConfig {
     pub initial_connection_window_size_mb: u32,
     pub initial_stream_window_size_mb: u32,
     pub tcp_nodelay: bool,
+    pub handler_timeout_secs: u64,
+    pub handler_timeout_overrides: HashMap<String, u64>,
 }
 
 impl GrpcServerConfig {
@@ -45,6 +47,12 @@ impl GrpcServerConfig {
             .unwrap_or(16),
 
             tcp_nodelay: Self::get_env("GRPC_SERVER_TCP_NODELAY").unwrap_or(true),
+
+            handler_timeout_secs: Self::get_env("GRPC_SERVER_HANDLER_TIMEOUT_SECS").unwrap_or(10),
+
+            handler_timeout_overrides: Self::parse_handler_overrides(
+                "GRPC_SERVER_HANDLER_TIMEOUT_OVERRIDES",
+            )?,
         };
 
         tracing::info!("gRPC Server Config loaded: {:?}", config);
@@ -55,6 +63,27 @@ impl GrpcServerConfig {
         std::env::var(key).ok().and_then(|v| v.parse().ok())
     }
 
+    fn parse_handler_overrides(key: &str) -> Result<HashMap<String, u64>> {
+        let Ok(raw) = std::env::var(key) else {
+            return Ok(HashMap::new());
+        };
+
+        raw.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (path, secs) = entry
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("{key} entry '{entry}' must be <rpc path>=<secs>"))?;
+                let secs = secs
+                    .trim()
+                    .parse::<u64>()
+                    .map_err(|_| anyhow!("{key} entry '{entry}' has an invalid timeout"))?;
+                Ok((path.trim().trim_start_matches('/').to_string(), secs))
+            })
+            .collect()
+    }
+
     pub fn timeout(&self) -> Duration {
         Duration::from_secs(self.timeout_secs)
     }
@@ -78,4 +107,14 @@ impl GrpcServerConfig {
     pub fn initial_stream_window_size(&self) -> Option<u32> {
         Some(self.initial_stream_window_size_mb * 1024 * 1024)
     }
+
+    pub fn handler_timeout_for(&self, path: &str) -> Option<Duration> {
+        let secs = self
+            .handler_timeout_overrides
+            .get(path.trim_start_matches('/'))
+            .copied()
+            .unwrap_or(self.handler_timeout_secs);
+
+        (secs > 0).then(|| Duration::from_secs(secs))
+    }
 }
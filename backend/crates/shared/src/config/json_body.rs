@@ -0,0 +1,43 @@
+use std::sync::OnceLock;
+
+static JSON_BODY: OnceLock<JsonBodyConfig> = OnceLock::new();
+
+const DEFAULT_MAX_VALIDATION_ERRORS: usize = 20;
+
+#[derive(Debug, Clone, Copy)]
+pub struct JsonBodyConfig {
+    pub reject_unknown_fields: bool,
+    pub max_validation_errors: usize,
+}
+
+impl Default for JsonBodyConfig {
+    fn default() -> Self {
+        Self {
+            reject_unknown_fields: false,
+            max_validation_errors: DEFAULT_MAX_VALIDATION_ERRORS,
+        }
+    }
+}
+
+impl JsonBodyConfig {
+    pub fn from_env() -> Self {
+        let config = Self {
+            reject_unknown_fields: std::env::var("JSON_REJECT_UNKNOWN_FIELDS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(false),
+            max_validation_errors: std::env::var("JSON_MAX_VALIDATION_ERRORS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .filter(|v: &usize| *v > 0)
+                .unwrap_or(DEFAULT_MAX_VALIDATION_ERRORS),
+        };
+
+        tracing::info!("JSON body config loaded: {:?}", config);
+        config
+    }
+
+    pub fn global() -> &'static Self {
+        JSON_BODY.get_or_init(Self::from_env)
+    }
+}
@@ -2,15 +2,25 @@ use crate::{abstract_trait::jwt::JwtServiceTrait, errors::ServiceError};
 use anyhow::Result;
 use async_trait::async_trait;
 use chrono::{Duration, Utc};
-use jsonwebtoken::{DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use jsonwebtoken::{
+    DecodingKey, EncodingKey, Header, TokenData, Validation, decode, encode,
+    errors::{Error as JwtError, ErrorKind},
+};
 use serde::{Deserialize, Serialize};
+use tracing::warn;
 
-#[derive(Debug, Serialize, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Claims {
     pub user_id: i64,
     pub exp: usize,
     pub iat: usize,
     pub token_type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub iss: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub aud: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub auth_time: Option<usize>,
 }
 
 impl Claims {
@@ -20,19 +30,73 @@ impl Claims {
             exp,
             iat,
             token_type,
+            iss: None,
+            aud: None,
+            auth_time: None,
         }
     }
+
+    pub fn with_scope(mut self, issuer: &str, audience: &str) -> Self {
+        self.iss = Some(issuer.to_string());
+        self.aud = Some(audience.to_string());
+        self
+    }
+
+    pub fn with_auth_time(mut self, auth_time: Option<usize>) -> Self {
+        self.auth_time = auth_time;
+        self
+    }
+
+    pub fn authenticated_within(&self, max_age_secs: u64, now: usize) -> bool {
+        self.auth_time
+            .is_some_and(|auth_time| now.saturating_sub(auth_time) as u64 <= max_age_secs)
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct JwtConfig {
     pub jwt_secret: String,
+    pub issuer: String,
+    pub audience: String,
+    pub accept_legacy_tokens: bool,
 }
 
 impl JwtConfig {
-    pub fn new(jwt_secret: &str) -> Self {
+    pub fn new(jwt_secret: &str, issuer: &str, audience: &str, accept_legacy_tokens: bool) -> Self {
         JwtConfig {
             jwt_secret: jwt_secret.to_string(),
+            issuer: issuer.to_string(),
+            audience: audience.to_string(),
+            accept_legacy_tokens,
+        }
+    }
+
+    fn decode_claims(&self, token: &str) -> Result<TokenData<Claims>, JwtError> {
+        let decoding_key = DecodingKey::from_secret(self.jwt_secret.as_ref());
+
+        let mut validation = Validation::default();
+        validation.set_issuer(&[&self.issuer]);
+        validation.set_audience(&[&self.audience]);
+        validation.set_required_spec_claims(&["exp", "iss", "aud"]);
+
+        match decode::<Claims>(token, &decoding_key, &validation) {
+            Err(e)
+                if self.accept_legacy_tokens
+                    && matches!(e.kind(), ErrorKind::MissingRequiredClaim(claim) if claim == "iss" || claim == "aud") =>
+            {
+                let token_data = decode::<Claims>(token, &decoding_key, &Validation::default())?;
+
+                if token_data.claims.iss.is_some() || token_data.claims.aud.is_some() {
+                    return Err(e);
+                }
+
+                warn!(
+                    "⚠️ Accepted legacy token without iss/aud for user {}",
+                    token_data.claims.user_id
+                );
+                Ok(token_data)
+            }
+            result => result,
         }
     }
 }
@@ -40,15 +104,28 @@ impl JwtConfig {
 #[async_trait]
 impl JwtServiceTrait for JwtConfig {
     fn generate_token(&self, user_id: i64, token_type: &str) -> Result<String, ServiceError> {
+        let auth_time = Utc::now().timestamp() as usize;
+        self.generate_token_with_auth_time(user_id, token_type, Some(auth_time))
+    }
+
+    fn generate_token_with_auth_time(
+        &self,
+        user_id: i64,
+        token_type: &str,
+        auth_time: Option<usize>,
+    ) -> Result<String, ServiceError> {
         let now = Utc::now();
         let iat = now.timestamp() as usize;
         let exp = match token_type {
             "access" => (now + Duration::minutes(60)).timestamp() as usize,
             "refresh" => (now + Duration::days(7)).timestamp() as usize,
+            "password_change" => (now + Duration::minutes(15)).timestamp() as usize,
             _ => return Err(ServiceError::InvalidTokenType),
         };
 
-        let claims = Claims::new(user_id, exp, iat, token_type.to_string());
+        let claims = Claims::new(user_id, exp, iat, token_type.to_string())
+            .with_scope(&self.issuer, &self.audience)
+            .with_auth_time(auth_time);
 
         encode(
             &Header::default(),
@@ -59,9 +136,12 @@ impl JwtServiceTrait for JwtConfig {
     }
 
     fn verify_token(&self, token: &str, expected_type: &str) -> Result<i64, ServiceError> {
-        let decoding_key = DecodingKey::from_secret(self.jwt_secret.as_ref());
-        let token_data = decode::<Claims>(token, &decoding_key, &Validation::default())
-            .map_err(ServiceError::Jwt)?;
+        self.verify_claims(token, expected_type)
+            .map(|claims| claims.user_id)
+    }
+
+    fn verify_claims(&self, token: &str, expected_type: &str) -> Result<Claims, ServiceError> {
+        let token_data = self.decode_claims(token).map_err(ServiceError::Jwt)?;
 
         let current_time = Utc::now().timestamp() as usize;
 
@@ -73,6 +153,124 @@ impl JwtServiceTrait for JwtConfig {
             return Err(ServiceError::InvalidTokenType);
         }
 
-        Ok(token_data.claims.user_id)
+        Ok(token_data.claims)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SECRET: &str = "test-secret";
+
+    fn config(issuer: &str, audience: &str, accept_legacy_tokens: bool) -> JwtConfig {
+        JwtConfig::new(SECRET, issuer, audience, accept_legacy_tokens)
+    }
+
+    fn encode_claims(claims: &Claims) -> String {
+        encode(
+            &Header::default(),
+            claims,
+            &EncodingKey::from_secret(SECRET.as_ref()),
+        )
+        .unwrap()
+    }
+
+    fn unscoped_claims() -> Claims {
+        let now = Utc::now();
+        Claims::new(
+            42,
+            (now + Duration::minutes(5)).timestamp() as usize,
+            now.timestamp() as usize,
+            "access".to_string(),
+        )
+    }
+
+    #[test]
+    fn accepts_tokens_with_the_configured_claims() {
+        let jwt = config("payment-gateway", "payment-gateway-api", false);
+        let token = jwt.generate_token(42, "access").unwrap();
+
+        let claims = jwt.verify_claims(&token, "access").unwrap();
+        assert_eq!(claims.user_id, 42);
+        assert_eq!(claims.iss.as_deref(), Some("payment-gateway"));
+        assert_eq!(claims.aud.as_deref(), Some("payment-gateway-api"));
+    }
+
+    #[test]
+    fn rejects_a_wrong_audience() {
+        let token = config("payment-gateway", "other-api", false)
+            .generate_token(42, "access")
+            .unwrap();
+
+        let jwt = config("payment-gateway", "payment-gateway-api", true);
+        assert!(matches!(
+            jwt.verify_token(&token, "access"),
+            Err(ServiceError::Jwt(_))
+        ));
+    }
+
+    #[test]
+    fn rejects_a_wrong_issuer() {
+        let token = config("someone-else", "payment-gateway-api", false)
+            .generate_token(42, "access")
+            .unwrap();
+
+        let jwt = config("payment-gateway", "payment-gateway-api", true);
+        assert!(matches!(
+            jwt.verify_token(&token, "access"),
+            Err(ServiceError::Jwt(_))
+        ));
+    }
+
+    #[test]
+    fn legacy_tokens_need_the_fallback_flag() {
+        let token = encode_claims(&unscoped_claims());
+
+        assert!(
+            config("payment-gateway", "payment-gateway-api", false)
+                .verify_token(&token, "access")
+                .is_err()
+        );
+        assert_eq!(
+            config("payment-gateway", "payment-gateway-api", true)
+                .verify_token(&token, "access")
+                .unwrap(),
+            42
+        );
+    }
+
+    #[test]
+    fn partially_scoped_tokens_are_not_treated_as_legacy() {
+        let mut claims = unscoped_claims();
+        claims.iss = Some("payment-gateway".to_string());
+        let token = encode_claims(&claims);
+
+        assert!(
+            config("payment-gateway", "payment-gateway-api", true)
+                .verify_token(&token, "access")
+                .is_err()
+        );
+    }
+
+    fn claims(auth_time: Option<usize>) -> Claims {
+        Claims::new(42, 2_000, 1_000, "access".to_string()).with_auth_time(auth_time)
+    }
+
+    #[test]
+    fn recent_authentication_is_within_the_window() {
+        assert!(claims(Some(1_000)).authenticated_within(300, 1_200));
+        assert!(claims(Some(1_000)).authenticated_within(300, 1_300));
+    }
+
+    #[test]
+    fn stale_or_missing_authentication_is_rejected() {
+        assert!(!claims(Some(1_000)).authenticated_within(300, 1_301));
+        assert!(!claims(None).authenticated_within(300, 1_000));
+    }
+
+    #[test]
+    fn auth_time_in_the_future_counts_as_recent() {
+        assert!(claims(Some(5_000)).authenticated_within(0, 1_000));
     }
 }
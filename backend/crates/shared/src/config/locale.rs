@@ -0,0 +1,24 @@
+use crate::utils::{negotiate_locale, supported_locale};
+
+#[derive(Debug, Clone)]
+pub struct LocaleConfig {
+    pub default_locale: &'static str,
+}
+
+impl LocaleConfig {
+    pub fn from_env() -> Self {
+        let default_locale = std::env::var("DEFAULT_LOCALE")
+            .ok()
+            .and_then(|v| supported_locale(&v))
+            .unwrap_or("en");
+
+        let config = Self { default_locale };
+
+        tracing::info!("Locale config loaded: {:?}", config);
+        config
+    }
+
+    pub fn negotiate(&self, accept_language: Option<&str>) -> &'static str {
+        negotiate_locale(accept_language, self.default_locale)
+    }
+}
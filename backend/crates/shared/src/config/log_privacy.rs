@@ -0,0 +1,49 @@
+use crate::utils::{mask_email, redact_connection_string};
+
+#[derive(Debug, Clone, Copy)]
+pub struct LogPrivacyConfig {
+    pub log_full_emails: bool,
+    pub log_connection_credentials: bool,
+}
+
+impl LogPrivacyConfig {
+    pub fn from_env() -> Self {
+        let is_dev = std::env::var("DEV_MODE")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        let log_full_emails = std::env::var("LOG_FULL_EMAILS")
+            .ok()
+            .and_then(|v| v.trim().parse::<bool>().ok())
+            .unwrap_or(is_dev);
+
+        let log_connection_credentials = std::env::var("LOG_CONNECTION_CREDENTIALS")
+            .ok()
+            .and_then(|v| v.trim().parse::<bool>().ok())
+            .unwrap_or(false);
+
+        let config = Self {
+            log_full_emails,
+            log_connection_credentials,
+        };
+
+        tracing::info!("Log privacy config loaded: {:?}", config);
+        config
+    }
+
+    pub fn email(&self, email: &str) -> String {
+        if self.log_full_emails {
+            email.to_string()
+        } else {
+            mask_email(email)
+        }
+    }
+
+    pub fn connection_string(&self, text: &str) -> String {
+        if self.log_connection_credentials {
+            text.to_string()
+        } else {
+            redact_connection_string(text)
+        }
+    }
+}
@@ -0,0 +1,54 @@
+use std::sync::OnceLock;
+
+static METADATA_LIMITS: OnceLock<MetadataLimitsConfig> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy)]
+pub struct MetadataLimitsConfig {
+    pub max_keys: usize,
+    pub max_key_len: usize,
+    pub max_value_len: usize,
+    pub max_bytes: usize,
+    pub max_depth: usize,
+}
+
+impl Default for MetadataLimitsConfig {
+    fn default() -> Self {
+        Self {
+            max_keys: 20,
+            max_key_len: 40,
+            max_value_len: 500,
+            max_bytes: 4096,
+            max_depth: 3,
+        }
+    }
+}
+
+impl MetadataLimitsConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let config = Self {
+            max_keys: Self::get_env("TRANSACTION_METADATA_MAX_KEYS").unwrap_or(defaults.max_keys),
+            max_key_len: Self::get_env("TRANSACTION_METADATA_MAX_KEY_LEN")
+                .unwrap_or(defaults.max_key_len),
+            max_value_len: Self::get_env("TRANSACTION_METADATA_MAX_VALUE_LEN")
+                .unwrap_or(defaults.max_value_len),
+            max_bytes: Self::get_env("TRANSACTION_METADATA_MAX_BYTES")
+                .unwrap_or(defaults.max_bytes),
+            max_depth: Self::get_env("TRANSACTION_METADATA_MAX_DEPTH")
+                .unwrap_or(defaults.max_depth)
+                .max(1),
+        };
+
+        tracing::info!("Transaction metadata limits loaded: {:?}", config);
+        config
+    }
+
+    pub fn global() -> &'static Self {
+        METADATA_LIMITS.get_or_init(Self::from_env)
+    }
+
+    fn get_env(key: &str) -> Option<usize> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+}
@@ -1,21 +1,106 @@
+mod anomaly;
+mod cache_consistency;
+mod card_expiry;
+mod dashboard;
 mod database;
+mod dependency_wait;
+mod email_domain_denylist;
+mod email_normalization;
+mod email_verification;
+mod events;
+mod features;
 mod gateway_limiter;
 mod grpc_client;
+mod grpc_health;
 mod grpc_server;
 mod grpc_service_endpoints;
 mod hashing;
+mod json_body;
 mod jwt;
+mod locale;
+mod log_privacy;
+mod metadata_limits;
 mod myconfig;
+mod openapi;
+mod payment_methods;
+mod pending_transaction_expiry;
+mod pool_metrics;
+mod query_params;
+mod readiness;
+mod reauth;
 mod redis;
+mod request_id;
+mod request_timeout;
+mod restart_backoff;
+mod retry_after;
+mod saldo;
+mod security_headers;
+mod service_account;
 mod service_limiter;
+mod soft_delete_retention;
+mod span_redaction;
+mod statement_timeout;
+mod telemetry;
+mod transaction_fee;
+mod transfer_limits;
+mod warmup;
+mod webhook;
+mod withdraw_cancellation;
+mod write_limiter;
 
+pub use self::anomaly::AnomalyConfig;
+pub use self::cache_consistency::CacheConsistencyConfig;
+pub use self::card_expiry::CardExpiryConfig;
+pub use self::dashboard::DashboardConfig;
 pub use self::database::{ConnectionManager, ConnectionPool};
+pub use self::dependency_wait::DependencyWaitConfig;
+pub use self::email_domain_denylist::EmailDomainDenylist;
+pub use self::email_normalization::EmailNormalizationConfig;
+pub use self::email_verification::EmailVerificationConfig;
+pub use self::events::{EventSinkKind, EventsConfig};
+pub use self::features::FeaturesConfig;
 pub use self::gateway_limiter::GatewayLimiterConfig;
 pub use self::grpc_client::GrpcClientConfig;
+pub use self::grpc_health::GrpcHealthConfig;
 pub use self::grpc_server::GrpcServerConfig;
 pub use self::grpc_service_endpoints::GrpcServiceEndpoints;
 pub use self::hashing::Hashing;
-pub use self::jwt::JwtConfig;
+pub use self::json_body::JsonBodyConfig;
+pub use self::jwt::{Claims, JwtConfig};
+pub use self::locale::LocaleConfig;
+pub use self::log_privacy::LogPrivacyConfig;
+pub use self::metadata_limits::MetadataLimitsConfig;
 pub use self::myconfig::{Config, ServiceConfig};
+pub use self::openapi::OpenApiConfig;
+pub use self::payment_methods::{PaymentMethodRule, PaymentMethodsConfig};
+pub use self::pending_transaction_expiry::PendingTransactionExpiryConfig;
+pub use self::pool_metrics::PoolMetricsConfig;
+pub use self::query_params::QueryParamLoggingConfig;
+pub use self::readiness::ReadinessConfig;
+pub use self::reauth::ReauthConfig;
 pub use self::redis::{RedisConfig, RedisPool};
+pub use self::request_id::RequestIdConfig;
+pub use self::request_timeout::RequestTimeoutConfig;
+pub use self::restart_backoff::RestartBackoffConfig;
+pub use self::retry_after::RetryAfterConfig;
+pub use self::saldo::SaldoConfig;
+pub use self::security_headers::SecurityHeadersConfig;
+pub use self::service_account::{
+    SERVICE_ACCOUNT_HEADER, SERVICE_ACCOUNT_TOKEN_HEADER, ServiceAccount, ServiceAccountConfig,
+    ServiceAccountDecision,
+};
 pub use self::service_limiter::ServiceLimiterConfig;
+pub use self::soft_delete_retention::{
+    PROTECTED_ENTITIES, PURGEABLE_ENTITIES, SoftDeleteRetentionConfig,
+};
+pub use self::span_redaction::{SpanRedactionAction, SpanRedactionConfig};
+pub use self::statement_timeout::{
+    QueryCategory, StatementTimeoutConfig, begin_with_statement_timeout, set_statement_timeout,
+};
+pub use self::telemetry::{OtlpProtocol, TelemetryConfig};
+pub use self::transaction_fee::{FeeBreakdown, FeeRule, TransactionFeeConfig};
+pub use self::transfer_limits::{TransferLimit, TransferLimitsConfig};
+pub use self::warmup::WarmupConfig;
+pub use self::webhook::WebhookPolicyConfig;
+pub use self::withdraw_cancellation::WithdrawCancellationConfig;
+pub use self::write_limiter::WriteLimiterConfig;
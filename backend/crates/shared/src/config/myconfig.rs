@@ -1,13 +1,18 @@
+use crate::config::TelemetryConfig;
 use anyhow::{Context, Result, anyhow};
 
 #[derive(Debug, Clone)]
 pub struct Config {
     pub database_url: String,
     pub jwt_secret: String,
+    pub jwt_issuer: String,
+    pub jwt_audience: String,
+    pub jwt_accept_legacy_tokens: bool,
     pub run_migrations: bool,
     pub port: u16,
     pub db_max_conn: u32,
     pub db_min_conn: u32,
+    pub telemetry: TelemetryConfig,
     pub auth: ServiceConfig,
     pub card: ServiceConfig,
     pub merchant: ServiceConfig,
@@ -24,6 +29,14 @@ impl Config {
     pub fn init() -> Result<Self> {
         let database_url = std::env::var("DATABASE_URL").context("Missing env: DATABASE_URL")?;
         let jwt_secret = std::env::var("JWT_SECRET").context("Missing env: JWT_SECRET")?;
+        let jwt_issuer =
+            std::env::var("JWT_ISSUER").unwrap_or_else(|_| "example-payment-gateway".to_string());
+        let jwt_audience = std::env::var("JWT_AUDIENCE")
+            .unwrap_or_else(|_| "example-payment-gateway-api".to_string());
+        let jwt_accept_legacy_tokens = std::env::var("JWT_ACCEPT_LEGACY_TOKENS")
+            .unwrap_or_else(|_| "false".to_string())
+            .parse::<bool>()
+            .context("JWT_ACCEPT_LEGACY_TOKENS must be 'true' or 'false'")?;
         let run_migrations_str =
             std::env::var("RUN_MIGRATIONS").context("Missing env: RUN_MIGRATIONS")?;
         let port_str = std::env::var("PORT").context("Missing env: PORT")?;
@@ -55,10 +68,15 @@ impl Config {
         Ok(Self {
             database_url,
             jwt_secret,
+            jwt_issuer,
+            jwt_audience,
+            jwt_accept_legacy_tokens,
             run_migrations,
             port,
             db_max_conn,
             db_min_conn,
+            telemetry: TelemetryConfig::from_env()
+                .context("Invalid OpenTelemetry exporter configuration")?,
             auth: ServiceConfig::from_env("AUTH")?,
             card: ServiceConfig::from_env("CARD")?,
             merchant: ServiceConfig::from_env("MERCHANT")?,
@@ -77,6 +95,7 @@ impl Config {
 pub struct ServiceConfig {
     pub grpc_port: u16,
     pub metric_port: u16,
+    pub shutdown_timeout_secs: u64,
 }
 
 impl ServiceConfig {
@@ -91,9 +110,18 @@ impl ServiceConfig {
             .parse::<u16>()
             .context(format!("{prefix}_METRIC_PORT must be a valid u16 integer",))?;
 
+        let shutdown_timeout_secs = std::env::var(format!("{prefix}_SHUTDOWN_TIMEOUT_SECS"))
+            .or_else(|_| std::env::var("SHUTDOWN_TIMEOUT_SECS"))
+            .unwrap_or_else(|_| "30".to_string())
+            .parse::<u64>()
+            .context(format!(
+                "{prefix}_SHUTDOWN_TIMEOUT_SECS must be a valid u64 integer",
+            ))?;
+
         Ok(Self {
             grpc_port,
             metric_port,
+            shutdown_timeout_secs,
         })
     }
 }
@@ -0,0 +1,18 @@
+#[derive(Debug, Clone, Default)]
+pub struct OpenApiConfig {
+    pub server_url: Option<String>,
+}
+
+impl OpenApiConfig {
+    pub fn from_env() -> Self {
+        let config = Self {
+            server_url: std::env::var("OPENAPI_SERVER_URL")
+                .ok()
+                .map(|v| v.trim().trim_end_matches('/').to_string())
+                .filter(|v| !v.is_empty()),
+        };
+
+        tracing::info!("OpenAPI config loaded: {:?}", config);
+        config
+    }
+}
@@ -0,0 +1,135 @@
+use anyhow::{Result, anyhow};
+
+const DEFAULT_PAYMENT_METHODS: &str = "visa,mastercard,bca,bri,mandiri,gopay,ovo,dana";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentMethodRule {
+    pub name: String,
+    pub enabled: bool,
+    pub min_amount: Option<i64>,
+    pub max_amount: Option<i64>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PaymentMethodsConfig {
+    pub methods: Vec<PaymentMethodRule>,
+}
+
+impl PaymentMethodsConfig {
+    pub fn from_env() -> Result<Self> {
+        let raw = std::env::var("PAYMENT_METHODS")
+            .ok()
+            .filter(|v| !v.trim().is_empty())
+            .unwrap_or_else(|| DEFAULT_PAYMENT_METHODS.to_string());
+        let disabled = std::env::var("PAYMENT_METHODS_DISABLED").unwrap_or_default();
+
+        let config = Self::parse(&raw, &disabled)?;
+
+        tracing::info!("Payment methods config loaded: {:?}", config);
+        Ok(config)
+    }
+
+    pub fn parse(raw: &str, disabled: &str) -> Result<Self> {
+        let disabled: Vec<String> = disabled
+            .split(',')
+            .map(|m| m.trim().to_lowercase())
+            .filter(|m| !m.is_empty())
+            .collect();
+
+        let mut methods: Vec<PaymentMethodRule> = Vec::new();
+
+        for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let (name, limits) = match entry.split_once('=') {
+                Some((name, limits)) => (name.trim().to_lowercase(), Some(limits.trim())),
+                None => (entry.to_lowercase(), None),
+            };
+
+            if name.is_empty() {
+                return Err(anyhow!("PAYMENT_METHODS entry '{entry}' has an empty name"));
+            }
+            if methods.iter().any(|m| m.name == name) {
+                return Err(anyhow!("PAYMENT_METHODS lists '{name}' more than once"));
+            }
+
+            let (min_amount, max_amount) = match limits {
+                Some(limits) => Self::parse_limits(limits).ok_or_else(|| {
+                    anyhow!(
+                        "PAYMENT_METHODS entry '{entry}' has invalid limits, expected <min>..<max>"
+                    )
+                })?,
+                None => (None, None),
+            };
+
+            methods.push(PaymentMethodRule {
+                enabled: !disabled.contains(&name),
+                name,
+                min_amount,
+                max_amount,
+            });
+        }
+
+        Ok(Self { methods })
+    }
+
+    pub(crate) fn parse_limits(raw: &str) -> Option<(Option<i64>, Option<i64>)> {
+        let (min, max) = raw.split_once("..")?;
+
+        let bound = |v: &str| -> Option<Option<i64>> {
+            let v = v.trim();
+            if v.is_empty() {
+                return Some(None);
+            }
+            v.parse::<i64>().ok().filter(|n| *n >= 0).map(Some)
+        };
+
+        let (min, max) = (bound(min)?, bound(max)?);
+        if let (Some(min), Some(max)) = (min, max)
+            && min > max
+        {
+            return None;
+        }
+
+        Some((min, max))
+    }
+
+    pub fn get(&self, name: &str) -> Option<&PaymentMethodRule> {
+        let name = name.trim().to_lowercase();
+        self.methods.iter().find(|m| m.name == name)
+    }
+
+    pub fn check(&self, name: &str, amount: i64) -> Result<(), String> {
+        let rule = match self.get(name) {
+            Some(rule) => rule,
+            None => return Err(format!("payment method '{name}' is not supported")),
+        };
+
+        if !rule.enabled {
+            return Err(format!("payment method '{}' is disabled", rule.name));
+        }
+        if let Some(min) = rule.min_amount
+            && amount < min
+        {
+            return Err(format!(
+                "amount {amount} is below the minimum of {min} for payment method '{}'",
+                rule.name
+            ));
+        }
+        if let Some(max) = rule.max_amount
+            && amount > max
+        {
+            return Err(format!(
+                "amount {amount} is above the maximum of {max} for payment method '{}'",
+                rule.name
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn available(&self, include_disabled: bool) -> Vec<&PaymentMethodRule> {
+        self.methods
+            .iter()
+            .filter(|m| include_disabled || m.enabled)
+            .collect()
+    }
+}
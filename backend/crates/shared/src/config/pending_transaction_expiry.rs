@@ -0,0 +1,55 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PendingTransactionExpiryConfig {
+    pub enabled: bool,
+    pub max_age_secs: u64,
+    pub interval_secs: u64,
+    pub batch_size: i64,
+}
+
+impl Default for PendingTransactionExpiryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            max_age_secs: 1800,
+            interval_secs: 60,
+            batch_size: 500,
+        }
+    }
+}
+
+impl PendingTransactionExpiryConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let config = Self {
+            enabled: Self::get_env("PENDING_TRANSACTION_EXPIRY_ENABLED")
+                .unwrap_or(defaults.enabled),
+            max_age_secs: Self::get_env("PENDING_TRANSACTION_MAX_AGE_SECS")
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.max_age_secs),
+            interval_secs: Self::get_env("PENDING_TRANSACTION_EXPIRY_INTERVAL_SECS")
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.interval_secs),
+            batch_size: Self::get_env("PENDING_TRANSACTION_EXPIRY_BATCH_SIZE")
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.batch_size),
+        };
+
+        tracing::info!("Pending transaction expiry config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    pub fn max_age(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.max_age_secs as i64)
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_secs)
+    }
+}
@@ -0,0 +1,40 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+pub struct PoolMetricsConfig {
+    pub enabled: bool,
+    pub sample_interval_secs: u64,
+}
+
+impl Default for PoolMetricsConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            sample_interval_secs: 15,
+        }
+    }
+}
+
+impl PoolMetricsConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let config = Self {
+            enabled: Self::get_env("DB_POOL_METRICS_ENABLED").unwrap_or(defaults.enabled),
+            sample_interval_secs: Self::get_env("DB_POOL_METRICS_INTERVAL_SECS")
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.sample_interval_secs),
+        };
+
+        tracing::info!("Pool metrics config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    pub fn sample_interval(&self) -> Duration {
+        Duration::from_secs(self.sample_interval_secs)
+    }
+}
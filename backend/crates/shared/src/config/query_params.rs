@@ -0,0 +1,113 @@
+use std::sync::OnceLock;
+
+static QUERY_PARAMS: OnceLock<QueryParamLoggingConfig> = OnceLock::new();
+
+const REDACTED: &str = "[REDACTED]";
+
+const SENSITIVE_KEYS: &[&str] = &[
+    "password",
+    "confirm_password",
+    "token",
+    "secret",
+    "api_key",
+    "cvv",
+    "card_number",
+    "transfer_from",
+    "transfer_to",
+    "email",
+];
+
+#[derive(Debug, Clone, Default)]
+pub struct QueryParamLoggingConfig {
+    pub enabled: bool,
+    pub extra_sensitive_keys: Vec<String>,
+}
+
+impl QueryParamLoggingConfig {
+    pub fn from_env() -> Self {
+        let is_dev = std::env::var("DEV_MODE")
+            .map(|v| v == "true" || v == "1")
+            .unwrap_or(false);
+
+        let requested = std::env::var("LOG_QUERY_PARAMS")
+            .ok()
+            .and_then(|v| v.trim().parse::<bool>().ok())
+            .unwrap_or(false);
+
+        if requested && !is_dev {
+            tracing::warn!("LOG_QUERY_PARAMS is ignored because DEV_MODE is not enabled");
+        }
+
+        let extra_sensitive_keys = std::env::var("LOG_QUERY_PARAMS_REDACT")
+            .unwrap_or_default()
+            .split(',')
+            .map(|key| key.trim().to_ascii_lowercase())
+            .filter(|key| !key.is_empty())
+            .collect();
+
+        let config = Self::new(is_dev, requested, extra_sensitive_keys);
+
+        tracing::info!("Query param logging config loaded: {:?}", config);
+        config
+    }
+
+    pub fn new(is_dev: bool, requested: bool, extra_sensitive_keys: Vec<String>) -> Self {
+        Self {
+            enabled: is_dev && requested,
+            extra_sensitive_keys,
+        }
+    }
+
+    pub fn global() -> &'static Self {
+        QUERY_PARAMS.get_or_init(Self::from_env)
+    }
+
+    pub fn is_sensitive(&self, key: &str) -> bool {
+        let key = key.to_ascii_lowercase();
+
+        SENSITIVE_KEYS
+            .iter()
+            .any(|sensitive| key == *sensitive || key.ends_with(&format!("_{sensitive}")))
+            || self.extra_sensitive_keys.contains(&key)
+    }
+
+    pub fn sanitize(&self, key: &str, value: &dyn std::fmt::Debug) -> String {
+        if self.is_sensitive(key) {
+            REDACTED.to_string()
+        } else {
+            format!("{value:?}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_known_keys_and_suffixes() {
+        let config = QueryParamLoggingConfig::new(true, true, Vec::new());
+
+        assert!(config.is_sensitive("password"));
+        assert!(config.is_sensitive("Card_Number"));
+        assert!(config.is_sensitive("new_password"));
+        assert!(config.is_sensitive("user_email"));
+        assert!(!config.is_sensitive("page"));
+        assert!(!config.is_sensitive("tokens"));
+    }
+
+    #[test]
+    fn honours_extra_keys() {
+        let config = QueryParamLoggingConfig::new(true, true, vec!["merchant_ref".to_string()]);
+
+        assert!(config.is_sensitive("MERCHANT_REF"));
+        assert_eq!(config.sanitize("merchant_ref", &"abc"), REDACTED);
+        assert_eq!(config.sanitize("page", &2), "2");
+    }
+
+    #[test]
+    fn logging_needs_dev_mode() {
+        assert!(!QueryParamLoggingConfig::new(false, true, Vec::new()).enabled);
+        assert!(QueryParamLoggingConfig::new(true, true, Vec::new()).enabled);
+    }
+}
@@ -0,0 +1,37 @@
+use std::{collections::HashSet, time::Duration};
+
+#[derive(Debug, Clone)]
+pub struct ReadinessConfig {
+    pub critical: HashSet<String>,
+    pub check_timeout_ms: u64,
+}
+
+impl ReadinessConfig {
+    pub fn from_env() -> Self {
+        let critical = std::env::var("READINESS_CRITICAL_DEPENDENCIES")
+            .unwrap_or_else(|_| "redis,auth".to_string())
+            .split(',')
+            .map(|name| name.trim().to_lowercase())
+            .filter(|name| !name.is_empty())
+            .collect();
+
+        let config = Self {
+            critical,
+            check_timeout_ms: std::env::var("READINESS_CHECK_TIMEOUT_MS")
+                .ok()
+                .and_then(|v| v.trim().parse().ok())
+                .unwrap_or(1000),
+        };
+
+        tracing::info!("Readiness config loaded: {:?}", config);
+        config
+    }
+
+    pub fn is_critical(&self, dependency: &str) -> bool {
+        self.critical.contains(dependency)
+    }
+
+    pub fn check_timeout(&self) -> Duration {
+        Duration::from_millis(self.check_timeout_ms)
+    }
+}
@@ -0,0 +1,67 @@
+use crate::config::Claims;
+use chrono::Utc;
+
+#[derive(Debug, Clone)]
+pub struct ReauthConfig {
+    pub max_age_secs: u64,
+    pub routes: Vec<String>,
+    pub transfer_amount_threshold: Option<i64>,
+}
+
+impl Default for ReauthConfig {
+    fn default() -> Self {
+        Self {
+            max_age_secs: 900,
+            routes: vec!["/api/auth/change-password".to_string()],
+            transfer_amount_threshold: Some(10_000_000),
+        }
+    }
+}
+
+impl ReauthConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let config = Self {
+            max_age_secs: Self::get_env("REAUTH_MAX_AGE_SECS")
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.max_age_secs),
+            routes: std::env::var("REAUTH_REQUIRED_ROUTES")
+                .map(|v| {
+                    v.split(',')
+                        .map(str::trim)
+                        .filter(|route| !route.is_empty())
+                        .map(String::from)
+                        .collect()
+                })
+                .unwrap_or(defaults.routes),
+            transfer_amount_threshold: match Self::get_env::<i64>(
+                "REAUTH_TRANSFER_AMOUNT_THRESHOLD",
+            ) {
+                Some(v) if v <= 0 => None,
+                Some(v) => Some(v),
+                None => defaults.transfer_amount_threshold,
+            },
+        };
+
+        tracing::info!("Re-auth config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    pub fn requires_reauth(&self, route: &str) -> bool {
+        self.routes.iter().any(|r| r == route)
+    }
+
+    pub fn requires_reauth_for_transfer(&self, amount: i64) -> bool {
+        self.transfer_amount_threshold
+            .is_some_and(|threshold| amount >= threshold)
+    }
+
+    pub fn is_recent(&self, claims: &Claims) -> bool {
+        claims.authenticated_within(self.max_age_secs, Utc::now().timestamp() as usize)
+    }
+}
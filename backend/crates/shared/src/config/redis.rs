@@ -1,11 +1,12 @@
-use anyhow::{Context, Result};
+use crate::config::LogPrivacyConfig;
+use anyhow::{Result, anyhow};
 use deadpool_redis::{
     Config as DeadpoolRedisConfig, Connection, Pool, PoolError, Runtime, redis::cmd,
 };
-use std::env;
+use std::{env, fmt};
 use tracing::info;
 
-#[derive(Debug, Clone)]
+#[derive(Clone)]
 pub struct RedisConfig {
     pub host: String,
     pub port: u16,
@@ -59,6 +60,18 @@ impl RedisConfig {
     }
 }
 
+impl fmt::Debug for RedisConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RedisConfig")
+            .field("host", &self.host)
+            .field("port", &self.port)
+            .field("db", &self.db)
+            .field("user", &self.user)
+            .field("password", &self.password.as_ref().map(|_| "****"))
+            .finish()
+    }
+}
+
 impl Default for RedisConfig {
     fn default() -> Self {
         RedisConfig::new()
@@ -72,13 +85,22 @@ pub struct RedisPool {
 
 impl RedisPool {
     pub fn new(config: &RedisConfig) -> Result<Self> {
-        info!("Creating redis pool (deadpool-redis)");
+        let privacy = LogPrivacyConfig::from_env();
+        let url = config.url();
+
+        info!(
+            "Creating redis pool (deadpool-redis) for {}",
+            privacy.connection_string(&url)
+        );
 
-        let pool_cfg = DeadpoolRedisConfig::from_url(config.url());
+        let pool_cfg = DeadpoolRedisConfig::from_url(url);
 
-        let pool = pool_cfg
-            .create_pool(Some(Runtime::Tokio1))
-            .context("failed create redis connection pool")?;
+        let pool = pool_cfg.create_pool(Some(Runtime::Tokio1)).map_err(|e| {
+            anyhow!(
+                "failed create redis connection pool: {}",
+                privacy.connection_string(&e.to_string())
+            )
+        })?;
 
         Ok(Self { pool })
     }
@@ -94,4 +116,10 @@ impl RedisPool {
         info!("Pinged redis (deadpool-redis)");
         Ok(())
     }
+
+    pub async fn check(&self) -> Result<(), PoolError> {
+        let mut conn = self.get_conn().await?;
+        cmd("PING").query_async::<()>(&mut conn).await?;
+        Ok(())
+    }
 }
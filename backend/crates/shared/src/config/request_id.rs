@@ -0,0 +1,42 @@
+#[derive(Debug, Clone)]
+pub struct RequestIdConfig {
+    pub headers: Vec<String>,
+}
+
+impl Default for RequestIdConfig {
+    fn default() -> Self {
+        Self {
+            headers: vec!["x-request-id".to_string()],
+        }
+    }
+}
+
+impl RequestIdConfig {
+    pub fn from_env() -> Self {
+        let headers: Vec<String> = std::env::var("REQUEST_ID_HEADERS")
+            .map(|v| {
+                v.split(',')
+                    .map(|h| h.trim().to_ascii_lowercase())
+                    .filter(|h| !h.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        let config = if headers.is_empty() {
+            Self::default()
+        } else {
+            Self { headers }
+        };
+
+        tracing::info!("Request ID config loaded: {:?}", config);
+        config
+    }
+
+    pub fn primary_header(&self) -> &str {
+        self.headers
+            .iter()
+            .map(String::as_str)
+            .find(|h| *h != "traceparent")
+            .unwrap_or("x-request-id")
+    }
+}
@@ -0,0 +1,41 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy)]
+pub struct RequestTimeoutConfig {
+    pub timeout_secs: u64,
+    pub propagate_deadline: bool,
+}
+
+impl Default for RequestTimeoutConfig {
+    fn default() -> Self {
+        Self {
+            timeout_secs: 45,
+            propagate_deadline: true,
+        }
+    }
+}
+
+impl RequestTimeoutConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let config = Self {
+            timeout_secs: Self::get_env("GATEWAY_REQUEST_TIMEOUT_SECS")
+                .filter(|v| *v > 0)
+                .unwrap_or(defaults.timeout_secs),
+            propagate_deadline: Self::get_env("GATEWAY_PROPAGATE_DEADLINE")
+                .unwrap_or(defaults.propagate_deadline),
+        };
+
+        tracing::info!("Request timeout config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+}
@@ -0,0 +1,52 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct RestartBackoffConfig {
+    pub initial_secs: u64,
+    pub max_secs: u64,
+    pub multiplier: f64,
+    pub jitter_ratio: f64,
+    pub healthy_reset_secs: u64,
+}
+
+impl RestartBackoffConfig {
+    pub fn from_env() -> Self {
+        let initial_secs = Self::get_env("GRPC_RESTART_INITIAL_SECS")
+            .unwrap_or(5)
+            .max(1);
+        let max_secs = Self::get_env("GRPC_RESTART_MAX_SECS")
+            .unwrap_or(120)
+            .max(initial_secs);
+
+        let config = Self {
+            initial_secs,
+            max_secs,
+            multiplier: Self::get_env("GRPC_RESTART_MULTIPLIER")
+                .unwrap_or(2.0_f64)
+                .max(1.0),
+            jitter_ratio: Self::get_env("GRPC_RESTART_JITTER_RATIO")
+                .unwrap_or(0.2_f64)
+                .clamp(0.0, 1.0),
+            healthy_reset_secs: Self::get_env("GRPC_RESTART_HEALTHY_RESET_SECS").unwrap_or(60),
+        };
+
+        tracing::info!("gRPC restart backoff config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    pub fn initial(&self) -> Duration {
+        Duration::from_secs(self.initial_secs)
+    }
+
+    pub fn max(&self) -> Duration {
+        Duration::from_secs(self.max_secs)
+    }
+
+    pub fn healthy_reset(&self) -> Duration {
+        Duration::from_secs(self.healthy_reset_secs)
+    }
+}
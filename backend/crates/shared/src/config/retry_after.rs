@@ -0,0 +1,82 @@
+use rand::{Rng, rng};
+
+#[derive(Debug, Clone)]
+pub struct RetryAfterConfig {
+    pub load_shed_secs: u64,
+    pub jitter_secs: u64,
+    pub min_secs: u64,
+    pub max_secs: u64,
+}
+
+impl RetryAfterConfig {
+    pub fn from_env() -> Self {
+        let min_secs = Self::get_env("RETRY_AFTER_MIN_SECS").unwrap_or(1).max(1);
+        let max_secs = Self::get_env("RETRY_AFTER_MAX_SECS")
+            .unwrap_or(120)
+            .max(min_secs);
+
+        let config = Self {
+            load_shed_secs: Self::get_env("RETRY_AFTER_LOAD_SHED_SECS").unwrap_or(2),
+            jitter_secs: Self::get_env("RETRY_AFTER_JITTER_SECS").unwrap_or(5),
+            min_secs,
+            max_secs,
+        };
+
+        tracing::info!("Retry-After config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env(key: &str) -> Option<u64> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    pub fn seconds(&self, base_secs: u64) -> u64 {
+        let jitter = if self.jitter_secs > 0 {
+            rng().random_range(0..=self.jitter_secs)
+        } else {
+            0
+        };
+
+        base_secs
+            .saturating_add(jitter)
+            .clamp(self.min_secs, self.max_secs)
+    }
+
+    pub fn header_value(&self, base_secs: u64) -> String {
+        self.seconds(base_secs).to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(jitter_secs: u64) -> RetryAfterConfig {
+        RetryAfterConfig {
+            load_shed_secs: 2,
+            jitter_secs,
+            min_secs: 1,
+            max_secs: 30,
+        }
+    }
+
+    #[test]
+    fn clamps_to_the_configured_range() {
+        let config = config(0);
+
+        assert_eq!(config.seconds(0), 1);
+        assert_eq!(config.seconds(10), 10);
+        assert_eq!(config.seconds(600), 30);
+        assert_eq!(config.header_value(u64::MAX), "30");
+    }
+
+    #[test]
+    fn adds_at_most_the_jitter() {
+        let config = config(5);
+
+        for _ in 0..50 {
+            let secs = config.seconds(10);
+            assert!((10..=15).contains(&secs));
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use anyhow::{Result, anyhow};
+
+#[derive(Debug, Clone, Default)]
+pub struct SaldoConfig {
+    pub default_initial_balance: i64,
+}
+
+impl SaldoConfig {
+    pub fn from_env() -> Result<Self> {
+        let default_initial_balance = match std::env::var("DEFAULT_INITIAL_BALANCE") {
+            Ok(raw) => raw
+                .trim()
+                .parse::<i64>()
+                .map_err(|_| anyhow!("DEFAULT_INITIAL_BALANCE must be a valid integer"))?,
+            Err(_) => 0,
+        };
+
+        if default_initial_balance < 0 {
+            return Err(anyhow!(
+                "DEFAULT_INITIAL_BALANCE must be non-negative, got {default_initial_balance}"
+            ));
+        }
+
+        let config = Self {
+            default_initial_balance,
+        };
+
+        tracing::info!("Saldo config loaded: {:?}", config);
+        Ok(config)
+    }
+}
@@ -0,0 +1,81 @@
+#[derive(Debug, Clone)]
+pub struct SecurityHeadersConfig {
+    pub enabled: bool,
+    pub hsts_enabled: bool,
+    pub hsts_max_age_secs: u64,
+    pub hsts_include_subdomains: bool,
+    pub frame_options: String,
+    pub content_security_policy: Option<String>,
+    pub docs_content_security_policy: Option<String>,
+}
+
+impl SecurityHeadersConfig {
+    const DOCS_PATHS: [&'static str; 2] = ["/swagger-ui", "/api-docs"];
+
+    pub fn from_env() -> Self {
+        let config = Self {
+            enabled: Self::get_env("SECURITY_HEADERS_ENABLED").unwrap_or(true),
+            hsts_enabled: Self::get_env("SECURITY_HEADERS_HSTS_ENABLED").unwrap_or(true),
+            hsts_max_age_secs: Self::get_env("SECURITY_HEADERS_HSTS_MAX_AGE_SECS")
+                .unwrap_or(31_536_000),
+            hsts_include_subdomains: Self::get_env("SECURITY_HEADERS_HSTS_INCLUDE_SUBDOMAINS")
+                .unwrap_or(true),
+            frame_options: std::env::var("SECURITY_HEADERS_FRAME_OPTIONS")
+                .ok()
+                .map(|v| v.trim().to_ascii_uppercase())
+                .filter(|v| v == "DENY" || v == "SAMEORIGIN")
+                .unwrap_or_else(|| "DENY".to_string()),
+            content_security_policy: Self::get_policy(
+                "SECURITY_HEADERS_CSP",
+                "default-src 'none'; frame-ancestors 'none'",
+            ),
+            docs_content_security_policy: Self::get_policy(
+                "SECURITY_HEADERS_DOCS_CSP",
+                "default-src 'self'; script-src 'self' 'unsafe-inline'; \
+                 style-src 'self' 'unsafe-inline'; img-src 'self' data:; \
+                 frame-ancestors 'none'",
+            ),
+        };
+
+        tracing::info!("Security headers config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    fn get_policy(key: &str, default: &str) -> Option<String> {
+        match std::env::var(key) {
+            Ok(v) if v.trim().is_empty() => None,
+            Ok(v) => Some(v.trim().to_string()),
+            Err(_) => Some(default.to_string()),
+        }
+    }
+
+    pub fn hsts_value(&self) -> Option<String> {
+        if !self.hsts_enabled {
+            return None;
+        }
+
+        let mut value = format!("max-age={}", self.hsts_max_age_secs);
+        if self.hsts_include_subdomains {
+            value.push_str("; includeSubDomains");
+        }
+        Some(value)
+    }
+
+    pub fn is_docs_path(path: &str) -> bool {
+        Self::DOCS_PATHS
+            .iter()
+            .any(|prefix| path.starts_with(prefix))
+    }
+
+    pub fn content_security_policy_for(&self, path: &str) -> Option<&str> {
+        if Self::is_docs_path(path) {
+            self.docs_content_security_policy.as_deref()
+        } else {
+            self.content_security_policy.as_deref()
+        }
+    }
+}
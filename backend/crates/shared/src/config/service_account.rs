@@ -0,0 +1,191 @@
+use anyhow::{Result, anyhow};
+use std::collections::{HashMap, HashSet};
+
+pub const SERVICE_ACCOUNT_HEADER: &str = "x-service-account";
+pub const SERVICE_ACCOUNT_TOKEN_HEADER: &str = "x-service-account-token";
+
+#[derive(Clone)]
+pub struct ServiceAccount {
+    pub token: String,
+    pub methods: HashSet<String>,
+}
+
+#[derive(Clone, Default)]
+pub struct ServiceAccountConfig {
+    pub accounts: HashMap<String, ServiceAccount>,
+}
+
+impl std::fmt::Debug for ServiceAccountConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let accounts: HashMap<&String, &HashSet<String>> = self
+            .accounts
+            .iter()
+            .map(|(name, account)| (name, &account.methods))
+            .collect();
+
+        f.debug_struct("ServiceAccountConfig")
+            .field("accounts", &accounts)
+            .finish()
+    }
+}
+
+impl ServiceAccountConfig {
+    pub fn from_env() -> Result<Self> {
+        let tokens = std::env::var("SERVICE_ACCOUNTS").unwrap_or_default();
+        let methods = std::env::var("SERVICE_ACCOUNT_METHODS").unwrap_or_default();
+
+        let config = Self::parse(&tokens, &methods)?;
+
+        tracing::info!("Service account config loaded: {:?}", config);
+        Ok(config)
+    }
+
+    pub fn parse(tokens: &str, methods: &str) -> Result<Self> {
+        let mut accounts: HashMap<String, ServiceAccount> = HashMap::new();
+
+        for (name, token) in Self::entries("SERVICE_ACCOUNTS", tokens)? {
+            if token.len() < 32 {
+                return Err(anyhow!(
+                    "SERVICE_ACCOUNTS token for '{name}' must be at least 32 characters"
+                ));
+            }
+
+            accounts.insert(
+                name.to_string(),
+                ServiceAccount {
+                    token: token.to_string(),
+                    methods: HashSet::new(),
+                },
+            );
+        }
+
+        for (name, list) in Self::entries("SERVICE_ACCOUNT_METHODS", methods)? {
+            let account = accounts.get_mut(name).ok_or_else(|| {
+                anyhow!("SERVICE_ACCOUNT_METHODS references unknown service account '{name}'")
+            })?;
+
+            for method in list.split('|').map(str::trim).filter(|m| !m.is_empty()) {
+                if !method.starts_with('/') || method.matches('/').count() != 2 {
+                    return Err(anyhow!(
+                        "SERVICE_ACCOUNT_METHODS entry '{method}' must look like /package.Service/Method"
+                    ));
+                }
+                account.methods.insert(method.to_string());
+            }
+        }
+
+        Ok(Self { accounts })
+    }
+
+    fn entries<'a>(key: &str, raw: &'a str) -> Result<Vec<(&'a str, &'a str)>> {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                entry
+                    .split_once('=')
+                    .map(|(name, value)| (name.trim(), value.trim()))
+                    .filter(|(name, value)| !name.is_empty() && !value.is_empty())
+                    .ok_or_else(|| anyhow!("{key} entry must be <name>=<value>"))
+            })
+            .collect()
+    }
+
+    pub fn is_protected(&self, method: &str) -> bool {
+        self.accounts
+            .values()
+            .any(|account| account.methods.contains(method))
+    }
+
+    pub fn authorize(&self, name: &str, token: &str, method: &str) -> ServiceAccountDecision {
+        let Some(account) = self.accounts.get(name) else {
+            return ServiceAccountDecision::Unauthenticated;
+        };
+
+        if !constant_time_eq(account.token.as_bytes(), token.as_bytes()) {
+            return ServiceAccountDecision::Unauthenticated;
+        }
+
+        if account.methods.contains(method) {
+            ServiceAccountDecision::Allowed
+        } else {
+            ServiceAccountDecision::MethodNotAllowed
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ServiceAccountDecision {
+    Allowed,
+    Unauthenticated,
+    MethodNotAllowed,
+}
+
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TOKEN: &str = "0123456789abcdef0123456789abcdef";
+
+    fn config() -> ServiceAccountConfig {
+        ServiceAccountConfig::parse(
+            &format!("billing={TOKEN}"),
+            "billing=/card.CardService/FindByIdCard|/saldo.SaldoService/FindAllSaldo",
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn allows_listed_methods_with_the_right_token() {
+        assert_eq!(
+            config().authorize("billing", TOKEN, "/card.CardService/FindByIdCard"),
+            ServiceAccountDecision::Allowed
+        );
+    }
+
+    #[test]
+    fn rejects_unknown_accounts_and_wrong_tokens() {
+        let config = config();
+
+        assert_eq!(
+            config.authorize("reports", TOKEN, "/card.CardService/FindByIdCard"),
+            ServiceAccountDecision::Unauthenticated
+        );
+        assert_eq!(
+            config.authorize(
+                "billing",
+                "fedcba9876543210fedcba9876543210",
+                "/card.CardService/FindByIdCard"
+            ),
+            ServiceAccountDecision::Unauthenticated
+        );
+        assert_eq!(
+            config.authorize("billing", "short", "/card.CardService/FindByIdCard"),
+            ServiceAccountDecision::Unauthenticated
+        );
+    }
+
+    #[test]
+    fn rejects_methods_outside_the_account() {
+        assert_eq!(
+            config().authorize("billing", TOKEN, "/user.UserService/FindAll"),
+            ServiceAccountDecision::MethodNotAllowed
+        );
+    }
+
+    #[test]
+    fn parse_validates_tokens_and_methods() {
+        assert!(ServiceAccountConfig::parse("billing=short", "").is_err());
+        assert!(ServiceAccountConfig::parse(&format!("billing={TOKEN}"), "other=/a.B/C").is_err());
+        assert!(
+            ServiceAccountConfig::parse(&format!("billing={TOKEN}"), "billing=FindAll").is_err()
+        );
+    }
+}
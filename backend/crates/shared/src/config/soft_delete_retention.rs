@@ -0,0 +1,90 @@
+use std::{collections::BTreeSet, time::Duration};
+use tracing::warn;
+
+pub const PURGEABLE_ENTITIES: &[(&str, &str)] = &[
+    ("cards", "card_id"),
+    ("merchants", "merchant_id"),
+    ("roles", "role_id"),
+    ("topups", "topup_id"),
+    ("transfers", "transfer_id"),
+    ("users", "user_id"),
+    ("withdraws", "withdraw_id"),
+];
+
+pub const PROTECTED_ENTITIES: &[&str] = &["saldos", "transactions"];
+
+const DEFAULT_PURGE_ENTITIES: &str = "cards,merchants,topups,transfers,withdraws";
+
+#[derive(Debug, Clone)]
+pub struct SoftDeleteRetentionConfig {
+    pub enabled: bool,
+    pub retention_days: u32,
+    pub interval_secs: u64,
+    pub entities: BTreeSet<String>,
+}
+
+impl SoftDeleteRetentionConfig {
+    pub fn from_env() -> Self {
+        let entities = Self::parse_entities(
+            &std::env::var("SOFT_DELETE_PURGE_ENTITIES")
+                .unwrap_or_else(|_| DEFAULT_PURGE_ENTITIES.to_string()),
+        );
+
+        let config = Self {
+            enabled: Self::get_env("SOFT_DELETE_PURGE_ENABLED").unwrap_or(false),
+            retention_days: Self::get_env::<u32>("SOFT_DELETE_RETENTION_DAYS")
+                .filter(|v| *v > 0)
+                .unwrap_or(90),
+            interval_secs: Self::get_env::<u64>("SOFT_DELETE_PURGE_INTERVAL_SECS")
+                .filter(|v| *v > 0)
+                .unwrap_or(86400),
+            entities,
+        };
+
+        tracing::info!("Soft-delete retention config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    fn parse_entities(raw: &str) -> BTreeSet<String> {
+        raw.split(',')
+            .map(|entity| entity.trim().to_lowercase())
+            .filter(|entity| !entity.is_empty())
+            .filter(|entity| {
+                if PROTECTED_ENTITIES.contains(&entity.as_str()) {
+                    warn!(
+                        "⚠️  {entity} are never purged; ignoring it in SOFT_DELETE_PURGE_ENTITIES"
+                    );
+                    return false;
+                }
+                if Self::id_column(entity).is_none() {
+                    warn!("⚠️  Unknown entity {entity:?} in SOFT_DELETE_PURGE_ENTITIES");
+                    return false;
+                }
+                true
+            })
+            .collect()
+    }
+
+    pub fn id_column(entity: &str) -> Option<&'static str> {
+        PURGEABLE_ENTITIES
+            .iter()
+            .find(|(name, _)| *name == entity)
+            .map(|(_, id_column)| *id_column)
+    }
+
+    pub fn should_purge(&self, entity: &str) -> bool {
+        self.enabled && self.entities.contains(entity)
+    }
+
+    pub fn retention(&self) -> chrono::Duration {
+        chrono::Duration::days(self.retention_days as i64)
+    }
+
+    pub fn interval(&self) -> Duration {
+        Duration::from_secs(self.interval_secs)
+    }
+}
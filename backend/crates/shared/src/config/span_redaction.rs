@@ -0,0 +1,222 @@
+use anyhow::{Result, bail};
+use opentelemetry::{KeyValue, Value};
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+
+const DEFAULT_RULES: &str = "email=hash,user.email=hash,user_email=hash,password=drop,token=drop,access_token=drop,refresh_token=drop";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SpanRedactionAction {
+    Hash,
+    Drop,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct SpanRedactionConfig {
+    pub rules: HashMap<String, SpanRedactionAction>,
+    pub operation_rules: HashMap<(String, String), SpanRedactionAction>,
+    pub salt: String,
+}
+
+impl SpanRedactionConfig {
+    pub fn from_env() -> Result<Self> {
+        let raw = std::env::var("SPAN_REDACTION_RULES").unwrap_or_else(|_| DEFAULT_RULES.into());
+        let salt = std::env::var("SPAN_REDACTION_SALT").unwrap_or_default();
+
+        let config = Self::parse(&raw, salt);
+        config.validate()?;
+
+        tracing::info!(
+            "Span redaction config loaded: {} global rules, {} per-operation rules",
+            config.rules.len(),
+            config.operation_rules.len()
+        );
+        Ok(config)
+    }
+
+    /// Unsalted hashes of emails can be reversed with a dictionary, so any
+    /// `hash` rule requires a non-empty salt.
+    pub fn validate(&self) -> Result<()> {
+        let hashes = self
+            .rules
+            .values()
+            .chain(self.operation_rules.values())
+            .any(|action| *action == SpanRedactionAction::Hash);
+
+        if hashes && self.salt.trim().is_empty() {
+            bail!("SPAN_REDACTION_SALT must be set when SPAN_REDACTION_RULES contains hash rules");
+        }
+        Ok(())
+    }
+
+    pub fn parse(raw: &str, salt: String) -> Self {
+        let mut config = Self {
+            salt,
+            ..Default::default()
+        };
+
+        for entry in raw.split(',').map(str::trim).filter(|e| !e.is_empty()) {
+            let Some((target, action)) = entry.split_once('=') else {
+                tracing::warn!("Ignoring span redaction rule without action: {entry}");
+                continue;
+            };
+
+            let action = match action.trim().to_ascii_lowercase().as_str() {
+                "hash" => SpanRedactionAction::Hash,
+                "drop" => SpanRedactionAction::Drop,
+                other => {
+                    tracing::warn!("Ignoring span redaction rule with unknown action {other:?}");
+                    continue;
+                }
+            };
+
+            match target.trim().split_once(':') {
+                Some((operation, key)) => {
+                    config.operation_rules.insert(
+                        (operation.trim().to_string(), key.trim().to_string()),
+                        action,
+                    );
+                }
+                None => {
+                    config.rules.insert(target.trim().to_string(), action);
+                }
+            }
+        }
+
+        config
+    }
+
+    pub fn action_for(&self, operation: &str, key: &str) -> Option<SpanRedactionAction> {
+        self.operation_rules
+            .get(&(operation.to_string(), key.to_string()))
+            .or_else(|| self.rules.get(key))
+            .copied()
+    }
+
+    pub fn apply(&self, operation: &str, attributes: Vec<KeyValue>) -> Vec<KeyValue> {
+        if self.rules.is_empty() && self.operation_rules.is_empty() {
+            return attributes;
+        }
+
+        attributes
+            .into_iter()
+            .filter_map(|kv| match self.action_for(operation, kv.key.as_str()) {
+                None => Some(kv),
+                Some(SpanRedactionAction::Drop) => None,
+                Some(SpanRedactionAction::Hash) => {
+                    let hashed = self.hash(&kv.value);
+                    Some(KeyValue::new(kv.key, hashed))
+                }
+            })
+            .collect()
+    }
+
+    fn hash(&self, value: &Value) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.salt.as_bytes());
+        hasher.update(value.as_str().as_bytes());
+        let digest = hex::encode(hasher.finalize());
+        format!("sha256:{}", &digest[..16])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn value_of<'a>(attributes: &'a [KeyValue], key: &str) -> Option<&'a Value> {
+        attributes
+            .iter()
+            .find(|kv| kv.key.as_str() == key)
+            .map(|kv| &kv.value)
+    }
+
+    #[test]
+    fn drops_and_hashes_matching_attributes() {
+        let config = SpanRedactionConfig::parse("email=hash,password=drop", "salt".into());
+
+        let redacted = config.apply(
+            "login",
+            vec![
+                KeyValue::new("email", "alice@example.com"),
+                KeyValue::new("password", "hunter2"),
+                KeyValue::new("user_id", "42"),
+            ],
+        );
+
+        assert_eq!(redacted.len(), 2);
+        assert_eq!(value_of(&redacted, "password"), None);
+        assert_eq!(
+            value_of(&redacted, "user_id").map(|v| v.as_str().to_string()),
+            Some("42".to_string())
+        );
+
+        let hashed = value_of(&redacted, "email").unwrap().as_str().to_string();
+        assert!(hashed.starts_with("sha256:"));
+        assert_eq!(hashed.len(), "sha256:".len() + 16);
+        assert!(!hashed.contains("alice"));
+    }
+
+    #[test]
+    fn hashes_depend_on_the_salt() {
+        let attribute = || vec![KeyValue::new("email", "alice@example.com")];
+        let first = SpanRedactionConfig::parse("email=hash", "one".into()).apply("op", attribute());
+        let again = SpanRedactionConfig::parse("email=hash", "one".into()).apply("op", attribute());
+        let other = SpanRedactionConfig::parse("email=hash", "two".into()).apply("op", attribute());
+
+        assert_eq!(value_of(&first, "email"), value_of(&again, "email"));
+        assert_ne!(value_of(&first, "email"), value_of(&other, "email"));
+    }
+
+    #[test]
+    fn hash_rules_require_a_salt() {
+        assert!(
+            SpanRedactionConfig::parse("email=hash", String::new())
+                .validate()
+                .is_err()
+        );
+        assert!(
+            SpanRedactionConfig::parse("login:email=hash", " ".into())
+                .validate()
+                .is_err()
+        );
+        assert!(
+            SpanRedactionConfig::parse("email=hash", "salt".into())
+                .validate()
+                .is_ok()
+        );
+        assert!(
+            SpanRedactionConfig::parse("password=drop", String::new())
+                .validate()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn operation_rules_override_global_rules() {
+        let config = SpanRedactionConfig::parse("email=hash,register:email=drop", "salt".into());
+
+        assert!(
+            config
+                .apply("register", vec![KeyValue::new("email", "a@b.c")])
+                .is_empty()
+        );
+        assert_eq!(
+            config
+                .apply("login", vec![KeyValue::new("email", "a@b.c")])
+                .len(),
+            1
+        );
+    }
+
+    #[test]
+    fn ignores_malformed_rules() {
+        let config = SpanRedactionConfig::parse("email, token=mask, password=drop", "".into());
+
+        assert_eq!(config.rules.len(), 1);
+        assert_eq!(
+            config.action_for("any", "password"),
+            Some(SpanRedactionAction::Drop)
+        );
+    }
+}
@@ -0,0 +1,79 @@
+use crate::{config::ConnectionPool, errors::RepositoryError, observability::begin_transaction};
+use sqlx::{PgConnection, Postgres, Transaction};
+use std::sync::OnceLock;
+use tracing::error;
+
+static STATEMENT_TIMEOUTS: OnceLock<StatementTimeoutConfig> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QueryCategory {
+    Oltp,
+    Analytics,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct StatementTimeoutConfig {
+    pub oltp_ms: u64,
+    pub analytics_ms: u64,
+}
+
+impl StatementTimeoutConfig {
+    pub fn from_env() -> Self {
+        let config = Self {
+            oltp_ms: Self::get_env("DB_STATEMENT_TIMEOUT_OLTP_MS").unwrap_or(5_000),
+            analytics_ms: Self::get_env("DB_STATEMENT_TIMEOUT_ANALYTICS_MS").unwrap_or(30_000),
+        };
+
+        tracing::info!("Statement timeout config loaded: {:?}", config);
+        config
+    }
+
+    pub fn global() -> &'static Self {
+        STATEMENT_TIMEOUTS.get_or_init(Self::from_env)
+    }
+
+    fn get_env(key: &str) -> Option<u64> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    pub fn timeout_ms(&self, category: QueryCategory) -> u64 {
+        match category {
+            QueryCategory::Oltp => self.oltp_ms,
+            QueryCategory::Analytics => self.analytics_ms,
+        }
+    }
+}
+
+pub async fn set_statement_timeout(
+    conn: &mut PgConnection,
+    category: QueryCategory,
+) -> Result<(), RepositoryError> {
+    let timeout_ms = StatementTimeoutConfig::global().timeout_ms(category);
+    if timeout_ms == 0 {
+        return Ok(());
+    }
+
+    sqlx::query(&format!("SET LOCAL statement_timeout = {timeout_ms}"))
+        .execute(conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to set {category:?} statement timeout: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+    Ok(())
+}
+
+pub async fn begin_with_statement_timeout(
+    db: &ConnectionPool,
+    category: QueryCategory,
+) -> Result<Transaction<'static, Postgres>, RepositoryError> {
+    let mut tx = begin_transaction(db).await.map_err(|e| {
+        error!("❌ Failed to begin {category:?} transaction: {e:?}");
+        RepositoryError::from(e)
+    })?;
+
+    set_statement_timeout(&mut tx, category).await?;
+
+    Ok(tx)
+}
@@ -0,0 +1,76 @@
+use anyhow::{Result, bail};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OtlpProtocol {
+    Grpc,
+    HttpProtobuf,
+}
+
+impl OtlpProtocol {
+    fn parse(raw: &str) -> Result<Self> {
+        match raw.trim().to_ascii_lowercase().as_str() {
+            "grpc" => Ok(Self::Grpc),
+            "http" | "http/protobuf" => Ok(Self::HttpProtobuf),
+            other => bail!(
+                "OTEL_EXPORTER_OTLP_PROTOCOL must be 'grpc' or 'http/protobuf', got '{other}'"
+            ),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct TelemetryConfig {
+    pub endpoint: Option<String>,
+    pub protocol: OtlpProtocol,
+    pub headers: Vec<(String, String)>,
+}
+
+impl fmt::Debug for TelemetryConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let header_names: Vec<&str> = self.headers.iter().map(|(k, _)| k.as_str()).collect();
+        f.debug_struct("TelemetryConfig")
+            .field("endpoint", &self.endpoint)
+            .field("protocol", &self.protocol)
+            .field("headers", &header_names)
+            .finish()
+    }
+}
+
+impl TelemetryConfig {
+    pub fn from_env() -> Result<Self> {
+        let endpoint = std::env::var("OTEL_EXPORTER_OTLP_ENDPOINT")
+            .ok()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty());
+
+        let protocol = match std::env::var("OTEL_EXPORTER_OTLP_PROTOCOL") {
+            Ok(v) if !v.trim().is_empty() => OtlpProtocol::parse(&v)?,
+            _ => OtlpProtocol::Grpc,
+        };
+
+        let headers = std::env::var("OTEL_EXPORTER_OTLP_HEADERS")
+            .map(|v| {
+                v.split(',')
+                    .filter_map(|pair| pair.split_once('='))
+                    .map(|(k, v)| (k.trim().to_ascii_lowercase(), v.trim().to_string()))
+                    .filter(|(k, _)| !k.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+
+        Ok(Self {
+            endpoint,
+            protocol,
+            headers,
+        })
+    }
+
+    pub fn signal_endpoint(&self, http_path: &str) -> Option<String> {
+        let endpoint = self.endpoint.as_deref()?;
+        Some(match self.protocol {
+            OtlpProtocol::Grpc => endpoint.to_string(),
+            OtlpProtocol::HttpProtobuf => format!("{}{http_path}", endpoint.trim_end_matches('/')),
+        })
+    }
+}
@@ -0,0 +1,235 @@
+use anyhow::{Result, anyhow};
+use std::collections::HashMap;
+
+const BPS_SCALE: i128 = 10_000;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FeeRule {
+    pub flat: i64,
+    pub percent_bps: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeBreakdown {
+    pub gross: i64,
+    pub fee: i64,
+    pub net: i64,
+}
+
+impl FeeRule {
+    pub fn parse(raw: &str) -> Result<Self> {
+        let mut rule = FeeRule::default();
+
+        for part in raw.split('+').map(str::trim).filter(|p| !p.is_empty()) {
+            if let Some(percent) = part.strip_suffix('%') {
+                rule.percent_bps = Self::parse_percent_bps(percent.trim())
+                    .ok_or_else(|| anyhow!("invalid fee percentage '{part}'"))?;
+            } else {
+                rule.flat = part
+                    .parse::<i64>()
+                    .ok()
+                    .filter(|v| *v >= 0)
+                    .ok_or_else(|| anyhow!("invalid flat fee '{part}'"))?;
+            }
+        }
+
+        Ok(rule)
+    }
+
+    pub fn compute(&self, amount: i64) -> FeeBreakdown {
+        let gross = amount.max(0);
+        let percent_fee = (gross as i128 * self.percent_bps as i128 + BPS_SCALE / 2) / BPS_SCALE;
+        let fee = (self.flat as i128 + percent_fee).min(gross as i128) as i64;
+
+        FeeBreakdown {
+            gross,
+            fee,
+            net: gross - fee,
+        }
+    }
+
+    fn parse_percent_bps(raw: &str) -> Option<i64> {
+        let (whole, frac) = raw.split_once('.').unwrap_or((raw, ""));
+        if frac.len() > 2 || !frac.chars().all(|c| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let whole = whole.parse::<i64>().ok()?;
+        let frac = format!("{frac:0<2}").parse::<i64>().ok()?;
+        let bps = whole.checked_mul(100)?.checked_add(frac)?;
+
+        (0..=BPS_SCALE as i64).contains(&bps).then_some(bps)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct TransactionFeeConfig {
+    pub default_rule: FeeRule,
+    pub payment_methods: HashMap<String, FeeRule>,
+    pub merchants: HashMap<i32, FeeRule>,
+}
+
+impl TransactionFeeConfig {
+    pub fn from_env() -> Result<Self> {
+        let default_rule = match std::env::var("TRANSACTION_FEE_DEFAULT") {
+            Ok(raw) if !raw.trim().is_empty() => FeeRule::parse(&raw)
+                .map_err(|e| anyhow!("TRANSACTION_FEE_DEFAULT is invalid: {e}"))?,
+            _ => FeeRule::default(),
+        };
+
+        let payment_methods = Self::parse_overrides("TRANSACTION_FEE_PAYMENT_METHODS", |k| {
+            Some(k.to_lowercase())
+        })?;
+        let merchants =
+            Self::parse_overrides("TRANSACTION_FEE_MERCHANTS", |k| k.parse::<i32>().ok())?;
+
+        let config = Self {
+            default_rule,
+            payment_methods,
+            merchants,
+        };
+
+        tracing::info!("Transaction fee config loaded: {:?}", config);
+        Ok(config)
+    }
+
+    pub fn rule_for(&self, merchant_id: i32, payment_method: &str) -> FeeRule {
+        self.merchants
+            .get(&merchant_id)
+            .or_else(|| self.payment_methods.get(&payment_method.to_lowercase()))
+            .copied()
+            .unwrap_or(self.default_rule)
+    }
+
+    pub fn compute(&self, merchant_id: i32, payment_method: &str, amount: i64) -> FeeBreakdown {
+        self.rule_for(merchant_id, payment_method).compute(amount)
+    }
+
+    fn parse_overrides<K, F>(key: &str, parse_key: F) -> Result<HashMap<K, FeeRule>>
+    where
+        K: std::hash::Hash + Eq,
+        F: Fn(&str) -> Option<K>,
+    {
+        let Ok(raw) = std::env::var(key) else {
+            return Ok(HashMap::new());
+        };
+
+        raw.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (name, rule) = entry
+                    .split_once('=')
+                    .ok_or_else(|| anyhow!("{key} entry '{entry}' must be <name>=<rule>"))?;
+                let name = parse_key(name.trim())
+                    .ok_or_else(|| anyhow!("{key} entry '{entry}' has an invalid key"))?;
+                let rule = FeeRule::parse(rule).map_err(|e| anyhow!("{key} is invalid: {e}"))?;
+                Ok((name, rule))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_flat_and_percent_parts() {
+        assert_eq!(
+            FeeRule::parse("500 + 1.5%").unwrap(),
+            FeeRule {
+                flat: 500,
+                percent_bps: 150,
+            }
+        );
+        assert_eq!(
+            FeeRule::parse("2%").unwrap(),
+            FeeRule {
+                flat: 0,
+                percent_bps: 200,
+            }
+        );
+        assert_eq!(FeeRule::parse("").unwrap(), FeeRule::default());
+    }
+
+    #[test]
+    fn rejects_invalid_rules() {
+        assert!(FeeRule::parse("-5").is_err());
+        assert!(FeeRule::parse("1.234%").is_err());
+        assert!(FeeRule::parse("101%").is_err());
+        assert!(FeeRule::parse("abc").is_err());
+    }
+
+    #[test]
+    fn rounds_percent_fee_half_up() {
+        let rule = FeeRule {
+            flat: 0,
+            percent_bps: 150,
+        };
+
+        assert_eq!(
+            rule.compute(1_001),
+            FeeBreakdown {
+                gross: 1_001,
+                fee: 15,
+                net: 986,
+            }
+        );
+        assert_eq!(rule.compute(1_000).fee, 15);
+        assert_eq!(rule.compute(1_034).fee, 16);
+    }
+
+    #[test]
+    fn caps_fee_at_gross_and_ignores_negative_amounts() {
+        let rule = FeeRule {
+            flat: 1_000,
+            percent_bps: 0,
+        };
+
+        assert_eq!(
+            rule.compute(400),
+            FeeBreakdown {
+                gross: 400,
+                fee: 400,
+                net: 0,
+            }
+        );
+        assert_eq!(
+            rule.compute(-50),
+            FeeBreakdown {
+                gross: 0,
+                fee: 0,
+                net: 0,
+            }
+        );
+    }
+
+    #[test]
+    fn merchant_rule_wins_over_payment_method_rule() {
+        let config = TransactionFeeConfig {
+            default_rule: FeeRule {
+                flat: 1,
+                percent_bps: 0,
+            },
+            payment_methods: HashMap::from([(
+                "gopay".to_string(),
+                FeeRule {
+                    flat: 2,
+                    percent_bps: 0,
+                },
+            )]),
+            merchants: HashMap::from([(
+                7,
+                FeeRule {
+                    flat: 3,
+                    percent_bps: 0,
+                },
+            )]),
+        };
+
+        assert_eq!(config.rule_for(7, "gopay").flat, 3);
+        assert_eq!(config.rule_for(8, "GoPay").flat, 2);
+        assert_eq!(config.rule_for(8, "ovo").flat, 1);
+    }
+}
@@ -0,0 +1,312 @@
+use crate::{config::PaymentMethodsConfig, utils::mask_card_number};
+use anyhow::{Result, anyhow};
+use std::{collections::HashMap, fmt};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TransferLimit {
+    pub min_amount: Option<i64>,
+    pub max_amount: Option<i64>,
+}
+
+#[derive(Clone, Default)]
+pub struct TransferLimitsConfig {
+    pub default_limit: TransferLimit,
+    pub merchants: HashMap<i32, TransferLimit>,
+    pub cards: HashMap<String, TransferLimit>,
+}
+
+impl fmt::Debug for TransferLimitsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let cards: HashMap<String, TransferLimit> = self
+            .cards
+            .iter()
+            .map(|(card_number, limit)| (mask_card_number(card_number), *limit))
+            .collect();
+
+        f.debug_struct("TransferLimitsConfig")
+            .field("default_limit", &self.default_limit)
+            .field("merchants", &self.merchants)
+            .field("cards", &cards)
+            .finish()
+    }
+}
+
+impl TransferLimitsConfig {
+    pub fn from_env() -> Result<Self> {
+        let default_limit = TransferLimit {
+            min_amount: Self::parse_amount("TRANSFER_MIN_AMOUNT")?,
+            max_amount: Self::parse_amount("TRANSFER_MAX_AMOUNT")?,
+        };
+
+        if let (Some(min), Some(max)) = (default_limit.min_amount, default_limit.max_amount)
+            && min > max
+        {
+            return Err(anyhow!(
+                "TRANSFER_MIN_AMOUNT ({min}) must not exceed TRANSFER_MAX_AMOUNT ({max})"
+            ));
+        }
+
+        let raw = std::env::var("TRANSFER_LIMITS_MERCHANTS").unwrap_or_default();
+        let merchants = Self::parse_merchants(&raw)?;
+
+        let raw = std::env::var("TRANSFER_LIMITS_CARDS").unwrap_or_default();
+        let cards = Self::parse_cards(&raw)?;
+
+        let config = Self {
+            default_limit,
+            merchants,
+            cards,
+        };
+
+        tracing::info!("Transfer limits config loaded: {:?}", config);
+        Ok(config)
+    }
+
+    pub fn parse_cards(raw: &str) -> Result<HashMap<String, TransferLimit>> {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (card_number, limits) = entry.split_once('=').ok_or_else(|| {
+                    anyhow!(
+                        "TRANSFER_LIMITS_CARDS entry '{}' must be <card_number>=<min>..<max>",
+                        mask_card_number(entry)
+                    )
+                })?;
+                let (min_amount, max_amount) = PaymentMethodsConfig::parse_limits(limits.trim())
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "TRANSFER_LIMITS_CARDS entry for card {} has invalid limits, expected <min>..<max>",
+                            mask_card_number(card_number.trim())
+                        )
+                    })?;
+
+                Ok((
+                    card_number.trim().to_string(),
+                    TransferLimit {
+                        min_amount,
+                        max_amount,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    pub fn parse_merchants(raw: &str) -> Result<HashMap<i32, TransferLimit>> {
+        raw.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| {
+                let (merchant_id, limits) = entry.split_once('=').ok_or_else(|| {
+                    anyhow!(
+                        "TRANSFER_LIMITS_MERCHANTS entry '{entry}' must be <merchant_id>=<min>..<max>"
+                    )
+                })?;
+                let merchant_id = merchant_id.trim().parse::<i32>().map_err(|_| {
+                    anyhow!("TRANSFER_LIMITS_MERCHANTS entry '{entry}' has an invalid merchant id")
+                })?;
+                let (min_amount, max_amount) = PaymentMethodsConfig::parse_limits(limits.trim())
+                    .ok_or_else(|| {
+                        anyhow!(
+                            "TRANSFER_LIMITS_MERCHANTS entry for merchant {merchant_id} has invalid limits, expected <min>..<max>"
+                        )
+                    })?;
+
+                Ok((
+                    merchant_id,
+                    TransferLimit {
+                        min_amount,
+                        max_amount,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// The merchant override, falling back to the system default per side.
+    pub fn merchant_limit(&self, merchant_id: Option<i32>) -> TransferLimit {
+        match merchant_id.and_then(|id| self.merchants.get(&id)) {
+            Some(merchant) => TransferLimit {
+                min_amount: merchant.min_amount.or(self.default_limit.min_amount),
+                max_amount: merchant.max_amount.or(self.default_limit.max_amount),
+            },
+            None => self.default_limit,
+        }
+    }
+
+    /// Resolves each side of the limit from the card override, then the
+    /// merchant override, then the system default.
+    pub fn limit_for(&self, card_number: &str, merchant_id: Option<i32>) -> TransferLimit {
+        let merchant = self.merchant_limit(merchant_id);
+
+        match self.cards.get(card_number) {
+            Some(card) => TransferLimit {
+                min_amount: card.min_amount.or(merchant.min_amount),
+                max_amount: card.max_amount.or(merchant.max_amount),
+            },
+            None => merchant,
+        }
+    }
+
+    /// Name of the most specific override that applies, as reported to clients.
+    pub fn source_for(&self, card_number: Option<&str>, merchant_id: Option<i32>) -> &'static str {
+        if card_number.is_some_and(|card| self.cards.contains_key(card)) {
+            "card"
+        } else if merchant_id.is_some_and(|id| self.merchants.contains_key(&id)) {
+            "merchant"
+        } else {
+            "default"
+        }
+    }
+
+    pub fn check(
+        &self,
+        card_number: &str,
+        merchant_id: Option<i32>,
+        amount: i64,
+    ) -> Result<(), String> {
+        let limit = self.limit_for(card_number, merchant_id);
+        let masked_card = mask_card_number(card_number);
+
+        if let Some(min) = limit.min_amount
+            && amount < min
+        {
+            return Err(format!(
+                "transfer amount {amount} is below the minimum of {min} for card {masked_card}"
+            ));
+        }
+
+        if let Some(max) = limit.max_amount
+            && amount > max
+        {
+            return Err(format!(
+                "transfer amount {amount} exceeds the maximum of {max} for card {masked_card}"
+            ));
+        }
+
+        Ok(())
+    }
+
+    fn parse_amount(key: &str) -> Result<Option<i64>> {
+        match std::env::var(key) {
+            Ok(raw) if !raw.trim().is_empty() => raw
+                .trim()
+                .parse::<i64>()
+                .ok()
+                .filter(|v| *v >= 0)
+                .map(Some)
+                .ok_or_else(|| anyhow!("{key} must be a non-negative integer")),
+            _ => Ok(None),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CARD: &str = "4111111111111111";
+    const OTHER: &str = "4222222222222222";
+    const MERCHANT: i32 = 7;
+
+    fn config() -> TransferLimitsConfig {
+        TransferLimitsConfig {
+            default_limit: TransferLimit {
+                min_amount: Some(10_000),
+                max_amount: Some(1_000_000),
+            },
+            merchants: TransferLimitsConfig::parse_merchants(&format!("{MERCHANT}=50000..2000000"))
+                .unwrap(),
+            cards: TransferLimitsConfig::parse_cards(&format!("{CARD}=..5000000")).unwrap(),
+        }
+    }
+
+    #[test]
+    fn card_override_falls_back_to_default_per_side() {
+        assert_eq!(
+            config().limit_for(CARD, None),
+            TransferLimit {
+                min_amount: Some(10_000),
+                max_amount: Some(5_000_000),
+            }
+        );
+        assert_eq!(config().limit_for(OTHER, None), config().default_limit);
+    }
+
+    #[test]
+    fn checks_amounts_against_the_card_limit() {
+        let config = config();
+
+        assert!(config.check(CARD, None, 2_000_000).is_ok());
+        assert!(config.check(OTHER, None, 2_000_000).is_err());
+        assert!(config.check(CARD, None, 9_999).is_err());
+        assert!(config.check(CARD, None, 5_000_001).is_err());
+    }
+
+    #[test]
+    fn card_overrides_beat_merchant_overrides_beat_the_default() {
+        let config = config();
+
+        assert_eq!(
+            config.limit_for(OTHER, Some(MERCHANT)),
+            TransferLimit {
+                min_amount: Some(50_000),
+                max_amount: Some(2_000_000),
+            }
+        );
+        assert_eq!(
+            config.limit_for(CARD, Some(MERCHANT)),
+            TransferLimit {
+                min_amount: Some(50_000),
+                max_amount: Some(5_000_000),
+            }
+        );
+        assert_eq!(config.limit_for(OTHER, Some(99)), config.default_limit);
+
+        assert_eq!(config.source_for(Some(CARD), Some(MERCHANT)), "card");
+        assert_eq!(config.source_for(Some(OTHER), Some(MERCHANT)), "merchant");
+        assert_eq!(config.source_for(Some(OTHER), Some(99)), "default");
+    }
+
+    #[test]
+    fn checks_amounts_against_the_merchant_limit() {
+        let config = config();
+
+        assert!(config.check(OTHER, Some(MERCHANT), 1_500_000).is_ok());
+        assert!(config.check(OTHER, Some(MERCHANT), 20_000).is_err());
+        assert!(config.check(OTHER, Some(MERCHANT), 2_000_001).is_err());
+        assert!(config.check(CARD, Some(MERCHANT), 3_000_000).is_ok());
+    }
+
+    #[test]
+    fn rejects_malformed_merchant_entries() {
+        assert!(TransferLimitsConfig::parse_merchants("abc=1..2").is_err());
+        assert!(TransferLimitsConfig::parse_merchants("7").is_err());
+        assert!(TransferLimitsConfig::parse_merchants("7=x").is_err());
+        assert!(
+            TransferLimitsConfig::parse_merchants("")
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn errors_never_contain_the_full_card_number() {
+        let err = config().check(CARD, None, 1).unwrap_err();
+
+        assert!(err.contains(&mask_card_number(CARD)));
+        assert!(!err.contains(CARD));
+
+        let err = TransferLimitsConfig::parse_cards(&format!("{CARD}=abc"))
+            .unwrap_err()
+            .to_string();
+        assert!(!err.contains(CARD));
+    }
+
+    #[test]
+    fn debug_output_masks_card_numbers() {
+        let debug = format!("{:?}", config());
+
+        assert!(!debug.contains(CARD));
+    }
+}
@@ -0,0 +1,29 @@
+use std::time::Duration;
+
+#[derive(Debug, Clone)]
+pub struct WarmupConfig {
+    pub enabled: bool,
+    pub db_connections: u32,
+    pub timeout_secs: u64,
+}
+
+impl WarmupConfig {
+    pub fn from_env() -> Self {
+        let config = Self {
+            enabled: Self::get_env("WARMUP_ENABLED").unwrap_or(false),
+            db_connections: Self::get_env("WARMUP_DB_CONNECTIONS").unwrap_or(4),
+            timeout_secs: Self::get_env("WARMUP_TIMEOUT_SECS").unwrap_or(10).max(1),
+        };
+
+        tracing::info!("Warm-up config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    pub fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout_secs)
+    }
+}
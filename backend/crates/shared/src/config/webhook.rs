@@ -0,0 +1,55 @@
+use anyhow::Result;
+
+#[derive(Debug, Clone, Default)]
+pub struct WebhookPolicyConfig {
+    pub allowed_hosts: Vec<String>,
+    pub denied_hosts: Vec<String>,
+    pub allow_private_networks: bool,
+    pub ordered_delivery: bool,
+    pub batch_max_size: usize,
+    pub batch_max_wait_ms: u64,
+    pub batch_max_attempts: u32,
+}
+
+impl WebhookPolicyConfig {
+    pub fn from_env() -> Result<Self> {
+        let config = Self {
+            allowed_hosts: Self::get_list("WEBHOOK_ALLOWED_HOSTS"),
+            denied_hosts: Self::get_list("WEBHOOK_DENIED_HOSTS"),
+            allow_private_networks: std::env::var("WEBHOOK_ALLOW_PRIVATE_NETWORKS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(false),
+            ordered_delivery: std::env::var("WEBHOOK_ORDERED_DELIVERY")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(true),
+            batch_max_size: std::env::var("WEBHOOK_BATCH_MAX_SIZE")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(1),
+            batch_max_wait_ms: std::env::var("WEBHOOK_BATCH_MAX_WAIT_MS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .unwrap_or(500),
+            batch_max_attempts: std::env::var("WEBHOOK_BATCH_MAX_ATTEMPTS")
+                .ok()
+                .and_then(|v| v.parse().ok())
+                .filter(|v| *v > 0)
+                .unwrap_or(3),
+        };
+
+        tracing::info!("Webhook policy config loaded: {:?}", config);
+        Ok(config)
+    }
+
+    fn get_list(key: &str) -> Vec<String> {
+        std::env::var(key)
+            .unwrap_or_default()
+            .split(',')
+            .map(|host| host.trim().trim_end_matches('.').to_ascii_lowercase())
+            .filter(|host| !host.is_empty())
+            .collect()
+    }
+}
@@ -0,0 +1,36 @@
+#[derive(Debug, Clone, Copy)]
+pub struct WithdrawCancellationConfig {
+    pub window_secs: u64,
+}
+
+impl Default for WithdrawCancellationConfig {
+    fn default() -> Self {
+        Self { window_secs: 900 }
+    }
+}
+
+impl WithdrawCancellationConfig {
+    pub fn from_env() -> Self {
+        let defaults = Self::default();
+
+        let config = Self {
+            window_secs: Self::get_env("WITHDRAW_CANCELLATION_WINDOW_SECS")
+                .unwrap_or(defaults.window_secs),
+        };
+
+        tracing::info!("Withdraw cancellation config loaded: {:?}", config);
+        config
+    }
+
+    fn get_env<T: std::str::FromStr>(key: &str) -> Option<T> {
+        std::env::var(key).ok().and_then(|v| v.trim().parse().ok())
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.window_secs > 0
+    }
+
+    pub fn window(&self) -> chrono::Duration {
+        chrono::Duration::seconds(self.window_secs as i64)
+    }
+}
@@ -0,0 +1,23 @@
+use anyhow::Result;
+
+#[derive(Debug, Clone)]
+pub struct WriteLimiterConfig {
+    pub max_concurrent_writes: usize,
+}
+
+impl WriteLimiterConfig {
+    pub fn from_env() -> Result<Self> {
+        let max_concurrent_writes = std::env::var("DB_WRITE_MAX_CONCURRENT")
+            .ok()
+            .and_then(|v| v.trim().parse::<usize>().ok())
+            .unwrap_or(20)
+            .max(1);
+
+        let config = Self {
+            max_concurrent_writes,
+        };
+
+        tracing::info!("Write limiter config loaded: {:?}", config);
+        Ok(config)
+    }
+}
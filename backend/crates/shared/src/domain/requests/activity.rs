@@ -0,0 +1,37 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+pub const MAX_ACTIVITY_PAGE_SIZE: i32 = 50;
+pub const MAX_ACTIVITY_WINDOW: i32 = 100;
+
+#[derive(Debug, Deserialize, IntoParams, Clone)]
+pub struct FindRecentActivity {
+    #[serde(default = "default_page")]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    pub page_size: i32,
+}
+
+fn default_page() -> i32 {
+    1
+}
+
+fn default_page_size() -> i32 {
+    10
+}
+
+impl FindRecentActivity {
+    pub fn page_size(&self) -> i32 {
+        self.page_size.clamp(1, MAX_ACTIVITY_PAGE_SIZE)
+    }
+
+    pub fn page(&self) -> i32 {
+        let max_page = (MAX_ACTIVITY_WINDOW / self.page_size()).max(1);
+        self.page.clamp(1, max_page)
+    }
+
+    pub fn window(&self) -> i32 {
+        self.page() * self.page_size()
+    }
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyScope {
+    Topup,
+    Transaction,
+    Transfer,
+    Withdraw,
+}
+
+impl AnomalyScope {
+    pub fn source(&self) -> (&'static str, &'static str, &'static str) {
+        match self {
+            AnomalyScope::Topup => ("topups", "topup_time", "topup_amount"),
+            AnomalyScope::Transaction => ("transactions", "transaction_time", "amount"),
+            AnomalyScope::Transfer => ("transfers", "transfer_time", "transfer_amount"),
+            AnomalyScope::Withdraw => ("withdraws", "withdraw_time", "withdraw_amount"),
+        }
+    }
+}
+
+impl fmt::Display for AnomalyScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            AnomalyScope::Topup => "topup",
+            AnomalyScope::Transaction => "transaction",
+            AnomalyScope::Transfer => "transfer",
+            AnomalyScope::Withdraw => "withdraw",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum AnomalyMetric {
+    TotalAmount,
+    Count,
+}
+
+impl fmt::Display for AnomalyMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            AnomalyMetric::TotalAmount => "total_amount",
+            AnomalyMetric::Count => "count",
+        };
+        f.write_str(name)
+    }
+}
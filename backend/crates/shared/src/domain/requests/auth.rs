@@ -28,3 +28,30 @@ pub struct RegisterRequest {
     #[validate(length(min = 6, message = "Confirm password minimal 6 karakter"))]
     pub confirm_password: String,
 }
+
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct VerifyEmailRequest {
+    #[validate(length(min = 1, message = "Verification token is required"))]
+    pub token: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct ResendVerificationRequest {
+    #[validate(email(message = "Email tidak valid"))]
+    pub email: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct ChangePasswordRequest {
+    #[validate(length(min = 6, message = "Password minimal 6 karakter"))]
+    pub current_password: String,
+
+    #[validate(length(min = 6, message = "Password minimal 6 karakter"))]
+    pub new_password: String,
+
+    #[validate(must_match(
+        other = "new_password",
+        message = "Password confirmation does not match"
+    ))]
+    pub confirm_password: String,
+}
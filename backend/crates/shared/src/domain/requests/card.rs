@@ -1,6 +1,7 @@
 use crate::utils::deserialize_date_only;
-use chrono::NaiveDate;
-use serde::Deserialize;
+use chrono::{NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::{fmt, str::FromStr};
 use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
 
@@ -14,6 +15,9 @@ pub struct FindAllCards {
 
     #[serde(default)]
     pub search: String,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
 }
 
 fn default_page() -> i32 {
@@ -24,6 +28,27 @@ fn default_page_size() -> i32 {
     10
 }
 
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct RestoreCardsByFilter {
+    #[serde(default)]
+    pub search: String,
+
+    #[validate(range(min = 1))]
+    pub user_id: Option<i32>,
+
+    pub deleted_from: Option<NaiveDate>,
+    pub deleted_to: Option<NaiveDate>,
+}
+
+impl RestoreCardsByFilter {
+    pub fn is_empty(&self) -> bool {
+        self.search.trim().is_empty()
+            && self.user_id.is_none()
+            && self.deleted_from.is_none()
+            && self.deleted_to.is_none()
+    }
+}
+
 #[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
 pub struct MonthYearCardNumberCard {
     #[validate(length(min = 1, message = "Card number wajib diisi"))]
@@ -70,3 +95,124 @@ pub struct UpdateCardRequest {
     #[validate(length(min = 1, message = "Card provider wajib diisi"))]
     pub card_provider: String,
 }
+
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct FindCardDashboards {
+    #[validate(length(min = 1, max = 50, message = "Card numbers harus antara 1 - 50"))]
+    pub card_numbers: Vec<String>,
+}
+
+pub const DEFAULT_TOP_CARDS_WINDOW_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum TopCardMetric {
+    Balance,
+    TransactionCount,
+    TransactionVolume,
+}
+
+impl fmt::Display for TopCardMetric {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            TopCardMetric::Balance => "balance",
+            TopCardMetric::TransactionCount => "transaction_count",
+            TopCardMetric::TransactionVolume => "transaction_volume",
+        };
+        f.write_str(name)
+    }
+}
+
+impl FromStr for TopCardMetric {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "balance" => Ok(TopCardMetric::Balance),
+            "transaction_count" => Ok(TopCardMetric::TransactionCount),
+            "transaction_volume" => Ok(TopCardMetric::TransactionVolume),
+            other => Err(format!("Unknown top card metric '{other}'")),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
+pub struct FindTopCards {
+    pub metric: TopCardMetric,
+
+    #[serde(default = "default_top_cards_limit")]
+    #[validate(range(min = 1, max = 100, message = "Limit harus antara 1 - 100"))]
+    pub limit: i32,
+
+    #[serde(default)]
+    pub start_date: Option<NaiveDate>,
+
+    #[serde(default)]
+    pub end_date: Option<NaiveDate>,
+}
+
+fn default_top_cards_limit() -> i32 {
+    10
+}
+
+impl FindTopCards {
+    pub fn date_range(&self) -> Result<(NaiveDate, NaiveDate), String> {
+        let end = self.end_date.unwrap_or_else(|| Utc::now().date_naive());
+        let start = self
+            .start_date
+            .unwrap_or(end - chrono::Duration::days(DEFAULT_TOP_CARDS_WINDOW_DAYS - 1));
+
+        if start > end {
+            return Err("start_date must not be after end_date".to_string());
+        }
+
+        Ok((start, end))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(start_date: Option<NaiveDate>, end_date: Option<NaiveDate>) -> FindTopCards {
+        FindTopCards {
+            metric: TopCardMetric::Balance,
+            limit: 10,
+            start_date,
+            end_date,
+        }
+    }
+
+    fn day(month: u32, day: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, month, day).unwrap()
+    }
+
+    #[test]
+    fn uses_the_given_range() {
+        assert_eq!(
+            request(Some(day(8, 1)), Some(day(8, 31))).date_range(),
+            Ok((day(8, 1), day(8, 31)))
+        );
+    }
+
+    #[test]
+    fn defaults_to_a_window_ending_on_end_date() {
+        assert_eq!(
+            request(None, Some(day(8, 30))).date_range(),
+            Ok((day(8, 1), day(8, 30)))
+        );
+
+        let (start, end) = request(None, None).date_range().unwrap();
+        assert_eq!(end, Utc::now().date_naive());
+        assert_eq!((end - start).num_days(), DEFAULT_TOP_CARDS_WINDOW_DAYS - 1);
+    }
+
+    #[test]
+    fn rejects_a_start_after_the_end() {
+        assert!(
+            request(Some(day(9, 1)), Some(day(8, 31)))
+                .date_range()
+                .is_err()
+        );
+    }
+}
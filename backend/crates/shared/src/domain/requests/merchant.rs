@@ -1,3 +1,4 @@
+use chrono::{NaiveDate, Utc};
 use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
@@ -12,6 +13,9 @@ pub struct FindAllMerchants {
 
     #[serde(default)]
     pub search: String,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
@@ -41,6 +45,23 @@ pub struct FindAllMerchantTransactionsById {
     pub search: String,
 }
 
+#[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
+pub struct FindMerchantTransactionsDaily {
+    #[validate(range(min = 1))]
+    pub merchant_id: i32,
+
+    pub start_date: NaiveDate,
+    pub end_date: NaiveDate,
+}
+
+impl FindMerchantTransactionsDaily {
+    /// A range that reaches today can still gain transactions, so its totals
+    /// must not be cached.
+    pub fn is_closed(&self) -> bool {
+        self.end_date < Utc::now().date_naive()
+    }
+}
+
 #[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
 pub struct FindAllMerchantTransactionsByApiKey {
     #[validate(length(min = 1))]
@@ -125,6 +146,10 @@ pub struct CreateMerchantRequest {
 
     #[validate(range(min = 1))]
     pub user_id: i32,
+
+    #[serde(default)]
+    #[validate(length(min = 1, max = 64))]
+    pub business_id: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
@@ -148,3 +173,20 @@ pub struct UpdateMerchantStatus {
     #[validate(length(min = 1))]
     pub status: String,
 }
+
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct SetMerchantWebhookRequest {
+    pub merchant_id: Option<i32>,
+
+    #[validate(length(min = 1, max = 2048))]
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct MerchantUserRequest {
+    #[validate(range(min = 1))]
+    pub merchant_id: i32,
+
+    #[validate(range(min = 1))]
+    pub user_id: i32,
+}
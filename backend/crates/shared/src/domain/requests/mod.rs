@@ -1,12 +1,18 @@
+pub mod activity;
+pub mod anomaly;
 pub mod auth;
 pub mod card;
 pub mod merchant;
+pub mod payment_method;
+pub mod rate_limit;
 pub mod refresh_token;
 pub mod role;
 pub mod saldo;
+pub mod stats;
 pub mod topup;
 pub mod transaction;
 pub mod transfer;
+pub mod trashed;
 pub mod user;
 pub mod user_role;
 pub mod withdraw;
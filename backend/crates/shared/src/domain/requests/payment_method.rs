@@ -0,0 +1,8 @@
+use serde::Deserialize;
+use utoipa::IntoParams;
+
+#[derive(Debug, Deserialize, IntoParams, Clone, Default)]
+pub struct FindPaymentMethods {
+    #[serde(default)]
+    pub include_disabled: bool,
+}
@@ -0,0 +1,33 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum RateLimitNamespace {
+    RateLimit,
+    LoginAttempts,
+}
+
+impl RateLimitNamespace {
+    pub fn prefix(&self) -> &'static str {
+        match self {
+            RateLimitNamespace::RateLimit => "rate_limit:",
+            RateLimitNamespace::LoginAttempts => "auth:login_attempts:",
+        }
+    }
+
+    pub fn key_for(&self, identifier: &str) -> String {
+        format!("{}{identifier}", self.prefix())
+    }
+}
+
+impl fmt::Display for RateLimitNamespace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            RateLimitNamespace::RateLimit => "rate_limit",
+            RateLimitNamespace::LoginAttempts => "login_attempts",
+        };
+        f.write_str(name)
+    }
+}
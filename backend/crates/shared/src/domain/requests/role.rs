@@ -12,6 +12,9 @@ pub struct FindAllRoles {
 
     #[serde(default)]
     pub search: String,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
 }
 
 fn default_page() -> i32 {
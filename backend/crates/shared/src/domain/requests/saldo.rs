@@ -13,6 +13,9 @@ pub struct FindAllSaldos {
 
     #[serde(default)]
     pub search: String,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
 }
 
 fn default_page() -> i32 {
@@ -32,13 +35,25 @@ pub struct MonthTotalSaldoBalance {
     pub month: i32,
 }
 
+#[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
+pub struct FindCardStatement {
+    #[validate(length(min = 1, message = "Card number wajib diisi"))]
+    pub card_number: String,
+
+    #[validate(range(min = 2000, max = 2100, message = "Tahun tidak valid"))]
+    pub year: i32,
+
+    #[validate(range(min = 1, max = 12, message = "Bulan harus antara 1 - 12"))]
+    pub month: i32,
+}
+
 #[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
 pub struct CreateSaldoRequest {
     #[validate(length(min = 1, message = "Card number wajib diisi"))]
     pub card_number: String,
 
-    #[validate(range(min = 1, message = "Total balance wajib diisi"))]
-    pub total_balance: i64,
+    #[validate(range(min = 0, message = "Total balance tidak boleh negatif"))]
+    pub total_balance: Option<i64>,
 }
 
 #[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
@@ -52,6 +67,23 @@ pub struct UpdateSaldoRequest {
     pub total_balance: i64,
 }
 
+#[derive(Debug, Clone)]
+pub struct ApplySaldoTransfer {
+    pub from_card_number: String,
+    pub to_card_number: String,
+    pub amount: i64,
+    pub reference_type: &'static str,
+    pub reference_id: i32,
+}
+
+#[derive(Debug, Clone)]
+pub struct ApplySaldoLedgerEntry {
+    pub card_number: String,
+    pub amount: i64,
+    pub reference_type: &'static str,
+    pub reference_id: i32,
+}
+
 #[derive(Debug, Deserialize, Validate, ToSchema)]
 pub struct UpdateSaldoBalance {
     #[validate(length(min = 1, message = "Card number wajib diisi"))]
@@ -74,3 +106,9 @@ pub struct UpdateSaldoWithdraw {
 
     pub withdraw_time: NaiveDateTime,
 }
+
+#[derive(Debug, Deserialize, Validate, ToSchema, Clone)]
+pub struct FindSaldoBalances {
+    #[validate(length(min = 1, max = 100, message = "Card numbers harus antara 1 - 100"))]
+    pub card_numbers: Vec<String>,
+}
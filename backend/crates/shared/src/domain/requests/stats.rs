@@ -0,0 +1,102 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use utoipa::ToSchema;
+use validator::Validate;
+
+pub const MAX_RECOMPUTE_YEARS: i32 = 10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum StatsScope {
+    Transaction,
+    Topup,
+    Transfer,
+    Withdraw,
+    Saldo,
+}
+
+impl StatsScope {
+    pub fn cache_prefixes(&self) -> &'static [&'static str] {
+        match self {
+            StatsScope::Transaction => &["transaction", "card_stats_transaction", "merchant"],
+            StatsScope::Topup => &["topup", "card_stats_topup"],
+            StatsScope::Transfer => &["transfer", "card_stats_transfer"],
+            StatsScope::Withdraw => &["withdrawal", "card_stats_withdraw"],
+            StatsScope::Saldo => &["saldo", "card_stats_balance"],
+        }
+    }
+
+    pub fn lock_key(&self) -> String {
+        format!("stats:recompute:lock:{self}")
+    }
+}
+
+impl fmt::Display for StatsScope {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            StatsScope::Transaction => "transaction",
+            StatsScope::Topup => "topup",
+            StatsScope::Transfer => "transfer",
+            StatsScope::Withdraw => "withdraw",
+            StatsScope::Saldo => "saldo",
+        };
+        f.write_str(name)
+    }
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize, Validate, ToSchema)]
+pub struct RecomputeStatsRequest {
+    pub scope: StatsScope,
+
+    #[validate(range(min = 2000, max = 2100))]
+    pub from_year: i32,
+
+    #[validate(range(min = 2000, max = 2100))]
+    pub to_year: i32,
+}
+
+impl RecomputeStatsRequest {
+    pub fn check_year_range(&self) -> Result<(), String> {
+        if self.from_year > self.to_year {
+            return Err("from_year must not be after to_year".to_string());
+        }
+
+        if self.to_year - self.from_year >= MAX_RECOMPUTE_YEARS {
+            return Err(format!(
+                "At most {MAX_RECOMPUTE_YEARS} years can be recomputed at once"
+            ));
+        }
+
+        Ok(())
+    }
+
+    pub fn years(&self) -> std::ops::RangeInclusive<i32> {
+        self.from_year..=self.to_year
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(from_year: i32, to_year: i32) -> RecomputeStatsRequest {
+        RecomputeStatsRequest {
+            scope: StatsScope::Topup,
+            from_year,
+            to_year,
+        }
+    }
+
+    #[test]
+    fn accepts_ranges_up_to_the_maximum() {
+        assert!(request(2024, 2024).check_year_range().is_ok());
+        assert!(request(2016, 2025).check_year_range().is_ok());
+        assert_eq!(request(2023, 2025).years().count(), 3);
+    }
+
+    #[test]
+    fn rejects_reversed_and_oversized_ranges() {
+        assert!(request(2025, 2024).check_year_range().is_err());
+        assert!(request(2015, 2025).check_year_range().is_err());
+    }
+}
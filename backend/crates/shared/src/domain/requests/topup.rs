@@ -12,6 +12,9 @@ pub struct FindAllTopups {
 
     #[serde(default)]
     pub search: String,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
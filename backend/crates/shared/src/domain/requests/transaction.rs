@@ -1,8 +1,9 @@
-use crate::utils::deserialize_datetime;
-use chrono::NaiveDateTime;
+use crate::{config::MetadataLimitsConfig, utils::deserialize_datetime};
+use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use utoipa::{IntoParams, ToSchema};
-use validator::Validate;
+use validator::{Validate, ValidationError};
 
 #[derive(Debug, Deserialize, Serialize, Validate, IntoParams, Clone)]
 pub struct FindAllTransactions {
@@ -14,6 +15,9 @@ pub struct FindAllTransactions {
 
     #[serde(default)]
     pub search: String,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Serialize, Validate, IntoParams, Clone)]
@@ -30,6 +34,81 @@ pub struct FindAllTransactionCardNumber {
     pub search: String,
 }
 
+#[derive(Debug, Deserialize, Serialize, Validate, IntoParams, Clone, Default)]
+pub struct SearchTransactions {
+    #[serde(default = "default_page")]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    pub page_size: i32,
+
+    #[serde(default)]
+    pub status: Option<String>,
+
+    #[serde(default)]
+    pub payment_method: Option<String>,
+
+    #[serde(default)]
+    pub min_amount: Option<i32>,
+
+    #[serde(default)]
+    pub max_amount: Option<i32>,
+
+    #[serde(default)]
+    pub merchant_id: Option<i32>,
+
+    #[serde(default)]
+    pub card_number: Option<String>,
+
+    #[serde(default)]
+    pub start_date: Option<NaiveDate>,
+
+    #[serde(default)]
+    pub end_date: Option<NaiveDate>,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
+
+    #[serde(default)]
+    pub sort_dir: Option<String>,
+
+    #[serde(default)]
+    pub metadata_key: Option<String>,
+
+    #[serde(default)]
+    pub metadata_value: Option<String>,
+}
+
+impl SearchTransactions {
+    pub const SORT_COLUMNS: [&'static str; 5] = [
+        "transaction_time",
+        "amount",
+        "status",
+        "payment_method",
+        "created_at",
+    ];
+
+    pub fn sort_column(&self) -> Option<&'static str> {
+        match self.sort_by.as_deref().map(str::trim) {
+            None | Some("") => Some("transaction_time"),
+            Some(col) => Self::SORT_COLUMNS.into_iter().find(|c| *c == col),
+        }
+    }
+
+    pub fn sort_direction(&self) -> Option<&'static str> {
+        match self
+            .sort_dir
+            .as_deref()
+            .map(|d| d.trim().to_ascii_lowercase())
+            .as_deref()
+        {
+            None | Some("") | Some("desc") => Some("DESC"),
+            Some("asc") => Some("ASC"),
+            Some(_) => None,
+        }
+    }
+}
+
 fn default_page() -> i32 {
     1
 }
@@ -85,6 +164,85 @@ pub struct CreateTransactionRequest {
 
     #[serde(deserialize_with = "deserialize_datetime")]
     pub transaction_time: NaiveDateTime,
+
+    #[serde(default)]
+    #[validate(custom(function = "validate_metadata"))]
+    pub metadata: Option<BTreeMap<String, String>>,
+}
+
+pub fn validate_metadata(metadata: &BTreeMap<String, String>) -> Result<(), ValidationError> {
+    let limits = MetadataLimitsConfig::global();
+    let reject =
+        |message: String| Err(ValidationError::new("metadata").with_message(message.into()));
+
+    if metadata.len() > limits.max_keys {
+        return reject(format!(
+            "metadata must not have more than {} keys",
+            limits.max_keys
+        ));
+    }
+
+    if let Some(key) = metadata
+        .keys()
+        .find(|k| k.trim().is_empty() || k.chars().count() > limits.max_key_len)
+    {
+        return reject(format!(
+            "metadata key '{key}' must be between 1 and {} characters",
+            limits.max_key_len
+        ));
+    }
+
+    if let Some(key) = metadata
+        .iter()
+        .find(|(_, v)| v.chars().count() > limits.max_value_len)
+        .map(|(k, _)| k)
+    {
+        return reject(format!(
+            "metadata value for '{key}' must not exceed {} characters",
+            limits.max_value_len
+        ));
+    }
+
+    let size: usize = metadata.iter().map(|(k, v)| k.len() + v.len()).sum();
+    if size > limits.max_bytes {
+        return reject(format!(
+            "metadata must not exceed {} bytes in total",
+            limits.max_bytes
+        ));
+    }
+
+    if let Some(key) = metadata
+        .iter()
+        .find(|(_, v)| embedded_json_depth(v).saturating_add(1) > limits.max_depth)
+        .map(|(k, _)| k)
+    {
+        return reject(format!(
+            "metadata value for '{key}' must not nest deeper than {} levels",
+            limits.max_depth
+        ));
+    }
+
+    Ok(())
+}
+
+fn embedded_json_depth(value: &str) -> usize {
+    if !value.trim_start().starts_with(['{', '[']) {
+        return 0;
+    }
+
+    match serde_json::from_str::<serde_json::Value>(value) {
+        Ok(parsed) => json_depth(&parsed),
+        Err(e) if e.to_string().starts_with("recursion limit exceeded") => usize::MAX,
+        Err(_) => 0,
+    }
+}
+
+fn json_depth(value: &serde_json::Value) -> usize {
+    match value {
+        serde_json::Value::Array(items) => 1 + items.iter().map(json_depth).max().unwrap_or(0),
+        serde_json::Value::Object(map) => 1 + map.values().map(json_depth).max().unwrap_or(0),
+        _ => 0,
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize, Validate, ToSchema, Clone)]
@@ -109,3 +267,83 @@ pub struct UpdateTransactionStatus {
     #[validate(length(min = 1))]
     pub status: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // These tests rely on the default MetadataLimitsConfig: 20 keys, 40-character
+    // keys, 500-character values, 4096 bytes in total and a depth of 3.
+
+    fn metadata(entries: &[(&str, &str)]) -> BTreeMap<String, String> {
+        entries
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn accepts_ordinary_metadata() {
+        assert!(validate_metadata(&metadata(&[("order_id", "ORD-1"), ("channel", "web")])).is_ok());
+        assert!(validate_metadata(&BTreeMap::new()).is_ok());
+    }
+
+    #[test]
+    fn rejects_too_many_keys() {
+        let many: BTreeMap<String, String> = (0..21)
+            .map(|i| (format!("key{i}"), "v".to_string()))
+            .collect();
+
+        assert!(validate_metadata(&many).is_err());
+    }
+
+    #[test]
+    fn rejects_blank_and_long_keys() {
+        assert!(validate_metadata(&metadata(&[(" ", "v")])).is_err());
+        assert!(validate_metadata(&metadata(&[(&"k".repeat(41), "v")])).is_err());
+    }
+
+    #[test]
+    fn rejects_long_values() {
+        assert!(validate_metadata(&metadata(&[("note", &"x".repeat(501))])).is_err());
+    }
+
+    #[test]
+    fn counts_key_and_value_length_in_characters() {
+        assert!(validate_metadata(&metadata(&[(&"é".repeat(40), "v")])).is_ok());
+        assert!(validate_metadata(&metadata(&[("note", &"é".repeat(500))])).is_ok());
+        assert!(validate_metadata(&metadata(&[("note", &"é".repeat(501))])).is_err());
+    }
+
+    #[test]
+    fn rejects_oversized_metadata() {
+        let value = "x".repeat(450);
+        let big: BTreeMap<String, String> = (0..10)
+            .map(|i| (format!("key{i}"), value.clone()))
+            .collect();
+
+        assert!(validate_metadata(&big).is_err());
+    }
+
+    #[test]
+    fn rejects_deeply_nested_json_values() {
+        assert!(validate_metadata(&metadata(&[("cart", r#"{"items":[1,2]}"#)])).is_ok());
+        assert!(validate_metadata(&metadata(&[("cart", r#"{"a":{"b":{"c":1}}}"#)])).is_err());
+    }
+
+    #[test]
+    fn measures_embedded_json_depth() {
+        assert_eq!(embedded_json_depth("plain text"), 0);
+        assert_eq!(embedded_json_depth("{not json"), 0);
+        assert_eq!(embedded_json_depth("[]"), 1);
+        assert_eq!(embedded_json_depth(r#" {"a":[{"b":1}]}"#), 3);
+        assert_eq!(embedded_json_depth(r#"["{\"a\":{}}"]"#), 1);
+    }
+
+    #[test]
+    fn treats_recursion_limit_as_unbounded_depth() {
+        let deep = format!("{}{}", "[".repeat(200), "]".repeat(200));
+
+        assert_eq!(embedded_json_depth(&deep), usize::MAX);
+    }
+}
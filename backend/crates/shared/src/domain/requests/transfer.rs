@@ -1,3 +1,4 @@
+use chrono::NaiveDate;
 use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
@@ -12,6 +13,74 @@ pub struct FindAllTransfers {
 
     #[serde(default)]
     pub search: String,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
+}
+
+#[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
+pub struct FindTransfers {
+    #[serde(default = "default_page")]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    pub page_size: i32,
+
+    #[serde(default)]
+    pub transfer_from: Option<String>,
+
+    #[serde(default)]
+    pub transfer_to: Option<String>,
+
+    #[serde(default)]
+    pub status: Option<String>,
+
+    #[serde(default)]
+    pub start_date: Option<NaiveDate>,
+
+    #[serde(default)]
+    pub end_date: Option<NaiveDate>,
+}
+
+/// Sender, receiver and status filters of a [`FindTransfers`]; `None` matches any value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransferFilters {
+    pub transfer_from: Option<String>,
+    pub transfer_to: Option<String>,
+    pub status: Option<String>,
+}
+
+impl FindTransfers {
+    /// Trims the filters and treats blank values as absent.
+    pub fn filters(&self) -> TransferFilters {
+        let non_empty = |v: &Option<String>| {
+            v.as_deref()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+        };
+
+        TransferFilters {
+            transfer_from: non_empty(&self.transfer_from),
+            transfer_to: non_empty(&self.transfer_to),
+            status: non_empty(&self.status),
+        }
+    }
+}
+
+#[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
+pub struct FindTransferNetPosition {
+    #[validate(length(min = 1, message = "Card A wajib diisi"))]
+    pub card_a: String,
+
+    #[validate(length(min = 1, message = "Card B wajib diisi"))]
+    pub card_b: String,
+
+    #[serde(default)]
+    pub start_date: Option<NaiveDate>,
+
+    #[serde(default)]
+    pub end_date: Option<NaiveDate>,
 }
 
 fn default_page() -> i32 {
@@ -104,3 +173,63 @@ pub struct UpdateTransferStatus {
     #[validate(length(min = 1, message = "Status wajib diisi"))]
     pub status: String,
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn request(transfer_from: Option<&str>, transfer_to: Option<&str>) -> FindTransfers {
+        FindTransfers {
+            page: 1,
+            page_size: 10,
+            transfer_from: transfer_from.map(str::to_string),
+            transfer_to: transfer_to.map(str::to_string),
+            status: None,
+            start_date: None,
+            end_date: None,
+        }
+    }
+
+    #[test]
+    fn filters_by_sender_only() {
+        let filters = request(Some("4111000011112222"), None).filters();
+
+        assert_eq!(filters.transfer_from.as_deref(), Some("4111000011112222"));
+        assert_eq!(filters.transfer_to, None);
+    }
+
+    #[test]
+    fn filters_by_receiver_only() {
+        let filters = request(None, Some("5500000011113333")).filters();
+
+        assert_eq!(filters.transfer_from, None);
+        assert_eq!(filters.transfer_to.as_deref(), Some("5500000011113333"));
+    }
+
+    #[test]
+    fn filters_by_sender_and_receiver() {
+        let filters = request(Some("4111000011112222"), Some("5500000011113333")).filters();
+
+        assert_eq!(filters.transfer_from.as_deref(), Some("4111000011112222"));
+        assert_eq!(filters.transfer_to.as_deref(), Some("5500000011113333"));
+    }
+
+    #[test]
+    fn no_filters_match_every_transfer() {
+        assert_eq!(request(None, None).filters(), TransferFilters::default());
+        assert_eq!(
+            request(Some("  "), Some("")).filters(),
+            TransferFilters::default()
+        );
+    }
+
+    #[test]
+    fn trims_filter_values() {
+        let mut req = request(Some(" 4111000011112222 "), None);
+        req.status = Some(" success ".to_string());
+
+        let filters = req.filters();
+        assert_eq!(filters.transfer_from.as_deref(), Some("4111000011112222"));
+        assert_eq!(filters.status.as_deref(), Some("success"));
+    }
+}
@@ -0,0 +1,72 @@
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TrashedSort {
+    #[default]
+    DeletedAt,
+    Id,
+}
+
+impl TrashedSort {
+    pub const SORT_COLUMNS: [&'static str; 2] = ["deleted_at", "id"];
+
+    pub fn parse(sort_by: Option<&str>) -> Option<Self> {
+        match sort_by.map(str::trim) {
+            None | Some("") | Some("deleted_at") => Some(Self::DeletedAt),
+            Some("id") => Some(Self::Id),
+            Some(_) => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::DeletedAt => "deleted_at",
+            Self::Id => "id",
+        }
+    }
+
+    pub fn order_by(&self, deleted_at_column: &str, id_column: &str) -> String {
+        match self {
+            Self::DeletedAt => format!("{deleted_at_column} DESC, {id_column} DESC"),
+            Self::Id => format!("{id_column} DESC"),
+        }
+    }
+
+    pub fn validation_error() -> String {
+        format!("sort_by must be one of: {}", Self::SORT_COLUMNS.join(", "))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_known_sorts_and_defaults_to_deleted_at() {
+        assert_eq!(TrashedSort::parse(None), Some(TrashedSort::DeletedAt));
+        assert_eq!(TrashedSort::parse(Some(" ")), Some(TrashedSort::DeletedAt));
+        assert_eq!(TrashedSort::parse(Some("id")), Some(TrashedSort::Id));
+        assert_eq!(TrashedSort::parse(Some("name")), None);
+    }
+
+    #[test]
+    fn deleted_at_order_breaks_ties_by_id() {
+        assert_eq!(
+            TrashedSort::DeletedAt.order_by("c.deleted_at", "c.card_id"),
+            "c.deleted_at DESC, c.card_id DESC"
+        );
+        assert_eq!(
+            TrashedSort::Id.order_by("c.deleted_at", "c.card_id"),
+            "c.card_id DESC"
+        );
+    }
+
+    #[test]
+    fn sort_names_round_trip() {
+        for sort in [TrashedSort::DeletedAt, TrashedSort::Id] {
+            assert_eq!(TrashedSort::parse(Some(sort.as_str())), Some(sort));
+        }
+        assert_eq!(
+            TrashedSort::validation_error(),
+            "sort_by must be one of: deleted_at, id"
+        );
+    }
+}
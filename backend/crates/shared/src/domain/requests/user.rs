@@ -12,6 +12,9 @@ pub struct FindAllUserRequest {
 
     #[serde(default)]
     pub search: String,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
 }
 
 fn default_page() -> i32 {
@@ -39,6 +42,9 @@ pub struct CreateUserRequest {
     #[validate(length(min = 6, message = "Confirm password must be at least 6 characters"))]
     #[validate(must_match(other = "password"))]
     pub confirm_password: String,
+
+    #[serde(default)]
+    pub must_change_password: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, Validate)]
@@ -61,3 +67,12 @@ pub struct UpdateUserRequest {
     #[validate(must_match(other = "password"))]
     pub confirm_password: String,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema, Validate)]
+pub struct MergeUsersRequest {
+    #[validate(range(min = 1, message = "Primary user ID must be at least 1"))]
+    pub primary_id: i32,
+
+    #[validate(range(min = 1, message = "Duplicate user ID must be at least 1"))]
+    pub duplicate_id: i32,
+}
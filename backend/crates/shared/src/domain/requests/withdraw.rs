@@ -1,5 +1,5 @@
 use crate::utils::deserialize_datetime;
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use serde::Deserialize;
 use utoipa::{IntoParams, ToSchema};
 use validator::Validate;
@@ -19,6 +19,9 @@ pub struct FindAllWithdraws {
 
     #[serde(default)]
     pub search: String,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
 }
 
 #[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
@@ -36,6 +39,58 @@ pub struct FindAllWithdrawCardNumber {
     pub page_size: i32,
 }
 
+#[derive(Debug, Deserialize, Validate, IntoParams, Clone)]
+pub struct FindWithdraws {
+    #[serde(default = "default_page")]
+    pub page: i32,
+
+    #[serde(default = "default_page_size")]
+    pub page_size: i32,
+
+    #[serde(default)]
+    pub status: Option<String>,
+
+    #[serde(default)]
+    pub card_number: Option<String>,
+
+    #[serde(default)]
+    pub from: Option<NaiveDate>,
+
+    #[serde(default)]
+    pub to: Option<NaiveDate>,
+
+    #[serde(default)]
+    pub sort_by: Option<String>,
+
+    #[serde(default)]
+    pub sort_dir: Option<String>,
+}
+
+impl FindWithdraws {
+    pub const SORT_COLUMNS: [&'static str; 4] =
+        ["withdraw_time", "withdraw_amount", "status", "created_at"];
+
+    pub fn sort_column(&self) -> Option<&'static str> {
+        match self.sort_by.as_deref().map(str::trim) {
+            None | Some("") => Some("withdraw_time"),
+            Some(col) => Self::SORT_COLUMNS.into_iter().find(|c| *c == col),
+        }
+    }
+
+    pub fn sort_direction(&self) -> Option<&'static str> {
+        match self
+            .sort_dir
+            .as_deref()
+            .map(|d| d.trim().to_ascii_lowercase())
+            .as_deref()
+        {
+            None | Some("") | Some("desc") => Some("DESC"),
+            Some("asc") => Some("ASC"),
+            Some(_) => None,
+        }
+    }
+}
+
 fn default_page() -> i32 {
     1
 }
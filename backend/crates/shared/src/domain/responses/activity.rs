@@ -0,0 +1,286 @@
+use crate::domain::responses::{
+    TopupResponse, TransactionResponse, TransferResponse, WithdrawResponse,
+};
+use chrono::NaiveDateTime;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityType {
+    Topup,
+    Transfer,
+    Withdraw,
+    Transaction,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum ActivityDirection {
+    Credit,
+    Debit,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct ActivityItem {
+    #[serde(rename = "type")]
+    pub activity_type: ActivityType,
+    pub direction: ActivityDirection,
+    pub id: i32,
+    pub reference_no: String,
+    pub card_number: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub counterparty: Option<String>,
+    pub amount: i64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub method: Option<String>,
+    pub occurred_at: String,
+}
+
+impl ActivityItem {
+    pub fn from_transfer(transfer: TransferResponse, card_number: &str) -> Self {
+        let sent = transfer.transfer_from == card_number;
+        let (direction, counterparty) = if sent {
+            (ActivityDirection::Debit, transfer.transfer_to)
+        } else {
+            (ActivityDirection::Credit, transfer.transfer_from)
+        };
+
+        Self {
+            activity_type: ActivityType::Transfer,
+            direction,
+            id: transfer.id,
+            reference_no: transfer.transfer_no,
+            card_number: card_number.to_string(),
+            counterparty: Some(counterparty),
+            amount: transfer.transfer_amount,
+            method: None,
+            occurred_at: transfer.transfer_time,
+        }
+    }
+
+    fn occurred_at_key(&self) -> Option<NaiveDateTime> {
+        NaiveDateTime::parse_from_str(&self.occurred_at, "%Y-%m-%d %H:%M:%S%.f").ok()
+    }
+
+    fn newest_first(a: &Self, b: &Self) -> Ordering {
+        match (a.occurred_at_key(), b.occurred_at_key()) {
+            (Some(x), Some(y)) => y.cmp(&x),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => b.occurred_at.cmp(&a.occurred_at),
+        }
+        .then_with(|| a.activity_type.cmp(&b.activity_type))
+        .then_with(|| b.id.cmp(&a.id))
+        .then_with(|| a.card_number.cmp(&b.card_number))
+    }
+}
+
+impl From<TopupResponse> for ActivityItem {
+    fn from(topup: TopupResponse) -> Self {
+        Self {
+            activity_type: ActivityType::Topup,
+            direction: ActivityDirection::Credit,
+            id: topup.id,
+            reference_no: topup.topup_no,
+            card_number: topup.card_number,
+            counterparty: None,
+            amount: topup.topup_amount,
+            method: Some(topup.topup_method),
+            occurred_at: topup.topup_time,
+        }
+    }
+}
+
+impl From<WithdrawResponse> for ActivityItem {
+    fn from(withdraw: WithdrawResponse) -> Self {
+        Self {
+            activity_type: ActivityType::Withdraw,
+            direction: ActivityDirection::Debit,
+            id: withdraw.id,
+            reference_no: withdraw.withdraw_no,
+            card_number: withdraw.card_number,
+            counterparty: None,
+            amount: withdraw.withdraw_amount,
+            method: None,
+            occurred_at: withdraw.withdraw_time,
+        }
+    }
+}
+
+impl From<TransactionResponse> for ActivityItem {
+    fn from(transaction: TransactionResponse) -> Self {
+        Self {
+            activity_type: ActivityType::Transaction,
+            direction: ActivityDirection::Debit,
+            id: transaction.id,
+            reference_no: transaction.transaction_no,
+            card_number: transaction.card_number,
+            counterparty: Some(format!("merchant:{}", transaction.merchant_id)),
+            amount: transaction.amount,
+            method: Some(transaction.payment_method),
+            occurred_at: transaction.transaction_time,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Default)]
+pub struct RecentActivityResponse {
+    pub items: Vec<ActivityItem>,
+    pub failed_sources: Vec<ActivityType>,
+}
+
+impl RecentActivityResponse {
+    pub fn merge(
+        sources: Vec<Vec<ActivityItem>>,
+        failed_sources: Vec<ActivityType>,
+        page: i32,
+        page_size: i32,
+    ) -> Self {
+        let mut items: Vec<ActivityItem> = sources.into_iter().flatten().collect();
+        items.sort_by(ActivityItem::newest_first);
+        items.dedup_by(|a, b| {
+            a.activity_type == b.activity_type
+                && a.id == b.id
+                && a.card_number == b.card_number
+                && a.direction == b.direction
+        });
+
+        let offset = ((page.max(1) - 1) * page_size.max(1)) as usize;
+        let items = items
+            .into_iter()
+            .skip(offset)
+            .take(page_size.max(1) as usize)
+            .collect();
+
+        let mut failed_sources = failed_sources;
+        failed_sources.sort();
+        failed_sources.dedup();
+
+        Self {
+            items,
+            failed_sources,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn item(activity_type: ActivityType, id: i32, card: &str, at: &str) -> ActivityItem {
+        ActivityItem {
+            activity_type,
+            direction: ActivityDirection::Debit,
+            id,
+            reference_no: format!("REF-{id}"),
+            card_number: card.to_string(),
+            counterparty: None,
+            amount: 1_000,
+            method: None,
+            occurred_at: at.to_string(),
+        }
+    }
+
+    fn keys(response: &RecentActivityResponse) -> Vec<(ActivityType, i32)> {
+        response
+            .items
+            .iter()
+            .map(|item| (item.activity_type, item.id))
+            .collect()
+    }
+
+    #[test]
+    fn merges_sources_newest_first() {
+        let response = RecentActivityResponse::merge(
+            vec![
+                vec![item(ActivityType::Topup, 1, "A", "2025-08-01 10:00:00")],
+                vec![item(ActivityType::Withdraw, 2, "A", "2025-08-03 10:00:00")],
+                vec![item(
+                    ActivityType::Transaction,
+                    3,
+                    "B",
+                    "2025-08-02 10:00:00.5",
+                )],
+            ],
+            Vec::new(),
+            1,
+            10,
+        );
+
+        assert_eq!(
+            keys(&response),
+            vec![
+                (ActivityType::Withdraw, 2),
+                (ActivityType::Transaction, 3),
+                (ActivityType::Topup, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn drops_duplicates_but_keeps_both_sides_of_an_own_transfer() {
+        let debit = ActivityItem {
+            direction: ActivityDirection::Debit,
+            ..item(ActivityType::Transfer, 5, "A", "2025-08-01 10:00:00")
+        };
+        let credit = ActivityItem {
+            direction: ActivityDirection::Credit,
+            ..item(ActivityType::Transfer, 5, "B", "2025-08-01 10:00:00")
+        };
+
+        let response = RecentActivityResponse::merge(
+            vec![vec![debit.clone(), credit], vec![debit]],
+            Vec::new(),
+            1,
+            10,
+        );
+
+        assert_eq!(response.items.len(), 2);
+        assert_eq!(response.items[0].card_number, "A");
+        assert_eq!(response.items[1].card_number, "B");
+    }
+
+    #[test]
+    fn pages_through_the_merged_feed() {
+        let items: Vec<ActivityItem> = (1..=5)
+            .map(|id| {
+                item(
+                    ActivityType::Topup,
+                    id,
+                    "A",
+                    &format!("2025-08-0{id} 10:00:00"),
+                )
+            })
+            .collect();
+
+        let response = RecentActivityResponse::merge(vec![items.clone()], Vec::new(), 2, 2);
+        assert_eq!(
+            keys(&response),
+            vec![(ActivityType::Topup, 3), (ActivityType::Topup, 2)]
+        );
+
+        let response = RecentActivityResponse::merge(vec![items], Vec::new(), 4, 2);
+        assert!(response.items.is_empty());
+    }
+
+    #[test]
+    fn reports_each_failed_source_once() {
+        let response = RecentActivityResponse::merge(
+            Vec::new(),
+            vec![
+                ActivityType::Withdraw,
+                ActivityType::Topup,
+                ActivityType::Withdraw,
+            ],
+            1,
+            10,
+        );
+
+        assert_eq!(
+            response.failed_sources,
+            vec![ActivityType::Topup, ActivityType::Withdraw]
+        );
+    }
+}
@@ -0,0 +1,17 @@
+use crate::domain::requests::anomaly::{AnomalyMetric, AnomalyScope};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct AnomalyReport {
+    pub scope: AnomalyScope,
+    pub metric: AnomalyMetric,
+    pub period: String,
+    pub current: i64,
+    pub baseline_mean: f64,
+    pub baseline_std_dev: f64,
+    pub z_score: f64,
+    pub threshold: f64,
+    pub window_days: u32,
+    pub is_anomaly: bool,
+}
@@ -1,4 +1,4 @@
-use crate::domain::responses::pagination::Pagination;
+use crate::domain::responses::pagination::{Pagination, PaginationLinks};
 use core::fmt;
 use serde::{Deserialize, Serialize};
 use std::fmt::Formatter;
@@ -27,6 +27,9 @@ pub struct ApiResponsePagination<T> {
     pub message: String,
     pub data: T,
     pub pagination: Pagination,
+    /// Filled in by the gateway with links to the neighbouring pages.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub links: Option<PaginationLinks>,
 }
 
 impl<T: Serialize> fmt::Display for ApiResponsePagination<T> {
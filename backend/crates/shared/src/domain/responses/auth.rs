@@ -1,4 +1,7 @@
-use genproto::auth::TokenResponse as ProtoTokenResponse;
+use crate::config::Claims;
+use genproto::auth::{
+    TokenIntrospection as ProtoTokenIntrospection, TokenResponse as ProtoTokenResponse,
+};
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
 
@@ -6,6 +9,70 @@ use utoipa::ToSchema;
 pub struct TokenResponse {
     pub access_token: String,
     pub refresh_token: String,
+    #[serde(default)]
+    pub password_change_required: bool,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema)]
+pub struct TokenInfoResponse {
+    pub subject: i64,
+    pub token_type: String,
+    pub issued_at: i64,
+    pub exp: i64,
+    pub expires_in: i64,
+}
+
+impl TokenInfoResponse {
+    pub fn from_claims(claims: &Claims, now: i64) -> Self {
+        Self {
+            subject: claims.user_id,
+            token_type: claims.token_type.clone(),
+            issued_at: claims.iat as i64,
+            exp: claims.exp as i64,
+            expires_in: (claims.exp as i64 - now).max(0),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct TokenIntrospectionResponse {
+    pub active: bool,
+    pub user_id: Option<i64>,
+    pub exp: Option<i64>,
+}
+
+impl TokenIntrospectionResponse {
+    pub fn from_claims(claims: &Claims) -> Self {
+        Self {
+            active: true,
+            user_id: Some(claims.user_id),
+            exp: Some(claims.exp as i64),
+        }
+    }
+}
+
+impl From<TokenIntrospectionResponse> for ProtoTokenIntrospection {
+    fn from(value: TokenIntrospectionResponse) -> Self {
+        Self {
+            active: value.active,
+            user_id: value.user_id.unwrap_or_default(),
+            exp: value.exp.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<ProtoTokenIntrospection> for TokenIntrospectionResponse {
+    fn from(value: ProtoTokenIntrospection) -> Self {
+        if !value.active {
+            return Self::default();
+        }
+
+        Self {
+            active: true,
+            user_id: Some(value.user_id),
+            exp: Some(value.exp),
+        }
+    }
 }
 
 impl From<ProtoTokenResponse> for TokenResponse {
@@ -13,6 +80,7 @@ impl From<ProtoTokenResponse> for TokenResponse {
         Self {
             access_token: value.access_token,
             refresh_token: value.refresh_token,
+            password_change_required: value.password_change_required,
         }
     }
 }
@@ -22,6 +90,7 @@ impl From<TokenResponse> for ProtoTokenResponse {
         Self {
             access_token: value.access_token,
             refresh_token: value.refresh_token,
+            password_change_required: value.password_change_required,
         }
     }
 }
@@ -0,0 +1,61 @@
+use genproto::api::BalanceImpact as ProtoBalanceImpact;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct BalanceImpactResponse {
+    pub card_number: String,
+    pub current_balance: i64,
+    pub amount: i64,
+    pub fee: i64,
+    pub resulting_balance: i64,
+    pub allowed: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl BalanceImpactResponse {
+    pub fn evaluate(card_number: &str, current_balance: i64, amount: i64, fee: i64) -> Self {
+        let debit = amount.saturating_add(fee);
+        let resulting_balance = current_balance.saturating_sub(debit);
+        let allowed = resulting_balance >= 0;
+
+        Self {
+            card_number: card_number.to_string(),
+            current_balance,
+            amount,
+            fee,
+            resulting_balance,
+            allowed,
+            reason: (!allowed).then(|| "insufficient balance".to_string()),
+        }
+    }
+}
+
+impl From<ProtoBalanceImpact> for BalanceImpactResponse {
+    fn from(value: ProtoBalanceImpact) -> Self {
+        Self {
+            card_number: value.card_number,
+            current_balance: value.current_balance,
+            amount: value.amount,
+            fee: value.fee,
+            resulting_balance: value.resulting_balance,
+            allowed: value.allowed,
+            reason: (!value.reason.is_empty()).then_some(value.reason),
+        }
+    }
+}
+
+impl From<BalanceImpactResponse> for ProtoBalanceImpact {
+    fn from(value: BalanceImpactResponse) -> Self {
+        Self {
+            card_number: value.card_number,
+            current_balance: value.current_balance,
+            amount: value.amount,
+            fee: value.fee,
+            resulting_balance: value.resulting_balance,
+            allowed: value.allowed,
+            reason: value.reason.unwrap_or_default(),
+        }
+    }
+}
@@ -0,0 +1,78 @@
+use crate::model::bulk::{BulkItemErrorModel, BulkResultModel};
+use genproto::api::{BulkResult as ProtoBulkResult, BulkResultError as ProtoBulkResultError};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct BulkResultError {
+    pub id: i32,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq, Eq, ToSchema)]
+pub struct BulkResult {
+    pub total: i64,
+    pub affected: i64,
+    pub skipped: i64,
+    pub errors: Vec<BulkResultError>,
+}
+
+impl From<BulkItemErrorModel> for BulkResultError {
+    fn from(value: BulkItemErrorModel) -> Self {
+        Self {
+            id: value.id,
+            reason: value.reason,
+        }
+    }
+}
+
+impl From<BulkResultModel> for BulkResult {
+    fn from(value: BulkResultModel) -> Self {
+        Self {
+            total: value.total,
+            affected: value.affected,
+            skipped: value.skipped,
+            errors: value.errors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<ProtoBulkResultError> for BulkResultError {
+    fn from(value: ProtoBulkResultError) -> Self {
+        Self {
+            id: value.id,
+            reason: value.reason,
+        }
+    }
+}
+
+impl From<BulkResultError> for ProtoBulkResultError {
+    fn from(value: BulkResultError) -> Self {
+        Self {
+            id: value.id,
+            reason: value.reason,
+        }
+    }
+}
+
+impl From<ProtoBulkResult> for BulkResult {
+    fn from(value: ProtoBulkResult) -> Self {
+        Self {
+            total: value.total,
+            affected: value.affected,
+            skipped: value.skipped,
+            errors: value.errors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<BulkResult> for ProtoBulkResult {
+    fn from(value: BulkResult) -> Self {
+        Self {
+            total: value.total,
+            affected: value.affected,
+            skipped: value.skipped,
+            errors: value.errors.into_iter().map(Into::into).collect(),
+        }
+    }
+}
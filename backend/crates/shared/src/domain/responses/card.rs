@@ -1,19 +1,26 @@
 use crate::{
     model::card::{
-        CardModel, CardMonthAmount, CardMonthBalance, CardYearAmount, CardYearlyBalance,
+        CardFreezeModel, CardModel, CardMonthAmount, CardMonthBalance, CardReissueModel,
+        CardStatusModel, CardYearAmount, CardYearlyBalance, SystemStatsModel, TopCardModel,
     },
-    utils::parse_datetime,
+    utils::{mask_card_number, parse_datetime},
 };
+use chrono::NaiveDate;
+use std::collections::BTreeMap;
 use utoipa::ToSchema;
 
 use genproto::card::{
+    CardFreezeResponse as CardFreezeResponseProto, CardReissueResponse as CardReissueResponseProto,
     CardResponse as CardResponseProto, CardResponseDashboard as CardResponseDashboardProto,
     CardResponseDashboardCardNumber as CardResponseDashboardCardNumberProto,
     CardResponseDeleteAt as CardResponseDeleteAtProto,
     CardResponseMonthlyAmount as CardResponseMonthlyAmountProto,
     CardResponseMonthlyBalance as CardResponseMonthBalanceProto,
+    CardResponseSystemStats as CardResponseSystemStatsProto,
+    CardResponseTopCard as CardResponseTopCardProto,
     CardResponseYearlyAmount as CardResponseYearAmountProto,
     CardResponseYearlyBalance as CardResponseYearBalanceProto,
+    CardStatusResponse as CardStatusResponseProto,
 };
 use serde::{Deserialize, Serialize};
 
@@ -59,6 +66,29 @@ pub struct CardResponseDeleteAt {
     pub deleted_at: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct CardReissueResponse {
+    #[serde(rename = "previous_card")]
+    pub previous_card: CardResponseDeleteAt,
+    pub card: CardResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Default)]
+pub struct CardStatusResponse {
+    pub exists: bool,
+    pub active: bool,
+    pub scheme: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct CardFreezeResponse {
+    pub id: i32,
+    pub user_id: i32,
+    pub card_number: String,
+    pub frozen: bool,
+    pub frozen_at: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
 pub struct DashboardCard {
     #[serde(rename = "total_balance")]
@@ -89,6 +119,29 @@ pub struct DashboardCardCardNumber {
     pub total_transfer_receiver: Option<i64>,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Default)]
+pub struct DashboardCardNumbers {
+    pub dashboards: BTreeMap<String, DashboardCardCardNumber>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Default)]
+pub struct SystemStatsResponse {
+    pub total_users: i64,
+    pub total_cards: i64,
+    pub total_balance: i64,
+    pub today_transaction_count: i64,
+    pub today_transaction_volume: i64,
+    pub date: NaiveDate,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct TopCardResponse {
+    pub rank: i32,
+    pub card_number: String,
+    pub user_id: i32,
+    pub value: i64,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
 pub struct CardResponseMonthBalance {
     pub month: String,
@@ -151,6 +204,51 @@ impl From<CardModel> for CardResponseDeleteAt {
     }
 }
 
+impl From<CardFreezeModel> for CardFreezeResponse {
+    fn from(model: CardFreezeModel) -> Self {
+        Self {
+            id: model.card_id,
+            user_id: model.user_id,
+            card_number: model.card_number,
+            frozen: model.frozen_at.is_some(),
+            frozen_at: model.frozen_at.map(|dt| dt.to_string()),
+        }
+    }
+}
+
+impl From<CardFreezeResponse> for CardFreezeResponseProto {
+    fn from(r: CardFreezeResponse) -> Self {
+        Self {
+            id: r.id,
+            user_id: r.user_id,
+            card_number: r.card_number,
+            frozen: r.frozen,
+            frozen_at: r.frozen_at.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<CardFreezeResponseProto> for CardFreezeResponse {
+    fn from(r: CardFreezeResponseProto) -> Self {
+        Self {
+            id: r.id,
+            user_id: r.user_id,
+            card_number: r.card_number,
+            frozen: r.frozen,
+            frozen_at: (!r.frozen_at.is_empty()).then_some(r.frozen_at),
+        }
+    }
+}
+
+impl From<CardReissueModel> for CardReissueResponse {
+    fn from(model: CardReissueModel) -> Self {
+        Self {
+            previous_card: model.previous_card.into(),
+            card: model.card.into(),
+        }
+    }
+}
+
 impl From<CardMonthBalance> for CardResponseMonthBalance {
     fn from(b: CardMonthBalance) -> Self {
         Self {
@@ -187,7 +285,66 @@ impl From<CardYearAmount> for CardResponseYearAmount {
     }
 }
 
+impl SystemStatsResponse {
+    pub fn from_model(model: SystemStatsModel, date: NaiveDate) -> Self {
+        Self {
+            total_users: model.total_users,
+            total_cards: model.total_cards,
+            total_balance: model.total_balance,
+            today_transaction_count: model.today_transaction_count,
+            today_transaction_volume: model.today_transaction_volume,
+            date,
+        }
+    }
+}
+
+impl TopCardResponse {
+    pub fn from_model(rank: i32, model: TopCardModel) -> Self {
+        Self {
+            rank,
+            card_number: mask_card_number(&model.card_number),
+            user_id: model.user_id,
+            value: model.value,
+        }
+    }
+}
+
+impl From<TopCardResponse> for CardResponseTopCardProto {
+    fn from(r: TopCardResponse) -> Self {
+        Self {
+            rank: r.rank,
+            card_number: r.card_number,
+            user_id: r.user_id,
+            value: r.value,
+        }
+    }
+}
+
+impl From<CardResponseTopCardProto> for TopCardResponse {
+    fn from(proto: CardResponseTopCardProto) -> Self {
+        Self {
+            rank: proto.rank,
+            card_number: proto.card_number,
+            user_id: proto.user_id,
+            value: proto.value,
+        }
+    }
+}
+
 // response to proto
+impl From<SystemStatsResponse> for CardResponseSystemStatsProto {
+    fn from(r: SystemStatsResponse) -> Self {
+        Self {
+            total_users: r.total_users,
+            total_cards: r.total_cards,
+            total_balance: r.total_balance,
+            today_transaction_count: r.today_transaction_count,
+            today_transaction_volume: r.today_transaction_volume,
+            date: r.date.to_string(),
+        }
+    }
+}
+
 impl From<DashboardCard> for CardResponseDashboardProto {
     fn from(d: DashboardCard) -> Self {
         Self {
@@ -246,6 +403,48 @@ impl From<CardResponseDeleteAt> for CardResponseDeleteAtProto {
     }
 }
 
+impl CardStatusResponse {
+    pub fn from_model(model: Option<CardStatusModel>, today: NaiveDate) -> Self {
+        match model {
+            Some(m) => Self {
+                exists: true,
+                active: m.deleted_at.is_none() && m.frozen_at.is_none() && m.expire_date >= today,
+                scheme: Some(m.card_provider),
+            },
+            None => Self::default(),
+        }
+    }
+}
+
+impl From<CardStatusResponse> for CardStatusResponseProto {
+    fn from(r: CardStatusResponse) -> Self {
+        Self {
+            exists: r.exists,
+            active: r.active,
+            scheme: r.scheme.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<CardStatusResponseProto> for CardStatusResponse {
+    fn from(r: CardStatusResponseProto) -> Self {
+        Self {
+            exists: r.exists,
+            active: r.active,
+            scheme: (!r.scheme.is_empty()).then_some(r.scheme),
+        }
+    }
+}
+
+impl From<CardReissueResponse> for CardReissueResponseProto {
+    fn from(r: CardReissueResponse) -> Self {
+        Self {
+            previous_card: Some(r.previous_card.into()),
+            card: Some(r.card.into()),
+        }
+    }
+}
+
 impl From<CardResponseMonthBalance> for CardResponseMonthBalanceProto {
     fn from(r: CardResponseMonthBalance) -> Self {
         Self {
@@ -283,6 +482,19 @@ impl From<CardResponseYearAmount> for CardResponseYearAmountProto {
 }
 
 // proto to response
+impl From<CardResponseSystemStatsProto> for SystemStatsResponse {
+    fn from(proto: CardResponseSystemStatsProto) -> Self {
+        Self {
+            total_users: proto.total_users,
+            total_cards: proto.total_cards,
+            total_balance: proto.total_balance,
+            today_transaction_count: proto.today_transaction_count,
+            today_transaction_volume: proto.today_transaction_volume,
+            date: proto.date.parse().unwrap_or_default(),
+        }
+    }
+}
+
 impl From<CardResponseDashboardProto> for DashboardCard {
     fn from(proto: CardResponseDashboardProto) -> Self {
         Self {
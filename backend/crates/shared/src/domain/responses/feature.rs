@@ -0,0 +1,37 @@
+use crate::{
+    config::{FeaturesConfig, LocaleConfig, PaymentMethodsConfig},
+    utils::SUPPORTED_LOCALES,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct FeatureFlagsResponse {
+    pub self_registration: bool,
+    pub email_verification_required: bool,
+    pub two_factor_auth: bool,
+    pub payment_methods: Vec<String>,
+    pub supported_locales: Vec<String>,
+    pub default_locale: String,
+}
+
+impl FeatureFlagsResponse {
+    pub fn from_config(
+        features: &FeaturesConfig,
+        payment_methods: &PaymentMethodsConfig,
+        locale: &LocaleConfig,
+    ) -> Self {
+        Self {
+            self_registration: features.self_registration,
+            email_verification_required: features.email_verification_required,
+            two_factor_auth: false,
+            payment_methods: payment_methods
+                .available(false)
+                .into_iter()
+                .map(|m| m.name.clone())
+                .collect(),
+            supported_locales: SUPPORTED_LOCALES.iter().map(|l| l.to_string()).collect(),
+            default_locale: locale.default_locale.to_string(),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    config::{PaymentMethodsConfig, TransferLimit, TransferLimitsConfig},
+    domain::responses::PaymentMethodResponse,
+    utils::mask_card_number,
+};
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct RateLimitInfoResponse {
+    pub scope: String,
+    pub max_requests: u32,
+    pub window_secs: u32,
+    /// Routes the quota is checked on; a trailing `*` matches any sub-path.
+    pub routes: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct AmountLimitResponse {
+    pub min_amount: Option<i64>,
+    pub max_amount: Option<i64>,
+    pub source: String,
+}
+
+impl AmountLimitResponse {
+    pub fn unlimited() -> Self {
+        Self {
+            min_amount: None,
+            max_amount: None,
+            source: "none".to_string(),
+        }
+    }
+
+    fn from_limit(limit: TransferLimit, source: &str) -> Self {
+        if limit == TransferLimit::default() {
+            return Self::unlimited();
+        }
+
+        Self {
+            min_amount: limit.min_amount,
+            max_amount: limit.max_amount,
+            source: source.to_string(),
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct CardLimitsResponse {
+    pub card_number: String,
+    pub transfer: AmountLimitResponse,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct EffectiveLimitsResponse {
+    pub rate_limit: RateLimitInfoResponse,
+    pub transaction: Vec<PaymentMethodResponse>,
+    pub transfer: AmountLimitResponse,
+    pub cards: Vec<CardLimitsResponse>,
+}
+
+impl EffectiveLimitsResponse {
+    pub fn resolve(
+        card_numbers: &[String],
+        rate_limit: RateLimitInfoResponse,
+        payment_methods: &PaymentMethodsConfig,
+        transfer_limits: &TransferLimitsConfig,
+        merchant_id: Option<i32>,
+    ) -> Self {
+        let cards = card_numbers
+            .iter()
+            .map(|card| {
+                let transfer = AmountLimitResponse::from_limit(
+                    transfer_limits.limit_for(card, merchant_id),
+                    transfer_limits.source_for(Some(card), merchant_id),
+                );
+
+                CardLimitsResponse {
+                    card_number: mask_card_number(card),
+                    transfer,
+                }
+            })
+            .collect();
+
+        Self {
+            rate_limit,
+            transaction: payment_methods
+                .available(false)
+                .into_iter()
+                .map(PaymentMethodResponse::from)
+                .collect(),
+            transfer: AmountLimitResponse::from_limit(
+                transfer_limits.merchant_limit(merchant_id),
+                transfer_limits.source_for(None, merchant_id),
+            ),
+            cards,
+        }
+    }
+}
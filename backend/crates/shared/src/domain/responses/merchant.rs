@@ -1,12 +1,14 @@
 use crate::{
     model::merchant::{
         MerchantModel, MerchantMonthlyAmount, MerchantMonthlyPaymentMethod,
-        MerchantMonthlyTotalAmount, MerchantTransactionsModel, MerchantYearlyAmount,
-        MerchantYearlyPaymentMethod, MerchantYearlyTotalAmount,
+        MerchantMonthlyTotalAmount, MerchantTransactionDailyModel, MerchantTransactionsModel,
+        MerchantWebhookModel, MerchantYearlyAmount, MerchantYearlyPaymentMethod,
+        MerchantYearlyTotalAmount,
     },
-    utils::parse_datetime,
+    utils::{mask_api_key, parse_datetime},
 };
 use genproto::merchant::{
+    MerchantApiKeyUsageResponse as MerchantApiKeyUsageResponseProto,
     MerchantResponse as MerchantResponseProto,
     MerchantResponseDeleteAt as MerchantResponseDeleteAtProto,
     MerchantResponseMonthlyAmount as MerchantResponseMonthlyAmountProto,
@@ -15,7 +17,10 @@ use genproto::merchant::{
     MerchantResponseYearlyAmount as MerchantResponseYearlyAmountProto,
     MerchantResponseYearlyPaymentMethod as MerchantResponseYearlyPaymentMethodProto,
     MerchantResponseYearlyTotalAmount as MerchantResponseYearlyTotalAmountProto,
+    MerchantSettlementResponse as MerchantSettlementResponseProto,
+    MerchantTransactionDailyResponse as MerchantTransactionDailyResponseProto,
     MerchantTransactionResponse as MerchantTransactionResponseProto,
+    MerchantWebhookResponse as MerchantWebhookResponseProto,
 };
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -48,6 +53,16 @@ pub struct MerchantResponseDeleteAt {
     pub deleted_at: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct MerchantWebhookResponse {
+    pub merchant_id: i32,
+    pub url: String,
+    #[serde(rename = "created_at")]
+    pub created_at: Option<String>,
+    #[serde(rename = "updated_at")]
+    pub updated_at: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
 pub struct MerchantTransactionResponse {
     pub id: i32,
@@ -65,6 +80,34 @@ pub struct MerchantTransactionResponse {
     pub deleted_at: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct MerchantTransactionDailyResponse {
+    pub day: String,
+    pub total_transactions: i32,
+    pub total_amount: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct MerchantApiKeyUsageResponse {
+    pub api_key: String,
+    pub total_transactions: i32,
+    pub total_amount: i64,
+    pub daily: Vec<MerchantTransactionDailyResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct MerchantSettlementResponse {
+    pub merchant_id: i32,
+    pub start_date: String,
+    pub end_date: String,
+    pub gross: i64,
+    pub fees: i64,
+    /// Placeholder: there is no refund flow yet, so this is always `0`.
+    pub refunds: i64,
+    /// `gross - fees - refunds`; not clamped to zero.
+    pub net: i64,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
 pub struct MerchantResponseMonthlyPaymentMethod {
     pub month: String,
@@ -111,7 +154,7 @@ impl From<MerchantModel> for MerchantResponse {
             id: model.merchant_id,
             name: model.name,
             user_id: model.user_id,
-            api_key: model.api_key,
+            api_key: mask_api_key(&model.api_key),
             status: model.status,
             created_at: model.created_at.map(|dt| dt.to_string()),
             updated_at: model.updated_at.map(|dt| dt.to_string()),
@@ -119,13 +162,24 @@ impl From<MerchantModel> for MerchantResponse {
     }
 }
 
+impl From<MerchantWebhookModel> for MerchantWebhookResponse {
+    fn from(model: MerchantWebhookModel) -> Self {
+        Self {
+            merchant_id: model.merchant_id,
+            url: model.url,
+            created_at: model.created_at.map(|dt| dt.to_string()),
+            updated_at: model.updated_at.map(|dt| dt.to_string()),
+        }
+    }
+}
+
 impl From<MerchantModel> for MerchantResponseDeleteAt {
     fn from(model: MerchantModel) -> Self {
         Self {
             id: model.merchant_id,
             name: model.name,
             user_id: model.user_id,
-            api_key: model.api_key,
+            api_key: mask_api_key(&model.api_key),
             status: model.status,
             created_at: model.created_at.map(|dt| dt.to_string()),
             updated_at: model.updated_at.map(|dt| dt.to_string()),
@@ -151,6 +205,16 @@ impl From<MerchantTransactionsModel> for MerchantTransactionResponse {
     }
 }
 
+impl From<MerchantTransactionDailyModel> for MerchantTransactionDailyResponse {
+    fn from(model: MerchantTransactionDailyModel) -> Self {
+        Self {
+            day: model.day.to_string(),
+            total_transactions: model.total_transactions as i32,
+            total_amount: model.total_amount,
+        }
+    }
+}
+
 impl From<MerchantMonthlyPaymentMethod> for MerchantResponseMonthlyPaymentMethod {
     fn from(m: MerchantMonthlyPaymentMethod) -> Self {
         Self {
@@ -223,6 +287,17 @@ impl From<MerchantResponse> for MerchantResponseProto {
     }
 }
 
+impl From<MerchantWebhookResponse> for MerchantWebhookResponseProto {
+    fn from(r: MerchantWebhookResponse) -> Self {
+        Self {
+            merchant_id: r.merchant_id,
+            url: r.url,
+            created_at: r.created_at.unwrap_or_default(),
+            updated_at: r.updated_at.unwrap_or_default(),
+        }
+    }
+}
+
 impl From<MerchantResponseDeleteAt> for MerchantResponseDeleteAtProto {
     fn from(r: MerchantResponseDeleteAt) -> Self {
         Self {
@@ -255,6 +330,41 @@ impl From<MerchantTransactionResponse> for MerchantTransactionResponseProto {
     }
 }
 
+impl From<MerchantTransactionDailyResponse> for MerchantTransactionDailyResponseProto {
+    fn from(r: MerchantTransactionDailyResponse) -> Self {
+        Self {
+            day: r.day,
+            total_transactions: r.total_transactions,
+            total_amount: r.total_amount,
+        }
+    }
+}
+
+impl From<MerchantApiKeyUsageResponse> for MerchantApiKeyUsageResponseProto {
+    fn from(r: MerchantApiKeyUsageResponse) -> Self {
+        Self {
+            api_key: r.api_key,
+            total_transactions: r.total_transactions,
+            total_amount: r.total_amount,
+            daily: r.daily.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<MerchantSettlementResponse> for MerchantSettlementResponseProto {
+    fn from(r: MerchantSettlementResponse) -> Self {
+        Self {
+            merchant_id: r.merchant_id,
+            start_date: r.start_date,
+            end_date: r.end_date,
+            gross: r.gross,
+            fees: r.fees,
+            refunds: r.refunds,
+            net: r.net,
+        }
+    }
+}
+
 impl From<MerchantResponseMonthlyPaymentMethod> for MerchantResponseMonthlyPaymentMethodProto {
     fn from(r: MerchantResponseMonthlyPaymentMethod) -> Self {
         Self {
@@ -327,6 +437,17 @@ impl From<MerchantResponseProto> for MerchantResponse {
     }
 }
 
+impl From<MerchantWebhookResponseProto> for MerchantWebhookResponse {
+    fn from(p: MerchantWebhookResponseProto) -> Self {
+        Self {
+            merchant_id: p.merchant_id,
+            url: p.url,
+            created_at: parse_datetime(&p.created_at),
+            updated_at: parse_datetime(&p.updated_at),
+        }
+    }
+}
+
 impl From<MerchantResponseDeleteAtProto> for MerchantResponseDeleteAt {
     fn from(p: MerchantResponseDeleteAtProto) -> Self {
         Self {
@@ -359,6 +480,41 @@ impl From<MerchantTransactionResponseProto> for MerchantTransactionResponse {
     }
 }
 
+impl From<MerchantTransactionDailyResponseProto> for MerchantTransactionDailyResponse {
+    fn from(p: MerchantTransactionDailyResponseProto) -> Self {
+        Self {
+            day: p.day,
+            total_transactions: p.total_transactions,
+            total_amount: p.total_amount,
+        }
+    }
+}
+
+impl From<MerchantApiKeyUsageResponseProto> for MerchantApiKeyUsageResponse {
+    fn from(p: MerchantApiKeyUsageResponseProto) -> Self {
+        Self {
+            api_key: p.api_key,
+            total_transactions: p.total_transactions,
+            total_amount: p.total_amount,
+            daily: p.daily.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+impl From<MerchantSettlementResponseProto> for MerchantSettlementResponse {
+    fn from(p: MerchantSettlementResponseProto) -> Self {
+        Self {
+            merchant_id: p.merchant_id,
+            start_date: p.start_date,
+            end_date: p.end_date,
+            gross: p.gross,
+            fees: p.fees,
+            refunds: p.refunds,
+            net: p.net,
+        }
+    }
+}
+
 impl From<MerchantResponseMonthlyPaymentMethodProto> for MerchantResponseMonthlyPaymentMethod {
     fn from(p: MerchantResponseMonthlyPaymentMethodProto) -> Self {
         Self {
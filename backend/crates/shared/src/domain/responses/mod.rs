@@ -1,36 +1,61 @@
+mod activity;
+mod anomaly;
 mod api;
 mod auth;
+mod balance_impact;
+mod bulk_result;
 mod card;
+mod feature;
+mod limits;
 mod merchant;
 mod pagination;
+mod payment_method;
+mod rate_limit;
 mod role;
 mod saldo;
 mod session;
+mod stats;
 mod topup;
 mod transaction;
 mod transfer;
 mod user;
 mod withdraw;
 
+pub use self::activity::{ActivityDirection, ActivityItem, ActivityType, RecentActivityResponse};
+pub use self::anomaly::AnomalyReport;
 pub use self::api::{ApiResponse, ApiResponsePagination};
-pub use self::auth::TokenResponse;
+pub use self::auth::{TokenInfoResponse, TokenIntrospectionResponse, TokenResponse};
+pub use self::balance_impact::BalanceImpactResponse;
+pub use self::bulk_result::{BulkResult, BulkResultError};
 pub use self::card::{
-    CardResponse, CardResponseDeleteAt, CardResponseMonthAmount, CardResponseMonthBalance,
-    CardResponseYearAmount, CardResponseYearlyBalance, DashboardCard, DashboardCardCardNumber,
+    CardFreezeResponse, CardReissueResponse, CardResponse, CardResponseDeleteAt,
+    CardResponseMonthAmount, CardResponseMonthBalance, CardResponseYearAmount,
+    CardResponseYearlyBalance, CardStatusResponse, DashboardCard, DashboardCardCardNumber,
+    DashboardCardNumbers, SystemStatsResponse, TopCardResponse,
+};
+pub use self::feature::FeatureFlagsResponse;
+pub use self::limits::{
+    AmountLimitResponse, CardLimitsResponse, EffectiveLimitsResponse, RateLimitInfoResponse,
 };
 pub use self::merchant::{
-    MerchantResponse, MerchantResponseDeleteAt, MerchantResponseMonthlyAmount,
-    MerchantResponseMonthlyPaymentMethod, MerchantResponseMonthlyTotalAmount,
-    MerchantResponseYearlyAmount, MerchantResponseYearlyPaymentMethod,
-    MerchantResponseYearlyTotalAmount, MerchantTransactionResponse,
+    MerchantApiKeyUsageResponse, MerchantResponse, MerchantResponseDeleteAt,
+    MerchantResponseMonthlyAmount, MerchantResponseMonthlyPaymentMethod,
+    MerchantResponseMonthlyTotalAmount, MerchantResponseYearlyAmount,
+    MerchantResponseYearlyPaymentMethod, MerchantResponseYearlyTotalAmount,
+    MerchantSettlementResponse, MerchantTransactionDailyResponse, MerchantTransactionResponse,
+    MerchantWebhookResponse,
 };
-pub use self::pagination::Pagination;
+pub use self::pagination::{Pagination, PaginationLinks};
+pub use self::payment_method::PaymentMethodResponse;
+pub use self::rate_limit::{RateLimitClearResponse, RateLimitStateResponse};
 pub use self::role::{RoleResponse, RoleResponseDeleteAt};
 pub use self::saldo::{
-    SaldoMonthBalanceResponse, SaldoMonthTotalBalanceResponse, SaldoResponse,
-    SaldoResponseDeleteAt, SaldoYearBalanceResponse, SaldoYearTotalBalanceResponse,
+    CardStatementEntryResponse, CardStatementResponse, CardStatementTotalsResponse,
+    SaldoBalancesResponse, SaldoMonthBalanceResponse, SaldoMonthTotalBalanceResponse,
+    SaldoResponse, SaldoResponseDeleteAt, SaldoYearBalanceResponse, SaldoYearTotalBalanceResponse,
 };
 pub use self::session::Session;
+pub use self::stats::StatsRecomputeResponse;
 pub use self::topup::{
     TopupMonthAmountResponse, TopupMonthMethodResponse, TopupResponse, TopupResponseDeleteAt,
     TopupResponseMonthStatusFailed, TopupResponseMonthStatusSuccess, TopupResponseYearStatusFailed,
@@ -44,12 +69,15 @@ pub use self::transaction::{
     TransactionYearlyAmountResponse,
 };
 pub use self::transfer::{
-    TransferMonthAmountResponse, TransferResponse, TransferResponseDeleteAt,
-    TransferResponseMonthStatusFailed, TransferResponseMonthStatusSuccess,
-    TransferResponseYearStatusFailed, TransferResponseYearStatusSuccess,
-    TransferYearAmountResponse,
+    TransferMonthAmountResponse, TransferNetPositionResponse, TransferResponse,
+    TransferResponseDeleteAt, TransferResponseMonthStatusFailed,
+    TransferResponseMonthStatusSuccess, TransferResponseYearStatusFailed,
+    TransferResponseYearStatusSuccess, TransferYearAmountResponse,
+};
+pub use self::user::{
+    UserDataExportResponse, UserExportCardResponse, UserExportTransactionSummaryResponse,
+    UserMergeResponse, UserResponse, UserResponseDeleteAt,
 };
-pub use self::user::{UserResponse, UserResponseDeleteAt};
 pub use self::withdraw::{
     WithdrawMonthlyAmountResponse, WithdrawResponse, WithdrawResponseDeleteAt,
     WithdrawResponseMonthStatusFailed, WithdrawResponseMonthStatusSuccess,
@@ -42,3 +42,105 @@ impl From<Pagination> for ProtoPagination {
         }
     }
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, ToSchema)]
+pub struct PaginationLinks {
+    #[serde(rename = "self")]
+    pub self_link: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub prev: Option<String>,
+    pub first: String,
+    pub last: String,
+}
+
+impl PaginationLinks {
+    pub fn build(path: &str, query: Option<&str>, pagination: &Pagination) -> Self {
+        let filters: Vec<&str> = query
+            .unwrap_or_default()
+            .split('&')
+            .filter(|pair| !pair.is_empty())
+            .filter(|pair| {
+                let name = pair.split_once('=').map_or(*pair, |(name, _)| name);
+                name != "page" && name != "page_size"
+            })
+            .collect();
+
+        let page_size = pagination.page_size.max(1);
+        let last_page = pagination.total_pages.max(1);
+        let page = pagination.page.max(1);
+
+        let link = |target: i32| {
+            let mut params = filters.join("&");
+            if !params.is_empty() {
+                params.push('&');
+            }
+            format!("{path}?{params}page={target}&page_size={page_size}")
+        };
+
+        Self {
+            self_link: link(page),
+            next: (page < last_page).then(|| link(page + 1)),
+            prev: (page > 1).then(|| link((page - 1).min(last_page))),
+            first: link(1),
+            last: link(last_page),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pagination(page: i32, page_size: i32, total_pages: i32) -> Pagination {
+        Pagination {
+            page,
+            page_size,
+            total_items: (total_pages * page_size) as i64,
+            total_pages,
+        }
+    }
+
+    #[test]
+    fn builds_links_for_a_middle_page() {
+        let links = PaginationLinks::build(
+            "/api/cards",
+            Some("search=visa&page=2&page_size=5"),
+            &pagination(2, 5, 4),
+        );
+
+        assert_eq!(links.self_link, "/api/cards?search=visa&page=2&page_size=5");
+        assert_eq!(
+            links.next.as_deref(),
+            Some("/api/cards?search=visa&page=3&page_size=5")
+        );
+        assert_eq!(
+            links.prev.as_deref(),
+            Some("/api/cards?search=visa&page=1&page_size=5")
+        );
+        assert_eq!(links.first, "/api/cards?search=visa&page=1&page_size=5");
+        assert_eq!(links.last, "/api/cards?search=visa&page=4&page_size=5");
+    }
+
+    #[test]
+    fn omits_prev_and_next_at_the_edges() {
+        let links = PaginationLinks::build("/api/users", None, &pagination(1, 10, 1));
+
+        assert_eq!(links.self_link, "/api/users?page=1&page_size=10");
+        assert_eq!(links.next, None);
+        assert_eq!(links.prev, None);
+        assert_eq!(links.last, "/api/users?page=1&page_size=10");
+    }
+
+    #[test]
+    fn points_prev_back_into_range_past_the_last_page() {
+        let links = PaginationLinks::build("/api/users", None, &pagination(9, 10, 3));
+
+        assert_eq!(links.next, None);
+        assert_eq!(
+            links.prev.as_deref(),
+            Some("/api/users?page=3&page_size=10")
+        );
+    }
+}
@@ -0,0 +1,22 @@
+use crate::config::PaymentMethodRule;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct PaymentMethodResponse {
+    pub name: String,
+    pub enabled: bool,
+    pub min_amount: Option<i64>,
+    pub max_amount: Option<i64>,
+}
+
+impl From<&PaymentMethodRule> for PaymentMethodResponse {
+    fn from(rule: &PaymentMethodRule) -> Self {
+        Self {
+            name: rule.name.clone(),
+            enabled: rule.enabled,
+            min_amount: rule.min_amount,
+            max_amount: rule.max_amount,
+        }
+    }
+}
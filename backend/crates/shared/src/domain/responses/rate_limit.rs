@@ -0,0 +1,15 @@
+use crate::domain::requests::rate_limit::RateLimitNamespace;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct RateLimitStateResponse {
+    pub namespace: RateLimitNamespace,
+    pub identifiers: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct RateLimitClearResponse {
+    pub namespace: RateLimitNamespace,
+    pub cleared: usize,
+}
@@ -1,11 +1,14 @@
 use crate::{
     model::saldo::{
-        SaldoModel, SaldoMonthSaldoBalance, SaldoMonthTotalBalance, SaldoYearSaldoBalance,
-        SaldoYearTotalBalance,
+        SaldoLedgerModel, SaldoModel, SaldoMonthSaldoBalance, SaldoMonthTotalBalance,
+        SaldoStatementModel, SaldoYearSaldoBalance, SaldoYearTotalBalance,
     },
     utils::parse_datetime,
 };
 use genproto::saldo::{
+    CardStatementEntry as CardStatementEntryProto,
+    CardStatementResponse as CardStatementResponseProto,
+    CardStatementTotals as CardStatementTotalsProto,
     SaldoMonthBalanceResponse as SaldoMonthBalanceResponseProto,
     SaldoMonthTotalBalanceResponse as SaldoMonthTotalBalanceResponseProto,
     SaldoResponse as SaldoResponseProto, SaldoResponseDeleteAt as SaldoResponseDeleteAtProto,
@@ -13,6 +16,7 @@ use genproto::saldo::{
     SaldoYearTotalBalanceResponse as SaldoYearTotalBalanceResponseProto,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use utoipa::ToSchema;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
@@ -28,6 +32,42 @@ pub struct SaldoResponse {
     pub updated_at: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Default)]
+pub struct SaldoBalancesResponse {
+    pub balances: BTreeMap<String, i64>,
+    pub missing_card_numbers: Vec<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone, Default, PartialEq, Eq)]
+pub struct CardStatementTotalsResponse {
+    pub topup: i64,
+    pub transfer: i64,
+    pub withdraw: i64,
+    pub transaction: i64,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct CardStatementEntryResponse {
+    pub id: i32,
+    pub category: String,
+    pub reference_type: String,
+    pub reference_id: i32,
+    pub amount: i64,
+    pub balance_after: i64,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct CardStatementResponse {
+    pub card_number: String,
+    pub year: i32,
+    pub month: i32,
+    pub opening_balance: i64,
+    pub closing_balance: i64,
+    pub totals: CardStatementTotalsResponse,
+    pub entries: Vec<CardStatementEntryResponse>,
+}
+
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
 pub struct SaldoResponseDeleteAt {
     pub id: i32,
@@ -135,6 +175,70 @@ impl From<SaldoYearSaldoBalance> for SaldoYearBalanceResponse {
     }
 }
 
+fn statement_category(reference_type: &str) -> &'static str {
+    match reference_type {
+        "topup" => "topup",
+        "transfer" => "transfer",
+        "withdraw" | "withdraw_cancel" => "withdraw",
+        "transaction" | "transaction_expiry" => "transaction",
+        "card_reissue" => "reissue",
+        _ => "other",
+    }
+}
+
+impl From<SaldoLedgerModel> for CardStatementEntryResponse {
+    fn from(model: SaldoLedgerModel) -> Self {
+        Self {
+            id: model.ledger_id,
+            category: statement_category(&model.reference_type).to_string(),
+            reference_type: model.reference_type,
+            reference_id: model.reference_id,
+            amount: model.amount,
+            balance_after: model.balance_after,
+            created_at: model.created_at.map(|dt| dt.to_string()),
+        }
+    }
+}
+
+impl CardStatementResponse {
+    pub fn from_model(
+        card_number: String,
+        year: i32,
+        month: i32,
+        model: SaldoStatementModel,
+    ) -> Self {
+        let closing_balance = model
+            .entries
+            .last()
+            .map(|entry| entry.balance_after)
+            .unwrap_or(model.opening_balance);
+
+        let entries: Vec<CardStatementEntryResponse> =
+            model.entries.into_iter().map(Into::into).collect();
+
+        let mut totals = CardStatementTotalsResponse::default();
+        for entry in &entries {
+            match entry.category.as_str() {
+                "topup" => totals.topup += entry.amount,
+                "transfer" => totals.transfer += entry.amount,
+                "withdraw" => totals.withdraw += entry.amount,
+                "transaction" => totals.transaction += entry.amount,
+                _ => {}
+            }
+        }
+
+        Self {
+            card_number,
+            year,
+            month,
+            opening_balance: model.opening_balance,
+            closing_balance,
+            totals,
+            entries,
+        }
+    }
+}
+
 // response to proto
 impl From<SaldoResponse> for SaldoResponseProto {
     fn from(r: SaldoResponse) -> Self {
@@ -202,6 +306,45 @@ impl From<SaldoYearBalanceResponse> for SaldoYearBalanceResponseProto {
     }
 }
 
+impl From<CardStatementTotalsResponse> for CardStatementTotalsProto {
+    fn from(r: CardStatementTotalsResponse) -> Self {
+        Self {
+            topup: r.topup,
+            transfer: r.transfer,
+            withdraw: r.withdraw,
+            transaction: r.transaction,
+        }
+    }
+}
+
+impl From<CardStatementEntryResponse> for CardStatementEntryProto {
+    fn from(r: CardStatementEntryResponse) -> Self {
+        Self {
+            id: r.id,
+            category: r.category,
+            reference_type: r.reference_type,
+            reference_id: r.reference_id,
+            amount: r.amount,
+            balance_after: r.balance_after,
+            created_at: r.created_at.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<CardStatementResponse> for CardStatementResponseProto {
+    fn from(r: CardStatementResponse) -> Self {
+        Self {
+            card_number: r.card_number,
+            year: r.year,
+            month: r.month,
+            opening_balance: r.opening_balance,
+            closing_balance: r.closing_balance,
+            totals: Some(r.totals.into()),
+            entries: r.entries.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
 // proto to response
 impl From<SaldoResponseProto> for SaldoResponse {
     fn from(p: SaldoResponseProto) -> Self {
@@ -268,3 +411,157 @@ impl From<SaldoYearBalanceResponseProto> for SaldoYearBalanceResponse {
         }
     }
 }
+
+impl From<CardStatementTotalsProto> for CardStatementTotalsResponse {
+    fn from(p: CardStatementTotalsProto) -> Self {
+        Self {
+            topup: p.topup,
+            transfer: p.transfer,
+            withdraw: p.withdraw,
+            transaction: p.transaction,
+        }
+    }
+}
+
+impl From<CardStatementEntryProto> for CardStatementEntryResponse {
+    fn from(p: CardStatementEntryProto) -> Self {
+        Self {
+            id: p.id,
+            category: p.category,
+            reference_type: p.reference_type,
+            reference_id: p.reference_id,
+            amount: p.amount,
+            balance_after: p.balance_after,
+            created_at: (!p.created_at.is_empty()).then_some(p.created_at),
+        }
+    }
+}
+
+impl From<CardStatementResponseProto> for CardStatementResponse {
+    fn from(p: CardStatementResponseProto) -> Self {
+        Self {
+            card_number: p.card_number,
+            year: p.year,
+            month: p.month,
+            opening_balance: p.opening_balance,
+            closing_balance: p.closing_balance,
+            totals: p.totals.map(Into::into).unwrap_or_default(),
+            entries: p.entries.into_iter().map(Into::into).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: i32, reference_type: &str, amount: i64, balance_after: i64) -> SaldoLedgerModel {
+        SaldoLedgerModel {
+            ledger_id: id,
+            card_number: "4111111111111111".to_string(),
+            amount,
+            balance_after,
+            reference_type: reference_type.to_string(),
+            reference_id: id,
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn totals_entries_by_category_and_closes_on_the_last_balance() {
+        let statement = CardStatementResponse::from_model(
+            "4111111111111111".to_string(),
+            2025,
+            8,
+            SaldoStatementModel {
+                opening_balance: 100_000,
+                entries: vec![
+                    entry(1, "topup", 50_000, 150_000),
+                    entry(2, "withdraw", -20_000, 130_000),
+                    entry(3, "withdraw_cancel", 20_000, 150_000),
+                    entry(4, "transaction", -30_000, 120_000),
+                    entry(5, "transaction_expiry", 30_000, 150_000),
+                    entry(6, "transfer", -10_000, 140_000),
+                    entry(7, "adjustment", 5_000, 145_000),
+                ],
+            },
+        );
+
+        assert_eq!(statement.opening_balance, 100_000);
+        assert_eq!(statement.closing_balance, 145_000);
+        assert_eq!(
+            statement.totals,
+            CardStatementTotalsResponse {
+                topup: 50_000,
+                transfer: -10_000,
+                withdraw: 0,
+                transaction: 0,
+            }
+        );
+        assert_eq!(statement.entries.len(), 7);
+        assert_eq!(statement.entries[6].category, "other");
+    }
+
+    #[test]
+    fn empty_month_closes_on_the_opening_balance() {
+        let statement = CardStatementResponse::from_model(
+            "4111111111111111".to_string(),
+            2025,
+            8,
+            SaldoStatementModel {
+                opening_balance: 75_000,
+                entries: Vec::new(),
+            },
+        );
+
+        assert_eq!(statement.closing_balance, 75_000);
+        assert_eq!(statement.totals, CardStatementTotalsResponse::default());
+    }
+
+    #[test]
+    fn reissue_entries_move_the_balance_between_cards() {
+        let mut closing = entry(8, "card_reissue", -145_000, 0);
+        closing.reference_id = 12;
+
+        let previous = CardStatementResponse::from_model(
+            "4111111111111111".to_string(),
+            2025,
+            8,
+            SaldoStatementModel {
+                opening_balance: 100_000,
+                entries: vec![entry(1, "topup", 45_000, 145_000), closing],
+            },
+        );
+
+        assert_eq!(previous.closing_balance, 0);
+        assert_eq!(previous.entries[1].category, "reissue");
+        assert_eq!(previous.entries[1].reference_id, 12);
+        assert_eq!(
+            previous.totals,
+            CardStatementTotalsResponse {
+                topup: 45_000,
+                ..CardStatementTotalsResponse::default()
+            }
+        );
+
+        let mut opening = entry(9, "card_reissue", 145_000, 145_000);
+        opening.card_number = "4222222222222222".to_string();
+        opening.reference_id = 12;
+
+        let reissued = CardStatementResponse::from_model(
+            "4222222222222222".to_string(),
+            2025,
+            8,
+            SaldoStatementModel {
+                opening_balance: 0,
+                entries: vec![opening, entry(10, "withdraw", -5_000, 140_000)],
+            },
+        );
+
+        assert_eq!(reissued.opening_balance, 0);
+        assert_eq!(reissued.closing_balance, 140_000);
+        assert_eq!(reissued.entries[0].category, "reissue");
+        assert_eq!(reissued.totals.withdraw, -5_000);
+        assert_eq!(reissued.totals.topup, 0);
+    }
+}
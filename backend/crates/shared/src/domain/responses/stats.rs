@@ -0,0 +1,13 @@
+use crate::domain::requests::stats::StatsScope;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct StatsRecomputeResponse {
+    pub scope: StatsScope,
+    pub from_year: i32,
+    pub to_year: i32,
+    pub invalidated_keys: usize,
+    pub recomputed: usize,
+    pub failed: Vec<String>,
+}
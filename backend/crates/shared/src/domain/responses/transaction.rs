@@ -1,9 +1,9 @@
 use crate::{
     model::transaction::{
-        TransactionModel, TransactionModelMonthStatusFailed, TransactionModelMonthStatusSuccess,
-        TransactionModelYearStatusFailed, TransactionModelYearStatusSuccess,
-        TransactionMonthAmount, TransactionMonthMethod, TransactionYearMethod,
-        TransactionYearlyAmount,
+        TransactionFeeModel, TransactionModel, TransactionModelMonthStatusFailed,
+        TransactionModelMonthStatusSuccess, TransactionModelYearStatusFailed,
+        TransactionModelYearStatusSuccess, TransactionMonthAmount, TransactionMonthMethod,
+        TransactionYearMethod, TransactionYearlyAmount,
     },
     utils::parse_datetime,
 };
@@ -20,6 +20,7 @@ use genproto::transaction::{
     TransactionYearlyAmountResponse as TransactionYearlyAmountResponseProto,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use utoipa::ToSchema;
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
@@ -35,6 +36,28 @@ pub struct TransactionResponse {
     pub created_at: Option<String>,
     #[serde(rename = "updated_at")]
     pub updated_at: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gross_amount: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub fee_amount: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub net_amount: Option<i64>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<BTreeMap<String, String>>,
+}
+
+impl TransactionResponse {
+    pub fn with_fee(mut self, fee: &TransactionFeeModel) -> Self {
+        self.gross_amount = Some(fee.gross_amount);
+        self.fee_amount = Some(fee.fee_amount);
+        self.net_amount = Some(fee.net_amount);
+        self
+    }
+
+    pub fn with_metadata(mut self, metadata: Option<BTreeMap<String, String>>) -> Self {
+        self.metadata = metadata.filter(|m| !m.is_empty());
+        self
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
@@ -125,6 +148,10 @@ impl From<TransactionModel> for TransactionResponse {
             transaction_time: model.transaction_time.to_string(),
             created_at: model.created_at.map(|dt| dt.to_string()),
             updated_at: model.updated_at.map(|dt| dt.to_string()),
+            gross_amount: None,
+            fee_amount: None,
+            net_amount: None,
+            metadata: None,
         }
     }
 }
@@ -241,6 +268,10 @@ impl From<TransactionResponse> for TransactionResponseProto {
             transaction_time: r.transaction_time,
             created_at: r.created_at.unwrap_or_default(),
             updated_at: r.updated_at.unwrap_or_default(),
+            gross_amount: r.gross_amount,
+            fee_amount: r.fee_amount,
+            net_amount: r.net_amount,
+            metadata: r.metadata.unwrap_or_default().into_iter().collect(),
         }
     }
 }
@@ -357,6 +388,10 @@ impl From<TransactionResponseProto> for TransactionResponse {
             transaction_time: p.transaction_time,
             created_at: parse_datetime(&p.created_at),
             updated_at: parse_datetime(&p.updated_at),
+            gross_amount: p.gross_amount,
+            fee_amount: p.fee_amount,
+            net_amount: p.net_amount,
+            metadata: (!p.metadata.is_empty()).then(|| p.metadata.into_iter().collect()),
         }
     }
 }
@@ -2,7 +2,7 @@ use crate::{
     model::transfer::{
         TransferModel, TransferModelMonthStatusFailed, TransferModelMonthStatusSuccess,
         TransferModelYearStatusFailed, TransferModelYearStatusSuccess, TransferMonthAmount,
-        TransferYearAmount,
+        TransferNetPositionModel, TransferYearAmount,
     },
     utils::parse_datetime,
 };
@@ -10,6 +10,7 @@ use genproto::transfer::{
     TransferMonthAmountResponse as TransferMonthAmountResponseProto,
     TransferMonthStatusFailedResponse as TransferResponseMonthStatusFailedProto,
     TransferMonthStatusSuccessResponse as TransferResponseMonthStatusSuccessProto,
+    TransferNetPositionResponse as TransferNetPositionResponseProto,
     TransferResponse as TransferResponseProto,
     TransferResponseDeleteAt as TransferResponseDeleteAtProto,
     TransferYearAmountResponse as TransferYearAmountResponseProto,
@@ -91,6 +92,40 @@ pub struct TransferYearAmountResponse {
     pub total_amount: i64,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct TransferNetPositionResponse {
+    pub card_a: String,
+    pub card_b: String,
+    pub a_to_b_amount: i64,
+    pub a_to_b_count: i64,
+    pub b_to_a_amount: i64,
+    pub b_to_a_count: i64,
+    pub net_amount: i64,
+    pub direction: String,
+}
+
+impl TransferNetPositionResponse {
+    pub fn from_model(card_a: &str, card_b: &str, m: TransferNetPositionModel) -> Self {
+        let net = m.a_to_b_amount - m.b_to_a_amount;
+        let direction = match net.signum() {
+            1 => "a_to_b",
+            -1 => "b_to_a",
+            _ => "balanced",
+        };
+
+        Self {
+            card_a: card_a.to_string(),
+            card_b: card_b.to_string(),
+            a_to_b_amount: m.a_to_b_amount,
+            a_to_b_count: m.a_to_b_count,
+            b_to_a_amount: m.b_to_a_amount,
+            b_to_a_count: m.b_to_a_count,
+            net_amount: net.abs(),
+            direction: direction.to_string(),
+        }
+    }
+}
+
 // model to response
 impl From<TransferModel> for TransferResponse {
     fn from(model: TransferModel) -> Self {
@@ -275,6 +310,21 @@ impl From<TransferYearAmountResponse> for TransferYearAmountResponseProto {
     }
 }
 
+impl From<TransferNetPositionResponse> for TransferNetPositionResponseProto {
+    fn from(r: TransferNetPositionResponse) -> Self {
+        Self {
+            card_a: r.card_a,
+            card_b: r.card_b,
+            a_to_b_amount: r.a_to_b_amount,
+            a_to_b_count: r.a_to_b_count,
+            b_to_a_amount: r.b_to_a_amount,
+            b_to_a_count: r.b_to_a_count,
+            net_amount: r.net_amount,
+            direction: r.direction,
+        }
+    }
+}
+
 // proto to response
 impl From<TransferResponseProto> for TransferResponse {
     fn from(p: TransferResponseProto) -> Self {
@@ -366,3 +416,57 @@ impl From<TransferYearAmountResponseProto> for TransferYearAmountResponse {
         }
     }
 }
+
+impl From<TransferNetPositionResponseProto> for TransferNetPositionResponse {
+    fn from(p: TransferNetPositionResponseProto) -> Self {
+        Self {
+            card_a: p.card_a,
+            card_b: p.card_b,
+            a_to_b_amount: p.a_to_b_amount,
+            a_to_b_count: p.a_to_b_count,
+            b_to_a_amount: p.b_to_a_amount,
+            b_to_a_count: p.b_to_a_count,
+            net_amount: p.net_amount,
+            direction: p.direction,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(a_to_b_amount: i64, b_to_a_amount: i64) -> TransferNetPositionResponse {
+        TransferNetPositionResponse::from_model(
+            "4111111111111111",
+            "4222222222222222",
+            TransferNetPositionModel {
+                a_to_b_amount,
+                a_to_b_count: 2,
+                b_to_a_amount,
+                b_to_a_count: 1,
+            },
+        )
+    }
+
+    #[test]
+    fn reports_the_net_amount_and_its_direction() {
+        let response = position(300_000, 100_000);
+        assert_eq!(response.net_amount, 200_000);
+        assert_eq!(response.direction, "a_to_b");
+        assert_eq!(response.a_to_b_count, 2);
+        assert_eq!(response.b_to_a_count, 1);
+
+        let response = position(50_000, 80_000);
+        assert_eq!(response.net_amount, 30_000);
+        assert_eq!(response.direction, "b_to_a");
+    }
+
+    #[test]
+    fn equal_flows_are_balanced() {
+        let response = position(70_000, 70_000);
+
+        assert_eq!(response.net_amount, 0);
+        assert_eq!(response.direction, "balanced");
+    }
+}
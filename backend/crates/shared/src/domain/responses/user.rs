@@ -1,6 +1,12 @@
-use crate::{model::user::UserModel, utils::parse_datetime};
+use crate::{
+    model::user::{UserExportCardModel, UserMergeModel, UserModel, UserTransactionSummaryModel},
+    utils::{mask_card_number, parse_datetime},
+};
 use genproto::user::{
-    UserResponse as UserResponseProto, UserResponseDeleteAt as UserResponseDeleteAtProto,
+    UserDataExport as UserDataExportProto, UserExportCard as UserExportCardProto,
+    UserExportTransactionSummary as UserExportTransactionSummaryProto,
+    UserMergeResult as UserMergeResultProto, UserResponse as UserResponseProto,
+    UserResponseDeleteAt as UserResponseDeleteAtProto,
 };
 use serde::{Deserialize, Serialize};
 use utoipa::ToSchema;
@@ -31,6 +37,43 @@ pub struct UserResponseDeleteAt {
     pub deleted_at: Option<String>,
 }
 
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct UserExportCardResponse {
+    pub card_number: String,
+    pub card_type: String,
+    pub card_provider: String,
+    pub expire_date: String,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct UserExportTransactionSummaryResponse {
+    pub card_number: String,
+    pub total_transactions: i64,
+    pub total_amount: i64,
+    pub first_transaction_at: Option<String>,
+    pub last_transaction_at: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct UserDataExportResponse {
+    pub exported_at: String,
+    pub profile: UserResponse,
+    pub roles: Vec<String>,
+    pub cards: Vec<UserExportCardResponse>,
+    pub transaction_summaries: Vec<UserExportTransactionSummaryResponse>,
+}
+
+#[derive(Debug, Serialize, Deserialize, ToSchema, Clone)]
+pub struct UserMergeResponse {
+    pub primary: UserResponse,
+    pub duplicate: UserResponseDeleteAt,
+    pub cards_moved: i64,
+    pub merchants_moved: i64,
+    pub roles_moved: i64,
+    pub merchant_links_moved: i64,
+}
+
 // model to response
 impl From<UserModel> for UserResponse {
     fn from(value: UserModel) -> Self {
@@ -59,6 +102,43 @@ impl From<UserModel> for UserResponseDeleteAt {
     }
 }
 
+impl From<UserMergeModel> for UserMergeResponse {
+    fn from(value: UserMergeModel) -> Self {
+        UserMergeResponse {
+            primary: value.primary.into(),
+            duplicate: value.duplicate.into(),
+            cards_moved: value.cards_moved,
+            merchants_moved: value.merchants_moved,
+            roles_moved: value.roles_moved,
+            merchant_links_moved: value.merchant_links_moved,
+        }
+    }
+}
+
+impl From<UserExportCardModel> for UserExportCardResponse {
+    fn from(value: UserExportCardModel) -> Self {
+        UserExportCardResponse {
+            card_number: mask_card_number(&value.card_number),
+            card_type: value.card_type,
+            card_provider: value.card_provider,
+            expire_date: value.expire_date.to_string(),
+            created_at: value.created_at.map(|dt| dt.to_string()),
+        }
+    }
+}
+
+impl From<UserTransactionSummaryModel> for UserExportTransactionSummaryResponse {
+    fn from(value: UserTransactionSummaryModel) -> Self {
+        UserExportTransactionSummaryResponse {
+            card_number: mask_card_number(&value.card_number),
+            total_transactions: value.total_transactions,
+            total_amount: value.total_amount,
+            first_transaction_at: value.first_transaction_at.map(|dt| dt.to_string()),
+            last_transaction_at: value.last_transaction_at.map(|dt| dt.to_string()),
+        }
+    }
+}
+
 // pub fn to_user_response(opt: Option<UserModel>) -> Option<UserResponse> {
 //     opt.map(UserResponse::from)
 // }
@@ -91,6 +171,59 @@ impl From<UserResponseDeleteAt> for UserResponseDeleteAtProto {
     }
 }
 
+impl From<UserExportCardResponse> for UserExportCardProto {
+    fn from(value: UserExportCardResponse) -> Self {
+        UserExportCardProto {
+            card_number: value.card_number,
+            card_type: value.card_type,
+            card_provider: value.card_provider,
+            expire_date: value.expire_date,
+            created_at: value.created_at.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<UserExportTransactionSummaryResponse> for UserExportTransactionSummaryProto {
+    fn from(value: UserExportTransactionSummaryResponse) -> Self {
+        UserExportTransactionSummaryProto {
+            card_number: value.card_number,
+            total_transactions: value.total_transactions,
+            total_amount: value.total_amount,
+            first_transaction_at: value.first_transaction_at.unwrap_or_default(),
+            last_transaction_at: value.last_transaction_at.unwrap_or_default(),
+        }
+    }
+}
+
+impl From<UserDataExportResponse> for UserDataExportProto {
+    fn from(value: UserDataExportResponse) -> Self {
+        UserDataExportProto {
+            exported_at: value.exported_at,
+            profile: Some(value.profile.into()),
+            roles: value.roles,
+            cards: value.cards.into_iter().map(Into::into).collect(),
+            transaction_summaries: value
+                .transaction_summaries
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        }
+    }
+}
+
+impl From<UserMergeResponse> for UserMergeResultProto {
+    fn from(value: UserMergeResponse) -> Self {
+        UserMergeResultProto {
+            primary: Some(value.primary.into()),
+            duplicate: Some(value.duplicate.into()),
+            cards_moved: value.cards_moved,
+            merchants_moved: value.merchants_moved,
+            roles_moved: value.roles_moved,
+            merchant_links_moved: value.merchant_links_moved,
+        }
+    }
+}
+
 // proto to response
 impl From<UserResponseProto> for UserResponse {
     fn from(value: UserResponseProto) -> Self {
@@ -117,3 +250,71 @@ impl From<UserResponseDeleteAtProto> for UserResponseDeleteAt {
         }
     }
 }
+
+impl From<UserExportCardProto> for UserExportCardResponse {
+    fn from(value: UserExportCardProto) -> Self {
+        UserExportCardResponse {
+            card_number: value.card_number,
+            card_type: value.card_type,
+            card_provider: value.card_provider,
+            expire_date: value.expire_date,
+            created_at: parse_datetime(&value.created_at),
+        }
+    }
+}
+
+impl From<UserExportTransactionSummaryProto> for UserExportTransactionSummaryResponse {
+    fn from(value: UserExportTransactionSummaryProto) -> Self {
+        UserExportTransactionSummaryResponse {
+            card_number: value.card_number,
+            total_transactions: value.total_transactions,
+            total_amount: value.total_amount,
+            first_transaction_at: parse_datetime(&value.first_transaction_at),
+            last_transaction_at: parse_datetime(&value.last_transaction_at),
+        }
+    }
+}
+
+impl TryFrom<UserDataExportProto> for UserDataExportResponse {
+    type Error = String;
+
+    fn try_from(value: UserDataExportProto) -> Result<Self, Self::Error> {
+        let profile = value
+            .profile
+            .ok_or_else(|| "user export is missing profile".to_string())?;
+
+        Ok(UserDataExportResponse {
+            exported_at: value.exported_at,
+            profile: profile.into(),
+            roles: value.roles,
+            cards: value.cards.into_iter().map(Into::into).collect(),
+            transaction_summaries: value
+                .transaction_summaries
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+        })
+    }
+}
+
+impl TryFrom<UserMergeResultProto> for UserMergeResponse {
+    type Error = String;
+
+    fn try_from(value: UserMergeResultProto) -> Result<Self, Self::Error> {
+        let primary = value
+            .primary
+            .ok_or_else(|| "user merge is missing primary user".to_string())?;
+        let duplicate = value
+            .duplicate
+            .ok_or_else(|| "user merge is missing duplicate user".to_string())?;
+
+        Ok(UserMergeResponse {
+            primary: primary.into(),
+            duplicate: duplicate.into(),
+            cards_moved: value.cards_moved,
+            merchants_moved: value.merchants_moved,
+            roles_moved: value.roles_moved,
+            merchant_links_moved: value.merchant_links_moved,
+        })
+    }
+}
@@ -11,6 +11,8 @@ pub enum AppErrorGrpc {
     Service(#[from] ServiceError),
     #[error("Circuit breaker is open - service temporarily unavailable")]
     CircuitBreakerOpen,
+    #[error("Timed out: {0}")]
+    Timeout(String),
     #[error("Unhandled: {0}")]
     Unhandled(String),
 }
@@ -22,9 +24,11 @@ impl AppErrorGrpc {
                 ServiceError::NotFound(_) => warn!("⚠️ {}", self),
                 ServiceError::InvalidCredentials => warn!("🔐 {}", self),
                 ServiceError::TokenExpired => warn!("⏰ {}", self),
+                ServiceError::Overloaded(_) => warn!("🚦 {}", self),
                 _ => error!("🚨 {}", self),
             },
             AppErrorGrpc::CircuitBreakerOpen => warn!("🔌 {}", self),
+            AppErrorGrpc::Timeout(_) => warn!("⏱️ {}", self),
             AppErrorGrpc::Unhandled(_) => error!("💥 {}", self),
         }
     }
@@ -58,6 +62,14 @@ impl From<AppErrorGrpc> for Status {
                 ),
                 ServiceError::Forbidden(msg) => (Code::PermissionDenied, msg),
                 ServiceError::Repo(repo_err) => match repo_err {
+                    ref e if e.is_statement_timeout() => {
+                        warn!("⏱️ Query cancelled by statement timeout: {e:?}");
+                        (
+                            Code::DeadlineExceeded,
+                            "⏱️ Query took too long and was cancelled by the statement timeout"
+                                .into(),
+                        )
+                    }
                     RepositoryError::NotFound => (Code::NotFound, "🔍 Resource not found".into()),
                     RepositoryError::Conflict(msg) => {
                         (Code::AlreadyExists, format!("⚡ Conflict: {msg}"))
@@ -104,11 +116,13 @@ impl From<AppErrorGrpc> for Status {
                     warn!("🔍 Not found: {msg}");
                     (Code::NotFound, format!("🔍 {msg}"))
                 }
+                ServiceError::Overloaded(msg) => (Code::ResourceExhausted, format!("🚦 {msg}")),
             },
             AppErrorGrpc::CircuitBreakerOpen => (
                 Code::Unavailable,
                 "🔌 Service temporarily unavailable - circuit breaker is open".into(),
             ),
+            AppErrorGrpc::Timeout(msg) => (Code::DeadlineExceeded, msg),
             AppErrorGrpc::Unhandled(msg) => {
                 error!("💥 Unhandled application error: {msg}");
                 (Code::Internal, format!("💥 Unexpected error: {msg}"))
@@ -153,8 +167,14 @@ impl From<Status> for AppErrorGrpc {
                 status.message().to_string(),
             )),
 
+            tonic::Code::ResourceExhausted => {
+                AppErrorGrpc::Service(ServiceError::Overloaded(status.message().to_string()))
+            }
+
             tonic::Code::Unavailable => AppErrorGrpc::CircuitBreakerOpen,
 
+            tonic::Code::DeadlineExceeded => AppErrorGrpc::Timeout(status.message().to_string()),
+
             _ => {
                 warn!("🌐 Unknown gRPC status conversion: {status_code} - {message}");
                 AppErrorGrpc::Unhandled(format!("gRPC error: {status_code} - {message}"))
@@ -12,6 +12,9 @@ use axum::{
 };
 use tracing::{error, info, warn};
 
+#[derive(Debug, Clone, Copy)]
+pub struct LoadShed;
+
 #[derive(Debug)]
 pub enum HttpError {
     BadRequest(String),
@@ -19,6 +22,7 @@ pub enum HttpError {
     NotFound(String),
     Conflict(String),
     ServiceUnavailable(String),
+    TooManyRequests(String),
     Internal(String),
     Forbidden(String),
 }
@@ -62,6 +66,8 @@ impl From<AppErrorGrpc> for HttpError {
                 ServiceError::InvalidTokenType => {
                     HttpError::Unauthorized("Invalid token type".into())
                 }
+
+                ServiceError::Overloaded(msg) => HttpError::TooManyRequests(msg),
                 _ => HttpError::Internal("Unknown service error".into()),
             },
 
@@ -69,6 +75,8 @@ impl From<AppErrorGrpc> for HttpError {
                 "Service temporarily unavailable - circuit breaker is open".to_string(),
             ),
 
+            AppErrorGrpc::Timeout(msg) => HttpError::ServiceUnavailable(msg),
+
             AppErrorGrpc::Unhandled(msg) => HttpError::Internal(msg),
         }
     }
@@ -83,6 +91,7 @@ impl IntoResponse for HttpError {
             HttpError::NotFound(msg) => (StatusCode::NOT_FOUND, msg, "info"),
             HttpError::Conflict(msg) => (StatusCode::CONFLICT, msg, "warn"),
             HttpError::ServiceUnavailable(msg) => (StatusCode::SERVICE_UNAVAILABLE, msg, "warn"),
+            HttpError::TooManyRequests(msg) => (StatusCode::TOO_MANY_REQUESTS, msg, "warn"),
             HttpError::Internal(msg) => (StatusCode::INTERNAL_SERVER_ERROR, msg, "error"),
         };
 
@@ -101,6 +110,10 @@ impl IntoResponse for HttpError {
             trace_id,
         });
 
-        (status, body).into_response()
+        let mut response = (status, body).into_response();
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            response.extensions_mut().insert(LoadShed);
+        }
+        response
     }
 }
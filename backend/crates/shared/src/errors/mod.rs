@@ -9,7 +9,7 @@ mod validate;
 pub use self::circuit::CircuitBreakerError;
 pub use self::error::ErrorResponse;
 pub use self::grpc::AppErrorGrpc;
-pub use self::http::HttpError;
+pub use self::http::{HttpError, LoadShed};
 pub use self::repository::RepositoryError;
 pub use self::service::ServiceError;
 pub use self::validate::format_validation_errors;
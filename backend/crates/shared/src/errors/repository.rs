@@ -21,3 +21,19 @@ pub enum RepositoryError {
     #[error("Custom: {0}")]
     Custom(String),
 }
+
+impl RepositoryError {
+    pub fn is_not_found(&self) -> bool {
+        matches!(
+            self,
+            RepositoryError::NotFound | RepositoryError::Sqlx(SqlxError::RowNotFound)
+        )
+    }
+
+    pub fn is_statement_timeout(&self) -> bool {
+        matches!(
+            self,
+            RepositoryError::Sqlx(SqlxError::Database(e)) if e.code().as_deref() == Some("57014")
+        )
+    }
+}
@@ -37,4 +37,7 @@ pub enum ServiceError {
 
     #[error("Custom error: {0}")]
     Custom(String),
+
+    #[error("Service overloaded: {0}")]
+    Overloaded(String),
 }
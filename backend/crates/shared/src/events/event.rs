@@ -0,0 +1,27 @@
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+
+pub const EVENT_USER_REGISTERED: &str = "user.registered";
+pub const EVENT_TRANSACTION_CREATED: &str = "transaction.created";
+pub const EVENT_TRANSFER_SETTLED: &str = "transfer.settled";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DomainEvent {
+    pub event_type: String,
+    pub occurred_at: String,
+    /// Per-endpoint ordering hint, set by the webhook dispatcher on delivery.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub sequence: Option<u64>,
+    pub payload: serde_json::Value,
+}
+
+impl DomainEvent {
+    pub fn new(event_type: impl Into<String>, payload: serde_json::Value) -> Self {
+        Self {
+            event_type: event_type.into(),
+            occurred_at: Utc::now().to_rfc3339(),
+            sequence: None,
+            payload,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+mod event;
+mod publisher;
+mod sink;
+
+pub use self::event::{
+    DomainEvent, EVENT_TRANSACTION_CREATED, EVENT_TRANSFER_SETTLED, EVENT_USER_REGISTERED,
+};
+pub use self::publisher::EventPublisher;
+pub use self::sink::LogEventSink;
@@ -0,0 +1,107 @@
+use super::{DomainEvent, LogEventSink};
+use crate::{
+    abstract_trait::events::{DynEventPublisher, DynEventSink, EventPublisherTrait},
+    config::{EventSinkKind, EventsConfig},
+};
+use opentelemetry::{KeyValue, global, metrics::Counter};
+use std::sync::{Arc, LazyLock};
+use tokio::sync::mpsc::{self, error::TrySendError};
+use tracing::{info, warn};
+
+static PUBLISHED: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter("events")
+        .u64_counter("events.published")
+        .with_description("Domain events delivered to the event sink")
+        .build()
+});
+
+static FAILED: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter("events")
+        .u64_counter("events.failed")
+        .with_description("Domain events the event sink rejected")
+        .build()
+});
+
+static DROPPED: LazyLock<Counter<u64>> = LazyLock::new(|| {
+    global::meter("events")
+        .u64_counter("events.dropped")
+        .with_description("Domain events dropped before reaching the event sink")
+        .build()
+});
+
+#[derive(Clone)]
+pub struct EventPublisher {
+    sender: Option<mpsc::Sender<DomainEvent>>,
+}
+
+impl EventPublisher {
+    pub fn new(sink: DynEventSink, buffer_size: usize) -> Self {
+        let (sender, mut receiver) = mpsc::channel::<DomainEvent>(buffer_size.max(1));
+
+        tokio::spawn(async move {
+            while let Some(event) = receiver.recv().await {
+                let labels = [
+                    KeyValue::new("event_type", event.event_type.clone()),
+                    KeyValue::new("sink", sink.name()),
+                ];
+
+                match sink.publish(&event).await {
+                    Ok(()) => PUBLISHED.add(1, &labels),
+                    Err(e) => {
+                        FAILED.add(1, &labels);
+                        warn!("⚠️  Failed to publish {} event: {e}", event.event_type);
+                    }
+                }
+            }
+        });
+
+        Self {
+            sender: Some(sender),
+        }
+    }
+
+    pub fn disabled() -> Self {
+        Self { sender: None }
+    }
+
+    pub fn from_config(config: &EventsConfig) -> DynEventPublisher {
+        if !config.enabled {
+            return Arc::new(Self::disabled());
+        }
+
+        let sink: DynEventSink = match config.sink {
+            EventSinkKind::Log => Arc::new(LogEventSink),
+        };
+
+        info!(
+            "📣 Publishing domain events to the {} sink (buffer {})",
+            sink.name(),
+            config.buffer_size
+        );
+
+        Arc::new(Self::new(sink, config.buffer_size))
+    }
+}
+
+impl EventPublisherTrait for EventPublisher {
+    fn emit(&self, event: DomainEvent) {
+        let Some(sender) = &self.sender else {
+            return;
+        };
+
+        let (reason, event) = match sender.try_send(event) {
+            Ok(()) => return,
+            Err(TrySendError::Full(event)) => ("full", event),
+            Err(TrySendError::Closed(event)) => ("closed", event),
+        };
+
+        DROPPED.add(
+            1,
+            &[
+                KeyValue::new("event_type", event.event_type.clone()),
+                KeyValue::new("reason", reason),
+            ],
+        );
+        warn!("⚠️  Dropped {} event: buffer {reason}", event.event_type);
+    }
+}
@@ -0,0 +1,25 @@
+use super::DomainEvent;
+use crate::{abstract_trait::events::EventSinkTrait, errors::ServiceError};
+use async_trait::async_trait;
+use tracing::info;
+
+#[derive(Debug, Default)]
+pub struct LogEventSink;
+
+#[async_trait]
+impl EventSinkTrait for LogEventSink {
+    fn name(&self) -> &'static str {
+        "log"
+    }
+
+    async fn publish(&self, event: &DomainEvent) -> Result<(), ServiceError> {
+        info!(
+            target: "events",
+            event_type = %event.event_type,
+            occurred_at = %event.occurred_at,
+            payload = %event.payload,
+            "domain event"
+        );
+        Ok(())
+    }
+}
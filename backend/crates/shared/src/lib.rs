@@ -4,9 +4,11 @@ pub mod config;
 pub mod context;
 pub mod domain;
 pub mod errors;
+pub mod events;
 pub mod model;
 pub mod observability;
 pub mod repository;
 pub mod resilience;
 pub mod service;
 pub mod utils;
+pub mod webhook;
@@ -0,0 +1,9 @@
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+use sqlx::FromRow;
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct DailyMetricModel {
+    pub day: NaiveDate,
+    pub value: i64,
+}
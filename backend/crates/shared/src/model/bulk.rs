@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BulkItemErrorModel {
+    pub id: i32,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct BulkResultModel {
+    pub total: i64,
+    pub affected: i64,
+    pub skipped: i64,
+    pub errors: Vec<BulkItemErrorModel>,
+}
@@ -16,6 +16,44 @@ pub struct CardModel {
     pub deleted_at: Option<NaiveDateTime>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CardStatusModel {
+    pub card_provider: String,
+    pub expire_date: NaiveDate,
+    pub deleted_at: Option<NaiveDateTime>,
+    pub frozen_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct CardFreezeModel {
+    pub card_id: i32,
+    pub user_id: i32,
+    pub card_number: String,
+    pub frozen_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SystemStatsModel {
+    pub total_users: i64,
+    pub total_cards: i64,
+    pub total_balance: i64,
+    pub today_transaction_count: i64,
+    pub today_transaction_volume: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TopCardModel {
+    pub card_number: String,
+    pub user_id: i32,
+    pub value: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CardReissueModel {
+    pub previous_card: CardModel,
+    pub card: CardModel,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct CardMonthBalance {
     pub month: String,
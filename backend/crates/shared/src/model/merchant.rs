@@ -1,4 +1,4 @@
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -14,6 +14,14 @@ pub struct MerchantModel {
     pub deleted_at: Option<NaiveDateTime>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MerchantWebhookModel {
+    pub merchant_id: i32,
+    pub url: String,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct MerchantTransactionsModel {
     pub transaction_id: i32,
@@ -28,6 +36,29 @@ pub struct MerchantTransactionsModel {
     pub deleted_at: Option<NaiveDateTime>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MerchantTransactionDailyModel {
+    pub day: NaiveDate,
+    pub total_transactions: i64,
+    pub total_amount: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MerchantApiKeyUsageDailyModel {
+    pub api_key: String,
+    pub day: NaiveDate,
+    pub total_transactions: i64,
+    pub total_amount: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct MerchantSettlementModel {
+    pub gross: i64,
+    pub fees: i64,
+    pub refunds: i64,
+    pub net: i64,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct MerchantYearlyPaymentMethod {
     pub year: String,
@@ -14,6 +14,23 @@ pub struct SaldoModel {
     pub deleted_at: Option<NaiveDateTime>,
 }
 
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct SaldoLedgerModel {
+    pub ledger_id: i32,
+    pub card_number: String,
+    pub amount: i64,
+    pub balance_after: i64,
+    pub reference_type: String,
+    pub reference_id: i32,
+    pub created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaldoStatementModel {
+    pub opening_balance: i64,
+    pub entries: Vec<SaldoLedgerModel>,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
 pub struct SaldoMonthTotalBalance {
     pub year: String,
@@ -75,3 +75,11 @@ pub struct TransactionYearlyAmount {
     pub year: String,
     pub total_amount: i64,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TransactionFeeModel {
+    pub transaction_id: i32,
+    pub gross_amount: i64,
+    pub fee_amount: i64,
+    pub net_amount: i64,
+}
@@ -58,3 +58,11 @@ pub struct TransferYearAmount {
     pub year: String,
     pub total_amount: i64,
 }
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct TransferNetPositionModel {
+    pub a_to_b_amount: i64,
+    pub a_to_b_count: i64,
+    pub b_to_a_amount: i64,
+    pub b_to_a_count: i64,
+}
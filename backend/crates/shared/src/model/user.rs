@@ -1,4 +1,4 @@
-use chrono::NaiveDateTime;
+use chrono::{NaiveDate, NaiveDateTime};
 use serde::{Deserialize, Serialize};
 use sqlx::FromRow;
 
@@ -15,3 +15,31 @@ pub struct UserModel {
     pub updated_at: Option<NaiveDateTime>,
     pub deleted_at: Option<NaiveDateTime>,
 }
+
+#[derive(Debug, Clone)]
+pub struct UserMergeModel {
+    pub primary: UserModel,
+    pub duplicate: UserModel,
+    pub cards_moved: i64,
+    pub merchants_moved: i64,
+    pub roles_moved: i64,
+    pub merchant_links_moved: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UserExportCardModel {
+    pub card_number: String,
+    pub card_type: String,
+    pub card_provider: String,
+    pub expire_date: NaiveDate,
+    pub created_at: Option<NaiveDateTime>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, FromRow)]
+pub struct UserTransactionSummaryModel {
+    pub card_number: String,
+    pub total_transactions: i64,
+    pub total_amount: i64,
+    pub first_transaction_at: Option<NaiveDateTime>,
+    pub last_transaction_at: Option<NaiveDateTime>,
+}
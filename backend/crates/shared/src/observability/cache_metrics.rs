@@ -22,6 +22,7 @@ pub enum CacheOperation {
     Delete,
     Clear,
     Invalidate,
+    Scan,
     GetStats,
 }
 
@@ -33,6 +34,7 @@ impl CacheOperation {
             Self::Delete => "delete",
             Self::Clear => "clear",
             Self::Invalidate => "invalidate",
+            Self::Scan => "scan",
             Self::GetStats => "get_stats",
         }
     }
@@ -1,3 +1,7 @@
+use crate::{
+    config::{ConnectionPool, PoolMetricsConfig},
+    observability::PoolMetrics,
+};
 use opentelemetry::{
     KeyValue, global,
     metrics::{Counter, Gauge, Histogram, Meter},
@@ -151,6 +155,10 @@ impl Display for Status {
     }
 }
 
+const REQUEST_DURATION_BUCKETS_MS: &[f64] = &[
+    1.0, 2.5, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0, 2500.0, 5000.0, 10000.0,
+];
+
 #[derive(Clone, Debug)]
 pub struct Metrics {
     request_counter: Counter<u64>,
@@ -165,9 +173,10 @@ impl Metrics {
             .build();
 
         let request_duration = meter
-            .f64_histogram("request_duration_seconds")
-            .with_description("HTTP request duration in seconds")
-            .with_unit("s")
+            .f64_histogram("request_duration_milliseconds")
+            .with_description("HTTP request duration in milliseconds")
+            .with_unit("ms")
+            .with_boundaries(REQUEST_DURATION_BUCKETS_MS.to_vec())
             .build();
 
         Self {
@@ -176,14 +185,14 @@ impl Metrics {
         }
     }
 
-    pub fn record(&self, method: Method, status: Status, duration_secs: f64) {
+    pub fn record(&self, method: Method, status: Status, duration_ms: f64) {
         let attributes = &[
             KeyValue::new("http.method", method.to_string()),
             KeyValue::new("http.status", status.to_string()),
         ];
 
         self.request_counter.add(1, attributes);
-        self.request_duration.record(duration_secs, attributes);
+        self.request_duration.record(duration_ms, attributes);
     }
 }
 
@@ -193,7 +202,21 @@ impl Default for Metrics {
     }
 }
 
-pub async fn run_metrics_collector() {
+pub async fn run_metrics_collector(pool: Option<ConnectionPool>) {
+    let pool_config = PoolMetricsConfig::from_env();
+
+    if let Some(pool) = pool.filter(|_| pool_config.enabled) {
+        tokio::spawn(async move {
+            let metrics = PoolMetrics::new();
+            let mut interval = tokio::time::interval(pool_config.sample_interval());
+
+            loop {
+                interval.tick().await;
+                metrics.update_metrics(&pool);
+            }
+        });
+    }
+
     let mut metrics = SystemMetrics::new();
     let mut interval = tokio::time::interval(std::time::Duration::from_secs(15));
 
@@ -3,6 +3,10 @@ mod logs;
 mod metadata;
 mod metrics;
 mod otel;
+mod pool_metrics;
+mod query_params;
+mod request_deadline;
+mod request_id;
 mod tracing_metrics;
 
 pub use self::cache_metrics::{CacheMetrics, CacheMetricsCore, CacheOperation, CacheResult};
@@ -10,4 +14,10 @@ pub use self::logs::init_logger;
 pub use self::metadata::MetadataInjector;
 pub use self::metrics::{Method, Metrics, Status, SystemMetrics, run_metrics_collector};
 pub use self::otel::{Telemetry, TracingContext};
+pub use self::pool_metrics::{PoolMetrics, acquire_connection, begin_transaction};
+pub use self::query_params::log_query_params;
+pub use self::request_deadline::{
+    apply_request_deadline, remaining_request_budget, with_request_deadline,
+};
+pub use self::request_id::{RequestId, current_request_id, inject_request_id, with_request_id};
 pub use self::tracing_metrics::{TracingMetrics, TracingMetricsCore};
@@ -0,0 +1,93 @@
+use crate::config::ConnectionPool;
+use opentelemetry::{
+    KeyValue, global,
+    metrics::{Gauge, Histogram},
+};
+use sqlx::{Postgres, Transaction, pool::PoolConnection};
+use std::{sync::LazyLock, time::Instant};
+
+static ACQUIRE_WAIT: LazyLock<Histogram<f64>> = LazyLock::new(|| {
+    global::meter("db_pool")
+        .f64_histogram("db.pool.acquire_wait_seconds")
+        .with_description("Time spent waiting for a pooled database connection")
+        .with_unit("s")
+        .build()
+});
+
+#[derive(Debug)]
+pub struct PoolMetrics {
+    size: Gauge<u64>,
+    idle: Gauge<u64>,
+    in_use: Gauge<u64>,
+    max: Gauge<u64>,
+}
+
+impl PoolMetrics {
+    pub fn new() -> Self {
+        let meter = global::meter("db_pool");
+
+        Self {
+            size: meter
+                .u64_gauge("db.pool.connections")
+                .with_description("Open connections in the database pool")
+                .build(),
+            idle: meter
+                .u64_gauge("db.pool.connections_idle")
+                .with_description("Idle connections in the database pool")
+                .build(),
+            in_use: meter
+                .u64_gauge("db.pool.connections_in_use")
+                .with_description("Connections currently checked out of the database pool")
+                .build(),
+            max: meter
+                .u64_gauge("db.pool.connections_max")
+                .with_description("Configured maximum size of the database pool")
+                .build(),
+        }
+    }
+
+    pub fn update_metrics(&self, pool: &ConnectionPool) {
+        let size = pool.size() as u64;
+        let idle = pool.num_idle() as u64;
+
+        self.size.record(size, &[]);
+        self.idle.record(idle, &[]);
+        self.in_use.record(size.saturating_sub(idle), &[]);
+        self.max
+            .record(pool.options().get_max_connections() as u64, &[]);
+    }
+}
+
+impl Default for PoolMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+pub async fn acquire_connection(
+    pool: &ConnectionPool,
+) -> Result<PoolConnection<Postgres>, sqlx::Error> {
+    let start = Instant::now();
+    let result = pool.acquire().await;
+    record_acquire_wait("acquire", start, result.is_ok());
+    result
+}
+
+pub async fn begin_transaction(
+    pool: &ConnectionPool,
+) -> Result<Transaction<'static, Postgres>, sqlx::Error> {
+    let start = Instant::now();
+    let result = pool.begin().await;
+    record_acquire_wait("begin", start, result.is_ok());
+    result
+}
+
+fn record_acquire_wait(operation: &'static str, start: Instant, success: bool) {
+    ACQUIRE_WAIT.record(
+        start.elapsed().as_secs_f64(),
+        &[
+            KeyValue::new("operation", operation),
+            KeyValue::new("status", if success { "success" } else { "error" }),
+        ],
+    );
+}
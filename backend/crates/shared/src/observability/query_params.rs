@@ -0,0 +1,16 @@
+use crate::config::QueryParamLoggingConfig;
+
+pub fn log_query_params(query: &str, params: &[(&str, &dyn std::fmt::Debug)]) {
+    let config = QueryParamLoggingConfig::global();
+    if !config.enabled {
+        return;
+    }
+
+    let rendered = params
+        .iter()
+        .map(|(key, value)| format!("{key}={}", config.sanitize(key, *value)))
+        .collect::<Vec<_>>()
+        .join(", ");
+
+    tracing::info!(target: "db.query_params", query, params = %rendered, "🧾 Query parameters");
+}
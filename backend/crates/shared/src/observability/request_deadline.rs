@@ -0,0 +1,25 @@
+use std::{future::Future, time::Duration};
+use tokio::time::Instant;
+use tonic::Request;
+
+const MIN_DOWNSTREAM_TIMEOUT: Duration = Duration::from_millis(1);
+
+tokio::task_local! {
+    static CURRENT_DEADLINE: Instant;
+}
+
+pub async fn with_request_deadline<F: Future>(deadline: Instant, fut: F) -> F::Output {
+    CURRENT_DEADLINE.scope(deadline, fut).await
+}
+
+pub fn remaining_request_budget() -> Option<Duration> {
+    CURRENT_DEADLINE
+        .try_with(|deadline| deadline.saturating_duration_since(Instant::now()))
+        .ok()
+}
+
+pub fn apply_request_deadline<T>(request: &mut Request<T>) {
+    if let Some(remaining) = remaining_request_budget() {
+        request.set_timeout(remaining.max(MIN_DOWNSTREAM_TIMEOUT));
+    }
+}
@@ -0,0 +1,32 @@
+use std::future::Future;
+use tonic::metadata::{MetadataKey, MetadataMap, MetadataValue};
+
+#[derive(Debug, Clone)]
+pub struct RequestId {
+    pub header: String,
+    pub value: String,
+}
+
+tokio::task_local! {
+    static CURRENT_REQUEST_ID: RequestId;
+}
+
+pub async fn with_request_id<F: Future>(request_id: RequestId, fut: F) -> F::Output {
+    CURRENT_REQUEST_ID.scope(request_id, fut).await
+}
+
+pub fn current_request_id() -> Option<RequestId> {
+    CURRENT_REQUEST_ID.try_with(Clone::clone).ok()
+}
+
+pub fn inject_request_id(metadata: &mut MetadataMap) {
+    let Some(request_id) = current_request_id() else {
+        return;
+    };
+
+    if let Ok(key) = request_id.header.parse::<MetadataKey<_>>()
+        && let Ok(value) = request_id.value.parse::<MetadataValue<_>>()
+    {
+        metadata.insert(key, value);
+    }
+}
@@ -1,4 +1,10 @@
-use crate::observability::{MetadataInjector, Method, Metrics, Status, TracingContext};
+use crate::{
+    config::SpanRedactionConfig,
+    observability::{
+        MetadataInjector, Method, Metrics, Status, TracingContext, apply_request_deadline,
+        inject_request_id,
+    },
+};
 use anyhow::Result;
 use opentelemetry::{
     Context, KeyValue,
@@ -16,13 +22,20 @@ pub type TracingMetrics = Arc<TracingMetricsCore>;
 pub struct TracingMetricsCore {
     metrics: Metrics,
     name: &'static str,
+    redaction: Arc<SpanRedactionConfig>,
 }
 
 impl TracingMetricsCore {
     pub fn new(name: &'static str) -> Result<Self> {
         let metrics = Metrics::new(global::meter(name));
 
-        Ok(Self { metrics, name })
+        let redaction = Arc::new(SpanRedactionConfig::from_env()?);
+
+        Ok(Self {
+            metrics,
+            name,
+            redaction,
+        })
     }
 
     pub fn get_tracer(&self) -> BoxedTracer {
@@ -35,11 +48,14 @@ impl TracingMetricsCore {
         global::get_text_map_propagator(|propagator| {
             propagator.inject_context(cx, &mut MetadataInjector(request.metadata_mut()))
         });
+        inject_request_id(request.metadata_mut());
+        apply_request_deadline(request);
     }
 
     pub fn start_tracing(&self, operation_name: &str, attributes: Vec<KeyValue>) -> TracingContext {
         let start_time = Instant::now();
         let tracer = self.get_tracer();
+        let attributes = self.redaction.apply(operation_name, attributes);
         let mut span = tracer
             .span_builder(operation_name.to_string())
             .with_kind(SpanKind::Server)
@@ -93,13 +109,13 @@ impl TracingMetricsCore {
         } else {
             Status::Error
         };
-        let elapsed_ms = tracing_ctx.start_time.elapsed().as_secs_f64();
+        let elapsed_ms = tracing_ctx.start_time.elapsed().as_secs_f64() * 1000.0;
 
         tracing_ctx.cx.span().add_event(
             "Operation completed",
             vec![
                 KeyValue::new("status", status_str),
-                KeyValue::new("duration_secs", elapsed_ms),
+                KeyValue::new("duration_ms", elapsed_ms),
                 KeyValue::new("message", message.to_string()),
             ],
         );
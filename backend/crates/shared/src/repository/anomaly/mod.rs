@@ -0,0 +1,95 @@
+use crate::{
+    abstract_trait::anomaly::repository::AnomalyRepositoryTrait,
+    config::ConnectionPool,
+    domain::requests::anomaly::{AnomalyMetric, AnomalyScope},
+    errors::RepositoryError,
+    model::anomaly::DailyMetricModel,
+    observability::acquire_connection,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use sqlx::Row;
+use tracing::error;
+
+pub struct AnomalyRepository {
+    db: ConnectionPool,
+}
+
+impl AnomalyRepository {
+    pub fn new(db: ConnectionPool) -> Self {
+        Self { db }
+    }
+
+    async fn get_conn(
+        &self,
+    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
+        acquire_connection(&self.db).await.map_err(|e| {
+            error!("❌ Failed to acquire DB connection: {e:?}");
+            RepositoryError::from(e)
+        })
+    }
+}
+
+#[async_trait]
+impl AnomalyRepositoryTrait for AnomalyRepository {
+    async fn get_daily_series(
+        &self,
+        scope: AnomalyScope,
+        metric: AnomalyMetric,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<DailyMetricModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let (table, time_column, amount_column) = scope.source();
+        let aggregate = match metric {
+            AnomalyMetric::TotalAmount => format!("COALESCE(SUM(t.{amount_column}), 0)::bigint"),
+            AnomalyMetric::Count => "COUNT(t.*)::bigint".to_string(),
+        };
+
+        let sql = format!(
+            r#"
+            WITH days AS (
+                SELECT generate_series($1::date, $2::date, interval '1 day')::date AS day
+            )
+            SELECT
+                d.day AS day,
+                {aggregate} AS value
+            FROM days d
+            LEFT JOIN {table} t
+                ON date_trunc('day', t.{time_column})::date = d.day
+                AND t.deleted_at IS NULL
+                AND t.status = 'success'
+            GROUP BY d.day
+            ORDER BY d.day;
+            "#
+        );
+
+        let rows = sqlx::query(&sql)
+            .bind(start)
+            .bind(end)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Database error in get_daily_series ({scope}/{metric}): {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        let data = rows
+            .into_iter()
+            .map(|row| {
+                Ok(DailyMetricModel {
+                    day: row.try_get("day")?,
+                    value: row.try_get("value")?,
+                })
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| {
+                error!("Failed to map daily metric rows: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        Ok(data)
+    }
+}
@@ -0,0 +1,161 @@
+use crate::{
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
+    errors::RepositoryError,
+    model::bulk::{BulkItemErrorModel, BulkResultModel},
+};
+use chrono::NaiveDateTime;
+use sqlx::{PgConnection, Row};
+use tracing::{error, warn};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BulkOperation {
+    Restore,
+    DeletePermanent,
+}
+
+impl BulkOperation {
+    fn statement(&self, table: &str) -> String {
+        match self {
+            BulkOperation::Restore => {
+                format!("UPDATE {table} SET deleted_at = NULL WHERE {TRASHED_FILTER}")
+            }
+            BulkOperation::DeletePermanent => {
+                format!("DELETE FROM {table} WHERE {TRASHED_FILTER}")
+            }
+        }
+    }
+}
+
+const TRASHED_FILTER: &str =
+    "deleted_at IS NOT NULL AND ($1::TIMESTAMP IS NULL OR deleted_at < $1::TIMESTAMP)";
+
+pub async fn apply_to_trashed(
+    db: &ConnectionPool,
+    table: &str,
+    id_column: &str,
+    operation: BulkOperation,
+) -> Result<BulkResultModel, RepositoryError> {
+    apply_to_trashed_before(db, table, id_column, operation, None).await
+}
+
+pub async fn purge_trashed_before(
+    db: &ConnectionPool,
+    table: &str,
+    id_column: &str,
+    cutoff: NaiveDateTime,
+) -> Result<BulkResultModel, RepositoryError> {
+    apply_to_trashed_before(
+        db,
+        table,
+        id_column,
+        BulkOperation::DeletePermanent,
+        Some(cutoff),
+    )
+    .await
+}
+
+async fn apply_to_trashed_before(
+    db: &ConnectionPool,
+    table: &str,
+    id_column: &str,
+    operation: BulkOperation,
+    cutoff: Option<NaiveDateTime>,
+) -> Result<BulkResultModel, RepositoryError> {
+    let mut tx = begin_with_statement_timeout(db, QueryCategory::Oltp).await?;
+
+    let ids: Vec<i32> = sqlx::query(&format!(
+        "SELECT {id_column} FROM {table} WHERE {TRASHED_FILTER} ORDER BY {id_column} FOR UPDATE"
+    ))
+    .bind(cutoff)
+    .fetch_all(&mut *tx)
+    .await
+    .map_err(|e| {
+        error!("❌ Failed to lock trashed {table}: {e:?}");
+        RepositoryError::Sqlx(e)
+    })?
+    .into_iter()
+    .map(|row| row.try_get(0))
+    .collect::<Result<_, sqlx::Error>>()?;
+
+    let mut result = BulkResultModel {
+        total: ids.len() as i64,
+        ..Default::default()
+    };
+
+    if ids.is_empty() {
+        tx.commit().await?;
+        return Ok(result);
+    }
+
+    savepoint(&mut tx, "SAVEPOINT bulk_all").await?;
+
+    match sqlx::query(&operation.statement(table))
+        .bind(cutoff)
+        .execute(&mut *tx)
+        .await
+    {
+        Ok(done) => {
+            savepoint(&mut tx, "RELEASE SAVEPOINT bulk_all").await?;
+            result.affected = done.rows_affected() as i64;
+        }
+        Err(e) => {
+            warn!("⚠️  {operation:?} of all trashed {table} failed, retrying row by row: {e}");
+            savepoint(&mut tx, "ROLLBACK TO SAVEPOINT bulk_all").await?;
+
+            let statement = format!("{} AND {id_column} = $2", operation.statement(table));
+
+            for id in ids {
+                savepoint(&mut tx, "SAVEPOINT bulk_item").await?;
+
+                match sqlx::query(&statement)
+                    .bind(cutoff)
+                    .bind(id)
+                    .execute(&mut *tx)
+                    .await
+                {
+                    Ok(done) => {
+                        savepoint(&mut tx, "RELEASE SAVEPOINT bulk_item").await?;
+                        result.affected += done.rows_affected() as i64;
+                    }
+                    Err(e) => {
+                        savepoint(&mut tx, "ROLLBACK TO SAVEPOINT bulk_item").await?;
+                        result.errors.push(BulkItemErrorModel {
+                            id,
+                            reason: failure_reason(&e),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    result.skipped = result.total - result.affected - result.errors.len() as i64;
+
+    tx.commit().await.map_err(|e| {
+        error!("❌ Failed to commit {operation:?} of trashed {table}: {e:?}");
+        RepositoryError::Sqlx(e)
+    })?;
+
+    Ok(result)
+}
+
+async fn savepoint(conn: &mut PgConnection, statement: &str) -> Result<(), RepositoryError> {
+    sqlx::query(statement).execute(conn).await.map_err(|e| {
+        error!("❌ Failed to run `{statement}`: {e:?}");
+        RepositoryError::Sqlx(e)
+    })?;
+    Ok(())
+}
+
+fn failure_reason(err: &sqlx::Error) -> String {
+    match err.as_database_error() {
+        Some(db_err) if db_err.is_foreign_key_violation() => {
+            "still referenced by other records".to_string()
+        }
+        Some(db_err) if db_err.is_unique_violation() => {
+            "conflicts with an active record".to_string()
+        }
+        Some(db_err) => db_err.message().to_string(),
+        None => err.to_string(),
+    }
+}
@@ -1,28 +1,62 @@
 use crate::{
     abstract_trait::card::repository::command::CardCommandRepositoryTrait,
-    config::ConnectionPool,
-    domain::requests::card::{CreateCardRequest, UpdateCardRequest},
+    config::{ConnectionPool, QueryCategory, set_statement_timeout},
+    domain::requests::card::{CreateCardRequest, RestoreCardsByFilter, UpdateCardRequest},
     errors::RepositoryError,
-    model::card::CardModel,
-    utils::random_card_number,
+    model::bulk::BulkResultModel,
+    model::card::{CardFreezeModel, CardModel, CardReissueModel},
+    observability::{acquire_connection, begin_transaction, log_query_params},
+    repository::bulk::{BulkOperation, apply_to_trashed},
+    utils::{random_card_number, random_cvv},
 };
 use anyhow::Result;
 use async_trait::async_trait;
-use tracing::error;
+use chrono::NaiveDate;
+use sqlx::Row;
+use tracing::{error, info};
 
 pub struct CardCommandRepository {
     db: ConnectionPool,
 }
 
+const REISSUE_CARD_NUMBER_ATTEMPTS: usize = 5;
+const LEDGER_REFERENCE_CARD_REISSUE: &str = "card_reissue";
+
 impl CardCommandRepository {
     pub fn new(db: ConnectionPool) -> Self {
         Self { db }
     }
 
+    async fn unused_card_number(conn: &mut sqlx::PgConnection) -> Result<String, RepositoryError> {
+        for _ in 0..REISSUE_CARD_NUMBER_ATTEMPTS {
+            let card_number = random_card_number().map_err(|_| {
+                RepositoryError::Custom("❌ error ketika gen card_number".to_string())
+            })?;
+
+            let exists: bool =
+                sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM cards WHERE card_number = $1)")
+                    .bind(&card_number)
+                    .fetch_one(&mut *conn)
+                    .await
+                    .map_err(|e| {
+                        error!("❌ Failed to check card number uniqueness: {e:?}");
+                        RepositoryError::Sqlx(e)
+                    })?;
+
+            if !exists {
+                return Ok(card_number);
+            }
+        }
+
+        Err(RepositoryError::Conflict(
+            "could not generate a unique card number".to_string(),
+        ))
+    }
+
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -34,6 +68,17 @@ impl CardCommandRepositoryTrait for CardCommandRepository {
     async fn create(&self, request: &CreateCardRequest) -> Result<CardModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
+        log_query_params(
+            "insert_card",
+            &[
+                ("user_id", &request.user_id),
+                ("card_type", &request.card_type),
+                ("expire_date", &request.expire_date),
+                ("cvv", &request.cvv),
+                ("card_provider", &request.card_provider),
+            ],
+        );
+
         let card_number = random_card_number()
             .map_err(|_| RepositoryError::Custom("❌ error ketika gen card_number".to_string()))?;
 
@@ -83,6 +128,18 @@ impl CardCommandRepositoryTrait for CardCommandRepository {
     async fn update(&self, request: &UpdateCardRequest) -> Result<CardModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
+        log_query_params(
+            "update_card",
+            &[
+                ("card_id", &request.card_id),
+                ("user_id", &request.user_id),
+                ("card_type", &request.card_type),
+                ("expire_date", &request.expire_date),
+                ("cvv", &request.cvv),
+                ("card_provider", &request.card_provider),
+            ],
+        );
+
         let card_id = request
             .card_id
             .ok_or_else(|| RepositoryError::Custom("card_id is required".into()))?;
@@ -214,42 +271,270 @@ impl CardCommandRepositoryTrait for CardCommandRepository {
         Ok(result.rows_affected() > 0)
     }
 
-    async fn restore_all(&self) -> Result<bool, RepositoryError> {
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(&self.db, "cards", "card_id", BulkOperation::Restore).await
+    }
+
+    async fn set_frozen(&self, id: i32, frozen: bool) -> Result<CardFreezeModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
-        let result = sqlx::query!(
+        sqlx::query_as::<_, CardFreezeModel>(
             r#"
             UPDATE cards
-            SET deleted_at = NULL
-            WHERE deleted_at IS NOT NULL
-            "#
+            SET
+                frozen_at = CASE WHEN $2 THEN COALESCE(frozen_at, CURRENT_TIMESTAMP) END,
+                updated_at = CURRENT_TIMESTAMP
+            WHERE card_id = $1 AND deleted_at IS NULL
+            RETURNING card_id, user_id, card_number, frozen_at
+            "#,
         )
-        .execute(&mut *conn)
+        .bind(id)
+        .bind(frozen)
+        .fetch_optional(&mut *conn)
         .await
         .map_err(|e| {
-            error!("❌ Failed to restore all merchant: {e:?}");
+            error!("❌ Failed to update frozen state of card {id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn reissue(
+        &self,
+        id: i32,
+        expire_date: NaiveDate,
+    ) -> Result<CardReissueModel, RepositoryError> {
+        let mut tx = begin_transaction(&self.db).await.map_err(|e| {
+            error!("❌ Failed to begin reissue transaction: {e:?}");
             RepositoryError::Sqlx(e)
         })?;
 
-        Ok(result.rows_affected() > 0)
-    }
+        set_statement_timeout(&mut tx, QueryCategory::Oltp).await?;
 
-    async fn delete_all(&self) -> Result<bool, RepositoryError> {
-        let mut conn = self.get_conn().await?;
+        let previous = sqlx::query_as::<_, CardModel>(
+            r#"
+            SELECT
+                card_id,
+                user_id,
+                card_number,
+                card_type,
+                expire_date,
+                cvv,
+                card_provider,
+                created_at,
+                updated_at,
+                deleted_at
+            FROM cards
+            WHERE card_id = $1
+              AND deleted_at IS NULL
+            FOR UPDATE
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to lock card {id} for reissue: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?
+        .ok_or(RepositoryError::NotFound)?;
 
-        let result = sqlx::query!(
+        let card_number = Self::unused_card_number(&mut tx).await?;
+
+        let card = sqlx::query_as::<_, CardModel>(
             r#"
-            DELETE FROM cards
-            WHERE deleted_at IS NOT NULL
-            "#
+            INSERT INTO cards (
+                user_id,
+                card_number,
+                card_type,
+                expire_date,
+                cvv,
+                card_provider,
+                reissued_from_card_id,
+                created_at,
+                updated_at
+            )
+            VALUES ($1, $2, $3, $4, $5, $6, $7, NOW(), NOW())
+            RETURNING
+                card_id,
+                user_id,
+                card_number,
+                card_type,
+                expire_date,
+                cvv,
+                card_provider,
+                created_at,
+                updated_at,
+                deleted_at
+            "#,
         )
-        .execute(&mut *conn)
+        .bind(previous.user_id)
+        .bind(&card_number)
+        .bind(&previous.card_type)
+        .bind(expire_date)
+        .bind(random_cvv())
+        .bind(&previous.card_provider)
+        .bind(previous.card_id)
+        .fetch_one(&mut *tx)
         .await
         .map_err(|e| {
-            error!("❌ Failed to delete all merchant: {e:?}");
+            error!("❌ Failed to insert reissued card for {id}: {e:?}");
             RepositoryError::Sqlx(e)
         })?;
 
-        Ok(result.rows_affected() > 0)
+        let moved = sqlx::query_scalar::<_, i64>(
+            r#"
+            UPDATE saldos
+            SET card_number = $2,
+                updated_at = current_timestamp
+            WHERE card_number = $1
+              AND deleted_at IS NULL
+            RETURNING total_balance::BIGINT
+            "#,
+        )
+        .bind(&previous.card_number)
+        .bind(&card.card_number)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to move saldo to reissued card {id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        // Close the old card's ledger and open the new one with the moved
+        // balance, so statements on both numbers still add up.
+        if let Some(balance) = moved.filter(|balance| *balance != 0) {
+            sqlx::query(
+                r#"
+                INSERT INTO saldo_ledger (
+                    card_number,
+                    amount,
+                    balance_after,
+                    reference_type,
+                    reference_id
+                )
+                VALUES ($1, -$3::BIGINT, 0, $4, $5),
+                       ($2, $3::BIGINT, $3::BIGINT, $4, $5)
+                "#,
+            )
+            .bind(&previous.card_number)
+            .bind(&card.card_number)
+            .bind(balance)
+            .bind(LEDGER_REFERENCE_CARD_REISSUE)
+            .bind(card.card_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                error!("❌ Failed to record reissue ledger entries for card {id}: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+        }
+
+        let previous_card = sqlx::query_as::<_, CardModel>(
+            r#"
+            UPDATE cards
+            SET deleted_at = current_timestamp,
+                updated_at = current_timestamp
+            WHERE card_id = $1
+            RETURNING
+                card_id,
+                user_id,
+                card_number,
+                card_type,
+                expire_date,
+                cvv,
+                card_provider,
+                created_at,
+                updated_at,
+                deleted_at
+            "#,
+        )
+        .bind(previous.card_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to deactivate card {id} after reissue: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            error!("❌ Failed to commit reissue transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        info!(
+            "✅ Reissued card {} as {} (saldo moved: {})",
+            previous_card.card_id,
+            card.card_id,
+            moved.is_some()
+        );
+
+        Ok(CardReissueModel {
+            previous_card,
+            card,
+        })
+    }
+
+    async fn restore_by_filter(
+        &self,
+        filter: &RestoreCardsByFilter,
+    ) -> Result<Vec<i32>, RepositoryError> {
+        let mut tx = begin_transaction(&self.db).await.map_err(|e| {
+            error!("❌ Failed to begin restore-by-filter transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        set_statement_timeout(&mut tx, QueryCategory::Oltp).await?;
+
+        let search_pattern = if filter.search.trim().is_empty() {
+            None
+        } else {
+            Some(filter.search.as_str())
+        };
+
+        let sql = r#"
+            UPDATE cards c
+            SET deleted_at = NULL,
+                updated_at = current_timestamp
+            WHERE c.deleted_at IS NOT NULL
+              AND ($1::TEXT IS NULL OR
+                   c.card_number ILIKE '%' || $1 || '%' OR
+                   c.card_type ILIKE '%' || $1 || '%' OR
+                   c.card_provider ILIKE '%' || $1 || '%')
+              AND ($2::INT IS NULL OR c.user_id = $2)
+              AND ($3::DATE IS NULL OR c.deleted_at >= $3::DATE)
+              AND ($4::DATE IS NULL OR c.deleted_at < $4::DATE + INTERVAL '1 day')
+            RETURNING c.card_id
+        "#;
+
+        let rows = sqlx::query(sql)
+            .bind(search_pattern)
+            .bind(filter.user_id)
+            .bind(filter.deleted_from)
+            .bind(filter.deleted_to)
+            .fetch_all(&mut *tx)
+            .await
+            .map_err(|e| {
+                error!("❌ Failed to restore cards by filter: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        let mut restored_ids = Vec::with_capacity(rows.len());
+        for row in rows {
+            restored_ids.push(row.try_get::<i32, _>("card_id")?);
+        }
+
+        tx.commit().await.map_err(|e| {
+            error!("❌ Failed to commit restore-by-filter transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        info!("✅ Restored {} cards by filter", restored_ids.len());
+
+        Ok(restored_ids)
+    }
+
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(&self.db, "cards", "card_id", BulkOperation::DeletePermanent).await
     }
 }
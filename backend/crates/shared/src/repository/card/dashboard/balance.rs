@@ -1,6 +1,7 @@
 use crate::{
     abstract_trait::card::repository::dashboard::balance::CardDashboardBalanceRepositoryTrait,
-    config::ConnectionPool, errors::RepositoryError,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
+    errors::RepositoryError,
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -17,11 +18,8 @@ impl CardDashboardBalanceRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
@@ -71,4 +69,30 @@ impl CardDashboardBalanceRepositoryTrait for CardDashboardBalanceRepository {
 
         Ok(total.unwrap_or(0))
     }
+
+    async fn get_total_balance_by_cards(
+        &self,
+        card_numbers: &[String],
+    ) -> Result<Vec<(String, i64)>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT c.card_number, COALESCE(SUM(s.total_balance), 0)::BIGINT
+            FROM saldos s
+            JOIN cards c ON s.card_number = c.card_number
+            WHERE s.deleted_at IS NULL
+              AND c.deleted_at IS NULL
+              AND c.card_number = ANY($1)
+            GROUP BY c.card_number
+            "#,
+        )
+        .bind(card_numbers)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Database error in get_total_balance_by_cards: {e:?}");
+            RepositoryError::Sqlx(e)
+        })
+    }
 }
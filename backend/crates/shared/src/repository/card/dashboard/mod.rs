@@ -1,4 +1,5 @@
 pub mod balance;
+pub mod system;
 pub mod topup;
 pub mod transaction;
 pub mod transfer;
@@ -0,0 +1,137 @@
+use crate::{
+    abstract_trait::card::repository::dashboard::system::CardDashboardSystemRepositoryTrait,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
+    domain::requests::card::TopCardMetric,
+    errors::RepositoryError,
+    model::card::{SystemStatsModel, TopCardModel},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::NaiveDate;
+use tracing::error;
+
+pub struct CardDashboardSystemRepository {
+    db: ConnectionPool,
+}
+
+impl CardDashboardSystemRepository {
+    pub fn new(db: ConnectionPool) -> Self {
+        Self { db }
+    }
+
+    async fn get_conn(
+        &self,
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
+    }
+}
+
+#[async_trait]
+impl CardDashboardSystemRepositoryTrait for CardDashboardSystemRepository {
+    async fn get_system_stats(&self, day: NaiveDate) -> Result<SystemStatsModel, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let stats = sqlx::query_as::<_, SystemStatsModel>(
+            r#"
+            SELECT
+                (SELECT COUNT(*) FROM users WHERE deleted_at IS NULL) AS total_users,
+                (SELECT COUNT(*) FROM cards WHERE deleted_at IS NULL) AS total_cards,
+                (
+                    SELECT COALESCE(SUM(s.total_balance), 0)::BIGINT
+                    FROM saldos s
+                    JOIN cards c ON s.card_number = c.card_number
+                    WHERE s.deleted_at IS NULL AND c.deleted_at IS NULL
+                ) AS total_balance,
+                today.today_transaction_count,
+                today.today_transaction_volume
+            FROM (
+                SELECT
+                    COUNT(*) AS today_transaction_count,
+                    COALESCE(SUM(amount), 0)::BIGINT AS today_transaction_volume
+                FROM transactions
+                WHERE deleted_at IS NULL
+                  AND status = 'success'
+                  AND transaction_time >= $1::DATE
+                  AND transaction_time < $1::DATE + INTERVAL '1 day'
+            ) today
+            "#,
+        )
+        .bind(day)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Database error in get_system_stats: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        Ok(stats)
+    }
+
+    async fn get_top_cards(
+        &self,
+        metric: TopCardMetric,
+        limit: i32,
+        start: NaiveDate,
+        end: NaiveDate,
+    ) -> Result<Vec<TopCardModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let sql = match metric {
+            TopCardMetric::Balance => {
+                r#"
+                SELECT c.card_number, c.user_id, s.total_balance::BIGINT AS value
+                FROM saldos s
+                JOIN cards c ON s.card_number = c.card_number
+                WHERE s.deleted_at IS NULL AND c.deleted_at IS NULL
+                ORDER BY s.total_balance DESC, s.card_number ASC
+                LIMIT $1
+                "#
+            }
+            TopCardMetric::TransactionCount => {
+                r#"
+                SELECT t.card_number, c.user_id, COUNT(*)::BIGINT AS value
+                FROM transactions t
+                JOIN cards c ON t.card_number = c.card_number
+                WHERE t.deleted_at IS NULL
+                  AND c.deleted_at IS NULL
+                  AND t.status = 'success'
+                  AND t.transaction_time >= $2::DATE
+                  AND t.transaction_time < $3::DATE + INTERVAL '1 day'
+                GROUP BY t.card_number, c.user_id
+                ORDER BY value DESC, t.card_number ASC
+                LIMIT $1
+                "#
+            }
+            TopCardMetric::TransactionVolume => {
+                r#"
+                SELECT t.card_number, c.user_id, COALESCE(SUM(t.amount), 0)::BIGINT AS value
+                FROM transactions t
+                JOIN cards c ON t.card_number = c.card_number
+                WHERE t.deleted_at IS NULL
+                  AND c.deleted_at IS NULL
+                  AND t.status = 'success'
+                  AND t.transaction_time >= $2::DATE
+                  AND t.transaction_time < $3::DATE + INTERVAL '1 day'
+                GROUP BY t.card_number, c.user_id
+                ORDER BY value DESC, t.card_number ASC
+                LIMIT $1
+                "#
+            }
+        };
+
+        let query = sqlx::query_as::<_, TopCardModel>(sql).bind(limit as i64);
+        let query = match metric {
+            TopCardMetric::Balance => query,
+            TopCardMetric::TransactionCount | TopCardMetric::TransactionVolume => {
+                query.bind(start).bind(end)
+            }
+        };
+
+        let cards = query.fetch_all(&mut *conn).await.map_err(|e| {
+            error!("❌ Database error in get_top_cards: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        Ok(cards)
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{
     abstract_trait::card::repository::dashboard::transfer::CardDashboardTransferRepositoryTrait,
-    config::ConnectionPool, errors::RepositoryError,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
+    errors::RepositoryError,
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -17,11 +18,8 @@ impl CardDashboardTransferRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
@@ -96,4 +94,52 @@ impl CardDashboardTransferRepositoryTrait for CardDashboardTransferRepository {
 
         Ok(total)
     }
+
+    async fn get_total_amount_by_senders(
+        &self,
+        card_numbers: &[String],
+    ) -> Result<Vec<(String, i64)>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT transfer_from, COALESCE(SUM(transfer_amount), 0)::BIGINT
+            FROM transfers
+            WHERE transfer_from = ANY($1)
+              AND deleted_at IS NULL
+            GROUP BY transfer_from
+            "#,
+        )
+        .bind(card_numbers)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Database error in get_total_amount_by_senders: {e:?}");
+            RepositoryError::Sqlx(e)
+        })
+    }
+
+    async fn get_total_amount_by_receivers(
+        &self,
+        card_numbers: &[String],
+    ) -> Result<Vec<(String, i64)>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        sqlx::query_as::<_, (String, i64)>(
+            r#"
+            SELECT transfer_to, COALESCE(SUM(transfer_amount), 0)::BIGINT
+            FROM transfers
+            WHERE transfer_to = ANY($1)
+              AND deleted_at IS NULL
+            GROUP BY transfer_to
+            "#,
+        )
+        .bind(card_numbers)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Database error in get_total_amount_by_receivers: {e:?}");
+            RepositoryError::Sqlx(e)
+        })
+    }
 }
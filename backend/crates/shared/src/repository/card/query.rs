@@ -1,9 +1,15 @@
 use crate::{
-    abstract_trait::card::repository::query::CardQueryRepositoryTrait, config::ConnectionPool,
-    domain::requests::card::FindAllCards, errors::RepositoryError, model::card::CardModel,
+    abstract_trait::card::repository::query::CardQueryRepositoryTrait,
+    config::ConnectionPool,
+    domain::requests::{card::FindAllCards, trashed::TrashedSort},
+    errors::RepositoryError,
+    model::card::{CardModel, CardStatusModel},
+    observability::acquire_connection,
+    utils::mask_card_number,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use sqlx::Row;
 use tracing::error;
 
 pub struct CardQueryRepository {
@@ -18,7 +24,7 @@ impl CardQueryRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -179,7 +185,11 @@ impl CardQueryRepositoryTrait for CardQueryRepository {
             Some(req.search.as_str())
         };
 
-        let rows = sqlx::query!(
+        let order_by = TrashedSort::parse(req.sort_by.as_deref())
+            .unwrap_or_default()
+            .order_by("c.deleted_at", "c.card_id");
+
+        let sql = format!(
             r#"
             SELECT
                 c.card_id,
@@ -199,37 +209,48 @@ impl CardQueryRepositoryTrait for CardQueryRepository {
                    c.card_number ILIKE '%' || $1 || '%' OR 
                    c.card_type ILIKE '%' || $1 || '%' OR 
                    c.card_provider ILIKE '%' || $1 || '%')
-            ORDER BY c.card_id
+            ORDER BY {order_by}
             LIMIT $2 OFFSET $3
-            "#,
-            search_pattern,
-            limit as i64,
-            offset as i64
-        )
-        .fetch_all(&mut *conn)
-        .await
-        .map_err(|e| {
-            error!("❌ Failed to fetch trashed cards: {e:?}");
-            RepositoryError::Sqlx(e)
-        })?;
-
-        let total = rows.first().and_then(|r| r.total_count).unwrap_or(0);
+            "#
+        );
+
+        let rows = sqlx::query(&sql)
+            .bind(search_pattern)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Failed to fetch trashed cards: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        let total = rows
+            .first()
+            .and_then(|r| r.try_get::<i64, _>("total_count").ok())
+            .unwrap_or(0);
 
         let cards = rows
             .into_iter()
-            .map(|r| CardModel {
-                card_id: r.card_id,
-                user_id: r.user_id,
-                card_number: r.card_number,
-                card_type: r.card_type,
-                expire_date: r.expire_date,
-                cvv: r.cvv,
-                card_provider: r.card_provider,
-                created_at: r.created_at,
-                updated_at: r.updated_at,
-                deleted_at: r.deleted_at,
+            .map(|row| {
+                Ok(CardModel {
+                    card_id: row.try_get("card_id")?,
+                    user_id: row.try_get("user_id")?,
+                    card_number: row.try_get("card_number")?,
+                    card_type: row.try_get("card_type")?,
+                    expire_date: row.try_get("expire_date")?,
+                    cvv: row.try_get("cvv")?,
+                    card_provider: row.try_get("card_provider")?,
+                    created_at: row.try_get("created_at")?,
+                    updated_at: row.try_get("updated_at")?,
+                    deleted_at: row.try_get("deleted_at")?,
+                })
             })
-            .collect();
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| {
+                error!("Failed to map card rows: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
 
         Ok((cards, total))
     }
@@ -367,4 +388,92 @@ impl CardQueryRepositoryTrait for CardQueryRepository {
             deleted_at: row.deleted_at,
         })
     }
+
+    async fn find_all_by_user_id(&self, user_id: i32) -> Result<Vec<CardModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        sqlx::query_as::<_, CardModel>(
+            r#"
+            SELECT
+                c.card_id,
+                c.user_id,
+                c.card_number,
+                c.card_type,
+                c.expire_date,
+                c.cvv,
+                c.card_provider,
+                c.created_at,
+                c.updated_at,
+                c.deleted_at
+            FROM cards c
+            WHERE c.user_id = $1 AND c.deleted_at IS NULL
+            ORDER BY c.card_id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch cards by user_id {user_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })
+    }
+
+    async fn find_status_by_card(
+        &self,
+        card_number: &str,
+    ) -> Result<Option<CardStatusModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        sqlx::query_as::<_, CardStatusModel>(
+            r#"
+            SELECT card_provider, expire_date, deleted_at, frozen_at
+            FROM cards
+            WHERE card_number = $1
+            ORDER BY deleted_at NULLS FIRST
+            LIMIT 1
+            "#,
+        )
+        .bind(card_number)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!(
+                "❌ Failed to fetch card status for {}: {e:?}",
+                mask_card_number(card_number)
+            );
+            RepositoryError::Sqlx(e)
+        })
+    }
+
+    async fn find_merchant_id_by_card(
+        &self,
+        card_number: &str,
+    ) -> Result<Option<i32>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        // A card belongs to a user; when the owner runs several merchants the
+        // oldest one is treated as the card's merchant.
+        sqlx::query_scalar::<_, i32>(
+            r#"
+            SELECT m.merchant_id
+            FROM cards c
+            JOIN merchants m ON m.user_id = c.user_id AND m.deleted_at IS NULL
+            WHERE c.card_number = $1
+              AND c.deleted_at IS NULL
+            ORDER BY m.merchant_id
+            LIMIT 1
+            "#,
+        )
+        .bind(card_number)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!(
+                "❌ Failed to fetch merchant for card {}: {e:?}",
+                mask_card_number(card_number)
+            );
+            RepositoryError::Sqlx(e)
+        })
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::card::repository::stats::transfer::CardStatsTransferRepositoryTrait,
-    config::ConnectionPool,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
     errors::RepositoryError,
     model::card::{CardMonthAmount, CardYearAmount},
 };
@@ -20,11 +20,8 @@ impl CardStatsTransferRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
@@ -0,0 +1,215 @@
+use crate::{
+    abstract_trait::email_verification::EmailVerificationRepositoryTrait,
+    config::{ConnectionPool, QueryCategory, set_statement_timeout},
+    errors::RepositoryError,
+    model::email_verification::EmailVerificationTokenModel,
+    observability::{acquire_connection, begin_transaction},
+};
+use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::Row;
+use tracing::{error, info};
+
+pub struct EmailVerificationRepository {
+    db: ConnectionPool,
+}
+
+impl EmailVerificationRepository {
+    pub fn new(db: ConnectionPool) -> Self {
+        Self { db }
+    }
+
+    async fn get_conn(
+        &self,
+    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
+        acquire_connection(&self.db).await.map_err(|e| {
+            error!("❌ Failed to acquire DB connection: {e:?}");
+            RepositoryError::from(e)
+        })
+    }
+}
+
+#[async_trait]
+impl EmailVerificationRepositoryTrait for EmailVerificationRepository {
+    async fn create_token(
+        &self,
+        user_id: i32,
+        token_hash: &str,
+        expires_at: NaiveDateTime,
+    ) -> Result<(), RepositoryError> {
+        let mut tx = begin_transaction(&self.db).await.map_err(|e| {
+            error!("❌ Failed to begin verification token transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        set_statement_timeout(&mut tx, QueryCategory::Oltp).await?;
+
+        sqlx::query(
+            r#"
+            UPDATE email_verification_tokens
+            SET used_at = current_timestamp
+            WHERE user_id = $1 AND used_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to invalidate previous verification tokens: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        sqlx::query(
+            r#"
+            INSERT INTO email_verification_tokens (user_id, token_hash, expires_at)
+            VALUES ($1, $2, $3)
+            "#,
+        )
+        .bind(user_id)
+        .bind(token_hash)
+        .bind(expires_at)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to create verification token: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            error!("❌ Failed to commit verification token transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        info!("✅ Verification token created for user_id={user_id}");
+
+        Ok(())
+    }
+
+    async fn find_by_token_hash(
+        &self,
+        token_hash: &str,
+    ) -> Result<Option<EmailVerificationTokenModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let token = sqlx::query_as::<_, EmailVerificationTokenModel>(
+            r#"
+            SELECT
+                verification_token_id,
+                user_id,
+                token_hash,
+                expires_at,
+                used_at,
+                created_at
+            FROM email_verification_tokens
+            WHERE token_hash = $1
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch verification token: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        Ok(token)
+    }
+
+    async fn consume_token(&self, token_hash: &str) -> Result<Option<i32>, RepositoryError> {
+        let mut tx = begin_transaction(&self.db).await.map_err(|e| {
+            error!("❌ Failed to begin verification transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        set_statement_timeout(&mut tx, QueryCategory::Oltp).await?;
+
+        let row = sqlx::query(
+            r#"
+            UPDATE email_verification_tokens
+            SET used_at = current_timestamp
+            WHERE token_hash = $1
+              AND used_at IS NULL
+              AND expires_at > current_timestamp
+            RETURNING user_id
+            "#,
+        )
+        .bind(token_hash)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to consume verification token: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        let Some(row) = row else {
+            return Ok(None);
+        };
+
+        let user_id: i32 = row.try_get("user_id")?;
+
+        sqlx::query(
+            r#"
+            UPDATE users
+            SET email_verified = TRUE,
+                updated_at = current_timestamp
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to mark user {user_id} as verified: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            error!("❌ Failed to commit verification transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        Ok(Some(user_id))
+    }
+
+    async fn is_verified(&self, user_id: i32) -> Result<bool, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let row = sqlx::query("SELECT email_verified FROM users WHERE user_id = $1")
+            .bind(user_id)
+            .fetch_optional(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Failed to fetch verification status: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        match row {
+            Some(row) => Ok(row.try_get("email_verified")?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+
+    async fn latest_token_created_at(
+        &self,
+        user_id: i32,
+    ) -> Result<Option<NaiveDateTime>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let row = sqlx::query(
+            r#"
+            SELECT MAX(created_at) AS created_at
+            FROM email_verification_tokens
+            WHERE user_id = $1
+            "#,
+        )
+        .bind(user_id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch latest verification token: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        Ok(row.try_get("created_at")?)
+    }
+}
@@ -2,10 +2,13 @@ use crate::{
     abstract_trait::merchant::repository::command::MerchantCommandRepositoryTrait,
     config::ConnectionPool,
     domain::requests::merchant::{
-        CreateMerchantRequest, UpdateMerchantRequest, UpdateMerchantStatus,
+        CreateMerchantRequest, MerchantUserRequest, UpdateMerchantRequest, UpdateMerchantStatus,
     },
     errors::RepositoryError,
-    model::merchant::MerchantModel,
+    model::bulk::BulkResultModel,
+    model::merchant::{MerchantModel, MerchantWebhookModel},
+    observability::acquire_connection,
+    repository::bulk::{BulkOperation, apply_to_trashed},
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -23,7 +26,7 @@ impl MerchantCommandRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -36,21 +39,22 @@ impl MerchantCommandRepositoryTrait for MerchantCommandRepository {
         &self,
         api_key: String,
         request: &CreateMerchantRequest,
-    ) -> Result<MerchantModel, RepositoryError> {
+    ) -> Result<(MerchantModel, bool), RepositoryError> {
         let mut conn = self.get_conn().await?;
 
-        let merchant = sqlx::query_as!(
-            MerchantModel,
+        let inserted = sqlx::query_as::<_, MerchantModel>(
             r#"
             INSERT INTO merchants (
                 name,
                 api_key,
                 user_id,
                 status,
+                business_id,
                 created_at,
                 updated_at
             )
-            VALUES ($1, $2, $3, $4, NOW(), NOW())
+            VALUES ($1, $2, $3, $4, $5, NOW(), NOW())
+            ON CONFLICT (business_id) DO NOTHING
             RETURNING
                 merchant_id,
                 name,
@@ -61,19 +65,60 @@ impl MerchantCommandRepositoryTrait for MerchantCommandRepository {
                 updated_at,
                 deleted_at
             "#,
-            request.name,
-            api_key,
-            request.user_id,
-            "inactive".to_string(),
         )
-        .fetch_one(&mut *conn)
+        .bind(&request.name)
+        .bind(&api_key)
+        .bind(request.user_id)
+        .bind("inactive")
+        .bind(&request.business_id)
+        .fetch_optional(&mut *conn)
         .await
         .map_err(|e| {
             error!("❌ Failed to create merchant: {e:?}");
             RepositoryError::Sqlx(e)
         })?;
 
-        Ok(merchant)
+        if let Some(merchant) = inserted {
+            return Ok((merchant, true));
+        }
+
+        let business_id = request.business_id.as_deref().unwrap_or_default();
+
+        let existing = sqlx::query_as::<_, MerchantModel>(
+            r#"
+            SELECT
+                merchant_id,
+                name,
+                api_key,
+                user_id,
+                status,
+                created_at,
+                updated_at,
+                deleted_at
+            FROM merchants
+            WHERE business_id = $1
+            "#,
+        )
+        .bind(business_id)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to load merchant for business_id {business_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        if existing.user_id != request.user_id || existing.deleted_at.is_some() {
+            return Err(RepositoryError::Conflict(format!(
+                "business_id {business_id} is already registered to another merchant"
+            )));
+        }
+
+        info!(
+            "♻️ Merchant with business_id {business_id} already exists: id={}",
+            existing.merchant_id
+        );
+
+        Ok((existing, false))
     }
 
     async fn update(
@@ -268,42 +313,114 @@ impl MerchantCommandRepositoryTrait for MerchantCommandRepository {
         Ok(result.rows_affected() > 0)
     }
 
-    async fn restore_all(&self) -> Result<bool, RepositoryError> {
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(&self.db, "merchants", "merchant_id", BulkOperation::Restore).await
+    }
+
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(
+            &self.db,
+            "merchants",
+            "merchant_id",
+            BulkOperation::DeletePermanent,
+        )
+        .await
+    }
+
+    async fn grant_user(&self, request: &MerchantUserRequest) -> Result<(), RepositoryError> {
         let mut conn = self.get_conn().await?;
 
-        let result = sqlx::query!(
+        let granted = sqlx::query_scalar::<_, i32>(
             r#"
-            UPDATE merchants
-            SET deleted_at = NULL
-            WHERE deleted_at IS NOT NULL
-            "#
+            INSERT INTO merchant_users (merchant_id, user_id)
+            SELECT m.merchant_id, u.user_id
+            FROM merchants m
+            JOIN users u ON u.user_id = $2 AND u.deleted_at IS NULL
+            WHERE m.merchant_id = $1 AND m.deleted_at IS NULL
+            ON CONFLICT (merchant_id, user_id) DO UPDATE
+            SET merchant_id = EXCLUDED.merchant_id
+            RETURNING merchant_user_id
+            "#,
         )
-        .execute(&mut *conn)
+        .bind(request.merchant_id)
+        .bind(request.user_id)
+        .fetch_optional(&mut *conn)
         .await
         .map_err(|e| {
-            error!("❌ Failed to restore all trashed merchants: {e:?}");
+            error!(
+                "❌ Failed to grant user {} access to merchant {}: {e:?}",
+                request.user_id, request.merchant_id
+            );
             RepositoryError::Sqlx(e)
         })?;
 
-        Ok(result.rows_affected() > 0)
+        if granted.is_none() {
+            return Err(RepositoryError::NotFound);
+        }
+
+        info!(
+            "✅ User {} granted access to merchant {}",
+            request.user_id, request.merchant_id
+        );
+
+        Ok(())
     }
 
-    async fn delete_all(&self) -> Result<bool, RepositoryError> {
+    async fn revoke_user(&self, request: &MerchantUserRequest) -> Result<bool, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
-        let result = sqlx::query!(
+        let result = sqlx::query(
             r#"
-            DELETE FROM merchants
-            WHERE deleted_at IS NOT NULL
-            "#
+            DELETE FROM merchant_users
+            WHERE merchant_id = $1 AND user_id = $2
+            "#,
         )
+        .bind(request.merchant_id)
+        .bind(request.user_id)
         .execute(&mut *conn)
         .await
         .map_err(|e| {
-            error!("❌ Failed to delete all trashed merchants: {e:?}");
+            error!(
+                "❌ Failed to revoke user {} access to merchant {}: {e:?}",
+                request.user_id, request.merchant_id
+            );
             RepositoryError::Sqlx(e)
         })?;
 
         Ok(result.rows_affected() > 0)
     }
+
+    async fn set_webhook(
+        &self,
+        merchant_id: i32,
+        url: &str,
+    ) -> Result<MerchantWebhookModel, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let webhook = sqlx::query_as::<_, MerchantWebhookModel>(
+            r#"
+            INSERT INTO merchant_webhooks (merchant_id, url)
+            SELECT m.merchant_id, $2
+            FROM merchants m
+            WHERE m.merchant_id = $1 AND m.deleted_at IS NULL
+            ON CONFLICT (merchant_id) DO UPDATE
+            SET url = EXCLUDED.url,
+                updated_at = current_timestamp
+            RETURNING merchant_id, url, created_at, updated_at
+            "#,
+        )
+        .bind(merchant_id)
+        .bind(url)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to set webhook for merchant {merchant_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?
+        .ok_or(RepositoryError::NotFound)?;
+
+        info!("✅ Webhook set for merchant {merchant_id}");
+
+        Ok(webhook)
+    }
 }
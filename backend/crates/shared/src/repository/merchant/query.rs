@@ -1,10 +1,14 @@
 use crate::{
     abstract_trait::merchant::repository::query::MerchantQueryRepositoryTrait,
-    config::ConnectionPool, domain::requests::merchant::FindAllMerchants, errors::RepositoryError,
+    config::ConnectionPool,
+    domain::requests::{merchant::FindAllMerchants, trashed::TrashedSort},
+    errors::RepositoryError,
     model::merchant::MerchantModel,
+    observability::acquire_connection,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use sqlx::Row;
 use tracing::{error, info};
 
 pub struct MerchantQueryRepository {
@@ -19,7 +23,7 @@ impl MerchantQueryRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -175,7 +179,11 @@ impl MerchantQueryRepositoryTrait for MerchantQueryRepository {
             Some(req.search.as_str())
         };
 
-        let rows = sqlx::query!(
+        let order_by = TrashedSort::parse(req.sort_by.as_deref())
+            .unwrap_or_default()
+            .order_by("m.deleted_at", "m.merchant_id");
+
+        let sql = format!(
             r#"
             SELECT
                 m.merchant_id,
@@ -193,35 +201,46 @@ impl MerchantQueryRepositoryTrait for MerchantQueryRepository {
                    m.name ILIKE '%' || $1 || '%' OR 
                    m.api_key ILIKE '%' || $1 || '%' OR 
                    m.status ILIKE '%' || $1 || '%')
-            ORDER BY m.merchant_id
+            ORDER BY {order_by}
             LIMIT $2 OFFSET $3
-            "#,
-            search_pattern,
-            limit as i64,
-            offset as i64
-        )
-        .fetch_all(&mut *conn)
-        .await
-        .map_err(|e| {
-            error!("❌ Failed to fetch trashed merchants: {e:?}");
-            RepositoryError::Sqlx(e)
-        })?;
-
-        let total = rows.first().and_then(|r| r.total_count).unwrap_or(0);
+            "#
+        );
+
+        let rows = sqlx::query(&sql)
+            .bind(search_pattern)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Failed to fetch trashed merchants: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        let total = rows
+            .first()
+            .and_then(|r| r.try_get::<i64, _>("total_count").ok())
+            .unwrap_or(0);
 
         let merchants = rows
             .into_iter()
-            .map(|r| MerchantModel {
-                merchant_id: r.merchant_id,
-                name: r.name,
-                api_key: r.api_key,
-                user_id: r.user_id,
-                status: r.status,
-                created_at: r.created_at,
-                updated_at: r.updated_at,
-                deleted_at: r.deleted_at,
+            .map(|row| {
+                Ok(MerchantModel {
+                    merchant_id: row.try_get("merchant_id")?,
+                    name: row.try_get("name")?,
+                    api_key: row.try_get("api_key")?,
+                    user_id: row.try_get("user_id")?,
+                    status: row.try_get("status")?,
+                    created_at: row.try_get("created_at")?,
+                    updated_at: row.try_get("updated_at")?,
+                    deleted_at: row.try_get("deleted_at")?,
+                })
             })
-            .collect();
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| {
+                error!("Failed to map merchant rows: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
 
         Ok((merchants, total))
     }
@@ -364,4 +383,41 @@ impl MerchantQueryRepositoryTrait for MerchantQueryRepository {
 
         Ok(rows)
     }
+
+    async fn list_accessible_merchants(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<MerchantModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let rows = sqlx::query_as::<_, MerchantModel>(
+            r#"
+            SELECT
+                m.merchant_id,
+                m.name,
+                m.api_key,
+                m.user_id,
+                m.status,
+                m.created_at,
+                m.updated_at,
+                m.deleted_at
+            FROM merchants m
+            WHERE m.deleted_at IS NULL
+              AND (m.user_id = $1 OR EXISTS (
+                  SELECT 1 FROM merchant_users mu
+                  WHERE mu.merchant_id = m.merchant_id AND mu.user_id = $1
+              ))
+            ORDER BY m.merchant_id
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch merchants accessible to user_id {user_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        Ok(rows)
+    }
 }
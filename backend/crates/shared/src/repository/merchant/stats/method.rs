@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::merchant::repository::stats::method::MerchantStatsMethodRepositoryTrait,
-    config::ConnectionPool,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
     errors::RepositoryError,
     model::merchant::{MerchantMonthlyPaymentMethod, MerchantYearlyPaymentMethod},
 };
@@ -21,11 +21,8 @@ impl MerchantStatsMethodRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
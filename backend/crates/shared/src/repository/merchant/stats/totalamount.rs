@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::merchant::repository::stats::totalamount::MerchantStatsTotalAmountRepositoryTrait,
-    config::ConnectionPool,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
     errors::RepositoryError,
     model::merchant::{MerchantMonthlyTotalAmount, MerchantYearlyTotalAmount},
 };
@@ -21,11 +21,8 @@ impl MerchantStatsTotalAmountRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
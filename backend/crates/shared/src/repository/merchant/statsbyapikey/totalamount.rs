@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::merchant::repository::statsbyapikey::totalamount::MerchantStatsTotalAmountByApiKeyRepositoryTrait,
-    config::ConnectionPool,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
     domain::requests::merchant::MonthYearTotalAmountApiKey,
     errors::RepositoryError,
     model::merchant::{MerchantMonthlyTotalAmount, MerchantYearlyTotalAmount},
@@ -22,11 +22,8 @@ impl MerchantStatsTotalAmountByApiKeyRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
@@ -3,13 +3,18 @@ use crate::{
     config::ConnectionPool,
     domain::requests::merchant::{
         FindAllMerchantTransactions, FindAllMerchantTransactionsByApiKey,
-        FindAllMerchantTransactionsById,
+        FindAllMerchantTransactionsById, FindMerchantTransactionsDaily,
     },
     errors::RepositoryError,
-    model::merchant::MerchantTransactionsModel,
+    model::merchant::{
+        MerchantApiKeyUsageDailyModel, MerchantSettlementModel, MerchantTransactionDailyModel,
+        MerchantTransactionsModel,
+    },
+    observability::acquire_connection,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use sqlx::Row;
 use tracing::{error, info};
 
 pub struct MerchantTransactionRepository {
@@ -24,7 +29,7 @@ impl MerchantTransactionRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -253,4 +258,219 @@ impl MerchantTransactionRepositoryTrait for MerchantTransactionRepository {
 
         Ok((merchants, total))
     }
+
+    async fn find_daily_transactions_by_id(
+        &self,
+        req: &FindMerchantTransactionsDaily,
+    ) -> Result<Vec<MerchantTransactionDailyModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        info!(
+            "📅 Fetching daily transactions for merchant_id: {} from {} to {}",
+            req.merchant_id, req.start_date, req.end_date
+        );
+
+        let sql = r#"
+            WITH daily_data AS (
+                SELECT
+                    date_trunc('day', t.transaction_time)::date AS day,
+                    COUNT(t.transaction_id)::bigint AS total_transactions,
+                    COALESCE(SUM(t.amount), 0)::bigint AS total_amount
+                FROM
+                    transactions t
+                INNER JOIN
+                    merchants m ON t.merchant_id = m.merchant_id
+                WHERE
+                    t.deleted_at IS NULL
+                    AND m.deleted_at IS NULL
+                    AND t.merchant_id = $1
+                    AND t.transaction_time >= $2::date
+                    AND t.transaction_time < $3::date + INTERVAL '1 day'
+                GROUP BY
+                    date_trunc('day', t.transaction_time)::date
+            )
+            SELECT
+                gs.day::date AS day,
+                COALESCE(dd.total_transactions, 0)::bigint AS total_transactions,
+                COALESCE(dd.total_amount, 0)::bigint AS total_amount
+            FROM generate_series($2::date, $3::date, INTERVAL '1 day') AS gs(day)
+            LEFT JOIN daily_data dd ON dd.day = gs.day::date
+            ORDER BY gs.day ASC;
+        "#;
+
+        let rows = sqlx::query(sql)
+            .bind(req.merchant_id)
+            .bind(req.start_date)
+            .bind(req.end_date)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Database error in find_daily_transactions_by_id: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        let mut result = Vec::with_capacity(rows.len());
+        for row in rows {
+            result.push(MerchantTransactionDailyModel {
+                day: row.try_get("day")?,
+                total_transactions: row.try_get("total_transactions")?,
+                total_amount: row.try_get("total_amount")?,
+            });
+        }
+
+        info!(
+            "✅ Retrieved {} daily buckets for merchant_id: {}",
+            result.len(),
+            req.merchant_id
+        );
+
+        Ok(result)
+    }
+
+    async fn find_api_key_usage_by_id(
+        &self,
+        req: &FindMerchantTransactionsDaily,
+    ) -> Result<Vec<MerchantApiKeyUsageDailyModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        info!(
+            "🔑 Fetching API key usage for merchant_id: {} from {} to {}",
+            req.merchant_id, req.start_date, req.end_date
+        );
+
+        let sql = r#"
+            WITH merchant_keys AS (
+                SELECT
+                    m.merchant_id,
+                    m.api_key
+                FROM
+                    merchants m
+                WHERE
+                    m.merchant_id = $1
+                    AND m.deleted_at IS NULL
+            ),
+            daily_data AS (
+                SELECT
+                    t.merchant_id,
+                    date_trunc('day', t.transaction_time)::date AS day,
+                    COUNT(t.transaction_id)::bigint AS total_transactions,
+                    COALESCE(SUM(t.amount), 0)::bigint AS total_amount
+                FROM
+                    transactions t
+                WHERE
+                    t.deleted_at IS NULL
+                    AND t.merchant_id = $1
+                    AND t.transaction_time >= $2::date
+                    AND t.transaction_time < $3::date + INTERVAL '1 day'
+                GROUP BY
+                    t.merchant_id,
+                    date_trunc('day', t.transaction_time)::date
+            )
+            SELECT
+                mk.api_key,
+                gs.day::date AS day,
+                COALESCE(dd.total_transactions, 0)::bigint AS total_transactions,
+                COALESCE(dd.total_amount, 0)::bigint AS total_amount
+            FROM merchant_keys mk
+            CROSS JOIN generate_series($2::date, $3::date, INTERVAL '1 day') AS gs(day)
+            LEFT JOIN daily_data dd
+                ON dd.merchant_id = mk.merchant_id
+                AND dd.day = gs.day::date
+            ORDER BY mk.api_key ASC, gs.day ASC;
+        "#;
+
+        let rows = sqlx::query(sql)
+            .bind(req.merchant_id)
+            .bind(req.start_date)
+            .bind(req.end_date)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Database error in find_api_key_usage_by_id: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        let mut result = Vec::with_capacity(rows.len());
+        for row in rows {
+            result.push(MerchantApiKeyUsageDailyModel {
+                api_key: row.try_get("api_key")?,
+                day: row.try_get("day")?,
+                total_transactions: row.try_get("total_transactions")?,
+                total_amount: row.try_get("total_amount")?,
+            });
+        }
+
+        info!(
+            "✅ Retrieved {} API key usage buckets for merchant_id: {}",
+            result.len(),
+            req.merchant_id
+        );
+
+        Ok(result)
+    }
+
+    async fn settlement(
+        &self,
+        req: &FindMerchantTransactionsDaily,
+    ) -> Result<MerchantSettlementModel, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        info!(
+            "💰 Computing settlement for merchant_id: {} from {} to {}",
+            req.merchant_id, req.start_date, req.end_date
+        );
+
+        let sql = r#"
+            WITH settled AS (
+                SELECT
+                    COALESCE(SUM(t.amount), 0)::bigint AS gross,
+                    COALESCE(SUM(f.fee_amount), 0)::bigint AS fees
+                FROM
+                    transactions t
+                LEFT JOIN
+                    transaction_fees f ON f.transaction_id = t.transaction_id
+                WHERE
+                    t.deleted_at IS NULL
+                    AND t.status = 'success'
+                    AND t.merchant_id = $1
+                    AND t.transaction_time >= $2::date
+                    AND t.transaction_time < $3::date + INTERVAL '1 day'
+            ),
+            refunded AS (
+                SELECT 0::bigint AS refunds
+            )
+            SELECT
+                s.gross,
+                s.fees,
+                r.refunds,
+                (s.gross - s.fees - r.refunds)::bigint AS net
+            FROM settled s
+            CROSS JOIN refunded r;
+        "#;
+
+        let row = sqlx::query(sql)
+            .bind(req.merchant_id)
+            .bind(req.start_date)
+            .bind(req.end_date)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Database error in settlement: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        let settlement = MerchantSettlementModel {
+            gross: row.try_get("gross")?,
+            fees: row.try_get("fees")?,
+            refunds: row.try_get("refunds")?,
+            net: row.try_get("net")?,
+        };
+
+        info!(
+            "✅ Settlement for merchant_id {}: gross={}, fees={}, refunds={}, net={}",
+            req.merchant_id, settlement.gross, settlement.fees, settlement.refunds, settlement.net
+        );
+
+        Ok(settlement)
+    }
 }
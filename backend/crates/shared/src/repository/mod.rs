@@ -1,4 +1,7 @@
+pub mod anomaly;
+pub mod bulk;
 pub mod card;
+pub mod email_verification;
 pub mod merchant;
 pub mod refresh_token;
 pub mod role;
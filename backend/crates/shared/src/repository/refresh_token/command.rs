@@ -4,6 +4,7 @@ use crate::{
     domain::requests::refresh_token::{CreateRefreshToken, UpdateRefreshToken},
     errors::RepositoryError,
     model::refresh_token::RefreshTokenModel,
+    observability::acquire_connection,
     utils::parse_expiration_datetime,
 };
 use async_trait::async_trait;
@@ -21,7 +22,7 @@ impl RefreshTokenCommandRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
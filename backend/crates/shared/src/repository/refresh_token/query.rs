@@ -1,6 +1,7 @@
 use crate::{
     abstract_trait::refresh_token::query::RefreshTokenQueryRepositoryTrait, config::ConnectionPool,
     errors::RepositoryError, model::refresh_token::RefreshTokenModel,
+    observability::acquire_connection,
 };
 use async_trait::async_trait;
 use tracing::error;
@@ -17,7 +18,7 @@ impl RefreshTokenQueryRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -3,7 +3,10 @@ use crate::{
     config::ConnectionPool,
     domain::requests::role::{CreateRoleRequest, UpdateRoleRequest},
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::role::RoleModel,
+    observability::acquire_connection,
+    repository::bulk::{BulkOperation, apply_to_trashed},
 };
 use async_trait::async_trait;
 use tracing::error;
@@ -20,7 +23,7 @@ impl RoleCommandRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -149,42 +152,11 @@ impl RoleCommandRepositoryTrait for RoleCommandRepository {
         Ok(())
     }
 
-    async fn restore_all(&self) -> Result<(), RepositoryError> {
-        let mut conn = self.get_conn().await?;
-
-        sqlx::query!(
-            r#"
-            UPDATE roles
-            SET deleted_at = NULL
-            WHERE deleted_at IS NOT NULL
-            "#
-        )
-        .execute(&mut *conn)
-        .await
-        .map_err(|err| {
-            error!("❌ Failed to restore all roles: {err:?}");
-            RepositoryError::from(err)
-        })?;
-
-        Ok(())
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(&self.db, "roles", "role_id", BulkOperation::Restore).await
     }
 
-    async fn delete_all(&self) -> Result<(), RepositoryError> {
-        let mut conn = self.get_conn().await?;
-
-        sqlx::query!(
-            r#"
-            DELETE FROM roles
-            WHERE deleted_at IS NOT NULL
-            "#
-        )
-        .execute(&mut *conn)
-        .await
-        .map_err(|err| {
-            error!("❌ Failed to delete all trashed roles: {err:?}");
-            RepositoryError::from(err)
-        })?;
-
-        Ok(())
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(&self.db, "roles", "role_id", BulkOperation::DeletePermanent).await
     }
 }
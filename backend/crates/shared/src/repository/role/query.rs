@@ -1,9 +1,14 @@
 use crate::{
-    abstract_trait::role::repository::query::RoleQueryRepositoryTrait, config::ConnectionPool,
-    domain::requests::role::FindAllRoles, errors::RepositoryError, model::role::RoleModel,
+    abstract_trait::role::repository::query::RoleQueryRepositoryTrait,
+    config::ConnectionPool,
+    domain::requests::{role::FindAllRoles, trashed::TrashedSort},
+    errors::RepositoryError,
+    model::role::RoleModel,
+    observability::acquire_connection,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use sqlx::Row;
 use tracing::error;
 
 #[derive(Clone)]
@@ -19,7 +24,7 @@ impl RoleQueryRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -148,41 +153,53 @@ impl RoleQueryRepositoryTrait for RoleQueryRepository {
             Some(req.search.as_str())
         };
 
-        let rows = sqlx::query!(
+        let order_by = TrashedSort::parse(req.sort_by.as_deref())
+            .unwrap_or_default()
+            .order_by("deleted_at", "role_id");
+
+        let sql = format!(
             r#"
             SELECT role_id, role_name, created_at, updated_at, deleted_at, COUNT(*) OVER() AS total_count
             FROM roles
             WHERE deleted_at IS NOT NULL
               AND ($1::TEXT IS NULL OR role_name ILIKE '%' || $1 || '%')
-            ORDER BY deleted_at DESC
+            ORDER BY {order_by}
             LIMIT $2 OFFSET $3
-            "#,
-            search_pattern,
-             limit as i64,
-            offset as i64
-        )
-        .fetch_all(&mut *conn)
-        .await
-        .map_err(|e| {
-            error!("❌ Error fetching trashed roles: {e:?}");
-            RepositoryError::from(e)
-        })?;
+            "#
+        );
+
+        let rows = sqlx::query(&sql)
+            .bind(search_pattern)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Error fetching trashed roles: {e:?}");
+                RepositoryError::from(e)
+            })?;
 
         let total = rows
             .first()
-            .map(|r| r.total_count.unwrap_or(0))
+            .and_then(|r| r.try_get::<i64, _>("total_count").ok())
             .unwrap_or(0);
 
         let result = rows
             .into_iter()
-            .map(|r| RoleModel {
-                role_id: r.role_id,
-                role_name: r.role_name,
-                created_at: r.created_at,
-                updated_at: r.updated_at,
-                deleted_at: r.deleted_at,
+            .map(|row| {
+                Ok(RoleModel {
+                    role_id: row.try_get("role_id")?,
+                    role_name: row.try_get("role_name")?,
+                    created_at: row.try_get("created_at")?,
+                    updated_at: row.try_get("updated_at")?,
+                    deleted_at: row.try_get("deleted_at")?,
+                })
             })
-            .collect();
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| {
+                error!("Failed to map role rows: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
 
         Ok((result, total))
     }
@@ -2,15 +2,22 @@ use crate::{
     abstract_trait::saldo::repository::command::SaldoCommandRepositoryTrait,
     config::ConnectionPool,
     domain::requests::saldo::{
-        CreateSaldoRequest, UpdateSaldoBalance, UpdateSaldoRequest, UpdateSaldoWithdraw,
+        ApplySaldoLedgerEntry, ApplySaldoTransfer, CreateSaldoRequest, UpdateSaldoBalance,
+        UpdateSaldoRequest, UpdateSaldoWithdraw,
     },
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::saldo::SaldoModel,
+    observability::{acquire_connection, begin_transaction},
+    repository::bulk::{BulkOperation, apply_to_trashed},
 };
 use anyhow::Result;
 use async_trait::async_trait;
 use tracing::error;
 
+const LEDGER_REFERENCE_SALDO: &str = "saldo";
+const LEDGER_REFERENCE_WITHDRAW: &str = "withdraw";
+
 pub struct SaldoCommandRepository {
     db: ConnectionPool,
 }
@@ -23,11 +30,70 @@ impl SaldoCommandRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
     }
+
+    async fn apply_entry(
+        conn: &mut sqlx::PgConnection,
+        entry: &ApplySaldoLedgerEntry,
+    ) -> Result<SaldoModel, RepositoryError> {
+        let saldo = sqlx::query_as::<_, SaldoModel>(
+            r#"
+            WITH updated AS (
+                UPDATE saldos
+                SET total_balance = total_balance + $2, updated_at = NOW()
+                WHERE card_number = $1
+                  AND deleted_at IS NULL
+                  AND total_balance + $2 >= 0
+                RETURNING
+                    saldo_id,
+                    card_number,
+                    total_balance::BIGINT AS total_balance,
+                    withdraw_amount,
+                    withdraw_time,
+                    created_at,
+                    updated_at,
+                    deleted_at
+            ),
+            ledger AS (
+                INSERT INTO saldo_ledger (
+                    card_number,
+                    amount,
+                    balance_after,
+                    reference_type,
+                    reference_id
+                )
+                SELECT card_number, $2, total_balance, $3, $4
+                FROM updated
+            )
+            SELECT * FROM updated
+            "#,
+        )
+        .bind(&entry.card_number)
+        .bind(entry.amount)
+        .bind(entry.reference_type)
+        .bind(entry.reference_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!(
+                "❌ Failed to apply {} ledger entry {} to card {}: {e:?}",
+                entry.reference_type, entry.reference_id, entry.card_number
+            );
+            RepositoryError::Sqlx(e)
+        })?;
+
+        saldo.ok_or_else(|| {
+            error!(
+                "❌ Insufficient balance or card not found: {}",
+                entry.card_number
+            );
+            RepositoryError::Custom("Insufficient balance or card not found".into())
+        })
+    }
 }
 
 #[async_trait]
@@ -35,29 +101,45 @@ impl SaldoCommandRepositoryTrait for SaldoCommandRepository {
     async fn create(&self, req: &CreateSaldoRequest) -> Result<SaldoModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
-        let saldo = sqlx::query_as!(
-            SaldoModel,
+        let saldo = sqlx::query_as::<_, SaldoModel>(
             r#"
-            INSERT INTO saldos (
-                card_number,
-                total_balance,
-                created_at,
-                updated_at
+            WITH created AS (
+                INSERT INTO saldos (
+                    card_number,
+                    total_balance,
+                    created_at,
+                    updated_at
+                )
+                VALUES ($1, $2, NOW(), NOW())
+                RETURNING saldo_id, card_number, total_balance, created_at, updated_at, deleted_at
+            ),
+            ledger AS (
+                INSERT INTO saldo_ledger (
+                    card_number,
+                    amount,
+                    balance_after,
+                    reference_type,
+                    reference_id
+                )
+                SELECT card_number, total_balance, total_balance, $3, saldo_id
+                FROM created
+                WHERE total_balance <> 0
             )
-            VALUES ($1, $2, NOW(), NOW())
-            RETURNING
+            SELECT
                 saldo_id,
                 card_number,
-                total_balance as "total_balance!: i64",
-                NULL::TIMESTAMP AS "withdraw_time",
-                NULL::INT AS "withdraw_amount",
+                total_balance::BIGINT AS total_balance,
+                NULL::INT AS withdraw_amount,
+                NULL::TIMESTAMP AS withdraw_time,
                 created_at,
                 updated_at,
                 deleted_at
+            FROM created
             "#,
-            req.card_number,
-            req.total_balance as i32
         )
+        .bind(&req.card_number)
+        .bind(req.total_balance.unwrap_or_default() as i32)
+        .bind(LEDGER_REFERENCE_SALDO)
         .fetch_one(&mut *conn)
         .await
         .map_err(|e| {
@@ -75,29 +157,77 @@ impl SaldoCommandRepositoryTrait for SaldoCommandRepository {
             .saldo_id
             .ok_or_else(|| RepositoryError::Custom("saldo_id is required".into()))?;
 
-        let saldo = sqlx::query_as!(
-            SaldoModel,
+        let saldo = sqlx::query_as::<_, SaldoModel>(
             r#"
-            UPDATE saldos
-            SET
-                card_number = $2,
-                total_balance = $3,
-                updated_at = NOW()
-            WHERE saldo_id = $1 AND deleted_at IS NULL
-            RETURNING
+            WITH previous AS (
+                SELECT saldo_id, card_number, total_balance
+                FROM saldos
+                WHERE saldo_id = $1 AND deleted_at IS NULL
+                FOR UPDATE
+            ),
+            updated AS (
+                UPDATE saldos s
+                SET
+                    card_number = $2,
+                    total_balance = $3,
+                    updated_at = NOW()
+                FROM previous p
+                WHERE s.saldo_id = p.saldo_id
+                RETURNING
+                    s.saldo_id,
+                    s.card_number,
+                    s.total_balance,
+                    s.withdraw_amount,
+                    s.withdraw_time,
+                    s.created_at,
+                    s.updated_at,
+                    s.deleted_at,
+                    p.card_number AS previous_card_number,
+                    p.total_balance AS previous_balance
+            ),
+            ledger AS (
+                INSERT INTO saldo_ledger (
+                    card_number,
+                    amount,
+                    balance_after,
+                    reference_type,
+                    reference_id
+                )
+                SELECT previous_card_number, -previous_balance, 0, $4, saldo_id
+                FROM updated
+                WHERE previous_card_number <> card_number AND previous_balance <> 0
+                UNION ALL
+                SELECT
+                    card_number,
+                    total_balance - CASE
+                        WHEN previous_card_number = card_number THEN previous_balance
+                        ELSE 0
+                    END,
+                    total_balance,
+                    $4,
+                    saldo_id
+                FROM updated
+                WHERE total_balance <> CASE
+                    WHEN previous_card_number = card_number THEN previous_balance
+                    ELSE 0
+                END
+            )
+            SELECT
                 saldo_id,
                 card_number,
-                total_balance,
+                total_balance::BIGINT AS total_balance,
                 withdraw_amount,
                 withdraw_time,
                 created_at,
                 updated_at,
                 deleted_at
+            FROM updated
             "#,
-            saldo_id,
-            req.card_number,
-            req.total_balance as i32
         )
+        .bind(saldo_id)
+        .bind(&req.card_number)
+        .bind(req.total_balance as i32)
+        .bind(LEDGER_REFERENCE_SALDO)
         .fetch_one(&mut *conn)
         .await
         .map_err(|e| match e {
@@ -210,6 +340,80 @@ impl SaldoCommandRepositoryTrait for SaldoCommandRepository {
         Ok(saldo)
     }
 
+    async fn withdraw_with_ledger(
+        &self,
+        req: &UpdateSaldoWithdraw,
+        withdraw_id: i32,
+    ) -> Result<SaldoModel, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let saldo = sqlx::query_as::<_, SaldoModel>(
+            r#"
+            WITH updated AS (
+                UPDATE saldos
+                SET
+                    withdraw_amount = $2,
+                    total_balance = total_balance - $2,
+                    withdraw_time = $3,
+                    updated_at = NOW()
+                WHERE
+                    card_number = $1
+                    AND deleted_at IS NULL
+                    AND total_balance >= $2
+                RETURNING
+                    saldo_id,
+                    card_number,
+                    total_balance::BIGINT AS total_balance,
+                    withdraw_amount,
+                    withdraw_time,
+                    created_at,
+                    updated_at,
+                    deleted_at
+            ),
+            ledger AS (
+                INSERT INTO saldo_ledger (
+                    card_number,
+                    amount,
+                    balance_after,
+                    reference_type,
+                    reference_id
+                )
+                SELECT card_number, -($2::BIGINT), total_balance, $4, $5
+                FROM updated
+            )
+            SELECT * FROM updated
+            "#,
+        )
+        .bind(&req.card_number)
+        .bind(req.withdraw_amount)
+        .bind(req.withdraw_time)
+        .bind(LEDGER_REFERENCE_WITHDRAW)
+        .bind(withdraw_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to withdraw from card {}: {e:?}", req.card_number);
+            RepositoryError::Sqlx(e)
+        })?;
+
+        saldo.ok_or_else(|| {
+            error!(
+                "❌ Insufficient balance or card not found: {}",
+                req.card_number
+            );
+            RepositoryError::Custom("Insufficient balance or card not found".into())
+        })
+    }
+
+    async fn apply_ledger_entry(
+        &self,
+        entry: &ApplySaldoLedgerEntry,
+    ) -> Result<SaldoModel, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        Self::apply_entry(&mut conn, entry).await
+    }
+
     async fn trash(&self, id: i32) -> Result<SaldoModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
@@ -304,42 +508,62 @@ impl SaldoCommandRepositoryTrait for SaldoCommandRepository {
         Ok(())
     }
 
-    async fn restore_all(&self) -> Result<(), RepositoryError> {
-        let mut conn = self.get_conn().await?;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(&self.db, "saldos", "saldo_id", BulkOperation::Restore).await
+    }
 
-        sqlx::query!(
-            r#"
-            UPDATE saldos
-            SET deleted_at = NULL
-            WHERE deleted_at IS NOT NULL
-            "#
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(
+            &self.db,
+            "saldos",
+            "saldo_id",
+            BulkOperation::DeletePermanent,
         )
-        .execute(&mut *conn)
         .await
-        .map_err(|e| {
-            error!("❌ Failed to restore all saldos: {e:?}");
+    }
+
+    async fn transfer_with_ledger(
+        &self,
+        req: &ApplySaldoTransfer,
+    ) -> Result<(SaldoModel, SaldoModel), RepositoryError> {
+        let mut tx = begin_transaction(&self.db).await.map_err(|e| {
+            error!("❌ Failed to begin transfer ledger transaction: {e:?}");
             RepositoryError::Sqlx(e)
         })?;
 
-        Ok(())
-    }
+        let debit = ApplySaldoLedgerEntry {
+            card_number: req.from_card_number.clone(),
+            amount: -req.amount,
+            reference_type: req.reference_type,
+            reference_id: req.reference_id,
+        };
+        let credit = ApplySaldoLedgerEntry {
+            card_number: req.to_card_number.clone(),
+            amount: req.amount,
+            reference_type: req.reference_type,
+            reference_id: req.reference_id,
+        };
 
-    async fn delete_all(&self) -> Result<(), RepositoryError> {
-        let mut conn = self.get_conn().await?;
+        // Lock the two saldo rows in card number order so opposite transfers
+        // between the same cards cannot deadlock.
+        let (sender, receiver) = if debit.card_number <= credit.card_number {
+            let sender = Self::apply_entry(&mut tx, &debit).await?;
+            let receiver = Self::apply_entry(&mut tx, &credit).await?;
+            (sender, receiver)
+        } else {
+            let receiver = Self::apply_entry(&mut tx, &credit).await?;
+            let sender = Self::apply_entry(&mut tx, &debit).await?;
+            (sender, receiver)
+        };
 
-        sqlx::query!(
-            r#"
-            DELETE FROM saldos
-            WHERE deleted_at IS NOT NULL
-            "#
-        )
-        .execute(&mut *conn)
-        .await
-        .map_err(|e| {
-            error!("❌ Failed to delete all trashed saldos: {e:?}");
+        tx.commit().await.map_err(|e| {
+            error!(
+                "❌ Failed to commit {} ledger entries {}: {e:?}",
+                req.reference_type, req.reference_id
+            );
             RepositoryError::Sqlx(e)
         })?;
 
-        Ok(())
+        Ok((sender, receiver))
     }
 }
@@ -1,9 +1,15 @@
 use crate::{
-    abstract_trait::saldo::repository::query::SaldoQueryRepositoryTrait, config::ConnectionPool,
-    domain::requests::saldo::FindAllSaldos, errors::RepositoryError, model::saldo::SaldoModel,
+    abstract_trait::saldo::repository::query::SaldoQueryRepositoryTrait,
+    config::ConnectionPool,
+    domain::requests::{saldo::FindAllSaldos, trashed::TrashedSort},
+    errors::RepositoryError,
+    model::saldo::{SaldoLedgerModel, SaldoModel, SaldoStatementModel},
+    observability::acquire_connection,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::Row;
 use tracing::error;
 
 pub struct SaldoQueryRepository {
@@ -18,7 +24,7 @@ impl SaldoQueryRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -168,7 +174,11 @@ impl SaldoQueryRepositoryTrait for SaldoQueryRepository {
             Some(req.search.as_str())
         };
 
-        let rows = sqlx::query!(
+        let order_by = TrashedSort::parse(req.sort_by.as_deref())
+            .unwrap_or_default()
+            .order_by("deleted_at", "saldo_id");
+
+        let sql = format!(
             r#"
             SELECT 
                 saldo_id, 
@@ -183,35 +193,46 @@ impl SaldoQueryRepositoryTrait for SaldoQueryRepository {
             FROM saldos
             WHERE deleted_at IS NOT NULL
               AND ($1::TEXT IS NULL OR card_number ILIKE '%' || $1 || '%')
-            ORDER BY saldo_id
+            ORDER BY {order_by}
             LIMIT $2 OFFSET $3
-            "#,
-            search_pattern,
-            limit as i64,
-            offset as i64
-        )
-        .fetch_all(&mut *conn)
-        .await
-        .map_err(|e| {
-            error!("❌ Failed to fetch trashed saldos: {e:?}");
-            RepositoryError::from(e)
-        })?;
+            "#
+        );
 
-        let total = rows.first().and_then(|r| r.total_count).unwrap_or(0);
+        let rows = sqlx::query(&sql)
+            .bind(search_pattern)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Failed to fetch trashed saldos: {e:?}");
+                RepositoryError::from(e)
+            })?;
+
+        let total = rows
+            .first()
+            .and_then(|r| r.try_get::<i64, _>("total_count").ok())
+            .unwrap_or(0);
 
         let result = rows
             .into_iter()
-            .map(|r| SaldoModel {
-                saldo_id: r.saldo_id,
-                card_number: r.card_number,
-                withdraw_amount: r.withdraw_amount,
-                total_balance: r.total_balance as i64,
-                withdraw_time: r.withdraw_time,
-                created_at: r.created_at,
-                updated_at: r.updated_at,
-                deleted_at: r.deleted_at,
+            .map(|row| {
+                Ok(SaldoModel {
+                    saldo_id: row.try_get("saldo_id")?,
+                    card_number: row.try_get("card_number")?,
+                    withdraw_amount: row.try_get("withdraw_amount")?,
+                    total_balance: row.try_get::<i32, _>("total_balance")? as i64,
+                    withdraw_time: row.try_get("withdraw_time")?,
+                    created_at: row.try_get("created_at")?,
+                    updated_at: row.try_get("updated_at")?,
+                    deleted_at: row.try_get("deleted_at")?,
+                })
             })
-            .collect();
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| {
+                error!("Failed to map saldo rows: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
 
         Ok((result, total))
     }
@@ -302,4 +323,101 @@ impl SaldoQueryRepositoryTrait for SaldoQueryRepository {
             }
         }
     }
+
+    async fn find_balances_by_cards(
+        &self,
+        card_numbers: &[String],
+    ) -> Result<Vec<(String, i64)>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT card_number, total_balance::BIGINT AS total_balance
+            FROM saldos
+            WHERE card_number = ANY($1)
+              AND deleted_at IS NULL
+            "#,
+        )
+        .bind(card_numbers)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch saldo balances by cards: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        rows.into_iter()
+            .map(|row| {
+                Ok((
+                    row.try_get::<String, _>("card_number")?,
+                    row.try_get::<i64, _>("total_balance")?,
+                ))
+            })
+            .collect()
+    }
+
+    async fn find_statement(
+        &self,
+        card_number: &str,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+    ) -> Result<SaldoStatementModel, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let opening_balance: i64 = sqlx::query_scalar(
+            r#"
+            SELECT COALESCE(
+                (
+                    SELECT balance_after
+                    FROM saldo_ledger
+                    WHERE card_number = $1 AND created_at < $2
+                    ORDER BY created_at DESC, ledger_id DESC
+                    LIMIT 1
+                ),
+                (
+                    SELECT balance_after - amount
+                    FROM saldo_ledger
+                    WHERE card_number = $1 AND created_at >= $2
+                    ORDER BY created_at, ledger_id
+                    LIMIT 1
+                ),
+                0
+            )::BIGINT
+            "#,
+        )
+        .bind(card_number)
+        .bind(start)
+        .fetch_one(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch statement opening balance: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        let entries = sqlx::query_as::<_, SaldoLedgerModel>(
+            r#"
+            SELECT ledger_id, card_number, amount, balance_after,
+                   reference_type, reference_id, created_at
+            FROM saldo_ledger
+            WHERE card_number = $1
+              AND created_at >= $2
+              AND created_at < $3
+            ORDER BY created_at, ledger_id
+            "#,
+        )
+        .bind(card_number)
+        .bind(start)
+        .bind(end)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch statement ledger entries: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        Ok(SaldoStatementModel {
+            opening_balance,
+            entries,
+        })
+    }
 }
@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::saldo::repository::stats::total::SaldoTotalBalanceRepositoryTrait,
-    config::ConnectionPool,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
     domain::requests::saldo::MonthTotalSaldoBalance,
     errors::RepositoryError,
     model::saldo::{SaldoMonthTotalBalance, SaldoYearTotalBalance},
@@ -23,11 +23,8 @@ impl SaldoTotalBalanceRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
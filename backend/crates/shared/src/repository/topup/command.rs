@@ -5,7 +5,10 @@ use crate::{
         CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest, UpdateTopupStatus,
     },
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::topup::TopupModel,
+    observability::{acquire_connection, log_query_params},
+    repository::bulk::{BulkOperation, apply_to_trashed},
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -23,7 +26,7 @@ impl TopupCommandRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -35,6 +38,15 @@ impl TopupCommandRepositoryTrait for TopupCommandRepository {
     async fn create(&self, req: &CreateTopupRequest) -> Result<TopupModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
+        log_query_params(
+            "insert_topup",
+            &[
+                ("card_number", &req.card_number),
+                ("topup_amount", &req.topup_amount),
+                ("topup_method", &req.topup_method),
+            ],
+        );
+
         let topup_time = chrono::Utc::now().naive_utc();
 
         let record = sqlx::query_as!(
@@ -80,6 +92,16 @@ impl TopupCommandRepositoryTrait for TopupCommandRepository {
     async fn update(&self, req: &UpdateTopupRequest) -> Result<TopupModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
+        log_query_params(
+            "update_topup",
+            &[
+                ("topup_id", &req.topup_id),
+                ("card_number", &req.card_number),
+                ("topup_amount", &req.topup_amount),
+                ("topup_method", &req.topup_method),
+            ],
+        );
+
         let topup_id = req
             .topup_id
             .ok_or_else(|| RepositoryError::Custom("topup_id is required".into()))?;
@@ -298,42 +320,17 @@ impl TopupCommandRepositoryTrait for TopupCommandRepository {
         Ok(true)
     }
 
-    async fn restore_all(&self) -> Result<bool, RepositoryError> {
-        let mut conn = self.get_conn().await?;
-
-        let result = sqlx::query!(
-            r#"
-            UPDATE topups
-            SET deleted_at = NULL
-            WHERE deleted_at IS NOT NULL
-            "#
-        )
-        .execute(&mut *conn)
-        .await
-        .map_err(|e| {
-            error!("❌ Database error during restore all: {e:?}");
-            RepositoryError::Sqlx(e)
-        })?;
-
-        Ok(result.rows_affected() > 0)
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(&self.db, "topups", "topup_id", BulkOperation::Restore).await
     }
 
-    async fn delete_all(&self) -> Result<bool, RepositoryError> {
-        let mut conn = self.get_conn().await?;
-
-        let result = sqlx::query!(
-            r#"
-            DELETE FROM topups
-            WHERE deleted_at IS NOT NULL
-            "#
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(
+            &self.db,
+            "topups",
+            "topup_id",
+            BulkOperation::DeletePermanent,
         )
-        .execute(&mut *conn)
         .await
-        .map_err(|e| {
-            error!("❌ Database error during delete all permanent: {e:?}");
-            RepositoryError::Sqlx(e)
-        })?;
-
-        Ok(result.rows_affected() > 0)
     }
 }
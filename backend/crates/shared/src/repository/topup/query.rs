@@ -1,12 +1,17 @@
 use crate::{
     abstract_trait::topup::repository::query::TopupQueryRepositoryTrait,
     config::ConnectionPool,
-    domain::requests::topup::{FindAllTopups, FindAllTopupsByCardNumber},
+    domain::requests::{
+        topup::{FindAllTopups, FindAllTopupsByCardNumber},
+        trashed::TrashedSort,
+    },
     errors::RepositoryError,
     model::topup::TopupModel,
+    observability::acquire_connection,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use sqlx::Row;
 use tracing::error;
 
 pub struct TopupQueryRepository {
@@ -21,7 +26,7 @@ impl TopupQueryRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -262,7 +267,11 @@ impl TopupQueryRepositoryTrait for TopupQueryRepository {
             Some(req.search.as_str())
         };
 
-        let rows = sqlx::query!(
+        let order_by = TrashedSort::parse(req.sort_by.as_deref())
+            .unwrap_or_default()
+            .order_by("t.deleted_at", "t.topup_id");
+
+        let sql = format!(
             r#"
             SELECT
                 t.topup_id,
@@ -282,37 +291,48 @@ impl TopupQueryRepositoryTrait for TopupQueryRepository {
                    t.card_number ILIKE '%' || $1 || '%' OR 
                    t.topup_no::TEXT ILIKE '%' || $1 || '%' OR 
                    t.topup_method ILIKE '%' || $1 || '%')
-            ORDER BY t.topup_time DESC
+            ORDER BY {order_by}
             LIMIT $2 OFFSET $3
-            "#,
-            search_pattern,
-            limit as i64,
-            offset as i64
-        )
-        .fetch_all(&mut *conn)
-        .await
-        .map_err(|e| {
-            error!("❌ Failed to fetch trashed topups: {e:?}");
-            RepositoryError::Sqlx(e)
-        })?;
-
-        let total = rows.first().and_then(|r| r.total_count).unwrap_or(0);
+            "#
+        );
+
+        let rows = sqlx::query(&sql)
+            .bind(search_pattern)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Failed to fetch trashed topups: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        let total = rows
+            .first()
+            .and_then(|r| r.try_get::<i64, _>("total_count").ok())
+            .unwrap_or(0);
 
         let topups = rows
             .into_iter()
-            .map(|r| TopupModel {
-                topup_id: r.topup_id,
-                card_number: r.card_number,
-                topup_no: r.topup_no,
-                topup_amount: r.topup_amount as i64,
-                topup_method: r.topup_method,
-                topup_time: r.topup_time,
-                status: r.status,
-                created_at: r.created_at,
-                updated_at: r.updated_at,
-                deleted_at: r.deleted_at,
+            .map(|row| {
+                Ok(TopupModel {
+                    topup_id: row.try_get("topup_id")?,
+                    card_number: row.try_get("card_number")?,
+                    topup_no: row.try_get("topup_no")?,
+                    topup_amount: row.try_get::<i32, _>("topup_amount")? as i64,
+                    topup_method: row.try_get("topup_method")?,
+                    topup_time: row.try_get("topup_time")?,
+                    status: row.try_get("status")?,
+                    created_at: row.try_get("created_at")?,
+                    updated_at: row.try_get("updated_at")?,
+                    deleted_at: row.try_get("deleted_at")?,
+                })
             })
-            .collect();
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| {
+                error!("Failed to map topup rows: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
 
         Ok((topups, total))
     }
@@ -345,12 +365,6 @@ impl TopupQueryRepositoryTrait for TopupQueryRepository {
             RepositoryError::Sqlx(e)
         })?;
 
-        if rows.is_empty() {
-            return Err(RepositoryError::Custom(format!(
-                "No topups found for card_number {card_number}"
-            )));
-        }
-
         let topups = rows
             .into_iter()
             .map(|r| TopupModel {
@@ -411,9 +425,7 @@ impl TopupQueryRepositoryTrait for TopupQueryRepository {
                 updated_at: r.updated_at,
                 deleted_at: r.deleted_at,
             }),
-            None => Err(RepositoryError::Custom(format!(
-                "Topup with ID {id} not found",
-            ))),
+            None => Err(RepositoryError::NotFound),
         }
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::topup::repository::statsbycard::method::TopupStatsMethodByCardRepositoryTrait,
-    config::ConnectionPool,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
     domain::requests::topup::YearMonthMethod,
     errors::RepositoryError,
     model::topup::{TopupMonthMethod, TopupYearlyMethod},
@@ -22,11 +22,8 @@ impl TopupStatsMethodByCardRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
@@ -5,10 +5,15 @@ use crate::{
         CreateTransactionRequest, UpdateTransactionRequest, UpdateTransactionStatus,
     },
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::transaction::TransactionModel,
+    observability::acquire_connection,
+    repository::bulk::{BulkOperation, apply_to_trashed},
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
+use sqlx::types::Json;
 use tracing::error;
 
 pub struct TransactionCommandRepository {
@@ -23,7 +28,7 @@ impl TransactionCommandRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -38,8 +43,9 @@ impl TransactionCommandRepositoryTrait for TransactionCommandRepository {
     ) -> Result<TransactionModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
-        let record = sqlx::query_as!(
-            TransactionModel,
+        let metadata = req.metadata.as_ref().filter(|m| !m.is_empty()).map(Json);
+
+        let record = sqlx::query_as::<_, TransactionModel>(
             r#"
         INSERT INTO transactions (
             card_number,
@@ -47,16 +53,17 @@ impl TransactionCommandRepositoryTrait for TransactionCommandRepository {
             payment_method,
             merchant_id,
             transaction_time,
+            metadata,
             status,
             created_at,
             updated_at
         )
-        VALUES ($1, $2, $3, $4, $5, 'pending', CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+        VALUES ($1, $2, $3, $4, $5, $6, 'pending', CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
         RETURNING
             transaction_id ,
             card_number,
             transaction_no,
-            amount as "amount!",
+            amount,
             payment_method,
             merchant_id,
             transaction_time,
@@ -65,12 +72,13 @@ impl TransactionCommandRepositoryTrait for TransactionCommandRepository {
             updated_at,
             deleted_at
         "#,
-            req.card_number,
-            req.amount as i64,
-            req.payment_method,
-            req.merchant_id,
-            req.transaction_time
         )
+        .bind(&req.card_number)
+        .bind(req.amount)
+        .bind(&req.payment_method)
+        .bind(req.merchant_id)
+        .bind(req.transaction_time)
+        .bind(metadata)
         .fetch_one(&mut *conn)
         .await
         .map_err(|e| {
@@ -273,42 +281,122 @@ impl TransactionCommandRepositoryTrait for TransactionCommandRepository {
         Ok(true)
     }
 
-    async fn restore_all(&self) -> Result<bool, RepositoryError> {
-        let mut conn = self.get_conn().await?;
-
-        let result = sqlx::query!(
-            r#"
-            UPDATE transactions
-            SET deleted_at = NULL
-            WHERE deleted_at IS NOT NULL
-            "#
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(
+            &self.db,
+            "transactions",
+            "transaction_id",
+            BulkOperation::Restore,
         )
-        .execute(&mut *conn)
         .await
-        .map_err(|e| {
-            error!("❌ Database error during restore all: {e:?}");
-            RepositoryError::Sqlx(e)
-        })?;
+    }
 
-        Ok(result.rows_affected() > 0)
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(
+            &self.db,
+            "transactions",
+            "transaction_id",
+            BulkOperation::DeletePermanent,
+        )
+        .await
     }
 
-    async fn delete_all(&self) -> Result<bool, RepositoryError> {
+    async fn expire_pending_before(
+        &self,
+        cutoff: NaiveDateTime,
+        limit: i64,
+    ) -> Result<Vec<TransactionModel>, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
-        let result = sqlx::query!(
+        let expired = sqlx::query_as::<_, TransactionModel>(
             r#"
-            DELETE FROM transactions
-            WHERE deleted_at IS NOT NULL
-            "#
+            WITH stale AS (
+                SELECT transaction_id
+                FROM transactions
+                WHERE status = 'pending'
+                  AND deleted_at IS NULL
+                  AND created_at < $1
+                ORDER BY transaction_id
+                LIMIT $2
+                FOR UPDATE SKIP LOCKED
+            ),
+            expired AS (
+                UPDATE transactions t
+                SET status = 'expired', updated_at = CURRENT_TIMESTAMP
+                FROM stale
+                WHERE t.transaction_id = stale.transaction_id
+                RETURNING
+                    t.transaction_id,
+                    t.card_number,
+                    t.transaction_no,
+                    t.amount,
+                    t.payment_method,
+                    t.merchant_id,
+                    t.transaction_time,
+                    t.status,
+                    t.created_at,
+                    t.updated_at,
+                    t.deleted_at
+            ),
+            -- Only the debit still held for a transaction is refunded. Rows
+            -- that were never charged, or whose charge was already returned,
+            -- are expired without a credit.
+            held AS (
+                SELECT e.transaction_id, e.card_number, (-SUM(l.amount))::BIGINT AS amount
+                FROM expired e
+                JOIN saldo_ledger l
+                  ON l.reference_type = 'transaction'
+                 AND l.reference_id = e.transaction_id
+                 AND l.card_number = e.card_number
+                GROUP BY e.transaction_id, e.card_number
+                HAVING SUM(l.amount) < 0
+            ),
+            refunds AS (
+                SELECT card_number, SUM(amount)::BIGINT AS total
+                FROM held
+                GROUP BY card_number
+            ),
+            credited AS (
+                UPDATE saldos s
+                SET total_balance = s.total_balance + r.total,
+                    updated_at = CURRENT_TIMESTAMP
+                FROM refunds r
+                WHERE s.card_number = r.card_number AND s.deleted_at IS NULL
+                RETURNING s.card_number, s.total_balance
+            ),
+            ledger AS (
+                INSERT INTO saldo_ledger (
+                    card_number,
+                    amount,
+                    balance_after,
+                    reference_type,
+                    reference_id
+                )
+                SELECT
+                    e.card_number,
+                    e.amount,
+                    c.total_balance - r.total + SUM(e.amount) OVER (
+                        PARTITION BY e.card_number ORDER BY e.transaction_id
+                    ),
+                    'transaction_expiry',
+                    e.transaction_id
+                FROM held e
+                JOIN refunds r ON r.card_number = e.card_number
+                JOIN credited c ON c.card_number = e.card_number
+            )
+            SELECT * FROM expired
+            ORDER BY transaction_id
+            "#,
         )
-        .execute(&mut *conn)
+        .bind(cutoff)
+        .bind(limit)
+        .fetch_all(&mut *conn)
         .await
         .map_err(|e| {
-            error!("❌ Database error during delete all permanent: {e:?}");
+            error!("❌ Failed to expire pending transactions: {e:?}");
             RepositoryError::Sqlx(e)
         })?;
 
-        Ok(result.rows_affected() > 0)
+        Ok(expired)
     }
 }
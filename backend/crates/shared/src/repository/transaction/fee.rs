@@ -0,0 +1,101 @@
+use crate::{
+    abstract_trait::transaction::repository::fee::TransactionFeeRepositoryTrait,
+    config::ConnectionPool, errors::RepositoryError, model::transaction::TransactionFeeModel,
+    observability::acquire_connection,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use tracing::{error, info};
+
+pub struct TransactionFeeRepository {
+    db: ConnectionPool,
+}
+
+impl TransactionFeeRepository {
+    pub fn new(db: ConnectionPool) -> Self {
+        Self { db }
+    }
+
+    async fn get_conn(
+        &self,
+    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
+        acquire_connection(&self.db).await.map_err(|e| {
+            error!("❌ Failed to acquire DB connection: {e:?}");
+            RepositoryError::from(e)
+        })
+    }
+}
+
+#[async_trait]
+impl TransactionFeeRepositoryTrait for TransactionFeeRepository {
+    async fn complete_with_fee(&self, fee: &TransactionFeeModel) -> Result<(), RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let result = sqlx::query(
+            r#"
+            WITH completed AS (
+                UPDATE transactions
+                SET status = 'success',
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE transaction_id = $1 AND deleted_at IS NULL
+                RETURNING transaction_id
+            )
+            INSERT INTO transaction_fees (transaction_id, gross_amount, fee_amount, net_amount)
+            SELECT transaction_id, $2, $3, $4 FROM completed
+            ON CONFLICT (transaction_id) DO UPDATE
+            SET gross_amount = EXCLUDED.gross_amount,
+                fee_amount = EXCLUDED.fee_amount,
+                net_amount = EXCLUDED.net_amount,
+                updated_at = current_timestamp
+            "#,
+        )
+        .bind(fee.transaction_id)
+        .bind(fee.gross_amount)
+        .bind(fee.fee_amount)
+        .bind(fee.net_amount)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!(
+                "❌ Failed to store fee for transaction {}: {e:?}",
+                fee.transaction_id
+            );
+            RepositoryError::Sqlx(e)
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        info!(
+            "✅ Stored fee {} for transaction {}",
+            fee.fee_amount, fee.transaction_id
+        );
+
+        Ok(())
+    }
+
+    async fn find_by_transaction_id(
+        &self,
+        transaction_id: i32,
+    ) -> Result<Option<TransactionFeeModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let fee = sqlx::query_as::<_, TransactionFeeModel>(
+            r#"
+            SELECT transaction_id, gross_amount, fee_amount, net_amount
+            FROM transaction_fees
+            WHERE transaction_id = $1
+            "#,
+        )
+        .bind(transaction_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch fee for transaction {transaction_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        Ok(fee)
+    }
+}
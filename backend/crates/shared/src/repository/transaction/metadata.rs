@@ -0,0 +1,70 @@
+use crate::{
+    abstract_trait::transaction::repository::metadata::TransactionMetadataRepositoryTrait,
+    config::ConnectionPool, errors::RepositoryError, observability::acquire_connection,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::{Row, types::Json};
+use std::collections::{BTreeMap, HashMap};
+use tracing::error;
+
+pub struct TransactionMetadataRepository {
+    db: ConnectionPool,
+}
+
+impl TransactionMetadataRepository {
+    pub fn new(db: ConnectionPool) -> Self {
+        Self { db }
+    }
+
+    async fn get_conn(
+        &self,
+    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
+        acquire_connection(&self.db).await.map_err(|e| {
+            error!("❌ Failed to acquire DB connection: {e:?}");
+            RepositoryError::from(e)
+        })
+    }
+}
+
+#[async_trait]
+impl TransactionMetadataRepositoryTrait for TransactionMetadataRepository {
+    async fn find_by_transaction_ids(
+        &self,
+        transaction_ids: &[i32],
+    ) -> Result<HashMap<i32, BTreeMap<String, String>>, RepositoryError> {
+        if transaction_ids.is_empty() {
+            return Ok(HashMap::new());
+        }
+
+        let mut conn = self.get_conn().await?;
+
+        let rows = sqlx::query(
+            r#"
+            SELECT transaction_id, metadata
+            FROM transactions
+            WHERE transaction_id = ANY($1)
+              AND metadata IS NOT NULL
+            "#,
+        )
+        .bind(transaction_ids)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to load transaction metadata: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        rows.into_iter()
+            .map(|row| {
+                let id: i32 = row.try_get("transaction_id")?;
+                let Json(metadata): Json<BTreeMap<String, String>> = row.try_get("metadata")?;
+                Ok((id, metadata))
+            })
+            .collect::<Result<HashMap<_, _>, sqlx::Error>>()
+            .map_err(|e| {
+                error!("Failed to map transaction metadata rows: {e:?}");
+                RepositoryError::Sqlx(e)
+            })
+    }
+}
@@ -1,4 +1,6 @@
 pub mod command;
+pub mod fee;
+pub mod metadata;
 pub mod query;
 pub mod stats;
 pub mod statsbycard;
@@ -1,13 +1,17 @@
 use crate::{
     abstract_trait::transaction::repository::query::TransactionQueryRepositoryTrait,
     config::ConnectionPool,
-    domain::requests::transaction::{FindAllTransactionCardNumber, FindAllTransactions},
+    domain::requests::{
+        transaction::{FindAllTransactionCardNumber, FindAllTransactions, SearchTransactions},
+        trashed::TrashedSort,
+    },
     errors::RepositoryError,
     model::transaction::TransactionModel,
+    observability::acquire_connection,
 };
 use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::Row;
+use sqlx::{Postgres, QueryBuilder, Row};
 use tracing::error;
 
 pub struct TransactionQueryRepository {
@@ -22,7 +26,7 @@ impl TransactionQueryRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -66,7 +70,7 @@ impl TransactionQueryRepositoryTrait for TransactionQueryRepository {
                    OR card_number ILIKE '%' || $1 || '%'
                    OR payment_method ILIKE '%' || $1 || '%'
                    OR status ILIKE '%' || $1 || '%')
-            ORDER BY transaction_time DESC
+            ORDER BY transaction_time DESC, transaction_id DESC
             LIMIT $2 OFFSET $3;
         "#;
 
@@ -147,7 +151,7 @@ impl TransactionQueryRepositoryTrait for TransactionQueryRepository {
                    OR card_number ILIKE '%' || $1 || '%'
                    OR payment_method ILIKE '%' || $1 || '%'
                    OR status ILIKE '%' || $1 || '%')
-            ORDER BY transaction_time DESC
+            ORDER BY transaction_time DESC, transaction_id DESC
             LIMIT $2 OFFSET $3;
         "#;
 
@@ -208,7 +212,12 @@ impl TransactionQueryRepositoryTrait for TransactionQueryRepository {
             Some(req.search.as_str())
         };
 
-        let sql = r#"
+        let order_by = TrashedSort::parse(req.sort_by.as_deref())
+            .unwrap_or_default()
+            .order_by("deleted_at", "transaction_id");
+
+        let sql = format!(
+            r#"
             SELECT
                 transaction_id,
                 card_number,
@@ -227,11 +236,12 @@ impl TransactionQueryRepositoryTrait for TransactionQueryRepository {
               AND ($1::TEXT IS NULL
                    OR card_number ILIKE '%' || $1 || '%'
                    OR payment_method ILIKE '%' || $1 || '%')
-            ORDER BY transaction_time DESC
+            ORDER BY {order_by}
             LIMIT $2 OFFSET $3;
-        "#;
+        "#
+        );
 
-        let rows = sqlx::query(sql)
+        let rows = sqlx::query(&sql)
             .bind(search_pattern)
             .bind(limit as i64)
             .bind(offset)
@@ -308,7 +318,7 @@ impl TransactionQueryRepositoryTrait for TransactionQueryRepository {
               AND ($2::TEXT IS NULL
                    OR payment_method ILIKE '%' || $2 || '%'
                    OR status ILIKE '%' || $2 || '%')
-            ORDER BY transaction_time DESC
+            ORDER BY transaction_time DESC, transaction_id DESC
             LIMIT $3 OFFSET $4;
         "#;
 
@@ -355,6 +365,136 @@ impl TransactionQueryRepositoryTrait for TransactionQueryRepository {
         Ok((data, total))
     }
 
+    async fn search(
+        &self,
+        req: &SearchTransactions,
+    ) -> Result<(Vec<TransactionModel>, i64), RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let limit = req.page_size.clamp(1, 100);
+        let offset = (req.page - 1).max(0) * limit;
+
+        let non_empty = |v: &Option<String>| {
+            v.as_deref()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+        };
+
+        let sort_column = req.sort_column().unwrap_or("transaction_time");
+        let sort_direction = req.sort_direction().unwrap_or("DESC");
+
+        let mut query = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT
+                transaction_id,
+                card_number,
+                transaction_no,
+                amount,
+                payment_method,
+                merchant_id,
+                status,
+                transaction_time,
+                created_at,
+                updated_at,
+                deleted_at,
+                COUNT(*) OVER() AS total_count
+            FROM transactions
+            WHERE deleted_at IS NULL"#,
+        );
+
+        if let Some(status) = non_empty(&req.status) {
+            query.push(" AND status = ").push_bind(status);
+        }
+        if let Some(payment_method) = non_empty(&req.payment_method) {
+            query
+                .push(" AND payment_method = ")
+                .push_bind(payment_method);
+        }
+        if let Some(card_number) = non_empty(&req.card_number) {
+            query.push(" AND card_number = ").push_bind(card_number);
+        }
+        if let Some(merchant_id) = req.merchant_id {
+            query.push(" AND merchant_id = ").push_bind(merchant_id);
+        }
+        if let Some(min_amount) = req.min_amount {
+            query.push(" AND amount >= ").push_bind(min_amount);
+        }
+        if let Some(max_amount) = req.max_amount {
+            query.push(" AND amount <= ").push_bind(max_amount);
+        }
+        if let Some(start_date) = req.start_date {
+            query
+                .push(" AND transaction_time >= ")
+                .push_bind(start_date)
+                .push("::DATE");
+        }
+        if let Some(end_date) = req.end_date {
+            query
+                .push(" AND transaction_time < ")
+                .push_bind(end_date)
+                .push("::DATE + INTERVAL '1 day'");
+        }
+        if let Some(metadata_key) = non_empty(&req.metadata_key) {
+            match non_empty(&req.metadata_value) {
+                Some(metadata_value) => {
+                    query
+                        .push(" AND metadata ->> ")
+                        .push_bind(metadata_key)
+                        .push(" = ")
+                        .push_bind(metadata_value);
+                }
+                None => {
+                    query.push(" AND metadata ? ").push_bind(metadata_key);
+                }
+            }
+        }
+
+        query.push(format!(
+            " ORDER BY {sort_column} {sort_direction}, transaction_id {sort_direction}"
+        ));
+        query
+            .push(" LIMIT ")
+            .push_bind(limit as i64)
+            .push(" OFFSET ")
+            .push_bind(offset as i64);
+
+        let rows = query.build().fetch_all(&mut *conn).await.map_err(|e| {
+            error!("❌ Database error in search transactions: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        let total = rows
+            .first()
+            .and_then(|r| r.try_get::<i64, _>("total_count").ok())
+            .unwrap_or(0);
+
+        let data = rows
+            .into_iter()
+            .map(|row| {
+                Ok(TransactionModel {
+                    transaction_id: row.try_get("transaction_id")?,
+                    card_number: row.try_get("card_number")?,
+                    transaction_no: row.try_get("transaction_no")?,
+                    amount: row.try_get("amount")?,
+                    payment_method: row.try_get("payment_method")?,
+                    merchant_id: row.try_get("merchant_id")?,
+                    transaction_time: row.try_get("transaction_time")?,
+                    status: row.try_get("status")?,
+                    created_at: row.try_get("created_at")?,
+                    updated_at: row.try_get("updated_at")?,
+                    deleted_at: row.try_get("deleted_at")?,
+                })
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| {
+                error!("Failed to map searched transaction rows: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        Ok((data, total))
+    }
+
     async fn find_by_id(&self, transaction_id: i32) -> Result<TransactionModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
@@ -403,7 +543,7 @@ impl TransactionQueryRepositoryTrait for TransactionQueryRepository {
             SELECT *
             FROM transactions
             WHERE merchant_id = $1 AND deleted_at IS NULL
-            ORDER BY transaction_time DESC;
+            ORDER BY transaction_time DESC, transaction_id DESC;
         "#;
 
         let rows = sqlx::query(sql)
@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::transaction::repository::stats::status::TransactionStatsStatusRepositoryTrait,
-    config::ConnectionPool,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
     domain::requests::transaction::MonthStatusTransaction,
     errors::RepositoryError,
     model::transaction::{
@@ -25,11 +25,8 @@ impl TransactionStatsStatusRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
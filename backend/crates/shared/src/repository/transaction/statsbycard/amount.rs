@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::transaction::repository::statsbycard::amount::TransactionStatsAmountByCardRepositoryTrait,
-    config::ConnectionPool,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
     domain::requests::transaction::MonthYearPaymentMethod,
     errors::RepositoryError,
     model::transaction::{TransactionMonthAmount, TransactionYearlyAmount},
@@ -22,11 +22,8 @@ impl TransactionStatsAmountByCardRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
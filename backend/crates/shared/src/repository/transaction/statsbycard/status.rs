@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::transaction::repository::statsbycard::status::TransactionStatsStatusByCardRepositoryTrait,
-    config::ConnectionPool,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
     domain::requests::transaction::{
         MonthStatusTransactionCardNumber, YearStatusTransactionCardNumber,
     },
@@ -27,11 +27,8 @@ impl TransactionStatsStatusByCardRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
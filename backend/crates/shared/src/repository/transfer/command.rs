@@ -6,7 +6,10 @@ use crate::{
         UpdateTransferStatus,
     },
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::transfer::TransferModel,
+    observability::{acquire_connection, log_query_params},
+    repository::bulk::{BulkOperation, apply_to_trashed},
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -24,7 +27,7 @@ impl TransferCommandRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -36,6 +39,15 @@ impl TransferCommandRepositoryTrait for TransferCommandRepository {
     async fn create(&self, req: &CreateTransferRequest) -> Result<TransferModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
+        log_query_params(
+            "insert_transfer",
+            &[
+                ("transfer_from", &req.transfer_from),
+                ("transfer_to", &req.transfer_to),
+                ("transfer_amount", &req.transfer_amount),
+            ],
+        );
+
         let now = chrono::Utc::now().naive_utc();
 
         let record = sqlx::query_as!(
@@ -80,6 +92,16 @@ impl TransferCommandRepositoryTrait for TransferCommandRepository {
     async fn update(&self, req: &UpdateTransferRequest) -> Result<TransferModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
+        log_query_params(
+            "update_transfer",
+            &[
+                ("transfer_id", &req.transfer_id),
+                ("transfer_from", &req.transfer_from),
+                ("transfer_to", &req.transfer_to),
+                ("transfer_amount", &req.transfer_amount),
+            ],
+        );
+
         let transfer_id = req
             .transfer_id
             .ok_or_else(|| RepositoryError::Custom("transfer_id is required".into()))?;
@@ -302,42 +324,17 @@ impl TransferCommandRepositoryTrait for TransferCommandRepository {
         Ok(result.rows_affected() > 0)
     }
 
-    async fn restore_all(&self) -> Result<bool, RepositoryError> {
-        let mut conn = self.get_conn().await?;
-
-        let result = sqlx::query!(
-            r#"
-            UPDATE transfers
-            SET deleted_at = NULL
-            WHERE deleted_at IS NOT NULL
-            "#
-        )
-        .execute(&mut *conn)
-        .await
-        .map_err(|e| {
-            error!("❌ Database error during restore all: {e:?}");
-            RepositoryError::Sqlx(e)
-        })?;
-
-        Ok(result.rows_affected() > 0)
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(&self.db, "transfers", "transfer_id", BulkOperation::Restore).await
     }
 
-    async fn delete_all(&self) -> Result<bool, RepositoryError> {
-        let mut conn = self.get_conn().await?;
-
-        let result = sqlx::query!(
-            r#"
-            DELETE FROM transfers
-            WHERE deleted_at IS NOT NULL
-            "#
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(
+            &self.db,
+            "transfers",
+            "transfer_id",
+            BulkOperation::DeletePermanent,
         )
-        .execute(&mut *conn)
         .await
-        .map_err(|e| {
-            error!("❌ Database error during delete all permanent: {e:?}");
-            RepositoryError::Sqlx(e)
-        })?;
-
-        Ok(result.rows_affected() > 0)
     }
 }
@@ -1,7 +1,13 @@
 use crate::{
     abstract_trait::transfer::repository::query::TransferQueryRepositoryTrait,
-    config::ConnectionPool, domain::requests::transfer::FindAllTransfers, errors::RepositoryError,
-    model::transfer::TransferModel,
+    config::ConnectionPool,
+    domain::requests::{
+        transfer::{FindAllTransfers, FindTransferNetPosition, FindTransfers},
+        trashed::TrashedSort,
+    },
+    errors::RepositoryError,
+    model::transfer::{TransferModel, TransferNetPositionModel},
+    observability::acquire_connection,
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -20,7 +26,7 @@ impl TransferQueryRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -62,7 +68,7 @@ impl TransferQueryRepositoryTrait for TransferQueryRepository {
               AND ($1::TEXT IS NULL
                    OR transfer_from ILIKE '%' || $1 || '%'
                    OR transfer_to ILIKE '%' || $1 || '%')
-            ORDER BY transfer_time DESC
+            ORDER BY transfer_time DESC, transfer_id DESC
             LIMIT $2 OFFSET $3;
         "#;
 
@@ -140,7 +146,7 @@ impl TransferQueryRepositoryTrait for TransferQueryRepository {
               AND ($1::TEXT IS NULL
                    OR transfer_from ILIKE '%' || $1 || '%'
                    OR transfer_to ILIKE '%' || $1 || '%')
-            ORDER BY transfer_time DESC
+            ORDER BY transfer_time DESC, transfer_id DESC
             LIMIT $2 OFFSET $3;
         "#;
 
@@ -200,7 +206,12 @@ impl TransferQueryRepositoryTrait for TransferQueryRepository {
             Some(req.search.as_str())
         };
 
-        let sql = r#"
+        let order_by = TrashedSort::parse(req.sort_by.as_deref())
+            .unwrap_or_default()
+            .order_by("deleted_at", "transfer_id");
+
+        let sql = format!(
+            r#"
             SELECT
                 transfer_id,
                 transfer_no,
@@ -218,11 +229,12 @@ impl TransferQueryRepositoryTrait for TransferQueryRepository {
               AND ($1::TEXT IS NULL
                    OR transfer_from ILIKE '%' || $1 || '%'
                    OR transfer_to ILIKE '%' || $1 || '%')
-            ORDER BY transfer_time DESC
+            ORDER BY {order_by}
             LIMIT $2 OFFSET $3;
-        "#;
+        "#
+        );
 
-        let rows = sqlx::query(sql)
+        let rows = sqlx::query(&sql)
             .bind(search_pattern)
             .bind(limit as i64)
             .bind(offset as i64)
@@ -263,6 +275,86 @@ impl TransferQueryRepositoryTrait for TransferQueryRepository {
         Ok((data, total))
     }
 
+    async fn find_transfers(
+        &self,
+        req: &FindTransfers,
+    ) -> Result<(Vec<TransferModel>, i64), RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let limit = req.page_size.clamp(1, 100);
+        let offset = (req.page - 1).max(0) * limit;
+
+        let filters = req.filters();
+
+        let sql = r#"
+            SELECT
+                transfer_id,
+                transfer_no,
+                transfer_from,
+                transfer_to,
+                transfer_amount,
+                transfer_time AS transfer_time,
+                status,
+                created_at,
+                updated_at,
+                deleted_at,
+                COUNT(*) OVER() AS total_count
+            FROM transfers
+            WHERE deleted_at IS NULL
+              AND ($1::TEXT IS NULL OR transfer_from = $1)
+              AND ($2::TEXT IS NULL OR transfer_to = $2)
+              AND ($3::TEXT IS NULL OR status = $3)
+              AND ($4::DATE IS NULL OR transfer_time >= $4::DATE)
+              AND ($5::DATE IS NULL OR transfer_time < $5::DATE + INTERVAL '1 day')
+            ORDER BY transfer_time DESC, transfer_id DESC
+            LIMIT $6 OFFSET $7;
+        "#;
+
+        let rows = sqlx::query(sql)
+            .bind(filters.transfer_from)
+            .bind(filters.transfer_to)
+            .bind(filters.status)
+            .bind(req.start_date)
+            .bind(req.end_date)
+            .bind(limit as i64)
+            .bind(offset as i64)
+            .fetch_all(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Database error in find_transfers: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        let total = rows
+            .first()
+            .and_then(|r| r.try_get::<i64, _>("total_count").ok())
+            .unwrap_or(0);
+
+        let data = rows
+            .into_iter()
+            .map(|row| {
+                Ok(TransferModel {
+                    transfer_id: row.try_get("transfer_id")?,
+                    transfer_no: row.try_get("transfer_no")?,
+                    transfer_from: row.try_get("transfer_from")?,
+                    transfer_to: row.try_get("transfer_to")?,
+                    transfer_amount: row.try_get("transfer_amount")?,
+                    transfer_time: row.try_get("transfer_time")?,
+                    status: row.try_get("status")?,
+                    created_at: row.try_get("created_at")?,
+                    updated_at: row.try_get("updated_at")?,
+                    deleted_at: row.try_get("deleted_at")?,
+                })
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| {
+                error!("Failed to map filtered transfer rows: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        Ok((data, total))
+    }
+
     async fn find_by_id(&self, id: i32) -> Result<TransferModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
@@ -327,7 +419,7 @@ impl TransferQueryRepositoryTrait for TransferQueryRepository {
                 deleted_at
             FROM transfers
             WHERE deleted_at IS NULL AND transfer_from = $1
-            ORDER BY transfer_time DESC;
+            ORDER BY transfer_time DESC, transfer_id DESC;
         "#;
 
         let rows = sqlx::query(sql)
@@ -384,7 +476,7 @@ impl TransferQueryRepositoryTrait for TransferQueryRepository {
                 deleted_at
             FROM transfers
             WHERE deleted_at IS NULL AND transfer_to = $1
-            ORDER BY transfer_time DESC;
+            ORDER BY transfer_time DESC, transfer_id DESC;
         "#;
 
         let rows = sqlx::query(sql)
@@ -420,4 +512,40 @@ impl TransferQueryRepositoryTrait for TransferQueryRepository {
 
         Ok(data)
     }
+
+    async fn get_net_position(
+        &self,
+        req: &FindTransferNetPosition,
+    ) -> Result<TransferNetPositionModel, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let sql = r#"
+            SELECT
+                COALESCE(SUM(transfer_amount) FILTER (WHERE transfer_from = $1 AND transfer_to = $2), 0)::BIGINT AS a_to_b_amount,
+                COUNT(*) FILTER (WHERE transfer_from = $1 AND transfer_to = $2) AS a_to_b_count,
+                COALESCE(SUM(transfer_amount) FILTER (WHERE transfer_from = $2 AND transfer_to = $1), 0)::BIGINT AS b_to_a_amount,
+                COUNT(*) FILTER (WHERE transfer_from = $2 AND transfer_to = $1) AS b_to_a_count
+            FROM transfers
+            WHERE deleted_at IS NULL
+              AND status = 'success'
+              AND (
+                (transfer_from = $1 AND transfer_to = $2)
+                OR (transfer_from = $2 AND transfer_to = $1)
+              )
+              AND ($3::DATE IS NULL OR transfer_time >= $3::DATE)
+              AND ($4::DATE IS NULL OR transfer_time < $4::DATE + INTERVAL '1 day');
+        "#;
+
+        sqlx::query_as::<_, TransferNetPositionModel>(sql)
+            .bind(&req.card_a)
+            .bind(&req.card_b)
+            .bind(req.start_date)
+            .bind(req.end_date)
+            .fetch_one(&mut *conn)
+            .await
+            .map_err(|e| {
+                error!("❌ Database error in get_net_position: {e:?}");
+                RepositoryError::Sqlx(e)
+            })
+    }
 }
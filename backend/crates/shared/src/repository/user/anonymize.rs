@@ -0,0 +1,99 @@
+use crate::{
+    abstract_trait::user::repository::anonymize::{
+        AnonymizedIdentity, UserAnonymizationRepositoryTrait,
+    },
+    config::{ConnectionPool, QueryCategory, set_statement_timeout},
+    errors::RepositoryError,
+    model::user::UserModel,
+    observability::begin_transaction,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use tracing::{error, info};
+
+#[derive(Clone)]
+pub struct UserAnonymizationRepository {
+    db_pool: ConnectionPool,
+}
+
+impl UserAnonymizationRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+}
+
+#[async_trait]
+impl UserAnonymizationRepositoryTrait for UserAnonymizationRepository {
+    async fn anonymize(
+        &self,
+        user_id: i32,
+        placeholder: &AnonymizedIdentity,
+    ) -> Result<UserModel, RepositoryError> {
+        let mut tx = begin_transaction(&self.db_pool).await.map_err(|e| {
+            error!("❌ Failed to begin anonymization transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        set_statement_timeout(&mut tx, QueryCategory::Oltp).await?;
+
+        let user = sqlx::query_as::<_, UserModel>(
+            r#"
+            UPDATE users
+            SET firstname = $2,
+                lastname = $3,
+                email = $4,
+                password = $5,
+                updated_at = CASE WHEN email = $4 THEN updated_at ELSE current_timestamp END,
+                deleted_at = COALESCE(deleted_at, current_timestamp)
+            WHERE user_id = $1
+            RETURNING user_id, firstname, lastname, email, password,
+                      created_at, updated_at, deleted_at
+            "#,
+        )
+        .bind(user_id)
+        .bind(&placeholder.firstname)
+        .bind(&placeholder.lastname)
+        .bind(&placeholder.email)
+        .bind(&placeholder.password)
+        .fetch_optional(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to scrub PII for user {user_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?
+        .ok_or(RepositoryError::NotFound)?;
+
+        sqlx::query("DELETE FROM refresh_tokens WHERE user_id = $1")
+            .bind(user_id)
+            .execute(&mut *tx)
+            .await
+            .map_err(|e| {
+                error!("❌ Failed to revoke refresh tokens for user {user_id}: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        sqlx::query(
+            r#"
+            UPDATE email_verification_tokens
+            SET used_at = current_timestamp
+            WHERE user_id = $1 AND used_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .execute(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to invalidate verification tokens for user {user_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            error!("❌ Failed to commit anonymization transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        info!("✅ User {user_id} anonymized");
+
+        Ok(user)
+    }
+}
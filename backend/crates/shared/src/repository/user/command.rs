@@ -3,7 +3,10 @@ use crate::{
     config::ConnectionPool,
     domain::requests::user::{CreateUserRequest, UpdateUserRequest},
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::user::UserModel,
+    observability::{acquire_connection, log_query_params},
+    repository::bulk::{BulkOperation, apply_to_trashed},
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -22,7 +25,7 @@ impl UserCommandRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db_pool.acquire().await.map_err(|e| {
+        acquire_connection(&self.db_pool).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -34,18 +37,28 @@ impl UserCommandRepositoryTrait for UserCommandRepository {
     async fn create(&self, req: &CreateUserRequest) -> Result<UserModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
-        let record = sqlx::query_as!(
-            UserModel,
+        log_query_params(
+            "insert_user",
+            &[
+                ("firstname", &req.firstname),
+                ("lastname", &req.lastname),
+                ("email", &req.email),
+                ("password", &req.password),
+            ],
+        );
+
+        let record = sqlx::query_as::<_, UserModel>(
             r#"
             INSERT INTO users (
                 firstname,
                 lastname,
                 email,
                 password,
+                must_change_password,
                 created_at,
                 updated_at
             )
-            VALUES ($1, $2, $3, $4, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
+            VALUES ($1, $2, $3, $4, $5, CURRENT_TIMESTAMP, CURRENT_TIMESTAMP)
             RETURNING
                 user_id ,
                 firstname,
@@ -56,11 +69,12 @@ impl UserCommandRepositoryTrait for UserCommandRepository {
                 updated_at,
                 deleted_at
             "#,
-            req.firstname,
-            req.lastname,
-            req.email,
-            req.password
         )
+        .bind(&req.firstname)
+        .bind(&req.lastname)
+        .bind(&req.email)
+        .bind(&req.password)
+        .bind(req.must_change_password)
         .fetch_one(&mut *conn)
         .await
         .map_err(|e| {
@@ -74,6 +88,17 @@ impl UserCommandRepositoryTrait for UserCommandRepository {
     async fn update(&self, req: &UpdateUserRequest) -> Result<UserModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
+        log_query_params(
+            "update_user",
+            &[
+                ("user_id", &req.id),
+                ("firstname", &req.firstname),
+                ("lastname", &req.lastname),
+                ("email", &req.email),
+                ("password", &req.password),
+            ],
+        );
+
         let user_id = req
             .id
             .ok_or_else(|| RepositoryError::Custom("user_id is required".into()))?;
@@ -205,42 +230,17 @@ impl UserCommandRepositoryTrait for UserCommandRepository {
         Ok(result.rows_affected() > 0)
     }
 
-    async fn restore_all(&self) -> Result<bool, RepositoryError> {
-        let mut conn = self.get_conn().await?;
-
-        let result = sqlx::query!(
-            r#"
-            UPDATE users
-            SET deleted_at = NULL
-            WHERE deleted_at IS NOT NULL
-            "#
-        )
-        .execute(&mut *conn)
-        .await
-        .map_err(|e| {
-            error!("❌ Database error in restore_all users: {e:?}");
-            RepositoryError::Sqlx(e)
-        })?;
-
-        Ok(result.rows_affected() > 0)
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(&self.db_pool, "users", "user_id", BulkOperation::Restore).await
     }
 
-    async fn delete_all(&self) -> Result<bool, RepositoryError> {
-        let mut conn = self.get_conn().await?;
-
-        let result = sqlx::query!(
-            r#"
-            DELETE FROM users
-            WHERE deleted_at IS NOT NULL
-            "#
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(
+            &self.db_pool,
+            "users",
+            "user_id",
+            BulkOperation::DeletePermanent,
         )
-        .execute(&mut *conn)
         .await
-        .map_err(|e| {
-            error!("❌ Database error in delete_all_permanent users: {e:?}");
-            RepositoryError::Sqlx(e)
-        })?;
-
-        Ok(result.rows_affected() > 0)
     }
 }
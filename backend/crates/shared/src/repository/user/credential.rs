@@ -0,0 +1,85 @@
+use crate::{
+    abstract_trait::user::repository::credential::UserCredentialRepositoryTrait,
+    config::ConnectionPool, errors::RepositoryError, observability::acquire_connection,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::Row;
+use tracing::{error, info};
+
+#[derive(Clone)]
+pub struct UserCredentialRepository {
+    db_pool: ConnectionPool,
+}
+
+impl UserCredentialRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+
+    async fn get_conn(
+        &self,
+    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
+        acquire_connection(&self.db_pool).await.map_err(|e| {
+            error!("❌ Failed to acquire DB connection: {e:?}");
+            RepositoryError::from(e)
+        })
+    }
+}
+
+#[async_trait]
+impl UserCredentialRepositoryTrait for UserCredentialRepository {
+    async fn must_change_password(&self, user_id: i32) -> Result<bool, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let row = sqlx::query(
+            "SELECT must_change_password FROM users WHERE user_id = $1 AND deleted_at IS NULL",
+        )
+        .bind(user_id)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch must_change_password for user {user_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        match row {
+            Some(row) => Ok(row.try_get("must_change_password")?),
+            None => Err(RepositoryError::NotFound),
+        }
+    }
+
+    async fn update_password(
+        &self,
+        user_id: i32,
+        password_hash: &str,
+    ) -> Result<(), RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let result = sqlx::query(
+            r#"
+            UPDATE users
+            SET password = $2,
+                must_change_password = FALSE,
+                updated_at = current_timestamp
+            WHERE user_id = $1 AND deleted_at IS NULL
+            "#,
+        )
+        .bind(user_id)
+        .bind(password_hash)
+        .execute(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to update password for user {user_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        if result.rows_affected() == 0 {
+            return Err(RepositoryError::NotFound);
+        }
+
+        info!("✅ Password updated for user {user_id}");
+
+        Ok(())
+    }
+}
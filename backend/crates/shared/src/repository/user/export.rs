@@ -0,0 +1,80 @@
+use crate::{
+    abstract_trait::user::repository::export::UserExportRepositoryTrait,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
+    errors::RepositoryError,
+    model::user::{UserExportCardModel, UserTransactionSummaryModel},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use tracing::error;
+
+#[derive(Clone)]
+pub struct UserExportRepository {
+    db_pool: ConnectionPool,
+}
+
+impl UserExportRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+
+    async fn get_conn(
+        &self,
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db_pool, QueryCategory::Analytics).await
+    }
+}
+
+#[async_trait]
+impl UserExportRepositoryTrait for UserExportRepository {
+    async fn find_cards(&self, user_id: i32) -> Result<Vec<UserExportCardModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        sqlx::query_as::<_, UserExportCardModel>(
+            r#"
+            SELECT card_number, card_type, card_provider, expire_date, created_at
+            FROM cards
+            WHERE user_id = $1 AND deleted_at IS NULL
+            ORDER BY created_at
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch cards for export of user {user_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })
+    }
+
+    async fn transaction_summaries(
+        &self,
+        user_id: i32,
+    ) -> Result<Vec<UserTransactionSummaryModel>, RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        sqlx::query_as::<_, UserTransactionSummaryModel>(
+            r#"
+            SELECT
+                c.card_number,
+                COUNT(t.transaction_id)::BIGINT AS total_transactions,
+                COALESCE(SUM(t.amount), 0)::BIGINT AS total_amount,
+                MIN(t.transaction_time) AS first_transaction_at,
+                MAX(t.transaction_time) AS last_transaction_at
+            FROM cards c
+            LEFT JOIN transactions t
+                ON t.card_number = c.card_number AND t.deleted_at IS NULL
+            WHERE c.user_id = $1 AND c.deleted_at IS NULL
+            GROUP BY c.card_number
+            ORDER BY c.card_number
+            "#,
+        )
+        .bind(user_id)
+        .fetch_all(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to fetch transaction summaries for user {user_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })
+    }
+}
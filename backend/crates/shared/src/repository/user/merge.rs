@@ -0,0 +1,197 @@
+use crate::{
+    abstract_trait::user::repository::merge::UserMergeRepositoryTrait,
+    config::{ConnectionPool, QueryCategory, set_statement_timeout},
+    errors::RepositoryError,
+    model::user::{UserMergeModel, UserModel},
+    observability::begin_transaction,
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use sqlx::{Postgres, Transaction};
+use tracing::{error, info};
+
+#[derive(Clone)]
+pub struct UserMergeRepository {
+    db_pool: ConnectionPool,
+}
+
+impl UserMergeRepository {
+    pub fn new(db_pool: ConnectionPool) -> Self {
+        Self { db_pool }
+    }
+
+    async fn lock_active_user(
+        tx: &mut Transaction<'static, Postgres>,
+        user_id: i32,
+    ) -> Result<UserModel, RepositoryError> {
+        sqlx::query_as::<_, UserModel>(
+            r#"
+            SELECT user_id, firstname, lastname, email, password,
+                   created_at, updated_at, deleted_at
+            FROM users
+            WHERE user_id = $1 AND deleted_at IS NULL
+            FOR UPDATE
+            "#,
+        )
+        .bind(user_id)
+        .fetch_optional(&mut **tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to lock user {user_id} for merge: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?
+        .ok_or(RepositoryError::NotFound)
+    }
+
+    async fn execute(
+        tx: &mut Transaction<'static, Postgres>,
+        sql: &str,
+        primary_id: i32,
+        duplicate_id: i32,
+    ) -> Result<i64, RepositoryError> {
+        let result = sqlx::query(sql)
+            .bind(primary_id)
+            .bind(duplicate_id)
+            .execute(&mut **tx)
+            .await
+            .map_err(|e| {
+                error!("❌ Failed to merge user {duplicate_id} into {primary_id}: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        Ok(result.rows_affected() as i64)
+    }
+}
+
+#[async_trait]
+impl UserMergeRepositoryTrait for UserMergeRepository {
+    async fn merge(
+        &self,
+        primary_id: i32,
+        duplicate_id: i32,
+    ) -> Result<UserMergeModel, RepositoryError> {
+        let mut tx = begin_transaction(&self.db_pool).await.map_err(|e| {
+            error!("❌ Failed to begin user merge transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        set_statement_timeout(&mut tx, QueryCategory::Oltp).await?;
+
+        let (first, second) = if primary_id < duplicate_id {
+            (primary_id, duplicate_id)
+        } else {
+            (duplicate_id, primary_id)
+        };
+        let first = Self::lock_active_user(&mut tx, first).await?;
+        let second = Self::lock_active_user(&mut tx, second).await?;
+        let primary = if first.user_id == primary_id {
+            first
+        } else {
+            second
+        };
+
+        let cards_moved = Self::execute(
+            &mut tx,
+            r#"
+            UPDATE cards
+            SET user_id = $1, updated_at = current_timestamp
+            WHERE user_id = $2
+            "#,
+            primary_id,
+            duplicate_id,
+        )
+        .await?;
+
+        let merchants_moved = Self::execute(
+            &mut tx,
+            r#"
+            UPDATE merchants
+            SET user_id = $1, updated_at = current_timestamp
+            WHERE user_id = $2
+            "#,
+            primary_id,
+            duplicate_id,
+        )
+        .await?;
+
+        let roles_moved = Self::execute(
+            &mut tx,
+            r#"
+            INSERT INTO user_roles (user_id, role_id, created_at, updated_at)
+            SELECT DISTINCT $1, ur.role_id, current_timestamp, current_timestamp
+            FROM user_roles ur
+            WHERE ur.user_id = $2
+              AND ur.deleted_at IS NULL
+              AND NOT EXISTS (
+                  SELECT 1 FROM user_roles p
+                  WHERE p.user_id = $1
+                    AND p.role_id = ur.role_id
+                    AND p.deleted_at IS NULL
+              )
+            "#,
+            primary_id,
+            duplicate_id,
+        )
+        .await?;
+
+        let merchant_links_moved = Self::execute(
+            &mut tx,
+            r#"
+            INSERT INTO merchant_users (merchant_id, user_id)
+            SELECT mu.merchant_id, $1
+            FROM merchant_users mu
+            WHERE mu.user_id = $2
+            ON CONFLICT (merchant_id, user_id) DO NOTHING
+            "#,
+            primary_id,
+            duplicate_id,
+        )
+        .await?;
+
+        for table in ["user_roles", "merchant_users", "refresh_tokens"] {
+            sqlx::query(&format!("DELETE FROM {table} WHERE user_id = $1"))
+                .bind(duplicate_id)
+                .execute(&mut *tx)
+                .await
+                .map_err(|e| {
+                    error!("❌ Failed to clear {table} for merged user {duplicate_id}: {e:?}");
+                    RepositoryError::Sqlx(e)
+                })?;
+        }
+
+        let duplicate = sqlx::query_as::<_, UserModel>(
+            r#"
+            UPDATE users
+            SET deleted_at = current_timestamp, updated_at = current_timestamp
+            WHERE user_id = $1
+            RETURNING user_id, firstname, lastname, email, password,
+                      created_at, updated_at, deleted_at
+            "#,
+        )
+        .bind(duplicate_id)
+        .fetch_one(&mut *tx)
+        .await
+        .map_err(|e| {
+            error!("❌ Failed to trash merged user {duplicate_id}: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        tx.commit().await.map_err(|e| {
+            error!("❌ Failed to commit user merge transaction: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        info!(
+            "✅ Merged user {duplicate_id} into {primary_id}: {cards_moved} cards, {merchants_moved} merchants, {roles_moved} roles, {merchant_links_moved} merchant links"
+        );
+
+        Ok(UserMergeModel {
+            primary,
+            duplicate,
+            cards_moved,
+            merchants_moved,
+            roles_moved,
+            merchant_links_moved,
+        })
+    }
+}
@@ -1,2 +1,6 @@
+pub mod anonymize;
 pub mod command;
+pub mod credential;
+pub mod export;
+pub mod merge;
 pub mod query;
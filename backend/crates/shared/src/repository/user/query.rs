@@ -1,6 +1,10 @@
 use crate::{
-    abstract_trait::user::repository::query::UserQueryRepositoryTrait, config::ConnectionPool,
-    domain::requests::user::FindAllUserRequest, errors::RepositoryError, model::user::UserModel,
+    abstract_trait::user::repository::query::UserQueryRepositoryTrait,
+    config::ConnectionPool,
+    domain::requests::{trashed::TrashedSort, user::FindAllUserRequest},
+    errors::RepositoryError,
+    model::user::UserModel,
+    observability::acquire_connection,
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -20,7 +24,7 @@ impl UserQueryRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -54,7 +58,7 @@ impl UserQueryRepositoryTrait for UserQueryRepository {
                    OR firstname ILIKE '%' || $1 || '%' 
                    OR lastname ILIKE '%' || $1 || '%' 
                    OR email ILIKE '%' || $1 || '%')
-            ORDER BY created_at DESC
+            ORDER BY created_at DESC, user_id DESC
             LIMIT $2 OFFSET $3;
         "#;
 
@@ -122,7 +126,7 @@ impl UserQueryRepositoryTrait for UserQueryRepository {
                    OR firstname ILIKE '%' || $1 || '%' 
                    OR lastname ILIKE '%' || $1 || '%' 
                    OR email ILIKE '%' || $1 || '%')
-            ORDER BY created_at DESC
+            ORDER BY created_at DESC, user_id DESC
             LIMIT $2 OFFSET $3;
         "#;
 
@@ -180,7 +184,12 @@ impl UserQueryRepositoryTrait for UserQueryRepository {
             Some(req.search.as_str())
         };
 
-        let sql = r#"
+        let order_by = TrashedSort::parse(req.sort_by.as_deref())
+            .unwrap_or_default()
+            .order_by("deleted_at", "user_id");
+
+        let sql = format!(
+            r#"
             SELECT
                 *,
                 COUNT(*) OVER() AS total_count
@@ -190,11 +199,12 @@ impl UserQueryRepositoryTrait for UserQueryRepository {
                    OR firstname ILIKE '%' || $1 || '%' 
                    OR lastname ILIKE '%' || $1 || '%' 
                    OR email ILIKE '%' || $1 || '%')
-            ORDER BY created_at DESC
+            ORDER BY {order_by}
             LIMIT $2 OFFSET $3;
-        "#;
+        "#
+        );
 
-        let rows = sqlx::query(sql)
+        let rows = sqlx::query(&sql)
             .bind(search_pattern)
             .bind(limit as i64)
             .bind(offset as i64)
@@ -8,6 +8,7 @@ use crate::{
     domain::requests::user_role::{CreateUserRoleRequest, RemoveUserRoleRequest},
     errors::RepositoryError,
     model::user_role::UserRoleModel,
+    observability::acquire_connection,
 };
 
 pub struct UserRoleRepository {
@@ -22,7 +23,7 @@ impl UserRoleRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db_pool.acquire().await.map_err(|e| {
+        acquire_connection(&self.db_pool).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
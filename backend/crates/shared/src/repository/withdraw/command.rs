@@ -5,10 +5,14 @@ use crate::{
         CreateWithdrawRequest, UpdateWithdrawRequest, UpdateWithdrawStatus,
     },
     errors::RepositoryError,
+    model::bulk::BulkResultModel,
     model::withdraw::WithdrawModel,
+    observability::{acquire_connection, log_query_params},
+    repository::bulk::{BulkOperation, apply_to_trashed},
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use tracing::error;
 
 pub struct WithdrawCommandRepository {
@@ -23,7 +27,7 @@ impl WithdrawCommandRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -35,6 +39,15 @@ impl WithdrawCommandRepositoryTrait for WithdrawCommandRepository {
     async fn create(&self, req: &CreateWithdrawRequest) -> Result<WithdrawModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
+        log_query_params(
+            "insert_withdraw",
+            &[
+                ("card_number", &req.card_number),
+                ("withdraw_amount", &req.withdraw_amount),
+                ("withdraw_time", &req.withdraw_time),
+            ],
+        );
+
         let now = chrono::Utc::now().naive_utc();
 
         let record = sqlx::query_as!(
@@ -78,6 +91,16 @@ impl WithdrawCommandRepositoryTrait for WithdrawCommandRepository {
     async fn update(&self, req: &UpdateWithdrawRequest) -> Result<WithdrawModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
+        log_query_params(
+            "update_withdraw",
+            &[
+                ("withdraw_id", &req.withdraw_id),
+                ("card_number", &req.card_number),
+                ("withdraw_amount", &req.withdraw_amount),
+                ("withdraw_time", &req.withdraw_time),
+            ],
+        );
+
         let withdraw_id = req
             .withdraw_id
             .ok_or_else(|| RepositoryError::Custom("withdraw_id is required".into()))?;
@@ -252,32 +275,103 @@ impl WithdrawCommandRepositoryTrait for WithdrawCommandRepository {
         Ok(result.rows_affected() > 0)
     }
 
-    async fn restore_all(&self) -> Result<bool, RepositoryError> {
+    async fn cancel(
+        &self,
+        withdraw_id: i32,
+        created_after: NaiveDateTime,
+    ) -> Result<Option<WithdrawModel>, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
-        let result =
-            sqlx::query("UPDATE withdraws SET deleted_at = NULL WHERE deleted_at IS NOT NULL")
-                .execute(&mut *conn)
-                .await
-                .map_err(|e| {
-                    error!("❌ Database error in restore_all withdraw: {e:?}");
-                    RepositoryError::Sqlx(e)
-                })?;
+        log_query_params(
+            "cancel_withdraw",
+            &[
+                ("withdraw_id", &withdraw_id),
+                ("created_after", &created_after),
+            ],
+        );
 
-        Ok(result.rows_affected() > 0)
-    }
+        let record = sqlx::query_as::<_, WithdrawModel>(
+            r#"
+            WITH cancelled AS (
+                UPDATE withdraws w
+                SET status = 'cancelled', updated_at = CURRENT_TIMESTAMP
+                WHERE w.withdraw_id = $1
+                  AND w.status = 'pending'
+                  AND w.deleted_at IS NULL
+                  AND w.created_at >= $2
+                  AND EXISTS (
+                      SELECT 1 FROM saldos s
+                      WHERE s.card_number = w.card_number AND s.deleted_at IS NULL
+                  )
+                  AND (
+                      SELECT COALESCE(SUM(l.amount), 0)
+                      FROM saldo_ledger l
+                      WHERE l.reference_type = 'withdraw'
+                        AND l.reference_id = w.withdraw_id
+                        AND l.card_number = w.card_number
+                  ) < 0
+                RETURNING
+                    w.withdraw_id,
+                    w.withdraw_no,
+                    w.card_number,
+                    w.withdraw_amount,
+                    w.withdraw_time,
+                    w.status,
+                    w.created_at,
+                    w.updated_at,
+                    w.deleted_at
+            ),
+            credited AS (
+                UPDATE saldos s
+                SET total_balance = s.total_balance + c.withdraw_amount,
+                    updated_at = CURRENT_TIMESTAMP
+                FROM cancelled c
+                WHERE s.card_number = c.card_number AND s.deleted_at IS NULL
+                RETURNING s.card_number, s.total_balance
+            ),
+            ledger AS (
+                INSERT INTO saldo_ledger (
+                    card_number,
+                    amount,
+                    balance_after,
+                    reference_type,
+                    reference_id
+                )
+                SELECT
+                    c.card_number,
+                    c.withdraw_amount,
+                    cr.total_balance,
+                    'withdraw_cancel',
+                    c.withdraw_id
+                FROM cancelled c
+                JOIN credited cr ON cr.card_number = c.card_number
+            )
+            SELECT * FROM cancelled
+            "#,
+        )
+        .bind(withdraw_id)
+        .bind(created_after)
+        .fetch_optional(&mut *conn)
+        .await
+        .map_err(|e| {
+            error!("❌ Database error in cancel withdraw: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
 
-    async fn delete_all(&self) -> Result<bool, RepositoryError> {
-        let mut conn = self.get_conn().await?;
+        Ok(record)
+    }
 
-        let result = sqlx::query("DELETE FROM withdraws WHERE deleted_at IS NOT NULL")
-            .execute(&mut *conn)
-            .await
-            .map_err(|e| {
-                error!("❌ Database error in delete_all withdraw: {e:?}");
-                RepositoryError::Sqlx(e)
-            })?;
+    async fn restore_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(&self.db, "withdraws", "withdraw_id", BulkOperation::Restore).await
+    }
 
-        Ok(result.rows_affected() > 0)
+    async fn delete_all(&self) -> Result<BulkResultModel, RepositoryError> {
+        apply_to_trashed(
+            &self.db,
+            "withdraws",
+            "withdraw_id",
+            BulkOperation::DeletePermanent,
+        )
+        .await
     }
 }
@@ -1,13 +1,17 @@
 use crate::{
     abstract_trait::withdraw::repository::query::WithdrawQueryRepositoryTrait,
     config::ConnectionPool,
-    domain::requests::withdraw::{FindAllWithdrawCardNumber, FindAllWithdraws},
+    domain::requests::{
+        trashed::TrashedSort,
+        withdraw::{FindAllWithdrawCardNumber, FindAllWithdraws, FindWithdraws},
+    },
     errors::RepositoryError,
     model::withdraw::WithdrawModel,
+    observability::acquire_connection,
 };
 use anyhow::Result;
 use async_trait::async_trait;
-use sqlx::Row;
+use sqlx::{Postgres, QueryBuilder, Row};
 use tracing::error;
 
 pub struct WithdrawQueryRepository {
@@ -21,7 +25,7 @@ impl WithdrawQueryRepository {
     async fn get_conn(
         &self,
     ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
+        acquire_connection(&self.db).await.map_err(|e| {
             error!("❌ Failed to acquire DB connection: {e:?}");
             RepositoryError::from(e)
         })
@@ -68,7 +72,8 @@ impl WithdrawQueryRepositoryTrait for WithdrawQueryRepository {
                     OR status ILIKE '%' || $1 || '%'
                 )
             ORDER BY
-                withdraw_time DESC
+                withdraw_time DESC,
+                withdraw_id DESC
             LIMIT $2 OFFSET $3;
         "#;
 
@@ -150,7 +155,8 @@ impl WithdrawQueryRepositoryTrait for WithdrawQueryRepository {
                     OR status ILIKE '%' || $1 || '%'
                 )
             ORDER BY
-                withdraw_time DESC
+                withdraw_time DESC,
+                withdraw_id DESC
             LIMIT $2 OFFSET $3;
         "#;
 
@@ -209,7 +215,12 @@ impl WithdrawQueryRepositoryTrait for WithdrawQueryRepository {
             Some(req.search.as_str())
         };
 
-        let sql = r#"
+        let order_by = TrashedSort::parse(req.sort_by.as_deref())
+            .unwrap_or_default()
+            .order_by("deleted_at", "withdraw_id");
+
+        let sql = format!(
+            r#"
             SELECT
                 withdraw_id,
                 withdraw_no,
@@ -231,12 +242,12 @@ impl WithdrawQueryRepositoryTrait for WithdrawQueryRepository {
                     OR withdraw_time::TEXT ILIKE '%' || $1 || '%'
                     OR status ILIKE '%' || $1 || '%'
                 )
-            ORDER BY
-                withdraw_time DESC
+            ORDER BY {order_by}
             LIMIT $2 OFFSET $3;
-        "#;
+        "#
+        );
 
-        let rows = sqlx::query(sql)
+        let rows = sqlx::query(&sql)
             .bind(search_pattern)
             .bind(limit as i64)
             .bind(offset as i64)
@@ -315,7 +326,8 @@ impl WithdrawQueryRepositoryTrait for WithdrawQueryRepository {
                     OR status ILIKE '%' || $2 || '%'
                 )
             ORDER BY
-                withdraw_time DESC
+                withdraw_time DESC,
+                withdraw_id DESC
             LIMIT $3 OFFSET $4;
         "#;
 
@@ -360,6 +372,104 @@ impl WithdrawQueryRepositoryTrait for WithdrawQueryRepository {
         Ok((data, total))
     }
 
+    async fn find_withdraws(
+        &self,
+        req: &FindWithdraws,
+    ) -> Result<(Vec<WithdrawModel>, i64), RepositoryError> {
+        let mut conn = self.get_conn().await?;
+
+        let limit = req.page_size.clamp(1, 100);
+        let offset = (req.page - 1).max(0) * limit;
+
+        let non_empty = |v: &Option<String>| {
+            v.as_deref()
+                .map(str::trim)
+                .filter(|s| !s.is_empty())
+                .map(str::to_string)
+        };
+
+        let sort_column = req.sort_column().unwrap_or("withdraw_time");
+        let sort_direction = req.sort_direction().unwrap_or("DESC");
+
+        let mut query = QueryBuilder::<Postgres>::new(
+            r#"
+            SELECT
+                withdraw_id,
+                withdraw_no,
+                card_number,
+                withdraw_amount,
+                withdraw_time,
+                status,
+                created_at,
+                updated_at,
+                deleted_at,
+                COUNT(*) OVER() AS total_count
+            FROM withdraws
+            WHERE deleted_at IS NULL"#,
+        );
+
+        if let Some(status) = non_empty(&req.status) {
+            query.push(" AND status = ").push_bind(status);
+        }
+        if let Some(card_number) = non_empty(&req.card_number) {
+            query.push(" AND card_number = ").push_bind(card_number);
+        }
+        if let Some(from) = req.from {
+            query
+                .push(" AND withdraw_time >= ")
+                .push_bind(from)
+                .push("::DATE");
+        }
+        if let Some(to) = req.to {
+            query
+                .push(" AND withdraw_time < ")
+                .push_bind(to)
+                .push("::DATE + INTERVAL '1 day'");
+        }
+
+        query.push(format!(
+            " ORDER BY {sort_column} {sort_direction}, withdraw_id {sort_direction}"
+        ));
+        query
+            .push(" LIMIT ")
+            .push_bind(limit as i64)
+            .push(" OFFSET ")
+            .push_bind(offset as i64);
+
+        let rows = query.build().fetch_all(&mut *conn).await.map_err(|e| {
+            error!("❌ Database error in find_withdraws: {e:?}");
+            RepositoryError::Sqlx(e)
+        })?;
+
+        let total = rows
+            .first()
+            .and_then(|r| r.try_get::<i64, _>("total_count").ok())
+            .unwrap_or(0);
+
+        let data = rows
+            .into_iter()
+            .map(|row| {
+                Ok(WithdrawModel {
+                    withdraw_id: row.try_get("withdraw_id")?,
+                    withdraw_no: row.try_get("withdraw_no")?,
+                    card_number: row.try_get("card_number")?,
+                    withdraw_amount: row.try_get("withdraw_amount")?,
+                    withdraw_time: row.try_get("withdraw_time")?,
+                    status: row.try_get("status")?,
+                    created_at: row.try_get("created_at")?,
+                    updated_at: row.try_get("updated_at")?,
+                    deleted_at: row.try_get("deleted_at")?,
+                })
+            })
+            .collect::<Result<Vec<_>, sqlx::Error>>()
+            .map_err(|e| {
+                error!("Failed to map filtered withdraw rows: {e:?}");
+                RepositoryError::Sqlx(e)
+            })?;
+
+        Ok((data, total))
+    }
+
     async fn find_by_id(&self, id: i32) -> Result<WithdrawModel, RepositoryError> {
         let mut conn = self.get_conn().await?;
 
@@ -435,12 +545,6 @@ impl WithdrawQueryRepositoryTrait for WithdrawQueryRepository {
                 RepositoryError::Sqlx(e)
             })?;
 
-        if rows.is_empty() {
-            return Err(RepositoryError::Custom(format!(
-                "No withdrawals found for card_number {card_number}"
-            )));
-        }
-
         let withdraws = rows
             .into_iter()
             .map(|row| WithdrawModel {
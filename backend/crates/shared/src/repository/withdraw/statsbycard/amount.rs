@@ -1,6 +1,6 @@
 use crate::{
     abstract_trait::withdraw::repository::statsbycard::amount::WithdrawStatsAmountByCardRepositoryTrait,
-    config::ConnectionPool,
+    config::{ConnectionPool, QueryCategory, begin_with_statement_timeout},
     domain::requests::withdraw::YearMonthCardNumber,
     errors::RepositoryError,
     model::withdraw::{WithdrawMonthlyAmount, WithdrawYearlyAmount},
@@ -22,11 +22,8 @@ impl WithdrawStatsAmountByCardRepository {
 
     async fn get_conn(
         &self,
-    ) -> Result<sqlx::pool::PoolConnection<sqlx::Postgres>, RepositoryError> {
-        self.db.acquire().await.map_err(|e| {
-            error!("❌ Failed to acquire DB connection: {e:?}");
-            RepositoryError::from(e)
-        })
+    ) -> Result<sqlx::Transaction<'static, sqlx::Postgres>, RepositoryError> {
+        begin_with_statement_timeout(&self.db, QueryCategory::Analytics).await
     }
 }
 
@@ -39,6 +39,14 @@ impl GatewayCircuitBreaker {
         self.success_count.load(Ordering::SeqCst)
     }
 
+    pub fn reset_timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    pub async fn is_rejecting(&self) -> bool {
+        self.is_open() && self.last_failure_time.read().await.elapsed() <= self.timeout
+    }
+
     pub async fn should_allow_request(&self) -> bool {
         if !self.is_open.load(Ordering::SeqCst) {
             return true;
@@ -1,16 +1,51 @@
-use std::sync::Arc;
-use tokio::sync::Semaphore;
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+pub enum RequestAdmission {
+    Admitted(OwnedSemaphorePermit),
+    Rejected,
+    Shed(Duration),
+}
 
 pub struct GatewayRequestLimiter {
     pub semaphore: Arc<Semaphore>,
     pub max_concurrent: usize,
+    pub max_queue_time: Duration,
 }
 
 impl GatewayRequestLimiter {
-    pub fn new(max_concurrent: usize) -> Self {
+    pub fn new(max_concurrent: usize, max_queue_time: Duration) -> Self {
         Self {
             semaphore: Arc::new(Semaphore::new(max_concurrent)),
             max_concurrent,
+            max_queue_time,
+        }
+    }
+
+    pub async fn admit(&self) -> RequestAdmission {
+        if let Ok(permit) = Arc::clone(&self.semaphore).try_acquire_owned() {
+            return RequestAdmission::Admitted(permit);
+        }
+
+        if self.max_queue_time.is_zero() {
+            return RequestAdmission::Rejected;
+        }
+
+        let queued_at = Instant::now();
+        let acquired = tokio::time::timeout(
+            self.max_queue_time,
+            Arc::clone(&self.semaphore).acquire_owned(),
+        )
+        .await;
+
+        match acquired {
+            Ok(Ok(permit)) if queued_at.elapsed() <= self.max_queue_time => {
+                RequestAdmission::Admitted(permit)
+            }
+            _ => RequestAdmission::Shed(queued_at.elapsed()),
         }
     }
 
@@ -25,6 +60,59 @@ impl GatewayRequestLimiter {
 
 impl Default for GatewayRequestLimiter {
     fn default() -> Self {
-        Self::new(2000)
+        Self::new(2000, Duration::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn admits_while_permits_are_free() {
+        let limiter = GatewayRequestLimiter::new(1, Duration::ZERO);
+
+        assert!(matches!(
+            limiter.admit().await,
+            RequestAdmission::Admitted(_)
+        ));
+        assert_eq!(limiter.available_permits(), 1);
+    }
+
+    #[tokio::test]
+    async fn rejects_immediately_without_a_queue() {
+        let limiter = GatewayRequestLimiter::new(1, Duration::ZERO);
+        let _held = limiter.admit().await;
+
+        assert!(matches!(limiter.admit().await, RequestAdmission::Rejected));
+    }
+
+    #[tokio::test]
+    async fn sheds_after_waiting_too_long() {
+        let limiter = GatewayRequestLimiter::new(1, Duration::from_millis(20));
+        let _held = limiter.admit().await;
+
+        match limiter.admit().await {
+            RequestAdmission::Shed(waited) => assert!(waited >= Duration::from_millis(20)),
+            _ => panic!("expected the request to be shed"),
+        }
+    }
+
+    #[tokio::test]
+    async fn queued_request_gets_a_released_permit() {
+        let limiter = Arc::new(GatewayRequestLimiter::new(1, Duration::from_secs(5)));
+        let held = limiter.admit().await;
+
+        let waiter = {
+            let limiter = Arc::clone(&limiter);
+            tokio::spawn(async move { limiter.admit().await })
+        };
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        drop(held);
+
+        assert!(matches!(
+            waiter.await.unwrap(),
+            RequestAdmission::Admitted(_)
+        ));
     }
 }
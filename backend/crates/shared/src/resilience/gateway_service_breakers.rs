@@ -0,0 +1,32 @@
+use super::{GatewayCircuitBreaker, gateway_service_limiter::SERVICES};
+use std::collections::BTreeMap;
+
+pub struct GatewayServiceBreakers {
+    breakers: BTreeMap<&'static str, GatewayCircuitBreaker>,
+}
+
+impl GatewayServiceBreakers {
+    pub fn new(threshold: u64, timeout_secs: u64) -> Self {
+        let breakers = SERVICES
+            .iter()
+            .map(|(_, service)| {
+                (
+                    *service,
+                    GatewayCircuitBreaker::new(threshold, timeout_secs),
+                )
+            })
+            .collect();
+
+        Self { breakers }
+    }
+
+    pub fn get(&self, service: &str) -> Option<&GatewayCircuitBreaker> {
+        self.breakers.get(service)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, &GatewayCircuitBreaker)> {
+        self.breakers
+            .iter()
+            .map(|(service, breaker)| (*service, breaker))
+    }
+}
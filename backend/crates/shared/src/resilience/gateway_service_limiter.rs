@@ -0,0 +1,89 @@
+use crate::config::GatewayLimiterConfig;
+use std::{collections::HashMap, sync::Arc, time::Duration};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+pub(super) const SERVICES: [(&str, &str); 10] = [
+    ("auth", "auth"),
+    ("users", "user"),
+    ("roles", "role"),
+    ("cards", "card"),
+    ("merchants", "merchant"),
+    ("saldos", "saldo"),
+    ("topups", "topup"),
+    ("transactions", "transaction"),
+    ("transfers", "transfer"),
+    ("withdraws", "withdraw"),
+];
+
+pub struct GatewayServiceLimiter {
+    semaphores: HashMap<&'static str, (Arc<Semaphore>, usize)>,
+    queue_timeout: Duration,
+}
+
+impl GatewayServiceLimiter {
+    pub fn new(
+        default_limit: usize,
+        limits: &HashMap<String, usize>,
+        queue_timeout: Duration,
+    ) -> Self {
+        let semaphores = SERVICES
+            .iter()
+            .map(|(_, service)| {
+                let limit = limits.get(*service).copied().unwrap_or(default_limit);
+                (*service, (Arc::new(Semaphore::new(limit)), limit))
+            })
+            .collect();
+
+        Self {
+            semaphores,
+            queue_timeout,
+        }
+    }
+
+    pub fn from_config(config: &GatewayLimiterConfig) -> Self {
+        Self::new(
+            config.service_limit_default,
+            &config.service_limits,
+            Duration::from_millis(config.service_queue_timeout_ms),
+        )
+    }
+
+    pub fn service_for_path(path: &str) -> Option<&'static str> {
+        let segment = path.strip_prefix("/api/")?.split('/').next()?;
+        SERVICES
+            .iter()
+            .find(|(prefix, _)| *prefix == segment)
+            .map(|(_, service)| *service)
+    }
+
+    pub async fn acquire(&self, service: &str) -> Option<OwnedSemaphorePermit> {
+        let (semaphore, _) = self.semaphores.get(service)?;
+
+        if let Ok(permit) = Arc::clone(semaphore).try_acquire_owned() {
+            return Some(permit);
+        }
+
+        if self.queue_timeout.is_zero() {
+            return None;
+        }
+
+        tokio::time::timeout(self.queue_timeout, Arc::clone(semaphore).acquire_owned())
+            .await
+            .ok()?
+            .ok()
+    }
+
+    pub fn available_permits(&self, service: &str) -> usize {
+        self.semaphores
+            .get(service)
+            .map(|(semaphore, _)| semaphore.available_permits())
+            .unwrap_or(0)
+    }
+
+    pub fn limit(&self, service: &str) -> usize {
+        self.semaphores
+            .get(service)
+            .map(|(_, limit)| *limit)
+            .unwrap_or(0)
+    }
+}
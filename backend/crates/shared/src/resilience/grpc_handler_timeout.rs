@@ -0,0 +1,94 @@
+use crate::config::GrpcServerConfig;
+use opentelemetry::{KeyValue, global, metrics::Counter};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tonic::{
+    Status,
+    codegen::http::{Request, Response},
+};
+use tower::{Layer, Service};
+use tracing::warn;
+
+#[derive(Clone)]
+pub struct GrpcHandlerTimeoutLayer {
+    config: Arc<GrpcServerConfig>,
+    timeouts: Counter<u64>,
+}
+
+impl GrpcHandlerTimeoutLayer {
+    pub fn new(config: &GrpcServerConfig) -> Self {
+        let timeouts = global::meter("grpc_server")
+            .u64_counter("grpc_handler_timeouts_total")
+            .with_description("gRPC handlers aborted after exceeding their timeout")
+            .build();
+
+        Self {
+            config: Arc::new(config.clone()),
+            timeouts,
+        }
+    }
+}
+
+impl<S> Layer<S> for GrpcHandlerTimeoutLayer {
+    type Service = GrpcHandlerTimeout<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        GrpcHandlerTimeout {
+            inner,
+            config: Arc::clone(&self.config),
+            timeouts: self.timeouts.clone(),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct GrpcHandlerTimeout<S> {
+    inner: S,
+    config: Arc<GrpcServerConfig>,
+    timeouts: Counter<u64>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for GrpcHandlerTimeout<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+    ResBody: Default + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, request: Request<ReqBody>) -> Self::Future {
+        let path = request.uri().path().to_string();
+        let timeout = self.config.handler_timeout_for(&path);
+        let timeouts = self.timeouts.clone();
+        let handler = self.inner.call(request);
+
+        Box::pin(async move {
+            let Some(timeout) = timeout else {
+                return handler.await;
+            };
+
+            match tokio::time::timeout(timeout, handler).await {
+                Ok(result) => result,
+                Err(_) => {
+                    warn!("⏱️ gRPC handler {path} exceeded {timeout:?}, aborting");
+                    timeouts.add(1, &[KeyValue::new("rpc", path.clone())]);
+
+                    Ok(Status::deadline_exceeded(format!(
+                        "{path} did not complete within {timeout:?}"
+                    ))
+                    .into_http())
+                }
+            }
+        })
+    }
+}
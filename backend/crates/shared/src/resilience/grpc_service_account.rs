@@ -0,0 +1,114 @@
+use crate::config::{
+    SERVICE_ACCOUNT_HEADER, SERVICE_ACCOUNT_TOKEN_HEADER, ServiceAccountConfig,
+    ServiceAccountDecision,
+};
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::Arc,
+    task::{Context, Poll},
+};
+use tonic::{
+    Status,
+    codegen::http::{Request, Response},
+};
+use tower::{Layer, Service};
+use tracing::{info, warn};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ServiceAccountIdentity(pub String);
+
+#[derive(Clone)]
+pub struct ServiceAccountLayer {
+    config: Arc<ServiceAccountConfig>,
+}
+
+impl ServiceAccountLayer {
+    pub fn new(config: ServiceAccountConfig) -> Self {
+        Self {
+            config: Arc::new(config),
+        }
+    }
+}
+
+impl<S> Layer<S> for ServiceAccountLayer {
+    type Service = ServiceAccountAuth<S>;
+
+    fn layer(&self, inner: S) -> Self::Service {
+        ServiceAccountAuth {
+            inner,
+            config: Arc::clone(&self.config),
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct ServiceAccountAuth<S> {
+    inner: S,
+    config: Arc<ServiceAccountConfig>,
+}
+
+impl<S, ReqBody, ResBody> Service<Request<ReqBody>> for ServiceAccountAuth<S>
+where
+    S: Service<Request<ReqBody>, Response = Response<ResBody>>,
+    S::Future: Send + 'static,
+    ResBody: Default + Send + 'static,
+{
+    type Response = S::Response;
+    type Error = S::Error;
+    type Future = Pin<Box<dyn Future<Output = Result<Self::Response, Self::Error>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), Self::Error>> {
+        self.inner.poll_ready(cx)
+    }
+
+    fn call(&mut self, mut request: Request<ReqBody>) -> Self::Future {
+        let header = |name: &str| {
+            request
+                .headers()
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(str::to_string)
+        };
+        let account = header(SERVICE_ACCOUNT_HEADER);
+        let token = header(SERVICE_ACCOUNT_TOKEN_HEADER);
+
+        let path = request.uri().path().to_string();
+
+        if account.is_none() && token.is_none() {
+            if !self.config.is_protected(&path) {
+                return Box::pin(self.inner.call(request));
+            }
+
+            warn!("🚫 Rejected call to {path}: service account credential required");
+            let rejection =
+                Status::unauthenticated(format!("{path} requires a service account credential"));
+            return Box::pin(async move { Ok(rejection.into_http()) });
+        }
+
+        let account = account.unwrap_or_default();
+        let token = token.unwrap_or_default();
+
+        let rejection = match self.config.authorize(&account, &token, &path) {
+            ServiceAccountDecision::Allowed => {
+                info!("🔑 Service account '{account}' authorized for {path}");
+                request
+                    .extensions_mut()
+                    .insert(ServiceAccountIdentity(account));
+                return Box::pin(self.inner.call(request));
+            }
+            ServiceAccountDecision::Unauthenticated => {
+                warn!("🚫 Rejected service account '{account}' for {path}: invalid credential");
+                Status::unauthenticated("Invalid service account credential")
+            }
+            ServiceAccountDecision::MethodNotAllowed => {
+                warn!("🚫 Rejected service account '{account}' for {path}: method not allowed");
+                Status::permission_denied(format!(
+                    "Service account '{account}' is not allowed to call {path}"
+                ))
+            }
+        };
+
+        Box::pin(async move { Ok(rejection.into_http()) })
+    }
+}
@@ -1,9 +1,23 @@
 mod circuit_breaker;
 mod gateway_circuit_breaker;
 mod gateway_request_limiter;
+mod gateway_service_breakers;
+mod gateway_service_limiter;
+mod grpc_handler_timeout;
+mod grpc_service_account;
 mod load_monitor;
+mod restart_backoff;
+mod write_limiter;
 
 pub use self::circuit_breaker::CircuitBreaker;
 pub use self::gateway_circuit_breaker::GatewayCircuitBreaker;
-pub use self::gateway_request_limiter::GatewayRequestLimiter;
+pub use self::gateway_request_limiter::{GatewayRequestLimiter, RequestAdmission};
+pub use self::gateway_service_breakers::GatewayServiceBreakers;
+pub use self::gateway_service_limiter::GatewayServiceLimiter;
+pub use self::grpc_handler_timeout::{GrpcHandlerTimeout, GrpcHandlerTimeoutLayer};
+pub use self::grpc_service_account::{
+    ServiceAccountAuth, ServiceAccountIdentity, ServiceAccountLayer,
+};
 pub use self::load_monitor::LoadMonitor;
+pub use self::restart_backoff::RestartBackoff;
+pub use self::write_limiter::WriteLimiter;
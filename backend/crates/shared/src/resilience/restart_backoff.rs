@@ -0,0 +1,102 @@
+use crate::config::RestartBackoffConfig;
+use rand::{Rng, rng};
+use std::time::Duration;
+use tracing::info;
+
+pub struct RestartBackoff {
+    config: RestartBackoffConfig,
+    attempt: u32,
+}
+
+impl RestartBackoff {
+    pub fn new(config: RestartBackoffConfig) -> Self {
+        Self { config, attempt: 0 }
+    }
+
+    pub fn from_env() -> Self {
+        Self::new(RestartBackoffConfig::from_env())
+    }
+
+    pub fn attempt(&self) -> u32 {
+        self.attempt
+    }
+
+    pub fn next_delay(&mut self, uptime: Duration) -> Duration {
+        if self.attempt > 0 && uptime >= self.config.healthy_reset() {
+            info!(
+                "gRPC server was healthy for {uptime:?}, resetting restart backoff after {} attempts",
+                self.attempt
+            );
+            self.attempt = 0;
+        }
+
+        let base = self.base_delay();
+        self.attempt = self.attempt.saturating_add(1);
+
+        let jitter_ratio = self.config.jitter_ratio;
+        let jitter = if jitter_ratio > 0.0 {
+            rng().random_range(-jitter_ratio..=jitter_ratio)
+        } else {
+            0.0
+        };
+
+        base.mul_f64(1.0 + jitter)
+            .clamp(self.config.initial(), self.config.max())
+    }
+
+    fn base_delay(&self) -> Duration {
+        let factor = self.config.multiplier.powi(self.attempt.min(32) as i32);
+        let secs = (self.config.initial_secs as f64 * factor).min(self.config.max_secs as f64);
+        Duration::from_secs_f64(secs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(jitter_ratio: f64) -> RestartBackoffConfig {
+        RestartBackoffConfig {
+            initial_secs: 5,
+            max_secs: 60,
+            multiplier: 2.0,
+            jitter_ratio,
+            healthy_reset_secs: 30,
+        }
+    }
+
+    #[test]
+    fn doubles_up_to_the_maximum() {
+        let mut backoff = RestartBackoff::new(config(0.0));
+        let delays: Vec<u64> = (0..6)
+            .map(|_| backoff.next_delay(Duration::ZERO).as_secs())
+            .collect();
+
+        assert_eq!(delays, vec![5, 10, 20, 40, 60, 60]);
+        assert_eq!(backoff.attempt(), 6);
+    }
+
+    #[test]
+    fn resets_after_a_healthy_run() {
+        let mut backoff = RestartBackoff::new(config(0.0));
+        backoff.next_delay(Duration::ZERO);
+        backoff.next_delay(Duration::ZERO);
+
+        assert_eq!(
+            backoff.next_delay(Duration::from_secs(30)),
+            Duration::from_secs(5)
+        );
+        assert_eq!(backoff.attempt(), 1);
+    }
+
+    #[test]
+    fn jitter_stays_within_bounds() {
+        let mut backoff = RestartBackoff::new(config(0.5));
+
+        for _ in 0..50 {
+            let delay = backoff.next_delay(Duration::ZERO);
+            assert!(delay >= Duration::from_secs(5));
+            assert!(delay <= Duration::from_secs(60));
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use crate::errors::ServiceError;
+use tokio::sync::{Semaphore, SemaphorePermit, TryAcquireError};
+use tracing::warn;
+
+pub struct WriteLimiter {
+    semaphore: Semaphore,
+    limit: usize,
+}
+
+impl WriteLimiter {
+    pub fn new(limit: usize) -> Self {
+        let limit = limit.max(1);
+
+        Self {
+            semaphore: Semaphore::new(limit),
+            limit,
+        }
+    }
+
+    pub fn try_acquire(&self, operation: &str) -> Result<SemaphorePermit<'_>, ServiceError> {
+        self.semaphore.try_acquire().map_err(|e| match e {
+            TryAcquireError::NoPermits => {
+                warn!(
+                    "🚦 Shedding {operation}: {} concurrent writes already in flight",
+                    self.limit
+                );
+                ServiceError::Overloaded(format!(
+                    "Too many concurrent {operation} requests, please retry shortly"
+                ))
+            }
+            TryAcquireError::Closed => {
+                ServiceError::Overloaded(format!("{operation} write limiter is closed"))
+            }
+        })
+    }
+}
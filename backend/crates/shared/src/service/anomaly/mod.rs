@@ -0,0 +1,269 @@
+use crate::{
+    abstract_trait::{
+        anomaly::{repository::DynAnomalyRepository, service::AnomalyDetectionServiceTrait},
+        webhook::DynWebhookDispatcher,
+    },
+    config::AnomalyConfig,
+    context::shared_resources::SharedResources,
+    domain::{
+        requests::anomaly::{AnomalyMetric, AnomalyScope},
+        responses::AnomalyReport,
+    },
+    errors::ServiceError,
+    events::DomainEvent,
+    model::anomaly::DailyMetricModel,
+    observability::{Method, TracingMetrics},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::{Duration, NaiveDate, Utc};
+use opentelemetry::KeyValue;
+use std::sync::Arc;
+use tracing::{error, info, warn};
+
+pub struct AnomalyDetectionService {
+    pub repository: DynAnomalyRepository,
+    pub webhook: Option<DynWebhookDispatcher>,
+    pub config: AnomalyConfig,
+    pub tracing_metrics_core: TracingMetrics,
+}
+
+impl AnomalyDetectionService {
+    pub fn new(
+        repository: DynAnomalyRepository,
+        webhook: Option<DynWebhookDispatcher>,
+        config: AnomalyConfig,
+        shared: &SharedResources,
+    ) -> Result<Self> {
+        Ok(Self {
+            repository,
+            webhook,
+            config,
+            tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
+        })
+    }
+
+    /// Today is still in progress, so the check looks at yesterday and the
+    /// `window_days` before it.
+    pub fn checked_window(today: NaiveDate, window_days: u32) -> (NaiveDate, NaiveDate) {
+        let checked_day = today - Duration::days(1);
+        (
+            checked_day - Duration::days(window_days as i64),
+            checked_day,
+        )
+    }
+
+    pub fn split_series(series: Vec<DailyMetricModel>, checked_day: NaiveDate) -> (i64, Vec<i64>) {
+        let (current, baseline): (Vec<_>, Vec<_>) = series
+            .into_iter()
+            .partition(|point| point.day == checked_day);
+
+        let current = current.first().map(|point| point.value).unwrap_or(0);
+        let baseline = baseline.into_iter().map(|point| point.value).collect();
+
+        (current, baseline)
+    }
+
+    pub fn evaluate(baseline: &[i64], current: i64, threshold: f64) -> (f64, f64, f64, bool) {
+        if baseline.is_empty() {
+            return (0.0, 0.0, 0.0, false);
+        }
+
+        let n = baseline.len() as f64;
+        let mean = baseline.iter().map(|v| *v as f64).sum::<f64>() / n;
+        let variance = baseline
+            .iter()
+            .map(|v| (*v as f64 - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        let std_dev = variance.sqrt();
+
+        let z_score = (current as f64 - mean) / std_dev.max(1.0);
+
+        (mean, std_dev, z_score, z_score.abs() >= threshold)
+    }
+
+    async fn emit_alert(&self, report: &AnomalyReport) {
+        warn!(
+            scope = %report.scope,
+            metric = %report.metric,
+            current = report.current,
+            baseline_mean = report.baseline_mean,
+            z_score = report.z_score,
+            threshold = report.threshold,
+            "🚨 Anomaly detected: {} {} deviates from the {}-day baseline",
+            report.scope,
+            report.metric,
+            report.window_days
+        );
+
+        let (Some(webhook), Some(url)) = (&self.webhook, &self.config.webhook_url) else {
+            return;
+        };
+
+        let payload = match serde_json::to_value(report) {
+            Ok(payload) => payload,
+            Err(e) => {
+                error!("❌ Failed to serialize anomaly report: {e}");
+                return;
+            }
+        };
+
+        let event = DomainEvent::new("stats.anomaly_detected", payload);
+
+        if let Err(e) = webhook.dispatch(url, &event).await {
+            error!("❌ Failed to dispatch anomaly alert: {e}");
+        }
+    }
+}
+
+#[async_trait]
+impl AnomalyDetectionServiceTrait for AnomalyDetectionService {
+    async fn detect_anomaly(
+        &self,
+        scope: AnomalyScope,
+        metric: AnomalyMetric,
+    ) -> Result<AnomalyReport, ServiceError> {
+        info!("🔎 Checking {scope} {metric} against rolling baseline");
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "detect_anomaly",
+            vec![
+                KeyValue::new("component", "anomaly"),
+                KeyValue::new("operation", "detect_anomaly"),
+                KeyValue::new("scope", scope.to_string()),
+                KeyValue::new("metric", metric.to_string()),
+            ],
+        );
+
+        let (window_start, checked_day) =
+            Self::checked_window(Utc::now().date_naive(), self.config.window_days);
+
+        let series = match self
+            .repository
+            .get_daily_series(scope, metric, window_start, checked_day)
+            .await
+        {
+            Ok(series) => series,
+            Err(e) => {
+                error!("❌ Failed to load {scope} {metric} series: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        &format!("Failed to load daily series: {e:?}"),
+                    )
+                    .await;
+                return Err(ServiceError::Repo(e));
+            }
+        };
+
+        let (current, baseline) = Self::split_series(series, checked_day);
+
+        let (baseline_mean, baseline_std_dev, z_score, is_anomaly) =
+            Self::evaluate(&baseline, current, self.config.z_threshold);
+
+        let report = AnomalyReport {
+            scope,
+            metric,
+            period: checked_day.to_string(),
+            current,
+            baseline_mean,
+            baseline_std_dev,
+            z_score,
+            threshold: self.config.z_threshold,
+            window_days: self.config.window_days,
+            is_anomaly,
+        };
+
+        if report.is_anomaly {
+            self.emit_alert(&report).await;
+        } else {
+            info!("✅ {scope} {metric} within baseline (z = {z_score:.2})");
+        }
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Anomaly check completed")
+            .await;
+
+        Ok(report)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2025, 8, d).unwrap()
+    }
+
+    #[test]
+    fn window_ends_on_the_last_completed_day() {
+        assert_eq!(
+            AnomalyDetectionService::checked_window(day(15), 7),
+            (day(7), day(14))
+        );
+    }
+
+    #[test]
+    fn splits_checked_day_from_baseline() {
+        let series = vec![
+            DailyMetricModel {
+                day: day(12),
+                value: 10,
+            },
+            DailyMetricModel {
+                day: day(13),
+                value: 20,
+            },
+            DailyMetricModel {
+                day: day(14),
+                value: 90,
+            },
+        ];
+
+        assert_eq!(
+            AnomalyDetectionService::split_series(series, day(14)),
+            (90, vec![10, 20])
+        );
+        assert_eq!(
+            AnomalyDetectionService::split_series(Vec::new(), day(14)),
+            (0, Vec::new())
+        );
+    }
+
+    #[test]
+    fn empty_baseline_is_never_an_anomaly() {
+        assert_eq!(
+            AnomalyDetectionService::evaluate(&[], 1_000, 3.0),
+            (0.0, 0.0, 0.0, false)
+        );
+    }
+
+    #[test]
+    fn flags_values_beyond_the_threshold() {
+        let baseline = [100, 110, 90, 100];
+
+        let (mean, std_dev, z_score, is_anomaly) =
+            AnomalyDetectionService::evaluate(&baseline, 200, 3.0);
+        assert_eq!(mean, 100.0);
+        assert!((std_dev - 50f64.sqrt()).abs() < 1e-9);
+        assert!(z_score > 3.0);
+        assert!(is_anomaly);
+
+        let (_, _, _, is_anomaly) = AnomalyDetectionService::evaluate(&baseline, 105, 3.0);
+        assert!(!is_anomaly);
+    }
+
+    #[test]
+    fn flat_baseline_uses_a_unit_deviation() {
+        let (_, std_dev, z_score, is_anomaly) =
+            AnomalyDetectionService::evaluate(&[50, 50, 50], 52, 3.0);
+
+        assert_eq!(std_dev, 0.0);
+        assert_eq!(z_score, 2.0);
+        assert!(!is_anomaly);
+    }
+}
@@ -0,0 +1,28 @@
+use crate::{
+    abstract_trait::email_verification::VerificationMailerTrait, config::LogPrivacyConfig,
+    errors::ServiceError,
+};
+use async_trait::async_trait;
+use tracing::info;
+
+#[derive(Debug, Clone)]
+pub struct LoggingVerificationMailer {
+    log_privacy: LogPrivacyConfig,
+}
+
+impl LoggingVerificationMailer {
+    pub fn new(log_privacy: LogPrivacyConfig) -> Self {
+        Self { log_privacy }
+    }
+}
+
+#[async_trait]
+impl VerificationMailerTrait for LoggingVerificationMailer {
+    async fn send_verification(&self, email: &str, _token: &str) -> Result<(), ServiceError> {
+        info!(
+            "📧 Sending verification email to {}",
+            self.log_privacy.email(email)
+        );
+        Ok(())
+    }
+}
@@ -1,34 +1,65 @@
+mod mailer;
+
+pub use self::mailer::LoggingVerificationMailer;
+
 use crate::{
     abstract_trait::{
         auth::service::AuthServiceTrait,
+        email_verification::{DynEmailVerificationRepository, DynVerificationMailer},
+        events::DynEventPublisher,
         hashing::DynHashing,
         jwt::DynJwtService,
         refresh_token::command::DynRefreshTokenCommandRepository,
         role::repository::query::DynRoleQueryRepository,
         token::DynTokenService,
-        user::repository::{command::DynUserCommandRepository, query::DynUserQueryRepository},
+        user::repository::{
+            command::DynUserCommandRepository, credential::DynUserCredentialRepository,
+            query::DynUserQueryRepository,
+        },
         user_roles::DynUserRoleCommandRepository,
     },
-    cache::CacheStore,
+    cache::{CacheStore, LocalLoginLimiter},
+    config::{
+        EmailDomainDenylist, EmailNormalizationConfig, EmailVerificationConfig, LogPrivacyConfig,
+    },
     domain::{
         requests::{
-            auth::{AuthRequest, RegisterRequest},
+            auth::{AuthRequest, ChangePasswordRequest, RegisterRequest},
             refresh_token::UpdateRefreshToken,
             user::CreateUserRequest,
             user_role::CreateUserRoleRequest,
         },
-        responses::{ApiResponse, TokenResponse, UserResponse},
+        responses::{ApiResponse, TokenIntrospectionResponse, TokenResponse, UserResponse},
     },
-    errors::ServiceError,
+    errors::{ServiceError, format_validation_errors},
+    events::{DomainEvent, EVENT_USER_REGISTERED},
     observability::{Method, TracingMetrics},
+    utils::generate_api_key,
 };
 use anyhow::Result;
 use async_trait::async_trait;
-use chrono::Duration;
+use chrono::{Duration, Utc};
 use opentelemetry::KeyValue;
+use sha2::{Digest, Sha256};
 use std::sync::Arc;
+use tokio::{sync::Semaphore, task::JoinSet};
 use tonic::Request;
 use tracing::{error, info, warn};
+use validator::Validate;
+
+const MAX_LOGIN_ATTEMPTS: usize = 5;
+const LOGIN_LOCKOUT_MINUTES: i64 = 15;
+const LOCAL_LOGIN_LIMITER_CAPACITY: usize = 10_000;
+const MAX_INTROSPECT_BATCH: usize = 100;
+const INTROSPECT_CONCURRENCY: usize = 8;
+/// Matches the access token lifetime; older tokens have expired by then.
+const TOKEN_REVOCATION_TTL_MINUTES: i64 = 60;
+
+fn token_revocation_key(user_id: i64) -> String {
+    format!("auth:tokens_revoked_before:{user_id}")
+}
+
+pub const DEFAULT_ROLE_NAME: &str = "ROLE_ADMIN";
 
 #[derive(Clone)]
 pub struct AuthService {
@@ -40,8 +71,17 @@ pub struct AuthService {
     refresh_command: DynRefreshTokenCommandRepository,
     jwt_config: DynJwtService,
     token: DynTokenService,
+    credential: DynUserCredentialRepository,
+    verification: DynEmailVerificationRepository,
+    mailer: DynVerificationMailer,
+    verification_config: EmailVerificationConfig,
+    email_denylist: Arc<EmailDomainDenylist>,
+    email_normalization: EmailNormalizationConfig,
+    log_privacy: LogPrivacyConfig,
+    events: DynEventPublisher,
     tracing_metrics_core: TracingMetrics,
     cache_store: Arc<CacheStore>,
+    login_limiter: Arc<LocalLoginLimiter>,
 }
 
 impl std::fmt::Debug for AuthService {
@@ -55,6 +95,14 @@ impl std::fmt::Debug for AuthService {
             .field("user_role", &"DynUserRoleService")
             .field("refresh_command", &"DynRefreshTokenCommandService")
             .field("token", &"DynTokenService")
+            .field("credential", &"DynUserCredentialRepository")
+            .field("verification", &"DynEmailVerificationRepository")
+            .field("mailer", &"DynVerificationMailer")
+            .field("verification_config", &self.verification_config)
+            .field("email_denylist", &self.email_denylist.len())
+            .field("email_normalization", &self.email_normalization)
+            .field("log_privacy", &self.log_privacy)
+            .field("events", &"DynEventPublisher")
             .finish()
     }
 }
@@ -68,6 +116,14 @@ pub struct AuthServiceDeps {
     pub refresh_command: DynRefreshTokenCommandRepository,
     pub jwt_config: DynJwtService,
     pub token: DynTokenService,
+    pub credential: DynUserCredentialRepository,
+    pub verification: DynEmailVerificationRepository,
+    pub mailer: DynVerificationMailer,
+    pub verification_config: EmailVerificationConfig,
+    pub email_denylist: Arc<EmailDomainDenylist>,
+    pub email_normalization: EmailNormalizationConfig,
+    pub log_privacy: LogPrivacyConfig,
+    pub events: DynEventPublisher,
     pub tracing_metrics_core: TracingMetrics,
     pub cache_store: Arc<CacheStore>,
 }
@@ -83,6 +139,14 @@ impl AuthService {
             refresh_command,
             jwt_config,
             token,
+            credential,
+            verification,
+            mailer,
+            verification_config,
+            email_denylist,
+            email_normalization,
+            log_privacy,
+            events,
             cache_store,
             tracing_metrics_core,
         } = deps;
@@ -96,10 +160,35 @@ impl AuthService {
             refresh_command,
             jwt_config,
             token,
+            credential,
+            verification,
+            mailer,
+            verification_config,
+            email_denylist,
+            email_normalization,
+            log_privacy,
+            events,
             cache_store,
             tracing_metrics_core,
+            login_limiter: Arc::new(LocalLoginLimiter::new(
+                std::time::Duration::from_secs(LOGIN_LOCKOUT_MINUTES as u64 * 60),
+                LOCAL_LOGIN_LIMITER_CAPACITY,
+            )),
         })
     }
+
+    async fn record_failed_login(&self, key: &str, current_attempts: usize) {
+        let new_attempts = (current_attempts + 1) as i32;
+
+        self.cache_store
+            .set_to_cache(key, &new_attempts, Duration::minutes(LOGIN_LOCKOUT_MINUTES))
+            .await;
+        self.login_limiter.record_failure(key, MAX_LOGIN_ATTEMPTS);
+    }
+
+    fn hash_verification_token(token: &str) -> String {
+        format!("{:x}", Sha256::digest(token.as_bytes()))
+    }
 }
 
 #[async_trait]
@@ -108,17 +197,20 @@ impl AuthServiceTrait for AuthService {
         &self,
         req: &RegisterRequest,
     ) -> Result<ApiResponse<UserResponse>, ServiceError> {
-        info!(
-            "🆕 New user registration attempt with email: {}",
-            &req.email.clone(),
-        );
+        let req = &RegisterRequest {
+            email: self.email_normalization.normalize(&req.email),
+            ..req.clone()
+        };
+
+        let log_email = self.log_privacy.email(&req.email);
+        info!("🆕 New user registration attempt with email: {log_email}");
 
         let method = Method::Post;
         let tracing_ctx = self.tracing_metrics_core.start_tracing(
             "RegisterUser",
             vec![
                 KeyValue::new("component", "auth"),
-                KeyValue::new("user.email", req.email.clone()),
+                KeyValue::new("user.email", log_email.clone()),
             ],
         );
 
@@ -126,13 +218,21 @@ impl AuthServiceTrait for AuthService {
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
+        if self.email_denylist.is_denied(&req.email) {
+            warn!("🚫 [REGISTER] Email domain is on the denylist | Email: {log_email}");
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method, "Email domain denied")
+                .await;
+            return Err(ServiceError::Custom(
+                "Registration with this email domain is not allowed".to_string(),
+            ));
+        }
+
         let cache_key = format!("auth:registered:{}", req.email);
 
         if let Some(cached_user) = self.cache_store.get_from_cache(&cache_key).await {
-            let log_msg = format!(
-                "✅ [REGISTER] Cache hit! User already registered | Email: {}",
-                req.email
-            );
+            let log_msg =
+                format!("✅ [REGISTER] Cache hit! User already registered | Email: {log_email}");
             info!("{log_msg}");
 
             self.tracing_metrics_core
@@ -162,7 +262,7 @@ impl AuthServiceTrait for AuthService {
 
         if existing_user.is_some() {
             let msg = "Email already exists";
-            error!("❌ [REGISTER] Email already taken | Email: {}", req.email);
+            error!("❌ [REGISTER] Email already taken | Email: {log_email}");
             self.tracing_metrics_core
                 .complete_tracing_error(&tracing_ctx, method, msg)
                 .await;
@@ -183,7 +283,6 @@ impl AuthServiceTrait for AuthService {
             }
         };
 
-        const DEFAULT_ROLE_NAME: &str = "ROLE_ADMIN";
         let role = match self.role.find_by_name(DEFAULT_ROLE_NAME).await {
             Ok(Some(role)) => role,
             Ok(None) => {
@@ -205,6 +304,7 @@ impl AuthServiceTrait for AuthService {
             password: hashed_password,
             email: req.email.clone(),
             confirm_password: req.confirm_password.clone(),
+            must_change_password: false,
         };
 
         let new_user = match self.command.create(&new_request).await {
@@ -241,9 +341,14 @@ impl AuthServiceTrait for AuthService {
 
         let user_response = UserResponse::from(new_user);
 
+        self.events.emit(DomainEvent::new(
+            EVENT_USER_REGISTERED,
+            serde_json::json!({ "user_id": user_response.id }),
+        ));
+
         info!(
-            "✅ User registered successfully: {} {} ({})",
-            user_response.firstname, user_response.lastname, user_response.email
+            "✅ User registered successfully: {} {} ({log_email})",
+            user_response.firstname, user_response.lastname
         );
 
         self.tracing_metrics_core
@@ -260,16 +365,17 @@ impl AuthServiceTrait for AuthService {
         &self,
         req: &AuthRequest,
     ) -> Result<ApiResponse<TokenResponse>, ServiceError> {
-        let email = req.email.clone();
+        let email = self.email_normalization.normalize(&req.email);
 
-        info!("🔐 Incoming login request for user: {}", &req.email);
+        let log_email = self.log_privacy.email(&email);
+        info!("🔐 Incoming login request for user: {log_email}");
 
         let method = Method::Post;
         let tracing_ctx = self.tracing_metrics_core.start_tracing(
             "Login",
             vec![
                 KeyValue::new("component", "auth"),
-                KeyValue::new("user.email", email.to_string()),
+                KeyValue::new("user.email", log_email.clone()),
             ],
         );
 
@@ -278,15 +384,21 @@ impl AuthServiceTrait for AuthService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         let failed_attempts_key = format!("auth:login_attempts:{email}");
-        let current_attempts = self
+        let current_attempts = match self
             .cache_store
-            .get_from_cache::<i32>(&failed_attempts_key)
+            .try_get_from_cache::<i32>(&failed_attempts_key)
             .await
-            .unwrap_or(0);
+        {
+            Ok(cached) => cached.unwrap_or(0).max(0) as usize,
+            Err(e) => {
+                warn!("⚠️ Login attempt counter unavailable, using local limiter: {e:?}");
+                self.login_limiter.attempts(&failed_attempts_key)
+            }
+        };
 
-        if current_attempts >= 5 {
+        if current_attempts >= MAX_LOGIN_ATTEMPTS {
             let msg = "Too many failed login attempts (rate limited)";
-            warn!("❌ {}: {}", msg, email);
+            warn!("❌ {msg}: {log_email}");
             self.tracing_metrics_core
                 .complete_tracing_error(&tracing_ctx, method, msg)
                 .await;
@@ -298,12 +410,9 @@ impl AuthServiceTrait for AuthService {
         let user = match self.query.find_by_email(email.clone()).await {
             Ok(Some(user)) => user,
             Ok(None) => {
-                error!("❌ User not found: {email}");
+                error!("❌ User not found: {log_email}");
 
-                let new_attempts = current_attempts + 1;
-
-                self.cache_store
-                    .set_to_cache(&failed_attempts_key, &new_attempts, Duration::minutes(15))
+                self.record_failed_login(&failed_attempts_key, current_attempts)
                     .await;
 
                 self.tracing_metrics_core
@@ -326,12 +435,9 @@ impl AuthServiceTrait for AuthService {
             .await
             .is_err()
         {
-            error!("❌ Invalid password for user: {email}");
-
-            let new_attempts = current_attempts + 1;
+            error!("❌ Invalid password for user: {log_email}");
 
-            self.cache_store
-                .set_to_cache(&failed_attempts_key, &new_attempts, Duration::minutes(15))
+            self.record_failed_login(&failed_attempts_key, current_attempts)
                 .await;
 
             self.tracing_metrics_core
@@ -343,6 +449,81 @@ impl AuthServiceTrait for AuthService {
         self.cache_store
             .delete_from_cache(&failed_attempts_key)
             .await;
+        self.login_limiter.reset(&failed_attempts_key);
+
+        if self.verification_config.require_verified_login {
+            match self.verification.is_verified(user.user_id as i32).await {
+                Ok(true) => {}
+                Ok(false) => {
+                    warn!("🚫 Login blocked, email not verified: {log_email}");
+                    self.tracing_metrics_core
+                        .complete_tracing_error(&tracing_ctx, method, "Email not verified")
+                        .await;
+                    return Err(ServiceError::Forbidden(
+                        "Email address has not been verified".to_string(),
+                    ));
+                }
+                Err(e) => {
+                    error!("❌ Failed to check verification status: {:?}", e);
+                    self.tracing_metrics_core
+                        .complete_tracing_error(&tracing_ctx, method, "Database error")
+                        .await;
+                    return Err(ServiceError::Repo(e));
+                }
+            }
+        }
+
+        let must_change_password = match self
+            .credential
+            .must_change_password(user.user_id as i32)
+            .await
+        {
+            Ok(flag) => flag,
+            Err(e) => {
+                error!("❌ Failed to check password change flag: {:?}", e);
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Database error")
+                    .await;
+                return Err(ServiceError::Repo(e));
+            }
+        };
+
+        if must_change_password {
+            let access_token = match self
+                .token
+                .create_password_change_token(user.user_id as i32)
+                .await
+            {
+                Ok(token) => token,
+                Err(e) => {
+                    error!("❌ Failed to generate password change token: {:?}", e);
+                    self.tracing_metrics_core
+                        .complete_tracing_error(
+                            &tracing_ctx,
+                            method,
+                            "Failed to generate password change token",
+                        )
+                        .await;
+                    return Err(e);
+                }
+            };
+
+            info!("🔑 Password change required for email: {log_email}");
+
+            self.tracing_metrics_core
+                .complete_tracing_success(&tracing_ctx, method, "Password change required")
+                .await;
+
+            return Ok(ApiResponse {
+                status: "success".to_string(),
+                message: "Password change required".to_string(),
+                data: TokenResponse {
+                    access_token,
+                    refresh_token: String::new(),
+                    password_change_required: true,
+                },
+            });
+        }
 
         let access_token = match self.token.create_access_token(user.user_id as i32).await {
             Ok(token) => token,
@@ -377,9 +558,10 @@ impl AuthServiceTrait for AuthService {
         let token = TokenResponse {
             access_token,
             refresh_token,
+            password_change_required: false,
         };
 
-        info!("✅ Login successful for email: {email}");
+        info!("✅ Login successful for email: {log_email}");
 
         self.tracing_metrics_core
             .complete_tracing_success(&tracing_ctx, method, "Login successful")
@@ -461,8 +643,8 @@ impl AuthServiceTrait for AuthService {
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
-        let user_id = match self.jwt_config.verify_token(token, "refresh") {
-            Ok(uid) => uid,
+        let (user_id, auth_time) = match self.jwt_config.verify_claims(token, "refresh") {
+            Ok(claims) => (claims.user_id, claims.auth_time),
             Err(ServiceError::TokenExpired) => {
                 let _ = self.refresh_command.delete_token(token.to_string()).await;
 
@@ -498,7 +680,11 @@ impl AuthServiceTrait for AuthService {
             return Err(ServiceError::from(e));
         }
 
-        let access_token = match self.token.create_access_token(user_id as i32).await {
+        let access_token = match self
+            .token
+            .renew_access_token(user_id as i32, auth_time)
+            .await
+        {
             Ok(token) => token,
             Err(e) => {
                 error!("❌ Failed to generate access token: {:?}", e);
@@ -513,7 +699,11 @@ impl AuthServiceTrait for AuthService {
             }
         };
 
-        let refresh_token = match self.token.create_refresh_token(user_id as i32).await {
+        let refresh_token = match self
+            .token
+            .renew_refresh_token(user_id as i32, auth_time)
+            .await
+        {
             Ok(token) => token,
             Err(e) => {
                 error!("❌ Failed to generate refresh token: {:?}", e);
@@ -564,7 +754,446 @@ impl AuthServiceTrait for AuthService {
             data: TokenResponse {
                 access_token,
                 refresh_token,
+                password_change_required: false,
+            },
+        })
+    }
+
+    async fn send_verification(&self, user_id: i32) -> Result<ApiResponse<bool>, ServiceError> {
+        info!("📧 Sending email verification for user_id={user_id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "SendVerification",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("user.id", user_id.to_string()),
+            ],
+        );
+
+        let user = match self.query.find_by_id(user_id).await {
+            Ok(user) => user,
+            Err(e) => {
+                error!("❌ Failed to fetch user {user_id}: {:?}", e);
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Database error")
+                    .await;
+                return Err(ServiceError::Repo(e));
+            }
+        };
+
+        match self.verification.is_verified(user_id).await {
+            Ok(true) => {
+                let msg = "Email address is already verified";
+                warn!("⚠️ {msg} for user_id={user_id}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, msg)
+                    .await;
+                return Err(ServiceError::Validation(vec![msg.to_string()]));
+            }
+            Ok(false) => {}
+            Err(e) => {
+                error!("❌ Failed to check verification status: {:?}", e);
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Database error")
+                    .await;
+                return Err(ServiceError::Repo(e));
+            }
+        }
+
+        let token = generate_api_key();
+        let expires_at = (chrono::Utc::now()
+            + Duration::minutes(self.verification_config.token_ttl_minutes))
+        .naive_utc();
+
+        if let Err(e) = self
+            .verification
+            .create_token(user_id, &Self::hash_verification_token(&token), expires_at)
+            .await
+        {
+            error!("❌ Failed to store verification token: {:?}", e);
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method, "Failed to store token")
+                .await;
+            return Err(ServiceError::Repo(e));
+        }
+
+        if let Err(e) = self.mailer.send_verification(&user.email, &token).await {
+            error!("❌ Failed to send verification email: {:?}", e);
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method, "Failed to send email")
+                .await;
+            return Err(e);
+        }
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Verification email sent")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "verification email sent".into(),
+            data: true,
+        })
+    }
+
+    async fn resend_verification(&self, email: &str) -> Result<ApiResponse<bool>, ServiceError> {
+        let email = self.email_normalization.normalize(email);
+        let log_email = self.log_privacy.email(&email);
+        info!("📧 Verification resend requested for {log_email}");
+
+        let accepted = ApiResponse {
+            status: "success".into(),
+            message: "if the address belongs to an unverified account, a verification email has been sent".into(),
+            data: true,
+        };
+
+        let user = match self.query.find_by_email(email).await {
+            Ok(Some(user)) => user,
+            Ok(None) => {
+                info!("📧 No account for {log_email}, skipping verification resend");
+                return Ok(accepted);
+            }
+            Err(e) => {
+                error!("❌ Failed to look up user for verification resend: {:?}", e);
+                return Err(ServiceError::Repo(e));
+            }
+        };
+
+        match self.verification.is_verified(user.user_id).await {
+            Ok(true) => return Ok(accepted),
+            Ok(false) => {}
+            Err(e) => {
+                error!("❌ Failed to check verification status: {:?}", e);
+                return Err(ServiceError::Repo(e));
+            }
+        }
+
+        let cooldown = Duration::seconds(self.verification_config.resend_cooldown_secs);
+        match self
+            .verification
+            .latest_token_created_at(user.user_id)
+            .await
+        {
+            Ok(Some(created_at)) if chrono::Utc::now().naive_utc() - created_at < cooldown => {
+                warn!(
+                    "⚠️ Verification resend for user_id={} is within the cooldown, skipping",
+                    user.user_id
+                );
+                return Ok(accepted);
+            }
+            Ok(_) => {}
+            Err(e) => {
+                error!("❌ Failed to check the last verification token: {:?}", e);
+                return Err(ServiceError::Repo(e));
+            }
+        }
+
+        self.send_verification(user.user_id).await?;
+
+        Ok(accepted)
+    }
+
+    async fn verify_email(&self, token: &str) -> Result<ApiResponse<bool>, ServiceError> {
+        info!("📧 Verifying email token");
+
+        let method = Method::Post;
+        let tracing_ctx = self
+            .tracing_metrics_core
+            .start_tracing("VerifyEmail", vec![KeyValue::new("component", "auth")]);
+
+        let token_hash = Self::hash_verification_token(token);
+
+        let stored = match self.verification.find_by_token_hash(&token_hash).await {
+            Ok(stored) => stored,
+            Err(e) => {
+                error!("❌ Failed to fetch verification token: {:?}", e);
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Database error")
+                    .await;
+                return Err(ServiceError::Repo(e));
+            }
+        };
+
+        let Some(stored) = stored else {
+            warn!("🚫 Unknown verification token");
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method, "Invalid token")
+                .await;
+            return Err(ServiceError::Validation(vec![
+                "invalid verification token".to_string(),
+            ]));
+        };
+
+        if stored.used_at.is_some() {
+            warn!(
+                "🚫 Verification token reused for user_id={}",
+                stored.user_id
+            );
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method, "Token already used")
+                .await;
+            return Err(ServiceError::Validation(vec![
+                "verification token has already been used".to_string(),
+            ]));
+        }
+
+        if stored.expires_at <= chrono::Utc::now().naive_utc() {
+            warn!(
+                "⏰ Verification token expired for user_id={}",
+                stored.user_id
+            );
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method, "Token expired")
+                .await;
+            return Err(ServiceError::TokenExpired);
+        }
+
+        let user_id = match self.verification.consume_token(&token_hash).await {
+            Ok(Some(user_id)) => user_id,
+            Ok(None) => {
+                warn!("🚫 Verification token consumed concurrently");
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Token already used")
+                    .await;
+                return Err(ServiceError::Validation(vec![
+                    "verification token has already been used".to_string(),
+                ]));
+            }
+            Err(e) => {
+                error!("❌ Failed to consume verification token: {:?}", e);
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Database error")
+                    .await;
+                return Err(ServiceError::Repo(e));
+            }
+        };
+
+        self.cache_store
+            .delete_from_cache(&format!("auth:getme:{user_id}"))
+            .await;
+
+        info!("✅ Email verified for user_id={user_id}");
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Email verified")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "email verified successfully".into(),
+            data: true,
+        })
+    }
+
+    async fn change_password(
+        &self,
+        user_id: i32,
+        req: &ChangePasswordRequest,
+    ) -> Result<ApiResponse<TokenResponse>, ServiceError> {
+        info!("🔑 Changing password for user_id={user_id}");
+
+        if let Err(validation_errors) = req.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Validation(vec![error_msg]));
+        }
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "ChangePassword",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("user.id", user_id.to_string()),
+            ],
+        );
+
+        let user = match self.query.find_by_id(user_id).await {
+            Ok(user) => user,
+            Err(e) => {
+                error!("❌ Failed to fetch user {user_id}: {:?}", e);
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Database error")
+                    .await;
+                return Err(ServiceError::Repo(e));
+            }
+        };
+
+        if self
+            .hashing
+            .compare_password(&user.password, &req.current_password)
+            .await
+            .is_err()
+        {
+            error!("❌ Invalid current password for user_id={user_id}");
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method, "Invalid password")
+                .await;
+            return Err(ServiceError::InvalidCredentials);
+        }
+
+        if req.new_password == req.current_password {
+            let msg = "New password must differ from the current password";
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method, msg)
+                .await;
+            return Err(ServiceError::Validation(vec![msg.to_string()]));
+        }
+
+        let hashed_password = match self.hashing.hash_password(&req.new_password).await {
+            Ok(hash) => hash,
+            Err(e) => {
+                error!("❌ Failed to hash password: {:?}", e);
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to hash password")
+                    .await;
+                return Err(ServiceError::InternalServerError(
+                    "Failed to hash password".into(),
+                ));
+            }
+        };
+
+        if let Err(e) = self
+            .credential
+            .update_password(user_id, &hashed_password)
+            .await
+        {
+            error!("❌ Failed to update password: {:?}", e);
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method, "Failed to update password")
+                .await;
+            return Err(ServiceError::Repo(e));
+        }
+
+        self.cache_store
+            .delete_from_cache(&format!("auth:getme:{user_id}"))
+            .await;
+
+        self.cache_store
+            .set_to_cache(
+                &token_revocation_key(user_id as i64),
+                &(Utc::now().timestamp() as usize),
+                Duration::minutes(TOKEN_REVOCATION_TTL_MINUTES),
+            )
+            .await;
+
+        let access_token = match self.token.create_access_token(user_id).await {
+            Ok(token) => token,
+            Err(e) => {
+                error!("❌ Failed to generate access token: {:?}", e);
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to generate access token")
+                    .await;
+                return Err(e);
+            }
+        };
+
+        let refresh_token = match self.token.create_refresh_token(user_id).await {
+            Ok(token) => token,
+            Err(e) => {
+                error!("❌ Failed to generate refresh token: {:?}", e);
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        "Failed to generate refresh token",
+                    )
+                    .await;
+                return Err(e);
+            }
+        };
+
+        info!("✅ Password changed for user_id={user_id}");
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Password changed")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "password changed successfully".into(),
+            data: TokenResponse {
+                access_token,
+                refresh_token,
+                password_change_required: false,
             },
         })
     }
+
+    async fn batch_introspect(
+        &self,
+        tokens: Vec<String>,
+    ) -> Result<ApiResponse<Vec<TokenIntrospectionResponse>>, ServiceError> {
+        if tokens.is_empty() || tokens.len() > MAX_INTROSPECT_BATCH {
+            return Err(ServiceError::Validation(vec![format!(
+                "tokens must contain between 1 and {MAX_INTROSPECT_BATCH} entries"
+            )]));
+        }
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "BatchIntrospect",
+            vec![
+                KeyValue::new("component", "auth"),
+                KeyValue::new("batch.size", tokens.len() as i64),
+            ],
+        );
+
+        let limiter = Arc::new(Semaphore::new(INTROSPECT_CONCURRENCY));
+        let mut tasks = JoinSet::new();
+
+        for (index, token) in tokens.into_iter().enumerate() {
+            let jwt_config = Arc::clone(&self.jwt_config);
+            let cache_store = Arc::clone(&self.cache_store);
+            let limiter = Arc::clone(&limiter);
+
+            tasks.spawn(async move {
+                let _permit = limiter.acquire_owned().await;
+
+                let Ok(claims) = jwt_config.verify_claims(&token, "access") else {
+                    return (index, TokenIntrospectionResponse::default());
+                };
+
+                let revoked = match cache_store
+                    .try_get_from_cache::<usize>(&token_revocation_key(claims.user_id))
+                    .await
+                {
+                    Ok(revoked_before) => revoked_before.is_some_and(|before| claims.iat < before),
+                    Err(e) => {
+                        error!("❌ Failed to check token revocation: {e:?}");
+                        true
+                    }
+                };
+
+                let introspection = if revoked {
+                    TokenIntrospectionResponse::default()
+                } else {
+                    TokenIntrospectionResponse::from_claims(&claims)
+                };
+
+                (index, introspection)
+            });
+        }
+
+        let mut results = vec![TokenIntrospectionResponse::default(); tasks.len()];
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok((index, introspection)) => results[index] = introspection,
+                Err(e) => error!("❌ Token introspection task failed: {e:?}"),
+            }
+        }
+
+        let active = results.iter().filter(|r| r.active).count();
+        info!("🔎 Introspected {} tokens, {active} active", results.len());
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Tokens introspected")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "tokens introspected successfully".into(),
+            data: results,
+        })
+    }
 }
@@ -7,16 +7,22 @@ use crate::{
         user::repository::query::DynUserQueryRepository,
     },
     cache::CacheStore,
+    config::CardExpiryConfig,
     context::shared_resources::SharedResources,
     domain::{
-        requests::card::{CreateCardRequest, UpdateCardRequest},
-        responses::{ApiResponse, CardResponse, CardResponseDeleteAt},
+        requests::card::{CreateCardRequest, RestoreCardsByFilter, UpdateCardRequest},
+        responses::{
+            ApiResponse, BulkResult, CardFreezeResponse, CardReissueResponse, CardResponse,
+            CardResponseDeleteAt,
+        },
     },
-    errors::{ServiceError, format_validation_errors},
+    errors::{RepositoryError, ServiceError, format_validation_errors},
     observability::{Method, TracingMetrics},
+    utils::mask_card_number,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::{NaiveDate, Utc};
 use opentelemetry::KeyValue;
 use std::sync::Arc;
 use tonic::Request;
@@ -27,6 +33,7 @@ pub struct CardCommandService {
     pub user_query: DynUserQueryRepository,
     pub query: DynCardQueryRepository,
     pub command: DynCardCommandRepository,
+    pub expiry: CardExpiryConfig,
     pub tracing_metrics_core: TracingMetrics,
     pub cache_store: Arc<CacheStore>,
 }
@@ -35,6 +42,7 @@ pub struct CardCommandServiceDeps {
     pub user_query: DynUserQueryRepository,
     pub query: DynCardQueryRepository,
     pub command: DynCardCommandRepository,
+    pub expiry: CardExpiryConfig,
 }
 
 impl CardCommandService {
@@ -43,16 +51,94 @@ impl CardCommandService {
             user_query,
             query,
             command,
+            expiry,
         } = deps;
 
         Ok(Self {
             user_query,
             query,
             command,
+            expiry,
             tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
             cache_store: Arc::clone(&shared.cache_store),
         })
     }
+
+    fn check_expire_date(&self, expire_date: NaiveDate) -> Result<(), ServiceError> {
+        self.expiry
+            .check(expire_date, Utc::now().date_naive())
+            .map_err(|msg| {
+                error!("Validation failed: {msg}");
+                ServiceError::Custom(msg)
+            })
+    }
+
+    async fn set_frozen(
+        &self,
+        id: i32,
+        frozen: bool,
+    ) -> Result<ApiResponse<CardFreezeResponse>, ServiceError> {
+        if id <= 0 {
+            return Err(ServiceError::Validation(vec![
+                "card_id must be greater than 0".to_string(),
+            ]));
+        }
+
+        let operation = if frozen { "freeze" } else { "unfreeze" };
+        info!("🧊 Card {operation} requested for id={id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            if frozen { "FreezeCard" } else { "UnfreezeCard" },
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", operation),
+                KeyValue::new("card.id", id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(id);
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let card = match self.command.set_frozen(id, frozen).await {
+            Ok(card) => card,
+            Err(e) => {
+                error!("💥 Failed to {operation} card id={id}: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to {operation} card: {:?}", e),
+                    )
+                    .await;
+                return Err(match e {
+                    RepositoryError::NotFound => {
+                        ServiceError::NotFound("Card not found".to_string())
+                    }
+                    _ => ServiceError::Custom(format!("Failed to {operation} card")),
+                });
+            }
+        };
+
+        info!(
+            "✅ Card {} {operation} applied",
+            mask_card_number(&card.card_number)
+        );
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, &format!("Card {operation} applied"))
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: if frozen {
+                "🧊 Card frozen successfully!".into()
+            } else {
+                "🔓 Card unfrozen successfully!".into()
+            },
+            data: CardFreezeResponse::from(card),
+        })
+    }
 }
 
 #[async_trait]
@@ -67,6 +153,8 @@ impl CardCommandServiceTrait for CardCommandService {
             return Err(ServiceError::Custom(error_msg));
         }
 
+        self.check_expire_date(req.expire_date)?;
+
         info!("🆕 Creating card for user_id={}", req.user_id);
 
         let method = Method::Post;
@@ -165,6 +253,8 @@ impl CardCommandServiceTrait for CardCommandService {
             .card_id
             .ok_or_else(|| ServiceError::Custom("card_id is required".into()))?;
 
+        self.check_expire_date(req.expire_date)?;
+
         info!("🔄 Updating card id={card_id} for user_id={}", req.user_id);
 
         let method = Method::Post;
@@ -235,15 +325,13 @@ impl CardCommandServiceTrait for CardCommandService {
 
         let response = CardResponse::from(updated_card);
 
-        let cache_keys = vec![
-            format!("card:find_by_id:id:{}", card_id),
-            format!("card:find_by_user_id:user_id:{}", req.user_id),
-            format!("card:find_by_card:number:{}", response.card_number),
-        ];
-
-        for key in cache_keys {
-            self.cache_store.delete_from_cache(&key).await;
-        }
+        self.cache_store
+            .invalidate_after_write(&[
+                format!("card:find_by_id:id:{}", card_id),
+                format!("card:find_by_user_id:user_id:{}", req.user_id),
+                format!("card:find_by_card:number:{}", response.card_number),
+            ])
+            .await;
 
         info!("✅ Card updated successfully with card_id={}", response.id);
 
@@ -459,7 +547,7 @@ impl CardCommandServiceTrait for CardCommandService {
         }
     }
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("🔄 Restoring ALL trashed cards");
 
         let method = Method::Post;
@@ -476,7 +564,7 @@ impl CardCommandServiceTrait for CardCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.restore_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All trashed cards restored successfully");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -499,7 +587,7 @@ impl CardCommandServiceTrait for CardCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "🔄 All cards restored successfully!".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -516,7 +604,184 @@ impl CardCommandServiceTrait for CardCommandService {
         }
     }
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn reissue(&self, id: i32) -> Result<ApiResponse<CardReissueResponse>, ServiceError> {
+        if id <= 0 {
+            return Err(ServiceError::Validation(vec![
+                "card_id must be greater than 0".to_string(),
+            ]));
+        }
+
+        info!("🔁 Reissuing card id={id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "ReissueCard",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "reissue"),
+                KeyValue::new("card.id", id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(id);
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let expire_date = self.expiry.reissue_expire_date(Utc::now().date_naive());
+        self.check_expire_date(expire_date)?;
+
+        let reissued = match self.command.reissue(id, expire_date).await {
+            Ok(reissued) => {
+                info!(
+                    "✅ Card {} reissued as card_id={}",
+                    id, reissued.card.card_id
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Card reissued successfully")
+                    .await;
+                reissued
+            }
+            Err(e) => {
+                error!("💥 Failed to reissue card id={id}: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to reissue card: {:?}", e),
+                    )
+                    .await;
+                return Err(match e {
+                    RepositoryError::NotFound => {
+                        ServiceError::NotFound("Card not found".to_string())
+                    }
+                    _ => ServiceError::Custom("Failed to reissue card".into()),
+                });
+            }
+        };
+
+        let response = CardReissueResponse::from(reissued);
+        let previous_number = &response.previous_card.card_number;
+
+        let cache_keys = vec![
+            format!("card:find_by_id:id:{}", id),
+            format!("card:find_by_user_id:user_id:{}", response.card.user_id),
+            format!("card:find_by_card:number:{}", previous_number),
+            format!("saldo:find_by_card:card_number:{}", previous_number),
+            format!(
+                "saldo:find_by_card:card_number:{}",
+                mask_card_number(previous_number)
+            ),
+            "card:find_all:*".to_string(),
+            "card:find_active:*".to_string(),
+            "card:find_trashed:*".to_string(),
+        ];
+
+        for key in cache_keys {
+            self.cache_store.delete_from_cache(&key).await;
+        }
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "🔁 Card reissued successfully!".into(),
+            data: response,
+        })
+    }
+
+    async fn freeze(&self, id: i32) -> Result<ApiResponse<CardFreezeResponse>, ServiceError> {
+        self.set_frozen(id, true).await
+    }
+
+    async fn unfreeze(&self, id: i32) -> Result<ApiResponse<CardFreezeResponse>, ServiceError> {
+        self.set_frozen(id, false).await
+    }
+
+    async fn restore_by_filter(
+        &self,
+        filter: &RestoreCardsByFilter,
+    ) -> Result<ApiResponse<Vec<i32>>, ServiceError> {
+        if let Err(validation_errors) = filter.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Validation(vec![error_msg]));
+        }
+
+        if filter.is_empty() {
+            return Err(ServiceError::Validation(vec![
+                "at least one filter is required; use restore-all to restore every card"
+                    .to_string(),
+            ]));
+        }
+
+        if let (Some(from), Some(to)) = (filter.deleted_from, filter.deleted_to)
+            && from > to
+        {
+            return Err(ServiceError::Validation(vec![
+                "deleted_from must not be after deleted_to".to_string(),
+            ]));
+        }
+
+        info!(
+            "🔄 Restoring trashed cards by filter | search={:?}, user_id={:?}, deleted_from={:?}, deleted_to={:?}",
+            filter.search, filter.user_id, filter.deleted_from, filter.deleted_to
+        );
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "RestoreCardsByFilter",
+            vec![
+                KeyValue::new("component", "card"),
+                KeyValue::new("operation", "restore_by_filter"),
+            ],
+        );
+
+        let mut request = Request::new(filter.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.command.restore_by_filter(filter).await {
+            Ok(restored_ids) => {
+                info!("✅ Restored {} cards by filter", restored_ids.len());
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Trashed cards restored by filter successfully",
+                    )
+                    .await;
+
+                let cache_keys = vec![
+                    "card:find_trashed:*",
+                    "card:find_active:*",
+                    "card:find_all:*",
+                ];
+
+                for key in cache_keys {
+                    self.cache_store.delete_from_cache(key).await;
+                }
+
+                Ok(ApiResponse {
+                    status: "success".into(),
+                    message: format!("🔄 Restored {} cards successfully!", restored_ids.len()),
+                    data: restored_ids,
+                })
+            }
+            Err(e) => {
+                error!("💥 Failed to restore cards by filter: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to restore cards by filter: {:?}", e),
+                    )
+                    .await;
+                Err(ServiceError::Custom(
+                    "Failed to restore cards by filter".into(),
+                ))
+            }
+        }
+    }
+
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("💣 Permanently deleting ALL trashed cards");
 
         let method = Method::Post;
@@ -533,7 +798,7 @@ impl CardCommandServiceTrait for CardCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.delete_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All trashed cards permanently deleted successfully");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -556,7 +821,7 @@ impl CardCommandServiceTrait for CardCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "💣 All cards permanently deleted!".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
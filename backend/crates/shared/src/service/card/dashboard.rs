@@ -1,7 +1,8 @@
 use crate::{
     abstract_trait::card::{
         repository::dashboard::{
-            balance::DynCardDashboardBalanceRepository, topup::DynCardDashboardTopupRepository,
+            balance::DynCardDashboardBalanceRepository, system::DynCardDashboardSystemRepository,
+            topup::DynCardDashboardTopupRepository,
             transaction::DynCardDashboardTransactionRepository,
             transfer::DynCardDashboardTransferRepository,
             withdraw::DynCardDashboardWithdrawRepository,
@@ -10,18 +11,33 @@ use crate::{
     },
     cache::CacheStore,
     context::shared_resources::SharedResources,
-    domain::responses::{ApiResponse, DashboardCard, DashboardCardCardNumber},
-    errors::ServiceError,
+    domain::{
+        requests::card::{FindCardDashboards, FindTopCards},
+        responses::{
+            ApiResponse, DashboardCard, DashboardCardCardNumber, DashboardCardNumbers,
+            SystemStatsResponse, TopCardResponse,
+        },
+    },
+    errors::{RepositoryError, ServiceError, format_validation_errors},
     observability::{Method, TracingMetrics},
     utils::mask_card_number,
 };
 use anyhow::Result;
 use async_trait::async_trait;
-use chrono::Duration;
+use chrono::{Duration, Utc};
 use opentelemetry::KeyValue;
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    future::Future,
+    sync::Arc,
+};
+use tokio::sync::Semaphore;
 use tonic::Request;
 use tracing::{error, info};
+use validator::Validate;
+
+const SYSTEM_STATS_CACHE_TTL_SECS: i64 = 30;
+const TOP_CARDS_CACHE_TTL_SECS: i64 = 60;
 
 pub struct CardDashboardService {
     pub balance: DynCardDashboardBalanceRepository,
@@ -29,6 +45,8 @@ pub struct CardDashboardService {
     pub transaction: DynCardDashboardTransactionRepository,
     pub transfer: DynCardDashboardTransferRepository,
     pub withdraw: DynCardDashboardWithdrawRepository,
+    pub system: DynCardDashboardSystemRepository,
+    pub stats_concurrency: usize,
     pub tracing_metrics_core: TracingMetrics,
     pub cache_store: Arc<CacheStore>,
 }
@@ -39,6 +57,8 @@ pub struct CardDashboardServiceDeps {
     pub transaction: DynCardDashboardTransactionRepository,
     pub transfer: DynCardDashboardTransferRepository,
     pub withdraw: DynCardDashboardWithdrawRepository,
+    pub system: DynCardDashboardSystemRepository,
+    pub stats_concurrency: usize,
 }
 
 impl CardDashboardService {
@@ -49,6 +69,8 @@ impl CardDashboardService {
             transaction,
             transfer,
             withdraw,
+            system,
+            stats_concurrency,
         } = deps;
 
         Ok(Self {
@@ -57,10 +79,63 @@ impl CardDashboardService {
             transaction,
             transfer,
             withdraw,
+            system,
+            stats_concurrency: stats_concurrency.max(1),
             tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
             cache_store: Arc::clone(&shared.cache_store),
         })
     }
+
+    /// Permit pool for the sub-queries of a single dashboard call, so one
+    /// request is bounded without queueing behind other callers.
+    fn stats_limiter(&self) -> Semaphore {
+        Semaphore::new(self.stats_concurrency)
+    }
+
+    async fn bounded<T, F>(limiter: &Semaphore, fut: F) -> Result<T, RepositoryError>
+    where
+        F: Future<Output = Result<T, RepositoryError>>,
+    {
+        let _permit = limiter
+            .acquire()
+            .await
+            .map_err(|e| RepositoryError::Custom(format!("stats limiter closed: {e}")))?;
+
+        fut.await
+    }
+
+    fn collect<T>(
+        name: &'static str,
+        result: Result<T, RepositoryError>,
+        failures: &mut Vec<(&'static str, RepositoryError)>,
+    ) -> Option<T> {
+        match result {
+            Ok(value) => Some(value),
+            Err(e) => {
+                failures.push((name, e));
+                None
+            }
+        }
+    }
+
+    fn aggregate_failures(mut failures: Vec<(&'static str, RepositoryError)>) -> ServiceError {
+        if failures.len() == 1 {
+            let (_, e) = failures.remove(0);
+            return ServiceError::Repo(e);
+        }
+
+        let details = failures
+            .iter()
+            .map(|(name, e)| format!("{name}: {e}"))
+            .collect::<Vec<_>>()
+            .join("; ");
+
+        ServiceError::Custom(format!("Failed to load dashboard stats ({details})"))
+    }
+
+    fn totals_by_card(rows: Option<Vec<(String, i64)>>) -> BTreeMap<String, i64> {
+        rows.unwrap_or_default().into_iter().collect()
+    }
 }
 
 #[async_trait]
@@ -96,87 +171,48 @@ impl CardDashboardServiceTrait for CardDashboardService {
             return Ok(cache);
         }
 
-        let total_balance = match self.balance.get_total_balance().await {
-            Ok(balance) => balance,
-            Err(e) => {
-                error!("❌ Failed to get total balance: {e:?}");
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        "Failed to get total balance",
-                    )
-                    .await;
-                return Err(ServiceError::Repo(e));
-            }
-        };
-
-        let total_topup = match self.topup.get_total_amount().await {
-            Ok(amount) => amount,
-            Err(e) => {
-                error!("❌ Failed to get total top-up: {e:?}");
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        "Failed to get total top-up",
-                    )
-                    .await;
-                return Err(ServiceError::Repo(e));
-            }
-        };
+        let limiter = self.stats_limiter();
 
-        let total_transaction = match self.transaction.get_total_amount().await {
-            Ok(amount) => amount,
-            Err(e) => {
-                error!("❌ Failed to get total transaction: {e:?}");
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        "Failed to get total transaction",
-                    )
-                    .await;
-                return Err(ServiceError::Repo(e));
-            }
-        };
+        let (balance, topup, transaction, transfer, withdraw) = tokio::join!(
+            Self::bounded(&limiter, self.balance.get_total_balance()),
+            Self::bounded(&limiter, self.topup.get_total_amount()),
+            Self::bounded(&limiter, self.transaction.get_total_amount()),
+            Self::bounded(&limiter, self.transfer.get_total_amount()),
+            Self::bounded(&limiter, self.withdraw.get_total_amount()),
+        );
 
-        let total_transfer = match self.transfer.get_total_amount().await {
-            Ok(amount) => amount,
-            Err(e) => {
-                error!("❌ Failed to get total transfer: {e:?}");
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        "Failed to get total transfer",
-                    )
-                    .await;
-                return Err(ServiceError::Repo(e));
-            }
-        };
+        let mut failures = Vec::new();
+        let total_balance = Self::collect("total_balance", balance, &mut failures);
+        let total_topup = Self::collect("total_topup", topup, &mut failures);
+        let total_transaction = Self::collect("total_transaction", transaction, &mut failures);
+        let total_transfer = Self::collect("total_transfer", transfer, &mut failures);
+        let total_withdraw = Self::collect("total_withdraw", withdraw, &mut failures);
 
-        let total_withdraw = match self.withdraw.get_total_amount().await {
-            Ok(amount) => amount,
-            Err(e) => {
-                error!("❌ Failed to get total withdraw: {e:?}");
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        "Failed to get total withdraw",
-                    )
-                    .await;
-                return Err(ServiceError::Repo(e));
+        if !failures.is_empty() {
+            for (name, e) in &failures {
+                error!("❌ Failed to get {name}: {e:?}");
             }
-        };
+            let names = failures
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ");
+            self.tracing_metrics_core
+                .complete_tracing_error(
+                    &tracing_ctx,
+                    method.clone(),
+                    &format!("Failed to get dashboard stats: {names}"),
+                )
+                .await;
+            return Err(Self::aggregate_failures(failures));
+        }
 
         let dashboard = DashboardCard {
-            total_balance: Some(total_balance),
-            total_topup: Some(total_topup),
-            total_transaction: Some(total_transaction),
-            total_transfer: Some(total_transfer),
-            total_withdraw: Some(total_withdraw),
+            total_balance,
+            total_topup,
+            total_transaction,
+            total_transfer,
+            total_withdraw,
         };
 
         let response = ApiResponse {
@@ -236,173 +272,406 @@ impl CardDashboardServiceTrait for CardDashboardService {
             return Ok(cache);
         }
 
-        let total_balance = match self
-            .balance
-            .get_total_balance_by_card(card_number.clone())
-            .await
-        {
-            Ok(balance) => balance,
-            Err(e) => {
-                error!("❌ Failed to get balance for card {card_number}: {e:?}");
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        &format!(
-                            "Failed to get balance for card {}",
-                            mask_card_number(&card_number)
-                        ),
-                    )
-                    .await;
-                return Err(ServiceError::Repo(e));
+        let limiter = self.stats_limiter();
+
+        let (balance, topup, transaction, transfer_send, transfer_receiver, withdraw) = tokio::join!(
+            Self::bounded(
+                &limiter,
+                self.balance.get_total_balance_by_card(card_number.clone())
+            ),
+            Self::bounded(
+                &limiter,
+                self.topup.get_total_amount_by_card(card_number.clone())
+            ),
+            Self::bounded(
+                &limiter,
+                self.transaction
+                    .get_total_amount_by_card(card_number.clone())
+            ),
+            Self::bounded(
+                &limiter,
+                self.transfer
+                    .get_total_amount_by_sender(card_number.clone())
+            ),
+            Self::bounded(
+                &limiter,
+                self.transfer
+                    .get_total_amount_by_receiver(card_number.clone())
+            ),
+            Self::bounded(
+                &limiter,
+                self.withdraw.get_total_amount_by_card(card_number.clone())
+            ),
+        );
+
+        let mut failures = Vec::new();
+        let total_balance = Self::collect("total_balance", balance, &mut failures);
+        let total_topup = Self::collect("total_topup", topup, &mut failures);
+        let total_transaction = Self::collect("total_transaction", transaction, &mut failures);
+        let total_transfer_send =
+            Self::collect("total_transfer_send", transfer_send, &mut failures);
+        let total_transfer_receiver =
+            Self::collect("total_transfer_receiver", transfer_receiver, &mut failures);
+        let total_withdraw = Self::collect("total_withdraw", withdraw, &mut failures);
+
+        if !failures.is_empty() {
+            for (name, e) in &failures {
+                error!(
+                    "❌ Failed to get {name} for card {}: {e:?}",
+                    mask_card_number(&card_number)
+                );
             }
+            let names = failures
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ");
+            self.tracing_metrics_core
+                .complete_tracing_error(
+                    &tracing_ctx,
+                    method.clone(),
+                    &format!(
+                        "Failed to get dashboard stats for card {}: {names}",
+                        mask_card_number(&card_number)
+                    ),
+                )
+                .await;
+            return Err(Self::aggregate_failures(failures));
+        }
+
+        let dashboard = DashboardCardCardNumber {
+            total_balance,
+            total_topup,
+            total_transaction,
+            total_transfer_send,
+            total_transfer_receiver,
+            total_withdraw,
         };
 
-        let total_topup = match self
-            .topup
-            .get_total_amount_by_card(card_number.clone())
-            .await
-        {
-            Ok(amount) => amount,
-            Err(e) => {
-                error!("❌ Failed to get top-up for card {card_number}: {e:?}");
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        &format!(
-                            "Failed to get top-up for card {}",
-                            mask_card_number(&card_number)
-                        ),
-                    )
-                    .await;
-                return Err(ServiceError::Repo(e));
-            }
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: format!(
+                "Dashboard for card {} retrieved successfully",
+                mask_card_number(&card_number)
+            ),
+            data: dashboard,
         };
 
-        let total_transaction = match self
-            .transaction
-            .get_total_amount_by_card(card_number.clone())
-            .await
-        {
-            Ok(amount) => amount,
-            Err(e) => {
-                error!("❌ Failed to get transaction for card {card_number}: {e:?}");
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        &format!(
-                            "Failed to get transaction for card {}",
-                            mask_card_number(&card_number)
-                        ),
-                    )
-                    .await;
-                return Err(ServiceError::Repo(e));
+        self.cache_store
+            .set_to_cache(&cache_key, &response, Duration::minutes(10))
+            .await;
+
+        info!(
+            "✅ Dashboard for card {} retrieved successfully",
+            mask_card_number(&card_number)
+        );
+        self.tracing_metrics_core
+            .complete_tracing_success(
+                &tracing_ctx,
+                method,
+                &format!(
+                    "Dashboard for card {} retrieved successfully",
+                    mask_card_number(&card_number)
+                ),
+            )
+            .await;
+
+        Ok(response)
+    }
+
+    async fn get_dashboard_bycards(
+        &self,
+        request: &FindCardDashboards,
+    ) -> Result<ApiResponse<DashboardCardNumbers>, ServiceError> {
+        if let Err(validation_errors) = request.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Validation(vec![error_msg]));
+        }
+
+        let card_numbers: Vec<String> = request
+            .card_numbers
+            .iter()
+            .map(|card| card.trim().to_string())
+            .filter(|card| !card.is_empty())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        if card_numbers.is_empty() {
+            return Err(ServiceError::Validation(vec![
+                "Card numbers wajib diisi".to_string(),
+            ]));
+        }
+
+        info!("💳📊 Fetching dashboards for {} cards", card_numbers.len());
+
+        let method = Method::Get;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "get_dashboard_bycards",
+            vec![KeyValue::new("card_count", card_numbers.len() as i64)],
+        );
+
+        let mut request = Request::new(card_numbers.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let limiter = self.stats_limiter();
+
+        let (balance, topup, transaction, transfer_send, transfer_receiver, withdraw) = tokio::join!(
+            Self::bounded(
+                &limiter,
+                self.balance.get_total_balance_by_cards(&card_numbers)
+            ),
+            Self::bounded(
+                &limiter,
+                self.topup.get_total_amount_by_cards(&card_numbers)
+            ),
+            Self::bounded(
+                &limiter,
+                self.transaction.get_total_amount_by_cards(&card_numbers)
+            ),
+            Self::bounded(
+                &limiter,
+                self.transfer.get_total_amount_by_senders(&card_numbers)
+            ),
+            Self::bounded(
+                &limiter,
+                self.transfer.get_total_amount_by_receivers(&card_numbers)
+            ),
+            Self::bounded(
+                &limiter,
+                self.withdraw.get_total_amount_by_cards(&card_numbers)
+            ),
+        );
+
+        let mut failures = Vec::new();
+        let balance = Self::collect("total_balance", balance, &mut failures);
+        let topup = Self::collect("total_topup", topup, &mut failures);
+        let transaction = Self::collect("total_transaction", transaction, &mut failures);
+        let transfer_send = Self::collect("total_transfer_send", transfer_send, &mut failures);
+        let transfer_receiver =
+            Self::collect("total_transfer_receiver", transfer_receiver, &mut failures);
+        let withdraw = Self::collect("total_withdraw", withdraw, &mut failures);
+
+        if !failures.is_empty() {
+            for (name, e) in &failures {
+                error!(
+                    "❌ Failed to get {name} for {} cards: {e:?}",
+                    card_numbers.len()
+                );
             }
+            let names = failures
+                .iter()
+                .map(|(name, _)| *name)
+                .collect::<Vec<_>>()
+                .join(", ");
+            self.tracing_metrics_core
+                .complete_tracing_error(
+                    &tracing_ctx,
+                    method.clone(),
+                    &format!("Failed to get batched dashboard stats: {names}"),
+                )
+                .await;
+            return Err(Self::aggregate_failures(failures));
+        }
+
+        let balance = Self::totals_by_card(balance);
+        let topup = Self::totals_by_card(topup);
+        let transaction = Self::totals_by_card(transaction);
+        let transfer_send = Self::totals_by_card(transfer_send);
+        let transfer_receiver = Self::totals_by_card(transfer_receiver);
+        let withdraw = Self::totals_by_card(withdraw);
+
+        let total_of = |totals: &BTreeMap<String, i64>, card: &str| {
+            Some(totals.get(card).copied().unwrap_or(0))
         };
 
-        let total_transfer_send = match self
-            .transfer
-            .get_total_amount_by_sender(card_number.clone())
+        let dashboards = card_numbers
+            .iter()
+            .map(|card| {
+                (
+                    card.clone(),
+                    DashboardCardCardNumber {
+                        total_balance: total_of(&balance, card),
+                        total_topup: total_of(&topup, card),
+                        total_transaction: total_of(&transaction, card),
+                        total_transfer_send: total_of(&transfer_send, card),
+                        total_transfer_receiver: total_of(&transfer_receiver, card),
+                        total_withdraw: total_of(&withdraw, card),
+                    },
+                )
+            })
+            .collect::<BTreeMap<_, _>>();
+
+        info!("✅ Dashboards for {} cards retrieved", dashboards.len());
+        self.tracing_metrics_core
+            .complete_tracing_success(
+                &tracing_ctx,
+                method,
+                "Batched card dashboards retrieved successfully",
+            )
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Card dashboards retrieved successfully".to_string(),
+            data: DashboardCardNumbers { dashboards },
+        })
+    }
+
+    async fn get_system_stats(&self) -> Result<ApiResponse<SystemStatsResponse>, ServiceError> {
+        info!("📊 Fetching system-wide stats");
+
+        let method = Method::Get;
+
+        let tracing_ctx = self
+            .tracing_metrics_core
+            .start_tracing("get_system_stats", vec![]);
+
+        let mut request = Request::new(());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let today = Utc::now().date_naive();
+        let cache_key = format!("dashboard:system:{today}");
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponse<SystemStatsResponse>>(&cache_key)
             .await
         {
-            Ok(amount) => amount,
+            info!("✅ Found system stats in cache");
+            self.tracing_metrics_core
+                .complete_tracing_success(&tracing_ctx, method, "System stats retrieved from cache")
+                .await;
+            return Ok(cache);
+        }
+
+        let stats = match self.system.get_system_stats(today).await {
+            Ok(stats) => stats,
             Err(e) => {
-                error!("❌ Failed to get transfer (sent) for card {card_number}: {e:?}");
+                error!("❌ Failed to get system stats: {e:?}");
                 self.tracing_metrics_core
                     .complete_tracing_error(
                         &tracing_ctx,
                         method.clone(),
-                        &format!(
-                            "Failed to get transfer (sent) for card {}",
-                            mask_card_number(&card_number)
-                        ),
+                        "Failed to get system stats",
                     )
                     .await;
                 return Err(ServiceError::Repo(e));
             }
         };
 
-        let total_transfer_receiver = match self
-            .transfer
-            .get_total_amount_by_receiver(card_number.clone())
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "System stats retrieved successfully".to_string(),
+            data: SystemStatsResponse::from_model(stats, today),
+        };
+
+        self.cache_store
+            .set_to_cache(
+                &cache_key,
+                &response,
+                Duration::seconds(SYSTEM_STATS_CACHE_TTL_SECS),
+            )
+            .await;
+
+        info!("✅ System stats retrieved successfully");
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "System stats retrieved successfully")
+            .await;
+
+        Ok(response)
+    }
+
+    async fn get_top_cards(
+        &self,
+        request: &FindTopCards,
+    ) -> Result<ApiResponse<Vec<TopCardResponse>>, ServiceError> {
+        if let Err(validation_errors) = request.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Validation(vec![error_msg]));
+        }
+
+        let (start, end) = request
+            .date_range()
+            .map_err(|e| ServiceError::Validation(vec![e]))?;
+
+        info!(
+            "🏆 Fetching top {} cards by {}",
+            request.limit, request.metric
+        );
+
+        let method = Method::Get;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "get_top_cards",
+            vec![
+                KeyValue::new("metric", request.metric.to_string()),
+                KeyValue::new("limit", request.limit as i64),
+            ],
+        );
+
+        let mut grpc_request = Request::new(request.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_request);
+
+        let cache_key = format!(
+            "dashboard:top_cards:{}:{}:{start}:{end}",
+            request.metric, request.limit
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponse<Vec<TopCardResponse>>>(&cache_key)
             .await
         {
-            Ok(amount) => amount,
-            Err(e) => {
-                error!("❌ Failed to get transfer (received) for card {card_number}: {e:?}");
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        &format!(
-                            "Failed to get transfer (received) for card {}",
-                            mask_card_number(&card_number)
-                        ),
-                    )
-                    .await;
-                return Err(ServiceError::Repo(e));
-            }
-        };
+            info!("✅ Found top cards in cache");
+            self.tracing_metrics_core
+                .complete_tracing_success(&tracing_ctx, method, "Top cards retrieved from cache")
+                .await;
+            return Ok(cache);
+        }
 
-        let total_withdraw = match self
-            .withdraw
-            .get_total_amount_by_card(card_number.clone())
+        let cards = match self
+            .system
+            .get_top_cards(request.metric, request.limit, start, end)
             .await
         {
-            Ok(amount) => amount,
+            Ok(cards) => cards,
             Err(e) => {
-                error!("❌ Failed to get withdraw for card {card_number}: {e:?}");
+                error!("❌ Failed to get top cards: {e:?}");
                 self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        &format!(
-                            "Failed to get withdraw for card {}",
-                            mask_card_number(&card_number)
-                        ),
-                    )
+                    .complete_tracing_error(&tracing_ctx, method.clone(), "Failed to get top cards")
                     .await;
                 return Err(ServiceError::Repo(e));
             }
         };
 
-        let dashboard = DashboardCardCardNumber {
-            total_balance: Some(total_balance),
-            total_topup: Some(total_topup),
-            total_transaction: Some(total_transaction),
-            total_transfer_send: Some(total_transfer_send),
-            total_transfer_receiver: Some(total_transfer_receiver),
-            total_withdraw: Some(total_withdraw),
-        };
-
         let response = ApiResponse {
             status: "success".to_string(),
-            message: format!(
-                "Dashboard for card {} retrieved successfully",
-                mask_card_number(&card_number)
-            ),
-            data: dashboard,
+            message: "Top cards retrieved successfully".to_string(),
+            data: cards
+                .into_iter()
+                .enumerate()
+                .map(|(i, card)| TopCardResponse::from_model(i as i32 + 1, card))
+                .collect(),
         };
 
         self.cache_store
-            .set_to_cache(&cache_key, &response, Duration::minutes(10))
+            .set_to_cache(
+                &cache_key,
+                &response,
+                Duration::seconds(TOP_CARDS_CACHE_TTL_SECS),
+            )
             .await;
 
-        info!(
-            "✅ Dashboard for card {} retrieved successfully",
-            mask_card_number(&card_number)
-        );
+        info!("✅ Top cards retrieved successfully");
         self.tracing_metrics_core
-            .complete_tracing_success(
-                &tracing_ctx,
-                method,
-                &format!(
-                    "Dashboard for card {} retrieved successfully",
-                    mask_card_number(&card_number)
-                ),
-            )
+            .complete_tracing_success(&tracing_ctx, method, "Top cards retrieved successfully")
             .await;
 
         Ok(response)
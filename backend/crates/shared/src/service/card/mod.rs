@@ -3,3 +3,4 @@ pub mod dashboard;
 pub mod query;
 pub mod stats;
 pub mod statsbycard;
+pub mod usable;
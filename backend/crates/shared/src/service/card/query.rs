@@ -5,9 +5,10 @@ use crate::{
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::{
-        requests::card::FindAllCards,
+        requests::{card::FindAllCards, trashed::TrashedSort},
         responses::{
-            ApiResponse, ApiResponsePagination, CardResponse, CardResponseDeleteAt, Pagination,
+            ApiResponse, ApiResponsePagination, CardResponse, CardResponseDeleteAt,
+            CardStatusResponse, Pagination,
         },
     },
     errors::{RepositoryError, ServiceError},
@@ -17,7 +18,7 @@ use crate::{
 
 use anyhow::Result;
 use async_trait::async_trait;
-use chrono::Duration;
+use chrono::{Duration, Utc};
 use opentelemetry::KeyValue;
 use std::sync::Arc;
 use tonic::Request;
@@ -46,7 +47,7 @@ impl CardQueryServiceTrait for CardQueryService {
         req: &FindAllCards,
     ) -> Result<ApiResponsePagination<Vec<CardResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -131,6 +132,7 @@ impl CardQueryServiceTrait for CardQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -150,7 +152,7 @@ impl CardQueryServiceTrait for CardQueryService {
         req: &FindAllCards,
     ) -> Result<ApiResponsePagination<Vec<CardResponseDeleteAt>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -236,6 +238,7 @@ impl CardQueryServiceTrait for CardQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -254,8 +257,14 @@ impl CardQueryServiceTrait for CardQueryService {
         &self,
         req: &FindAllCards,
     ) -> Result<ApiResponsePagination<Vec<CardResponseDeleteAt>>, ServiceError> {
+        let Some(sort) = TrashedSort::parse(req.sort_by.as_deref()) else {
+            return Err(ServiceError::Validation(vec![
+                TrashedSort::validation_error(),
+            ]));
+        };
+
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -287,8 +296,9 @@ impl CardQueryServiceTrait for CardQueryService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         let cache_key = format!(
-            "card:find_trashed:page:{page}:size:{page_size}:search:{}",
-            search.unwrap_or_default()
+            "card:find_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            search.unwrap_or_default(),
+            sort.as_str()
         );
 
         if let Some(cache) = self
@@ -341,6 +351,7 @@ impl CardQueryServiceTrait for CardQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -484,6 +495,53 @@ impl CardQueryServiceTrait for CardQueryService {
         Ok(response)
     }
 
+    async fn find_all_by_user_id(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<CardResponse>>, ServiceError> {
+        info!("👥 Finding all cards for user ID: {}", user_id);
+
+        let method = Method::Get;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "find_all_by_user_id",
+            vec![KeyValue::new("user_id", user_id.to_string())],
+        );
+
+        let mut request = Request::new(user_id);
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let cards = match self.query.find_all_by_user_id(user_id).await {
+            Ok(cards) => cards,
+            Err(e) => {
+                error!("❌ Failed to fetch cards for user ID {user_id}: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to fetch cards for user ID {user_id}"),
+                    )
+                    .await;
+                return Err(ServiceError::InternalServerError(e.to_string()));
+            }
+        };
+
+        let data: Vec<CardResponse> = cards.into_iter().map(CardResponse::from).collect();
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Cards for user retrieved")
+            .await;
+
+        info!("✅ Found {} cards for user ID {user_id}", data.len());
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "Cards by user ID retrieved successfully".into(),
+            data,
+        })
+    }
+
     async fn find_by_card(
         &self,
         card_number: &str,
@@ -572,4 +630,48 @@ impl CardQueryServiceTrait for CardQueryService {
 
         Ok(response)
     }
+
+    async fn check_card(
+        &self,
+        card_number: &str,
+    ) -> Result<ApiResponse<CardStatusResponse>, ServiceError> {
+        info!("💳 Checking card status: {}", mask_card_number(card_number));
+
+        let method = Method::Get;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "check_card",
+            vec![KeyValue::new("card_number", mask_card_number(card_number))],
+        );
+
+        let mut request = Request::new(card_number.to_string());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let status = match self.query.find_status_by_card(card_number).await {
+            Ok(status) => status,
+            Err(e) => {
+                error!(
+                    "❌ Error checking card {}: {e:?}",
+                    mask_card_number(card_number)
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Database error")
+                    .await;
+                return Err(ServiceError::InternalServerError(e.to_string()));
+            }
+        };
+
+        let data = CardStatusResponse::from_model(status, Utc::now().date_naive());
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Card status checked")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Card status checked".to_string(),
+            data,
+        })
+    }
 }
@@ -0,0 +1,38 @@
+use crate::{
+    abstract_trait::card::repository::query::DynCardQueryRepository, errors::ServiceError,
+    utils::mask_card_number,
+};
+use chrono::Utc;
+use tracing::warn;
+
+pub async fn ensure_card_usable(
+    card_query: &DynCardQueryRepository,
+    card_number: &str,
+) -> Result<(), ServiceError> {
+    let status = card_query
+        .find_status_by_card(card_number)
+        .await
+        .map_err(ServiceError::Repo)?;
+
+    let Some(status) = status.filter(|s| s.deleted_at.is_none()) else {
+        return Ok(());
+    };
+
+    if status.frozen_at.is_some() {
+        warn!(
+            "🧊 Rejected money movement on frozen card {}",
+            mask_card_number(card_number)
+        );
+        return Err(ServiceError::Custom("Card frozen".into()));
+    }
+
+    if status.expire_date < Utc::now().date_naive() {
+        warn!(
+            "⌛ Rejected money movement on expired card {}",
+            mask_card_number(card_number)
+        );
+        return Err(ServiceError::Custom("Card expired".into()));
+    }
+
+    Ok(())
+}
@@ -10,12 +10,19 @@ use crate::{
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::{
-        requests::merchant::{CreateMerchantRequest, UpdateMerchantRequest, UpdateMerchantStatus},
-        responses::{ApiResponse, MerchantResponse, MerchantResponseDeleteAt},
+        requests::merchant::{
+            CreateMerchantRequest, MerchantUserRequest, SetMerchantWebhookRequest,
+            UpdateMerchantRequest, UpdateMerchantStatus,
+        },
+        responses::{
+            ApiResponse, BulkResult, MerchantResponse, MerchantResponseDeleteAt,
+            MerchantWebhookResponse,
+        },
     },
-    errors::{ServiceError, format_validation_errors},
+    errors::{RepositoryError, ServiceError, format_validation_errors},
     observability::{Method, TracingMetrics},
     utils::generate_api_key,
+    webhook::WebhookPolicy,
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -29,6 +36,7 @@ use validator::Validate;
 pub struct MerchantCommandService {
     pub command: DynMerchantCommandRepository,
     pub user_query: DynUserQueryRepository,
+    pub webhook_policy: WebhookPolicy,
     pub tracing_metrics_core: TracingMetrics,
     pub cache_store: Arc<CacheStore>,
 }
@@ -37,17 +45,41 @@ impl MerchantCommandService {
     pub fn new(
         command: DynMerchantCommandRepository,
         user_query: DynUserQueryRepository,
+        webhook_policy: WebhookPolicy,
         shared: &SharedResources,
     ) -> Result<Self> {
         Ok(Self {
             command,
             user_query,
+            webhook_policy,
             tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
             cache_store: Arc::clone(&shared.cache_store),
         })
     }
 }
 
+/// Validates a webhook request and checks its URL against the webhook policy,
+/// returning the merchant id and the normalized URL to store.
+async fn check_webhook_request(
+    policy: &WebhookPolicy,
+    req: &SetMerchantWebhookRequest,
+) -> Result<(i32, String), ServiceError> {
+    if let Err(validation_errors) = req.validate() {
+        let error_msg = format_validation_errors(&validation_errors);
+        error!("Validation failed: {error_msg}");
+        return Err(ServiceError::Validation(vec![error_msg]));
+    }
+
+    let merchant_id = req
+        .merchant_id
+        .filter(|id| *id > 0)
+        .ok_or_else(|| ServiceError::Validation(vec!["merchant_id is required".into()]))?;
+
+    let target = policy.check_target(req.url.trim()).await?;
+
+    Ok((merchant_id, target.url.to_string()))
+}
+
 #[async_trait]
 impl MerchantCommandServiceTrait for MerchantCommandService {
     async fn create(
@@ -112,8 +144,8 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
             }
         };
 
-        let merchant = match self.command.create(api_key, req).await {
-            Ok(merchant) => {
+        let (merchant, created) = match self.command.create(api_key, req).await {
+            Ok((merchant, true)) => {
                 info!(
                     "✅ Merchant created successfully: id={}",
                     merchant.merchant_id
@@ -121,7 +153,24 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
                 self.tracing_metrics_core
                     .complete_tracing_success(&tracing_ctx, method, "Merchant created successfully")
                     .await;
-                merchant
+                (merchant, true)
+            }
+            Ok((merchant, false)) => {
+                info!(
+                    "♻️ Returning existing merchant id={} for business_id={:?}",
+                    merchant.merchant_id, req.business_id
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Existing merchant returned")
+                    .await;
+                (merchant, false)
+            }
+            Err(RepositoryError::Conflict(msg)) => {
+                error!("💥 Merchant business_id conflict: {msg}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method.clone(), &msg)
+                    .await;
+                return Err(ServiceError::Repo(RepositoryError::Conflict(msg)));
             }
             Err(e) => {
                 let error_msg = format!(
@@ -136,6 +185,7 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
             }
         };
 
+        let api_key = merchant.api_key.clone();
         let response = MerchantResponse::from(merchant);
 
         let masked_key = mask_api_key(&response.api_key);
@@ -152,10 +202,25 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
                 .await;
         }
 
+        let message = if created {
+            "Merchant created successfully"
+        } else {
+            "Merchant already exists for this business identifier"
+        };
+
+        let data = if created {
+            MerchantResponse {
+                api_key,
+                ..response
+            }
+        } else {
+            response
+        };
+
         Ok(ApiResponse {
             status: "success".to_string(),
-            message: "Merchant created successfully".to_string(),
-            data: response,
+            message: message.to_string(),
+            data,
         })
     }
 
@@ -549,7 +614,7 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
         }
     }
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("🔄 Restoring ALL trashed merchants");
 
         let method = Method::Post;
@@ -566,7 +631,7 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.restore_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All merchants restored successfully");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -592,7 +657,7 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "All trashed merchants restored successfully".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -611,7 +676,7 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
         }
     }
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("💣 Permanently deleting ALL trashed merchants");
 
         let method = Method::Post;
@@ -628,7 +693,7 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.delete_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All merchants permanently deleted");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -654,7 +719,7 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "All trashed merchants permanently deleted".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -672,4 +737,249 @@ impl MerchantCommandServiceTrait for MerchantCommandService {
             }
         }
     }
+
+    async fn grant_user(
+        &self,
+        req: &MerchantUserRequest,
+    ) -> Result<ApiResponse<bool>, ServiceError> {
+        if let Err(validation_errors) = req.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Custom(error_msg));
+        }
+
+        info!(
+            "🔑 Granting user {} access to merchant {}",
+            req.user_id, req.merchant_id
+        );
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "grant_merchant_user",
+            vec![
+                KeyValue::new("component", "merchant"),
+                KeyValue::new("operation", "grant_user"),
+                KeyValue::new("merchant.id", req.merchant_id.to_string()),
+                KeyValue::new("user.id", req.user_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.command.grant_user(req).await {
+            Ok(()) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Merchant access granted")
+                    .await;
+
+                self.cache_store
+                    .delete_from_cache(&format!("merchant:find_by_user_id:user_id:{}", req.user_id))
+                    .await;
+
+                Ok(ApiResponse {
+                    status: "success".into(),
+                    message: format!(
+                        "User {} granted access to merchant {}",
+                        req.user_id, req.merchant_id
+                    ),
+                    data: true,
+                })
+            }
+            Err(e) => {
+                error!(
+                    "💥 Failed to grant user {} access to merchant {}: {e:?}",
+                    req.user_id, req.merchant_id
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to grant merchant access: {:?}", e),
+                    )
+                    .await;
+                Err(ServiceError::Repo(e))
+            }
+        }
+    }
+
+    async fn revoke_user(
+        &self,
+        req: &MerchantUserRequest,
+    ) -> Result<ApiResponse<bool>, ServiceError> {
+        if let Err(validation_errors) = req.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Custom(error_msg));
+        }
+
+        info!(
+            "🔒 Revoking user {} access to merchant {}",
+            req.user_id, req.merchant_id
+        );
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "revoke_merchant_user",
+            vec![
+                KeyValue::new("component", "merchant"),
+                KeyValue::new("operation", "revoke_user"),
+                KeyValue::new("merchant.id", req.merchant_id.to_string()),
+                KeyValue::new("user.id", req.user_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.command.revoke_user(req).await {
+            Ok(revoked) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Merchant access revoked")
+                    .await;
+
+                self.cache_store
+                    .delete_from_cache(&format!("merchant:find_by_user_id:user_id:{}", req.user_id))
+                    .await;
+
+                let message = if revoked {
+                    format!(
+                        "User {} no longer has access to merchant {}",
+                        req.user_id, req.merchant_id
+                    )
+                } else {
+                    format!(
+                        "User {} was not linked to merchant {}",
+                        req.user_id, req.merchant_id
+                    )
+                };
+
+                Ok(ApiResponse {
+                    status: "success".into(),
+                    message,
+                    data: revoked,
+                })
+            }
+            Err(e) => {
+                error!(
+                    "💥 Failed to revoke user {} access to merchant {}: {e:?}",
+                    req.user_id, req.merchant_id
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to revoke merchant access: {:?}", e),
+                    )
+                    .await;
+                Err(ServiceError::Repo(e))
+            }
+        }
+    }
+
+    async fn set_webhook(
+        &self,
+        req: &SetMerchantWebhookRequest,
+    ) -> Result<ApiResponse<MerchantWebhookResponse>, ServiceError> {
+        let (merchant_id, url) = check_webhook_request(&self.webhook_policy, req).await?;
+
+        info!("🔗 Setting webhook for merchant id={merchant_id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "set_merchant_webhook",
+            vec![
+                KeyValue::new("component", "merchant"),
+                KeyValue::new("operation", "set_webhook"),
+                KeyValue::new("merchant.id", merchant_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        match self.command.set_webhook(merchant_id, &url).await {
+            Ok(webhook) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, "Merchant webhook set")
+                    .await;
+
+                Ok(ApiResponse {
+                    status: "success".into(),
+                    message: "Merchant webhook updated successfully".into(),
+                    data: MerchantWebhookResponse::from(webhook),
+                })
+            }
+            Err(e) => {
+                error!("💥 Failed to set webhook for merchant id={merchant_id}: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to set merchant webhook: {:?}", e),
+                    )
+                    .await;
+                Err(ServiceError::Repo(e))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::WebhookPolicyConfig;
+
+    fn request(url: &str) -> SetMerchantWebhookRequest {
+        SetMerchantWebhookRequest {
+            merchant_id: Some(7),
+            url: url.to_string(),
+        }
+    }
+
+    #[tokio::test]
+    async fn rejects_private_webhook_urls() {
+        let policy = WebhookPolicy::new(WebhookPolicyConfig::default());
+
+        for url in ["http://10.1.2.3/hook", "http://127.0.0.1:8080/hook"] {
+            let err = check_webhook_request(&policy, &request(url))
+                .await
+                .unwrap_err();
+            assert!(matches!(err, ServiceError::Validation(_)), "{url}: {err:?}");
+        }
+    }
+
+    #[tokio::test]
+    async fn accepts_public_webhook_urls() {
+        let policy = WebhookPolicy::new(WebhookPolicyConfig::default());
+
+        let (merchant_id, url) =
+            check_webhook_request(&policy, &request(" https://93.184.216.34/hook "))
+                .await
+                .unwrap();
+
+        assert_eq!(merchant_id, 7);
+        assert_eq!(url, "https://93.184.216.34/hook");
+    }
+
+    #[tokio::test]
+    async fn requires_a_merchant_and_a_url() {
+        let policy = WebhookPolicy::new(WebhookPolicyConfig::default());
+
+        let missing_merchant = SetMerchantWebhookRequest {
+            merchant_id: None,
+            ..request("https://93.184.216.34/hook")
+        };
+        assert!(matches!(
+            check_webhook_request(&policy, &missing_merchant).await,
+            Err(ServiceError::Validation(_))
+        ));
+        assert!(matches!(
+            check_webhook_request(&policy, &request("")).await,
+            Err(ServiceError::Validation(_))
+        ));
+    }
 }
@@ -5,7 +5,7 @@ use crate::{
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::{
-        requests::merchant::FindAllMerchants,
+        requests::{merchant::FindAllMerchants, trashed::TrashedSort},
         responses::{
             ApiResponse, ApiResponsePagination, MerchantResponse, MerchantResponseDeleteAt,
             Pagination,
@@ -46,7 +46,7 @@ impl MerchantQueryServiceTrait for MerchantQueryService {
         req: &FindAllMerchants,
     ) -> Result<ApiResponsePagination<Vec<MerchantResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -135,6 +135,7 @@ impl MerchantQueryServiceTrait for MerchantQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
@@ -149,7 +150,7 @@ impl MerchantQueryServiceTrait for MerchantQueryService {
         req: &FindAllMerchants,
     ) -> Result<ApiResponsePagination<Vec<MerchantResponseDeleteAt>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -244,6 +245,7 @@ impl MerchantQueryServiceTrait for MerchantQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
@@ -257,8 +259,14 @@ impl MerchantQueryServiceTrait for MerchantQueryService {
         &self,
         req: &FindAllMerchants,
     ) -> Result<ApiResponsePagination<Vec<MerchantResponseDeleteAt>>, ServiceError> {
+        let Some(sort) = TrashedSort::parse(req.sort_by.as_deref()) else {
+            return Err(ServiceError::Validation(vec![
+                TrashedSort::validation_error(),
+            ]));
+        };
+
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -291,8 +299,9 @@ impl MerchantQueryServiceTrait for MerchantQueryService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         let cache_key = format!(
-            "merchant:find_trashed:page:{page}:size:{page_size}:search:{}",
-            search_str.clone()
+            "merchant:find_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            search_str.clone(),
+            sort.as_str()
         );
 
         if let Some(cache) = self
@@ -353,6 +362,7 @@ impl MerchantQueryServiceTrait for MerchantQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
@@ -610,4 +620,59 @@ impl MerchantQueryServiceTrait for MerchantQueryService {
 
         Ok(response)
     }
+
+    async fn list_accessible_merchants(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<Vec<MerchantResponse>>, ServiceError> {
+        info!("🔐 Listing merchants accessible to user ID: {user_id}");
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "list_accessible_merchants",
+            vec![
+                KeyValue::new("component", "merchant"),
+                KeyValue::new("operation", "list_accessible"),
+                KeyValue::new("user_id", user_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(user_id);
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let merchants = match self.query.list_accessible_merchants(user_id).await {
+            Ok(merchants) => {
+                info!(
+                    "✅ User ID {user_id} can access {} merchants",
+                    merchants.len()
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Accessible merchants retrieved successfully",
+                    )
+                    .await;
+                merchants
+            }
+            Err(e) => {
+                error!("❌ Failed to list merchants accessible to user ID {user_id}: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to list accessible merchants: {:?}", e),
+                    )
+                    .await;
+                return Err(ServiceError::InternalServerError(e.to_string()));
+            }
+        };
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Accessible merchants retrieved successfully".to_string(),
+            data: merchants.into_iter().map(MerchantResponse::from).collect(),
+        })
+    }
 }
@@ -8,11 +8,15 @@ use crate::{
     domain::{
         requests::merchant::{
             FindAllMerchantTransactions, FindAllMerchantTransactionsByApiKey,
-            FindAllMerchantTransactionsById,
+            FindAllMerchantTransactionsById, FindMerchantTransactionsDaily,
+        },
+        responses::{
+            ApiResponse, ApiResponsePagination, MerchantApiKeyUsageResponse,
+            MerchantSettlementResponse, MerchantTransactionDailyResponse,
+            MerchantTransactionResponse, Pagination,
         },
-        responses::{ApiResponsePagination, MerchantTransactionResponse, Pagination},
     },
-    errors::{RepositoryError, ServiceError},
+    errors::ServiceError,
     observability::{Method, TracingMetrics},
     utils::mask_api_key,
 };
@@ -24,6 +28,8 @@ use std::sync::Arc;
 use tonic::Request;
 use tracing::{error, info};
 
+const MAX_DAILY_RANGE_DAYS: i64 = 366;
+
 pub struct MerchantTransactionService {
     pub transaction: DynMerchantTransactionRepository,
     pub tracing_metrics_core: TracingMetrics,
@@ -50,7 +56,7 @@ impl MerchantTransactionServiceTrait for MerchantTransactionService {
         req: &FindAllMerchantTransactions,
     ) -> Result<ApiResponsePagination<Vec<MerchantTransactionResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.trim().is_empty() {
             None
         } else {
@@ -145,6 +151,7 @@ impl MerchantTransactionServiceTrait for MerchantTransactionService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
@@ -159,7 +166,7 @@ impl MerchantTransactionServiceTrait for MerchantTransactionService {
         req: &FindAllMerchantTransactionsByApiKey,
     ) -> Result<ApiResponsePagination<Vec<MerchantTransactionResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.trim().is_empty() {
             None
         } else {
@@ -231,32 +238,28 @@ impl MerchantTransactionServiceTrait for MerchantTransactionService {
                     .await;
                 (transactions, total_items)
             }
+            Err(e) if e.is_not_found() => {
+                info!("✅ No transactions found for API key: {masked_key}");
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Merchant transactions by API key retrieved successfully",
+                    )
+                    .await;
+                (Vec::new(), 0)
+            }
             Err(e) => {
                 error!("❌ Failed to fetch transactions for API key {masked_key}: {e:?}",);
-                let error_message = match e {
-                    RepositoryError::NotFound => {
-                        "No transactions found for this API key".to_string()
-                    }
-                    _ => e.to_string(),
-                };
-
                 self.tracing_metrics_core
                     .complete_tracing_error(
                         &tracing_ctx,
                         method.clone(),
-                        &format!(
-                            "Failed to fetch transactions for API key: {}",
-                            error_message
-                        ),
+                        &format!("Failed to fetch transactions for API key: {e}"),
                     )
                     .await;
 
-                return match e {
-                    RepositoryError::NotFound => Err(ServiceError::NotFound(
-                        "No transactions found for this API key".to_string(),
-                    )),
-                    _ => Err(ServiceError::InternalServerError(e.to_string())),
-                };
+                return Err(ServiceError::InternalServerError(e.to_string()));
             }
         };
 
@@ -277,6 +280,7 @@ impl MerchantTransactionServiceTrait for MerchantTransactionService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
@@ -291,7 +295,7 @@ impl MerchantTransactionServiceTrait for MerchantTransactionService {
         req: &FindAllMerchantTransactionsById,
     ) -> Result<ApiResponsePagination<Vec<MerchantTransactionResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.trim().is_empty() {
             None
         } else {
@@ -362,35 +366,34 @@ impl MerchantTransactionServiceTrait for MerchantTransactionService {
                         .await;
                     (transactions, total_items)
                 }
+                Err(e) if e.is_not_found() => {
+                    info!(
+                        "✅ No transactions found for merchant ID {}",
+                        req.merchant_id
+                    );
+                    self.tracing_metrics_core
+                        .complete_tracing_success(
+                            &tracing_ctx,
+                            method,
+                            "Merchant transactions by ID retrieved successfully",
+                        )
+                        .await;
+                    (Vec::new(), 0)
+                }
                 Err(e) => {
                     error!(
                         "❌ Failed to fetch transactions for merchant ID {}: {e:?}",
                         req.merchant_id
                     );
-                    let error_message = match e {
-                        RepositoryError::NotFound => {
-                            "Merchant not found or has no transactions".to_string()
-                        }
-                        _ => e.to_string(),
-                    };
-
                     self.tracing_metrics_core
                         .complete_tracing_error(
                             &tracing_ctx,
                             method.clone(),
-                            &format!(
-                                "Failed to fetch transactions for merchant ID: {}",
-                                error_message
-                            ),
+                            &format!("Failed to fetch transactions for merchant ID: {e}"),
                         )
                         .await;
 
-                    return match e {
-                        RepositoryError::NotFound => Err(ServiceError::NotFound(
-                            "Merchant not found or has no transactions".to_string(),
-                        )),
-                        _ => Err(ServiceError::InternalServerError(e.to_string())),
-                    };
+                    return Err(ServiceError::InternalServerError(e.to_string()));
                 }
             };
 
@@ -411,6 +414,7 @@ impl MerchantTransactionServiceTrait for MerchantTransactionService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
@@ -419,4 +423,348 @@ impl MerchantTransactionServiceTrait for MerchantTransactionService {
 
         Ok(response)
     }
+
+    async fn find_daily_by_id(
+        &self,
+        req: &FindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<Vec<MerchantTransactionDailyResponse>>, ServiceError> {
+        info!(
+            "📅 Fetching daily transactions for merchant ID: {} | Range: {} - {}",
+            req.merchant_id, req.start_date, req.end_date
+        );
+
+        if req.start_date > req.end_date {
+            return Err(ServiceError::Validation(vec![
+                "start_date must not be after end_date".to_string(),
+            ]));
+        }
+
+        if (req.end_date - req.start_date).num_days() >= MAX_DAILY_RANGE_DAYS {
+            return Err(ServiceError::Validation(vec![format!(
+                "date range must not exceed {MAX_DAILY_RANGE_DAYS} days"
+            )]));
+        }
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "find_daily_merchant_transactions_by_id",
+            vec![
+                KeyValue::new("component", "merchant_transaction"),
+                KeyValue::new("operation", "find_daily_by_id"),
+                KeyValue::new("merchant_id", req.merchant_id.to_string()),
+                KeyValue::new("start_date", req.start_date.to_string()),
+                KeyValue::new("end_date", req.end_date.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let cache_key = format!(
+            "merchant_transaction:find_daily_by_id:merchant_id:{}:start:{}:end:{}",
+            req.merchant_id, req.start_date, req.end_date
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponse<Vec<MerchantTransactionDailyResponse>>>(&cache_key)
+            .await
+        {
+            info!("✅ Found daily merchant transactions in cache");
+            self.tracing_metrics_core
+                .complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Daily merchant transactions retrieved from cache",
+                )
+                .await;
+            return Ok(cache);
+        }
+
+        let buckets = match self.transaction.find_daily_transactions_by_id(req).await {
+            Ok(buckets) => {
+                info!(
+                    "✅ Found {} daily buckets for merchant ID {}",
+                    buckets.len(),
+                    req.merchant_id
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Daily merchant transactions retrieved successfully",
+                    )
+                    .await;
+                buckets
+            }
+            Err(e) => {
+                error!(
+                    "❌ Failed to fetch daily transactions for merchant ID {}: {e:?}",
+                    req.merchant_id
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to fetch daily merchant transactions: {:?}", e),
+                    )
+                    .await;
+                return Err(ServiceError::InternalServerError(e.to_string()));
+            }
+        };
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "Daily merchant transactions retrieved successfully".to_string(),
+            data: buckets
+                .into_iter()
+                .map(MerchantTransactionDailyResponse::from)
+                .collect(),
+        };
+
+        self.cache_store
+            .set_to_cache(&cache_key, &response, Duration::minutes(10))
+            .await;
+
+        Ok(response)
+    }
+
+    async fn find_api_key_usage_by_id(
+        &self,
+        req: &FindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<Vec<MerchantApiKeyUsageResponse>>, ServiceError> {
+        info!(
+            "🔑 Fetching API key usage for merchant ID: {} | Range: {} - {}",
+            req.merchant_id, req.start_date, req.end_date
+        );
+
+        if req.start_date > req.end_date {
+            return Err(ServiceError::Validation(vec![
+                "start_date must not be after end_date".to_string(),
+            ]));
+        }
+
+        if (req.end_date - req.start_date).num_days() >= MAX_DAILY_RANGE_DAYS {
+            return Err(ServiceError::Validation(vec![format!(
+                "date range must not exceed {MAX_DAILY_RANGE_DAYS} days"
+            )]));
+        }
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "find_merchant_api_key_usage_by_id",
+            vec![
+                KeyValue::new("component", "merchant_transaction"),
+                KeyValue::new("operation", "find_api_key_usage_by_id"),
+                KeyValue::new("merchant_id", req.merchant_id.to_string()),
+                KeyValue::new("start_date", req.start_date.to_string()),
+                KeyValue::new("end_date", req.end_date.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let cache_key = format!(
+            "merchant_transaction:find_api_key_usage_by_id:merchant_id:{}:start:{}:end:{}",
+            req.merchant_id, req.start_date, req.end_date
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponse<Vec<MerchantApiKeyUsageResponse>>>(&cache_key)
+            .await
+        {
+            info!("✅ Found merchant API key usage in cache");
+            self.tracing_metrics_core
+                .complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Merchant API key usage retrieved from cache",
+                )
+                .await;
+            return Ok(cache);
+        }
+
+        let rows = match self.transaction.find_api_key_usage_by_id(req).await {
+            Ok(rows) => {
+                info!(
+                    "✅ Found {} API key usage buckets for merchant ID {}",
+                    rows.len(),
+                    req.merchant_id
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Merchant API key usage retrieved successfully",
+                    )
+                    .await;
+                rows
+            }
+            Err(e) => {
+                error!(
+                    "❌ Failed to fetch API key usage for merchant ID {}: {e:?}",
+                    req.merchant_id
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to fetch merchant API key usage: {:?}", e),
+                    )
+                    .await;
+                return Err(ServiceError::InternalServerError(e.to_string()));
+            }
+        };
+
+        let mut usage: Vec<(String, MerchantApiKeyUsageResponse)> = Vec::new();
+        for row in rows {
+            let index = match usage.iter().position(|(key, _)| *key == row.api_key) {
+                Some(index) => index,
+                None => {
+                    usage.push((
+                        row.api_key.clone(),
+                        MerchantApiKeyUsageResponse {
+                            api_key: mask_api_key(&row.api_key),
+                            total_transactions: 0,
+                            total_amount: 0,
+                            daily: Vec::new(),
+                        },
+                    ));
+                    usage.len() - 1
+                }
+            };
+
+            let entry = &mut usage[index].1;
+            entry.total_transactions += row.total_transactions as i32;
+            entry.total_amount += row.total_amount;
+            entry.daily.push(MerchantTransactionDailyResponse {
+                day: row.day.to_string(),
+                total_transactions: row.total_transactions as i32,
+                total_amount: row.total_amount,
+            });
+        }
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "Merchant API key usage retrieved successfully".to_string(),
+            data: usage.into_iter().map(|(_, entry)| entry).collect(),
+        };
+
+        self.cache_store
+            .set_to_cache(&cache_key, &response, Duration::minutes(10))
+            .await;
+
+        Ok(response)
+    }
+
+    async fn settlement(
+        &self,
+        req: &FindMerchantTransactionsDaily,
+    ) -> Result<ApiResponse<MerchantSettlementResponse>, ServiceError> {
+        info!(
+            "💰 Computing settlement for merchant ID: {} | Range: {} - {}",
+            req.merchant_id, req.start_date, req.end_date
+        );
+
+        if req.start_date > req.end_date {
+            return Err(ServiceError::Validation(vec![
+                "start_date must not be after end_date".to_string(),
+            ]));
+        }
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "find_merchant_settlement",
+            vec![
+                KeyValue::new("component", "merchant_transaction"),
+                KeyValue::new("operation", "settlement"),
+                KeyValue::new("merchant_id", req.merchant_id.to_string()),
+                KeyValue::new("start_date", req.start_date.to_string()),
+                KeyValue::new("end_date", req.end_date.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let cache_key = format!(
+            "merchant_transaction:settlement:merchant_id:{}:start:{}:end:{}",
+            req.merchant_id, req.start_date, req.end_date
+        );
+
+        if req.is_closed()
+            && let Some(cache) = self
+                .cache_store
+                .get_from_cache::<ApiResponse<MerchantSettlementResponse>>(&cache_key)
+                .await
+        {
+            info!("✅ Found merchant settlement in cache");
+            self.tracing_metrics_core
+                .complete_tracing_success(
+                    &tracing_ctx,
+                    method,
+                    "Merchant settlement retrieved from cache",
+                )
+                .await;
+            return Ok(cache);
+        }
+
+        let settlement = match self.transaction.settlement(req).await {
+            Ok(settlement) => {
+                info!(
+                    "✅ Computed settlement for merchant ID {}: net={}",
+                    req.merchant_id, settlement.net
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Merchant settlement retrieved successfully",
+                    )
+                    .await;
+                settlement
+            }
+            Err(e) => {
+                error!(
+                    "❌ Failed to compute settlement for merchant ID {}: {e:?}",
+                    req.merchant_id
+                );
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to compute merchant settlement: {:?}", e),
+                    )
+                    .await;
+                return Err(ServiceError::InternalServerError(e.to_string()));
+            }
+        };
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "Merchant settlement retrieved successfully".to_string(),
+            data: MerchantSettlementResponse {
+                merchant_id: req.merchant_id,
+                start_date: req.start_date.to_string(),
+                end_date: req.end_date.to_string(),
+                gross: settlement.gross,
+                fees: settlement.fees,
+                refunds: settlement.refunds,
+                net: settlement.net,
+            },
+        };
+
+        if req.is_closed() {
+            self.cache_store
+                .set_to_cache(&cache_key, &response, Duration::minutes(10))
+                .await;
+        }
+
+        Ok(response)
+    }
 }
@@ -1,3 +1,4 @@
+pub mod anomaly;
 pub mod auth;
 pub mod card;
 pub mod merchant;
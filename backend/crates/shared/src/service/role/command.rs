@@ -6,7 +6,7 @@ use crate::{
     context::shared_resources::SharedResources,
     domain::{
         requests::role::{CreateRoleRequest, UpdateRoleRequest},
-        responses::{ApiResponse, RoleResponse, RoleResponseDeleteAt},
+        responses::{ApiResponse, BulkResult, RoleResponse, RoleResponseDeleteAt},
     },
     errors::{ServiceError, format_validation_errors},
     observability::{Method, TracingMetrics},
@@ -349,7 +349,7 @@ impl RoleCommandServiceTrait for RoleCommandService {
         }
     }
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("🔄 Restoring ALL trashed roles");
 
         let method = Method::Post;
@@ -366,7 +366,7 @@ impl RoleCommandServiceTrait for RoleCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.restore_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All roles restored successfully");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -389,7 +389,7 @@ impl RoleCommandServiceTrait for RoleCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "🔄 All roles restored successfully!".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -406,7 +406,7 @@ impl RoleCommandServiceTrait for RoleCommandService {
         }
     }
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("💣 Permanently deleting ALL trashed roles");
 
         let method = Method::Post;
@@ -423,7 +423,7 @@ impl RoleCommandServiceTrait for RoleCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.delete_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All roles permanently deleted");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -446,7 +446,7 @@ impl RoleCommandServiceTrait for RoleCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "💣 All roles permanently deleted!".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -5,7 +5,7 @@ use crate::{
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::{
-        requests::role::FindAllRoles,
+        requests::{role::FindAllRoles, trashed::TrashedSort},
         responses::{
             ApiResponse, ApiResponsePagination, Pagination, RoleResponse, RoleResponseDeleteAt,
         },
@@ -44,11 +44,7 @@ impl RoleQueryServiceTrait for RoleQueryService {
         request: &FindAllRoles,
     ) -> Result<ApiResponsePagination<Vec<RoleResponse>>, ServiceError> {
         let page = if request.page > 0 { request.page } else { 1 };
-        let page_size = if request.page_size > 0 {
-            request.page_size
-        } else {
-            10
-        };
+        let page_size = request.page_size.clamp(1, 100);
         let search = if request.search.is_empty() {
             None
         } else {
@@ -133,6 +129,7 @@ impl RoleQueryServiceTrait for RoleQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
@@ -152,11 +149,7 @@ impl RoleQueryServiceTrait for RoleQueryService {
         request: &FindAllRoles,
     ) -> Result<ApiResponsePagination<Vec<RoleResponseDeleteAt>>, ServiceError> {
         let page = if request.page > 0 { request.page } else { 1 };
-        let page_size = if request.page_size > 0 {
-            request.page_size
-        } else {
-            10
-        };
+        let page_size = request.page_size.clamp(1, 100);
         let search = if request.search.is_empty() {
             None
         } else {
@@ -242,6 +235,7 @@ impl RoleQueryServiceTrait for RoleQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
@@ -260,12 +254,14 @@ impl RoleQueryServiceTrait for RoleQueryService {
         &self,
         request: &FindAllRoles,
     ) -> Result<ApiResponsePagination<Vec<RoleResponseDeleteAt>>, ServiceError> {
-        let page = if request.page > 0 { request.page } else { 1 };
-        let page_size = if request.page_size > 0 {
-            request.page_size
-        } else {
-            10
+        let Some(sort) = TrashedSort::parse(request.sort_by.as_deref()) else {
+            return Err(ServiceError::Validation(vec![
+                TrashedSort::validation_error(),
+            ]));
         };
+
+        let page = if request.page > 0 { request.page } else { 1 };
+        let page_size = request.page_size.clamp(1, 100);
         let search = if request.search.is_empty() {
             None
         } else {
@@ -297,8 +293,9 @@ impl RoleQueryServiceTrait for RoleQueryService {
             .inject_trace_context(&tracing_ctx.cx, &mut request_obj);
 
         let cache_key = format!(
-            "role:find_trashed:page:{page}:size:{page_size}:search:{}",
-            search.unwrap_or_default()
+            "role:find_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            search.unwrap_or_default(),
+            sort.as_str()
         );
 
         if let Some(cache) = self
@@ -351,6 +348,7 @@ impl RoleQueryServiceTrait for RoleQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
         self.cache_store
             .set_to_cache(&cache_key, &response, Duration::minutes(10))
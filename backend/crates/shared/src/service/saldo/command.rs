@@ -10,7 +10,7 @@ use crate::{
     context::shared_resources::SharedResources,
     domain::{
         requests::saldo::{CreateSaldoRequest, UpdateSaldoRequest},
-        responses::{ApiResponse, SaldoResponse, SaldoResponseDeleteAt},
+        responses::{ApiResponse, BulkResult, SaldoResponse, SaldoResponseDeleteAt},
     },
     errors::{ServiceError, format_validation_errors},
     observability::{Method, TracingMetrics},
@@ -29,11 +29,13 @@ pub struct SaldoCommandService {
     pub card_query: DynCardQueryRepository,
     pub tracing_metrics_core: TracingMetrics,
     pub cache_store: Arc<CacheStore>,
+    pub default_initial_balance: i64,
 }
 
 pub struct SaldoCommandServiceDeps {
     pub card_query: DynCardQueryRepository,
     pub command: DynSaldoCommandRepository,
+    pub default_initial_balance: i64,
 }
 
 impl SaldoCommandService {
@@ -41,11 +43,13 @@ impl SaldoCommandService {
         let SaldoCommandServiceDeps {
             card_query,
             command,
+            default_initial_balance,
         } = deps;
 
         Ok(Self {
             card_query,
             command,
+            default_initial_balance,
             tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
             cache_store: Arc::clone(&shared.cache_store),
         })
@@ -106,6 +110,15 @@ impl SaldoCommandServiceTrait for SaldoCommandService {
             }
         };
 
+        let request = &CreateSaldoRequest {
+            card_number: request.card_number.clone(),
+            total_balance: Some(
+                request
+                    .total_balance
+                    .unwrap_or(self.default_initial_balance),
+            ),
+        };
+
         let saldo = match self.command.create(request).await {
             Ok(saldo) => {
                 info!(
@@ -430,7 +443,7 @@ impl SaldoCommandServiceTrait for SaldoCommandService {
         }
     }
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("♻️ Restoring all trashed saldos");
 
         let method = Method::Post;
@@ -447,7 +460,7 @@ impl SaldoCommandServiceTrait for SaldoCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.restore_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("All saldos restored successfully");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -470,7 +483,7 @@ impl SaldoCommandServiceTrait for SaldoCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "All saldos restored successfully".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -487,7 +500,7 @@ impl SaldoCommandServiceTrait for SaldoCommandService {
         }
     }
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("💀 Permanently deleting all trashed saldos");
 
         let method = Method::Post;
@@ -504,7 +517,7 @@ impl SaldoCommandServiceTrait for SaldoCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.delete_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("All saldos permanently deleted");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -527,7 +540,7 @@ impl SaldoCommandServiceTrait for SaldoCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "All saldos permanently deleted".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
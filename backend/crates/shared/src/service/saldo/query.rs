@@ -5,22 +5,30 @@ use crate::{
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::{
-        requests::saldo::FindAllSaldos,
+        requests::{
+            saldo::{FindAllSaldos, FindCardStatement, FindSaldoBalances},
+            trashed::TrashedSort,
+        },
         responses::{
-            ApiResponse, ApiResponsePagination, Pagination, SaldoResponse, SaldoResponseDeleteAt,
+            ApiResponse, ApiResponsePagination, CardStatementResponse, Pagination,
+            SaldoBalancesResponse, SaldoResponse, SaldoResponseDeleteAt,
         },
     },
-    errors::ServiceError,
+    errors::{ServiceError, format_validation_errors},
     observability::{Method, TracingMetrics},
     utils::mask_card_number,
 };
 use anyhow::Result;
 use async_trait::async_trait;
-use chrono::Duration;
+use chrono::{Duration, NaiveDate};
 use opentelemetry::KeyValue;
-use std::sync::Arc;
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
 use tonic::Request;
 use tracing::{error, info};
+use validator::Validate;
 
 pub struct SaldoQueryService {
     pub query: DynSaldoQueryRepository,
@@ -45,7 +53,7 @@ impl SaldoQueryServiceTrait for SaldoQueryService {
         req: &FindAllSaldos,
     ) -> Result<ApiResponsePagination<Vec<SaldoResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -131,6 +139,7 @@ impl SaldoQueryServiceTrait for SaldoQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
         self.cache_store
             .set_to_cache(&cache_key, &response, Duration::minutes(10))
@@ -149,7 +158,7 @@ impl SaldoQueryServiceTrait for SaldoQueryService {
         req: &FindAllSaldos,
     ) -> Result<ApiResponsePagination<Vec<SaldoResponseDeleteAt>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -237,6 +246,7 @@ impl SaldoQueryServiceTrait for SaldoQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
         self.cache_store
             .set_to_cache(&cache_key, &response, Duration::minutes(10))
@@ -254,8 +264,14 @@ impl SaldoQueryServiceTrait for SaldoQueryService {
         &self,
         req: &FindAllSaldos,
     ) -> Result<ApiResponsePagination<Vec<SaldoResponseDeleteAt>>, ServiceError> {
+        let Some(sort) = TrashedSort::parse(req.sort_by.as_deref()) else {
+            return Err(ServiceError::Validation(vec![
+                TrashedSort::validation_error(),
+            ]));
+        };
+
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -287,8 +303,9 @@ impl SaldoQueryServiceTrait for SaldoQueryService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         let cache_key = format!(
-            "saldo:find_trashed:page:{page}:size:{page_size}:search:{}",
-            search.unwrap_or_default()
+            "saldo:find_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            search.unwrap_or_default(),
+            sort.as_str()
         );
 
         if let Some(cache) = self
@@ -343,6 +360,7 @@ impl SaldoQueryServiceTrait for SaldoQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
@@ -412,7 +430,11 @@ impl SaldoQueryServiceTrait for SaldoQueryService {
                 self.tracing_metrics_core
                     .complete_tracing_error(&tracing_ctx, method.clone(), "Database error")
                     .await;
-                return Err(ServiceError::Custom(e.to_string()));
+                return Err(if e.is_not_found() {
+                    ServiceError::NotFound(format!("Saldo for card_number {masked_card} not found"))
+                } else {
+                    ServiceError::Custom(e.to_string())
+                });
             }
         };
 
@@ -474,7 +496,11 @@ impl SaldoQueryServiceTrait for SaldoQueryService {
                 self.tracing_metrics_core
                     .complete_tracing_error(&tracing_ctx, method.clone(), "Database error")
                     .await;
-                return Err(ServiceError::Custom(e.to_string()));
+                return Err(if e.is_not_found() {
+                    ServiceError::NotFound(format!("Saldo with ID {id} not found"))
+                } else {
+                    ServiceError::Custom(e.to_string())
+                });
             }
         };
 
@@ -490,4 +516,210 @@ impl SaldoQueryServiceTrait for SaldoQueryService {
 
         Ok(response)
     }
+
+    async fn get_balances(
+        &self,
+        request: &FindSaldoBalances,
+    ) -> Result<ApiResponse<SaldoBalancesResponse>, ServiceError> {
+        if let Err(validation_errors) = request.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Validation(vec![error_msg]));
+        }
+
+        let card_numbers: Vec<String> = request
+            .card_numbers
+            .iter()
+            .map(|card| card.trim().to_string())
+            .filter(|card| !card.is_empty())
+            .collect::<BTreeSet<_>>()
+            .into_iter()
+            .collect();
+
+        info!("💳 Fetching balances for {} cards", card_numbers.len());
+
+        let method = Method::Get;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "get_saldo_balances",
+            vec![
+                KeyValue::new("component", "saldo"),
+                KeyValue::new("operation", "get_balances"),
+                KeyValue::new("card_count", card_numbers.len() as i64),
+            ],
+        );
+
+        let mut request = Request::new(card_numbers.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let rows = match self.query.find_balances_by_cards(&card_numbers).await {
+            Ok(rows) => rows,
+            Err(e) => {
+                error!("❌ Database error while fetching saldo balances: {:?}", e);
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Database error")
+                    .await;
+                return Err(ServiceError::Repo(e));
+            }
+        };
+
+        let balances: BTreeMap<String, i64> = rows.into_iter().collect();
+
+        let missing_card_numbers: Vec<String> = card_numbers
+            .into_iter()
+            .filter(|card| !balances.contains_key(card))
+            .collect();
+
+        info!(
+            "✅ Found {} balances, {} cards missing",
+            balances.len(),
+            missing_card_numbers.len()
+        );
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Saldo balances retrieved")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Saldo balances retrieved successfully".to_string(),
+            data: SaldoBalancesResponse {
+                balances,
+                missing_card_numbers,
+            },
+        })
+    }
+
+    async fn get_statement(
+        &self,
+        request: &FindCardStatement,
+    ) -> Result<ApiResponse<CardStatementResponse>, ServiceError> {
+        if let Err(validation_errors) = request.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Validation(vec![error_msg]));
+        }
+
+        let masked_card = mask_card_number(&request.card_number);
+        info!(
+            "🧾 Building statement for card_number={masked_card}, {}-{:02}",
+            request.year, request.month
+        );
+
+        let (start, end) = match statement_bounds(request.year, request.month) {
+            Some(bounds) => bounds,
+            None => {
+                return Err(ServiceError::Validation(vec![
+                    "Bulan atau tahun tidak valid".to_string(),
+                ]));
+            }
+        };
+
+        let method = Method::Get;
+
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "get_card_statement",
+            vec![
+                KeyValue::new("component", "saldo"),
+                KeyValue::new("operation", "get_statement"),
+                KeyValue::new("card_number", masked_card.clone()),
+                KeyValue::new("year", request.year as i64),
+                KeyValue::new("month", request.month as i64),
+            ],
+        );
+
+        let mut grpc_request = Request::new(request.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut grpc_request);
+
+        let cache_key = format!(
+            "saldo:statement:{}:{}-{:02}",
+            request.card_number, request.year, request.month
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponse<CardStatementResponse>>(&cache_key)
+            .await
+        {
+            info!("✅ Found card statement in cache");
+            self.tracing_metrics_core
+                .complete_tracing_success(&tracing_ctx, method, "Statement retrieved from cache")
+                .await;
+            return Ok(cache);
+        }
+
+        if let Err(e) = self.query.find_by_card(&request.card_number).await {
+            error!("❌ Failed to find saldo for card_number={masked_card}: {e:?}");
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method, "Saldo not found")
+                .await;
+            return Err(if e.is_not_found() {
+                ServiceError::NotFound(format!("Saldo for card_number {masked_card} not found"))
+            } else {
+                ServiceError::Repo(e)
+            });
+        }
+
+        let statement = match self
+            .query
+            .find_statement(&request.card_number, start, end)
+            .await
+        {
+            Ok(statement) => statement,
+            Err(e) => {
+                error!("❌ Database error while building statement: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Database error")
+                    .await;
+                return Err(ServiceError::Repo(e));
+            }
+        };
+
+        let data = CardStatementResponse::from_model(
+            request.card_number.clone(),
+            request.year,
+            request.month,
+            statement,
+        );
+
+        info!(
+            "✅ Statement for card_number={masked_card} has {} entries, opening={}, closing={}",
+            data.entries.len(),
+            data.opening_balance,
+            data.closing_balance
+        );
+
+        let response = ApiResponse {
+            status: "success".to_string(),
+            message: "Card statement retrieved successfully".to_string(),
+            data,
+        };
+
+        self.cache_store
+            .set_to_cache(&cache_key, &response, Duration::minutes(1))
+            .await;
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Card statement retrieved")
+            .await;
+
+        Ok(response)
+    }
+}
+
+fn statement_bounds(
+    year: i32,
+    month: i32,
+) -> Option<(chrono::NaiveDateTime, chrono::NaiveDateTime)> {
+    let month = u32::try_from(month).ok()?;
+    let start = NaiveDate::from_ymd_opt(year, month, 1)?;
+    let end = if month == 12 {
+        NaiveDate::from_ymd_opt(year + 1, 1, 1)?
+    } else {
+        NaiveDate::from_ymd_opt(year, month + 1, 1)?
+    };
+
+    Some((start.and_hms_opt(0, 0, 0)?, end.and_hms_opt(0, 0, 0)?))
 }
@@ -24,7 +24,24 @@ impl TokenService {
 #[async_trait]
 impl TokenServiceTrait for TokenService {
     async fn create_access_token(&self, id: i32) -> Result<String, ServiceError> {
-        match self.token.generate_token(id as i64, "access") {
+        let auth_time = Utc::now().timestamp() as usize;
+        self.renew_access_token(id, Some(auth_time)).await
+    }
+
+    async fn create_refresh_token(&self, id: i32) -> Result<String, ServiceError> {
+        let auth_time = Utc::now().timestamp() as usize;
+        self.renew_refresh_token(id, Some(auth_time)).await
+    }
+
+    async fn renew_access_token(
+        &self,
+        id: i32,
+        auth_time: Option<usize>,
+    ) -> Result<String, ServiceError> {
+        match self
+            .token
+            .generate_token_with_auth_time(id as i64, "access", auth_time)
+        {
             Ok(token) => {
                 info!("✅ Successfully generated access token for user_id: {}", id);
                 Ok(token)
@@ -39,8 +56,14 @@ impl TokenServiceTrait for TokenService {
         }
     }
 
-    async fn create_refresh_token(&self, id: i32) -> Result<String, ServiceError> {
-        let token = self.token.generate_token(id as i64, "refresh")?;
+    async fn renew_refresh_token(
+        &self,
+        id: i32,
+        auth_time: Option<usize>,
+    ) -> Result<String, ServiceError> {
+        let token = self
+            .token
+            .generate_token_with_auth_time(id as i64, "refresh", auth_time)?;
 
         if let Err(e) = self.refresh.delete_by_user_id(id).await {
             error!("❌ Failed to delete existing refresh token: {e:?}");
@@ -67,4 +90,20 @@ impl TokenServiceTrait for TokenService {
             }
         }
     }
+
+    async fn create_password_change_token(&self, id: i32) -> Result<String, ServiceError> {
+        match self.token.generate_token(id as i64, "password_change") {
+            Ok(token) => {
+                info!("✅ Generated password change token for user_id: {}", id);
+                Ok(token)
+            }
+            Err(e) => {
+                error!(
+                    "❌ Failed to generate password change token for user_id {}: {e:?}",
+                    id,
+                );
+                Err(e)
+            }
+        }
+    }
 }
@@ -10,12 +10,13 @@ use crate::{
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::requests::{
-        saldo::UpdateSaldoBalance,
+        saldo::ApplySaldoLedgerEntry,
         topup::{CreateTopupRequest, UpdateTopupAmount, UpdateTopupRequest, UpdateTopupStatus},
     },
-    domain::responses::{ApiResponse, TopupResponse, TopupResponseDeleteAt},
+    domain::responses::{ApiResponse, BulkResult, TopupResponse, TopupResponseDeleteAt},
     errors::{ServiceError, format_validation_errors},
     observability::{Method, TracingMetrics},
+    service::card::usable::ensure_card_usable,
     utils::mask_card_number,
 };
 use anyhow::Result;
@@ -26,6 +27,8 @@ use tonic::Request;
 use tracing::{error, info};
 use validator::Validate;
 
+const LEDGER_REFERENCE_TOPUP: &str = "topup";
+
 pub struct TopupCommandService {
     pub card_query: DynCardQueryRepository,
     pub saldo_query: DynSaldoQueryRepository,
@@ -112,6 +115,13 @@ impl TopupCommandServiceTrait for TopupCommandService {
             }
         };
 
+        if let Err(e) = ensure_card_usable(&self.card_query, &req.card_number).await {
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), &e.to_string())
+                .await;
+            return Err(e);
+        }
+
         let topup = match self.command.create(req).await {
             Ok(t) => t,
             Err(e) => {
@@ -123,10 +133,34 @@ impl TopupCommandServiceTrait for TopupCommandService {
             }
         };
 
-        let mut saldo = match self.saldo_query.find_by_card(&req.card_number).await {
-            Ok(s) => s,
+        if let Err(e) = self.saldo_query.find_by_card(&req.card_number).await {
+            error!("❌ Failed to find saldo: {e:?}");
+            let _ = self
+                .command
+                .update_status(&UpdateTopupStatus {
+                    topup_id: topup.topup_id,
+                    status: "failed".to_string(),
+                })
+                .await;
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), "Saldo not found")
+                .await;
+            return Err(ServiceError::Custom("saldo not found".into()));
+        }
+
+        let new_balance = match self
+            .saldo_command
+            .apply_ledger_entry(&ApplySaldoLedgerEntry {
+                card_number: req.card_number.clone(),
+                amount: req.topup_amount,
+                reference_type: LEDGER_REFERENCE_TOPUP,
+                reference_id: topup.topup_id,
+            })
+            .await
+        {
+            Ok(saldo) => saldo.total_balance,
             Err(e) => {
-                error!("❌ Failed to find saldo: {e:?}");
+                error!("❌ Failed to update saldo: {e:?}");
                 let _ = self
                     .command
                     .update_status(&UpdateTopupStatus {
@@ -135,37 +169,12 @@ impl TopupCommandServiceTrait for TopupCommandService {
                     })
                     .await;
                 self.tracing_metrics_core
-                    .complete_tracing_error(&tracing_ctx, method.clone(), "Saldo not found")
+                    .complete_tracing_error(&tracing_ctx, method.clone(), "Failed to update saldo")
                     .await;
-                return Err(ServiceError::Custom("saldo not found".into()));
+                return Err(ServiceError::Custom("failed to update saldo".into()));
             }
         };
 
-        let new_balance = saldo.total_balance + req.topup_amount;
-        saldo.total_balance = new_balance;
-
-        if let Err(e) = self
-            .saldo_command
-            .update_balance(&UpdateSaldoBalance {
-                card_number: req.card_number.clone(),
-                total_balance: new_balance,
-            })
-            .await
-        {
-            error!("❌ Failed to update saldo: {e:?}");
-            let _ = self
-                .command
-                .update_status(&UpdateTopupStatus {
-                    topup_id: topup.topup_id,
-                    status: "failed".to_string(),
-                })
-                .await;
-            self.tracing_metrics_core
-                .complete_tracing_error(&tracing_ctx, method.clone(), "Failed to update saldo")
-                .await;
-            return Err(ServiceError::Custom("failed to update saldo".into()));
-        }
-
         if let Err(e) = self
             .command
             .update_status(&UpdateTopupStatus {
@@ -265,6 +274,13 @@ impl TopupCommandServiceTrait for TopupCommandService {
             return Err(ServiceError::Custom("card not found".into()));
         }
 
+        if let Err(e) = ensure_card_usable(&self.card_query, &req.card_number).await {
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), &e.to_string())
+                .await;
+            return Err(e);
+        }
+
         let existing = match self.query.find_by_id(topup_id).await {
             Ok(topup) => {
                 info!("✅ Found topup with ID: {topup_id}");
@@ -304,10 +320,42 @@ impl TopupCommandServiceTrait for TopupCommandService {
             return Err(ServiceError::Custom("failed to update topup".into()));
         }
 
-        let mut saldo = match self.saldo_query.find_by_card(&req.card_number).await {
-            Ok(s) => s,
+        if let Err(e) = self.saldo_query.find_by_card(&req.card_number).await {
+            error!("❌ Failed to get saldo: {e:?}");
+            let _ = self
+                .command
+                .update_status(&UpdateTopupStatus {
+                    topup_id,
+                    status: "failed".to_string(),
+                })
+                .await;
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), "Saldo not found")
+                .await;
+            return Err(ServiceError::Custom("saldo not found".into()));
+        }
+
+        let new_balance = match self
+            .saldo_command
+            .apply_ledger_entry(&ApplySaldoLedgerEntry {
+                card_number: req.card_number.clone(),
+                amount: difference,
+                reference_type: LEDGER_REFERENCE_TOPUP,
+                reference_id: topup_id,
+            })
+            .await
+        {
+            Ok(saldo) => saldo.total_balance,
             Err(e) => {
-                error!("❌ Failed to get saldo: {e:?}");
+                error!("❌ Failed to update saldo: {e:?}");
+
+                let _ = self
+                    .command
+                    .update_amount(&UpdateTopupAmount {
+                        topup_id,
+                        topup_amount: existing.topup_amount,
+                    })
+                    .await;
                 let _ = self
                     .command
                     .update_status(&UpdateTopupStatus {
@@ -316,45 +364,12 @@ impl TopupCommandServiceTrait for TopupCommandService {
                     })
                     .await;
                 self.tracing_metrics_core
-                    .complete_tracing_error(&tracing_ctx, method.clone(), "Saldo not found")
+                    .complete_tracing_error(&tracing_ctx, method.clone(), "Failed to update saldo")
                     .await;
-                return Err(ServiceError::Custom("saldo not found".into()));
+                return Err(ServiceError::Custom("failed to update saldo".into()));
             }
         };
 
-        let new_balance = saldo.total_balance + difference;
-        saldo.total_balance = new_balance;
-
-        if let Err(e) = self
-            .saldo_command
-            .update_balance(&UpdateSaldoBalance {
-                card_number: req.card_number.clone(),
-                total_balance: saldo.total_balance,
-            })
-            .await
-        {
-            error!("❌ Failed to update saldo: {e:?}");
-
-            let _ = self
-                .command
-                .update_amount(&UpdateTopupAmount {
-                    topup_id,
-                    topup_amount: existing.topup_amount,
-                })
-                .await;
-            let _ = self
-                .command
-                .update_status(&UpdateTopupStatus {
-                    topup_id,
-                    status: "failed".to_string(),
-                })
-                .await;
-            self.tracing_metrics_core
-                .complete_tracing_error(&tracing_ctx, method.clone(), "Failed to update saldo")
-                .await;
-            return Err(ServiceError::Custom("failed to update saldo".into()));
-        }
-
         let updated_topup = match self.query.find_by_id(topup_id).await {
             Ok(topup) => {
                 info!("✅ Found topup with ID: {topup_id}");
@@ -616,7 +631,7 @@ impl TopupCommandServiceTrait for TopupCommandService {
         }
     }
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("🔄 Restoring all trashed topups");
 
         let method = Method::Post;
@@ -633,7 +648,7 @@ impl TopupCommandServiceTrait for TopupCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.restore_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All topups restored successfully");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -656,7 +671,7 @@ impl TopupCommandServiceTrait for TopupCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "All topups restored successfully".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -675,7 +690,7 @@ impl TopupCommandServiceTrait for TopupCommandService {
         }
     }
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("💣 Permanently deleting all trashed topups");
 
         let method = Method::Post;
@@ -692,7 +707,7 @@ impl TopupCommandServiceTrait for TopupCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.delete_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All topups permanently deleted");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -715,7 +730,7 @@ impl TopupCommandServiceTrait for TopupCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "All topups permanently deleted".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -5,7 +5,10 @@ use crate::{
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::{
-        requests::topup::{FindAllTopups, FindAllTopupsByCardNumber},
+        requests::{
+            topup::{FindAllTopups, FindAllTopupsByCardNumber},
+            trashed::TrashedSort,
+        },
         responses::{
             ApiResponse, ApiResponsePagination, Pagination, TopupResponse, TopupResponseDeleteAt,
         },
@@ -45,7 +48,7 @@ impl TopupQueryServiceTrait for TopupQueryService {
         req: &FindAllTopups,
     ) -> Result<ApiResponsePagination<Vec<TopupResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -130,6 +133,7 @@ impl TopupQueryServiceTrait for TopupQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
         self.cache_store
             .set_to_cache(&cache_key, &response, Duration::minutes(10))
@@ -152,7 +156,7 @@ impl TopupQueryServiceTrait for TopupQueryService {
         }
 
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -251,6 +255,7 @@ impl TopupQueryServiceTrait for TopupQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -311,7 +316,11 @@ impl TopupQueryServiceTrait for TopupQueryService {
                 self.tracing_metrics_core
                     .complete_tracing_error(&tracing_ctx, method.clone(), "Database error")
                     .await;
-                return Err(ServiceError::Custom(e.to_string()));
+                return Err(if e.is_not_found() {
+                    ServiceError::NotFound(format!("Topup with ID {topup_id} not found"))
+                } else {
+                    ServiceError::Custom(e.to_string())
+                });
             }
         };
 
@@ -398,7 +407,7 @@ impl TopupQueryServiceTrait for TopupQueryService {
         req: &FindAllTopups,
     ) -> Result<ApiResponsePagination<Vec<TopupResponseDeleteAt>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -485,6 +494,7 @@ impl TopupQueryServiceTrait for TopupQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
         self.cache_store
             .set_to_cache(&cache_key, &response, Duration::minutes(10))
@@ -502,8 +512,14 @@ impl TopupQueryServiceTrait for TopupQueryService {
         &self,
         req: &FindAllTopups,
     ) -> Result<ApiResponsePagination<Vec<TopupResponseDeleteAt>>, ServiceError> {
+        let Some(sort) = TrashedSort::parse(req.sort_by.as_deref()) else {
+            return Err(ServiceError::Validation(vec![
+                TrashedSort::validation_error(),
+            ]));
+        };
+
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -534,8 +550,9 @@ impl TopupQueryServiceTrait for TopupQueryService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         let cache_key = format!(
-            "topup:find_by_trashed:page:{page}:size:{page_size}:search:{}",
-            search.unwrap_or_default()
+            "topup:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            search.unwrap_or_default(),
+            sort.as_str()
         );
 
         if let Some(cache) = self
@@ -590,6 +607,7 @@ impl TopupQueryServiceTrait for TopupQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
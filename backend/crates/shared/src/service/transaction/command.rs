@@ -1,26 +1,36 @@
 use crate::{
     abstract_trait::{
         card::repository::query::DynCardQueryRepository,
+        events::DynEventPublisher,
         merchant::repository::query::DynMerchantQueryRepository,
         saldo::repository::{command::DynSaldoCommandRepository, query::DynSaldoQueryRepository},
         transaction::{
             repository::{
-                command::DynTransactionCommandRepository, query::DynTransactionQueryRepository,
+                command::DynTransactionCommandRepository, fee::DynTransactionFeeRepository,
+                query::DynTransactionQueryRepository,
             },
             service::command::TransactionCommandServiceTrait,
         },
     },
     cache::CacheStore,
+    config::{FeeBreakdown, PaymentMethodsConfig, TransactionFeeConfig},
     context::shared_resources::SharedResources,
     domain::requests::{
-        saldo::UpdateSaldoBalance,
+        saldo::ApplySaldoLedgerEntry,
         transaction::{
             CreateTransactionRequest, UpdateTransactionRequest, UpdateTransactionStatus,
         },
     },
-    domain::responses::{ApiResponse, TransactionResponse, TransactionResponseDeleteAt},
-    errors::{ServiceError, format_validation_errors},
+    domain::responses::{
+        ApiResponse, BulkResult, TransactionResponse, TransactionResponseDeleteAt,
+    },
+    errors::{RepositoryError, ServiceError, format_validation_errors},
+    events::{DomainEvent, EVENT_TRANSACTION_CREATED},
+    model::transaction::TransactionFeeModel,
     observability::{Method, TracingMetrics},
+    resilience::WriteLimiter,
+    service::card::usable::ensure_card_usable,
+    utils::{mask_api_key, mask_card_number},
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -30,6 +40,9 @@ use tonic::Request;
 use tracing::{error, info};
 use validator::Validate;
 
+const LEDGER_REFERENCE_TRANSACTION: &str = "transaction";
+const UPDATABLE_STATUSES: &[&str] = &["pending", "success"];
+
 pub struct TransactionCommandService {
     pub query: DynTransactionQueryRepository,
     pub command: DynTransactionCommandRepository,
@@ -37,6 +50,11 @@ pub struct TransactionCommandService {
     pub saldo_query: DynSaldoQueryRepository,
     pub saldo_command: DynSaldoCommandRepository,
     pub card_query: DynCardQueryRepository,
+    pub fee: DynTransactionFeeRepository,
+    pub fee_config: TransactionFeeConfig,
+    pub payment_methods: PaymentMethodsConfig,
+    pub events: DynEventPublisher,
+    pub write_limiter: Arc<WriteLimiter>,
     pub tracing_metrics_core: TracingMetrics,
     pub cache_store: Arc<CacheStore>,
 }
@@ -48,6 +66,11 @@ pub struct TransactionCommandServiceDeps {
     pub saldo_query: DynSaldoQueryRepository,
     pub saldo_command: DynSaldoCommandRepository,
     pub card_query: DynCardQueryRepository,
+    pub fee: DynTransactionFeeRepository,
+    pub fee_config: TransactionFeeConfig,
+    pub payment_methods: PaymentMethodsConfig,
+    pub events: DynEventPublisher,
+    pub max_concurrent_writes: usize,
 }
 
 impl TransactionCommandService {
@@ -59,6 +82,11 @@ impl TransactionCommandService {
             saldo_query,
             saldo_command,
             card_query,
+            fee,
+            fee_config,
+            payment_methods,
+            events,
+            max_concurrent_writes,
         } = deps;
 
         Ok(Self {
@@ -68,10 +96,33 @@ impl TransactionCommandService {
             saldo_query,
             saldo_command,
             card_query,
+            fee,
+            fee_config,
+            payment_methods,
+            events,
+            write_limiter: Arc::new(WriteLimiter::new(max_concurrent_writes)),
             tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
             cache_store: Arc::clone(&shared.cache_store),
         })
     }
+
+    /// Marks the transaction successful and stores its fee in one statement.
+    async fn complete_with_fee(
+        &self,
+        transaction_id: i32,
+        breakdown: FeeBreakdown,
+    ) -> Result<TransactionFeeModel, RepositoryError> {
+        let fee = TransactionFeeModel {
+            transaction_id,
+            gross_amount: breakdown.gross,
+            fee_amount: breakdown.fee,
+            net_amount: breakdown.net,
+        };
+
+        self.fee.complete_with_fee(&fee).await?;
+
+        Ok(fee)
+    }
 }
 
 #[async_trait]
@@ -81,8 +132,9 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
         api_key: &str,
         req: &CreateTransactionRequest,
     ) -> Result<ApiResponse<TransactionResponse>, ServiceError> {
+        let masked_key = mask_api_key(api_key);
         info!(
-            "starting CreateTransaction process, api_key: {api_key}, req: {:?}",
+            "starting CreateTransaction process, api_key: {masked_key}, req: {:?}",
             req
         );
 
@@ -92,6 +144,11 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             return Err(ServiceError::Custom(error_msg));
         }
 
+        if let Err(error_msg) = self.payment_methods.check(&req.payment_method, req.amount) {
+            error!("Payment method rejected: {error_msg}");
+            return Err(ServiceError::Validation(vec![error_msg]));
+        }
+
         let method = Method::Post;
         let tracing_ctx = self.tracing_metrics_core.start_tracing(
             "create_transaction",
@@ -107,9 +164,23 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut request_with_trace);
 
+        let _write_permit = match self.write_limiter.try_acquire("transaction") {
+            Ok(permit) => permit,
+            Err(e) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        "Write capacity exhausted",
+                    )
+                    .await;
+                return Err(e);
+            }
+        };
+
         let merchant = match self.merchant_query.find_by_apikey(api_key).await {
             Ok(merchant) => {
-                info!("merchant found with api_key: {}", api_key);
+                info!("merchant found with api_key: {masked_key}");
                 self.tracing_metrics_core
                     .complete_tracing_success(
                         &tracing_ctx,
@@ -120,7 +191,7 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
                 merchant
             }
             Err(e) => {
-                error!("error finding merchant with api_key {}: {:?}", api_key, e);
+                error!("error finding merchant with api_key {masked_key}: {:?}", e);
                 self.tracing_metrics_core
                     .complete_tracing_error(
                         &tracing_ctx,
@@ -157,7 +228,22 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             }
         };
 
-        let mut saldo = match self.saldo_query.find_by_card(&req.card_number).await {
+        if let Err(e) = ensure_card_usable(&self.card_query, &card.card_number).await {
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), &e.to_string())
+                .await;
+            return Err(e);
+        }
+
+        let breakdown =
+            self.fee_config
+                .compute(merchant.merchant_id, &req.payment_method, req.amount);
+        info!(
+            "fee computed for merchant {}: gross={}, fee={}, net={}",
+            merchant.merchant_id, breakdown.gross, breakdown.fee, breakdown.net
+        );
+
+        let saldo = match self.saldo_query.find_by_card(&req.card_number).await {
             Ok(saldo) => {
                 info!("saldo found for card {}", req.card_number);
                 self.tracing_metrics_core
@@ -195,28 +281,6 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             return Err(ServiceError::Custom("insufficient balance".into()));
         }
 
-        saldo.total_balance -= req.amount;
-        if let Err(e) = self
-            .saldo_command
-            .update_balance(&UpdateSaldoBalance {
-                card_number: card.card_number.clone(),
-                total_balance: saldo.total_balance,
-            })
-            .await
-        {
-            error!("failed to update saldo {e:?}");
-
-            let error_msg = "failed to update saldo";
-            self.tracing_metrics_core
-                .complete_tracing_error(
-                    &tracing_ctx,
-                    method.clone(),
-                    &format!("{}: {:?}", error_msg, e),
-                )
-                .await;
-            return Err(ServiceError::Custom(error_msg.into()));
-        }
-
         let mut req_with_merchant = req.clone();
         req_with_merchant.merchant_id = Some(merchant.merchant_id);
 
@@ -230,32 +294,31 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
                     .complete_tracing_error(&tracing_ctx, method.clone(), &error_msg)
                     .await;
 
-                saldo.total_balance += req.amount;
-                if let Err(rollback_err) = self
-                    .saldo_command
-                    .update_balance(&UpdateSaldoBalance {
-                        card_number: card.card_number.clone(),
-                        total_balance: saldo.total_balance,
-                    })
-                    .await
-                {
-                    error!("failed to rollback saldo {rollback_err:?}");
-                }
-
                 return Err(ServiceError::Custom("failed to create transaction".into()));
             }
         };
 
         if let Err(e) = self
-            .command
-            .update_status(&UpdateTransactionStatus {
-                transaction_id: transaction.transaction_id,
-                status: "success".to_string(),
+            .saldo_command
+            .apply_ledger_entry(&ApplySaldoLedgerEntry {
+                card_number: card.card_number.clone(),
+                amount: -req.amount,
+                reference_type: LEDGER_REFERENCE_TRANSACTION,
+                reference_id: transaction.transaction_id,
             })
             .await
         {
-            error!("failed to update transaction status {e:?}");
-            let error_msg = "failed to update transaction status";
+            error!("failed to update saldo {e:?}");
+
+            let _ = self
+                .command
+                .update_status(&UpdateTransactionStatus {
+                    transaction_id: transaction.transaction_id,
+                    status: "failed".into(),
+                })
+                .await;
+
+            let error_msg = "failed to update saldo";
             self.tracing_metrics_core
                 .complete_tracing_error(
                     &tracing_ctx,
@@ -266,11 +329,14 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             return Err(ServiceError::Custom(error_msg.into()));
         }
 
-        let merchant_card = match self.card_query.find_by_user_id(merchant.user_id).await {
-            Ok(card) => card,
+        let fee = match self
+            .complete_with_fee(transaction.transaction_id, breakdown)
+            .await
+        {
+            Ok(fee) => fee,
             Err(e) => {
-                error!("error {e:?}");
-                let error_msg = "failed to fetch merchant card";
+                error!("failed to complete transaction with fee {e:?}");
+                let error_msg = "failed to complete transaction";
                 self.tracing_metrics_core
                     .complete_tracing_error(
                         &tracing_ctx,
@@ -282,15 +348,13 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             }
         };
 
-        let mut merchant_saldo = match self
-            .saldo_query
-            .find_by_card(&merchant_card.card_number)
-            .await
-        {
-            Ok(saldo) => saldo,
+        let metadata = req.metadata.clone().filter(|m| !m.is_empty());
+
+        let merchant_card = match self.card_query.find_by_user_id(merchant.user_id).await {
+            Ok(card) => card,
             Err(e) => {
                 error!("error {e:?}");
-                let error_msg = "failed to fetch merchant saldo";
+                let error_msg = "failed to fetch merchant card";
                 self.tracing_metrics_core
                     .complete_tracing_error(
                         &tracing_ctx,
@@ -302,13 +366,30 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             }
         };
 
-        merchant_saldo.total_balance += req.amount;
+        if let Err(e) = self
+            .saldo_query
+            .find_by_card(&merchant_card.card_number)
+            .await
+        {
+            error!("error {e:?}");
+            let error_msg = "failed to fetch merchant saldo";
+            self.tracing_metrics_core
+                .complete_tracing_error(
+                    &tracing_ctx,
+                    method.clone(),
+                    &format!("{}: {:?}", error_msg, e),
+                )
+                .await;
+            return Err(ServiceError::Custom(error_msg.into()));
+        }
 
         if let Err(e) = self
             .saldo_command
-            .update_balance(&UpdateSaldoBalance {
-                card_number: merchant_card.clone().card_number,
-                total_balance: merchant_saldo.total_balance,
+            .apply_ledger_entry(&ApplySaldoLedgerEntry {
+                card_number: merchant_card.card_number.clone(),
+                amount: fee.net_amount,
+                reference_type: LEDGER_REFERENCE_TRANSACTION,
+                reference_id: transaction.transaction_id,
             })
             .await
         {
@@ -332,6 +413,7 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             "transaction:find_all:*".to_string(),
             "transaction:find_by_active:*".to_string(),
             "transaction:find_by_trashed:*".to_string(),
+            "transaction:search:*".to_string(),
         ];
 
         for key in cache_keys {
@@ -339,10 +421,23 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             info!("Invalidated cache key: {}", key);
         }
 
-        let response = TransactionResponse::from(transaction);
+        let response = TransactionResponse::from(transaction)
+            .with_fee(&fee)
+            .with_metadata(metadata);
+
+        self.events.emit(DomainEvent::new(
+            EVENT_TRANSACTION_CREATED,
+            serde_json::json!({
+                "transaction_id": response.id,
+                "merchant_id": merchant.merchant_id,
+                "card_number": mask_card_number(&card.card_number),
+                "amount": req.amount,
+                "payment_method": req.payment_method,
+            }),
+        ));
 
         info!(
-            "CreateTransaction completed, api_key: {api_key}, transaction_id: {}",
+            "CreateTransaction completed, api_key: {masked_key}, transaction_id: {}",
             response.id
         );
 
@@ -369,6 +464,11 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             return Err(ServiceError::Custom(error_msg));
         }
 
+        if let Err(error_msg) = self.payment_methods.check(&req.payment_method, req.amount) {
+            error!("Payment method rejected: {error_msg}");
+            return Err(ServiceError::Validation(vec![error_msg]));
+        }
+
         let method = Method::Post;
         let tracing_ctx = self.tracing_metrics_core.start_tracing(
             "update_transaction",
@@ -445,6 +545,14 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             return Err(ServiceError::Custom(error_msg.into()));
         }
 
+        if let Err(e) = ensure_updatable(transaction_id, &transaction.status) {
+            error!("{e}");
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), &e.to_string())
+                .await;
+            return Err(e);
+        }
+
         let card = match self.card_query.find_by_card(&transaction.card_number).await {
             Ok(v) => v,
             Err(e) => {
@@ -463,12 +571,50 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             }
         };
 
-        let mut saldo = match self.saldo_query.find_by_card(&card.card_number).await {
-            Ok(v) => v,
+        if let Err(e) = ensure_card_usable(&self.card_query, &card.card_number).await {
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), &e.to_string())
+                .await;
+            return Err(e);
+        }
+
+        if let Err(e) = self.saldo_query.find_by_card(&card.card_number).await {
+            error!("failed to find saldo: {e:?}");
+
+            let error_msg = "saldo not found";
+            self.tracing_metrics_core
+                .complete_tracing_error(
+                    &tracing_ctx,
+                    method.clone(),
+                    &format!("{}: {:?}", error_msg, e),
+                )
+                .await;
+
+            return Err(ServiceError::Custom(error_msg.into()));
+        }
+
+        let saldo = match self
+            .saldo_command
+            .apply_ledger_entry(&ApplySaldoLedgerEntry {
+                card_number: card.card_number.clone(),
+                amount: transaction.amount as i64,
+                reference_type: LEDGER_REFERENCE_TRANSACTION,
+                reference_id: transaction_id,
+            })
+            .await
+        {
+            Ok(saldo) => saldo,
             Err(e) => {
-                error!("failed to find saldo: {e:?}");
+                error!("failed to restore balance: {e:?}");
+                let _ = self
+                    .command
+                    .update_status(&UpdateTransactionStatus {
+                        transaction_id,
+                        status: "failed".into(),
+                    })
+                    .await;
 
-                let error_msg = "saldo not found";
+                let error_msg = "failed to restore saldo";
                 self.tracing_metrics_core
                     .complete_tracing_error(
                         &tracing_ctx,
@@ -481,36 +627,6 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             }
         };
 
-        saldo.total_balance += transaction.amount as i64;
-        if let Err(e) = self
-            .saldo_command
-            .update_balance(&UpdateSaldoBalance {
-                card_number: card.card_number.clone(),
-                total_balance: saldo.total_balance,
-            })
-            .await
-        {
-            error!("failed to restore balance: {e:?}");
-            let _ = self
-                .command
-                .update_status(&UpdateTransactionStatus {
-                    transaction_id,
-                    status: "failed".into(),
-                })
-                .await;
-
-            let error_msg = "failed to restore saldo";
-            self.tracing_metrics_core
-                .complete_tracing_error(
-                    &tracing_ctx,
-                    method.clone(),
-                    &format!("{}: {:?}", error_msg, e),
-                )
-                .await;
-
-            return Err(ServiceError::Custom(error_msg.into()));
-        }
-
         if saldo.total_balance < req.amount {
             let error_msg = format!(
                 "insufficient balance, available: {}, requested: {}",
@@ -532,33 +648,30 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             return Err(ServiceError::Custom("insufficient balance".into()));
         }
 
-        saldo.total_balance -= req.amount;
-        let _ = match self
+        if let Err(e) = self
             .saldo_command
-            .update_balance(&UpdateSaldoBalance {
+            .apply_ledger_entry(&ApplySaldoLedgerEntry {
                 card_number: card.card_number.clone(),
-                total_balance: saldo.total_balance,
+                amount: -req.amount,
+                reference_type: LEDGER_REFERENCE_TRANSACTION,
+                reference_id: transaction_id,
             })
             .await
         {
-            Ok(v) => v,
-
-            Err(e) => {
-                error!("failed to update saldo: {e:?}");
+            error!("failed to update saldo: {e:?}");
 
-                let error_msg = "failed to update saldo";
+            let error_msg = "failed to update saldo";
 
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        &format!("{}: {:?}", error_msg, e),
-                    )
-                    .await;
+            self.tracing_metrics_core
+                .complete_tracing_error(
+                    &tracing_ctx,
+                    method.clone(),
+                    &format!("{}: {:?}", error_msg, e),
+                )
+                .await;
 
-                return Err(ServiceError::Custom(error_msg.into()));
-            }
-        };
+            return Err(ServiceError::Custom(error_msg.into()));
+        }
 
         transaction.amount = req.amount as i32;
         transaction.payment_method = req.payment_method.clone();
@@ -592,19 +705,18 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             }
         };
 
-        let _ = match self
-            .command
-            .update_status(&UpdateTransactionStatus {
-                transaction_id,
-                status: "success".into(),
-            })
-            .await
-        {
-            Ok(v) => v,
+        let breakdown = self.fee_config.compute(
+            transaction.merchant_id,
+            &transaction.payment_method,
+            transaction.amount as i64,
+        );
+
+        let fee = match self.complete_with_fee(transaction_id, breakdown).await {
+            Ok(fee) => fee,
             Err(e) => {
-                error!("failed to update transaction status: {e:?}");
+                error!("failed to complete transaction with fee: {e:?}");
 
-                let error_msg = "failed to update transaction status";
+                let error_msg = "failed to complete transaction";
                 self.tracing_metrics_core
                     .complete_tracing_error(
                         &tracing_ctx,
@@ -629,7 +741,7 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
             info!("Invalidated cache key: {}", key);
         }
 
-        let response = TransactionResponse::from(updated);
+        let response = TransactionResponse::from(updated).with_fee(&fee);
 
         self.tracing_metrics_core
             .complete_tracing_success(&tracing_ctx, method, "Transaction updated successfully")
@@ -850,7 +962,7 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
         })
     }
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("🔄 Restoring ALL trashed transactions");
 
         let method = Method::Post;
@@ -866,8 +978,8 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
-        match self.command.restore_all().await {
-            Ok(_) => {
+        let result = match self.command.restore_all().await {
+            Ok(result) => {
                 info!("✅ All transactions restored successfully");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -876,6 +988,7 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
                         "All transactions restored successfully",
                     )
                     .await;
+                result
             }
             Err(e) => {
                 error!("💥 Failed to restore all transactions: {e:?}");
@@ -890,7 +1003,7 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
                     "Failed to restore all trashed transactions".into(),
                 ));
             }
-        }
+        };
 
         let cache_keys = vec![
             "transaction:find_all:*".to_string(),
@@ -905,11 +1018,11 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
         Ok(ApiResponse {
             status: "success".into(),
             message: "🔄 All trashed transactions restored successfully!".into(),
-            data: true,
+            data: result.into(),
         })
     }
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("💣 Permanently deleting ALL trashed transactions");
 
         let method = Method::Post;
@@ -925,8 +1038,8 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
-        match self.command.delete_all().await {
-            Ok(_) => {
+        let result = match self.command.delete_all().await {
+            Ok(result) => {
                 info!("✅ All transactions permanently deleted");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -935,6 +1048,7 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
                         "All transactions permanently deleted successfully",
                     )
                     .await;
+                result
             }
             Err(e) => {
                 error!("💥 Failed to delete all transactions: {e:?}");
@@ -949,7 +1063,7 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
                     "Failed to delete all trashed transactions".into(),
                 ));
             }
-        }
+        };
 
         let cache_keys = vec![
             "transaction:find_all:*".to_string(),
@@ -964,7 +1078,40 @@ impl TransactionCommandServiceTrait for TransactionCommandService {
         Ok(ApiResponse {
             status: "success".into(),
             message: "💣 All trashed transactions permanently deleted!".into(),
-            data: true,
+            data: result.into(),
         })
     }
 }
+
+/// Only transactions that still hold their debit can be re-priced; failed or
+/// expired ones would be charged again without a matching refund.
+fn ensure_updatable(transaction_id: i32, status: &str) -> Result<(), ServiceError> {
+    if UPDATABLE_STATUSES.contains(&status) {
+        return Ok(());
+    }
+
+    Err(ServiceError::Forbidden(format!(
+        "transaction {transaction_id} cannot be updated in status '{status}'"
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_and_successful_transactions_can_be_updated() {
+        assert!(ensure_updatable(1, "pending").is_ok());
+        assert!(ensure_updatable(1, "success").is_ok());
+    }
+
+    #[test]
+    fn settled_out_transactions_cannot_be_updated() {
+        for status in ["failed", "expired", ""] {
+            assert!(matches!(
+                ensure_updatable(1, status),
+                Err(ServiceError::Forbidden(_))
+            ));
+        }
+    }
+}
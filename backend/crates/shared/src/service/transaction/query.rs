@@ -1,12 +1,18 @@
 use crate::{
     abstract_trait::transaction::{
-        repository::query::DynTransactionQueryRepository,
+        repository::{
+            fee::DynTransactionFeeRepository, metadata::DynTransactionMetadataRepository,
+            query::DynTransactionQueryRepository,
+        },
         service::query::TransactionQueryServiceTrait,
     },
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::{
-        requests::transaction::{FindAllTransactionCardNumber, FindAllTransactions},
+        requests::{
+            transaction::{FindAllTransactionCardNumber, FindAllTransactions, SearchTransactions},
+            trashed::TrashedSort,
+        },
         responses::{
             ApiResponse, ApiResponsePagination, Pagination, TransactionResponse,
             TransactionResponseDeleteAt,
@@ -22,22 +28,54 @@ use chrono::Duration;
 use opentelemetry::KeyValue;
 use std::sync::Arc;
 use tonic::Request;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 pub struct TransactionQueryService {
     pub query: DynTransactionQueryRepository,
+    pub fee: DynTransactionFeeRepository,
+    pub metadata: DynTransactionMetadataRepository,
     pub tracing_metrics_core: TracingMetrics,
     pub cache_store: Arc<CacheStore>,
 }
 
 impl TransactionQueryService {
-    pub fn new(query: DynTransactionQueryRepository, shared: &SharedResources) -> Result<Self> {
+    pub fn new(
+        query: DynTransactionQueryRepository,
+        fee: DynTransactionFeeRepository,
+        metadata: DynTransactionMetadataRepository,
+        shared: &SharedResources,
+    ) -> Result<Self> {
         Ok(Self {
             query,
+            fee,
+            metadata,
             tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
             cache_store: Arc::clone(&shared.cache_store),
         })
     }
+
+    async fn attach_metadata(
+        &self,
+        responses: Vec<TransactionResponse>,
+    ) -> Vec<TransactionResponse> {
+        let ids: Vec<i32> = responses.iter().map(|r| r.id).collect();
+
+        let mut metadata = match self.metadata.find_by_transaction_ids(&ids).await {
+            Ok(metadata) => metadata,
+            Err(e) => {
+                warn!("⚠️ Failed to load transaction metadata: {e:?}");
+                return responses;
+            }
+        };
+
+        responses
+            .into_iter()
+            .map(|r| {
+                let entry = metadata.remove(&r.id);
+                r.with_metadata(entry)
+            })
+            .collect()
+    }
 }
 
 #[async_trait]
@@ -47,7 +85,7 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
         req: &FindAllTransactions,
     ) -> Result<ApiResponsePagination<Vec<TransactionResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -123,6 +161,7 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
             .into_iter()
             .map(TransactionResponse::from)
             .collect();
+        let transaction_responses = self.attach_metadata(transaction_responses).await;
 
         let response = ApiResponsePagination {
             status: "success".to_string(),
@@ -134,6 +173,7 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -157,7 +197,7 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
         }
 
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -244,6 +284,7 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
             .into_iter()
             .map(TransactionResponse::from)
             .collect();
+        let transaction_responses = self.attach_metadata(transaction_responses).await;
 
         let response = ApiResponsePagination {
             status: "success".to_string(),
@@ -255,6 +296,7 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -270,6 +312,162 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
         Ok(response)
     }
 
+    async fn search(
+        &self,
+        req: &SearchTransactions,
+    ) -> Result<ApiResponsePagination<Vec<TransactionResponse>>, ServiceError> {
+        let page = if req.page > 0 { req.page } else { 1 };
+        let page_size = req.page_size.clamp(1, 100);
+
+        let mut errors = Vec::new();
+
+        let (Some(sort_column), Some(sort_direction)) = (req.sort_column(), req.sort_direction())
+        else {
+            if req.sort_column().is_none() {
+                errors.push(format!(
+                    "sort_by must be one of: {}",
+                    SearchTransactions::SORT_COLUMNS.join(", ")
+                ));
+            }
+            if req.sort_direction().is_none() {
+                errors.push("sort_dir must be either asc or desc".to_string());
+            }
+            return Err(ServiceError::Validation(errors));
+        };
+
+        if req.min_amount.is_some_and(|v| v < 0) {
+            errors.push("min_amount must not be negative".to_string());
+        }
+        if req.max_amount.is_some_and(|v| v < 0) {
+            errors.push("max_amount must not be negative".to_string());
+        }
+        if let (Some(min), Some(max)) = (req.min_amount, req.max_amount)
+            && min > max
+        {
+            errors.push("min_amount must not be greater than max_amount".to_string());
+        }
+        if let (Some(start), Some(end)) = (req.start_date, req.end_date)
+            && start > end
+        {
+            errors.push("start_date must not be after end_date".to_string());
+        }
+        if !errors.is_empty() {
+            return Err(ServiceError::Validation(errors));
+        }
+
+        let status = req.status.clone().unwrap_or_default();
+        let payment_method = req.payment_method.clone().unwrap_or_default();
+        let card_number = req.card_number.clone().unwrap_or_default();
+        let merchant_id = req.merchant_id.map(|v| v.to_string()).unwrap_or_default();
+        let min_amount = req.min_amount.map(|v| v.to_string()).unwrap_or_default();
+        let max_amount = req.max_amount.map(|v| v.to_string()).unwrap_or_default();
+        let start_date = req.start_date.map(|d| d.to_string()).unwrap_or_default();
+        let end_date = req.end_date.map(|d| d.to_string()).unwrap_or_default();
+        let metadata_key = req.metadata_key.clone().unwrap_or_default();
+        let metadata_value = req.metadata_value.clone().unwrap_or_default();
+
+        let masked_card = mask_card_number(&card_number);
+
+        info!(
+            "🔍 Searching transactions | Page: {page}, Size: {page_size}, Status: {status:?}, Method: {payment_method:?}, Card: {masked_card}, Merchant: {merchant_id:?}, Amount: {min_amount:?}..{max_amount:?}, Range: {start_date:?}..{end_date:?}, Metadata: {metadata_key:?}={metadata_value:?}, Sort: {sort_column} {sort_direction}"
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "search_transactions",
+            vec![
+                KeyValue::new("component", "transaction"),
+                KeyValue::new("operation", "search"),
+                KeyValue::new("page", page.to_string()),
+                KeyValue::new("page_size", page_size.to_string()),
+                KeyValue::new("status", status.clone()),
+                KeyValue::new("payment_method", payment_method.clone()),
+                KeyValue::new("card_number", masked_card.clone()),
+                KeyValue::new("merchant_id", merchant_id.clone()),
+                KeyValue::new("metadata_key", metadata_key.clone()),
+                KeyValue::new("sort", format!("{sort_column} {sort_direction}")),
+            ],
+        );
+
+        let mut request = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let cache_key = format!(
+            "transaction:search:page:{page}:size:{page_size}:status:{status}:method:{payment_method}:card:{card_number}:merchant:{merchant_id}:min:{min_amount}:max:{max_amount}:start:{start_date}:end:{end_date}:meta:{metadata_key}={metadata_value}:sort:{sort_column}:{sort_direction}"
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponsePagination<Vec<TransactionResponse>>>(&cache_key)
+            .await
+        {
+            let log_msg = format!(
+                "✅ Found {} searched transactions in cache",
+                cache.data.len()
+            );
+            info!("{log_msg}");
+            self.tracing_metrics_core
+                .complete_tracing_success(&tracing_ctx, method, &log_msg)
+                .await;
+            return Ok(cache);
+        }
+
+        let (transactions, total_items) = match self.query.search(req).await {
+            Ok(res) => {
+                let log_msg = format!("✅ Found {} searched transactions", res.0.len());
+                info!("{log_msg}");
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, &log_msg)
+                    .await;
+                res
+            }
+            Err(e) => {
+                error!("❌ Failed to search transactions: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("❌ Failed to search transactions: {e:?}"),
+                    )
+                    .await;
+                return Err(ServiceError::Custom(e.to_string()));
+            }
+        };
+
+        let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+        let transaction_responses: Vec<TransactionResponse> = transactions
+            .into_iter()
+            .map(TransactionResponse::from)
+            .collect();
+        let transaction_responses = self.attach_metadata(transaction_responses).await;
+
+        let response = ApiResponsePagination {
+            status: "success".to_string(),
+            message: "Transactions retrieved successfully".to_string(),
+            data: transaction_responses,
+            pagination: Pagination {
+                page,
+                page_size,
+                total_items,
+                total_pages,
+            },
+            links: None,
+        };
+
+        info!(
+            "✅ Found {} searched transactions (total: {total_items})",
+            response.data.len()
+        );
+
+        self.cache_store
+            .set_to_cache(&cache_key, &response, Duration::minutes(10))
+            .await;
+
+        Ok(response)
+    }
+
     async fn find_by_id(
         &self,
         transaction_id: i32,
@@ -321,14 +519,30 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
                 self.tracing_metrics_core
                     .complete_tracing_error(&tracing_ctx, method.clone(), "Database error")
                     .await;
-                return Err(ServiceError::Custom(e.to_string()));
+                return Err(if e.is_not_found() {
+                    ServiceError::NotFound(format!(
+                        "Transaction with ID {transaction_id} not found"
+                    ))
+                } else {
+                    ServiceError::Custom(e.to_string())
+                });
             }
         };
 
+        let mut data = self
+            .attach_metadata(vec![TransactionResponse::from(transaction)])
+            .await
+            .remove(0);
+        match self.fee.find_by_transaction_id(transaction_id).await {
+            Ok(Some(fee)) => data = data.with_fee(&fee),
+            Ok(None) => {}
+            Err(e) => warn!("⚠️ Failed to load fee for transaction {transaction_id}: {e:?}"),
+        }
+
         let response = ApiResponse {
             status: "success".to_string(),
             message: "Transaction retrieved successfully".to_string(),
-            data: TransactionResponse::from(transaction),
+            data,
         };
 
         self.cache_store
@@ -437,7 +651,7 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
         req: &FindAllTransactions,
     ) -> Result<ApiResponsePagination<Vec<TransactionResponseDeleteAt>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -524,6 +738,7 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -542,8 +757,14 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
         &self,
         req: &FindAllTransactions,
     ) -> Result<ApiResponsePagination<Vec<TransactionResponseDeleteAt>>, ServiceError> {
+        let Some(sort) = TrashedSort::parse(req.sort_by.as_deref()) else {
+            return Err(ServiceError::Validation(vec![
+                TrashedSort::validation_error(),
+            ]));
+        };
+
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -574,8 +795,9 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         let cache_key = format!(
-            "transaction:find_by_trashed:page:{page}:size:{page_size}:search:{}",
-            search.unwrap_or_default()
+            "transaction:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            search.unwrap_or_default(),
+            sort.as_str()
         );
 
         if let Some(cache) = self
@@ -633,6 +855,7 @@ impl TransactionQueryServiceTrait for TransactionQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -1,6 +1,7 @@
 use crate::{
     abstract_trait::{
         card::repository::query::DynCardQueryRepository,
+        events::DynEventPublisher,
         saldo::repository::{command::DynSaldoCommandRepository, query::DynSaldoQueryRepository},
         transfer::{
             repository::{
@@ -10,14 +11,21 @@ use crate::{
         },
     },
     cache::CacheStore,
+    config::TransferLimitsConfig,
     context::shared_resources::SharedResources,
     domain::requests::{
-        saldo::UpdateSaldoBalance,
+        saldo::ApplySaldoTransfer,
         transfer::{CreateTransferRequest, UpdateTransferRequest, UpdateTransferStatus},
     },
-    domain::responses::{ApiResponse, TransferResponse, TransferResponseDeleteAt},
+    domain::responses::{
+        ApiResponse, BalanceImpactResponse, BulkResult, TransferResponse, TransferResponseDeleteAt,
+    },
     errors::{ServiceError, format_validation_errors},
+    events::{DomainEvent, EVENT_TRANSFER_SETTLED},
     observability::{Method, TracingMetrics},
+    resilience::WriteLimiter,
+    service::card::usable::ensure_card_usable,
+    utils::mask_card_number,
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -27,12 +35,18 @@ use tonic::Request;
 use tracing::{error, info};
 use validator::Validate;
 
+const TRANSFER_FEE: i64 = 0;
+const LEDGER_REFERENCE_TRANSFER: &str = "transfer";
+
 pub struct TransferCommandService {
     pub card_query: DynCardQueryRepository,
     pub saldo_query: DynSaldoQueryRepository,
     pub saldo_command: DynSaldoCommandRepository,
     pub query: DynTransferQueryRepository,
     pub command: DynTransferCommandRepository,
+    pub limits: TransferLimitsConfig,
+    pub events: DynEventPublisher,
+    pub write_limiter: Arc<WriteLimiter>,
     pub tracing_metrics_core: TracingMetrics,
     pub cache_store: Arc<CacheStore>,
 }
@@ -43,6 +57,9 @@ pub struct TransferCommandServiceDeps {
     pub saldo_command: DynSaldoCommandRepository,
     pub query: DynTransferQueryRepository,
     pub command: DynTransferCommandRepository,
+    pub limits: TransferLimitsConfig,
+    pub events: DynEventPublisher,
+    pub max_concurrent_writes: usize,
 }
 
 impl TransferCommandService {
@@ -53,6 +70,9 @@ impl TransferCommandService {
             saldo_command,
             query,
             command,
+            limits,
+            events,
+            max_concurrent_writes,
         } = deps;
 
         Ok(Self {
@@ -61,14 +81,118 @@ impl TransferCommandService {
             saldo_command,
             query,
             command,
+            limits,
+            events,
+            write_limiter: Arc::new(WriteLimiter::new(max_concurrent_writes)),
             tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
             cache_store: Arc::clone(&shared.cache_store),
         })
     }
+
+    async fn check_limits(&self, card_number: &str, amount: i64) -> Result<(), ServiceError> {
+        let merchant_id = self
+            .card_query
+            .find_merchant_id_by_card(card_number)
+            .await
+            .map_err(|e| {
+                error!(
+                    "❌ Failed to resolve merchant for card {}: {e:?}",
+                    mask_card_number(card_number)
+                );
+                ServiceError::Repo(e)
+            })?;
+
+        self.limits
+            .check(card_number, merchant_id, amount)
+            .map_err(|error_msg| {
+                error!("Transfer limit violated: {error_msg}");
+                ServiceError::Validation(vec![error_msg])
+            })
+    }
 }
 
 #[async_trait]
 impl TransferCommandServiceTrait for TransferCommandService {
+    async fn preview(
+        &self,
+        req: &CreateTransferRequest,
+    ) -> Result<ApiResponse<BalanceImpactResponse>, ServiceError> {
+        info!("previewing transfer: {:?}", req);
+
+        if let Err(validation_errors) = req.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Validation(vec![error_msg]));
+        }
+
+        self.check_limits(&req.transfer_from, req.transfer_amount)
+            .await?;
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "preview_transfer",
+            vec![
+                KeyValue::new("component", "transfer"),
+                KeyValue::new("operation", "preview"),
+                KeyValue::new("transfer.amount", req.transfer_amount.to_string()),
+            ],
+        );
+
+        let mut request_with_trace = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request_with_trace);
+
+        for card_number in [&req.transfer_from, &req.transfer_to] {
+            if let Err(e) = self.card_query.find_by_card(card_number).await {
+                error!("error {e:?}");
+                let error_msg = format!("card {card_number} not found");
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method.clone(), &error_msg)
+                    .await;
+                return Err(ServiceError::NotFound(error_msg));
+            }
+        }
+
+        let (sender_saldo, receiver_saldo) = tokio::join!(
+            self.saldo_query.find_by_card(&req.transfer_from),
+            self.saldo_query.find_by_card(&req.transfer_to),
+        );
+
+        let sender_saldo = match (sender_saldo, receiver_saldo) {
+            (Ok(sender), Ok(_)) => sender,
+            (Err(e), _) | (_, Err(e)) => {
+                let error_msg = "failed to fetch saldo for transfer preview";
+                error!("{error_msg}: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method.clone(), error_msg)
+                    .await;
+                return Err(ServiceError::Custom(error_msg.into()));
+            }
+        };
+
+        let impact = BalanceImpactResponse::evaluate(
+            &sender_saldo.card_number,
+            sender_saldo.total_balance,
+            req.transfer_amount,
+            TRANSFER_FEE,
+        );
+
+        info!(
+            "transfer preview from {}: {} -> {} (allowed: {})",
+            req.transfer_from, impact.current_balance, impact.resulting_balance, impact.allowed
+        );
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Transfer preview computed")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "Transfer preview computed".into(),
+            data: impact,
+        })
+    }
+
     async fn create(
         &self,
         req: &CreateTransferRequest,
@@ -81,6 +205,9 @@ impl TransferCommandServiceTrait for TransferCommandService {
             return Err(ServiceError::Custom(error_msg));
         }
 
+        self.check_limits(&req.transfer_from, req.transfer_amount)
+            .await?;
+
         let method = Method::Post;
         let tracing_ctx = self.tracing_metrics_core.start_tracing(
             "create_transfer",
@@ -95,6 +222,20 @@ impl TransferCommandServiceTrait for TransferCommandService {
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut request_with_trace);
 
+        let _write_permit = match self.write_limiter.try_acquire("transfer") {
+            Ok(permit) => permit,
+            Err(e) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        "Write capacity exhausted",
+                    )
+                    .await;
+                return Err(e);
+            }
+        };
+
         if let Err(e) = self.card_query.find_by_card(&req.transfer_from).await {
             error!("error {e:?}");
             let error_msg = format!("sender card {} not found", req.transfer_from);
@@ -113,7 +254,16 @@ impl TransferCommandServiceTrait for TransferCommandService {
             return Err(ServiceError::Custom(error_msg));
         }
 
-        let mut sender_saldo = match self.saldo_query.find_by_card(&req.transfer_from).await {
+        for card_number in [&req.transfer_from, &req.transfer_to] {
+            if let Err(e) = ensure_card_usable(&self.card_query, card_number).await {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method.clone(), &e.to_string())
+                    .await;
+                return Err(e);
+            }
+        }
+
+        let sender_saldo = match self.saldo_query.find_by_card(&req.transfer_from).await {
             Ok(saldo) => saldo,
             Err(e) => {
                 let error_msg = "failed to fetch sender saldo";
@@ -131,7 +281,7 @@ impl TransferCommandServiceTrait for TransferCommandService {
             }
         };
 
-        let mut receiver_saldo = match self.saldo_query.find_by_card(&req.transfer_to).await {
+        let receiver_saldo = match self.saldo_query.find_by_card(&req.transfer_to).await {
             Ok(saldo) => saldo,
             Err(e) => {
                 let error_msg = "failed to fetch receiver saldo";
@@ -149,7 +299,14 @@ impl TransferCommandServiceTrait for TransferCommandService {
             }
         };
 
-        if sender_saldo.total_balance < req.transfer_amount {
+        let impact = BalanceImpactResponse::evaluate(
+            &sender_saldo.card_number,
+            sender_saldo.total_balance,
+            req.transfer_amount,
+            TRANSFER_FEE,
+        );
+
+        if !impact.allowed {
             let error_msg = format!(
                 "error insufficient balance, requested: {}, available: {}",
                 req.transfer_amount, sender_saldo.total_balance
@@ -162,39 +319,42 @@ impl TransferCommandServiceTrait for TransferCommandService {
             return Err(ServiceError::Custom("insufficient balance".into()));
         }
 
-        sender_saldo.total_balance -= req.transfer_amount;
+        let transfer_record = match self.command.create(req).await {
+            Ok(t) => t,
+            Err(e) => {
+                let error_msg = format!("failed to create transfer: {:?}", e);
+                error!("{error_msg}");
+
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method.clone(), &error_msg)
+                    .await;
+
+                return Err(ServiceError::Custom("failed to create transfer".into()));
+            }
+        };
+
         if let Err(e) = self
             .saldo_command
-            .update_balance(&UpdateSaldoBalance {
-                card_number: sender_saldo.card_number.clone(),
-                total_balance: sender_saldo.total_balance,
+            .transfer_with_ledger(&ApplySaldoTransfer {
+                from_card_number: sender_saldo.card_number.clone(),
+                to_card_number: receiver_saldo.card_number.clone(),
+                amount: req.transfer_amount,
+                reference_type: LEDGER_REFERENCE_TRANSFER,
+                reference_id: transfer_record.transfer_id,
             })
             .await
         {
             error!("error {e:?}");
 
-            let error_msg = "failed to update sender saldo";
-            self.tracing_metrics_core
-                .complete_tracing_error(
-                    &tracing_ctx,
-                    method.clone(),
-                    &format!("{}: {:?}", error_msg, e),
-                )
+            let _ = self
+                .command
+                .update_status(&UpdateTransferStatus {
+                    transfer_id: transfer_record.transfer_id,
+                    status: "failed".into(),
+                })
                 .await;
-            return Err(ServiceError::Custom(error_msg.into()));
-        }
 
-        receiver_saldo.total_balance += req.transfer_amount;
-        if let Err(e) = self
-            .saldo_command
-            .update_balance(&UpdateSaldoBalance {
-                card_number: receiver_saldo.card_number.clone(),
-                total_balance: receiver_saldo.total_balance,
-            })
-            .await
-        {
-            error!("error {e:?}");
-            let error_msg = "failed to update receiver saldo";
+            let error_msg = "failed to update saldo";
             self.tracing_metrics_core
                 .complete_tracing_error(
                     &tracing_ctx,
@@ -202,61 +362,9 @@ impl TransferCommandServiceTrait for TransferCommandService {
                     &format!("{}: {:?}", error_msg, e),
                 )
                 .await;
-
-            sender_saldo.total_balance += req.transfer_amount;
-            if let Err(rb) = self
-                .saldo_command
-                .update_balance(&UpdateSaldoBalance {
-                    card_number: sender_saldo.card_number.clone(),
-                    total_balance: sender_saldo.total_balance,
-                })
-                .await
-            {
-                error!("error rollback {rb:?}");
-            }
-
             return Err(ServiceError::Custom(error_msg.into()));
         }
 
-        let transfer_record = match self.command.create(req).await {
-            Ok(t) => t,
-            Err(e) => {
-                let error_msg = format!("failed to create transfer: {:?}", e);
-                error!("{error_msg}");
-
-                self.tracing_metrics_core
-                    .complete_tracing_error(&tracing_ctx, method.clone(), &error_msg)
-                    .await;
-
-                sender_saldo.total_balance += req.transfer_amount;
-                receiver_saldo.total_balance -= req.transfer_amount;
-
-                if let Err(rb1) = self
-                    .saldo_command
-                    .update_balance(&UpdateSaldoBalance {
-                        card_number: sender_saldo.card_number.clone(),
-                        total_balance: sender_saldo.total_balance,
-                    })
-                    .await
-                {
-                    error!("error rollback sender {rb1:?}");
-                }
-
-                if let Err(rb2) = self
-                    .saldo_command
-                    .update_balance(&UpdateSaldoBalance {
-                        card_number: receiver_saldo.card_number.clone(),
-                        total_balance: receiver_saldo.total_balance,
-                    })
-                    .await
-                {
-                    error!("error rollback receiver {rb2:?}");
-                }
-
-                return Err(ServiceError::Custom("failed to create transfer".into()));
-            }
-        };
-
         if let Err(e) = self
             .command
             .update_status(&UpdateTransferStatus {
@@ -297,6 +405,16 @@ impl TransferCommandServiceTrait for TransferCommandService {
 
         let response = TransferResponse::from(transfer_record);
 
+        self.events.emit(DomainEvent::new(
+            EVENT_TRANSFER_SETTLED,
+            serde_json::json!({
+                "transfer_id": response.id,
+                "transfer_from": mask_card_number(&req.transfer_from),
+                "transfer_to": mask_card_number(&req.transfer_to),
+                "amount": req.transfer_amount,
+            }),
+        ));
+
         self.tracing_metrics_core
             .complete_tracing_success(&tracing_ctx, method, "Transfer created successfully")
             .await;
@@ -367,9 +485,28 @@ impl TransferCommandServiceTrait for TransferCommandService {
             }
         };
 
+        if let Err(e) = self
+            .check_limits(&transfer.transfer_from, req.transfer_amount)
+            .await
+        {
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), &e.to_string())
+                .await;
+            return Err(e);
+        }
+
+        for card_number in [&transfer.transfer_from, &transfer.transfer_to] {
+            if let Err(e) = ensure_card_usable(&self.card_query, card_number).await {
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method.clone(), &e.to_string())
+                    .await;
+                return Err(e);
+            }
+        }
+
         let amount_difference = req.transfer_amount - transfer.transfer_amount as i64;
 
-        let mut sender_saldo = match self.saldo_query.find_by_card(&transfer.transfer_from).await {
+        let sender_saldo = match self.saldo_query.find_by_card(&transfer.transfer_from).await {
             Ok(s) => s,
             Err(e) => {
                 let error_msg = "failed to fetch sender saldo";
@@ -407,100 +544,21 @@ impl TransferCommandServiceTrait for TransferCommandService {
             return Err(ServiceError::Custom("insufficient balance".into()));
         }
 
-        sender_saldo.total_balance = new_sender_balance;
-
-        match self
-            .saldo_command
-            .update_balance(&UpdateSaldoBalance {
-                card_number: sender_saldo.card_number.clone(),
-                total_balance: sender_saldo.total_balance,
-            })
-            .await
-        {
-            Ok(_) => (),
-            Err(e) => {
-                let error_msg = "failed to update sender saldo";
-                error!("{error_msg}: {e:?}");
-
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        &format!("{}: {:?}", error_msg, e),
-                    )
-                    .await;
-
-                return Err(ServiceError::Custom(error_msg.into()));
-            }
-        }
-
-        let mut receiver_saldo = match self.saldo_query.find_by_card(&transfer.transfer_to).await {
-            Ok(s) => s,
-            Err(e) => {
-                error!("error {e:?}");
-                let error_msg = "failed to fetch receiver saldo";
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        &format!("{}: {:?}", error_msg, e),
-                    )
-                    .await;
-
-                let _ = self
-                    .saldo_command
-                    .update_balance(&UpdateSaldoBalance {
-                        card_number: sender_saldo.card_number.clone(),
-                        total_balance: sender_saldo.total_balance + amount_difference,
-                    })
-                    .await;
-
-                let _ = self
-                    .command
-                    .update_status(&UpdateTransferStatus {
-                        transfer_id,
-                        status: "failed".to_string(),
-                    })
-                    .await;
-
-                return Err(ServiceError::Custom(error_msg.into()));
-            }
+        let move_balance = |amount: i64| ApplySaldoTransfer {
+            from_card_number: transfer.transfer_from.clone(),
+            to_card_number: transfer.transfer_to.clone(),
+            amount,
+            reference_type: LEDGER_REFERENCE_TRANSFER,
+            reference_id: transfer_id,
         };
 
-        receiver_saldo.total_balance += amount_difference;
-        if let Err(e) = self
-            .saldo_command
-            .update_balance(&UpdateSaldoBalance {
-                card_number: receiver_saldo.card_number.clone(),
-                total_balance: receiver_saldo.total_balance,
-            })
-            .await
+        if amount_difference != 0
+            && let Err(e) = self
+                .saldo_command
+                .transfer_with_ledger(&move_balance(amount_difference))
+                .await
         {
             error!("error {e:?}");
-            let error_msg = "failed to update receiver saldo";
-            self.tracing_metrics_core
-                .complete_tracing_error(
-                    &tracing_ctx,
-                    method.clone(),
-                    &format!("{}: {:?}", error_msg, e),
-                )
-                .await;
-
-            let _ = self
-                .saldo_command
-                .update_balance(&UpdateSaldoBalance {
-                    card_number: sender_saldo.card_number.clone(),
-                    total_balance: sender_saldo.total_balance + amount_difference,
-                })
-                .await;
-
-            let _ = self
-                .saldo_command
-                .update_balance(&UpdateSaldoBalance {
-                    card_number: receiver_saldo.card_number.clone(),
-                    total_balance: receiver_saldo.total_balance - amount_difference,
-                })
-                .await;
 
             let _ = self
                 .command
@@ -510,6 +568,14 @@ impl TransferCommandServiceTrait for TransferCommandService {
                 })
                 .await;
 
+            let error_msg = "failed to update saldo";
+            self.tracing_metrics_core
+                .complete_tracing_error(
+                    &tracing_ctx,
+                    method.clone(),
+                    &format!("{}: {:?}", error_msg, e),
+                )
+                .await;
             return Err(ServiceError::Custom(error_msg.into()));
         }
 
@@ -522,21 +588,14 @@ impl TransferCommandServiceTrait for TransferCommandService {
                     .complete_tracing_error(&tracing_ctx, method.clone(), &error_msg)
                     .await;
 
-                let _ = self
-                    .saldo_command
-                    .update_balance(&UpdateSaldoBalance {
-                        card_number: sender_saldo.card_number.clone(),
-                        total_balance: sender_saldo.total_balance + amount_difference,
-                    })
-                    .await;
-
-                let _ = self
-                    .saldo_command
-                    .update_balance(&UpdateSaldoBalance {
-                        card_number: receiver_saldo.card_number.clone(),
-                        total_balance: receiver_saldo.total_balance - amount_difference,
-                    })
-                    .await;
+                if amount_difference != 0
+                    && let Err(rb) = self
+                        .saldo_command
+                        .transfer_with_ledger(&move_balance(-amount_difference))
+                        .await
+                {
+                    error!("error rollback {rb:?}");
+                }
 
                 let _ = self
                     .command
@@ -786,7 +845,7 @@ impl TransferCommandServiceTrait for TransferCommandService {
         }
     }
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("🔄 Restoring ALL trashed transfers");
 
         let method = Method::Post;
@@ -803,7 +862,7 @@ impl TransferCommandServiceTrait for TransferCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.restore_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All transfers restored successfully");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -826,7 +885,7 @@ impl TransferCommandServiceTrait for TransferCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "All transfers restored successfully".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -845,7 +904,7 @@ impl TransferCommandServiceTrait for TransferCommandService {
         }
     }
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("💣 Permanently deleting ALL trashed transfers");
 
         let method = Method::Post;
@@ -862,7 +921,7 @@ impl TransferCommandServiceTrait for TransferCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.delete_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All transfers permanently deleted");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -885,7 +944,7 @@ impl TransferCommandServiceTrait for TransferCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "All transfers permanently deleted".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
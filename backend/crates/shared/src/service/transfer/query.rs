@@ -5,10 +5,13 @@ use crate::{
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::{
-        requests::transfer::FindAllTransfers,
+        requests::{
+            transfer::{FindAllTransfers, FindTransferNetPosition, FindTransfers},
+            trashed::TrashedSort,
+        },
         responses::{
-            ApiResponse, ApiResponsePagination, Pagination, TransferResponse,
-            TransferResponseDeleteAt,
+            ApiResponse, ApiResponsePagination, Pagination, TransferNetPositionResponse,
+            TransferResponse, TransferResponseDeleteAt,
         },
     },
     errors::ServiceError,
@@ -45,7 +48,7 @@ impl TransferQueryServiceTrait for TransferQueryService {
         req: &FindAllTransfers,
     ) -> Result<ApiResponsePagination<Vec<TransferResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -130,6 +133,7 @@ impl TransferQueryServiceTrait for TransferQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -199,7 +203,11 @@ impl TransferQueryServiceTrait for TransferQueryService {
                         &format!("Database error fetching transfer: {:?}", e),
                     )
                     .await;
-                return Err(ServiceError::Custom(e.to_string()));
+                return Err(if e.is_not_found() {
+                    ServiceError::NotFound(format!("Transfer with ID {transfer_id} not found"))
+                } else {
+                    ServiceError::Custom(e.to_string())
+                });
             }
         };
 
@@ -221,7 +229,7 @@ impl TransferQueryServiceTrait for TransferQueryService {
         req: &FindAllTransfers,
     ) -> Result<ApiResponsePagination<Vec<TransferResponseDeleteAt>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -308,6 +316,7 @@ impl TransferQueryServiceTrait for TransferQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -326,8 +335,14 @@ impl TransferQueryServiceTrait for TransferQueryService {
         &self,
         req: &FindAllTransfers,
     ) -> Result<ApiResponsePagination<Vec<TransferResponseDeleteAt>>, ServiceError> {
+        let Some(sort) = TrashedSort::parse(req.sort_by.as_deref()) else {
+            return Err(ServiceError::Validation(vec![
+                TrashedSort::validation_error(),
+            ]));
+        };
+
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -358,8 +373,9 @@ impl TransferQueryServiceTrait for TransferQueryService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         let cache_key = format!(
-            "transfer:find_by_trashed:page:{page}:size:{page_size}:search:{}",
-            search.unwrap_or_default()
+            "transfer:find_by_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            search.unwrap_or_default(),
+            sort.as_str()
         );
 
         if let Some(cache) = self
@@ -414,6 +430,7 @@ impl TransferQueryServiceTrait for TransferQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -428,6 +445,120 @@ impl TransferQueryServiceTrait for TransferQueryService {
         Ok(response)
     }
 
+    async fn find_transfers(
+        &self,
+        req: &FindTransfers,
+    ) -> Result<ApiResponsePagination<Vec<TransferResponse>>, ServiceError> {
+        let page = if req.page > 0 { req.page } else { 1 };
+        let page_size = req.page_size.clamp(1, 100);
+
+        if let (Some(start), Some(end)) = (req.start_date, req.end_date)
+            && start > end
+        {
+            return Err(ServiceError::Validation(vec![
+                "start_date must not be after end_date".to_string(),
+            ]));
+        }
+
+        let filters = req.filters();
+        let transfer_from = filters.transfer_from.unwrap_or_default();
+        let transfer_to = filters.transfer_to.unwrap_or_default();
+        let status = filters.status.unwrap_or_default();
+        let start_date = req.start_date.map(|d| d.to_string()).unwrap_or_default();
+        let end_date = req.end_date.map(|d| d.to_string()).unwrap_or_default();
+
+        info!(
+            "🔍 Filtering transfers | Page: {page}, Size: {page_size}, From: {:?}, To: {:?}, Status: {:?}, Range: {:?}..{:?}",
+            transfer_from, transfer_to, status, start_date, end_date
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "find_transfers",
+            vec![
+                KeyValue::new("component", "transfer"),
+                KeyValue::new("operation", "find_transfers"),
+                KeyValue::new("page", page.to_string()),
+                KeyValue::new("page_size", page_size.to_string()),
+                KeyValue::new("transfer_from", transfer_from.clone()),
+                KeyValue::new("transfer_to", transfer_to.clone()),
+                KeyValue::new("status", status.clone()),
+            ],
+        );
+
+        let mut request = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let cache_key = format!(
+            "transfer:find_transfers:page:{page}:size:{page_size}:from:{transfer_from}:to:{transfer_to}:status:{status}:start:{start_date}:end:{end_date}"
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponsePagination<Vec<TransferResponse>>>(&cache_key)
+            .await
+        {
+            let log_msg = format!("✅ Found {} filtered transfers in cache", cache.data.len());
+            info!("{log_msg}");
+            self.tracing_metrics_core
+                .complete_tracing_success(&tracing_ctx, method, &log_msg)
+                .await;
+            return Ok(cache);
+        }
+
+        let (transfers, total_items) = match self.query.find_transfers(req).await {
+            Ok(res) => {
+                let log_msg = format!("✅ Found {} filtered transfers", res.0.len());
+                info!("{log_msg}");
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, &log_msg)
+                    .await;
+                res
+            }
+            Err(e) => {
+                error!("❌ Failed to fetch filtered transfers: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("❌ Failed to fetch filtered transfers: {e:?}"),
+                    )
+                    .await;
+                return Err(ServiceError::Custom(e.to_string()));
+            }
+        };
+
+        let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+        let transfer_responses: Vec<TransferResponse> =
+            transfers.into_iter().map(TransferResponse::from).collect();
+
+        let response = ApiResponsePagination {
+            status: "success".to_string(),
+            message: "Transfers retrieved successfully".to_string(),
+            data: transfer_responses,
+            pagination: Pagination {
+                page,
+                page_size,
+                total_items,
+                total_pages,
+            },
+            links: None,
+        };
+
+        info!(
+            "✅ Found {} filtered transfers (total: {total_items})",
+            response.data.len()
+        );
+
+        self.cache_store
+            .set_to_cache(&cache_key, &response, Duration::minutes(10))
+            .await;
+
+        Ok(response)
+    }
+
     async fn find_by_transfer_from(
         &self,
         transfer_from: &str,
@@ -603,4 +734,87 @@ impl TransferQueryServiceTrait for TransferQueryService {
 
         Ok(response)
     }
+
+    async fn net_position(
+        &self,
+        req: &FindTransferNetPosition,
+    ) -> Result<ApiResponse<TransferNetPositionResponse>, ServiceError> {
+        let card_a = req.card_a.trim();
+        let card_b = req.card_b.trim();
+
+        let mut errors = Vec::new();
+        if card_a.is_empty() || card_b.is_empty() {
+            errors.push("Both card_a and card_b are required".to_string());
+        } else if card_a == card_b {
+            errors.push("card_a and card_b must be different cards".to_string());
+        }
+        if let (Some(start), Some(end)) = (req.start_date, req.end_date)
+            && start > end
+        {
+            errors.push("start_date must not be after end_date".to_string());
+        }
+        if !errors.is_empty() {
+            return Err(ServiceError::Validation(errors));
+        }
+
+        info!("⚖️ Computing net position between {card_a} and {card_b}");
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "net_position",
+            vec![
+                KeyValue::new("component", "transfer"),
+                KeyValue::new("operation", "net_position"),
+                KeyValue::new("card_a", card_a.to_string()),
+                KeyValue::new("card_b", card_b.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let normalized = FindTransferNetPosition {
+            card_a: card_a.to_string(),
+            card_b: card_b.to_string(),
+            ..req.clone()
+        };
+
+        let model = match self.query.get_net_position(&normalized).await {
+            Ok(model) => {
+                self.tracing_metrics_core
+                    .complete_tracing_success(
+                        &tracing_ctx,
+                        method,
+                        "Net position computed successfully",
+                    )
+                    .await;
+                model
+            }
+            Err(e) => {
+                error!("❌ Failed to compute net position between {card_a} and {card_b}: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method,
+                        &format!("Failed to compute net position: {:?}", e),
+                    )
+                    .await;
+                return Err(ServiceError::Custom(e.to_string()));
+            }
+        };
+
+        let data = TransferNetPositionResponse::from_model(card_a, card_b, model);
+
+        info!(
+            "✅ Net position between {card_a} and {card_b}: {} ({})",
+            data.net_amount, data.direction
+        );
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "Net position retrieved successfully".to_string(),
+            data,
+        })
+    }
 }
@@ -3,19 +3,27 @@ use crate::{
         hashing::DynHashing,
         role::repository::query::DynRoleQueryRepository,
         user::{
-            repository::{command::DynUserCommandRepository, query::DynUserQueryRepository},
+            repository::{
+                anonymize::{AnonymizedIdentity, DynUserAnonymizationRepository},
+                command::DynUserCommandRepository,
+                merge::DynUserMergeRepository,
+                query::DynUserQueryRepository,
+            },
             service::command::UserCommandServiceTrait,
         },
         user_roles::DynUserRoleCommandRepository,
     },
     cache::CacheStore,
+    config::EmailNormalizationConfig,
     context::shared_resources::SharedResources,
     domain::{
         requests::{
-            user::{CreateUserRequest, UpdateUserRequest},
+            user::{CreateUserRequest, MergeUsersRequest, UpdateUserRequest},
             user_role::CreateUserRoleRequest,
         },
-        responses::{ApiResponse, UserResponse, UserResponseDeleteAt},
+        responses::{
+            ApiResponse, BulkResult, UserMergeResponse, UserResponse, UserResponseDeleteAt,
+        },
     },
     errors::{ServiceError, format_validation_errors},
     observability::{Method, TracingMetrics},
@@ -34,6 +42,9 @@ pub struct UserCommandService {
     pub hashing: DynHashing,
     pub user_role: DynUserRoleCommandRepository,
     pub role: DynRoleQueryRepository,
+    pub anonymization: DynUserAnonymizationRepository,
+    pub merge: DynUserMergeRepository,
+    pub email_normalization: EmailNormalizationConfig,
     pub tracing_metrics_core: TracingMetrics,
     pub cache_store: Arc<CacheStore>,
 }
@@ -44,6 +55,9 @@ pub struct UserCommandServiceDeps {
     pub hashing: DynHashing,
     pub user_role: DynUserRoleCommandRepository,
     pub role: DynRoleQueryRepository,
+    pub anonymization: DynUserAnonymizationRepository,
+    pub merge: DynUserMergeRepository,
+    pub email_normalization: EmailNormalizationConfig,
 }
 
 impl UserCommandService {
@@ -54,6 +68,9 @@ impl UserCommandService {
             hashing,
             user_role,
             role,
+            anonymization,
+            merge,
+            email_normalization,
         } = deps;
 
         Ok(Self {
@@ -62,6 +79,9 @@ impl UserCommandService {
             hashing,
             user_role,
             role,
+            anonymization,
+            merge,
+            email_normalization,
             tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
             cache_store: Arc::clone(&shared.cache_store),
         })
@@ -74,6 +94,11 @@ impl UserCommandServiceTrait for UserCommandService {
         &self,
         req: &CreateUserRequest,
     ) -> Result<ApiResponse<UserResponse>, ServiceError> {
+        let req = &CreateUserRequest {
+            email: self.email_normalization.normalize(&req.email),
+            ..req.clone()
+        };
+
         if let Err(validation_errors) = req.validate() {
             let error_msg = format_validation_errors(&validation_errors);
             error!("Validation failed: {error_msg}");
@@ -168,6 +193,7 @@ impl UserCommandServiceTrait for UserCommandService {
             password: hashed_password,
             email: req.email.clone(),
             confirm_password: req.confirm_password.clone(),
+            must_change_password: req.must_change_password,
         };
 
         let new_user = match self.command.create(new_request).await {
@@ -236,6 +262,14 @@ impl UserCommandServiceTrait for UserCommandService {
         &self,
         req: &UpdateUserRequest,
     ) -> Result<ApiResponse<UserResponse>, ServiceError> {
+        let req = &UpdateUserRequest {
+            email: req
+                .email
+                .as_deref()
+                .map(|email| self.email_normalization.normalize(email)),
+            ..req.clone()
+        };
+
         req.validate().map_err(|e| {
             let msg = format!("❌ Validation failed: {e:?}");
             error!("{msg}");
@@ -274,13 +308,10 @@ impl UserCommandServiceTrait for UserCommandService {
         };
 
         if let Some(new_email) = &req.email {
-            let new_email_norm = new_email.trim().to_lowercase();
-            let old_email_norm = user.email.trim().to_lowercase();
-
-            if new_email_norm == old_email_norm {
+            if new_email.trim().eq_ignore_ascii_case(user.email.trim()) {
                 info!("📧 Email unchanged after normalization, skipping DB check");
             } else {
-                match self.query.find_by_email(new_email_norm.clone()).await {
+                match self.query.find_by_email(new_email.clone()).await {
                     Ok(Some(_)) => {
                         let msg = format!("📧 Email {new_email} already used by another user");
                         error!("{msg}");
@@ -328,9 +359,13 @@ impl UserCommandServiceTrait for UserCommandService {
             .complete_tracing_success(&tracing_ctx, method, "User updated successfully")
             .await;
 
-        let cache_pattern = format!("user:find_by_id:id:{}", user_id);
-
-        self.cache_store.delete_from_cache(&cache_pattern).await;
+        self.cache_store
+            .invalidate_after_write(&[
+                format!("user:find_by_id:{}", user_id),
+                format!("user:find_by_id:id:{}", user_id),
+                format!("auth:getme:{}", user_id),
+            ])
+            .await;
 
         info!("✅ User updated successfully with id={}", response.id);
 
@@ -485,6 +520,150 @@ impl UserCommandServiceTrait for UserCommandService {
         })
     }
 
+    async fn anonymize(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<UserResponseDeleteAt>, ServiceError> {
+        info!("🕶️ Anonymizing user id={user_id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "anonymize_user",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("operation", "anonymize"),
+                KeyValue::new("user_id", user_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(user_id);
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let placeholder = AnonymizedIdentity::for_user(user_id);
+
+        let anonymized = match self.anonymization.anonymize(user_id, &placeholder).await {
+            Ok(user) => user,
+            Err(e) => {
+                let msg = format!("💥 Failed to anonymize user {user_id}: {e:?}");
+                error!("{msg}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method.clone(), &msg)
+                    .await;
+                return Err(if e.is_not_found() {
+                    ServiceError::NotFound(format!("User with ID {user_id} not found"))
+                } else {
+                    ServiceError::Repo(e)
+                });
+            }
+        };
+
+        let response = UserResponseDeleteAt::from(anonymized);
+
+        self.cache_store
+            .invalidate_after_write(&[
+                format!("user:find_by_id:{}", user_id),
+                format!("user:find_by_id:id:{}", user_id),
+                format!("auth:getme:{}", user_id),
+                format!("session:{}", user_id),
+                "user:find_all:*".to_string(),
+                "user:find_active:*".to_string(),
+                "user:find_trashed:*".to_string(),
+            ])
+            .await;
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "User anonymized successfully")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "🕶️ User anonymized successfully!".into(),
+            data: response,
+        })
+    }
+
+    async fn merge(
+        &self,
+        req: &MergeUsersRequest,
+    ) -> Result<ApiResponse<UserMergeResponse>, ServiceError> {
+        let primary_id = req.primary_id;
+        let duplicate_id = req.duplicate_id;
+
+        info!("🔗 Merging user id={duplicate_id} into id={primary_id}");
+
+        if let Err(validation_errors) = req.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Custom(error_msg));
+        }
+
+        if primary_id == duplicate_id {
+            return Err(ServiceError::Validation(vec![
+                "primary_id and duplicate_id must be different users".to_string(),
+            ]));
+        }
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "merge_users",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("operation", "merge"),
+                KeyValue::new("primary_id", primary_id.to_string()),
+                KeyValue::new("duplicate_id", duplicate_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let merged = match self.merge.merge(primary_id, duplicate_id).await {
+            Ok(merged) => merged,
+            Err(e) => {
+                let msg =
+                    format!("💥 Failed to merge user {duplicate_id} into {primary_id}: {e:?}");
+                error!("{msg}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method.clone(), &msg)
+                    .await;
+                return Err(if e.is_not_found() {
+                    ServiceError::NotFound(format!(
+                        "Active users with IDs {primary_id} and {duplicate_id} not found"
+                    ))
+                } else {
+                    ServiceError::Repo(e)
+                });
+            }
+        };
+
+        let response = UserMergeResponse::from(merged);
+
+        let cache_keys = vec![
+            format!("user:find_by_id:id:{}", primary_id),
+            format!("user:find_by_id:id:{}", duplicate_id),
+            format!("session:{}", duplicate_id),
+            "user:find_all:*".to_string(),
+            "user:find_active:*".to_string(),
+            "user:find_trashed:*".to_string(),
+        ];
+
+        for key in cache_keys {
+            self.cache_store.delete_from_cache(&key).await;
+        }
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Users merged successfully")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "🔗 Users merged successfully!".into(),
+            data: response,
+        })
+    }
+
     async fn delete_permanent(&self, user_id: i32) -> Result<ApiResponse<bool>, ServiceError> {
         info!("🧨 Permanently deleting user id={user_id}");
 
@@ -550,7 +729,7 @@ impl UserCommandServiceTrait for UserCommandService {
         }
     }
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("🔄 Restoring ALL trashed users");
 
         let method = Method::Post;
@@ -567,7 +746,7 @@ impl UserCommandServiceTrait for UserCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.restore_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All users restored successfully");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -590,7 +769,7 @@ impl UserCommandServiceTrait for UserCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "🔄 All users restored successfully!".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -604,7 +783,7 @@ impl UserCommandServiceTrait for UserCommandService {
         }
     }
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("💣 Permanently deleting ALL trashed users");
 
         let method = Method::Post;
@@ -621,7 +800,7 @@ impl UserCommandServiceTrait for UserCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.delete_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All users permanently deleted");
                 self.tracing_metrics_core
                     .complete_tracing_success(&tracing_ctx, method, "All users permanently deleted")
@@ -640,7 +819,7 @@ impl UserCommandServiceTrait for UserCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "💣 All users permanently deleted!".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -0,0 +1,138 @@
+use crate::{
+    abstract_trait::{
+        role::repository::query::DynRoleQueryRepository,
+        user::{
+            repository::{export::DynUserExportRepository, query::DynUserQueryRepository},
+            service::export::UserExportServiceTrait,
+        },
+    },
+    context::shared_resources::SharedResources,
+    domain::responses::{
+        ApiResponse, UserDataExportResponse, UserExportCardResponse,
+        UserExportTransactionSummaryResponse, UserResponse,
+    },
+    errors::ServiceError,
+    observability::{Method, TracingMetrics},
+};
+use anyhow::Result;
+use async_trait::async_trait;
+use chrono::Utc;
+use opentelemetry::KeyValue;
+use std::sync::Arc;
+use tonic::Request;
+use tracing::{error, info};
+
+pub struct UserExportService {
+    pub query: DynUserQueryRepository,
+    pub role: DynRoleQueryRepository,
+    pub export: DynUserExportRepository,
+    pub tracing_metrics_core: TracingMetrics,
+}
+
+pub struct UserExportServiceDeps {
+    pub query: DynUserQueryRepository,
+    pub role: DynRoleQueryRepository,
+    pub export: DynUserExportRepository,
+}
+
+impl UserExportService {
+    pub fn new(deps: UserExportServiceDeps, shared: &SharedResources) -> Result<Self> {
+        let UserExportServiceDeps {
+            query,
+            role,
+            export,
+        } = deps;
+
+        Ok(Self {
+            query,
+            role,
+            export,
+            tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
+        })
+    }
+}
+
+#[async_trait]
+impl UserExportServiceTrait for UserExportService {
+    async fn export_user_data(
+        &self,
+        user_id: i32,
+    ) -> Result<ApiResponse<UserDataExportResponse>, ServiceError> {
+        info!("📦 Exporting personal data for user ID: {user_id}");
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "export_user_data",
+            vec![
+                KeyValue::new("component", "user"),
+                KeyValue::new("operation", "export"),
+                KeyValue::new("user_id", user_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(user_id);
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        let user = match self.query.find_by_id(user_id).await {
+            Ok(user) => user,
+            Err(e) => {
+                error!("❌ Failed to load user {user_id} for export: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "User not found")
+                    .await;
+                return Err(if e.is_not_found() {
+                    ServiceError::NotFound(format!("User with ID {user_id} not found"))
+                } else {
+                    ServiceError::Repo(e)
+                });
+            }
+        };
+
+        let (roles, cards, summaries) = tokio::join!(
+            self.role.find_by_user_id(user_id),
+            self.export.find_cards(user_id),
+            self.export.transaction_summaries(user_id),
+        );
+
+        let (roles, cards, summaries) = match (roles, cards, summaries) {
+            (Ok(roles), Ok(cards), Ok(summaries)) => (roles, cards, summaries),
+            (Err(e), _, _) | (_, Err(e), _) | (_, _, Err(e)) => {
+                error!("❌ Failed to gather export data for user {user_id}: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method, "Failed to gather export data")
+                    .await;
+                return Err(ServiceError::Repo(e));
+            }
+        };
+
+        let export = UserDataExportResponse {
+            exported_at: Utc::now().naive_utc().to_string(),
+            profile: UserResponse::from(user),
+            roles: roles.into_iter().map(|r| r.role_name).collect(),
+            cards: cards
+                .into_iter()
+                .map(UserExportCardResponse::from)
+                .collect(),
+            transaction_summaries: summaries
+                .into_iter()
+                .map(UserExportTransactionSummaryResponse::from)
+                .collect(),
+        };
+
+        info!(
+            "✅ Exported data for user ID {user_id}: {} roles, {} cards",
+            export.roles.len(),
+            export.cards.len()
+        );
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "User data exported successfully")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".to_string(),
+            message: "User data exported successfully".to_string(),
+            data: export,
+        })
+    }
+}
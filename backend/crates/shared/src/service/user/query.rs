@@ -5,7 +5,7 @@ use crate::{
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::{
-        requests::user::FindAllUserRequest,
+        requests::{trashed::TrashedSort, user::FindAllUserRequest},
         responses::{
             ApiResponse, ApiResponsePagination, Pagination, UserResponse, UserResponseDeleteAt,
         },
@@ -44,7 +44,7 @@ impl UserQueryServiceTrait for UserQueryService {
         req: &FindAllUserRequest,
     ) -> Result<ApiResponsePagination<Vec<UserResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -128,6 +128,7 @@ impl UserQueryServiceTrait for UserQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
@@ -190,7 +191,11 @@ impl UserQueryServiceTrait for UserQueryService {
                         &format!("Database error fetching user: {:?}", e),
                     )
                     .await;
-                return Err(ServiceError::Custom(e.to_string()));
+                return Err(if e.is_not_found() {
+                    ServiceError::NotFound(format!("User with ID {user_id} not found"))
+                } else {
+                    ServiceError::Custom(e.to_string())
+                });
             }
         };
 
@@ -212,7 +217,7 @@ impl UserQueryServiceTrait for UserQueryService {
         req: &FindAllUserRequest,
     ) -> Result<ApiResponsePagination<Vec<UserResponseDeleteAt>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -297,6 +302,7 @@ impl UserQueryServiceTrait for UserQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
         self.cache_store
             .set_to_cache(&cache_key, &response, Duration::minutes(10))
@@ -314,8 +320,14 @@ impl UserQueryServiceTrait for UserQueryService {
         &self,
         req: &FindAllUserRequest,
     ) -> Result<ApiResponsePagination<Vec<UserResponseDeleteAt>>, ServiceError> {
+        let Some(sort) = TrashedSort::parse(req.sort_by.as_deref()) else {
+            return Err(ServiceError::Validation(vec![
+                TrashedSort::validation_error(),
+            ]));
+        };
+
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -346,8 +358,9 @@ impl UserQueryServiceTrait for UserQueryService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         let cache_key = format!(
-            "user:find_trashed:page:{page}:size:{page_size}:search:{}",
-            search.unwrap_or_default()
+            "user:find_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            search.unwrap_or_default(),
+            sort.as_str()
         );
 
         if let Some(cache) = self
@@ -400,6 +413,7 @@ impl UserQueryServiceTrait for UserQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         self.cache_store
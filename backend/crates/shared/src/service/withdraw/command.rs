@@ -10,19 +10,27 @@ use crate::{
         },
     },
     cache::CacheStore,
+    config::WithdrawCancellationConfig,
     context::shared_resources::SharedResources,
     domain::{
         requests::{
-            saldo::UpdateSaldoWithdraw,
+            saldo::{ApplySaldoLedgerEntry, UpdateSaldoWithdraw},
             withdraw::{CreateWithdrawRequest, UpdateWithdrawRequest, UpdateWithdrawStatus},
         },
-        responses::{ApiResponse, WithdrawResponse, WithdrawResponseDeleteAt},
+        responses::{
+            ApiResponse, BalanceImpactResponse, BulkResult, WithdrawResponse,
+            WithdrawResponseDeleteAt,
+        },
     },
     errors::{ServiceError, format_validation_errors},
+    model::withdraw::WithdrawModel,
     observability::{Method, TracingMetrics},
+    resilience::WriteLimiter,
+    service::card::usable::ensure_card_usable,
 };
 use anyhow::Result;
 use async_trait::async_trait;
+use chrono::NaiveDateTime;
 use opentelemetry::KeyValue;
 use std::sync::Arc;
 use tonic::Request;
@@ -30,12 +38,18 @@ use tracing::{error, info};
 
 use validator::Validate;
 
+const WITHDRAW_FEE: i64 = 0;
+const LEDGER_REFERENCE_WITHDRAW: &str = "withdraw";
+const CANCELLABLE_STATUS: &str = "pending";
+
 pub struct WithdrawCommandService {
     pub query: DynWithdrawQueryRepository,
     pub command: DynWithdrawCommandRepository,
     pub card_query: DynCardQueryRepository,
     pub saldo_query: DynSaldoQueryRepository,
     pub saldo_command: DynSaldoCommandRepository,
+    pub cancellation: WithdrawCancellationConfig,
+    pub write_limiter: Arc<WriteLimiter>,
     pub tracing_metrics_core: TracingMetrics,
     pub cache_store: Arc<CacheStore>,
 }
@@ -46,6 +60,8 @@ pub struct WithdrawCommandServiceDeps {
     pub card_query: DynCardQueryRepository,
     pub saldo_query: DynSaldoQueryRepository,
     pub saldo_command: DynSaldoCommandRepository,
+    pub cancellation: WithdrawCancellationConfig,
+    pub max_concurrent_writes: usize,
 }
 
 impl WithdrawCommandService {
@@ -56,6 +72,8 @@ impl WithdrawCommandService {
             card_query,
             saldo_query,
             saldo_command,
+            cancellation,
+            max_concurrent_writes,
         } = deps;
 
         Ok(Self {
@@ -64,6 +82,8 @@ impl WithdrawCommandService {
             card_query,
             saldo_query,
             saldo_command,
+            cancellation,
+            write_limiter: Arc::new(WriteLimiter::new(max_concurrent_writes)),
             tracing_metrics_core: Arc::clone(&shared.tracing_metrics),
             cache_store: Arc::clone(&shared.cache_store),
         })
@@ -72,6 +92,79 @@ impl WithdrawCommandService {
 
 #[async_trait]
 impl WithdrawCommandServiceTrait for WithdrawCommandService {
+    async fn preview(
+        &self,
+        req: &CreateWithdrawRequest,
+    ) -> Result<ApiResponse<BalanceImpactResponse>, ServiceError> {
+        info!("🔍 Previewing withdraw for card {}", req.card_number);
+
+        if let Err(validation_errors) = req.validate() {
+            let error_msg = format_validation_errors(&validation_errors);
+            error!("Validation failed: {error_msg}");
+            return Err(ServiceError::Validation(vec![error_msg]));
+        }
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "preview_withdraw",
+            vec![
+                KeyValue::new("component", "withdraw"),
+                KeyValue::new("operation", "preview"),
+                KeyValue::new("withdraw.amount", req.withdraw_amount.to_string()),
+            ],
+        );
+
+        let mut request_with_trace = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request_with_trace);
+
+        if let Err(e) = self.card_query.find_by_card(&req.card_number).await {
+            error!("❌ failed to find card {}: {e:?}", req.card_number);
+            let error_msg = format!("card {} not found", req.card_number);
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), &error_msg)
+                .await;
+            return Err(ServiceError::NotFound(error_msg));
+        }
+
+        let saldo = match self.saldo_query.find_by_card(&req.card_number).await {
+            Ok(saldo) => saldo,
+            Err(e) => {
+                error!(
+                    "❌ failed to find saldo for card {}: {e:?}",
+                    req.card_number
+                );
+                let error_msg = format!("failed to find saldo for card {}", req.card_number);
+                self.tracing_metrics_core
+                    .complete_tracing_error(&tracing_ctx, method.clone(), &error_msg)
+                    .await;
+                return Err(ServiceError::Custom(error_msg));
+            }
+        };
+
+        let impact = BalanceImpactResponse::evaluate(
+            &saldo.card_number,
+            saldo.total_balance,
+            req.withdraw_amount,
+            WITHDRAW_FEE,
+        );
+
+        info!(
+            "✅ Withdraw preview for card {}: {} -> {} (allowed: {})",
+            req.card_number, impact.current_balance, impact.resulting_balance, impact.allowed
+        );
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Withdraw preview computed")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "Withdraw preview computed".into(),
+            data: impact,
+        })
+    }
+
     async fn create(
         &self,
         req: &CreateWithdrawRequest,
@@ -98,6 +191,20 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
         self.tracing_metrics_core
             .inject_trace_context(&tracing_ctx.cx, &mut request_with_trace);
 
+        let _write_permit = match self.write_limiter.try_acquire("withdraw") {
+            Ok(permit) => permit,
+            Err(e) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        "Write capacity exhausted",
+                    )
+                    .await;
+                return Err(e);
+            }
+        };
+
         let _card = match self.card_query.find_by_card(&req.card_number).await {
             Ok(card) => card,
 
@@ -118,6 +225,13 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
             }
         };
 
+        if let Err(e) = ensure_card_usable(&self.card_query, &req.card_number).await {
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), &e.to_string())
+                .await;
+            return Err(e);
+        }
+
         let saldo = match self.saldo_query.find_by_card(&req.card_number).await {
             Ok(saldo) => saldo,
 
@@ -141,7 +255,14 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
             }
         };
 
-        if saldo.total_balance < req.withdraw_amount {
+        let impact = BalanceImpactResponse::evaluate(
+            &saldo.card_number,
+            saldo.total_balance,
+            req.withdraw_amount,
+            WITHDRAW_FEE,
+        );
+
+        if !impact.allowed {
             let error_msg = format!(
                 "error insufficient balance, requested: {}, available: {}",
                 req.withdraw_amount, saldo.total_balance
@@ -153,35 +274,6 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
             return Err(ServiceError::Custom("insufficient balance".into()));
         }
 
-        let new_total_balance = saldo.total_balance - req.withdraw_amount;
-
-        let update_data = UpdateSaldoWithdraw {
-            card_number: req.card_number.clone(),
-            total_balance: new_total_balance as i32,
-            withdraw_amount: req.withdraw_amount as i32,
-            withdraw_time: req.withdraw_time,
-        };
-
-        let _ = match self.saldo_command.update_withdraw(&update_data).await {
-            Ok(updated) => updated,
-
-            Err(e) => {
-                error!("❌ failed to update saldo: {e:?}");
-
-                let error_msg = "failed to update saldo";
-
-                self.tracing_metrics_core
-                    .complete_tracing_error(
-                        &tracing_ctx,
-                        method.clone(),
-                        &format!("{}: {:?}", error_msg, e),
-                    )
-                    .await;
-
-                return Err(ServiceError::Custom(error_msg.into()));
-            }
-        };
-
         let withdraw_record = match self.command.create(req).await {
             Ok(record) => {
                 info!("created withdraw record {:?}", record.withdraw_id);
@@ -195,24 +287,50 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
                     .complete_tracing_error(&tracing_ctx, method.clone(), &error_msg)
                     .await;
 
-                let rollback_data = UpdateSaldoWithdraw {
-                    card_number: req.card_number.clone(),
-                    total_balance: saldo.total_balance as i32,
-                    withdraw_amount: req.withdraw_amount as i32,
-                    withdraw_time: req.withdraw_time,
-                };
-
-                if let Err(rollback_err) = self.saldo_command.update_withdraw(&rollback_data).await
-                {
-                    error!("error rollback {rollback_err:?}");
-                }
-
                 return Err(ServiceError::Custom(
                     "failed to create withdraw record".into(),
                 ));
             }
         };
 
+        let update_data = UpdateSaldoWithdraw {
+            card_number: req.card_number.clone(),
+            total_balance: impact.resulting_balance as i32,
+            withdraw_amount: req.withdraw_amount as i32,
+            withdraw_time: req.withdraw_time,
+        };
+
+        if let Err(e) = self
+            .saldo_command
+            .withdraw_with_ledger(&update_data, withdraw_record.withdraw_id)
+            .await
+        {
+            error!("❌ failed to update saldo: {e:?}");
+
+            let error_msg = "failed to update saldo";
+
+            self.tracing_metrics_core
+                .complete_tracing_error(
+                    &tracing_ctx,
+                    method.clone(),
+                    &format!("{}: {:?}", error_msg, e),
+                )
+                .await;
+
+            if let Err(e2) = self
+                .command
+                .update_status(&UpdateWithdrawStatus {
+                    withdraw_id: withdraw_record.withdraw_id,
+                    status: "failed".to_string(),
+                })
+                .await
+            {
+                error!("error {e2:?}");
+            }
+
+            return Err(ServiceError::Custom(error_msg.into()));
+        }
+
         let update_status = UpdateWithdrawStatus {
             withdraw_id: withdraw_record.withdraw_id,
             status: "success".to_string(),
@@ -329,6 +447,13 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
             }
         };
 
+        if let Err(e) = ensure_card_usable(&self.card_query, &req.card_number).await {
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), &e.to_string())
+                .await;
+            return Err(e);
+        }
+
         let _withdraw = match self.query.find_by_id(withdraw_id).await {
             Ok(w) => w,
 
@@ -393,7 +518,11 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
             withdraw_time: req.withdraw_time,
         };
 
-        if let Err(e) = self.saldo_command.update_withdraw(&update_saldo_data).await {
+        if let Err(e) = self
+            .saldo_command
+            .withdraw_with_ledger(&update_saldo_data, withdraw_id)
+            .await
+        {
             error!("error {e:?}");
             let error_msg = "failed to update saldo";
             self.tracing_metrics_core
@@ -427,13 +556,15 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
                     .complete_tracing_error(&tracing_ctx, method.clone(), &error_msg)
                     .await;
 
-                let rollback_data = UpdateSaldoWithdraw {
-                    card_number: req.card_number.clone(),
-                    total_balance: saldo.total_balance as i32,
-                    withdraw_amount: req.withdraw_amount as i32,
-                    withdraw_time: req.withdraw_time,
-                };
-                if let Err(rollback_err) = self.saldo_command.update_withdraw(&rollback_data).await
+                if let Err(rollback_err) = self
+                    .saldo_command
+                    .apply_ledger_entry(&ApplySaldoLedgerEntry {
+                        card_number: req.card_number.clone(),
+                        amount: req.withdraw_amount,
+                        reference_type: LEDGER_REFERENCE_WITHDRAW,
+                        reference_id: withdraw_id,
+                    })
+                    .await
                 {
                     error!("error rollback {rollback_err:?}");
                 }
@@ -512,6 +643,109 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
         })
     }
 
+    async fn cancel_withdraw(
+        &self,
+        withdraw_id: i32,
+    ) -> Result<ApiResponse<WithdrawResponse>, ServiceError> {
+        info!("↩️ Cancelling withdraw id={withdraw_id}");
+
+        let method = Method::Post;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "cancel_withdraw",
+            vec![
+                KeyValue::new("component", "withdraw"),
+                KeyValue::new("operation", "cancel"),
+                KeyValue::new("withdraw_id", withdraw_id.to_string()),
+            ],
+        );
+
+        let mut request = Request::new(withdraw_id);
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request);
+
+        if !self.cancellation.is_enabled() {
+            let error_msg = "withdraw cancellation is disabled";
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), error_msg)
+                .await;
+            return Err(ServiceError::Forbidden(error_msg.into()));
+        }
+
+        let _write_permit = match self.write_limiter.try_acquire("withdraw") {
+            Ok(permit) => permit,
+            Err(e) => {
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        "Write capacity exhausted",
+                    )
+                    .await;
+                return Err(e);
+            }
+        };
+
+        let cutoff = chrono::Utc::now().naive_utc() - self.cancellation.window();
+
+        let cancelled = match self.command.cancel(withdraw_id, cutoff).await {
+            Ok(cancelled) => cancelled,
+            Err(e) => {
+                error!("❌ Failed to cancel withdraw id={withdraw_id}: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("Failed to cancel withdraw: {:?}", e),
+                    )
+                    .await;
+                return Err(ServiceError::Custom(format!(
+                    "Failed to cancel withdraw with id {withdraw_id}",
+                )));
+            }
+        };
+
+        let Some(withdraw) = cancelled else {
+            let error = match self.query.find_by_id(withdraw_id).await {
+                Err(_) => ServiceError::NotFound(format!("withdraw {withdraw_id} not found")),
+                Ok(existing) => cancel_rejection(&existing, cutoff, self.cancellation.window_secs),
+            };
+
+            error!("❌ Withdraw id={withdraw_id} was not cancelled: {error}");
+            self.tracing_metrics_core
+                .complete_tracing_error(&tracing_ctx, method.clone(), &error.to_string())
+                .await;
+            return Err(error);
+        };
+
+        info!(
+            "✅ Withdraw cancelled: id={withdraw_id}, {} credited back to card {}",
+            withdraw.withdraw_amount, withdraw.card_number
+        );
+
+        let cache_keys = vec![
+            format!("withdraw:find_by_card:{}", withdraw.card_number),
+            format!("saldo:find_by_card:{}", withdraw.card_number),
+            format!("withdraw:find_by_id:{withdraw_id}"),
+            "withdraw:find_all:*".to_string(),
+            "withdraw:find_by_active:*".to_string(),
+            "withdraw:find_by_trashed:*".to_string(),
+        ];
+
+        for key in cache_keys {
+            self.cache_store.delete_from_cache(&key).await;
+        }
+
+        self.tracing_metrics_core
+            .complete_tracing_success(&tracing_ctx, method, "Withdraw cancelled successfully")
+            .await;
+
+        Ok(ApiResponse {
+            status: "success".into(),
+            message: "Withdraw cancelled successfully".into(),
+            data: WithdrawResponse::from(withdraw),
+        })
+    }
+
     async fn trashed_withdraw(
         &self,
         withdraw_id: i32,
@@ -695,7 +929,7 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
         }
     }
 
-    async fn restore_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn restore_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("🔄 Restoring all trashed withdraws");
 
         let method = Method::Post;
@@ -712,7 +946,7 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.restore_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All withdraws restored successfully");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -736,7 +970,7 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "All withdraws restored successfully".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -755,7 +989,7 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
         }
     }
 
-    async fn delete_all(&self) -> Result<ApiResponse<bool>, ServiceError> {
+    async fn delete_all(&self) -> Result<ApiResponse<BulkResult>, ServiceError> {
         info!("💣 Permanently deleting all trashed withdraws");
 
         let method = Method::Post;
@@ -772,7 +1006,7 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
             .inject_trace_context(&tracing_ctx.cx, &mut request);
 
         match self.command.delete_all().await {
-            Ok(_) => {
+            Ok(result) => {
                 info!("✅ All withdraws permanently deleted");
                 self.tracing_metrics_core
                     .complete_tracing_success(
@@ -795,7 +1029,7 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
                 Ok(ApiResponse {
                     status: "success".into(),
                     message: "All withdraws permanently deleted".into(),
-                    data: true,
+                    data: result.into(),
                 })
             }
             Err(e) => {
@@ -814,3 +1048,76 @@ impl WithdrawCommandServiceTrait for WithdrawCommandService {
         }
     }
 }
+
+/// Explains why the cancel statement left a withdraw untouched. Only a
+/// withdraw that is still `pending`, and so not yet disbursed, can be reversed.
+fn cancel_rejection(
+    existing: &WithdrawModel,
+    cutoff: NaiveDateTime,
+    window_secs: u64,
+) -> ServiceError {
+    let withdraw_id = existing.withdraw_id;
+
+    if existing.status != CANCELLABLE_STATUS {
+        return ServiceError::Forbidden(format!(
+            "withdraw {withdraw_id} cannot be cancelled in status '{}'",
+            existing.status
+        ));
+    }
+
+    if existing.created_at.is_some_and(|at| at < cutoff) {
+        return ServiceError::Forbidden(format!(
+            "cancellation window of {window_secs}s for withdraw {withdraw_id} has passed"
+        ));
+    }
+
+    ServiceError::Custom(format!("Failed to cancel withdraw with id {withdraw_id}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, NaiveDate};
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2025, 8, 26)
+            .unwrap()
+            .and_hms_opt(12, 0, 0)
+            .unwrap()
+    }
+
+    fn withdraw(status: &str, created_at: NaiveDateTime) -> WithdrawModel {
+        WithdrawModel {
+            withdraw_id: 7,
+            withdraw_no: Default::default(),
+            card_number: "4111111111111111".to_string(),
+            withdraw_amount: 50_000,
+            withdraw_time: created_at,
+            status: status.to_string(),
+            created_at: Some(created_at),
+            updated_at: Some(created_at),
+            deleted_at: None,
+        }
+    }
+
+    #[test]
+    fn disbursed_withdraws_cannot_be_cancelled() {
+        let cutoff = now() - Duration::seconds(900);
+        let error = cancel_rejection(&withdraw("success", now()), cutoff, 900);
+
+        assert!(
+            matches!(error, ServiceError::Forbidden(ref msg) if msg.contains("status 'success'"))
+        );
+    }
+
+    #[test]
+    fn pending_withdraws_outside_the_window_are_rejected() {
+        let cutoff = now() - Duration::seconds(900);
+        let stale = withdraw("pending", cutoff - Duration::seconds(1));
+
+        assert!(matches!(
+            cancel_rejection(&stale, cutoff, 900),
+            ServiceError::Forbidden(ref msg) if msg.contains("900s")
+        ));
+    }
+}
@@ -5,7 +5,10 @@ use crate::{
     cache::CacheStore,
     context::shared_resources::SharedResources,
     domain::{
-        requests::withdraw::{FindAllWithdrawCardNumber, FindAllWithdraws},
+        requests::{
+            trashed::TrashedSort,
+            withdraw::{FindAllWithdrawCardNumber, FindAllWithdraws, FindWithdraws},
+        },
         responses::{
             ApiResponse, ApiResponsePagination, Pagination, WithdrawResponse,
             WithdrawResponseDeleteAt,
@@ -13,6 +16,7 @@ use crate::{
     },
     errors::ServiceError,
     observability::{Method, TracingMetrics},
+    utils::{card_cache_key, mask_card_number},
 };
 use anyhow::Result;
 use async_trait::async_trait;
@@ -45,7 +49,7 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
         req: &FindAllWithdraws,
     ) -> Result<ApiResponsePagination<Vec<WithdrawResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -132,6 +136,7 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -151,7 +156,7 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
         req: &FindAllWithdrawCardNumber,
     ) -> Result<ApiResponsePagination<Vec<WithdrawResponse>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -251,6 +256,7 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
 
         info!(
@@ -266,6 +272,140 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
         Ok(response)
     }
 
+    async fn find_withdraws(
+        &self,
+        req: &FindWithdraws,
+    ) -> Result<ApiResponsePagination<Vec<WithdrawResponse>>, ServiceError> {
+        let page = if req.page > 0 { req.page } else { 1 };
+        let page_size = req.page_size.clamp(1, 100);
+
+        let mut errors = Vec::new();
+
+        let (Some(sort_column), Some(sort_direction)) = (req.sort_column(), req.sort_direction())
+        else {
+            if req.sort_column().is_none() {
+                errors.push(format!(
+                    "sort_by must be one of: {}",
+                    FindWithdraws::SORT_COLUMNS.join(", ")
+                ));
+            }
+            if req.sort_direction().is_none() {
+                errors.push("sort_dir must be either asc or desc".to_string());
+            }
+            return Err(ServiceError::Validation(errors));
+        };
+
+        if let (Some(from), Some(to)) = (req.from, req.to)
+            && from > to
+        {
+            errors.push("from must not be after to".to_string());
+            return Err(ServiceError::Validation(errors));
+        }
+
+        let status = req.status.clone().unwrap_or_default();
+        let card_number = req.card_number.clone().unwrap_or_default();
+        let from = req.from.map(|d| d.to_string()).unwrap_or_default();
+        let to = req.to.map(|d| d.to_string()).unwrap_or_default();
+
+        let masked_card = mask_card_number(&card_number);
+
+        info!(
+            "🔍 Filtering withdrawals | Page: {page}, Size: {page_size}, Status: {status:?}, Card: {masked_card}, Range: {from:?}..{to:?}, Sort: {sort_column} {sort_direction}"
+        );
+
+        let method = Method::Get;
+        let tracing_ctx = self.tracing_metrics_core.start_tracing(
+            "find_withdraws",
+            vec![
+                KeyValue::new("component", "withdrawal"),
+                KeyValue::new("operation", "find_withdraws"),
+                KeyValue::new("page", page.to_string()),
+                KeyValue::new("page_size", page_size.to_string()),
+                KeyValue::new("status", status.clone()),
+                KeyValue::new("card_number", masked_card.clone()),
+                KeyValue::new("sort", format!("{sort_column} {sort_direction}")),
+            ],
+        );
+
+        let mut request_obj = Request::new(req.clone());
+        self.tracing_metrics_core
+            .inject_trace_context(&tracing_ctx.cx, &mut request_obj);
+
+        let card_key = card_cache_key(&card_number);
+        let cache_key = format!(
+            "withdrawal:find_withdraws:page:{page}:size:{page_size}:status:{status}:card:{card_key}:from:{from}:to:{to}:sort:{sort_column}:{sort_direction}"
+        );
+
+        if let Some(cache) = self
+            .cache_store
+            .get_from_cache::<ApiResponsePagination<Vec<WithdrawResponse>>>(&cache_key)
+            .await
+        {
+            let log_msg = format!(
+                "✅ Found {} filtered withdrawals in cache",
+                cache.data.len()
+            );
+            info!("{log_msg}");
+            self.tracing_metrics_core
+                .complete_tracing_success(&tracing_ctx, method, &log_msg)
+                .await;
+            return Ok(cache);
+        }
+
+        let (withdrawals, total_items) = match self.query.find_withdraws(req).await {
+            Ok(res) => {
+                let log_msg = format!("✅ Found {} filtered withdrawals", res.0.len());
+                info!("{log_msg}");
+                self.tracing_metrics_core
+                    .complete_tracing_success(&tracing_ctx, method, &log_msg)
+                    .await;
+                res
+            }
+            Err(e) => {
+                error!("❌ Failed to filter withdrawals: {e:?}");
+                self.tracing_metrics_core
+                    .complete_tracing_error(
+                        &tracing_ctx,
+                        method.clone(),
+                        &format!("❌ Failed to filter withdrawals: {e:?}"),
+                    )
+                    .await;
+                return Err(ServiceError::Custom(e.to_string()));
+            }
+        };
+
+        let total_pages = (total_items as f64 / page_size as f64).ceil() as i32;
+
+        let withdraw_responses: Vec<WithdrawResponse> = withdrawals
+            .into_iter()
+            .map(WithdrawResponse::from)
+            .collect();
+
+        let response = ApiResponsePagination {
+            status: "success".to_string(),
+            message: "Withdrawals retrieved successfully".to_string(),
+            data: withdraw_responses,
+            pagination: Pagination {
+                page,
+                page_size,
+                total_items,
+                total_pages,
+            },
+            links: None,
+        };
+
+        info!(
+            "✅ Found {} filtered withdrawals (total: {total_items})",
+            response.data.len()
+        );
+
+        self.cache_store
+            .set_to_cache(&cache_key, &response, Duration::minutes(10))
+            .await;
+
+        Ok(response)
+    }
+
     async fn find_by_id(
         &self,
         withdraw_id: i32,
@@ -319,7 +459,11 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
                         &format!("Database error fetching withdrawal: {:?}", e),
                     )
                     .await;
-                return Err(ServiceError::Custom(e.to_string()));
+                return Err(if e.is_not_found() {
+                    ServiceError::NotFound(format!("Withdraw with ID {withdraw_id} not found"))
+                } else {
+                    ServiceError::Custom(e.to_string())
+                });
             }
         };
 
@@ -422,7 +566,7 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
         req: &FindAllWithdraws,
     ) -> Result<ApiResponsePagination<Vec<WithdrawResponseDeleteAt>>, ServiceError> {
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -509,6 +653,7 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
         self.cache_store
             .set_to_cache(&cache_key, &response, Duration::minutes(10))
@@ -526,8 +671,14 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
         &self,
         req: &FindAllWithdraws,
     ) -> Result<ApiResponsePagination<Vec<WithdrawResponseDeleteAt>>, ServiceError> {
+        let Some(sort) = TrashedSort::parse(req.sort_by.as_deref()) else {
+            return Err(ServiceError::Validation(vec![
+                TrashedSort::validation_error(),
+            ]));
+        };
+
         let page = if req.page > 0 { req.page } else { 1 };
-        let page_size = if req.page_size > 0 { req.page_size } else { 10 };
+        let page_size = req.page_size.clamp(1, 100);
         let search = if req.search.is_empty() {
             None
         } else {
@@ -558,8 +709,9 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
             .inject_trace_context(&tracing_ctx.cx, &mut request_obj);
 
         let cache_key = format!(
-            "withdrawal:find_trashed:page:{page}:size:{page_size}:search:{}",
-            search.unwrap_or_default()
+            "withdrawal:find_trashed:page:{page}:size:{page_size}:search:{}:sort:{}",
+            search.unwrap_or_default(),
+            sort.as_str()
         );
 
         if let Some(cache) = self
@@ -614,6 +766,7 @@ impl WithdrawQueryServiceTrait for WithdrawQueryService {
                 total_items,
                 total_pages,
             },
+            links: None,
         };
         self.cache_store
             .set_to_cache(&cache_key, &response, Duration::minutes(10))
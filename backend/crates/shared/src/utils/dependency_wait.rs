@@ -0,0 +1,127 @@
+use crate::config::DependencyWaitConfig;
+use anyhow::{Result, bail};
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+use tonic::transport::Channel;
+use tonic_health::pb::{
+    HealthCheckRequest, health_check_response::ServingStatus, health_client::HealthClient,
+};
+use tracing::{debug, info, warn};
+
+fn dependency_name(service: &str) -> &str {
+    service.trim_end_matches("-service")
+}
+
+pub async fn wait_for_dependencies(
+    channels: &[(&Channel, &str)],
+    config: &DependencyWaitConfig,
+) -> Result<()> {
+    if !config.enabled {
+        return Ok(());
+    }
+
+    let known: Vec<&str> = channels
+        .iter()
+        .map(|(_, service)| dependency_name(service))
+        .collect();
+    let unknown: Vec<&str> = config
+        .required
+        .iter()
+        .map(String::as_str)
+        .filter(|name| !known.contains(name))
+        .collect();
+    if !unknown.is_empty() {
+        bail!(
+            "Unknown dependencies in GATEWAY_REQUIRED_DEPENDENCIES: {}, expected any of: {}",
+            unknown.join(", "),
+            known.join(", ")
+        );
+    }
+
+    let started = Instant::now();
+    let mut waiting: Vec<String> = Vec::new();
+    let mut pending = JoinSet::new();
+
+    for (channel, service) in channels {
+        if !config.is_required(dependency_name(service)) {
+            continue;
+        }
+
+        let channel = (*channel).clone();
+        let service = service.to_string();
+        let interval = config.poll_interval();
+
+        waiting.push(service.clone());
+        pending.spawn(async move {
+            wait_until_serving(channel, &service, interval).await;
+            service
+        });
+    }
+
+    info!(
+        "⏳ Waiting up to {:?} for {} to report SERVING",
+        config.timeout(),
+        waiting.join(", ")
+    );
+
+    let result = tokio::time::timeout(config.timeout(), async {
+        while let Some(joined) = pending.join_next().await {
+            match joined {
+                Ok(service) => {
+                    info!("💚 {service} is healthy after {:?}", started.elapsed());
+                    waiting.retain(|s| *s != service);
+                }
+                Err(e) => warn!("⚠️ Dependency health wait task failed: {e:?}"),
+            }
+        }
+    })
+    .await;
+
+    if result.is_err() || !waiting.is_empty() {
+        let waiting = waiting.join(", ");
+        if config.strict {
+            bail!(
+                "Timed out after {:?} waiting for {waiting} to become healthy",
+                config.timeout()
+            );
+        }
+        warn!(
+            "⚠️ Timed out after {:?} waiting for {waiting} to become healthy, starting anyway",
+            config.timeout()
+        );
+        return Ok(());
+    }
+
+    info!(
+        "✅ All required dependencies healthy in {:?}",
+        started.elapsed()
+    );
+    Ok(())
+}
+
+pub async fn check_grpc_health(channel: Channel) -> Result<(), String> {
+    let response = HealthClient::new(channel)
+        .check(HealthCheckRequest {
+            service: String::new(),
+        })
+        .await
+        .map_err(|status| status.message().to_string())?;
+
+    match response.get_ref().status() {
+        ServingStatus::Serving => Ok(()),
+        status => Err(format!("reports {}", status.as_str_name())),
+    }
+}
+
+async fn wait_until_serving(channel: Channel, service: &str, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+
+    loop {
+        ticker.tick().await;
+
+        match check_grpc_health(channel.clone()).await {
+            Ok(()) => return,
+            Err(reason) => debug!("{service} is not healthy yet: {reason}, retrying"),
+        }
+    }
+}
@@ -0,0 +1,98 @@
+use crate::{
+    config::{ConnectionPool, GrpcHealthConfig},
+    utils::check_database,
+};
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+use tonic::server::NamedService;
+use tonic_health::{
+    ServingStatus,
+    pb::health_server::HealthServer,
+    server::{HealthReporter, HealthService},
+};
+use tracing::{info, warn};
+
+#[derive(Clone)]
+pub struct GrpcHealthGate {
+    reporter: HealthReporter,
+    service_name: &'static str,
+    config: GrpcHealthConfig,
+    draining: Arc<AtomicBool>,
+}
+
+impl GrpcHealthGate {
+    pub async fn new<S: NamedService>(config: GrpcHealthConfig) -> Self {
+        let gate = Self {
+            reporter: HealthReporter::new(),
+            service_name: S::NAME,
+            config,
+            draining: Arc::new(AtomicBool::new(false)),
+        };
+
+        gate.set_status(ServingStatus::NotServing).await;
+        gate
+    }
+
+    pub fn service(&self) -> HealthServer<HealthService> {
+        HealthServer::new(HealthService::from_health_reporter(self.reporter.clone()))
+    }
+
+    pub fn spawn_monitor(&self, pool: ConnectionPool) {
+        let gate = self.clone();
+
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(gate.config.check_interval());
+            let mut serving = false;
+
+            loop {
+                interval.tick().await;
+
+                if gate.draining.load(Ordering::Acquire) {
+                    break;
+                }
+
+                match check_database(&pool).await {
+                    Ok(()) if !serving => {
+                        info!(
+                            "💚 [{}] database ready, reporting SERVING",
+                            gate.service_name
+                        );
+                        gate.set_status(ServingStatus::Serving).await;
+                        serving = true;
+                    }
+                    Err(reason) if serving => {
+                        warn!("💔 [{}] {reason}, reporting NOT_SERVING", gate.service_name);
+                        gate.set_status(ServingStatus::NotServing).await;
+                        serving = false;
+                    }
+                    _ => {}
+                }
+            }
+        });
+    }
+
+    pub async fn drain(&self) {
+        self.draining.store(true, Ordering::Release);
+        self.set_status(ServingStatus::NotServing).await;
+
+        let drain = self.config.drain();
+        info!(
+            "🚰 [{}] reporting NOT_SERVING, draining for {drain:?} before stopping",
+            self.service_name
+        );
+        tokio::time::sleep(drain).await;
+    }
+
+    async fn set_status(&self, status: ServingStatus) {
+        if self.draining.load(Ordering::Acquire) && status == ServingStatus::Serving {
+            return;
+        }
+
+        self.reporter.set_service_status("", status).await;
+        self.reporter
+            .set_service_status(self.service_name, status)
+            .await;
+    }
+}
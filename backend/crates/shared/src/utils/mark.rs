@@ -1,3 +1,5 @@
+use sha2::{Digest, Sha256};
+
 pub fn mask_card_number(number: &str) -> String {
     let len = number.len();
     if len < 8 {
@@ -9,6 +11,15 @@ pub fn mask_card_number(number: &str) -> String {
     }
 }
 
+/// Stable, non-reversible stand-in for a card number in cache keys.
+pub fn card_cache_key(number: &str) -> String {
+    if number.is_empty() {
+        return String::new();
+    }
+    let digest = Sha256::digest(number.as_bytes());
+    hex::encode(&digest[..12])
+}
+
 pub fn mask_api_key(key: &str) -> String {
     if key.len() < 10 {
         "****".to_string()
@@ -18,3 +29,147 @@ pub fn mask_api_key(key: &str) -> String {
         format!("{prefix}...{suffix}")
     }
 }
+
+pub fn mask_email(email: &str) -> String {
+    match email.trim().split_once('@') {
+        Some((local, domain)) if !local.is_empty() && !domain.is_empty() => {
+            let first = local.chars().next().unwrap_or('*');
+            format!("{first}***@{domain}")
+        }
+        _ => "****".to_string(),
+    }
+}
+
+pub fn redact_connection_string(text: &str) -> String {
+    let mut redacted = String::with_capacity(text.len());
+    let mut rest = text;
+
+    while let Some(idx) = rest.find("://") {
+        let (head, tail) = rest.split_at(idx + 3);
+        redacted.push_str(head);
+
+        let authority_end = tail
+            .find(|c: char| matches!(c, '/' | '?' | '#') || c.is_whitespace())
+            .unwrap_or(tail.len());
+        let authority = &tail[..authority_end];
+
+        match authority.rfind('@') {
+            Some(at) => {
+                match authority[..at].split_once(':') {
+                    Some((user, _)) => {
+                        redacted.push_str(user);
+                        redacted.push_str(":****");
+                    }
+                    None => redacted.push_str(&authority[..at]),
+                }
+                redacted.push_str(&authority[at..]);
+            }
+            None => redacted.push_str(authority),
+        }
+
+        rest = &tail[authority_end..];
+    }
+    redacted.push_str(rest);
+
+    mask_query_password(&redacted)
+        .split_inclusive(char::is_whitespace)
+        .map(|part| {
+            let trimmed = part.trim_end();
+            match trimmed.split_once('=') {
+                Some((key, _)) if key.eq_ignore_ascii_case("password") => {
+                    format!("{key}=****{}", &part[trimmed.len()..])
+                }
+                _ => part.to_string(),
+            }
+        })
+        .collect()
+}
+
+/// Masks `password` URL query parameters, e.g. `postgres://db/app?password=x`.
+fn mask_query_password(text: &str) -> String {
+    let lower = text.to_ascii_lowercase();
+    let mut masked = String::with_capacity(text.len());
+    let mut pos = 0;
+
+    while let Some(found) = ["?password=", "&password="]
+        .iter()
+        .filter_map(|key| lower[pos..].find(key).map(|idx| pos + idx + key.len()))
+        .min()
+    {
+        masked.push_str(&text[pos..found]);
+        masked.push_str("****");
+        pos = text[found..]
+            .find(|c: char| matches!(c, '&' | '#') || c.is_whitespace())
+            .map_or(text.len(), |end| found + end);
+    }
+    masked.push_str(&text[pos..]);
+
+    masked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn card_cache_key_hides_the_number() {
+        let key = card_cache_key("4111111111111111");
+        assert_eq!(key.len(), 24);
+        assert!(!key.contains("4111"));
+        assert_eq!(key, card_cache_key("4111111111111111"));
+        assert_ne!(key, card_cache_key("4111111111111112"));
+        assert_eq!(card_cache_key(""), "");
+    }
+
+    #[test]
+    fn masks_email_local_part() {
+        assert_eq!(mask_email("alice@example.com"), "a***@example.com");
+        assert_eq!(mask_email("  bob@example.com "), "b***@example.com");
+        assert_eq!(mask_email("élodie@example.fr"), "é***@example.fr");
+    }
+
+    #[test]
+    fn hides_malformed_emails_entirely() {
+        assert_eq!(mask_email("not-an-email"), "****");
+        assert_eq!(mask_email("@example.com"), "****");
+        assert_eq!(mask_email("alice@"), "****");
+    }
+
+    #[test]
+    fn redacts_url_passwords() {
+        assert_eq!(
+            redact_connection_string("postgres://app:secret@db:5432/payments"),
+            "postgres://app:****@db:5432/payments"
+        );
+        assert_eq!(
+            redact_connection_string("redis://:secret@redis:6379/0"),
+            "redis://:****@redis:6379/0"
+        );
+        assert_eq!(
+            redact_connection_string("postgres://app@db/payments"),
+            "postgres://app@db/payments"
+        );
+    }
+
+    #[test]
+    fn redacts_password_query_parameters() {
+        assert_eq!(
+            redact_connection_string(
+                "postgres://db/payments?user=app&password=secret&sslmode=require"
+            ),
+            "postgres://db/payments?user=app&password=****&sslmode=require"
+        );
+        assert_eq!(
+            redact_connection_string("failed: postgres://db/payments?PASSWORD=secret retrying"),
+            "failed: postgres://db/payments?PASSWORD=**** retrying"
+        );
+    }
+
+    #[test]
+    fn redacts_key_value_passwords() {
+        assert_eq!(
+            redact_connection_string("host=db user=app password=secret dbname=payments"),
+            "host=db user=app password=**** dbname=payments"
+        );
+    }
+}
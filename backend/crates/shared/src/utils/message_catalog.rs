@@ -0,0 +1,163 @@
+pub const SUPPORTED_LOCALES: [&str; 2] = ["en", "id"];
+
+const ID_MESSAGES: &[(&str, &str)] = &[
+    ("Invalid credentials", "Email atau kata sandi salah"),
+    ("Not found", "Data tidak ditemukan"),
+    (
+        "Repository error",
+        "Terjadi kesalahan pada penyimpanan data",
+    ),
+    ("Token expired", "Token telah kedaluwarsa"),
+    ("Invalid token", "Token tidak valid"),
+    ("Invalid token type", "Jenis token tidak valid"),
+    (
+        "Unknown service error",
+        "Terjadi kesalahan layanan yang tidak diketahui",
+    ),
+    (
+        "Internal authentication error",
+        "Terjadi kesalahan autentikasi internal",
+    ),
+    (
+        "Service temporarily unavailable - circuit breaker is open",
+        "Layanan sementara tidak tersedia",
+    ),
+    (
+        "Service temporarily unavailable due to high error rate. Please try again later.",
+        "Layanan sementara tidak tersedia karena tingginya tingkat kesalahan. Silakan coba lagi nanti.",
+    ),
+    (
+        "Too many requests, please try again later",
+        "Terlalu banyak permintaan, silakan coba lagi nanti",
+    ),
+    (
+        "You are not logged in, please provide token",
+        "Anda belum masuk, silakan sertakan token",
+    ),
+    (
+        "Session expired or not found",
+        "Sesi telah berakhir atau tidak ditemukan",
+    ),
+    (
+        "Missing user_id in request context",
+        "user_id tidak ditemukan pada permintaan",
+    ),
+    (
+        "Access denied. Required role: ADMIN",
+        "Akses ditolak. Diperlukan peran: ADMIN",
+    ),
+    ("token refreshed", "Token berhasil diperbarui"),
+    ("verification email sent", "Email verifikasi telah dikirim"),
+    ("user fetched successfully", "Pengguna berhasil diambil"),
+    ("User created successfully", "Pengguna berhasil dibuat"),
+    ("User updated successfully", "Pengguna berhasil diperbarui"),
+    ("Card created successfully", "Kartu berhasil dibuat"),
+    ("Card updated successfully", "Kartu berhasil diperbarui"),
+    ("Role created successfully", "Peran berhasil dibuat"),
+    ("Role updated successfully", "Peran berhasil diperbarui"),
+    (
+        "transaction created successfully",
+        "Transaksi berhasil dibuat",
+    ),
+    ("transfer created successfully", "Transfer berhasil dibuat"),
+    (
+        "Transactions retrieved successfully",
+        "Transaksi berhasil diambil",
+    ),
+    (
+        "Transfers retrieved successfully",
+        "Transfer berhasil diambil",
+    ),
+    (
+        "Withdrawals retrieved successfully",
+        "Penarikan berhasil diambil",
+    ),
+    ("Topups retrieved successfully", "Top up berhasil diambil"),
+    ("Saldo retrieved successfully", "Saldo berhasil diambil"),
+    (
+        "Payment methods retrieved successfully",
+        "Metode pembayaran berhasil diambil",
+    ),
+];
+
+pub fn supported_locale(tag: &str) -> Option<&'static str> {
+    let primary = tag.trim().split(['-', '_']).next()?.to_ascii_lowercase();
+    SUPPORTED_LOCALES.into_iter().find(|l| *l == primary)
+}
+
+pub fn negotiate_locale(
+    accept_language: Option<&str>,
+    default_locale: &'static str,
+) -> &'static str {
+    let Some(header) = accept_language else {
+        return default_locale;
+    };
+
+    let mut candidates: Vec<(&str, f32)> = header
+        .split(',')
+        .filter_map(|part| {
+            let mut pieces = part.split(';');
+            let tag = pieces.next()?.trim();
+            let quality = pieces
+                .find_map(|p| p.trim().strip_prefix("q="))
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+            (!tag.is_empty() && quality > 0.0).then_some((tag, quality))
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| b.1.total_cmp(&a.1));
+
+    candidates
+        .into_iter()
+        .find_map(|(tag, _)| {
+            if tag == "*" {
+                Some(default_locale)
+            } else {
+                supported_locale(tag)
+            }
+        })
+        .unwrap_or(default_locale)
+}
+
+pub fn translate_message(locale: &str, message: &str) -> Option<&'static str> {
+    let catalog = match locale {
+        "id" => ID_MESSAGES,
+        _ => return None,
+    };
+
+    let key = message
+        .trim_start_matches(|c: char| !c.is_ascii_alphanumeric())
+        .trim_end_matches('!')
+        .trim();
+
+    catalog
+        .iter()
+        .find(|(english, _)| english.eq_ignore_ascii_case(key))
+        .map(|(_, translated)| *translated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn picks_the_highest_quality_supported_locale() {
+        assert_eq!(negotiate_locale(Some("fr, id;q=0.8, en;q=0.5"), "en"), "id");
+        assert_eq!(negotiate_locale(Some("en;q=0.4, id-ID;q=0.9"), "en"), "id");
+        assert_eq!(negotiate_locale(Some("ID_id"), "en"), "id");
+    }
+
+    #[test]
+    fn falls_back_to_the_default() {
+        assert_eq!(negotiate_locale(None, "id"), "id");
+        assert_eq!(negotiate_locale(Some("fr, de"), "en"), "en");
+        assert_eq!(negotiate_locale(Some("*"), "id"), "id");
+        assert_eq!(negotiate_locale(Some(""), "en"), "en");
+    }
+
+    #[test]
+    fn ignores_locales_with_zero_quality() {
+        assert_eq!(negotiate_locale(Some("id;q=0, en;q=0.1"), "id"), "en");
+    }
+}
@@ -1,19 +1,34 @@
 mod api_key;
+mod dependency_wait;
 mod gracefull;
+mod grpc_health;
 mod logs;
 mod mark;
+mod message_catalog;
 mod metadata;
 // mod metrics;
 mod month;
 mod otel;
 mod parse_datetime;
+mod peer;
+mod pending_transaction_expiry;
 mod random_card_number;
+mod self_check;
+mod soft_delete_purge;
 mod trace_id;
+mod warmup;
 
 pub use self::api_key::generate_api_key;
+pub use self::dependency_wait::{check_grpc_health, wait_for_dependencies};
 pub use self::gracefull::shutdown_signal;
+pub use self::grpc_health::GrpcHealthGate;
 pub use self::logs::init_logger;
-pub use self::mark::{mask_api_key, mask_card_number};
+pub use self::mark::{
+    card_cache_key, mask_api_key, mask_card_number, mask_email, redact_connection_string,
+};
+pub use self::message_catalog::{
+    SUPPORTED_LOCALES, negotiate_locale, supported_locale, translate_message,
+};
 pub use self::metadata::MetadataInjector;
 pub use self::month::month_name;
 pub use self::otel::{Telemetry, TracingContext};
@@ -22,5 +37,10 @@ pub use self::parse_datetime::{
     naive_datetime_to_timestamp, parse_datetime, parse_expiration_datetime,
     timestamp_to_naive_date, timestamp_to_naive_datetime,
 };
-pub use self::random_card_number::random_card_number;
+pub use self::peer::peer_addr;
+pub use self::pending_transaction_expiry::spawn_pending_transaction_expiry;
+pub use self::random_card_number::{random_card_number, random_cvv};
+pub use self::self_check::{SelfCheck, check_database, check_tables};
+pub use self::soft_delete_purge::spawn_soft_delete_purge;
 pub use self::trace_id::get_trace_id;
+pub use self::warmup::{warm_up_channel, warm_up_db_pool};
@@ -1,8 +1,13 @@
-use std::{sync::OnceLock, time::Duration};
+use std::{collections::HashMap, sync::OnceLock, time::Duration};
 
+use crate::config::{OtlpProtocol, TelemetryConfig};
 use anyhow::Result;
+use axum::http::{HeaderMap, HeaderName, HeaderValue};
 use opentelemetry::{Context, global};
-use opentelemetry_otlp::{LogExporter, MetricExporter, SpanExporter, WithExportConfig};
+use opentelemetry_otlp::{
+    LogExporter, MetricExporter, SpanExporter, WithExportConfig, WithHttpConfig, WithTonicConfig,
+    tonic_types::metadata::MetadataMap,
+};
 use opentelemetry_sdk::{
     Resource,
     logs::SdkLoggerProvider,
@@ -14,7 +19,10 @@ use tokio::time::Instant;
 #[derive(Clone)]
 pub struct Telemetry {
     service_name: String,
-    otel_endpoint: String,
+    config: TelemetryConfig,
+    tracer_provider: OnceLock<SdkTracerProvider>,
+    meter_provider: OnceLock<SdkMeterProvider>,
+    logger_provider: OnceLock<SdkLoggerProvider>,
 }
 
 pub struct TracingContext {
@@ -23,10 +31,22 @@ pub struct TracingContext {
 }
 
 impl Telemetry {
-    pub fn new(service_name: impl Into<String>, otel_endpoint: String) -> Self {
+    pub fn new(service_name: impl Into<String>, config: TelemetryConfig) -> Self {
+        let service_name = service_name.into();
+
+        // Telemetry is set up before the tracing subscriber, so warnings go to stderr.
+        if config.endpoint.is_none() {
+            eprintln!(
+                "OTEL_EXPORTER_OTLP_ENDPOINT is not set; {service_name} will not export traces, metrics or logs"
+            );
+        }
+
         Self {
-            service_name: service_name.into(),
-            otel_endpoint,
+            service_name,
+            config,
+            tracer_provider: OnceLock::new(),
+            meter_provider: OnceLock::new(),
+            logger_provider: OnceLock::new(),
         }
     }
 
@@ -41,68 +61,158 @@ impl Telemetry {
             .clone()
     }
 
-    pub fn init_tracer(&self) -> SdkTracerProvider {
-        let exporter = SpanExporter::builder()
-            .with_tonic()
-            .with_endpoint(self.otel_endpoint.clone())
-            .build()
-            .expect("Failed to create span exporter");
-
-        let provider = SdkTracerProvider::builder()
-            .with_resource(self.get_resource())
-            .with_batch_exporter(exporter)
-            .build();
-
-        global::set_tracer_provider(provider.clone());
+    fn http_headers(&self) -> HashMap<String, String> {
+        self.config.headers.iter().cloned().collect()
+    }
 
-        provider
+    fn grpc_metadata(&self) -> MetadataMap {
+        let mut headers = HeaderMap::new();
+        for (key, value) in &self.config.headers {
+            match (
+                HeaderName::from_bytes(key.as_bytes()),
+                HeaderValue::from_str(value),
+            ) {
+                (Ok(name), Ok(value)) => {
+                    headers.insert(name, value);
+                }
+                _ => eprintln!("Ignoring invalid OTLP header '{key}'"),
+            }
+        }
+        MetadataMap::from_headers(headers)
     }
 
-    pub fn init_meter(&self) -> SdkMeterProvider {
-        let exporter = MetricExporter::builder()
-            .with_tonic()
-            .with_endpoint(self.otel_endpoint.clone())
-            .with_timeout(Duration::from_secs(10))
-            .build()
-            .expect("failed to build OTLP metric exporter");
+    fn build_span_exporter(&self, endpoint: String) -> Result<SpanExporter> {
+        let exporter = match self.config.protocol {
+            OtlpProtocol::Grpc => SpanExporter::builder()
+                .with_tonic()
+                .with_endpoint(endpoint)
+                .with_metadata(self.grpc_metadata())
+                .build()?,
+            OtlpProtocol::HttpProtobuf => SpanExporter::builder()
+                .with_http()
+                .with_endpoint(endpoint)
+                .with_headers(self.http_headers())
+                .build()?,
+        };
+        Ok(exporter)
+    }
 
-        let reader = PeriodicReader::builder(exporter)
-            .with_interval(Duration::from_secs(5))
-            .build();
+    fn build_metric_exporter(&self, endpoint: String) -> Result<MetricExporter> {
+        let exporter = match self.config.protocol {
+            OtlpProtocol::Grpc => MetricExporter::builder()
+                .with_tonic()
+                .with_endpoint(endpoint)
+                .with_metadata(self.grpc_metadata())
+                .with_timeout(Duration::from_secs(10))
+                .build()?,
+            OtlpProtocol::HttpProtobuf => MetricExporter::builder()
+                .with_http()
+                .with_endpoint(endpoint)
+                .with_headers(self.http_headers())
+                .with_timeout(Duration::from_secs(10))
+                .build()?,
+        };
+        Ok(exporter)
+    }
 
-        let provider = SdkMeterProvider::builder()
-            .with_resource(self.get_resource())
-            .with_reader(reader)
-            .build();
+    fn build_log_exporter(&self, endpoint: String) -> Result<LogExporter> {
+        let exporter = match self.config.protocol {
+            OtlpProtocol::Grpc => LogExporter::builder()
+                .with_tonic()
+                .with_endpoint(endpoint)
+                .with_metadata(self.grpc_metadata())
+                .build()?,
+            OtlpProtocol::HttpProtobuf => LogExporter::builder()
+                .with_http()
+                .with_endpoint(endpoint)
+                .with_headers(self.http_headers())
+                .build()?,
+        };
+        Ok(exporter)
+    }
 
-        global::set_meter_provider(provider.clone());
+    pub fn init_tracer(&self) -> SdkTracerProvider {
+        self.tracer_provider
+            .get_or_init(|| {
+                let mut builder = SdkTracerProvider::builder().with_resource(self.get_resource());
+
+                if let Some(endpoint) = self.config.signal_endpoint("/v1/traces") {
+                    match self.build_span_exporter(endpoint) {
+                        Ok(exporter) => builder = builder.with_batch_exporter(exporter),
+                        Err(e) => {
+                            eprintln!("Failed to create span exporter, traces disabled: {e:?}")
+                        }
+                    }
+                }
+
+                let provider = builder.build();
+                global::set_tracer_provider(provider.clone());
+                provider
+            })
+            .clone()
+    }
 
-        provider
+    pub fn init_meter(&self) -> SdkMeterProvider {
+        self.meter_provider
+            .get_or_init(|| {
+                let mut builder = SdkMeterProvider::builder().with_resource(self.get_resource());
+
+                if let Some(endpoint) = self.config.signal_endpoint("/v1/metrics") {
+                    match self.build_metric_exporter(endpoint) {
+                        Ok(exporter) => {
+                            let reader = PeriodicReader::builder(exporter)
+                                .with_interval(Duration::from_secs(5))
+                                .build();
+                            builder = builder.with_reader(reader);
+                        }
+                        Err(e) => {
+                            eprintln!("Failed to create metric exporter, metrics disabled: {e:?}")
+                        }
+                    }
+                }
+
+                let provider = builder.build();
+                global::set_meter_provider(provider.clone());
+                provider
+            })
+            .clone()
     }
 
     pub fn init_logger(&self) -> SdkLoggerProvider {
-        let exporter = LogExporter::builder()
-            .with_tonic()
-            .with_endpoint(self.otel_endpoint.clone())
-            .build()
-            .expect("Failed to create log exporter");
-
-        SdkLoggerProvider::builder()
-            .with_resource(self.get_resource())
-            .with_batch_exporter(exporter)
-            .build()
+        self.logger_provider
+            .get_or_init(|| {
+                let mut builder = SdkLoggerProvider::builder().with_resource(self.get_resource());
+
+                if let Some(endpoint) = self.config.signal_endpoint("/v1/logs") {
+                    match self.build_log_exporter(endpoint) {
+                        Ok(exporter) => builder = builder.with_batch_exporter(exporter),
+                        Err(e) => {
+                            eprintln!("Failed to create log exporter, log export disabled: {e:?}")
+                        }
+                    }
+                }
+
+                builder.build()
+            })
+            .clone()
     }
 
     pub async fn shutdown(self) -> Result<()> {
         let mut errors = Vec::new();
 
-        if let Err(e) = self.init_tracer().shutdown() {
+        if let Some(provider) = self.tracer_provider.get()
+            && let Err(e) = provider.shutdown()
+        {
             errors.push(format!("tracer provider: {e}"));
         }
-        if let Err(e) = self.init_meter().shutdown() {
+        if let Some(provider) = self.meter_provider.get()
+            && let Err(e) = provider.shutdown()
+        {
             errors.push(format!("meter provider: {e}"));
         }
-        if let Err(e) = self.init_logger().shutdown() {
+        if let Some(provider) = self.logger_provider.get()
+            && let Err(e) = provider.shutdown()
+        {
             errors.push(format!("logger provider: {e}"));
         }
 
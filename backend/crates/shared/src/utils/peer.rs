@@ -0,0 +1,8 @@
+use tonic::Request;
+
+pub fn peer_addr<T>(request: &Request<T>) -> String {
+    request
+        .remote_addr()
+        .map(|addr| addr.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
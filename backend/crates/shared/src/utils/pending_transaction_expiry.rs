@@ -0,0 +1,81 @@
+use crate::{
+    abstract_trait::transaction::repository::command::DynTransactionCommandRepository,
+    cache::CacheStore, config::PendingTransactionExpiryConfig,
+};
+use chrono::Utc;
+use std::{collections::BTreeSet, sync::Arc};
+use tracing::{error, info};
+
+pub fn spawn_pending_transaction_expiry(
+    command: DynTransactionCommandRepository,
+    cache_store: Arc<CacheStore>,
+    config: &PendingTransactionExpiryConfig,
+) {
+    if !config.enabled {
+        return;
+    }
+
+    let max_age = config.max_age();
+    let max_age_secs = config.max_age_secs;
+    let batch_size = config.batch_size;
+    let every = config.interval();
+
+    info!("⏳ Expiring transactions pending for more than {max_age_secs}s every {every:?}");
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+        loop {
+            interval.tick().await;
+
+            let cutoff = Utc::now().naive_utc() - max_age;
+
+            let expired = match command.expire_pending_before(cutoff, batch_size).await {
+                Ok(expired) => expired,
+                Err(e) => {
+                    error!("❌ Pending transaction expiry failed: {e:?}");
+                    continue;
+                }
+            };
+
+            if expired.is_empty() {
+                continue;
+            }
+
+            for tx in &expired {
+                info!(
+                    target: "audit",
+                    transaction_id = tx.transaction_id,
+                    amount = tx.amount,
+                    cutoff = %cutoff,
+                    "pending transaction expired and saldo released"
+                );
+            }
+
+            let cards: BTreeSet<&str> = expired.iter().map(|tx| tx.card_number.as_str()).collect();
+            let mut keys: Vec<String> = cards
+                .iter()
+                .flat_map(|card| {
+                    [
+                        format!("saldo:find_by_card:{card}"),
+                        format!("transaction:find_by_card:{card}"),
+                    ]
+                })
+                .collect();
+            keys.extend(
+                [
+                    "transaction:find_all:*",
+                    "transaction:find_by_active:*",
+                    "transaction:search:*",
+                ]
+                .map(String::from),
+            );
+            cache_store.invalidate_after_write(&keys).await;
+
+            info!(
+                "✅ Expired {} pending transactions across {} cards",
+                expired.len(),
+                cards.len()
+            );
+        }
+    });
+}
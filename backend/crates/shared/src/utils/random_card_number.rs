@@ -17,3 +17,7 @@ pub fn random_card_number() -> Result<String, Box<dyn std::error::Error>> {
         Err("Generated number is invalid".into())
     }
 }
+
+pub fn random_cvv() -> String {
+    format!("{:03}", rng().random_range(0..1000))
+}
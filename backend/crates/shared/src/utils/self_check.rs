@@ -0,0 +1,73 @@
+use crate::config::ConnectionPool;
+use anyhow::{Result, bail};
+use tracing::{error, info};
+
+pub struct SelfCheck {
+    service: &'static str,
+    failures: Vec<String>,
+}
+
+impl SelfCheck {
+    pub fn new(service: &'static str) -> Self {
+        Self {
+            service,
+            failures: Vec::new(),
+        }
+    }
+
+    pub fn record(&mut self, name: &str, result: Result<(), String>) {
+        match result {
+            Ok(()) => info!("✅ [{}] self-check '{name}' passed", self.service),
+            Err(reason) => {
+                error!("❌ [{}] self-check '{name}' failed: {reason}", self.service);
+                self.failures.push(format!("{name}: {reason}"));
+            }
+        }
+    }
+
+    pub fn finish(self) -> Result<()> {
+        if !self.failures.is_empty() {
+            bail!(
+                "{} self-check failed: {}",
+                self.service,
+                self.failures.join("; ")
+            );
+        }
+
+        info!("✅ [{}] all startup self-checks passed", self.service);
+        Ok(())
+    }
+}
+
+pub async fn check_database(pool: &ConnectionPool) -> Result<(), String> {
+    sqlx::query("SELECT 1")
+        .execute(pool)
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("database is unreachable ({e})"))
+}
+
+pub async fn check_tables(pool: &ConnectionPool, tables: &[&str]) -> Result<(), String> {
+    let mut missing = Vec::new();
+
+    for table in tables {
+        let exists: bool = sqlx::query_scalar("SELECT to_regclass($1) IS NOT NULL")
+            .bind(*table)
+            .fetch_one(pool)
+            .await
+            .map_err(|e| format!("failed to look up table '{table}' ({e})"))?;
+
+        if !exists {
+            missing.push(*table);
+        }
+    }
+
+    if missing.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "missing tables {}; have migrations been run?",
+            missing.join(", ")
+        ))
+    }
+}
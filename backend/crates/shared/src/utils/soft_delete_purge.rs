@@ -0,0 +1,60 @@
+use crate::{
+    config::{ConnectionPool, SoftDeleteRetentionConfig},
+    repository::bulk::purge_trashed_before,
+};
+use chrono::Utc;
+use tracing::{error, info, warn};
+
+pub fn spawn_soft_delete_purge(
+    db: ConnectionPool,
+    config: &SoftDeleteRetentionConfig,
+    entity: &'static str,
+) {
+    if !config.should_purge(entity) {
+        return;
+    }
+    let Some(id_column) = SoftDeleteRetentionConfig::id_column(entity) else {
+        return;
+    };
+
+    let retention = config.retention();
+    let retention_days = config.retention_days;
+    let every = config.interval();
+
+    info!("🗑️  Purging {entity} trashed for more than {retention_days} days every {every:?}");
+
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(every);
+        loop {
+            interval.tick().await;
+
+            let cutoff = Utc::now().naive_utc() - retention;
+
+            match purge_trashed_before(&db, entity, id_column, cutoff).await {
+                Ok(result) => {
+                    info!(
+                        target: "audit",
+                        entity,
+                        cutoff = %cutoff,
+                        eligible = result.total,
+                        purged = result.affected,
+                        failed = result.errors.len(),
+                        "soft-delete purge completed"
+                    );
+                    for item in &result.errors {
+                        warn!(
+                            target: "audit",
+                            entity,
+                            id = item.id,
+                            reason = %item.reason,
+                            "soft-delete purge skipped row"
+                        );
+                    }
+                }
+                Err(e) => {
+                    error!("❌ Soft-delete purge of {entity} failed: {e:?}");
+                }
+            }
+        }
+    });
+}
@@ -0,0 +1,71 @@
+use crate::config::{ConnectionPool, WarmupConfig};
+use anyhow::{Context, Result};
+use std::time::Instant;
+use tonic::transport::Channel;
+use tower::Service;
+use tracing::{info, warn};
+
+pub async fn warm_up_db_pool(pool: &ConnectionPool, config: &WarmupConfig) {
+    if !config.enabled || config.db_connections == 0 {
+        return;
+    }
+
+    let target = config
+        .db_connections
+        .min(pool.options().get_max_connections());
+    let started = Instant::now();
+
+    let result = tokio::time::timeout(config.timeout(), async {
+        let mut conns = Vec::with_capacity(target as usize);
+        for _ in 0..target {
+            let mut conn = pool
+                .acquire()
+                .await
+                .context("failed to acquire connection")?;
+            sqlx::query("SELECT 1")
+                .execute(&mut *conn)
+                .await
+                .context("failed to run warm-up query")?;
+            conns.push(conn);
+        }
+        Ok::<_, anyhow::Error>(conns.len())
+    })
+    .await;
+
+    match result {
+        Ok(Ok(warmed)) => info!(
+            "🔥 Database pool warmed up with {warmed} connections in {:?}",
+            started.elapsed()
+        ),
+        Ok(Err(e)) => warn!("⚠️ Database pool warm-up failed: {e:?}"),
+        Err(_) => warn!(
+            "⚠️ Database pool warm-up timed out after {:?}",
+            config.timeout()
+        ),
+    }
+}
+
+pub async fn warm_up_channel(channel: &Channel, service: &str, config: &WarmupConfig) {
+    if !config.enabled {
+        return;
+    }
+
+    let started = Instant::now();
+    let mut channel = channel.clone();
+
+    let result: Result<()> = tokio::time::timeout(
+        config.timeout(),
+        std::future::poll_fn(|cx| channel.poll_ready(cx)),
+    )
+    .await
+    .map_err(|_| anyhow::anyhow!("timed out after {:?}", config.timeout()))
+    .and_then(|ready| ready.context("channel is not ready"));
+
+    match result {
+        Ok(()) => info!(
+            "🔥 gRPC channel to {service} warmed up in {:?}",
+            started.elapsed()
+        ),
+        Err(e) => warn!("⚠️ gRPC channel warm-up for {service} failed: {e:?}"),
+    }
+}
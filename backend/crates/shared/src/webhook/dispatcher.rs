@@ -0,0 +1,313 @@
+use super::WebhookPolicy;
+use crate::{
+    abstract_trait::webhook::WebhookDispatcherTrait, errors::ServiceError, events::DomainEvent,
+};
+use async_trait::async_trait;
+use chrono::Utc;
+use serde::Serialize;
+use std::{
+    collections::HashMap,
+    net::SocketAddr,
+    sync::{
+        Arc, Mutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+use tokio::{sync::mpsc, task::JoinHandle, time::Instant};
+use tracing::{error, info, warn};
+
+type DeliveryLane = Arc<tokio::sync::Mutex<()>>;
+
+struct BatchLane {
+    tx: mpsc::Sender<DomainEvent>,
+    task: JoinHandle<()>,
+}
+
+const MAX_DELIVERY_LANES: usize = 1024;
+const BATCH_LANE_CAPACITY: usize = 1024;
+const BATCH_LANE_IDLE_TIMEOUT: Duration = Duration::from_secs(300);
+const BATCH_RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+#[derive(Debug, Clone, Copy)]
+struct WebhookBatching {
+    max_size: usize,
+    max_wait: Duration,
+    max_attempts: u32,
+}
+
+#[derive(Clone)]
+pub struct WebhookDispatcher {
+    client: reqwest::Client,
+    timeout: Duration,
+    policy: WebhookPolicy,
+    ordered: bool,
+    lanes: Arc<Mutex<HashMap<String, DeliveryLane>>>,
+    sequence: Arc<AtomicU64>,
+    batching: Option<WebhookBatching>,
+    batch_lanes: Arc<Mutex<HashMap<String, BatchLane>>>,
+}
+
+impl WebhookDispatcher {
+    pub fn new(
+        timeout: Duration,
+        policy: WebhookPolicy,
+        ordered: bool,
+    ) -> Result<Self, ServiceError> {
+        let client = Self::build_client(timeout, None)?;
+
+        Ok(Self {
+            client,
+            timeout,
+            policy,
+            ordered,
+            lanes: Arc::new(Mutex::new(HashMap::new())),
+            sequence: Arc::new(AtomicU64::new(Self::sequence_seed())),
+            batching: None,
+            batch_lanes: Arc::new(Mutex::new(HashMap::new())),
+        })
+    }
+
+    fn build_client(
+        timeout: Duration,
+        pinned: Option<(&str, SocketAddr)>,
+    ) -> Result<reqwest::Client, ServiceError> {
+        let mut builder = reqwest::Client::builder()
+            .timeout(timeout)
+            .redirect(reqwest::redirect::Policy::none());
+
+        if let Some((host, addr)) = pinned {
+            builder = builder.resolve(host, addr);
+        }
+
+        builder.build().map_err(|e| {
+            ServiceError::InternalServerError(format!("failed to build webhook client: {e}"))
+        })
+    }
+
+    pub fn with_batching(mut self, max_size: usize, max_wait: Duration, max_attempts: u32) -> Self {
+        self.batching = (max_size > 1).then_some(WebhookBatching {
+            max_size,
+            max_wait,
+            max_attempts: max_attempts.max(1),
+        });
+        self
+    }
+
+    /// Sequence numbers start from the current time in microseconds, so a
+    /// restarted process keeps counting upward instead of repeating values
+    /// an endpoint has already seen. This holds only while the clock does not
+    /// go backwards, and replicas do not coordinate with each other.
+    fn sequence_seed() -> u64 {
+        u64::try_from(Utc::now().timestamp_micros()).unwrap_or(0)
+    }
+
+    fn next_sequence(&self) -> u64 {
+        self.sequence.fetch_add(1, Ordering::Relaxed) + 1
+    }
+
+    fn lane(&self, url: &str) -> DeliveryLane {
+        let mut lanes = self.lanes.lock().unwrap_or_else(|e| e.into_inner());
+
+        if lanes.len() >= MAX_DELIVERY_LANES && !lanes.contains_key(url) {
+            // Only the map holds a lane nobody is delivering on, so it can be
+            // dropped and recreated later without losing any ordering.
+            lanes.retain(|_, lane| Arc::strong_count(lane) > 1);
+        }
+
+        lanes.entry(url.to_string()).or_default().clone()
+    }
+
+    fn batch_lane(&self, url: &str, batching: WebhookBatching) -> mpsc::Sender<DomainEvent> {
+        let mut lanes = self.batch_lanes.lock().unwrap_or_else(|e| e.into_inner());
+
+        // Lanes close themselves after sitting idle; forget them here so the
+        // map only holds endpoints that are still receiving events.
+        lanes.retain(|_, lane| !lane.tx.is_closed());
+
+        if let Some(lane) = lanes.get(url) {
+            return lane.tx.clone();
+        }
+
+        let (tx, rx) = mpsc::channel(BATCH_LANE_CAPACITY);
+        let task = tokio::spawn(self.clone().run_batch_lane(url.to_string(), rx, batching));
+        lanes.insert(
+            url.to_string(),
+            BatchLane {
+                tx: tx.clone(),
+                task,
+            },
+        );
+        tx
+    }
+
+    /// Closes every batch lane and waits for the events already queued on
+    /// them to be delivered.
+    pub async fn shutdown(&self) {
+        let lanes: Vec<(String, BatchLane)> = self
+            .batch_lanes
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .drain()
+            .collect();
+
+        if lanes.is_empty() {
+            return;
+        }
+
+        info!("📨 Flushing {} webhook batch lanes", lanes.len());
+
+        for (url, BatchLane { tx, task }) in lanes {
+            drop(tx);
+            if let Err(e) = task.await {
+                error!("❌ Webhook batch lane for {url} stopped abnormally: {e}");
+            }
+        }
+    }
+
+    async fn run_batch_lane(
+        self,
+        url: String,
+        mut rx: mpsc::Receiver<DomainEvent>,
+        batching: WebhookBatching,
+    ) {
+        loop {
+            let first = match tokio::time::timeout(BATCH_LANE_IDLE_TIMEOUT, rx.recv()).await {
+                Ok(Some(event)) => event,
+                Ok(None) => break,
+                Err(_) => {
+                    info!("📨 Closing idle webhook batch lane for {url}");
+                    rx.close();
+                    continue;
+                }
+            };
+
+            let deadline = Instant::now() + batching.max_wait;
+            let mut batch = vec![first];
+
+            while batch.len() < batching.max_size {
+                match tokio::time::timeout_at(deadline, rx.recv()).await {
+                    Ok(Some(event)) => batch.push(event),
+                    Ok(None) | Err(_) => break,
+                }
+            }
+
+            if self.ordered {
+                for event in &mut batch {
+                    event.sequence = Some(self.next_sequence());
+                }
+            }
+
+            self.deliver_batch(&url, &batch, batching.max_attempts)
+                .await;
+        }
+    }
+
+    async fn deliver_batch(&self, url: &str, batch: &[DomainEvent], max_attempts: u32) {
+        let label = format!("batch of {}", batch.len());
+        let mut delay = BATCH_RETRY_BASE_DELAY;
+
+        for attempt in 1..=max_attempts {
+            match self.deliver(url, &label, batch).await {
+                Ok(()) => return,
+                Err(e) if attempt < max_attempts => {
+                    warn!(
+                        "⚠️ Webhook {label} attempt {attempt}/{max_attempts} failed, retrying in {delay:?}: {e}"
+                    );
+                    tokio::time::sleep(delay).await;
+                    delay *= 2;
+                }
+                Err(e) => {
+                    let events: Vec<String> = batch
+                        .iter()
+                        .map(|event| match event.sequence {
+                            Some(sequence) => format!("{}#{sequence}", event.event_type),
+                            None => event.event_type.clone(),
+                        })
+                        .collect();
+                    error!(
+                        "❌ Dropped webhook {label} after {max_attempts} attempts: {e}; events: {}",
+                        events.join(", ")
+                    );
+                }
+            }
+        }
+    }
+
+    async fn deliver<T: Serialize + ?Sized>(
+        &self,
+        url: &str,
+        label: &str,
+        body: &T,
+    ) -> Result<(), ServiceError> {
+        let target = self.policy.check_target(url).await?;
+
+        // Connect to the address that passed the private-network check, so a
+        // second DNS answer cannot redirect the request to an internal host.
+        let client = match &target.pinned {
+            Some((host, addr)) => Self::build_client(self.timeout, Some((host, *addr)))?,
+            None => self.client.clone(),
+        };
+
+        let response = client
+            .post(target.url)
+            .json(body)
+            .send()
+            .await
+            .map_err(|e| {
+                error!("❌ Webhook {label} delivery failed: {e}");
+                ServiceError::Custom(format!("webhook delivery failed: {e}"))
+            })?;
+
+        let status = response.status();
+        if !status.is_success() {
+            error!("❌ Webhook {label} rejected by receiver with status {status}");
+            return Err(ServiceError::Custom(format!(
+                "webhook receiver responded with status {status}"
+            )));
+        }
+
+        info!("📨 Webhook {label} delivered");
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl WebhookDispatcherTrait for WebhookDispatcher {
+    async fn dispatch(&self, url: &str, event: &DomainEvent) -> Result<(), ServiceError> {
+        if let Some(batching) = self.batching {
+            // A lane can close for idleness between lookup and send; the event
+            // comes back in the error and goes onto a fresh lane.
+            let event = match self.batch_lane(url, batching).send(event.clone()).await {
+                Ok(()) => return Ok(()),
+                Err(mpsc::error::SendError(event)) => event,
+            };
+
+            return self
+                .batch_lane(url, batching)
+                .send(event)
+                .await
+                .map_err(|_| ServiceError::Custom("webhook batch lane closed".to_string()));
+        }
+
+        if !self.ordered {
+            return self.deliver(url, &event.event_type, event).await;
+        }
+
+        let lane = self.lane(url);
+        let _guard = lane.lock().await;
+        let sequence = self.next_sequence();
+
+        let event = DomainEvent {
+            sequence: Some(sequence),
+            ..event.clone()
+        };
+
+        let label = format!("{} (sequence {})", event.event_type, sequence);
+        self.deliver(url, &label, &event).await
+    }
+
+    async fn shutdown(&self) {
+        WebhookDispatcher::shutdown(self).await;
+    }
+}
@@ -0,0 +1,5 @@
+mod dispatcher;
+mod policy;
+
+pub use self::dispatcher::WebhookDispatcher;
+pub use self::policy::{WebhookPolicy, WebhookTarget};
@@ -65,6 +65,7 @@ use shared::{
         },
     },
 };
+use shared::{config::SoftDeleteRetentionConfig, utils::spawn_soft_delete_purge};
 use std::{fmt, sync::Arc, time::Duration};
 use tokio::sync::Semaphore;
 use tracing::{error, info, warn};
@@ -201,6 +202,7 @@ impl DependenciesInject {
 
         Self::spawn_monitoring_task(Arc::clone(&shared.cache_store));
         Self::spawn_cleanup_task(Arc::clone(&shared.cache_store));
+        spawn_soft_delete_purge(db.clone(), &SoftDeleteRetentionConfig::from_env(), "topups");
 
         Ok(Self {
             topup_command,
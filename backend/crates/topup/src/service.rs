@@ -17,7 +17,7 @@ use shared::{
         UpdateTopupRequest as DomainUpdateTopupRequst, YearMonthMethod, YearTopupStatusCardNumber,
     },
     errors::{AppErrorGrpc, CircuitBreakerError},
-    utils::{mask_api_key, mask_card_number},
+    utils::{mask_api_key, mask_card_number, peer_addr},
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
@@ -59,6 +59,7 @@ impl TopupServiceImpl {
 impl TopupService for TopupServiceImpl {
     #[instrument(skip(self, request), fields(
         method = "find_all_topup",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -74,6 +75,7 @@ impl TopupService for TopupServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -131,6 +133,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_all_topup_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
@@ -206,7 +209,7 @@ impl TopupService for TopupServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_id_topup", topup_id = request.get_ref().topup_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "find_by_id_topup", peer = %peer_addr(&request), topup_id = request.get_ref().topup_id), level = "info")]
     async fn find_by_id_topup(
         &self,
         request: Request<FindByIdTopupRequest>,
@@ -260,6 +263,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_topup_status_success",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         month = request.get_ref().month
     ), level = "info")]
@@ -329,6 +333,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_topup_status_success",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_yearly_topup_status_success(
@@ -384,6 +389,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_topup_status_failed",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         month = request.get_ref().month
     ), level = "info")]
@@ -453,6 +459,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_topup_status_failed",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_yearly_topup_status_failed(
@@ -507,6 +514,7 @@ impl TopupService for TopupServiceImpl {
     }
     #[instrument(skip(self, request), fields(
         method = "find_monthly_topup_status_success_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year,
         month = request.get_ref().month
@@ -581,6 +589,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_topup_status_success_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -652,6 +661,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_topup_status_failed_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year,
         month = request.get_ref().month
@@ -726,6 +736,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_topup_status_failed_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -797,6 +808,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_topup_methods",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_monthly_topup_methods(
@@ -852,6 +864,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_topup_methods",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_yearly_topup_methods(
@@ -907,6 +920,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_topup_amounts",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_monthly_topup_amounts(
@@ -962,6 +976,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_topup_amounts",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_yearly_topup_amounts(
@@ -1016,6 +1031,7 @@ impl TopupService for TopupServiceImpl {
     }
     #[instrument(skip(self, request), fields(
         method = "find_monthly_topup_methods_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -1087,6 +1103,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_topup_methods_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -1158,6 +1175,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_topup_amounts_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -1229,6 +1247,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_topup_amounts_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -1300,6 +1319,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_card_number_topup",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty
     ), level = "info")]
     async fn find_by_card_number_topup(
@@ -1360,6 +1380,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_active",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1375,6 +1396,7 @@ impl TopupService for TopupServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -1432,6 +1454,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_trashed",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1447,6 +1470,7 @@ impl TopupService for TopupServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: (!req.sort_by.is_empty()).then(|| req.sort_by.clone()),
         };
 
         let result = self
@@ -1503,6 +1527,7 @@ impl TopupService for TopupServiceImpl {
     }
     #[instrument(skip(self, request), fields(
         method = "create_topup",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty
     ), level = "info")]
     async fn create_topup(
@@ -1564,7 +1589,7 @@ impl TopupService for TopupServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "update_topup", topup_id = request.get_ref().topup_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "update_topup", peer = %peer_addr(&request), topup_id = request.get_ref().topup_id), level = "info")]
     async fn update_topup(
         &self,
         request: Request<UpdateTopupRequest>,
@@ -1623,7 +1648,7 @@ impl TopupService for TopupServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "trashed_topup", topup_id = request.get_ref().topup_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "trashed_topup", peer = %peer_addr(&request), topup_id = request.get_ref().topup_id), level = "info")]
     async fn trashed_topup(
         &self,
         request: Request<FindByIdTopupRequest>,
@@ -1675,7 +1700,7 @@ impl TopupService for TopupServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "restore_topup", topup_id = request.get_ref().topup_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "restore_topup", peer = %peer_addr(&request), topup_id = request.get_ref().topup_id), level = "info")]
     async fn restore_topup(
         &self,
         request: Request<FindByIdTopupRequest>,
@@ -1727,7 +1752,7 @@ impl TopupService for TopupServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "delete_topup_permanent", topup_id = request.get_ref().topup_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "delete_topup_permanent", peer = %peer_addr(&request), topup_id = request.get_ref().topup_id), level = "info")]
     async fn delete_topup_permanent(
         &self,
         request: Request<FindByIdTopupRequest>,
@@ -1780,7 +1805,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(
         skip(self, _request),
-        fields(method = "restore_all_topup"),
+        fields(method = "restore_all_topup", peer = %peer_addr(&_request)),
         level = "info"
     )]
     async fn restore_all_topup(
@@ -1804,6 +1829,7 @@ impl TopupService for TopupServiceImpl {
                 Ok(Response::new(ApiResponseTopupAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
@@ -1829,7 +1855,7 @@ impl TopupService for TopupServiceImpl {
 
     #[instrument(
         skip(self, _request),
-        fields(method = "delete_all_topup_permanent"),
+        fields(method = "delete_all_topup_permanent", peer = %peer_addr(&_request)),
         level = "info"
     )]
     async fn delete_all_topup_permanent(
@@ -1853,6 +1879,7 @@ impl TopupService for TopupServiceImpl {
                 Ok(Response::new(ApiResponseTopupAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
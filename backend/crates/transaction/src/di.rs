@@ -1,12 +1,15 @@
 use anyhow::{Context, Result};
 use shared::{
     abstract_trait::{
+        anomaly::{repository::DynAnomalyRepository, service::DynAnomalyDetectionService},
         card::repository::query::DynCardQueryRepository,
         merchant::repository::query::DynMerchantQueryRepository,
         saldo::repository::{command::DynSaldoCommandRepository, query::DynSaldoQueryRepository},
         transaction::{
             repository::{
                 command::DynTransactionCommandRepository,
+                fee::DynTransactionFeeRepository,
+                metadata::DynTransactionMetadataRepository,
                 query::DynTransactionQueryRepository,
                 stats::{
                     amount::DynTransactionStatsAmountRepository,
@@ -34,17 +37,27 @@ use shared::{
                 },
             },
         },
+        webhook::DynWebhookDispatcher,
     },
     cache::CacheStore,
-    config::{ConnectionPool, RedisPool},
+    config::{
+        AnomalyConfig, ConnectionPool, EventsConfig, PaymentMethodsConfig,
+        PendingTransactionExpiryConfig, RedisPool, TransactionFeeConfig, WebhookPolicyConfig,
+        WriteLimiterConfig,
+    },
     context::shared_resources::SharedResources,
+    domain::requests::anomaly::{AnomalyMetric, AnomalyScope},
+    events::EventPublisher,
     observability::{CacheMetricsCore, TracingMetricsCore},
     repository::{
+        anomaly::AnomalyRepository,
         card::query::CardQueryRepository,
         merchant::query::MerchantQueryRepository,
         saldo::{command::SaldoCommandRepository, query::SaldoQueryRepository},
         transaction::{
             command::TransactionCommandRepository,
+            fee::TransactionFeeRepository,
+            metadata::TransactionMetadataRepository,
             query::TransactionQueryRepository,
             stats::{
                 amount::TransactionStatsAmountRepository, method::TransactionStatsMethodRepository,
@@ -57,19 +70,24 @@ use shared::{
             },
         },
     },
-    service::transaction::{
-        command::{TransactionCommandService, TransactionCommandServiceDeps},
-        query::TransactionQueryService,
-        stats::{
-            amount::TransactionStatsAmountService, method::TransactionStatsMethodService,
-            status::TransactionStatsStatusService,
-        },
-        statsbycard::{
-            amount::TransactionStatsAmountByCardService,
-            method::TransactionStatsMethodByCardService,
-            status::TransactionStatsStatusByCardService,
+    service::{
+        anomaly::AnomalyDetectionService,
+        transaction::{
+            command::{TransactionCommandService, TransactionCommandServiceDeps},
+            query::TransactionQueryService,
+            stats::{
+                amount::TransactionStatsAmountService, method::TransactionStatsMethodService,
+                status::TransactionStatsStatusService,
+            },
+            statsbycard::{
+                amount::TransactionStatsAmountByCardService,
+                method::TransactionStatsMethodByCardService,
+                status::TransactionStatsStatusByCardService,
+            },
         },
     },
+    utils::spawn_pending_transaction_expiry,
+    webhook::{WebhookDispatcher, WebhookPolicy},
 };
 use std::{fmt, sync::Arc, time::Duration};
 use tokio::sync::Semaphore;
@@ -91,6 +109,8 @@ pub struct DependenciesInject {
     pub transaction_stats_amount_by_card: DynTransactionStatsAmountByCardService,
     pub transaction_stats_method_by_card: DynTransactionStatsMethodByCardService,
     pub transaction_stats_status_by_card: DynTransactionStatsStatusByCardService,
+    pub anomaly_detection: DynAnomalyDetectionService,
+    pub webhook: Option<DynWebhookDispatcher>,
     pub cache_store: Arc<CacheStore>,
     pub request_limiter: Arc<Semaphore>,
 }
@@ -125,6 +145,11 @@ impl fmt::Debug for DependenciesInject {
             .field(
                 "transaction_stats_status_by_card",
                 &"DynTransactionStatsStatusByCardService",
+            )
+            .field("anomaly_detection", &"DynAnomalyDetectionService")
+            .field(
+                "webhook",
+                &self.webhook.as_ref().map(|_| "DynWebhookDispatcher"),
             );
 
         debug_struct.finish()
@@ -148,9 +173,20 @@ impl DependenciesInject {
         let transaction_query_repo =
             Arc::new(TransactionQueryRepository::new(db.clone())) as DynTransactionQueryRepository;
 
+        let transaction_fee_repo =
+            Arc::new(TransactionFeeRepository::new(db.clone())) as DynTransactionFeeRepository;
+
+        let transaction_metadata_repo = Arc::new(TransactionMetadataRepository::new(db.clone()))
+            as DynTransactionMetadataRepository;
+
         let transaction_query = Arc::new(
-            TransactionQueryService::new(transaction_query_repo.clone(), &shared)
-                .context("failed to initialize transaction query service")?,
+            TransactionQueryService::new(
+                transaction_query_repo.clone(),
+                transaction_fee_repo.clone(),
+                transaction_metadata_repo.clone(),
+                &shared,
+            )
+            .context("failed to initialize transaction query service")?,
         ) as DynTransactionQueryService;
 
         let transaction_command_repo = Arc::new(TransactionCommandRepository::new(db.clone()))
@@ -171,6 +207,17 @@ impl DependenciesInject {
             saldo_query: saldo_query_repo,
             saldo_command: saldo_command_repo,
             card_query: card_query_repo,
+            fee: transaction_fee_repo,
+            fee_config: TransactionFeeConfig::from_env()
+                .context("failed to load transaction fee config")?,
+            payment_methods: PaymentMethodsConfig::from_env()
+                .context("failed to load payment methods config")?,
+            events: EventPublisher::from_config(
+                &EventsConfig::from_env().context("failed to load events config")?,
+            ),
+            max_concurrent_writes: WriteLimiterConfig::from_env()
+                .context("failed to load write limiter config")?
+                .max_concurrent_writes,
         };
         let transaction_command = Arc::new(
             TransactionCommandService::new(command_deps, &shared)
@@ -219,8 +266,59 @@ impl DependenciesInject {
                 .context("failed to initialize transaction stats status by card service")?,
         ) as DynTransactionStatsStatusByCardService;
 
+        let anomaly_config =
+            AnomalyConfig::from_env().context("failed to load anomaly detection config")?;
+        let webhook_config =
+            WebhookPolicyConfig::from_env().context("failed to load webhook policy config")?;
+        let ordered_delivery = webhook_config.ordered_delivery;
+        let batch_max_size = webhook_config.batch_max_size;
+        let batch_max_wait = Duration::from_millis(webhook_config.batch_max_wait_ms);
+        let batch_max_attempts = webhook_config.batch_max_attempts;
+        let webhook_policy = WebhookPolicy::new(webhook_config);
+        let webhook = match &anomaly_config.webhook_url {
+            Some(url) => {
+                webhook_policy
+                    .validate_url(url)
+                    .context("ANOMALY_WEBHOOK_URL is not an allowed webhook target")?;
+
+                Some(Arc::new(
+                    WebhookDispatcher::new(
+                        Duration::from_secs(5),
+                        webhook_policy,
+                        ordered_delivery,
+                    )
+                    .context("failed to initialize webhook dispatcher")?
+                    .with_batching(
+                        batch_max_size,
+                        batch_max_wait,
+                        batch_max_attempts,
+                    ),
+                ) as DynWebhookDispatcher)
+            }
+            None => None,
+        };
+        let anomaly_repo = Arc::new(AnomalyRepository::new(db.clone())) as DynAnomalyRepository;
+        let anomaly_detection = Arc::new(
+            AnomalyDetectionService::new(
+                anomaly_repo,
+                webhook.clone(),
+                anomaly_config.clone(),
+                &shared,
+            )
+            .context("failed to initialize anomaly detection service")?,
+        ) as DynAnomalyDetectionService;
+
         Self::spawn_monitoring_task(Arc::clone(&shared.cache_store));
         Self::spawn_cleanup_task(Arc::clone(&shared.cache_store));
+        spawn_pending_transaction_expiry(
+            transaction_command_repo.clone(),
+            Arc::clone(&shared.cache_store),
+            &PendingTransactionExpiryConfig::from_env(),
+        );
+
+        if anomaly_config.enabled {
+            Self::spawn_anomaly_task(anomaly_detection.clone(), anomaly_config.check_interval());
+        }
 
         Ok(Self {
             transaction_command,
@@ -231,6 +329,8 @@ impl DependenciesInject {
             transaction_stats_amount_by_card,
             transaction_stats_method_by_card,
             transaction_stats_status_by_card,
+            anomaly_detection,
+            webhook,
             request_limiter: Arc::new(Semaphore::new(1000)),
             cache_store: shared.cache_store,
         })
@@ -254,6 +354,23 @@ impl DependenciesInject {
         });
     }
 
+    fn spawn_anomaly_task(detector: DynAnomalyDetectionService, every: Duration) {
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(every);
+            loop {
+                interval.tick().await;
+                for metric in [AnomalyMetric::TotalAmount, AnomalyMetric::Count] {
+                    if let Err(e) = detector
+                        .detect_anomaly(AnomalyScope::Transaction, metric)
+                        .await
+                    {
+                        error!("❌ Anomaly check for transaction {metric} failed: {e}");
+                    }
+                }
+            }
+        });
+    }
+
     fn spawn_cleanup_task(cache: Arc<CacheStore>) {
         tokio::spawn(async move {
             let mut interval = tokio::time::interval(Duration::from_secs(120));
@@ -9,8 +9,8 @@ use genproto::transaction::{
     FindAllTransactionCardNumberRequest, FindAllTransactionRequest, FindByIdTransactionRequest,
     FindByYearCardNumberTransactionRequest, FindMonthlyTransactionStatus,
     FindMonthlyTransactionStatusCardNumber, FindTransactionByMerchantIdRequest,
-    FindYearTransactionStatus, FindYearTransactionStatusCardNumber, UpdateTransactionRequest,
-    transaction_service_server::TransactionService,
+    FindYearTransactionStatus, FindYearTransactionStatusCardNumber, SearchTransactionsRequest,
+    UpdateTransactionRequest, transaction_service_server::TransactionService,
 };
 use std::sync::Arc;
 
@@ -19,11 +19,12 @@ use shared::{
     domain::requests::transaction::{
         CreateTransactionRequest as DomainCreateTransactionRequest, FindAllTransactionCardNumber,
         FindAllTransactions, MonthStatusTransaction, MonthStatusTransactionCardNumber,
-        MonthYearPaymentMethod, UpdateTransactionRequest as DomainUpdateTransactionRequest,
+        MonthYearPaymentMethod, SearchTransactions,
+        UpdateTransactionRequest as DomainUpdateTransactionRequest,
         YearStatusTransactionCardNumber,
     },
     errors::{AppErrorGrpc, CircuitBreakerError},
-    utils::{mask_card_number, timestamp_to_naive_datetime},
+    utils::{mask_card_number, peer_addr, timestamp_to_naive_date, timestamp_to_naive_datetime},
 };
 use tonic::{Request, Response, Status};
 use tracing::{error, info, instrument, warn};
@@ -64,6 +65,7 @@ impl TransactionServiceImpl {
 impl TransactionService for TransactionServiceImpl {
     #[instrument(skip(self, request), fields(
         method = "find_all_transaction",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -79,6 +81,7 @@ impl TransactionService for TransactionServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -136,6 +139,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_all_transaction_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
@@ -211,7 +215,92 @@ impl TransactionService for TransactionServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_id_transaction", transaction_id = request.get_ref().transaction_id), level = "info")]
+    #[instrument(skip(self, request), fields(
+        method = "search_transactions",
+        peer = %peer_addr(&request),
+        page = request.get_ref().page,
+        page_size = request.get_ref().page_size
+    ), level = "info")]
+    async fn search_transactions(
+        &self,
+        request: Request<SearchTransactionsRequest>,
+    ) -> Result<Response<ApiResponsePaginationTransaction>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let non_empty = |v: String| if v.trim().is_empty() { None } else { Some(v) };
+
+        let domain_req = SearchTransactions {
+            page: req.page,
+            page_size: req.page_size,
+            status: non_empty(req.status),
+            payment_method: non_empty(req.payment_method),
+            min_amount: req.min_amount,
+            max_amount: req.max_amount,
+            merchant_id: req.merchant_id,
+            card_number: non_empty(req.card_number),
+            start_date: timestamp_to_naive_date(req.start_date),
+            end_date: timestamp_to_naive_date(req.end_date),
+            sort_by: non_empty(req.sort_by),
+            sort_dir: non_empty(req.sort_dir),
+            metadata_key: non_empty(req.metadata_key),
+            metadata_value: non_empty(req.metadata_value),
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .transaction_query
+                    .search(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponsePaginationTransaction {
+                    data: api_response.data.into_iter().map(Into::into).collect(),
+                    pagination: Some(api_response.pagination.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    page = domain_req.page,
+                    page_size = domain_req.page_size,
+                    "search_transactions success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            page = domain_req.page,
+                            page_size = domain_req.page_size,
+                            "search_transactions rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(
+                            page = domain_req.page,
+                            page_size = domain_req.page_size,
+                            error = %inner,
+                            "search_transactions failed"
+                        );
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "find_by_id_transaction", peer = %peer_addr(&request), transaction_id = request.get_ref().transaction_id), level = "info")]
     async fn find_by_id_transaction(
         &self,
         request: Request<FindByIdTransactionRequest>,
@@ -267,6 +356,7 @@ impl TransactionService for TransactionServiceImpl {
     }
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transaction_status_success",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         month = request.get_ref().month
     ), level = "info")]
@@ -331,6 +421,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transaction_status_success",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_yearly_transaction_status_success(
@@ -389,6 +480,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transaction_status_failed",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         month = request.get_ref().month
     ), level = "info")]
@@ -453,6 +545,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transaction_status_failed",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_yearly_transaction_status_failed(
@@ -508,6 +601,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transaction_status_success_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year,
         month = request.get_ref().month
@@ -582,6 +676,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transaction_status_success_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -653,6 +748,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transaction_status_failed_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year,
         month = request.get_ref().month
@@ -727,6 +823,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transaction_status_failed_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -798,6 +895,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_payment_methods",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_monthly_payment_methods(
@@ -853,6 +951,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_payment_methods",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_yearly_payment_methods(
@@ -908,6 +1007,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_amounts",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_monthly_amounts(
@@ -963,6 +1063,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_amounts",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ), level = "info")]
     async fn find_yearly_amounts(
@@ -1018,6 +1119,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_payment_methods_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -1089,6 +1191,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_payment_methods_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -1160,6 +1263,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_amounts_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -1231,6 +1335,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_amounts_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ), level = "info")]
@@ -1300,7 +1405,7 @@ impl TransactionService for TransactionServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_transaction_by_merchant_id", merchant_id = request.get_ref().merchant_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "find_transaction_by_merchant_id", peer = %peer_addr(&request), merchant_id = request.get_ref().merchant_id), level = "info")]
     async fn find_transaction_by_merchant_id(
         &self,
         request: Request<FindTransactionByMerchantIdRequest>,
@@ -1357,6 +1462,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_active_transaction",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1372,6 +1478,7 @@ impl TransactionService for TransactionServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -1429,6 +1536,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_trashed_transaction",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1444,6 +1552,7 @@ impl TransactionService for TransactionServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: (!req.sort_by.is_empty()).then(|| req.sort_by.clone()),
         };
 
         let result = self
@@ -1501,6 +1610,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "create_transaction",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty
     ), level = "info")]
     async fn create_transaction(
@@ -1524,6 +1634,7 @@ impl TransactionService for TransactionServiceImpl {
             payment_method: req.payment_method,
             merchant_id: Some(req.merchant_id),
             transaction_time: date,
+            metadata: (!req.metadata.is_empty()).then(|| req.metadata.into_iter().collect()),
         };
 
         let result = self
@@ -1570,6 +1681,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "update_transaction",
+        peer = %peer_addr(&request),
         transaction_id = request.get_ref().transaction_id,
         card_number = tracing::field::Empty
     ), level = "info")]
@@ -1644,7 +1756,7 @@ impl TransactionService for TransactionServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "trashed_transaction", transaction_id = request.get_ref().transaction_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "trashed_transaction", peer = %peer_addr(&request), transaction_id = request.get_ref().transaction_id), level = "info")]
     async fn trashed_transaction(
         &self,
         request: Request<FindByIdTransactionRequest>,
@@ -1699,7 +1811,7 @@ impl TransactionService for TransactionServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "restore_transaction", transaction_id = request.get_ref().transaction_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "restore_transaction", peer = %peer_addr(&request), transaction_id = request.get_ref().transaction_id), level = "info")]
     async fn restore_transaction(
         &self,
         request: Request<FindByIdTransactionRequest>,
@@ -1754,7 +1866,7 @@ impl TransactionService for TransactionServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "delete_transaction_permanent", transaction_id = request.get_ref().transaction_id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "delete_transaction_permanent", peer = %peer_addr(&request), transaction_id = request.get_ref().transaction_id), level = "info")]
     async fn delete_transaction_permanent(
         &self,
         request: Request<FindByIdTransactionRequest>,
@@ -1810,7 +1922,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(
         skip(self, _request),
-        fields(method = "restore_all_transaction"),
+        fields(method = "restore_all_transaction", peer = %peer_addr(&_request)),
         level = "info"
     )]
     async fn restore_all_transaction(
@@ -1834,6 +1946,7 @@ impl TransactionService for TransactionServiceImpl {
                 Ok(Response::new(ApiResponseTransactionAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
@@ -1859,7 +1972,7 @@ impl TransactionService for TransactionServiceImpl {
 
     #[instrument(
         skip(self, _request),
-        fields(method = "delete_all_transaction_permanent"),
+        fields(method = "delete_all_transaction_permanent", peer = %peer_addr(&_request)),
         level = "info"
     )]
     async fn delete_all_transaction_permanent(
@@ -1883,6 +1996,7 @@ impl TransactionService for TransactionServiceImpl {
                 Ok(Response::new(ApiResponseTransactionAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
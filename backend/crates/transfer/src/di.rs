@@ -1,5 +1,5 @@
 use anyhow::{Context, Result};
-use shared::config::RedisPool;
+use shared::config::{EventsConfig, RedisPool, TransferLimitsConfig, WriteLimiterConfig};
 use shared::{
     abstract_trait::{
         card::repository::query::DynCardQueryRepository,
@@ -33,6 +33,7 @@ use shared::{
     cache::CacheStore,
     config::ConnectionPool,
     context::shared_resources::SharedResources,
+    events::EventPublisher,
     observability::{CacheMetricsCore, TracingMetricsCore},
     repository::{
         card::query::CardQueryRepository,
@@ -56,6 +57,7 @@ use shared::{
         },
     },
 };
+use shared::{config::SoftDeleteRetentionConfig, utils::spawn_soft_delete_purge};
 use std::{fmt, sync::Arc, time::Duration};
 use tokio::sync::Semaphore;
 use tracing::{error, info, warn};
@@ -137,6 +139,14 @@ impl DependenciesInject {
             saldo_command: saldo_command_repo,
             query: transfer_query_repo.clone(),
             command: transfer_command_repo.clone(),
+            limits: TransferLimitsConfig::from_env()
+                .context("failed to load transfer limits config")?,
+            events: EventPublisher::from_config(
+                &EventsConfig::from_env().context("failed to load events config")?,
+            ),
+            max_concurrent_writes: WriteLimiterConfig::from_env()
+                .context("failed to load write limiter config")?
+                .max_concurrent_writes,
         };
         let transfer_command = Arc::new(
             TransferCommandService::new(command_deps, &shared)
@@ -173,6 +183,11 @@ impl DependenciesInject {
 
         Self::spawn_monitoring_task(Arc::clone(&shared.cache_store));
         Self::spawn_cleanup_task(Arc::clone(&shared.cache_store));
+        spawn_soft_delete_purge(
+            db.clone(),
+            &SoftDeleteRetentionConfig::from_env(),
+            "transfers",
+        );
 
         Ok(Self {
             transfer_command,
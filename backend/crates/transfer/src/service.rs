@@ -1,23 +1,27 @@
+use genproto::api::ApiResponseBalanceImpact;
 use genproto::transfer::{
     ApiResponsePaginationTransfer, ApiResponsePaginationTransferDeleteAt, ApiResponseTransfer,
     ApiResponseTransferAll, ApiResponseTransferDelete, ApiResponseTransferDeleteAt,
     ApiResponseTransferMonthAmount, ApiResponseTransferMonthStatusFailed,
-    ApiResponseTransferMonthStatusSuccess, ApiResponseTransferYearAmount,
-    ApiResponseTransferYearStatusFailed, ApiResponseTransferYearStatusSuccess,
-    ApiResponseTransfers, CreateTransferRequest, FindAllTransferRequest,
-    FindByCardNumberTransferRequest, FindByIdTransferRequest, FindMonthlyTransferStatus,
-    FindMonthlyTransferStatusCardNumber, FindTransferByTransferFromRequest,
-    FindTransferByTransferToRequest, FindYearTransferStatus, FindYearTransferStatusCardNumber,
-    UpdateTransferRequest, transfer_service_server::TransferService,
+    ApiResponseTransferMonthStatusSuccess, ApiResponseTransferNetPosition,
+    ApiResponseTransferYearAmount, ApiResponseTransferYearStatusFailed,
+    ApiResponseTransferYearStatusSuccess, ApiResponseTransfers, CreateTransferRequest,
+    FindAllTransferRequest, FindByCardNumberTransferRequest, FindByIdTransferRequest,
+    FindMonthlyTransferStatus, FindMonthlyTransferStatusCardNumber,
+    FindTransferByTransferFromRequest, FindTransferByTransferToRequest,
+    FindTransferNetPositionRequest, FindTransfersRequest, FindYearTransferStatus,
+    FindYearTransferStatusCardNumber, UpdateTransferRequest,
+    transfer_service_server::TransferService,
 };
 use shared::{
     domain::requests::transfer::{
         CreateTransferRequest as DomainCreateTransferRequest, FindAllTransfers,
-        MonthStatusTransfer, MonthStatusTransferCardNumber, MonthYearCardNumber,
-        UpdateTransferRequest as DomainUpdateTransferRequest, YearStatusTransferCardNumber,
+        FindTransferNetPosition, FindTransfers, MonthStatusTransfer, MonthStatusTransferCardNumber,
+        MonthYearCardNumber, UpdateTransferRequest as DomainUpdateTransferRequest,
+        YearStatusTransferCardNumber,
     },
     errors::{AppErrorGrpc, CircuitBreakerError},
-    utils::mask_card_number,
+    utils::{mask_card_number, peer_addr, timestamp_to_naive_date},
 };
 use std::sync::Arc;
 use tracing::{error, info, instrument, warn};
@@ -60,6 +64,7 @@ impl TransferServiceImpl {
 impl TransferService for TransferServiceImpl {
     #[instrument(skip(self, request), fields(
         method = "find_all_transfer",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -75,6 +80,7 @@ impl TransferService for TransferServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -130,7 +136,7 @@ impl TransferService for TransferServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_id_transfer", transfer_id = request.get_ref().transfer_id))]
+    #[instrument(skip(self, request), fields(method = "find_by_id_transfer", peer = %peer_addr(&request), transfer_id = request.get_ref().transfer_id))]
     async fn find_by_id_transfer(
         &self,
         request: Request<FindByIdTransferRequest>,
@@ -184,6 +190,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transfer_status_success",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         month = request.get_ref().month
     ))]
@@ -248,6 +255,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transfer_status_success",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ))]
     async fn find_yearly_transfer_status_success(
@@ -303,6 +311,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transfer_status_failed",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         month = request.get_ref().month
     ))]
@@ -367,6 +376,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transfer_status_failed",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ))]
     async fn find_yearly_transfer_status_failed(
@@ -422,6 +432,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transfer_status_success_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year,
         month = request.get_ref().month
@@ -496,6 +507,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transfer_status_success_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ))]
@@ -567,6 +579,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transfer_status_failed_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year,
         month = request.get_ref().month
@@ -641,6 +654,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transfer_status_failed_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ))]
@@ -712,6 +726,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transfer_amounts",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ))]
     async fn find_monthly_transfer_amounts(
@@ -767,6 +782,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transfer_amounts",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ))]
     async fn find_yearly_transfer_amounts(
@@ -822,6 +838,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transfer_amounts_by_sender_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ))]
@@ -893,6 +910,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transfer_amounts_by_sender_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ))]
@@ -964,6 +982,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_transfer_amounts_by_receiver_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ))]
@@ -1035,6 +1054,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_transfer_amounts_by_receiver_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ))]
@@ -1106,6 +1126,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_transfer_by_transfer_from",
+        peer = %peer_addr(&request),
         transfer_from = %request.get_ref().transfer_from
     ))]
     async fn find_transfer_by_transfer_from(
@@ -1164,6 +1185,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_transfer_by_transfer_to",
+        peer = %peer_addr(&request),
         transfer_to = %request.get_ref().transfer_to
     ))]
     async fn find_transfer_by_transfer_to(
@@ -1220,8 +1242,147 @@ impl TransferService for TransferServiceImpl {
         }
     }
 
+    #[instrument(skip(self, request), fields(
+        method = "find_transfers",
+        peer = %peer_addr(&request),
+        page = request.get_ref().page,
+        page_size = request.get_ref().page_size
+    ))]
+    async fn find_transfers(
+        &self,
+        request: Request<FindTransfersRequest>,
+    ) -> Result<Response<ApiResponsePaginationTransfer>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let non_empty = |v: String| if v.trim().is_empty() { None } else { Some(v) };
+
+        let domain_req = FindTransfers {
+            page: req.page,
+            page_size: req.page_size,
+            transfer_from: non_empty(req.transfer_from),
+            transfer_to: non_empty(req.transfer_to),
+            status: non_empty(req.status),
+            start_date: timestamp_to_naive_date(req.start_date),
+            end_date: timestamp_to_naive_date(req.end_date),
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .transfer_query
+                    .find_transfers(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponsePaginationTransfer {
+                    data: api_response.data.into_iter().map(Into::into).collect(),
+                    pagination: Some(api_response.pagination.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    page = domain_req.page,
+                    page_size = domain_req.page_size,
+                    "find_transfers success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            page = domain_req.page,
+                            page_size = domain_req.page_size,
+                            "find_transfers rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(
+                            page = domain_req.page,
+                            page_size = domain_req.page_size,
+                            error = %inner,
+                            "find_transfers failed"
+                        );
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(
+        method = "find_transfer_net_position",
+        peer = %peer_addr(&request),
+        card_a = %mask_card_number(&request.get_ref().card_a),
+        card_b = %mask_card_number(&request.get_ref().card_b)
+    ))]
+    async fn find_transfer_net_position(
+        &self,
+        request: Request<FindTransferNetPositionRequest>,
+    ) -> Result<Response<ApiResponseTransferNetPosition>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+
+        let domain_req = FindTransferNetPosition {
+            card_a: req.card_a,
+            card_b: req.card_b,
+            start_date: timestamp_to_naive_date(req.start_date),
+            end_date: timestamp_to_naive_date(req.end_date),
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .transfer_query
+                    .net_position(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseTransferNetPosition {
+                    status: api_response.status,
+                    message: api_response.message,
+                    data: Some(api_response.data.into()),
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!("find_transfer_net_position success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!("find_transfer_net_position rejected: circuit breaker open");
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(error = %inner, "find_transfer_net_position failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
     #[instrument(skip(self, request), fields(
         method = "find_by_active_transfer",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1237,6 +1398,7 @@ impl TransferService for TransferServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -1294,6 +1456,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_trashed_transfer",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1309,6 +1472,7 @@ impl TransferService for TransferServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: (!req.sort_by.is_empty()).then(|| req.sort_by.clone()),
         };
 
         let result = self
@@ -1366,6 +1530,7 @@ impl TransferService for TransferServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "create_transfer",
+        peer = %peer_addr(&request),
         transfer_from = %request.get_ref().transfer_from,
         transfer_to = %request.get_ref().transfer_to
     ))]
@@ -1435,8 +1600,76 @@ impl TransferService for TransferServiceImpl {
         }
     }
 
+    #[instrument(skip(self, request), fields(
+        method = "preview_transfer",
+        peer = %peer_addr(&request),
+        transfer_from = %request.get_ref().transfer_from,
+        transfer_to = %request.get_ref().transfer_to
+    ))]
+    async fn preview_transfer(
+        &self,
+        request: Request<CreateTransferRequest>,
+    ) -> Result<Response<ApiResponseBalanceImpact>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let transfer_from = req.transfer_from.clone();
+
+        let domain_req = DomainCreateTransferRequest {
+            transfer_from: transfer_from.clone(),
+            transfer_to: req.transfer_to,
+            transfer_amount: req.transfer_amount as i64,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .transfer_command
+                    .preview(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseBalanceImpact {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(transfer_from = transfer_from, "preview_transfer success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            transfer_from = transfer_from,
+                            "preview_transfer rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(
+                            transfer_from = transfer_from,
+                            error = %inner,
+                            "preview_transfer failed"
+                        );
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
     #[instrument(skip(self, request), fields(
         method = "update_transfer",
+        peer = %peer_addr(&request),
         transfer_id = request.get_ref().transfer_id
     ))]
     async fn update_transfer(
@@ -1497,7 +1730,7 @@ impl TransferService for TransferServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "trashed_transfer", transfer_id = request.get_ref().transfer_id))]
+    #[instrument(skip(self, request), fields(method = "trashed_transfer", peer = %peer_addr(&request), transfer_id = request.get_ref().transfer_id))]
     async fn trashed_transfer(
         &self,
         request: Request<FindByIdTransferRequest>,
@@ -1549,7 +1782,7 @@ impl TransferService for TransferServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "restore_transfer", transfer_id = request.get_ref().transfer_id))]
+    #[instrument(skip(self, request), fields(method = "restore_transfer", peer = %peer_addr(&request), transfer_id = request.get_ref().transfer_id))]
     async fn restore_transfer(
         &self,
         request: Request<FindByIdTransferRequest>,
@@ -1601,7 +1834,7 @@ impl TransferService for TransferServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "delete_transfer_permanent", transfer_id = request.get_ref().transfer_id))]
+    #[instrument(skip(self, request), fields(method = "delete_transfer_permanent", peer = %peer_addr(&request), transfer_id = request.get_ref().transfer_id))]
     async fn delete_transfer_permanent(
         &self,
         request: Request<FindByIdTransferRequest>,
@@ -1655,7 +1888,7 @@ impl TransferService for TransferServiceImpl {
         }
     }
 
-    #[instrument(skip(self, _request), fields(method = "restore_all_transfer"))]
+    #[instrument(skip(self, _request), fields(method = "restore_all_transfer", peer = %peer_addr(&_request)))]
     async fn restore_all_transfer(
         &self,
         _request: Request<()>,
@@ -1677,6 +1910,7 @@ impl TransferService for TransferServiceImpl {
                 Ok(Response::new(ApiResponseTransferAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
@@ -1700,7 +1934,7 @@ impl TransferService for TransferServiceImpl {
         }
     }
 
-    #[instrument(skip(self, _request), fields(method = "delete_all_transfer_permanent"))]
+    #[instrument(skip(self, _request), fields(method = "delete_all_transfer_permanent", peer = %peer_addr(&_request)))]
     async fn delete_all_transfer_permanent(
         &self,
         _request: Request<()>,
@@ -1722,6 +1956,7 @@ impl TransferService for TransferServiceImpl {
                 Ok(Response::new(ApiResponseTransferAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
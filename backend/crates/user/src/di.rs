@@ -4,25 +4,37 @@ use shared::{
         hashing::DynHashing,
         role::repository::query::DynRoleQueryRepository,
         user::{
-            repository::{command::DynUserCommandRepository, query::DynUserQueryRepository},
-            service::{command::DynUserCommandService, query::DynUserQueryService},
+            repository::{
+                anonymize::DynUserAnonymizationRepository, command::DynUserCommandRepository,
+                export::DynUserExportRepository, merge::DynUserMergeRepository,
+                query::DynUserQueryRepository,
+            },
+            service::{
+                command::DynUserCommandService, export::DynUserExportService,
+                query::DynUserQueryService,
+            },
         },
         user_roles::DynUserRoleCommandRepository,
     },
     cache::CacheStore,
-    config::{ConnectionPool, Hashing, RedisPool},
+    config::{ConnectionPool, EmailNormalizationConfig, Hashing, RedisPool},
     context::shared_resources::SharedResources,
     observability::{CacheMetricsCore, TracingMetricsCore},
     repository::{
         role::query::RoleQueryRepository,
-        user::{command::UserCommandRepository, query::UserQueryRepository},
+        user::{
+            anonymize::UserAnonymizationRepository, command::UserCommandRepository,
+            export::UserExportRepository, merge::UserMergeRepository, query::UserQueryRepository,
+        },
         user_role::UserRoleRepository,
     },
     service::user::{
         command::{UserCommandService, UserCommandServiceDeps},
+        export::{UserExportService, UserExportServiceDeps},
         query::UserQueryService,
     },
 };
+use shared::{config::SoftDeleteRetentionConfig, utils::spawn_soft_delete_purge};
 use std::{fmt, sync::Arc, time::Duration};
 use tokio::sync::Semaphore;
 use tracing::{error, info, warn};
@@ -37,6 +49,7 @@ pub struct DependencyMetrics {
 pub struct DependenciesInject {
     pub user_command: DynUserCommandService,
     pub user_query: DynUserQueryService,
+    pub user_export: DynUserExportService,
     pub cache_store: Arc<CacheStore>,
     pub request_limiter: Arc<Semaphore>,
 }
@@ -46,6 +59,7 @@ impl fmt::Debug for DependenciesInject {
         f.debug_struct("DependenciesInject")
             .field("user_command_service", &"DynUserCommandService")
             .field("user_query_service", &"DynUserQueryService")
+            .field("user_export_service", &"DynUserExportService")
             .finish()
     }
 }
@@ -79,6 +93,10 @@ impl DependenciesInject {
             hashing: hashing.clone(),
             user_role: user_role_repo.clone(),
             role: role_query_repo.clone(),
+            anonymization: Arc::new(UserAnonymizationRepository::new(db.clone()))
+                as DynUserAnonymizationRepository,
+            merge: Arc::new(UserMergeRepository::new(db.clone())) as DynUserMergeRepository,
+            email_normalization: EmailNormalizationConfig::from_env(),
         };
         let user_command = Arc::new(
             UserCommandService::new(user_command_service_deps, &shared)
@@ -90,12 +108,24 @@ impl DependenciesInject {
                 .context("failed to initialize user query service")?,
         ) as DynUserQueryService;
 
+        let user_export_deps = UserExportServiceDeps {
+            query: user_query_repo.clone(),
+            role: role_query_repo.clone(),
+            export: Arc::new(UserExportRepository::new(db.clone())) as DynUserExportRepository,
+        };
+        let user_export = Arc::new(
+            UserExportService::new(user_export_deps, &shared)
+                .context("failed to initialize user export service")?,
+        ) as DynUserExportService;
+
         Self::spawn_monitoring_task(Arc::clone(&shared.cache_store));
         Self::spawn_cleanup_task(Arc::clone(&shared.cache_store));
+        spawn_soft_delete_purge(db.clone(), &SoftDeleteRetentionConfig::from_env(), "users");
 
         Ok(Self {
             user_command,
             user_query,
+            user_export,
             request_limiter: Arc::new(Semaphore::new(1000)),
             cache_store: shared.cache_store,
         })
@@ -1,15 +1,17 @@
 use crate::state::AppState;
 use genproto::user::{
     ApiResponsePaginationUser, ApiResponsePaginationUserDeleteAt, ApiResponseUser,
-    ApiResponseUserAll, ApiResponseUserDelete, ApiResponseUserDeleteAt, CreateUserRequest,
-    FindAllUserRequest, FindByIdUserRequest, UpdateUserRequest, user_service_server::UserService,
+    ApiResponseUserAll, ApiResponseUserDataExport, ApiResponseUserDelete, ApiResponseUserDeleteAt,
+    ApiResponseUserMerge, CreateUserRequest, FindAllUserRequest, FindByIdUserRequest,
+    MergeUsersRequest, UpdateUserRequest, user_service_server::UserService,
 };
 use shared::{
     domain::requests::user::{
         CreateUserRequest as DomainCreateUserRequest, FindAllUserRequest as DomainFindAllRequest,
-        UpdateUserRequest as DomainUserRequest,
+        MergeUsersRequest as DomainMergeUsersRequest, UpdateUserRequest as DomainUserRequest,
     },
     errors::{AppErrorGrpc, CircuitBreakerError},
+    utils::peer_addr,
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
@@ -51,6 +53,7 @@ impl UserServiceImpl {
 impl UserService for UserServiceImpl {
     #[instrument(skip(self, request), fields(
         method = "find_all_user",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -66,6 +69,7 @@ impl UserService for UserServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -121,7 +125,7 @@ impl UserService for UserServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_id_user", user_id = request.get_ref().id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "find_by_id_user", peer = %peer_addr(&request), user_id = request.get_ref().id), level = "info")]
     async fn find_by_id(
         &self,
         request: Request<FindByIdUserRequest>,
@@ -173,8 +177,61 @@ impl UserService for UserServiceImpl {
         }
     }
 
+    #[instrument(skip(self, request), fields(method = "export_user_data", peer = %peer_addr(&request), user_id = request.get_ref().id), level = "info")]
+    async fn export_user_data(
+        &self,
+        request: Request<FindByIdUserRequest>,
+    ) -> Result<Response<ApiResponseUserDataExport>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let user_id = req.id;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .user_export
+                    .export_user_data(user_id)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseUserDataExport {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(user_id = user_id, "export_user_data success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            user_id = user_id,
+                            "export_user_data rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(user_id = user_id, error = %inner, "export_user_data failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
     #[instrument(skip(self, request), fields(
         method = "find_by_active_user",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -190,6 +247,7 @@ impl UserService for UserServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -247,6 +305,7 @@ impl UserService for UserServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_trashed_user",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -262,6 +321,7 @@ impl UserService for UserServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: (!req.sort_by.is_empty()).then(|| req.sort_by.clone()),
         };
 
         let result = self
@@ -316,7 +376,7 @@ impl UserService for UserServiceImpl {
             }
         }
     }
-    #[instrument(skip(self, request), fields(method = "create_user", email = %request.get_ref().email), level = "info")]
+    #[instrument(skip(self, request), fields(method = "create_user", peer = %peer_addr(&request), email = %request.get_ref().email), level = "info")]
     async fn create(
         &self,
         request: Request<CreateUserRequest>,
@@ -332,6 +392,7 @@ impl UserService for UserServiceImpl {
             email: email.clone(),
             password: req.password,
             confirm_password: req.confirm_password,
+            must_change_password: req.must_change_password,
         };
 
         let result = self
@@ -375,6 +436,7 @@ impl UserService for UserServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "update_user",
+        peer = %peer_addr(&request),
         user_id = request.get_ref().id,
         email = %request.get_ref().email
     ), level = "info")]
@@ -439,7 +501,7 @@ impl UserService for UserServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "trashed_user", user_id = request.get_ref().id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "trashed_user", peer = %peer_addr(&request), user_id = request.get_ref().id), level = "info")]
     async fn trashed_user(
         &self,
         request: Request<FindByIdUserRequest>,
@@ -491,7 +553,7 @@ impl UserService for UserServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "restore_user", user_id = request.get_ref().id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "restore_user", peer = %peer_addr(&request), user_id = request.get_ref().id), level = "info")]
     async fn restore_user(
         &self,
         request: Request<FindByIdUserRequest>,
@@ -543,7 +605,122 @@ impl UserService for UserServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "delete_user_permanent", user_id = request.get_ref().id), level = "info")]
+    #[instrument(skip(self, request), fields(method = "anonymize_user", peer = %peer_addr(&request), user_id = request.get_ref().id), level = "info")]
+    async fn anonymize_user(
+        &self,
+        request: Request<FindByIdUserRequest>,
+    ) -> Result<Response<ApiResponseUserDeleteAt>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let user_id = req.id;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .user_command
+                    .anonymize(user_id)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseUserDeleteAt {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(user_id = user_id, "anonymize_user success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            user_id = user_id,
+                            "anonymize_user rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(user_id = user_id, error = %inner, "anonymize_user failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "merge_users", peer = %peer_addr(&request), primary_id = request.get_ref().primary_id, duplicate_id = request.get_ref().duplicate_id), level = "info")]
+    async fn merge_users(
+        &self,
+        request: Request<MergeUsersRequest>,
+    ) -> Result<Response<ApiResponseUserMerge>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let primary_id = req.primary_id;
+        let duplicate_id = req.duplicate_id;
+
+        let domain_req = DomainMergeUsersRequest {
+            primary_id,
+            duplicate_id,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .user_command
+                    .merge(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseUserMerge {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    primary_id = primary_id,
+                    duplicate_id = duplicate_id,
+                    "merge_users success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            primary_id = primary_id,
+                            duplicate_id = duplicate_id,
+                            "merge_users rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(primary_id = primary_id, duplicate_id = duplicate_id, error = %inner, "merge_users failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "delete_user_permanent", peer = %peer_addr(&request), user_id = request.get_ref().id), level = "info")]
     async fn delete_user_permanent(
         &self,
         request: Request<FindByIdUserRequest>,
@@ -596,7 +773,7 @@ impl UserService for UserServiceImpl {
 
     #[instrument(
         skip(self, _request),
-        fields(method = "restore_all_user"),
+        fields(method = "restore_all_user", peer = %peer_addr(&_request)),
         level = "info"
     )]
     async fn restore_all_user(
@@ -620,6 +797,7 @@ impl UserService for UserServiceImpl {
                 Ok(Response::new(ApiResponseUserAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
@@ -645,7 +823,7 @@ impl UserService for UserServiceImpl {
 
     #[instrument(
         skip(self, _request),
-        fields(method = "delete_all_user_permanent"),
+        fields(method = "delete_all_user_permanent", peer = %peer_addr(&_request)),
         level = "info"
     )]
     async fn delete_all_user_permanent(
@@ -669,6 +847,7 @@ impl UserService for UserServiceImpl {
                 Ok(Response::new(ApiResponseUserAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
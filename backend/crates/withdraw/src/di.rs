@@ -30,7 +30,7 @@ use shared::{
         },
     },
     cache::CacheStore,
-    config::{ConnectionPool, RedisPool},
+    config::{ConnectionPool, RedisPool, WriteLimiterConfig},
     context::shared_resources::SharedResources,
     observability::{CacheMetricsCore, TracingMetricsCore},
     repository::{
@@ -55,6 +55,10 @@ use shared::{
         },
     },
 };
+use shared::{
+    config::{SoftDeleteRetentionConfig, WithdrawCancellationConfig},
+    utils::spawn_soft_delete_purge,
+};
 use std::{fmt, sync::Arc, time::Duration};
 use tokio::sync::Semaphore;
 use tracing::{error, info, warn};
@@ -137,6 +141,10 @@ impl DependenciesInject {
             card_query: card_query_repo,
             saldo_query: saldo_query_repo,
             saldo_command: saldo_command_repo,
+            cancellation: WithdrawCancellationConfig::from_env(),
+            max_concurrent_writes: WriteLimiterConfig::from_env()
+                .context("failed to load write limiter config")?
+                .max_concurrent_writes,
         };
         let withdraw_command = Arc::new(
             WithdrawCommandService::new(command_deps, &shared)
@@ -173,6 +181,11 @@ impl DependenciesInject {
 
         Self::spawn_monitoring_task(Arc::clone(&shared.cache_store));
         Self::spawn_cleanup_task(Arc::clone(&shared.cache_store));
+        spawn_soft_delete_purge(
+            db.clone(),
+            &SoftDeleteRetentionConfig::from_env(),
+            "withdraws",
+        );
 
         Ok(Self {
             withdraw_command,
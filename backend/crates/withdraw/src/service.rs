@@ -1,5 +1,6 @@
 use crate::state::AppState;
 use genproto::{
+    api::ApiResponseBalanceImpact,
     card::FindByCardNumberRequest,
     withdraw::{
         ApiResponsePaginationWithdraw, ApiResponsePaginationWithdrawDeleteAt, ApiResponseWithdraw,
@@ -9,20 +10,20 @@ use genproto::{
         ApiResponseWithdrawYearStatusFailed, ApiResponseWithdrawYearStatusSuccess,
         ApiResponsesWithdraw, CreateWithdrawRequest, FindAllWithdrawByCardNumberRequest,
         FindAllWithdrawRequest, FindByIdWithdrawRequest, FindMonthlyWithdrawStatus,
-        FindMonthlyWithdrawStatusCardNumber, FindYearWithdrawCardNumber, FindYearWithdrawStatus,
-        FindYearWithdrawStatusCardNumber, UpdateWithdrawRequest,
+        FindMonthlyWithdrawStatusCardNumber, FindWithdrawsRequest, FindYearWithdrawCardNumber,
+        FindYearWithdrawStatus, FindYearWithdrawStatusCardNumber, UpdateWithdrawRequest,
         withdraw_service_server::WithdrawService,
     },
 };
 use shared::{
     domain::requests::withdraw::{
         CreateWithdrawRequest as DomainCreateWithdrawRequest, FindAllWithdrawCardNumber,
-        FindAllWithdraws, MonthStatusWithdraw, MonthStatusWithdrawCardNumber,
+        FindAllWithdraws, FindWithdraws, MonthStatusWithdraw, MonthStatusWithdrawCardNumber,
         UpdateWithdrawRequest as DomainUpdateWithdrawRequest, YearMonthCardNumber,
         YearStatusWithdrawCardNumber,
     },
     errors::{AppErrorGrpc, CircuitBreakerError},
-    utils::{mask_card_number, timestamp_to_naive_datetime},
+    utils::{mask_card_number, peer_addr, timestamp_to_naive_date, timestamp_to_naive_datetime},
 };
 use std::sync::Arc;
 use tonic::{Request, Response, Status};
@@ -63,6 +64,7 @@ impl WithdrawServiceImpl {
 impl WithdrawService for WithdrawServiceImpl {
     #[instrument(skip(self, request), fields(
         method = "find_all_withdraw",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -78,6 +80,7 @@ impl WithdrawService for WithdrawServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -135,6 +138,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_all_withdraw_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
@@ -210,7 +214,86 @@ impl WithdrawService for WithdrawServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "find_by_id_withdraw", withdraw_id = request.get_ref().withdraw_id))]
+    #[instrument(skip(self, request), fields(
+        method = "find_withdraws",
+        peer = %peer_addr(&request),
+        page = request.get_ref().page,
+        page_size = request.get_ref().page_size
+    ))]
+    async fn find_withdraws(
+        &self,
+        request: Request<FindWithdrawsRequest>,
+    ) -> Result<Response<ApiResponsePaginationWithdraw>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let non_empty = |v: String| if v.trim().is_empty() { None } else { Some(v) };
+
+        let domain_req = FindWithdraws {
+            page: req.page,
+            page_size: req.page_size,
+            status: non_empty(req.status),
+            card_number: non_empty(req.card_number),
+            from: timestamp_to_naive_date(req.from),
+            to: timestamp_to_naive_date(req.to),
+            sort_by: non_empty(req.sort_by),
+            sort_dir: non_empty(req.sort_dir),
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .withdraw_query
+                    .find_withdraws(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponsePaginationWithdraw {
+                    data: api_response.data.into_iter().map(Into::into).collect(),
+                    pagination: Some(api_response.pagination.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(
+                    page = domain_req.page,
+                    page_size = domain_req.page_size,
+                    "find_withdraws success"
+                );
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            page = domain_req.page,
+                            page_size = domain_req.page_size,
+                            "find_withdraws rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(
+                            page = domain_req.page,
+                            page_size = domain_req.page_size,
+                            error = %inner,
+                            "find_withdraws failed"
+                        );
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "find_by_id_withdraw", peer = %peer_addr(&request), withdraw_id = request.get_ref().withdraw_id))]
     async fn find_by_id_withdraw(
         &self,
         request: Request<FindByIdWithdrawRequest>,
@@ -264,6 +347,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_withdraw_status_success",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         month = request.get_ref().month
     ))]
@@ -328,6 +412,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_withdraw_status_success",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ))]
     async fn find_yearly_withdraw_status_success(
@@ -383,6 +468,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_withdraw_status_failed",
+        peer = %peer_addr(&request),
         year = request.get_ref().year,
         month = request.get_ref().month
     ))]
@@ -447,6 +533,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_withdraw_status_failed",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ))]
     async fn find_yearly_withdraw_status_failed(
@@ -502,6 +589,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_withdraw_status_success_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year,
         month = request.get_ref().month
@@ -576,6 +664,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_withdraw_status_success_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ))]
@@ -647,6 +736,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_withdraw_status_failed_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year,
         month = request.get_ref().month
@@ -721,6 +811,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_withdraw_status_failed_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ))]
@@ -792,6 +883,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_withdraws",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ))]
     async fn find_monthly_withdraws(
@@ -847,6 +939,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_withdraws",
+        peer = %peer_addr(&request),
         year = request.get_ref().year
     ))]
     async fn find_yearly_withdraws(
@@ -902,6 +995,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_monthly_withdraws_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ))]
@@ -973,6 +1067,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_yearly_withdraws_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty,
         year = request.get_ref().year
     ))]
@@ -1044,6 +1139,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_card_number",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty
     ))]
     async fn find_by_card_number(
@@ -1101,6 +1197,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_active",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1116,6 +1213,7 @@ impl WithdrawService for WithdrawServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: None,
         };
 
         let result = self
@@ -1173,6 +1271,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "find_by_trashed",
+        peer = %peer_addr(&request),
         page = request.get_ref().page,
         page_size = request.get_ref().page_size,
         search = tracing::field::Empty
@@ -1188,6 +1287,7 @@ impl WithdrawService for WithdrawServiceImpl {
             page: req.page,
             page_size: req.page_size,
             search: req.search.clone(),
+            sort_by: (!req.sort_by.is_empty()).then(|| req.sort_by.clone()),
         };
 
         let result = self
@@ -1245,6 +1345,7 @@ impl WithdrawService for WithdrawServiceImpl {
 
     #[instrument(skip(self, request), fields(
         method = "create_withdraw",
+        peer = %peer_addr(&request),
         card_number = tracing::field::Empty
     ))]
     async fn create_withdraw(
@@ -1309,8 +1410,76 @@ impl WithdrawService for WithdrawServiceImpl {
         }
     }
 
+    #[instrument(skip(self, request), fields(
+        method = "preview_withdraw",
+        peer = %peer_addr(&request),
+        card_number = tracing::field::Empty
+    ))]
+    async fn preview_withdraw(
+        &self,
+        request: Request<CreateWithdrawRequest>,
+    ) -> Result<Response<ApiResponseBalanceImpact>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let card_number = req.card_number.clone();
+        let masked_card = mask_card_number(&card_number);
+        tracing::Span::current().record("card_number", &masked_card);
+
+        let date = timestamp_to_naive_datetime(req.withdraw_time)
+            .ok_or_else(|| Status::invalid_argument("withdraw_time invalid"))?;
+
+        let domain_req = DomainCreateWithdrawRequest {
+            card_number,
+            withdraw_amount: req.withdraw_amount,
+            withdraw_time: date,
+        };
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .withdraw_command
+                    .preview(&domain_req)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseBalanceImpact {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(card_number = masked_card, "preview_withdraw success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            card_number = masked_card,
+                            "preview_withdraw rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(card_number = masked_card, error = %inner, "preview_withdraw failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
     #[instrument(skip(self, request), fields(
         method = "update_withdraw",
+        peer = %peer_addr(&request),
         withdraw_id = request.get_ref().withdraw_id,
         card_number = tracing::field::Empty
     ))]
@@ -1382,7 +1551,59 @@ impl WithdrawService for WithdrawServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "trashed_withdraw", withdraw_id = request.get_ref().withdraw_id))]
+    #[instrument(skip(self, request), fields(method = "cancel_withdraw", peer = %peer_addr(&request), withdraw_id = request.get_ref().withdraw_id))]
+    async fn cancel_withdraw(
+        &self,
+        request: Request<FindByIdWithdrawRequest>,
+    ) -> Result<Response<ApiResponseWithdraw>, Status> {
+        self.check_rate_limit().await?;
+
+        let req = request.into_inner();
+        let withdraw_id = req.withdraw_id;
+
+        let result = self
+            .state
+            .circuit_breaker
+            .call_async(|| async {
+                let api_response = self
+                    .state
+                    .di_container
+                    .withdraw_command
+                    .cancel_withdraw(withdraw_id)
+                    .await
+                    .map_err(AppErrorGrpc::from)?;
+
+                Ok(Response::new(ApiResponseWithdraw {
+                    data: Some(api_response.data.into()),
+                    message: api_response.message,
+                    status: api_response.status,
+                }))
+            })
+            .await;
+
+        match result {
+            Ok(resp) => {
+                info!(withdraw_id = withdraw_id, "cancel_withdraw success");
+                Ok(resp)
+            }
+            Err(e) => {
+                match &e {
+                    CircuitBreakerError::Open => {
+                        warn!(
+                            withdraw_id = withdraw_id,
+                            "cancel_withdraw rejected: circuit breaker open"
+                        );
+                    }
+                    CircuitBreakerError::Inner(inner) => {
+                        error!(withdraw_id = withdraw_id, error = %inner, "cancel_withdraw failed");
+                    }
+                }
+                Err(e.into())
+            }
+        }
+    }
+
+    #[instrument(skip(self, request), fields(method = "trashed_withdraw", peer = %peer_addr(&request), withdraw_id = request.get_ref().withdraw_id))]
     async fn trashed_withdraw(
         &self,
         request: Request<FindByIdWithdrawRequest>,
@@ -1434,7 +1655,7 @@ impl WithdrawService for WithdrawServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "restore_withdraw", withdraw_id = request.get_ref().withdraw_id))]
+    #[instrument(skip(self, request), fields(method = "restore_withdraw", peer = %peer_addr(&request), withdraw_id = request.get_ref().withdraw_id))]
     async fn restore_withdraw(
         &self,
         request: Request<FindByIdWithdrawRequest>,
@@ -1486,7 +1707,7 @@ impl WithdrawService for WithdrawServiceImpl {
         }
     }
 
-    #[instrument(skip(self, request), fields(method = "delete_withdraw_permanent", withdraw_id = request.get_ref().withdraw_id))]
+    #[instrument(skip(self, request), fields(method = "delete_withdraw_permanent", peer = %peer_addr(&request), withdraw_id = request.get_ref().withdraw_id))]
     async fn delete_withdraw_permanent(
         &self,
         request: Request<FindByIdWithdrawRequest>,
@@ -1540,7 +1761,7 @@ impl WithdrawService for WithdrawServiceImpl {
         }
     }
 
-    #[instrument(skip(self, _request), fields(method = "restore_all_withdraw"))]
+    #[instrument(skip(self, _request), fields(method = "restore_all_withdraw", peer = %peer_addr(&_request)))]
     async fn restore_all_withdraw(
         &self,
         _request: Request<()>,
@@ -1562,6 +1783,7 @@ impl WithdrawService for WithdrawServiceImpl {
                 Ok(Response::new(ApiResponseWithdrawAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
@@ -1585,7 +1807,7 @@ impl WithdrawService for WithdrawServiceImpl {
         }
     }
 
-    #[instrument(skip(self, _request), fields(method = "delete_all_withdraw_permanent"))]
+    #[instrument(skip(self, _request), fields(method = "delete_all_withdraw_permanent", peer = %peer_addr(&_request)))]
     async fn delete_all_withdraw_permanent(
         &self,
         _request: Request<()>,
@@ -1607,6 +1829,7 @@ impl WithdrawService for WithdrawServiceImpl {
                 Ok(Response::new(ApiResponseWithdrawAll {
                     message: api_response.message,
                     status: api_response.status,
+                    data: Some(api_response.data.into()),
                 }))
             })
             .await;
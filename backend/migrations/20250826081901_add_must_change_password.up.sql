@@ -0,0 +1,3 @@
+-- Add up migration script here
+ALTER TABLE users
+    ADD COLUMN must_change_password BOOLEAN NOT NULL DEFAULT FALSE;
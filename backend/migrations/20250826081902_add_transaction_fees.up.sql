@@ -0,0 +1,9 @@
+-- Add up migration script here
+CREATE TABLE "transaction_fees" (
+    "transaction_id" INT PRIMARY KEY REFERENCES "transactions" ("transaction_id") ON DELETE CASCADE,
+    "gross_amount" BIGINT NOT NULL,
+    "fee_amount" BIGINT NOT NULL,
+    "net_amount" BIGINT NOT NULL,
+    "created_at" timestamp DEFAULT current_timestamp,
+    "updated_at" timestamp DEFAULT current_timestamp
+);
@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_transactions_metadata;
+
+ALTER TABLE "transactions" DROP COLUMN IF EXISTS "metadata";
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE "transactions" ADD COLUMN "metadata" JSONB;
+
+CREATE INDEX idx_transactions_metadata ON transactions USING GIN (metadata);
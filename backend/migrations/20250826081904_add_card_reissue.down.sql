@@ -0,0 +1,4 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_cards_reissued_from_card_id;
+
+ALTER TABLE "cards" DROP COLUMN IF EXISTS "reissued_from_card_id";
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE "cards" ADD COLUMN "reissued_from_card_id" INT DEFAULT NULL REFERENCES "cards" ("card_id");
+
+CREATE INDEX idx_cards_reissued_from_card_id ON cards (reissued_from_card_id);
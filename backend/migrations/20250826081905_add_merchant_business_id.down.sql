@@ -0,0 +1,4 @@
+-- Add down migration script here
+ALTER TABLE "merchants" DROP CONSTRAINT IF EXISTS uq_merchants_business_id;
+
+ALTER TABLE "merchants" DROP COLUMN IF EXISTS "business_id";
@@ -0,0 +1,4 @@
+-- Add up migration script here
+ALTER TABLE "merchants" ADD COLUMN "business_id" VARCHAR(64) DEFAULT NULL;
+
+ALTER TABLE "merchants" ADD CONSTRAINT uq_merchants_business_id UNIQUE ("business_id");
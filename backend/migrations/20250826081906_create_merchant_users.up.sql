@@ -0,0 +1,10 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS "merchant_users" (
+    "merchant_user_id" SERIAL PRIMARY KEY,
+    "merchant_id" INT NOT NULL REFERENCES "merchants" ("merchant_id") ON DELETE CASCADE,
+    "user_id" INT NOT NULL REFERENCES "users" ("user_id") ON DELETE CASCADE,
+    "created_at" timestamp DEFAULT current_timestamp,
+    CONSTRAINT uq_merchant_users_merchant_id_user_id UNIQUE ("merchant_id", "user_id")
+);
+
+CREATE INDEX idx_merchant_users_user_id ON merchant_users (user_id);
\ No newline at end of file
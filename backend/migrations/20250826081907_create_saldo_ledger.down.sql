@@ -0,0 +1,6 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_saldo_ledger_card_number_created_at;
+
+DROP INDEX IF EXISTS idx_saldo_ledger_reference;
+
+DROP TABLE IF EXISTS "saldo_ledger";
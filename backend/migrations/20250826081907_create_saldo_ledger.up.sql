@@ -0,0 +1,14 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS "saldo_ledger" (
+    "ledger_id" SERIAL PRIMARY KEY,
+    "card_number" VARCHAR(16) NOT NULL REFERENCES "cards" ("card_number"),
+    "amount" BIGINT NOT NULL,
+    "balance_after" BIGINT NOT NULL,
+    "reference_type" VARCHAR(32) NOT NULL,
+    "reference_id" INT NOT NULL,
+    "created_at" timestamp DEFAULT current_timestamp
+);
+
+CREATE INDEX idx_saldo_ledger_reference ON saldo_ledger (reference_type, reference_id);
+
+CREATE INDEX idx_saldo_ledger_card_number_created_at ON saldo_ledger (card_number, created_at);
@@ -0,0 +1,13 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_users_email_normalized;
+
+UPDATE "users" u
+SET email = b.email,
+    deleted_at = b.deleted_at,
+    updated_at = current_timestamp
+FROM user_email_backup b
+WHERE b.user_id = u.user_id;
+
+DROP TABLE IF EXISTS "user_email_backup";
+
+DROP TABLE IF EXISTS "user_email_conflicts";
@@ -0,0 +1,56 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS "user_email_conflicts" (
+    "user_id" INT PRIMARY KEY,
+    "original_email" varchar(100) NOT NULL,
+    "kept_user_id" INT NOT NULL,
+    "resolved_at" timestamp DEFAULT current_timestamp
+);
+
+-- Accounts whose emails only differ by case or surrounding whitespace would
+-- collide once normalized. Keep the oldest active account and retire the
+-- others, recording their original address so they can be merged by hand.
+WITH ranked AS (
+    SELECT
+        user_id,
+        email,
+        FIRST_VALUE(user_id) OVER w AS kept_user_id,
+        ROW_NUMBER() OVER w AS rn
+    FROM users
+    WINDOW w AS (
+        PARTITION BY LOWER(TRIM(email))
+        ORDER BY (deleted_at IS NOT NULL), user_id
+    )
+)
+INSERT INTO user_email_conflicts (user_id, original_email, kept_user_id)
+SELECT user_id, email, kept_user_id
+FROM ranked
+WHERE rn > 1
+ON CONFLICT (user_id) DO NOTHING;
+
+-- Snapshot every row the updates below touch so the down migration can put
+-- the original addresses and deletion state back.
+CREATE TABLE IF NOT EXISTS "user_email_backup" (
+    "user_id" INT PRIMARY KEY,
+    "email" varchar(100) NOT NULL,
+    "deleted_at" timestamp
+);
+
+INSERT INTO user_email_backup (user_id, email, deleted_at)
+SELECT u.user_id, u.email, u.deleted_at
+FROM users u
+WHERE u.email <> LOWER(TRIM(u.email))
+   OR EXISTS (SELECT 1 FROM user_email_conflicts c WHERE c.user_id = u.user_id)
+ON CONFLICT (user_id) DO NOTHING;
+
+UPDATE "users" u
+SET email = 'conflict-' || u.user_id || '@duplicate.invalid',
+    deleted_at = COALESCE(u.deleted_at, current_timestamp),
+    updated_at = current_timestamp
+FROM user_email_conflicts c
+WHERE c.user_id = u.user_id;
+
+UPDATE "users"
+SET email = LOWER(TRIM(email))
+WHERE email <> LOWER(TRIM(email));
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_users_email_normalized ON users (LOWER(TRIM(email)));
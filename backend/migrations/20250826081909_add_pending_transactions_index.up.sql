@@ -0,0 +1,5 @@
+-- Add up migration script here
+CREATE INDEX IF NOT EXISTS idx_transactions_pending_created_at ON transactions (created_at)
+WHERE
+    status = 'pending'
+    AND deleted_at IS NULL;
@@ -0,0 +1,2 @@
+-- Add up migration script here
+ALTER TABLE "cards" ADD COLUMN "frozen_at" TIMESTAMP DEFAULT NULL;
@@ -0,0 +1,3 @@
+-- Add up migration script here
+CREATE INDEX IF NOT EXISTS idx_saldos_leaderboard ON saldos (total_balance DESC, card_number ASC)
+WHERE deleted_at IS NULL;
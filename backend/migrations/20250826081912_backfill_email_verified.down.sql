@@ -0,0 +1 @@
+-- The backfill cannot be reverted: verified flags set by users afterwards are indistinguishable.
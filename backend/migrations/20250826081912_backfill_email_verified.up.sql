@@ -0,0 +1,9 @@
+-- Accounts created before email verification existed never received a token;
+-- treat them as verified so enabling REQUIRE_EMAIL_VERIFICATION does not lock them out.
+UPDATE users
+SET email_verified = TRUE
+WHERE email_verified = FALSE
+  AND created_at < COALESCE(
+      (SELECT installed_on FROM _sqlx_migrations WHERE version = 20250826081900),
+      CURRENT_TIMESTAMP
+  );
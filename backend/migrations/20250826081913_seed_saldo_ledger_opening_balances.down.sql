@@ -0,0 +1 @@
+-- Seeded rows cannot be told apart from saldo adjustments written afterwards, so they are kept.
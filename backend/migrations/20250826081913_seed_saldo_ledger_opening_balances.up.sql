@@ -0,0 +1,17 @@
+-- Give every card a ledger starting point so statements can rebuild balances from history.
+-- The seeded row carries whatever balance predates the ledger and sorts before the card's first entry.
+INSERT INTO saldo_ledger (card_number, amount, balance_after, reference_type, reference_id, created_at)
+SELECT
+    s.card_number,
+    s.total_balance - COALESCE(l.total, 0),
+    s.total_balance - COALESCE(l.total, 0),
+    'saldo',
+    s.saldo_id,
+    COALESCE(LEAST(s.created_at, l.first_at - INTERVAL '1 second'), CURRENT_TIMESTAMP)
+FROM saldos s
+LEFT JOIN (
+    SELECT card_number, SUM(amount) AS total, MIN(created_at) AS first_at
+    FROM saldo_ledger
+    GROUP BY card_number
+) l ON l.card_number = s.card_number
+WHERE s.total_balance - COALESCE(l.total, 0) <> 0;
@@ -0,0 +1,7 @@
+-- Add up migration script here
+CREATE TABLE IF NOT EXISTS "merchant_webhooks" (
+    "merchant_id" INT PRIMARY KEY REFERENCES "merchants" ("merchant_id") ON DELETE CASCADE,
+    "url" TEXT NOT NULL,
+    "created_at" timestamp DEFAULT current_timestamp,
+    "updated_at" timestamp DEFAULT current_timestamp
+);